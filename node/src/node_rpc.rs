@@ -30,6 +30,11 @@
 
 #![warn(missing_docs)]
 
+pub mod data_directory;
+pub mod elections;
+pub mod proposals;
+pub mod reward_arrears;
+
 use std::sync::Arc;
 
 use node_runtime::UncheckedExtrinsic;
@@ -108,11 +113,19 @@ where
     >,
     C::Api: BabeApi<Block>,
     C::Api: BlockBuilder<Block>,
+    C::Api: node_runtime::ProposalsApi<Block>,
+    C::Api: node_runtime::DataDirectoryApi<Block>,
+    C::Api: node_runtime::RewardArrearsApi<Block>,
+    C::Api: node_runtime::ElectionsApi<Block>,
     P: TransactionPool + 'static,
     M: jsonrpc_core::Metadata + Default,
     SC: SelectChain<Block> + 'static,
 {
+    use data_directory::{DataDirectory, DataDirectoryApi};
+    use elections::{Elections, ElectionsApi};
     use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApi};
+    use proposals::{Proposals, ProposalsApi};
+    use reward_arrears::{RewardArrears, RewardArrearsApi};
     use substrate_frame_rpc_system::{FullSystem, SystemApi};
 
     let mut io = jsonrpc_core::IoHandler::default();
@@ -145,6 +158,14 @@ where
     io.extend_with(TransactionPaymentApi::to_delegate(TransactionPayment::new(
         client.clone(),
     )));
+    io.extend_with(ProposalsApi::to_delegate(Proposals::new(client.clone())));
+    io.extend_with(DataDirectoryApi::to_delegate(DataDirectory::new(
+        client.clone(),
+    )));
+    io.extend_with(RewardArrearsApi::to_delegate(RewardArrears::new(
+        client.clone(),
+    )));
+    io.extend_with(ElectionsApi::to_delegate(Elections::new(client.clone())));
     io.extend_with(sc_consensus_babe_rpc::BabeApi::to_delegate(
         BabeRpcHandler::new(
             client,
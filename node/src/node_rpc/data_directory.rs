@@ -0,0 +1,76 @@
+//! Data directory RPC methods. Provides the known content ids by pages using the
+//! `DataDirectoryApi` runtime API.
+
+use std::sync::Arc;
+
+use jsonrpc_core::{Error as RpcError, ErrorCode, Result};
+use jsonrpc_derive::rpc;
+use node_runtime::{opaque::Block, ContentId, DataDirectoryApi as DataDirectoryRuntimeApi};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::{generic::BlockId, traits::Block as BlockT};
+
+/// Data directory RPC methods.
+#[rpc]
+pub trait DataDirectoryApi<BlockHash> {
+    /// Returns up to `limit` known content ids starting from the `start` index.
+    #[rpc(name = "dataDirectory_knownContentIds")]
+    fn known_content_ids(
+        &self,
+        start: u64,
+        limit: u64,
+        at: Option<BlockHash>,
+    ) -> Result<Vec<ContentId>>;
+
+    /// Returns the number of the known content ids.
+    #[rpc(name = "dataDirectory_knownContentIdsCount")]
+    fn known_content_ids_count(&self, at: Option<BlockHash>) -> Result<u64>;
+}
+
+/// Implements the data directory RPC methods.
+pub struct DataDirectory<C> {
+    client: Arc<C>,
+}
+
+impl<C> DataDirectory<C> {
+    /// Creates a new instance of the data directory RPC handler.
+    pub fn new(client: Arc<C>) -> Self {
+        DataDirectory { client }
+    }
+}
+
+impl<C> DataDirectoryApi<<Block as BlockT>::Hash> for DataDirectory<C>
+where
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: DataDirectoryRuntimeApi<Block>,
+{
+    fn known_content_ids(
+        &self,
+        start: u64,
+        limit: u64,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Vec<ContentId>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        api.known_content_ids(&at, start, limit)
+            .map_err(runtime_error_into_rpc_err)
+    }
+
+    fn known_content_ids_count(&self, at: Option<<Block as BlockT>::Hash>) -> Result<u64> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        api.known_content_ids_count(&at)
+            .map_err(runtime_error_into_rpc_err)
+    }
+}
+
+// Converts a runtime API error into the RPC error.
+fn runtime_error_into_rpc_err(err: impl std::fmt::Debug) -> RpcError {
+    RpcError {
+        code: ErrorCode::ServerError(1),
+        message: "Runtime error while querying the data directory.".into(),
+        data: Some(format!("{:?}", err).into()),
+    }
+}
@@ -0,0 +1,104 @@
+//! Council elections RPC methods. Provides the archived results of the election rounds and
+//! the participation history of the accounts using the `ElectionsApi` runtime API.
+
+use std::sync::Arc;
+
+use jsonrpc_core::{Error as RpcError, ErrorCode, Result};
+use jsonrpc_derive::rpc;
+use node_runtime::{
+    opaque::Block, AccountId, ElectionParticipation, ElectionResult,
+    ElectionsApi as ElectionsRuntimeApi,
+};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::{generic::BlockId, traits::Block as BlockT};
+
+/// Council elections RPC methods.
+#[rpc]
+pub trait ElectionsApi<BlockHash> {
+    /// Returns up to `limit` election results ordered from newer to older rounds starting
+    /// from the `start_round` (inclusive) or from the latest round.
+    #[rpc(name = "elections_electionResults")]
+    fn election_results(
+        &self,
+        start_round: Option<u32>,
+        limit: u32,
+        at: Option<BlockHash>,
+    ) -> Result<Vec<ElectionResult>>;
+
+    /// Returns the results of the election round.
+    #[rpc(name = "elections_electionResult")]
+    fn election_result(&self, round: u32, at: Option<BlockHash>) -> Result<Option<ElectionResult>>;
+
+    /// Returns the election rounds the account participated in as an applicant or a voter.
+    #[rpc(name = "elections_participationHistory")]
+    fn participation_history(
+        &self,
+        account_id: AccountId,
+        at: Option<BlockHash>,
+    ) -> Result<Vec<ElectionParticipation>>;
+}
+
+/// Implements the council elections RPC methods.
+pub struct Elections<C> {
+    client: Arc<C>,
+}
+
+impl<C> Elections<C> {
+    /// Creates a new instance of the council elections RPC handler.
+    pub fn new(client: Arc<C>) -> Self {
+        Elections { client }
+    }
+}
+
+impl<C> ElectionsApi<<Block as BlockT>::Hash> for Elections<C>
+where
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: ElectionsRuntimeApi<Block>,
+{
+    fn election_results(
+        &self,
+        start_round: Option<u32>,
+        limit: u32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Vec<ElectionResult>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        api.election_results(&at, start_round, limit)
+            .map_err(runtime_error_into_rpc_err)
+    }
+
+    fn election_result(
+        &self,
+        round: u32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Option<ElectionResult>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        api.election_result(&at, round)
+            .map_err(runtime_error_into_rpc_err)
+    }
+
+    fn participation_history(
+        &self,
+        account_id: AccountId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Vec<ElectionParticipation>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        api.participation_history(&at, account_id)
+            .map_err(runtime_error_into_rpc_err)
+    }
+}
+
+// Converts a runtime API error into the RPC error.
+fn runtime_error_into_rpc_err(err: impl std::fmt::Debug) -> RpcError {
+    RpcError {
+        code: ErrorCode::ServerError(1),
+        message: "Runtime error while querying the election results.".into(),
+        data: Some(format!("{:?}", err).into()),
+    }
+}
@@ -0,0 +1,85 @@
+//! Proposals RPC methods. Provides proposals with their decoded details and voting state
+//! using the `ProposalsApi` runtime API.
+
+use std::sync::Arc;
+
+use jsonrpc_core::{Error as RpcError, ErrorCode, Result};
+use jsonrpc_derive::rpc;
+use node_runtime::{
+    opaque::Block, ProposalInfo, ProposalsApi as ProposalsRuntimeApi, ProposalsFilter,
+};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::{generic::BlockId, traits::Block as BlockT};
+
+/// Proposals RPC methods.
+#[rpc]
+pub trait ProposalsApi<BlockHash> {
+    /// Returns up to `limit` proposals matching the filter. Proposals are ordered from newer
+    /// to older ones starting from the `start_id` (inclusive) or from the latest proposal.
+    #[rpc(name = "proposals_proposals")]
+    fn proposals(
+        &self,
+        filter: ProposalsFilter,
+        start_id: Option<u32>,
+        limit: u32,
+        at: Option<BlockHash>,
+    ) -> Result<Vec<ProposalInfo>>;
+
+    /// Returns the proposal with its details and voting state.
+    #[rpc(name = "proposals_proposal")]
+    fn proposal(&self, proposal_id: u32, at: Option<BlockHash>) -> Result<Option<ProposalInfo>>;
+}
+
+/// Implements the proposals RPC methods.
+pub struct Proposals<C> {
+    client: Arc<C>,
+}
+
+impl<C> Proposals<C> {
+    /// Creates a new instance of the proposals RPC handler.
+    pub fn new(client: Arc<C>) -> Self {
+        Proposals { client }
+    }
+}
+
+impl<C> ProposalsApi<<Block as BlockT>::Hash> for Proposals<C>
+where
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: ProposalsRuntimeApi<Block>,
+{
+    fn proposals(
+        &self,
+        filter: ProposalsFilter,
+        start_id: Option<u32>,
+        limit: u32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Vec<ProposalInfo>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        api.proposals(&at, filter, start_id, limit)
+            .map_err(runtime_error_into_rpc_err)
+    }
+
+    fn proposal(
+        &self,
+        proposal_id: u32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Option<ProposalInfo>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        api.proposal(&at, proposal_id)
+            .map_err(runtime_error_into_rpc_err)
+    }
+}
+
+// Converts a runtime API error into the RPC error.
+fn runtime_error_into_rpc_err(err: impl std::fmt::Debug) -> RpcError {
+    RpcError {
+        code: ErrorCode::ServerError(1),
+        message: "Runtime error while querying proposals.".into(),
+        data: Some(format!("{:?}", err).into()),
+    }
+}
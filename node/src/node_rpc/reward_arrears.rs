@@ -0,0 +1,124 @@
+//! Reward arrears RPC methods. Provides the rewards owed to the workers, the curators and the
+//! council members using the `RewardArrearsApi` runtime API.
+
+use std::sync::Arc;
+
+use jsonrpc_core::{Error as RpcError, ErrorCode, Result};
+use jsonrpc_derive::rpc;
+use node_runtime::common::working_group::WorkingGroup;
+use node_runtime::{
+    opaque::Block, AccountId, ActorId, Balance, RewardArrearsApi as RewardArrearsRuntimeApi,
+    RewardRelationshipId,
+};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::{generic::BlockId, traits::Block as BlockT};
+
+/// Reward arrears RPC methods.
+#[rpc]
+pub trait RewardArrearsApi<BlockHash> {
+    /// Returns the reward owed to the worker of the working group.
+    #[rpc(name = "rewardArrears_workerOwedReward")]
+    fn worker_owed_reward(
+        &self,
+        working_group: WorkingGroup,
+        worker_id: ActorId,
+        at: Option<BlockHash>,
+    ) -> Result<Balance>;
+
+    /// Returns the reward owed to the curator of the content working group.
+    #[rpc(name = "rewardArrears_curatorOwedReward")]
+    fn curator_owed_reward(&self, curator_id: ActorId, at: Option<BlockHash>) -> Result<Balance>;
+
+    /// Returns the reward owed to the council member.
+    #[rpc(name = "rewardArrears_councilorOwedReward")]
+    fn councilor_owed_reward(
+        &self,
+        account_id: AccountId,
+        at: Option<BlockHash>,
+    ) -> Result<Balance>;
+
+    /// Returns the reward owed for the reward relationship.
+    #[rpc(name = "rewardArrears_owedReward")]
+    fn owed_reward(
+        &self,
+        reward_relationship_id: RewardRelationshipId,
+        at: Option<BlockHash>,
+    ) -> Result<Balance>;
+}
+
+/// Implements the reward arrears RPC methods.
+pub struct RewardArrears<C> {
+    client: Arc<C>,
+}
+
+impl<C> RewardArrears<C> {
+    /// Creates a new instance of the reward arrears RPC handler.
+    pub fn new(client: Arc<C>) -> Self {
+        RewardArrears { client }
+    }
+}
+
+impl<C> RewardArrearsApi<<Block as BlockT>::Hash> for RewardArrears<C>
+where
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: RewardArrearsRuntimeApi<Block>,
+{
+    fn worker_owed_reward(
+        &self,
+        working_group: WorkingGroup,
+        worker_id: ActorId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Balance> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        api.worker_owed_reward(&at, working_group, worker_id)
+            .map_err(runtime_error_into_rpc_err)
+    }
+
+    fn curator_owed_reward(
+        &self,
+        curator_id: ActorId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Balance> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        api.curator_owed_reward(&at, curator_id)
+            .map_err(runtime_error_into_rpc_err)
+    }
+
+    fn councilor_owed_reward(
+        &self,
+        account_id: AccountId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Balance> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        api.councilor_owed_reward(&at, account_id)
+            .map_err(runtime_error_into_rpc_err)
+    }
+
+    fn owed_reward(
+        &self,
+        reward_relationship_id: RewardRelationshipId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Balance> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+        api.owed_reward(&at, reward_relationship_id)
+            .map_err(runtime_error_into_rpc_err)
+    }
+}
+
+// Converts a runtime API error into the RPC error.
+fn runtime_error_into_rpc_err(err: impl std::fmt::Debug) -> RpcError {
+    RpcError {
+        code: ErrorCode::ServerError(1),
+        message: "Runtime error while querying the reward arrears.".into(),
+        data: Some(format!("{:?}", err).into()),
+    }
+}
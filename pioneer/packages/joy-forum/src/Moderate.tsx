@@ -90,11 +90,8 @@ const InnerForm = (props: FormProps) => {
   const buildTxParams = () => {
     if (!isValid) return [];
 
-    if (isThread) {
-      return [id, rationale];
-    } else {
-      return [id, rationale];
-    }
+    // Moderating as the forum sudo, not as a category moderator.
+    return [null, id, rationale];
   };
 
   const form =
@@ -267,9 +267,11 @@ class Upload extends React.PureComponent<Props, State> {
 
     // TODO get corresponding data type id based on file content
     const dataObjectTypeId = new BN(1);
-    const { myMemberId } = this.props;
+    const { myMemberId, api, match: { params: { channelId } } } = this.props;
 
-    return [myMemberId, newContentId, dataObjectTypeId, new BN(file.size), ipfs_cid];
+    // The content counts against the channel storage quota. No Merkle root is committed,
+    // so the storage providers are not challenged to prove they keep the content.
+    return [myMemberId, api.createType('Option<ChannelId>', channelId), newContentId, dataObjectTypeId, new BN(file.size), ipfs_cid, null];
   }
 
   private onDataObjectCreated = async (_txResult: SubmittableResult) => {
@@ -222,6 +222,7 @@ export const GenericProposalForm: React.FunctionComponent<GenericFormInnerProps>
                 values.title,
                 values.rationale,
                 requiredStake,
+                null, // exactly_execute_at: executed after the grace period
                 // submitParams is any[], but it's not much of an issue (params can vary a lot)
                 // eslint-disable-next-line @typescript-eslint/no-unsafe-assignment
                 ...(submitParams || [])
@@ -4,6 +4,11 @@ use system::RawOrigin;
 pub trait ActorOriginValidator<Origin, ActorId, AccountId> {
     /// Check for valid combination of origin and actor_id.
     fn ensure_actor_origin(origin: Origin, actor_id: ActorId) -> Result<AccountId, &'static str>;
+
+    /// Grants the account the rights required by the validator on top of being the actor
+    /// account (eg.: council membership). Used to prepare the benchmarks state.
+    #[cfg(feature = "runtime-benchmarks")]
+    fn prepare_actor_origin(_actor_id: ActorId, _account_id: AccountId) {}
 }
 
 // TODO: delete when T::Origin will support the clone()
@@ -6,10 +6,16 @@ use serde::{Deserialize, Serialize};
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Encode, Decode, Clone, PartialEq, Eq, Copy, Debug)]
 pub enum WorkingGroup {
-    /* Reserved
-        /// Forum working group: working_group::Instance1.
-        Forum,
-    */
     /// Storage working group: working_group::Instance2.
     Storage,
+
+    /// Forum working group: working_group::Instance1.
+    /// Declared after `Storage` to keep the encoding of existing values.
+    Forum,
+
+    /// Content working group: working_group::Instance3.
+    Content,
+
+    /// Membership working group: working_group::Instance4.
+    Membership,
 }
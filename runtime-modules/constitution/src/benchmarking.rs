@@ -0,0 +1,40 @@
+//! Constitution module benchmarking.
+
+use super::*;
+use frame_benchmarking::benchmarks;
+use sp_std::vec;
+use system::RawOrigin;
+
+fn text(len: u32) -> Vec<u8> {
+    vec![b'x'; len as usize]
+}
+
+benchmarks! {
+    _ { }
+
+    amend_constitution {
+        let t in 1 .. T::ConstitutionTextMaxLength::get();
+
+        let text = text(t);
+        let amendment = ConstitutionAmendment {
+            content_hash: T::Hashing::hash(&text),
+            content: ConstitutionContent::Text(text),
+        };
+    }: _(RawOrigin::Root, amendment)
+    verify {
+        assert_eq!(Module::<T>::current_version(), 1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::mock::{initial_test_ext, Test};
+
+    #[test]
+    fn constitution_benchmarks_succeed() {
+        initial_test_ext().execute_with(|| {
+            assert!(test_benchmark_amend_constitution::<Test>().is_ok());
+        });
+    }
+}
@@ -0,0 +1,282 @@
+//! # Constitution module
+//! Constitution module for the Joystream platform.
+//!
+//! ## Overview
+//!
+//! The constitution module keeps the community rules on-chain. Each amendment creates a new
+//! version of the constitution, the previous versions are kept and can be queried by their
+//! version number. The constitution content is either the text itself or a data directory
+//! content id of the uploaded document, accompanied by its content hash. The uploaded document
+//! must exist in the data directory and cannot be deleted afterwards.
+//!
+//! The constitution is amended only by the root origin. On the platform, this is done through
+//! the _AmendConstitution_ proposal of the proposals codex.
+//!
+//! ## Supported extrinsics
+//! - [amend_constitution](./struct.Module.html#method.amend_constitution) - sets the new version
+//! of the constitution
+//!
+//! ## Public API methods
+//! - [constitution](./struct.Module.html#method.constitution) - returns the current constitution
+//! - [constitution_history](./struct.Module.html#method.constitution_history) - returns all
+//! versions of the constitution
+//! - [ensure_amendment_valid](./struct.Module.html#method.ensure_amendment_valid) - ensures the
+//! constitution can be amended
+//! - [is_constitution_content](./struct.Module.html#method.is_constitution_content) - verifies
+//! that the content is the constitution document
+
+// Ensure we're `no_std` when compiling for Wasm.
+#![cfg_attr(not(feature = "std"), no_std)]
+
+// Do not delete! Cannot be uncommented by default, because of Parity decl_module! issue.
+//#![warn(missing_docs)]
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+#[cfg(test)]
+mod tests;
+
+use codec::{Decode, Encode};
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+
+use frame_support::dispatch::DispatchResult;
+use frame_support::traits::Get;
+use frame_support::weights::Weight;
+use frame_support::{
+    decl_error, decl_event, decl_module, decl_storage, ensure, Parameter, StorageMap,
+};
+use sp_runtime::traits::{Hash, MaybeSerialize, Member};
+use sp_std::vec::Vec;
+use system::ensure_root;
+
+/// Version number of the constitution. The first version is 1.
+pub type ConstitutionVersion = u32;
+
+/// Content of the constitution.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug)]
+pub enum ConstitutionContent<ContentId> {
+    /// The constitution text.
+    Text(Vec<u8>),
+
+    /// Content id of the constitution document uploaded to the data directory.
+    DataObject(ContentId),
+}
+
+/// Amendment of the constitution: the new content with its hash.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug)]
+pub struct ConstitutionAmendment<Hash, ContentId> {
+    /// Hash of the constitution text or of the uploaded document.
+    pub content_hash: Hash,
+
+    /// The constitution content.
+    pub content: ConstitutionContent<ContentId>,
+}
+
+/// Version of the constitution.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug)]
+pub struct Constitution<Hash, ContentId, BlockNumber> {
+    /// Hash of the constitution text or of the uploaded document.
+    pub content_hash: Hash,
+
+    /// The constitution content.
+    pub content: ConstitutionContent<ContentId>,
+
+    /// When the constitution was amended to this version.
+    pub amended_at: BlockNumber,
+}
+
+/// _Constitution_ alias for type simplification.
+pub type ConstitutionOf<T> = Constitution<
+    <T as system::Trait>::Hash,
+    <T as Trait>::ContentId,
+    <T as system::Trait>::BlockNumber,
+>;
+
+/// _ConstitutionAmendment_ alias for type simplification.
+pub type ConstitutionAmendmentOf<T> =
+    ConstitutionAmendment<<T as system::Trait>::Hash, <T as Trait>::ContentId>;
+
+/// Weight information for the constitution extrinsics. `t` is the byte length of the
+/// constitution text.
+pub trait WeightInfo {
+    fn amend_constitution(t: u32) -> Weight;
+}
+
+/// Default weights, used by the tests.
+impl WeightInfo for () {
+    fn amend_constitution(_t: u32) -> Weight {
+        10_000_000
+    }
+}
+
+/// Validates the existence of the uploaded constitution document.
+pub trait ContentExistenceValidator<ContentId> {
+    /// Verifies that the content exists in the data directory.
+    fn content_exists(content_id: &ContentId) -> bool;
+}
+
+/// Constitution module trait.
+pub trait Trait: system::Trait {
+    /// Constitution event type.
+    type Event: From<Event<Self>> + Into<<Self as system::Trait>::Event>;
+
+    /// Content id of the data directory.
+    type ContentId: Parameter + Member + MaybeSerialize + Copy + Ord + Default;
+
+    /// Validates the existence of the uploaded constitution document.
+    type ContentExistenceValidator: ContentExistenceValidator<Self::ContentId>;
+
+    /// Defines max allowed constitution text length.
+    type ConstitutionTextMaxLength: Get<u32>;
+
+    /// Weight information for the extrinsics of this module.
+    type WeightInfo: WeightInfo;
+}
+
+decl_event!(
+    /// Constitution events
+    pub enum Event<T>
+    where
+        <T as system::Trait>::Hash,
+    {
+        /// Emits on the constitution amendment.
+        /// Params:
+        /// - Version of the amended constitution.
+        /// - Content hash of the amended constitution.
+        ConstitutionAmended(ConstitutionVersion, Hash),
+    }
+);
+
+decl_error! {
+    /// Constitution module predefined errors
+    pub enum Error for Module<T: Trait> {
+        /// Constitution text cannot be empty
+        ConstitutionTextIsEmpty,
+
+        /// Constitution text is too long
+        ConstitutionTextIsTooLong,
+
+        /// Content hash doesn't match the constitution text
+        ContentHashMismatch,
+
+        /// Constitution document doesn't exist in the data directory
+        ConstitutionContentNotFound,
+    }
+}
+
+// Storage for the constitution module
+decl_storage! {
+    pub trait Store for Module<T: Trait> as Constitution {
+        /// Versions of the constitution by the version number.
+        pub ConstitutionByVersion get(fn constitution_by_version): map hasher(twox_64_concat)
+            ConstitutionVersion => Option<ConstitutionOf<T>>;
+
+        /// Version of the current constitution. Zero if the constitution was never set.
+        pub CurrentVersion get(fn current_version): ConstitutionVersion;
+
+        /// Content ids of the uploaded documents of all constitution versions.
+        pub ConstitutionContentIds get(fn constitution_content_ids): map hasher(blake2_128_concat)
+            T::ContentId => ();
+    }
+}
+
+decl_module! {
+    /// 'Constitution' substrate module
+    pub struct Module<T: Trait> for enum Call where origin: T::Origin {
+        /// Predefined errors
+        type Error = Error<T>;
+
+        /// Emits an event. Default substrate implementation.
+        fn deposit_event() = default;
+
+        /// Exports const - max allowed constitution text length.
+        const ConstitutionTextMaxLength: u32 = T::ConstitutionTextMaxLength::get();
+
+        /// Amends the constitution: saves it as the new version. Requires root origin.
+        #[weight = <T as Trait>::WeightInfo::amend_constitution(
+            Module::<T>::text_length(&amendment.content),
+        )]
+        pub fn amend_constitution(origin, amendment: ConstitutionAmendmentOf<T>) {
+            ensure_root(origin)?;
+
+            Self::ensure_amendment_valid(&amendment)?;
+
+            // mutation
+
+            if let ConstitutionContent::DataObject(content_id) = amendment.content {
+                <ConstitutionContentIds<T>>::insert(content_id, ());
+            }
+
+            let version = Self::current_version() + 1;
+            let constitution = Constitution {
+                content_hash: amendment.content_hash,
+                content: amendment.content,
+                amended_at: <system::Module<T>>::block_number(),
+            };
+
+            <ConstitutionByVersion<T>>::insert(version, constitution);
+            CurrentVersion::put(version);
+
+            Self::deposit_event(RawEvent::ConstitutionAmended(version, amendment.content_hash));
+        }
+    }
+}
+
+impl<T: Trait> Module<T> {
+    /// Returns the current constitution if it was set.
+    pub fn constitution() -> Option<ConstitutionOf<T>> {
+        Self::constitution_by_version(Self::current_version())
+    }
+
+    /// Returns all versions of the constitution, starting with the first one.
+    pub fn constitution_history() -> Vec<(ConstitutionVersion, ConstitutionOf<T>)> {
+        (1..=Self::current_version())
+            .filter_map(|version| {
+                Self::constitution_by_version(version).map(|constitution| (version, constitution))
+            })
+            .collect()
+    }
+
+    /// Verifies that the content is the uploaded document of any constitution version.
+    pub fn is_constitution_content(content_id: &T::ContentId) -> bool {
+        <ConstitutionContentIds<T>>::contains_key(content_id)
+    }
+
+    /// Ensures the constitution can be amended: the text is not empty, not too long and matches
+    /// the content hash, the uploaded document exists.
+    pub fn ensure_amendment_valid(amendment: &ConstitutionAmendmentOf<T>) -> DispatchResult {
+        match amendment.content {
+            ConstitutionContent::Text(ref text) => {
+                ensure!(!text.is_empty(), Error::<T>::ConstitutionTextIsEmpty);
+                ensure!(
+                    text.len() as u32 <= T::ConstitutionTextMaxLength::get(),
+                    Error::<T>::ConstitutionTextIsTooLong
+                );
+                ensure!(
+                    T::Hashing::hash(text) == amendment.content_hash,
+                    Error::<T>::ContentHashMismatch
+                );
+            }
+            ConstitutionContent::DataObject(ref content_id) => {
+                ensure!(
+                    T::ContentExistenceValidator::content_exists(content_id),
+                    Error::<T>::ConstitutionContentNotFound
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    // Byte length of the constitution text. Zero for the uploaded document.
+    fn text_length(content: &ConstitutionContent<T::ContentId>) -> u32 {
+        match content {
+            ConstitutionContent::Text(text) => text.len() as u32,
+            ConstitutionContent::DataObject(_) => 0,
+        }
+    }
+}
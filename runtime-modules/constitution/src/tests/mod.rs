@@ -0,0 +1,171 @@
+pub(crate) mod mock;
+
+use frame_support::dispatch::DispatchError;
+use sp_core::H256;
+use sp_runtime::traits::{BlakeTwo256, Hash};
+use system::RawOrigin;
+use system::{EventRecord, Phase};
+
+use crate::*;
+use mock::*;
+
+fn text_amendment(text: &[u8]) -> ConstitutionAmendment<H256, u64> {
+    ConstitutionAmendment {
+        content_hash: BlakeTwo256::hash(text),
+        content: ConstitutionContent::Text(text.to_vec()),
+    }
+}
+
+fn amend_constitution(
+    origin: RawOrigin<u64>,
+    amendment: ConstitutionAmendment<H256, u64>,
+) -> Result<(), DispatchError> {
+    ConstitutionModule::amend_constitution(origin.into(), amendment)
+}
+
+#[test]
+fn amend_constitution_succeeds() {
+    initial_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        assert_eq!(ConstitutionModule::constitution(), None);
+
+        let amendment = text_amendment(b"constitution");
+
+        assert_eq!(
+            amend_constitution(RawOrigin::Root, amendment.clone()),
+            Ok(())
+        );
+
+        assert_eq!(ConstitutionModule::current_version(), 1);
+        assert_eq!(
+            ConstitutionModule::constitution(),
+            Some(Constitution {
+                content_hash: amendment.content_hash,
+                content: amendment.content,
+                amended_at: 1,
+            })
+        );
+
+        assert_eq!(
+            System::events(),
+            vec![EventRecord {
+                phase: Phase::Initialization,
+                event: TestEvent::constitution(RawEvent::ConstitutionAmended(
+                    1,
+                    amendment.content_hash
+                )),
+                topics: vec![],
+            }]
+        );
+    });
+}
+
+#[test]
+fn amend_constitution_keeps_version_history() {
+    initial_test_ext().execute_with(|| {
+        let first_amendment = text_amendment(b"first");
+        let second_amendment = ConstitutionAmendment {
+            content_hash: BlakeTwo256::hash(b"document"),
+            content: ConstitutionContent::DataObject(EXISTING_CONTENT_ID),
+        };
+
+        assert_eq!(
+            amend_constitution(RawOrigin::Root, first_amendment.clone()),
+            Ok(())
+        );
+
+        System::set_block_number(2);
+
+        assert_eq!(
+            amend_constitution(RawOrigin::Root, second_amendment.clone()),
+            Ok(())
+        );
+
+        assert_eq!(ConstitutionModule::current_version(), 2);
+        assert!(ConstitutionModule::is_constitution_content(
+            &EXISTING_CONTENT_ID
+        ));
+        assert_eq!(
+            ConstitutionModule::constitution_history(),
+            vec![
+                (
+                    1,
+                    Constitution {
+                        content_hash: first_amendment.content_hash,
+                        content: first_amendment.content,
+                        amended_at: 0,
+                    }
+                ),
+                (
+                    2,
+                    Constitution {
+                        content_hash: second_amendment.content_hash,
+                        content: second_amendment.content,
+                        amended_at: 2,
+                    }
+                ),
+            ]
+        );
+    });
+}
+
+#[test]
+fn amend_constitution_fails_with_invalid_origin() {
+    initial_test_ext().execute_with(|| {
+        assert_eq!(
+            amend_constitution(RawOrigin::Signed(1), text_amendment(b"constitution")),
+            Err(DispatchError::BadOrigin)
+        );
+
+        assert_eq!(ConstitutionModule::current_version(), 0);
+    });
+}
+
+#[test]
+fn amend_constitution_fails_with_invalid_text() {
+    initial_test_ext().execute_with(|| {
+        assert_eq!(
+            amend_constitution(RawOrigin::Root, text_amendment(b"")),
+            Err(Error::<Test>::ConstitutionTextIsEmpty.into())
+        );
+
+        assert_eq!(
+            amend_constitution(RawOrigin::Root, text_amendment(&[b'x'; 101])),
+            Err(Error::<Test>::ConstitutionTextIsTooLong.into())
+        );
+
+        let invalid_hash_amendment = ConstitutionAmendment {
+            content_hash: BlakeTwo256::hash(b"other"),
+            ..text_amendment(b"constitution")
+        };
+
+        assert_eq!(
+            amend_constitution(RawOrigin::Root, invalid_hash_amendment),
+            Err(Error::<Test>::ContentHashMismatch.into())
+        );
+
+        assert_eq!(ConstitutionModule::current_version(), 0);
+    });
+}
+
+#[test]
+fn amend_constitution_fails_with_missing_document() {
+    initial_test_ext().execute_with(|| {
+        let missing_content_id = EXISTING_CONTENT_ID + 1;
+        let amendment = ConstitutionAmendment {
+            content_hash: BlakeTwo256::hash(b"document"),
+            content: ConstitutionContent::DataObject(missing_content_id),
+        };
+
+        assert_eq!(
+            amend_constitution(RawOrigin::Root, amendment),
+            Err(Error::<Test>::ConstitutionContentNotFound.into())
+        );
+
+        assert_eq!(ConstitutionModule::current_version(), 0);
+        assert!(!ConstitutionModule::is_constitution_content(
+            &missing_content_id
+        ));
+    });
+}
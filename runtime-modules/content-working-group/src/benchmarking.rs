@@ -0,0 +1,493 @@
+//! Content working group module benchmarking.
+
+use super::*;
+use frame_benchmarking::{account, benchmarks};
+
+const SEED: u32 = 0;
+
+// Upper bound for the benchmarked text inputs.
+const MAX_BYTES: u32 = 5_000;
+
+// Upper bound for the benchmarked number of the curators hired at once.
+const MAX_CURATORS: u32 = 100;
+
+// Role and application stake amount of the benchmarked applications.
+const STAKE: u32 = 1_000;
+
+// Relaxes all input constraints, so the whole benchmarked text length range is valid.
+fn set_relaxed_constraints() {
+    let constraint = InputValidationLengthConstraint::new(1, MAX_BYTES as u16 - 1);
+
+    ChannelHandleConstraint::put(constraint);
+    ChannelTitleConstraint::put(constraint);
+    ChannelDescriptionConstraint::put(constraint);
+    ChannelAvatarConstraint::put(constraint);
+    ChannelBannerConstraint::put(constraint);
+    OpeningHumanReadableText::put(constraint);
+    CuratorApplicationHumanReadableText::put(constraint);
+    CuratorExitRationaleText::put(constraint);
+}
+
+fn text(len: u32) -> Vec<u8> {
+    vec![b'x'; len as usize]
+}
+
+// Creates a member with the same root and controller account.
+fn member<T: Trait>(index: u32) -> (T::AccountId, T::MemberId) {
+    let account_id: T::AccountId = account("member", index, SEED);
+    let member_id = <membership::Module<T>>::members_created();
+
+    <membership::Module<T>>::set_screening_authority(
+        system::RawOrigin::Root.into(),
+        account_id.clone(),
+    )
+    .unwrap();
+
+    <membership::Module<T>>::add_screened_member(
+        system::RawOrigin::Signed(account_id.clone()).into(),
+        account_id.clone(),
+        Some(account_id.encode()),
+        None,
+        None,
+    )
+    .unwrap();
+
+    (account_id, member_id)
+}
+
+fn staking_policy<T: Trait>() -> hiring::StakingPolicy<BalanceOf<T>, T::BlockNumber> {
+    hiring::StakingPolicy {
+        amount: BalanceOf::<T>::from(STAKE),
+        amount_mode: hiring::StakingAmountLimitMode::AtLeast,
+        crowded_out_unstaking_period_length: None,
+        review_period_expired_unstaking_period_length: None,
+    }
+}
+
+// Staked openings are the worst case for the whole hiring flow.
+fn opening_policy_commitment<T: Trait>() -> OpeningPolicyCommitment<T::BlockNumber, BalanceOf<T>> {
+    OpeningPolicyCommitment {
+        max_review_period_length: T::BlockNumber::from(1_000u32),
+        application_staking_policy: Some(staking_policy::<T>()),
+        role_staking_policy: Some(staking_policy::<T>()),
+        ..OpeningPolicyCommitment::default()
+    }
+}
+
+fn reward_policy<T: Trait>() -> RewardPolicy<minting::BalanceOf<T>, T::BlockNumber> {
+    RewardPolicy {
+        amount_per_payout: minting::BalanceOf::<T>::from(100u32),
+        next_payment_at_block: <system::Module<T>>::block_number() + T::BlockNumber::from(1_000u32),
+        payout_interval: Some(T::BlockNumber::from(1_000u32)),
+    }
+}
+
+// Sets the lead, returns the lead role account.
+fn set_lead<T: Trait>() -> T::AccountId {
+    let (account_id, member_id) = member::<T>(0);
+
+    Module::<T>::replace_lead(
+        system::RawOrigin::Root.into(),
+        Some((member_id, account_id.clone())),
+    )
+    .unwrap();
+
+    account_id
+}
+
+fn add_opening<T: Trait>(
+    lead_account_id: T::AccountId,
+    activate_at: hiring::ActivateOpeningAt<T::BlockNumber>,
+) -> CuratorOpeningId<T> {
+    let opening_id = NextCuratorOpeningId::<T>::get();
+
+    Module::<T>::add_curator_opening(
+        system::RawOrigin::Signed(lead_account_id).into(),
+        activate_at,
+        opening_policy_commitment::<T>(),
+        text(MAX_BYTES),
+    )
+    .unwrap();
+
+    opening_id
+}
+
+// Applies on the opening with both stakes as a newly created member.
+fn apply<T: Trait>(
+    opening_id: CuratorOpeningId<T>,
+    member_index: u32,
+) -> (T::AccountId, T::MemberId, CuratorApplicationId<T>) {
+    let (account_id, member_id) = member::<T>(member_index);
+    CurrencyOf::<T>::make_free_balance_be(&account_id, BalanceOf::<T>::from(3 * STAKE));
+
+    let application_id = NextCuratorApplicationId::<T>::get();
+
+    Module::<T>::apply_on_curator_opening(
+        system::RawOrigin::Signed(account_id.clone()).into(),
+        member_id,
+        opening_id,
+        account_id.clone(),
+        Some(BalanceOf::<T>::from(STAKE)),
+        Some(BalanceOf::<T>::from(STAKE)),
+        text(MAX_BYTES),
+    )
+    .unwrap();
+
+    (account_id, member_id, application_id)
+}
+
+// Hires the staked and rewarded curator. Returns the curator role account, member id and curator id.
+fn hire_curator<T: Trait>(
+    lead_account_id: T::AccountId,
+) -> (T::AccountId, T::MemberId, CuratorId<T>) {
+    let opening_id = add_opening::<T>(
+        lead_account_id.clone(),
+        hiring::ActivateOpeningAt::CurrentBlock,
+    );
+    let (account_id, member_id, application_id) = apply::<T>(opening_id, 1);
+
+    let mut application_ids = CuratorApplicationIdSet::<T>::new();
+    application_ids.insert(application_id);
+
+    let curator_id = NextCuratorId::<T>::get();
+
+    Module::<T>::begin_curator_applicant_review(
+        system::RawOrigin::Signed(lead_account_id.clone()).into(),
+        opening_id,
+    )
+    .unwrap();
+    Module::<T>::fill_curator_opening(
+        system::RawOrigin::Signed(lead_account_id).into(),
+        opening_id,
+        application_ids,
+        Some(reward_policy::<T>()),
+    )
+    .unwrap();
+
+    (account_id, member_id, curator_id)
+}
+
+// Creates the channel with the longest texts. Returns the owner account and the channel id.
+fn add_channel<T: Trait>() -> (T::AccountId, ChannelId<T>) {
+    let (account_id, member_id) = member::<T>(MAX_CURATORS + 1);
+    let channel_id = NextChannelId::<T>::get();
+
+    ChannelCreationEnabled::put(true);
+
+    Module::<T>::create_channel(
+        system::RawOrigin::Signed(account_id.clone()).into(),
+        member_id,
+        account_id.clone(),
+        ChannelContentType::Video,
+        b"channel".to_vec(),
+        Some(text(MAX_BYTES)),
+        Some(text(MAX_BYTES)),
+        Some(text(MAX_BYTES)),
+        Some(text(MAX_BYTES)),
+        ChannelPublicationStatus::Public,
+    )
+    .unwrap();
+
+    (account_id, channel_id)
+}
+
+benchmarks! {
+    _ { }
+
+    create_channel {
+        let h in 1 .. MAX_BYTES;
+        // The texts length is split evenly between the title, description, avatar and banner.
+        let t in 4 .. 4 * MAX_BYTES;
+
+        set_relaxed_constraints();
+        ChannelCreationEnabled::put(true);
+
+        let (account_id, member_id) = member::<T>(0);
+        let channel_id = NextChannelId::<T>::get();
+    }: _(
+        system::RawOrigin::Signed(account_id.clone()),
+        member_id,
+        account_id,
+        ChannelContentType::Video,
+        text(h),
+        Some(text(t / 4)),
+        Some(text(t / 4)),
+        Some(text(t / 4)),
+        Some(text(t / 4)),
+        ChannelPublicationStatus::Public
+    )
+    verify {
+        assert!(ChannelById::<T>::contains_key(channel_id));
+    }
+
+    transfer_channel_ownership {
+        set_relaxed_constraints();
+        let (account_id, channel_id) = add_channel::<T>();
+        let (new_owner_account_id, new_owner_id) = member::<T>(0);
+    }: _(
+        system::RawOrigin::Signed(account_id),
+        channel_id,
+        new_owner_id,
+        new_owner_account_id
+    )
+    verify {
+        assert_eq!(Module::<T>::channel_by_id(channel_id).owner, new_owner_id);
+    }
+
+    update_channel_as_owner {
+        let h in 1 .. MAX_BYTES;
+        // The texts length is split evenly between the title, description, avatar and banner.
+        let t in 4 .. 4 * MAX_BYTES;
+
+        set_relaxed_constraints();
+        let (account_id, channel_id) = add_channel::<T>();
+    }: _(
+        system::RawOrigin::Signed(account_id),
+        channel_id,
+        Some(text(h)),
+        Some(Some(text(t / 4))),
+        Some(Some(text(t / 4))),
+        Some(Some(text(t / 4))),
+        Some(Some(text(t / 4))),
+        Some(ChannelPublicationStatus::Unlisted)
+    )
+    verify {
+        assert_eq!(Module::<T>::channel_by_id(channel_id).handle, text(h));
+    }
+
+    update_channel_as_curation_actor {
+        set_relaxed_constraints();
+        let lead_account_id = set_lead::<T>();
+        let (_, channel_id) = add_channel::<T>();
+    }: _(
+        system::RawOrigin::Signed(lead_account_id),
+        CurationActor::Lead,
+        channel_id,
+        Some(true),
+        Some(ChannelCurationStatus::Censored)
+    )
+    verify {
+        assert!(Module::<T>::channel_by_id(channel_id).verified);
+    }
+
+    add_curator_opening {
+        let t in 1 .. MAX_BYTES;
+
+        set_relaxed_constraints();
+        let lead_account_id = set_lead::<T>();
+        let opening_id = NextCuratorOpeningId::<T>::get();
+    }: _(
+        system::RawOrigin::Signed(lead_account_id),
+        hiring::ActivateOpeningAt::CurrentBlock,
+        opening_policy_commitment::<T>(),
+        text(t)
+    )
+    verify {
+        assert!(CuratorOpeningById::<T>::contains_key(opening_id));
+    }
+
+    accept_curator_applications {
+        set_relaxed_constraints();
+        let lead_account_id = set_lead::<T>();
+        let activate_at = <system::Module<T>>::block_number() + T::BlockNumber::from(1_000u32);
+        let opening_id = add_opening::<T>(
+            lead_account_id.clone(),
+            hiring::ActivateOpeningAt::ExactBlock(activate_at),
+        );
+    }: _(system::RawOrigin::Signed(lead_account_id), opening_id)
+
+    begin_curator_applicant_review {
+        set_relaxed_constraints();
+        let lead_account_id = set_lead::<T>();
+        let opening_id = add_opening::<T>(
+            lead_account_id.clone(),
+            hiring::ActivateOpeningAt::CurrentBlock,
+        );
+        apply::<T>(opening_id, 1);
+    }: _(system::RawOrigin::Signed(lead_account_id), opening_id)
+
+    fill_curator_opening {
+        let a in 1 .. MAX_CURATORS;
+
+        set_relaxed_constraints();
+        let lead_account_id = set_lead::<T>();
+        let opening_id = add_opening::<T>(
+            lead_account_id.clone(),
+            hiring::ActivateOpeningAt::CurrentBlock,
+        );
+
+        let mut application_ids = CuratorApplicationIdSet::<T>::new();
+        for i in 1 .. a + 1 {
+            let (_, _, application_id) = apply::<T>(opening_id, i);
+            application_ids.insert(application_id);
+        }
+
+        Module::<T>::begin_curator_applicant_review(
+            system::RawOrigin::Signed(lead_account_id.clone()).into(),
+            opening_id,
+        )?;
+
+        let next_curator_id = NextCuratorId::<T>::get();
+    }: _(
+        system::RawOrigin::Signed(lead_account_id),
+        opening_id,
+        application_ids,
+        Some(reward_policy::<T>())
+    )
+    verify {
+        assert_eq!(NextCuratorId::<T>::get(), next_curator_id + CuratorId::<T>::from(a));
+    }
+
+    withdraw_curator_application {
+        set_relaxed_constraints();
+        let lead_account_id = set_lead::<T>();
+        let opening_id = add_opening::<T>(
+            lead_account_id,
+            hiring::ActivateOpeningAt::CurrentBlock,
+        );
+        let (account_id, _, application_id) = apply::<T>(opening_id, 1);
+    }: _(system::RawOrigin::Signed(account_id), application_id)
+
+    terminate_curator_application {
+        set_relaxed_constraints();
+        let lead_account_id = set_lead::<T>();
+        let opening_id = add_opening::<T>(
+            lead_account_id.clone(),
+            hiring::ActivateOpeningAt::CurrentBlock,
+        );
+        let (_, _, application_id) = apply::<T>(opening_id, 1);
+    }: _(system::RawOrigin::Signed(lead_account_id), application_id)
+
+    apply_on_curator_opening {
+        let t in 1 .. MAX_BYTES;
+
+        set_relaxed_constraints();
+        let lead_account_id = set_lead::<T>();
+        let opening_id = add_opening::<T>(
+            lead_account_id,
+            hiring::ActivateOpeningAt::CurrentBlock,
+        );
+
+        let (account_id, member_id) = member::<T>(1);
+        CurrencyOf::<T>::make_free_balance_be(&account_id, BalanceOf::<T>::from(3 * STAKE));
+
+        let application_id = NextCuratorApplicationId::<T>::get();
+    }: _(
+        system::RawOrigin::Signed(account_id.clone()),
+        member_id,
+        opening_id,
+        account_id,
+        Some(BalanceOf::<T>::from(STAKE)),
+        Some(BalanceOf::<T>::from(STAKE)),
+        text(t)
+    )
+    verify {
+        assert!(CuratorApplicationById::<T>::contains_key(application_id));
+    }
+
+    update_curator_role_account {
+        set_relaxed_constraints();
+        let lead_account_id = set_lead::<T>();
+        let (account_id, member_id, curator_id) = hire_curator::<T>(lead_account_id);
+        let new_role_account: T::AccountId = account("role", 0, SEED);
+    }: _(
+        system::RawOrigin::Signed(account_id),
+        member_id,
+        curator_id,
+        new_role_account.clone()
+    )
+    verify {
+        assert_eq!(Module::<T>::curator_by_id(curator_id).role_account, new_role_account);
+    }
+
+    update_curator_reward_account {
+        set_relaxed_constraints();
+        let lead_account_id = set_lead::<T>();
+        let (account_id, _, curator_id) = hire_curator::<T>(lead_account_id);
+        let new_reward_account: T::AccountId = account("reward", 0, SEED);
+    }: _(system::RawOrigin::Signed(account_id), curator_id, new_reward_account)
+
+    leave_curator_role {
+        let r in 1 .. MAX_BYTES;
+
+        set_relaxed_constraints();
+        let lead_account_id = set_lead::<T>();
+        let (account_id, _, curator_id) = hire_curator::<T>(lead_account_id);
+    }: _(system::RawOrigin::Signed(account_id), curator_id, text(r))
+
+    terminate_curator_role {
+        let r in 1 .. MAX_BYTES;
+
+        set_relaxed_constraints();
+        let lead_account_id = set_lead::<T>();
+        let (_, _, curator_id) = hire_curator::<T>(lead_account_id.clone());
+    }: _(system::RawOrigin::Signed(lead_account_id), curator_id, text(r))
+
+    replace_lead {
+        set_lead::<T>();
+        let (account_id, member_id) = member::<T>(1);
+    }: _(system::RawOrigin::Root, Some((member_id, account_id.clone())))
+    verify {
+        let (_, lead) = Module::<T>::ensure_lead_is_set()?;
+        assert_eq!(lead.role_account, account_id);
+    }
+
+    set_channel_creation_enabled {
+        let lead_account_id = set_lead::<T>();
+        ChannelCreationEnabled::put(true);
+    }: _(system::RawOrigin::Signed(lead_account_id), false)
+    verify {
+        assert!(!Module::<T>::channel_creation_enabled());
+    }
+
+    increase_mint_capacity {
+        let capacity = <minting::Module<T>>::mints(Module::<T>::mint()).capacity();
+        let additional_capacity = minting::BalanceOf::<T>::from(1_000_000u32);
+    }: _(system::RawOrigin::Root, additional_capacity)
+    verify {
+        assert_eq!(
+            <minting::Module<T>>::mints(Module::<T>::mint()).capacity(),
+            capacity + additional_capacity
+        );
+    }
+
+    set_mint_capacity {
+        let new_capacity = minting::BalanceOf::<T>::from(1_000_000u32);
+    }: _(system::RawOrigin::Root, new_capacity)
+    verify {
+        assert_eq!(<minting::Module<T>>::mints(Module::<T>::mint()).capacity(), new_capacity);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::{Test, TestExternalitiesBuilder};
+
+    #[test]
+    fn content_working_group_benchmarks_succeed() {
+        TestExternalitiesBuilder::<Test>::default()
+            .build()
+            .execute_with(|| {
+                assert!(test_benchmark_create_channel::<Test>().is_ok());
+                assert!(test_benchmark_transfer_channel_ownership::<Test>().is_ok());
+                assert!(test_benchmark_update_channel_as_owner::<Test>().is_ok());
+                assert!(test_benchmark_update_channel_as_curation_actor::<Test>().is_ok());
+                assert!(test_benchmark_add_curator_opening::<Test>().is_ok());
+                assert!(test_benchmark_accept_curator_applications::<Test>().is_ok());
+                assert!(test_benchmark_begin_curator_applicant_review::<Test>().is_ok());
+                assert!(test_benchmark_fill_curator_opening::<Test>().is_ok());
+                assert!(test_benchmark_withdraw_curator_application::<Test>().is_ok());
+                assert!(test_benchmark_terminate_curator_application::<Test>().is_ok());
+                assert!(test_benchmark_apply_on_curator_opening::<Test>().is_ok());
+                assert!(test_benchmark_update_curator_role_account::<Test>().is_ok());
+                assert!(test_benchmark_update_curator_reward_account::<Test>().is_ok());
+                assert!(test_benchmark_leave_curator_role::<Test>().is_ok());
+                assert!(test_benchmark_terminate_curator_role::<Test>().is_ok());
+                assert!(test_benchmark_replace_lead::<Test>().is_ok());
+                assert!(test_benchmark_set_channel_creation_enabled::<Test>().is_ok());
+                assert!(test_benchmark_increase_mint_capacity::<Test>().is_ok());
+                assert!(test_benchmark_set_mint_capacity::<Test>().is_ok());
+            });
+    }
+}
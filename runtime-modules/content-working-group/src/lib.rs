@@ -14,15 +14,20 @@ mod tests;
 #[cfg(test)]
 mod mock;
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
 pub mod genesis;
 
 #[cfg(feature = "std")]
 use serde::{Deserialize, Serialize};
 
 use codec::{Decode, Encode};
+use frame_support::storage::IterableStorageMap;
 use frame_support::traits::{Currency, ExistenceRequirement, WithdrawReasons};
+use frame_support::weights::Weight;
 use frame_support::{decl_event, decl_module, decl_storage, ensure};
-use sp_arithmetic::traits::{One, Zero};
+use sp_arithmetic::traits::{One, UniqueSaturatedInto, Zero};
 use sp_std::borrow::ToOwned;
 use sp_std::collections::{btree_map::BTreeMap, btree_set::BTreeSet};
 use sp_std::vec;
@@ -31,6 +36,94 @@ use system::{ensure_root, ensure_signed};
 
 use common::constraints::InputValidationLengthConstraint;
 
+/// Weight information for the content working group extrinsics. Length parameters are the byte
+/// lengths of the text inputs.
+pub trait WeightInfo {
+    /// `h` - channel handle length, `t` - total length of the channel title, description, avatar
+    /// and banner.
+    fn create_channel(h: u32, t: u32) -> Weight;
+    fn transfer_channel_ownership() -> Weight;
+    fn update_channel_as_owner(h: u32, t: u32) -> Weight;
+    fn update_channel_as_curation_actor() -> Weight;
+    fn add_curator_opening(t: u32) -> Weight;
+    fn accept_curator_applications() -> Weight;
+    fn begin_curator_applicant_review() -> Weight;
+    /// `a` - number of the successful applications.
+    fn fill_curator_opening(a: u32) -> Weight;
+    fn withdraw_curator_application() -> Weight;
+    fn terminate_curator_application() -> Weight;
+    fn apply_on_curator_opening(t: u32) -> Weight;
+    fn update_curator_role_account() -> Weight;
+    fn update_curator_reward_account() -> Weight;
+    fn leave_curator_role(r: u32) -> Weight;
+    fn terminate_curator_role(r: u32) -> Weight;
+    fn replace_lead() -> Weight;
+    fn set_channel_creation_enabled() -> Weight;
+    fn increase_mint_capacity() -> Weight;
+    fn set_mint_capacity() -> Weight;
+}
+
+/// Default weights, used by the tests.
+impl WeightInfo for () {
+    fn create_channel(_h: u32, _t: u32) -> Weight {
+        10_000_000
+    }
+    fn transfer_channel_ownership() -> Weight {
+        10_000_000
+    }
+    fn update_channel_as_owner(_h: u32, _t: u32) -> Weight {
+        10_000_000
+    }
+    fn update_channel_as_curation_actor() -> Weight {
+        10_000_000
+    }
+    fn add_curator_opening(_t: u32) -> Weight {
+        10_000_000
+    }
+    fn accept_curator_applications() -> Weight {
+        10_000_000
+    }
+    fn begin_curator_applicant_review() -> Weight {
+        10_000_000
+    }
+    fn fill_curator_opening(_a: u32) -> Weight {
+        10_000_000
+    }
+    fn withdraw_curator_application() -> Weight {
+        10_000_000
+    }
+    fn terminate_curator_application() -> Weight {
+        10_000_000
+    }
+    fn apply_on_curator_opening(_t: u32) -> Weight {
+        10_000_000
+    }
+    fn update_curator_role_account() -> Weight {
+        10_000_000
+    }
+    fn update_curator_reward_account() -> Weight {
+        10_000_000
+    }
+    fn leave_curator_role(_r: u32) -> Weight {
+        10_000_000
+    }
+    fn terminate_curator_role(_r: u32) -> Weight {
+        10_000_000
+    }
+    fn replace_lead() -> Weight {
+        10_000_000
+    }
+    fn set_channel_creation_enabled() -> Weight {
+        10_000_000
+    }
+    fn increase_mint_capacity() -> Weight {
+        10_000_000
+    }
+    fn set_mint_capacity() -> Weight {
+        10_000_000
+    }
+}
+
 /// Module configuration trait for this Substrate module.
 pub trait Trait:
     system::Trait
@@ -43,6 +136,9 @@ pub trait Trait:
 {
     /// The event type.
     type Event: From<Event<Self>> + Into<<Self as system::Trait>::Event>;
+
+    /// Weight information for the extrinsics of this module.
+    type WeightInfo: WeightInfo;
 }
 
 /// Type constraint for identifer used for actors.
@@ -90,6 +186,14 @@ pub type CuratorApplicationIdToCuratorIdMap<T> = BTreeMap<CuratorApplicationId<T
 // Workaround for BTreeSet type
 pub type CuratorApplicationIdSet<T> = BTreeSet<CuratorApplicationId<T>>;
 
+// Curator opening of the previous runtime versions, used by the storage migration.
+type LegacyCuratorOpeningOf<T> = LegacyCuratorOpening<
+    CuratorOpeningId<T>,
+    <T as system::Trait>::BlockNumber,
+    BalanceOf<T>,
+    CuratorApplicationId<T>,
+>;
+
 //TODO: Convert errors to the Substrate decl_error! macro.
 /// Result with string error message. This exists for backward compatibility purpose.
 pub type DispatchResult = Result<(), &'static str>;
@@ -138,6 +242,8 @@ pub static MSG_CURATOR_APPLICATION_TEXT_TOO_SHORT: &str = "Curator application t
 pub static MSG_SIGNER_IS_NOT_CURATOR_ROLE_ACCOUNT: &str = "Signer is not curator role account";
 pub static MSG_UNSTAKER_DOES_NOT_EXIST: &str = "Unstaker does not exist";
 pub static MSG_CURATOR_HAS_NO_REWARD: &str = "Curator has no recurring reward";
+pub static MSG_CURATORS_MIGRATED: &str =
+    "Curators were moved to the content directory working group";
 pub static MSG_CURATOR_NOT_CONTROLLED_BY_MEMBER: &str = "Curator not controlled by member";
 pub static MSG_INSUFFICIENT_BALANCE_TO_COVER_STAKE: &str = "Insuffieicnt balance to cover stake";
 
@@ -159,6 +265,8 @@ pub static MSG_BEGIN_CURATOR_APPLICANT_REVIEW_OPENING_DOES_NOT_EXIST: &str =
     "Opening does not exist";
 pub static MSG_BEGIN_CURATOR_APPLICANT_REVIEW_OPENING_OPENING_IS_NOT_WAITING_TO_BEGIN: &str =
     "Opening Is Not in Waiting";
+pub static MSG_BEGIN_CURATOR_APPLICANT_REVIEW_APPLICATION_DEADLINE_NOT_REACHED: &str =
+    "Review cannot begin before the application deadline";
 
 // Errors for `fill_curator_opening`
 pub static MSG_FULL_CURATOR_OPENING_OPENING_DOES_NOT_EXIST: &str = "OpeningDoesNotExist";
@@ -182,6 +290,10 @@ pub static MSG_FULL_CURATOR_OPENING_UNSUCCESSFUL_ROLE_STAKE_UNSTAKING_PERIOD_RED
     "Role stake unstaking period for failed applicants redundant";
 pub static MSG_FULL_CURATOR_OPENING_APPLICATION_DOES_NOT_EXIST: &str = "ApplicationDoesNotExist";
 pub static MSG_FULL_CURATOR_OPENING_APPLICATION_NOT_ACTIVE: &str = "ApplicationNotInActiveStage";
+pub static MSG_FULL_CURATOR_OPENING_OPENING_NOT_IN_LAST_REVIEW_ROUND: &str =
+    "Opening is not in the last review round";
+pub static MSG_FULL_CURATOR_OPENING_MAX_HIRES_EXCEEDED: &str =
+    "Number of the successful applications exceeds the opening max hires";
 pub static MSG_FILL_CURATOR_OPENING_INVALID_NEXT_PAYMENT_BLOCK: &str =
     "Reward policy has invalid next payment block number";
 pub static MSG_FILL_CURATOR_OPENING_MINT_DOES_NOT_EXIST: &str = "Working group mint does not exist";
@@ -222,6 +334,9 @@ pub static MSG_ADD_CURATOR_OPENING_NEW_APPLICATION_WAS_CROWDED_OUT: &str =
     "NewApplicationWasCrowdedOut";
 pub static MSG_ADD_CURATOR_OPENING_ZERO_MAX_APPLICANT_COUNT: &str =
     "Application rationing has zero max active applicants";
+pub static MSG_ADD_CURATOR_OPENING_APPLICATION_DEADLINE_NOT_AFTER_ACTIVATION: &str =
+    "Application deadline is not after the opening activation";
+pub static MSG_ADD_CURATOR_OPENING_ZERO_MAX_HIRES: &str = "Opening max hires is zero";
 
 // Errors for `apply_on_curator_opening`
 pub static MSG_APPLY_ON_CURATOR_OPENING_UNSIGNED_ORIGIN: &str = "Unsigned origin";
@@ -742,6 +857,74 @@ pub struct OpeningPolicyCommitment<BlockNumber, Balance> {
 
     /// When a curator exists: ..
     pub exit_curator_role_stake_unstaking_period: Option<BlockNumber>,
+
+    /// Block after which no applications are accepted, the review period begins automatically
+    /// at this block.
+    pub application_deadline: Option<BlockNumber>,
+
+    /// Maximum number of curators hired when filling the opening.
+    pub max_hires: Option<u32>,
+}
+
+/// 'OpeningPolicyCommitment' of the previous runtime versions without the opening limits.
+/// Used by the storage migration.
+#[derive(Encode, Decode)]
+pub(crate) struct LegacyOpeningPolicyCommitment<BlockNumber, Balance> {
+    pub(crate) application_rationing_policy: Option<hiring::ApplicationRationingPolicy>,
+    pub(crate) max_review_period_length: BlockNumber,
+    pub(crate) application_staking_policy: Option<hiring::StakingPolicy<Balance, BlockNumber>>,
+    pub(crate) role_staking_policy: Option<hiring::StakingPolicy<Balance, BlockNumber>>,
+    pub(crate) role_slashing_terms: SlashingTerms,
+    pub(crate) fill_opening_successful_applicant_application_stake_unstaking_period:
+        Option<BlockNumber>,
+    pub(crate) fill_opening_failed_applicant_application_stake_unstaking_period:
+        Option<BlockNumber>,
+    pub(crate) fill_opening_failed_applicant_role_stake_unstaking_period: Option<BlockNumber>,
+    pub(crate) terminate_curator_application_stake_unstaking_period: Option<BlockNumber>,
+    pub(crate) terminate_curator_role_stake_unstaking_period: Option<BlockNumber>,
+    pub(crate) exit_curator_role_application_stake_unstaking_period: Option<BlockNumber>,
+    pub(crate) exit_curator_role_stake_unstaking_period: Option<BlockNumber>,
+}
+
+impl<BlockNumber, Balance> LegacyOpeningPolicyCommitment<BlockNumber, Balance> {
+    // Converts the legacy commitment, leaving the opening limits unset.
+    pub(crate) fn migrate(self) -> OpeningPolicyCommitment<BlockNumber, Balance> {
+        OpeningPolicyCommitment {
+            application_rationing_policy: self.application_rationing_policy,
+            max_review_period_length: self.max_review_period_length,
+            application_staking_policy: self.application_staking_policy,
+            role_staking_policy: self.role_staking_policy,
+            role_slashing_terms: self.role_slashing_terms,
+            fill_opening_successful_applicant_application_stake_unstaking_period: self
+                .fill_opening_successful_applicant_application_stake_unstaking_period,
+            fill_opening_failed_applicant_application_stake_unstaking_period: self
+                .fill_opening_failed_applicant_application_stake_unstaking_period,
+            fill_opening_failed_applicant_role_stake_unstaking_period: self
+                .fill_opening_failed_applicant_role_stake_unstaking_period,
+            terminate_curator_application_stake_unstaking_period: self
+                .terminate_curator_application_stake_unstaking_period,
+            terminate_curator_role_stake_unstaking_period: self
+                .terminate_curator_role_stake_unstaking_period,
+            exit_curator_role_application_stake_unstaking_period: self
+                .exit_curator_role_application_stake_unstaking_period,
+            exit_curator_role_stake_unstaking_period: self.exit_curator_role_stake_unstaking_period,
+            application_deadline: None,
+            max_hires: None,
+        }
+    }
+}
+
+/// 'CuratorOpening' of the previous runtime versions. Used by the storage migration.
+#[derive(Encode, Decode)]
+pub(crate) struct LegacyCuratorOpening<
+    OpeningId,
+    BlockNumber,
+    Balance,
+    CuratorApplicationId: core::cmp::Ord,
+> {
+    pub(crate) opening_id: OpeningId,
+    pub(crate) curator_applications: BTreeSet<CuratorApplicationId>,
+    pub(crate) policy_commitment: LegacyOpeningPolicyCommitment<BlockNumber, Balance>,
 }
 
 /// Represents a possible unstaker in working group.
@@ -823,6 +1006,10 @@ impl sp_std::convert::From<WrappedError<hiring::AddOpeningError>> for &str {
                     MSG_ADD_CURATOR_OPENING_APPLICATION_STAKE_CANNOT_BE_ZERO
                 }
             },
+            hiring::AddOpeningError::ApplicationDeadlineNotAfterActivation => {
+                MSG_ADD_CURATOR_OPENING_APPLICATION_DEADLINE_NOT_AFTER_ACTIVATION
+            }
+            hiring::AddOpeningError::ZeroMaxHires => MSG_ADD_CURATOR_OPENING_ZERO_MAX_HIRES,
         }
     }
 }
@@ -836,6 +1023,9 @@ impl sp_std::convert::From<WrappedError<hiring::BeginReviewError>> for &str {
             hiring::BeginReviewError::OpeningNotInAcceptingApplicationsStage => {
                 MSG_BEGIN_CURATOR_APPLICANT_REVIEW_OPENING_OPENING_IS_NOT_WAITING_TO_BEGIN
             }
+            hiring::BeginReviewError::ApplicationDeadlineNotReached => {
+                MSG_BEGIN_CURATOR_APPLICANT_REVIEW_APPLICATION_DEADLINE_NOT_REACHED
+            }
         }
     }
 }
@@ -874,6 +1064,8 @@ impl<T: hiring::Trait> sp_std::convert::From<WrappedError<hiring::FillOpeningErr
             hiring::FillOpeningError::<T>::ApplicationDoesNotExist(_application_id) => MSG_FULL_CURATOR_OPENING_APPLICATION_DOES_NOT_EXIST,
             hiring::FillOpeningError::<T>::ApplicationNotInActiveStage(_application_id) => MSG_FULL_CURATOR_OPENING_APPLICATION_NOT_ACTIVE,
             hiring::FillOpeningError::<T>::ApplicationForWrongOpening(_application_id) => MSG_FILL_CURATOR_OPENING_APPLICATION_FOR_WRONG_OPENING,
+            hiring::FillOpeningError::<T>::OpeningNotInLastReviewRound => MSG_FULL_CURATOR_OPENING_OPENING_NOT_IN_LAST_REVIEW_ROUND,
+            hiring::FillOpeningError::<T>::MaxHiresExceeded => MSG_FULL_CURATOR_OPENING_MAX_HIRES_EXCEEDED,
         }
     }
 }
@@ -996,6 +1188,10 @@ decl_storage! {
         /// Next identifier value for new curator application.
         pub NextCuratorApplicationId get(fn next_curator_application_id) config(): CuratorApplicationId<T>;
 
+        /// Maps hiring application identifier to the curator application.
+        pub CuratorApplicationIdByHiringApplicationId get(fn curator_application_id_by_hiring_application_id): map hasher(blake2_128_concat)
+            T::ApplicationId => CuratorApplicationId<T>;
+
         /// Maps identifier to corresponding channel.
         pub ChannelById get(fn channel_by_id) config(): map hasher(blake2_128_concat)
             ChannelId<T> => Channel<T::MemberId, T::AccountId, T::BlockNumber, PrincipalId<T>>;
@@ -1040,6 +1236,17 @@ decl_storage! {
         pub OpeningHumanReadableText get(fn opening_human_readable_text) config(): InputValidationLengthConstraint;
         pub CuratorApplicationHumanReadableText get(fn curator_application_human_readable_text) config(): InputValidationLengthConstraint;
         pub CuratorExitRationaleText get(fn curator_exit_rationale_text) config(): InputValidationLengthConstraint;
+
+        /// Whether the curator openings were migrated to the opening policy commitment with the
+        /// opening limits.
+        pub OpeningPolicyCommitmentMigrated get(fn opening_policy_commitment_migrated): bool;
+
+        /// Whether the curator applications were indexed by the hiring application identifiers.
+        pub CuratorApplicationIdByHiringApplicationIdMigrated get(fn curator_application_id_by_hiring_application_id_migrated): bool;
+
+        /// Whether the active curators and the lead were moved to the content directory working group,
+        /// keeping the curator identifiers as the worker identifiers.
+        pub CuratorsMigrated get(fn curators_migrated): bool;
     }
     add_extra_genesis {
         config(mint_capacity): minting::BalanceOf<T>;
@@ -1048,6 +1255,15 @@ decl_storage! {
             let mint_id = <minting::Module<T>>::add_mint(config.mint_capacity, None)
                 .expect("Failed to create a mint for the content working group");
             Mint::<T>::put(mint_id);
+
+            // new chains start with the current opening format
+            OpeningPolicyCommitmentMigrated::put(true);
+
+            // index the genesis curator applications
+            for (curator_application_id, curator_application) in config.curator_application_by_id.iter() {
+                CuratorApplicationIdByHiringApplicationId::<T>::insert(curator_application.application_id, curator_application_id);
+            }
+            CuratorApplicationIdByHiringApplicationIdMigrated::put(true);
         });
     }
 }
@@ -1063,6 +1279,7 @@ decl_event! {
         MintBalanceOf = minting::BalanceOf<T>,
         <T as system::Trait>::AccountId,
         <T as minting::Trait>::MintId,
+        ApplicationDeactivationCause = hiring::ApplicationDeactivationCause,
     {
         ChannelCreated(ChannelId),
         ChannelOwnershipTransferred(ChannelId),
@@ -1078,6 +1295,7 @@ decl_event! {
         CuratorUnstaking(CuratorId),
         CuratorApplicationTerminated(CuratorApplicationId),
         CuratorApplicationWithdrawn(CuratorApplicationId),
+        CuratorApplicationDeactivated(CuratorApplicationId, ApplicationDeactivationCause),
         CuratorRoleAccountUpdated(CuratorId, AccountId),
         CuratorRewardAccountUpdated(CuratorId, AccountId),
         ChannelUpdatedByCurationActor(ChannelId),
@@ -1097,7 +1315,15 @@ decl_module! {
          */
 
         /// Create a new channel.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::create_channel(
+            handle.len() as u32,
+            channel_texts_len(&[
+                title.as_ref(),
+                description.as_ref(),
+                avatar.as_ref(),
+                banner.as_ref(),
+            ]),
+        )]
         pub fn create_channel(
             origin,
             owner: T::MemberId,
@@ -1184,7 +1410,7 @@ decl_module! {
         /// Notice that working group participants cannot do this.
         /// Notice that censored or unlisted channel may still be transferred.
         /// Notice that transfers are unilateral, so new owner cannot block. This may be problematic: https://github.com/Joystream/substrate-runtime-joystream/issues/95
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::transfer_channel_ownership()]
         pub fn transfer_channel_ownership(origin, channel_id: ChannelId<T>, new_owner: T::MemberId, new_role_account: T::AccountId) {
 
             // Ensure channel owner has signed
@@ -1209,7 +1435,15 @@ decl_module! {
         }
 
         /// Channel owner updates some channel properties
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::update_channel_as_owner(
+            new_handle.as_ref().map_or(0, |handle| handle.len() as u32),
+            channel_texts_len(&[
+                new_title.as_ref().and_then(Option::as_ref),
+                new_description.as_ref().and_then(Option::as_ref),
+                new_avatar.as_ref().and_then(Option::as_ref),
+                new_banner.as_ref().and_then(Option::as_ref),
+            ]),
+        )]
         pub fn update_channel_as_owner(
             origin,
             channel_id: ChannelId<T>,
@@ -1267,7 +1501,7 @@ decl_module! {
         }
 
         /// Update channel as a curation actor
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::update_channel_as_curation_actor()]
         pub fn update_channel_as_curation_actor(
             origin,
             curation_actor: CurationActor<CuratorId<T>>,
@@ -1297,9 +1531,12 @@ decl_module! {
         }
 
         /// Add an opening for a curator role.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::add_curator_opening(human_readable_text.len() as u32)]
         pub fn add_curator_opening(origin, activate_at: hiring::ActivateOpeningAt<T::BlockNumber>, commitment: OpeningPolicyCommitment<T::BlockNumber, BalanceOf<T>>, human_readable_text: Vec<u8>)  {
 
+            // Curators are hired by the content directory working group once migrated
+            Self::ensure_curators_not_migrated()?;
+
             // Ensure lead is set and is origin signer
             Self::ensure_origin_is_set_lead(origin)?;
 
@@ -1319,6 +1556,8 @@ decl_module! {
                     commitment.application_rationing_policy,
                     commitment.application_staking_policy,
                     commitment.role_staking_policy,
+                    commitment.application_deadline,
+                    commitment.max_hires,
                     human_readable_text,
                 ))?;
 
@@ -1345,9 +1584,12 @@ decl_module! {
         }
 
         /// Begin accepting curator applications to an opening that is active.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::accept_curator_applications()]
         pub fn accept_curator_applications(origin, curator_opening_id: CuratorOpeningId<T>)  {
 
+            // Curators are hired by the content directory working group once migrated
+            Self::ensure_curators_not_migrated()?;
+
             // Ensure lead is set and is origin signer
             Self::ensure_origin_is_set_lead(origin)?;
 
@@ -1372,9 +1614,12 @@ decl_module! {
         }
 
         /// Begin reviewing, and therefore not accepting new applications.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::begin_curator_applicant_review()]
         pub fn begin_curator_applicant_review(origin, curator_opening_id: CuratorOpeningId<T>) {
 
+            // Curators are hired by the content directory working group once migrated
+            Self::ensure_curators_not_migrated()?;
+
             // Ensure lead is set and is origin signer
             let (_lead_id, _lead) = Self::ensure_origin_is_set_lead(origin)?;
 
@@ -1399,13 +1644,18 @@ decl_module! {
         }
 
         /// Fill opening for curator
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::fill_curator_opening(
+            successful_curator_application_ids.len() as u32,
+        )]
         pub fn fill_curator_opening(
             origin,
             curator_opening_id: CuratorOpeningId<T>,
             successful_curator_application_ids: CuratorApplicationIdSet<T>,
             reward_policy: Option<RewardPolicy<minting::BalanceOf<T>, T::BlockNumber>>
         ) {
+            // Curators are hired by the content directory working group once migrated
+            Self::ensure_curators_not_migrated()?;
+
             // Ensure lead is set and is origin signer
             let (lead_id, _lead) = Self::ensure_origin_is_set_lead(origin)?;
 
@@ -1559,7 +1809,7 @@ decl_module! {
 
         }
 
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::withdraw_curator_application()]
         pub fn withdraw_curator_application(
             origin,
             curator_application_id: CuratorApplicationId<T>
@@ -1596,7 +1846,7 @@ decl_module! {
         }
 
         /// Lead terminate curator application
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::terminate_curator_application()]
         pub fn terminate_curator_application(
             origin,
             curator_application_id: CuratorApplicationId<T>
@@ -1627,7 +1877,7 @@ decl_module! {
         }
 
         /// Apply on a curator opening.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::apply_on_curator_opening(human_readable_text.len() as u32)]
         pub fn apply_on_curator_opening(
             origin,
             member_id: T::MemberId,
@@ -1637,6 +1887,9 @@ decl_module! {
             opt_application_stake_balance: Option<BalanceOf<T>>,
             human_readable_text: Vec<u8>
         ) {
+            // Curators are hired by the content directory working group once migrated
+            Self::ensure_curators_not_migrated()?;
+
             // Ensure origin which will server as the source account for staked funds is signed
             let source_account = ensure_signed(origin)?;
 
@@ -1703,6 +1956,9 @@ decl_module! {
             // Store application
             CuratorApplicationById::<T>::insert(new_curator_application_id, curator_application);
 
+            // Index application by the hiring application to handle its deactivation
+            CuratorApplicationIdByHiringApplicationId::<T>::insert(application_id, new_curator_application_id);
+
             // Update next curator application identifier value
             NextCuratorApplicationId::<T>::mutate(|id| *id += <CuratorApplicationId<T> as One>::one());
 
@@ -1716,7 +1972,7 @@ decl_module! {
         }
 
         /// An active curator can update the associated role account.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::update_curator_role_account()]
         pub fn update_curator_role_account(
             origin,
             member_id: T::MemberId,
@@ -1744,7 +2000,7 @@ decl_module! {
 
         /// An active curator can update the reward account associated
         /// with a set reward relationship.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::update_curator_reward_account()]
         pub fn update_curator_reward_account(
             origin,
             curator_id: CuratorId<T>,
@@ -1776,7 +2032,7 @@ decl_module! {
         }
 
         /// An active curator leaves role
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::leave_curator_role(rationale_text.len() as u32)]
         pub fn leave_curator_role(
             origin,
             curator_id: CuratorId<T>,
@@ -1798,7 +2054,7 @@ decl_module! {
         }
 
         /// Lead can terminate and active curator
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::terminate_curator_role(rationale_text.len() as u32)]
         pub fn terminate_curator_role(
             origin,
             curator_id: CuratorId<T>,
@@ -1830,11 +2086,14 @@ decl_module! {
         /// If a value is provided for new_lead it will then set that new lead.
         /// It is responsibility of the caller to ensure the new lead can be set
         /// to avoid the lead role being vacant at the end of the call.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::replace_lead()]
         pub fn replace_lead(origin, new_lead: Option<(T::MemberId, T::AccountId)>) {
             // Ensure root is origin
             ensure_root(origin)?;
 
+            // The lead is set in the content directory working group once migrated
+            Self::ensure_curators_not_migrated()?;
+
             // Unset current lead first
             if Self::ensure_lead_is_set().is_ok() {
                 Self::unset_lead()?;
@@ -1847,7 +2106,7 @@ decl_module! {
         }
 
         /// Add an opening for a curator role.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::set_channel_creation_enabled()]
         pub fn set_channel_creation_enabled(origin, enabled: bool)  {
 
             // Ensure lead is set and is origin signer
@@ -1869,7 +2128,8 @@ decl_module! {
         /// both increase and decrease capacity. Although when considering that it may be executed
         /// by a proposal, given the temporal delay in approving a proposal, it might be more suitable
         /// than set_mint_capacity?
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::increase_mint_capacity()
+            .saturating_add(<recurringrewards::Module<T>>::pay_arrears_weight())]
         pub fn increase_mint_capacity(
             origin,
             additional_capacity: minting::BalanceOf<T>
@@ -1881,13 +2141,17 @@ decl_module! {
             let new_capacity = mint.capacity() + additional_capacity;
             <minting::Module<T>>::set_mint_capacity(mint_id, new_capacity).map_err(<&str>::from)?;
 
+            // Rewards owed to the curators are paid first.
+            <recurringrewards::Module<T>>::pay_arrears(mint_id);
+
             Self::deposit_event(RawEvent::MintCapacityIncreased(
                 mint_id, additional_capacity, new_capacity
             ));
         }
 
         /// Sets the capacity of the current active mint
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::set_mint_capacity()
+            .saturating_add(<recurringrewards::Module<T>>::pay_arrears_weight())]
         pub fn set_mint_capacity(
             origin,
             new_capacity: minting::BalanceOf<T>
@@ -1908,6 +2172,9 @@ decl_module! {
                 <minting::Module<T>>::set_mint_capacity(mint_id, new_capacity).map_err(<&str>::from)?;
 
                 if new_capacity > current_capacity {
+                    // Rewards owed to the curators are paid first.
+                    <recurringrewards::Module<T>>::pay_arrears(mint_id);
+
                     Self::deposit_event(RawEvent::MintCapacityIncreased(
                         mint_id, new_capacity - current_capacity, new_capacity
                     ));
@@ -1963,6 +2230,101 @@ impl<T: Trait> versioned_store_permissions::CredentialChecker<T> for Module<T> {
 }
 
 impl<T: Trait> Module<T> {
+    /// Moves the opening policy commitments of the curator openings created by the previous
+    /// runtime versions to the current format. Does nothing once migrated.
+    pub fn migrate_opening_policy_commitments() -> Weight {
+        if Self::opening_policy_commitment_migrated() {
+            return T::DbWeight::get().reads(1);
+        }
+
+        // Curator openings are never removed.
+        let openings_count: Weight = Self::next_curator_opening_id().unique_saturated_into();
+
+        <CuratorOpeningById<T>>::translate(|_, opening: LegacyCuratorOpeningOf<T>| {
+            Some(CuratorOpening {
+                opening_id: opening.opening_id,
+                curator_applications: opening.curator_applications,
+                policy_commitment: opening.policy_commitment.migrate(),
+            })
+        });
+
+        OpeningPolicyCommitmentMigrated::put(true);
+
+        T::DbWeight::get().reads_writes(1 + openings_count, 1 + openings_count)
+    }
+
+    /// Indexes the curator applications created by the previous runtime versions by the hiring
+    /// application identifiers. Does nothing once migrated.
+    pub fn migrate_curator_application_ids_by_hiring_application_id() -> Weight {
+        if Self::curator_application_id_by_hiring_application_id_migrated() {
+            return T::DbWeight::get().reads(1);
+        }
+
+        let mut applications_count: Weight = 0;
+
+        for (curator_application_id, curator_application) in <CuratorApplicationById<T>>::iter() {
+            <CuratorApplicationIdByHiringApplicationId<T>>::insert(
+                curator_application.application_id,
+                curator_application_id,
+            );
+
+            applications_count += 1;
+        }
+
+        CuratorApplicationIdByHiringApplicationIdMigrated::put(true);
+
+        T::DbWeight::get().reads_writes(1 + applications_count, 1 + applications_count)
+    }
+
+    /// Handles the curator application deactivation in the hiring module. Returns false if the
+    /// hiring application is not a curator application.
+    ///
+    /// Applications of the hired curators are kept, as the curator induction refers to them.
+    /// Other applications are removed once no stakes are left, since the stakes are refunded to
+    /// the applicant member.
+    pub fn application_deactivated(
+        application_id: &T::ApplicationId,
+        cause: hiring::ApplicationDeactivationCause,
+    ) -> bool {
+        if !<CuratorApplicationIdByHiringApplicationId<T>>::contains_key(application_id) {
+            return false;
+        }
+
+        let curator_application_id =
+            <CuratorApplicationIdByHiringApplicationId<T>>::get(application_id);
+
+        let (is_deactivation_initiated, no_stakes_left) =
+            match <hiring::ApplicationById<T>>::get(application_id).stage {
+                hiring::ApplicationStage::Unstaking { .. } => (true, false),
+                // Staked applications are notified once more on the unstaking completion.
+                hiring::ApplicationStage::Inactive {
+                    deactivation_initiated,
+                    deactivated,
+                    ..
+                } => (deactivation_initiated == deactivated, true),
+                hiring::ApplicationStage::Active => (false, false),
+            };
+
+        if is_deactivation_initiated {
+            Self::deposit_event(RawEvent::CuratorApplicationDeactivated(
+                curator_application_id,
+                cause,
+            ));
+        }
+
+        if cause != hiring::ApplicationDeactivationCause::Hired && no_stakes_left {
+            let curator_application = <CuratorApplicationById<T>>::take(curator_application_id);
+
+            <CuratorOpeningById<T>>::mutate(curator_application.curator_opening_id, |opening| {
+                opening.curator_applications.remove(&curator_application_id);
+            });
+
+            <CuratorApplicationIdByHiringApplicationId<T>>::remove(application_id);
+        }
+
+        true
+    }
+
     /// Introduce a lead when one is not currently set.
     fn set_lead(member: T::MemberId, role_account: T::AccountId) -> DispatchResult {
         // Ensure there is no current lead
@@ -2181,6 +2543,12 @@ impl<T: Trait> Module<T> {
         }
     }
 
+    fn ensure_curators_not_migrated() -> DispatchResult {
+        ensure!(!CuratorsMigrated::get(), MSG_CURATORS_MIGRATED);
+
+        Ok(())
+    }
+
     fn ensure_origin_is_set_lead(
         origin: T::Origin,
     ) -> Result<
@@ -2677,3 +3045,11 @@ impl<T: Trait> Module<T> {
         Self::deposit_event(event);
     }
 }
+
+// Returns the total byte length of the optional channel texts.
+fn channel_texts_len(texts: &[Option<&Vec<u8>>]) -> u32 {
+    texts
+        .iter()
+        .map(|text| text.map_or(0, |text| text.len() as u32))
+        .sum()
+}
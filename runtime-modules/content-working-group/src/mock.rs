@@ -50,6 +50,7 @@ impl_outer_event! {
         versioned_store<T>,
         membership<T>,
         balances<T>,
+        recurringrewards<T>,
         system<T>,
         lib<T>,
     }
@@ -65,6 +66,7 @@ pub type RawLibTestEvent = RawEvent<
     minting::BalanceOf<Test>,
     <Test as system::Trait>::AccountId,
     <Test as minting::Trait>::MintId,
+    hiring::ApplicationDeactivationCause,
 >;
 
 pub fn get_last_event_or_panic() -> RawLibTestEvent {
@@ -121,14 +123,22 @@ impl GovernanceCurrency for Test {
 }
 
 type TestMintId = u64;
+parameter_types! {
+    pub const MintSpendingEpochLength: u64 = 100;
+}
+
 impl minting::Trait for Test {
     type Currency = Balances;
     type MintId = TestMintId;
+    type SpendingEpochLength = MintSpendingEpochLength;
+    type MintCapacityIncreased = ();
+    type WeightInfo = ();
 }
 
 type TestRecipientId = u64;
 type TestRewardRelationshipId = u64;
 impl recurringrewards::Trait for Test {
+    type Event = TestEvent;
     type PayoutStatusHandler = ();
     type RecipientId = TestRecipientId;
     type RewardRelationshipId = TestRewardRelationshipId;
@@ -162,6 +172,7 @@ impl versioned_store_permissions::Trait for Test {
     type Credential = TestPrincipalId;
     type CredentialChecker = ();
     type CreateClassPermissionsChecker = ();
+    type WeightInfo = ();
 }
 
 type TestMemberId = u64;
@@ -171,10 +182,12 @@ impl membership::Trait for Test {
     type PaidTermId = u64;
     type SubscriptionId = u64;
     type ActorId = u64;
+    type WeightInfo = ();
 }
 
 impl Trait for Test {
     type Event = TestEvent;
+    type WeightInfo = ();
 }
 
 pub struct TestExternalitiesBuilder<T: Trait> {
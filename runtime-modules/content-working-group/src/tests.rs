@@ -438,6 +438,62 @@ fn add_curator_opening_success() {
         });
 }
 
+#[test]
+fn add_curator_opening_fails_with_application_deadline_not_after_activation() {
+    TestExternalitiesBuilder::<Test>::default()
+        .build()
+        .execute_with(|| {
+            add_member_and_set_as_lead();
+
+            let expected_curator_opening_id = NextCuratorOpeningId::<Test>::get();
+
+            let policy_commitment = OpeningPolicyCommitment {
+                application_deadline: Some(34),
+                ..get_baseline_opening_policy()
+            };
+
+            assert_err!(
+                ContentWorkingGroup::add_curator_opening(
+                    Origin::signed(LEAD_ROLE_ACCOUNT),
+                    hiring::ActivateOpeningAt::ExactBlock(34),
+                    policy_commitment,
+                    generate_valid_length_buffer(&OpeningHumanReadableText::get())
+                ),
+                MSG_ADD_CURATOR_OPENING_APPLICATION_DEADLINE_NOT_AFTER_ACTIVATION
+            );
+
+            assert!(!crate::CuratorOpeningById::<Test>::contains_key(
+                expected_curator_opening_id
+            ));
+        });
+}
+
+#[test]
+fn add_curator_opening_fails_with_curators_migrated() {
+    TestExternalitiesBuilder::<Test>::default()
+        .build()
+        .execute_with(|| {
+            add_member_and_set_as_lead();
+
+            CuratorsMigrated::put(true);
+
+            assert_err!(
+                ContentWorkingGroup::add_curator_opening(
+                    Origin::signed(LEAD_ROLE_ACCOUNT),
+                    hiring::ActivateOpeningAt::ExactBlock(34),
+                    get_baseline_opening_policy(),
+                    generate_valid_length_buffer(&OpeningHumanReadableText::get())
+                ),
+                MSG_CURATORS_MIGRATED
+            );
+
+            assert_err!(
+                ContentWorkingGroup::replace_lead(RawOrigin::Root.into(), None),
+                MSG_CURATORS_MIGRATED
+            );
+        });
+}
+
 #[test]
 fn accept_curator_applications_success() {
     TestExternalitiesBuilder::<Test>::default()
@@ -648,6 +704,70 @@ fn withdraw_curator_application_success() {
         });
 }
 
+#[test]
+fn application_deactivated_keeps_staked_curator_application() {
+    TestExternalitiesBuilder::<Test>::default()
+        .build()
+        .execute_with(|| {
+            /*
+             * Setup
+             */
+
+            let normal_opening_constructed = setup_normal_accepting_opening();
+
+            let curator_applicant_root_and_controller_account = 333;
+            let curator_applicant_role_account = 11111;
+            let human_readable_text =
+                generate_valid_length_buffer(&CuratorApplicationHumanReadableText::get());
+
+            let result = add_member_and_apply_on_opening(
+                normal_opening_constructed.curator_opening_id,
+                curator_applicant_root_and_controller_account,
+                to_vec("CuratorWannabe"),
+                curator_applicant_role_account,
+                human_readable_text,
+            );
+
+            let application_id =
+                ContentWorkingGroup::curator_application_by_id(result.curator_application_id)
+                    .application_id;
+
+            assert_ok!(ContentWorkingGroup::withdraw_curator_application(
+                Origin::signed(curator_applicant_role_account),
+                result.curator_application_id
+            ));
+
+            /*
+             * Test
+             */
+
+            assert!(ContentWorkingGroup::application_deactivated(
+                &application_id,
+                hiring::ApplicationDeactivationCause::External
+            ));
+
+            // Event was triggered
+            assert_eq!(
+                get_last_event_or_panic(),
+                crate::RawEvent::CuratorApplicationDeactivated(
+                    result.curator_application_id,
+                    hiring::ApplicationDeactivationCause::External
+                )
+            );
+
+            // Application is kept to refund the stakes
+            assert!(CuratorApplicationById::<Test>::contains_key(
+                result.curator_application_id
+            ));
+
+            // Unknown hiring application is not handled
+            assert!(!ContentWorkingGroup::application_deactivated(
+                &(application_id + 1),
+                hiring::ApplicationDeactivationCause::External
+            ));
+        });
+}
+
 #[test]
 fn terminate_curator_application_success() {
     TestExternalitiesBuilder::<Test>::default()
@@ -1431,6 +1551,8 @@ pub fn get_baseline_opening_policy(
         terminate_curator_role_stake_unstaking_period: None,
         exit_curator_role_application_stake_unstaking_period: None,
         exit_curator_role_stake_unstaking_period: None,
+        application_deadline: None,
+        max_hires: None,
     }
 }
 
@@ -0,0 +1,302 @@
+//! Forum module benchmarking.
+
+use super::*;
+use frame_benchmarking::{account, benchmarks};
+use system::RawOrigin;
+
+const SEED: u32 = 0;
+
+// Upper bound for the benchmarked text inputs.
+const MAX_BYTES: u32 = 5_000;
+
+// Relaxes all input constraints, so the whole benchmarked text length range is valid.
+fn set_relaxed_constraints() {
+    let constraint = InputValidationLengthConstraint::new(1, MAX_BYTES as u16 - 1);
+
+    CategoryTitleConstraint::put(constraint);
+    CategoryDescriptionConstraint::put(constraint);
+    ThreadTitleConstraint::put(constraint);
+    PostTextConstraint::put(constraint);
+    ThreadModerationRationaleConstraint::put(constraint);
+    PostModerationRationaleConstraint::put(constraint);
+}
+
+fn forum_sudo<T: Trait>() -> T::AccountId {
+    let sudo: T::AccountId = account("sudo", 0, SEED);
+    <ForumSudo<T>>::put(sudo.clone());
+
+    sudo
+}
+
+fn forum_user<T: Trait>() -> T::AccountId {
+    let user: T::AccountId = account("user", 0, SEED);
+    T::MembershipRegistry::add_forum_user(&user);
+
+    user
+}
+
+// Creates a forum working group worker moderating the root category of the thread. It is the
+// worst case for the moderator check, as the whole category tree path is traversed.
+fn root_category_moderator<T: Trait>(thread_id: T::ThreadId) -> (T::AccountId, T::ModeratorId) {
+    let moderator: T::AccountId = account("moderator", 0, SEED);
+    let moderator_id = T::ModeratorRegistry::add_forum_worker(&moderator, false);
+
+    let category_id = <ThreadById<T>>::get(thread_id).category_id;
+    let root_category = <Module<T>>::build_category_tree_path(category_id)
+        .pop()
+        .expect("Category tree path is not empty");
+    <CategoryModerators<T>>::insert(root_category.id, moderator_id, true);
+
+    (moderator, moderator_id)
+}
+
+fn text(len: u32) -> Vec<u8> {
+    vec![b'x'; len as usize]
+}
+
+// Creates the worst case category: the leaf of the deepest valid category tree path.
+fn create_leaf_category<T: Trait>(sudo: &T::AccountId) -> CategoryId {
+    let root_category_id = NextCategoryId::get();
+    <Module<T>>::create_category(
+        RawOrigin::Signed(sudo.clone()).into(),
+        None,
+        text(MAX_BYTES),
+        text(MAX_BYTES),
+    )
+    .unwrap();
+
+    let leaf_category_id = NextCategoryId::get();
+    <Module<T>>::create_category(
+        RawOrigin::Signed(sudo.clone()).into(),
+        Some(root_category_id),
+        text(MAX_BYTES),
+        text(MAX_BYTES),
+    )
+    .unwrap();
+
+    leaf_category_id
+}
+
+fn create_thread<T: Trait>(sudo: &T::AccountId, user: &T::AccountId) -> T::ThreadId {
+    let category_id = create_leaf_category::<T>(sudo);
+    let thread_id = <NextThreadId<T>>::get();
+
+    <Module<T>>::create_thread(
+        RawOrigin::Signed(user.clone()).into(),
+        category_id,
+        text(MAX_BYTES),
+        text(MAX_BYTES),
+    )
+    .unwrap();
+
+    thread_id
+}
+
+benchmarks! {
+    _ { }
+
+    set_forum_sudo {
+        let new_sudo: T::AccountId = account("sudo", 1, SEED);
+    }: _(RawOrigin::Root, Some(new_sudo.clone()))
+    verify {
+        assert_eq!(<ForumSudo<T>>::get(), Some(new_sudo));
+    }
+
+    create_category {
+        let t in 1 .. MAX_BYTES;
+        let d in 1 .. MAX_BYTES;
+
+        set_relaxed_constraints();
+        let sudo = forum_sudo::<T>();
+
+        let parent_category_id = NextCategoryId::get();
+        <Module<T>>::create_category(
+            RawOrigin::Signed(sudo.clone()).into(),
+            None,
+            text(MAX_BYTES),
+            text(MAX_BYTES),
+        )?;
+
+        let category_id = NextCategoryId::get();
+    }: _(RawOrigin::Signed(sudo), Some(parent_category_id), text(t), text(d))
+    verify {
+        assert!(<CategoryById<T>>::contains_key(category_id));
+    }
+
+    update_category {
+        set_relaxed_constraints();
+        let sudo = forum_sudo::<T>();
+        let category_id = create_leaf_category::<T>(&sudo);
+    }: _(RawOrigin::Signed(sudo), category_id, Some(true), Some(true))
+    verify {
+        assert!(<CategoryById<T>>::get(category_id).archived);
+    }
+
+    create_thread {
+        let t in 1 .. MAX_BYTES;
+        let x in 1 .. MAX_BYTES;
+
+        set_relaxed_constraints();
+        let sudo = forum_sudo::<T>();
+        let user = forum_user::<T>();
+        let category_id = create_leaf_category::<T>(&sudo);
+        let thread_id = <NextThreadId<T>>::get();
+    }: _(RawOrigin::Signed(user), category_id, text(t), text(x))
+    verify {
+        assert!(<ThreadById<T>>::contains_key(thread_id));
+    }
+
+    moderate_thread {
+        let r in 1 .. MAX_BYTES;
+
+        set_relaxed_constraints();
+        let sudo = forum_sudo::<T>();
+        let user = forum_user::<T>();
+        let thread_id = create_thread::<T>(&sudo, &user);
+        let (moderator, moderator_id) = root_category_moderator::<T>(thread_id);
+    }: _(RawOrigin::Signed(moderator), Some(moderator_id), thread_id, text(r))
+    verify {
+        assert!(<ThreadById<T>>::get(thread_id).moderation.is_some());
+    }
+
+    add_post {
+        let x in 1 .. MAX_BYTES;
+
+        set_relaxed_constraints();
+        let sudo = forum_sudo::<T>();
+        let user = forum_user::<T>();
+        let thread_id = create_thread::<T>(&sudo, &user);
+        let post_id = <NextPostId<T>>::get();
+    }: _(RawOrigin::Signed(user), thread_id, text(x))
+    verify {
+        assert!(<PostById<T>>::contains_key(post_id));
+    }
+
+    edit_post_text {
+        let x in 1 .. MAX_BYTES;
+
+        set_relaxed_constraints();
+        let sudo = forum_sudo::<T>();
+        let user = forum_user::<T>();
+        let thread_id = create_thread::<T>(&sudo, &user);
+        let post_id = <NextPostId<T>>::get();
+        <Module<T>>::add_post(RawOrigin::Signed(user.clone()).into(), thread_id, text(MAX_BYTES))?;
+    }: _(RawOrigin::Signed(user), post_id, text(x))
+    verify {
+        assert_eq!(<PostById<T>>::get(post_id).text_change_history.len(), 1);
+    }
+
+    moderate_post {
+        let r in 1 .. MAX_BYTES;
+
+        set_relaxed_constraints();
+        let sudo = forum_sudo::<T>();
+        let user = forum_user::<T>();
+        let thread_id = create_thread::<T>(&sudo, &user);
+        let post_id = <NextPostId<T>>::get();
+        <Module<T>>::add_post(RawOrigin::Signed(user).into(), thread_id, text(MAX_BYTES))?;
+        let (moderator, moderator_id) = root_category_moderator::<T>(thread_id);
+    }: _(RawOrigin::Signed(moderator), Some(moderator_id), post_id, text(r))
+    verify {
+        assert!(<PostById<T>>::get(post_id).moderation.is_some());
+    }
+
+    set_category_moderator {
+        set_relaxed_constraints();
+        let sudo = forum_sudo::<T>();
+        let category_id = create_leaf_category::<T>(&sudo);
+
+        let lead: T::AccountId = account("lead", 0, SEED);
+        T::ModeratorRegistry::add_forum_worker(&lead, true);
+
+        let moderator: T::AccountId = account("moderator", 0, SEED);
+        let moderator_id = T::ModeratorRegistry::add_forum_worker(&moderator, false);
+    }: _(RawOrigin::Signed(lead), category_id, moderator_id, true)
+    verify {
+        assert!(<CategoryModerators<T>>::get(category_id, moderator_id));
+    }
+
+    set_thread_lock_status {
+        set_relaxed_constraints();
+        let sudo = forum_sudo::<T>();
+        let user = forum_user::<T>();
+        let thread_id = create_thread::<T>(&sudo, &user);
+        let (moderator, moderator_id) = root_category_moderator::<T>(thread_id);
+    }: _(RawOrigin::Signed(moderator), Some(moderator_id), thread_id, true)
+    verify {
+        assert!(<LockedThreads<T>>::get(thread_id));
+    }
+
+    set_thread_pin_status {
+        set_relaxed_constraints();
+        let sudo = forum_sudo::<T>();
+        let user = forum_user::<T>();
+        let thread_id = create_thread::<T>(&sudo, &user);
+        let category_id = <ThreadById<T>>::get(thread_id).category_id;
+
+        // Fill the pinned threads up to the limit.
+        for _ in 1 .. MAX_PINNED_THREADS_PER_CATEGORY {
+            let pinned_thread_id = <NextThreadId<T>>::get();
+            <Module<T>>::create_thread(
+                RawOrigin::Signed(user.clone()).into(),
+                category_id,
+                text(MAX_BYTES),
+                text(MAX_BYTES),
+            )?;
+            <Module<T>>::set_thread_pin_status(
+                RawOrigin::Signed(sudo.clone()).into(),
+                None,
+                pinned_thread_id,
+                true,
+            )?;
+        }
+
+        let (moderator, moderator_id) = root_category_moderator::<T>(thread_id);
+    }: _(RawOrigin::Signed(moderator), Some(moderator_id), thread_id, true)
+    verify {
+        assert!(<PinnedThreadIds<T>>::get(category_id).contains(&thread_id));
+    }
+
+    react_to_post {
+        set_relaxed_constraints();
+        let sudo = forum_sudo::<T>();
+        let user = forum_user::<T>();
+        let thread_id = create_thread::<T>(&sudo, &user);
+        let post_id = <NextPostId<T>>::get();
+        <Module<T>>::add_post(RawOrigin::Signed(user.clone()).into(), thread_id, text(MAX_BYTES))?;
+
+        // Replacing a previous reaction is the worst case.
+        <Module<T>>::react_to_post(
+            RawOrigin::Signed(user.clone()).into(),
+            post_id,
+            Some(PostReaction::Like),
+        )?;
+    }: _(RawOrigin::Signed(user.clone()), post_id, Some(PostReaction::Dislike))
+    verify {
+        assert_eq!(<PostReactionByAccount<T>>::get(post_id, user), Some(PostReaction::Dislike));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::{build_test_externalities, default_genesis_config, Runtime};
+
+    #[test]
+    fn forum_benchmarks_succeed() {
+        build_test_externalities(default_genesis_config()).execute_with(|| {
+            assert!(test_benchmark_set_forum_sudo::<Runtime>().is_ok());
+            assert!(test_benchmark_create_category::<Runtime>().is_ok());
+            assert!(test_benchmark_update_category::<Runtime>().is_ok());
+            assert!(test_benchmark_create_thread::<Runtime>().is_ok());
+            assert!(test_benchmark_moderate_thread::<Runtime>().is_ok());
+            assert!(test_benchmark_add_post::<Runtime>().is_ok());
+            assert!(test_benchmark_edit_post_text::<Runtime>().is_ok());
+            assert!(test_benchmark_moderate_post::<Runtime>().is_ok());
+            assert!(test_benchmark_set_category_moderator::<Runtime>().is_ok());
+            assert!(test_benchmark_set_thread_lock_status::<Runtime>().is_ok());
+            assert!(test_benchmark_set_thread_pin_status::<Runtime>().is_ok());
+            assert!(test_benchmark_react_to_post::<Runtime>().is_ok());
+        });
+    }
+}
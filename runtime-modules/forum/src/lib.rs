@@ -14,13 +14,18 @@ use serde::{Deserialize, Serialize};
 pub type DispatchResult = Result<(), &'static str>;
 
 use codec::{Codec, Decode, Encode};
-use frame_support::{decl_event, decl_module, decl_storage, ensure, Parameter};
+use frame_support::weights::Weight;
+use frame_support::{
+    decl_event, decl_module, decl_storage, ensure, IterableStorageDoubleMap, Parameter,
+};
 use sp_arithmetic::traits::{BaseArithmetic, One};
 use sp_runtime::traits::{MaybeSerialize, Member};
 use sp_std::borrow::ToOwned;
 use sp_std::vec;
 use sp_std::vec::Vec;
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
 mod mock;
 mod tests;
 
@@ -34,6 +39,9 @@ use common::BlockAndTime;
 /// The depth of a root category is 0.
 const MAX_CATEGORY_DEPTH: u16 = 3;
 
+/// The greatest number of pinned threads in a category.
+const MAX_PINNED_THREADS_PER_CATEGORY: usize = 5;
+
 /// Error messages for dispatchables
 const ERROR_FORUM_SUDO_NOT_SET: &str = "Forum sudo not set.";
 const ERROR_ORIGIN_NOT_FORUM_SUDO: &str = "Origin not forum sudo.";
@@ -63,6 +71,14 @@ const ERROR_POST_MODERATION_RATIONALE_TOO_LONG: &str = "Post moderation rational
 const ERROR_CATEGORY_NOT_BEING_UPDATED: &str = "Category not being updated.";
 const ERROR_CATEGORY_CANNOT_BE_UNARCHIVED_WHEN_DELETED: &str =
     "Category cannot be unarchived when deleted.";
+const ERROR_ORIGIN_NOT_FORUM_MODERATOR: &str = "Origin not forum sudo or category moderator.";
+const ERROR_ORIGIN_NOT_FORUM_LEAD: &str = "Origin not forum working group lead.";
+const ERROR_NOT_FORUM_WORKER: &str = "Worker is not an active forum working group worker.";
+const ERROR_THREAD_LOCKED: &str = "Thread is locked.";
+const ERROR_THREAD_LOCK_STATUS_NOT_CHANGED: &str = "Thread lock status not changed.";
+const ERROR_THREAD_PIN_STATUS_NOT_CHANGED: &str = "Thread pin status not changed.";
+const ERROR_MAX_PINNED_THREADS_EXCEEDED: &str =
+    "Maximum number of pinned threads in category exceeded.";
 
 use system::{ensure_root, ensure_signed};
 
@@ -79,6 +95,25 @@ pub struct ForumUser<AccountId> {
 /// Represents a regsitry of `ForumUser` instances.
 pub trait ForumUserRegistry<AccountId> {
     fn get_forum_user(id: &AccountId) -> Option<ForumUser<AccountId>>;
+
+    /// Registers the account as a forum user. Used to prepare the benchmarks state.
+    #[cfg(feature = "runtime-benchmarks")]
+    fn add_forum_user(id: &AccountId);
+}
+
+/// Represents the forum working group, whose active workers can be assigned as
+/// category moderators by the group lead.
+pub trait ForumModeratorRegistry<AccountId, ModeratorId> {
+    /// Role account of the active forum working group worker, `None` if the worker is not active.
+    fn forum_worker_account(id: &ModeratorId) -> Option<AccountId>;
+
+    /// Whether the account is the role account of the forum working group lead.
+    fn is_forum_lead(id: &AccountId) -> bool;
+
+    /// Registers the account as a forum worker, and as the lead if `lead` is set.
+    /// Returns the worker id. Used to prepare the benchmarks state.
+    #[cfg(feature = "runtime-benchmarks")]
+    fn add_forum_worker(id: &AccountId, lead: bool) -> ModeratorId;
 }
 
 /// Represents a moderation outcome applied to a post or a thread.
@@ -95,6 +130,23 @@ pub struct ModerationAction<BlockNumber, Moment, AccountId> {
     rationale: Vec<u8>,
 }
 
+/// Represents a reaction of a forum user to a post.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize, Debug))]
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum PostReaction {
+    /// Approval of the post.
+    Like,
+
+    /// Disapproval of the post.
+    Dislike,
+
+    /// The post is funny.
+    Laugh,
+
+    /// The post is insightful.
+    Insightful,
+}
+
 /// Represents a revision of the text of a Post
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize, Debug))]
 #[derive(Encode, Decode, Default, Clone, PartialEq, Eq)]
@@ -261,11 +313,77 @@ impl<BlockNumber, Moment, AccountId> Category<BlockNumber, Moment, AccountId> {
 type CategoryTreePath<BlockNumber, Moment, AccountId> =
     Vec<Category<BlockNumber, Moment, AccountId>>;
 
+/// Weight information for the forum extrinsics. Length parameters are the byte lengths of
+/// the text inputs.
+pub trait WeightInfo {
+    fn set_forum_sudo() -> Weight;
+    fn create_category(t: u32, d: u32) -> Weight;
+    fn update_category() -> Weight;
+    fn create_thread(t: u32, x: u32) -> Weight;
+    fn moderate_thread(r: u32) -> Weight;
+    fn add_post(x: u32) -> Weight;
+    fn edit_post_text(x: u32) -> Weight;
+    fn moderate_post(r: u32) -> Weight;
+    fn set_category_moderator() -> Weight;
+    fn set_thread_lock_status() -> Weight;
+    fn set_thread_pin_status() -> Weight;
+    fn react_to_post() -> Weight;
+}
+
+/// Default weights, used by the tests.
+impl WeightInfo for () {
+    fn set_forum_sudo() -> Weight {
+        10_000_000
+    }
+    fn create_category(_t: u32, _d: u32) -> Weight {
+        10_000_000
+    }
+    fn update_category() -> Weight {
+        10_000_000
+    }
+    fn create_thread(_t: u32, _x: u32) -> Weight {
+        10_000_000
+    }
+    fn moderate_thread(_r: u32) -> Weight {
+        10_000_000
+    }
+    fn add_post(_x: u32) -> Weight {
+        10_000_000
+    }
+    fn edit_post_text(_x: u32) -> Weight {
+        10_000_000
+    }
+    fn moderate_post(_r: u32) -> Weight {
+        10_000_000
+    }
+    fn set_category_moderator() -> Weight {
+        10_000_000
+    }
+    fn set_thread_lock_status() -> Weight {
+        10_000_000
+    }
+    fn set_thread_pin_status() -> Weight {
+        10_000_000
+    }
+    fn react_to_post() -> Weight {
+        10_000_000
+    }
+}
+
 pub trait Trait: system::Trait + pallet_timestamp::Trait + Sized {
     type Event: From<Event<Self>> + Into<<Self as system::Trait>::Event>;
 
     type MembershipRegistry: ForumUserRegistry<Self::AccountId>;
 
+    /// Forum working group, whose workers may act as category moderators.
+    type ModeratorRegistry: ForumModeratorRegistry<Self::AccountId, Self::ModeratorId>;
+
+    /// Forum working group worker id, identifies the category moderator.
+    type ModeratorId: Parameter + Member + Codec + Default + Copy + MaybeSerialize + PartialEq;
+
+    /// Weight information for the extrinsics of this module.
+    type WeightInfo: WeightInfo;
+
     /// Thread Id type
     type ThreadId: Parameter
         + Member
@@ -314,6 +432,26 @@ decl_storage! {
         /// Account of forum sudo.
         pub ForumSudo get(fn forum_sudo) config(): Option<T::AccountId>;
 
+        /// Forum working group workers assigned as moderators of a category.
+        /// A moderator of a category may also moderate in all of its subcategories.
+        pub CategoryModerators get(fn category_moderators): double_map
+            hasher(blake2_128_concat) CategoryId, hasher(blake2_128_concat) T::ModeratorId => bool;
+
+        /// Threads which stay visible, but no longer accept new posts.
+        pub LockedThreads get(fn locked_threads): map hasher(blake2_128_concat) T::ThreadId => bool;
+
+        /// Threads pinned to the top of a category, in pinning order.
+        pub PinnedThreadIds get(fn pinned_thread_ids): map hasher(blake2_128_concat)
+            CategoryId => Vec<T::ThreadId>;
+
+        /// Reaction of a forum user to a post.
+        pub PostReactionByAccount get(fn post_reaction_by_account): double_map
+            hasher(blake2_128_concat) T::PostId, hasher(blake2_128_concat) T::AccountId => Option<PostReaction>;
+
+        /// Number of forum users who reacted to a post with the given reaction.
+        pub PostReactionCount get(fn post_reaction_count): double_map
+            hasher(blake2_128_concat) T::PostId, hasher(twox_64_concat) PostReaction => u32;
+
         /// Input constraints
         /// These are all forward looking, that is they are enforced on all
         /// future calls.
@@ -332,6 +470,7 @@ decl_event!(
         <T as system::Trait>::AccountId,
         <T as Trait>::ThreadId,
         <T as Trait>::PostId,
+        <T as Trait>::ModeratorId,
     {
         /// A category was introduced
         CategoryCreated(CategoryId),
@@ -359,6 +498,22 @@ decl_event!(
 
         /// Given account was set as forum sudo.
         ForumSudoSet(Option<AccountId>, Option<AccountId>),
+
+        /// Given forum working group worker was assigned as (or removed from) the moderators
+        /// of the category. The third argument reflects the new moderator status of the worker.
+        CategoryModeratorUpdated(CategoryId, ModeratorId, bool),
+
+        /// Thread with given id was locked or unlocked.
+        /// The second argument reflects the new lock status of the thread.
+        ThreadLockStatusUpdated(ThreadId, bool),
+
+        /// Thread with given id was pinned or unpinned in its category.
+        /// The second argument reflects the new pin status of the thread.
+        ThreadPinStatusUpdated(ThreadId, bool),
+
+        /// Given account reacted to the post with given id.
+        /// The third argument is the new reaction, none if the reaction was removed.
+        PostReactionUpdated(PostId, AccountId, Option<PostReaction>),
     }
 );
 
@@ -368,7 +523,7 @@ decl_module! {
         fn deposit_event() = default;
 
         /// Set forum sudo.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = T::WeightInfo::set_forum_sudo()]
         fn set_forum_sudo(origin, new_forum_sudo: Option<T::AccountId>) -> DispatchResult {
             ensure_root(origin)?;
 
@@ -394,7 +549,7 @@ decl_module! {
         }
 
         /// Add a new category.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = T::WeightInfo::create_category(title.len() as u32, description.len() as u32)]
         fn create_category(origin, parent: Option<CategoryId>, title: Vec<u8>, description: Vec<u8>) -> DispatchResult {
 
             // Check that its a valid signature
@@ -474,7 +629,7 @@ decl_module! {
         }
 
         /// Update category
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = T::WeightInfo::update_category()]
         fn update_category(origin, category_id: CategoryId, new_archival_status: Option<bool>, new_deletion_status: Option<bool>) -> DispatchResult {
 
             // Check that its a valid signature
@@ -538,7 +693,7 @@ decl_module! {
         }
 
         /// Create new thread in category
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = T::WeightInfo::create_thread(title.len() as u32, text.len() as u32)]
         fn create_thread(origin, category_id: CategoryId, title: Vec<u8>, text: Vec<u8>) -> DispatchResult {
 
             /*
@@ -581,19 +736,25 @@ decl_module! {
             Ok(())
         }
 
-        /// Moderate thread
-        #[weight = 10_000_000] // TODO: adjust weight
-        fn moderate_thread(origin, thread_id: T::ThreadId, rationale: Vec<u8>) -> DispatchResult {
+        /// Moderate thread. The `moderator_id` is the forum working group worker id of the
+        /// category moderator, `None` for the forum sudo.
+        #[weight = T::WeightInfo::moderate_thread(rationale.len() as u32)]
+        fn moderate_thread(
+            origin,
+            moderator_id: Option<T::ModeratorId>,
+            thread_id: T::ThreadId,
+            rationale: Vec<u8>
+        ) -> DispatchResult {
 
             // Check that its a valid signature
             let who = ensure_signed(origin)?;
 
-            // Signed by forum SUDO
-            Self::ensure_is_forum_sudo(&who)?;
-
             // Get thread
             let mut thread = Self::ensure_thread_exists(thread_id)?;
 
+            // Signed by forum SUDO or a moderator of the thread category
+            Self::ensure_can_moderate_category(&who, &moderator_id, thread.category_id)?;
+
             // Thread is not already moderated
             ensure!(thread.moderation.is_none(), ERROR_THREAD_ALREADY_MODERATED);
 
@@ -621,6 +782,11 @@ decl_module! {
 
             <ThreadById<T>>::insert(thread_id, thread.clone());
 
+            // Moderated threads are no longer pinned
+            <PinnedThreadIds<T>>::mutate(thread.category_id, |thread_ids| {
+                thread_ids.retain(|id| *id != thread_id)
+            });
+
             // Update moderation/umoderation count of corresponding category
             <CategoryById<T>>::mutate(thread.category_id, |category| {
                 category.num_direct_unmoderated_threads -= 1;
@@ -634,7 +800,7 @@ decl_module! {
         }
 
         /// Edit post text
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = T::WeightInfo::add_post(text.len() as u32)]
         fn add_post(origin, thread_id: T::ThreadId, text: Vec<u8>) -> DispatchResult {
 
             /*
@@ -653,6 +819,9 @@ decl_module! {
             // Make sure thread exists and is mutable
             let thread = Self::ensure_thread_is_mutable(thread_id)?;
 
+            // and accepts new posts
+            ensure!(!<LockedThreads<T>>::get(thread_id), ERROR_THREAD_LOCKED);
+
             // Get path from parent to root of category tree.
             let category_tree_path = Self::ensure_valid_category_and_build_category_tree_path(thread.category_id)?;
 
@@ -672,7 +841,7 @@ decl_module! {
         }
 
         /// Edit post text
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = T::WeightInfo::edit_post_text(new_text.len() as u32)]
         fn edit_post_text(origin, post_id: T::PostId, new_text: Vec<u8>) -> DispatchResult {
 
             /* Edit spec.
@@ -719,19 +888,26 @@ decl_module! {
             Ok(())
         }
 
-        /// Moderate post
-        #[weight = 10_000_000] // TODO: adjust weight
-        fn moderate_post(origin, post_id: T::PostId, rationale: Vec<u8>) -> DispatchResult {
+        /// Moderate post. The `moderator_id` is the forum working group worker id of the
+        /// category moderator, `None` for the forum sudo.
+        #[weight = T::WeightInfo::moderate_post(rationale.len() as u32)]
+        fn moderate_post(
+            origin,
+            moderator_id: Option<T::ModeratorId>,
+            post_id: T::PostId,
+            rationale: Vec<u8>
+        ) -> DispatchResult {
 
             // Check that its a valid signature
             let who = ensure_signed(origin)?;
 
-            // Signed by forum SUDO
-            Self::ensure_is_forum_sudo(&who)?;
-
             // Make sure post exists and is mutable
             let post = Self::ensure_post_is_mutable(post_id)?;
 
+            // Signed by forum SUDO or a moderator of the post category
+            let thread = <ThreadById<T>>::get(post.thread_id);
+            Self::ensure_can_moderate_category(&who, &moderator_id, thread.category_id)?;
+
             Self::ensure_post_moderation_rationale_is_valid(&rationale)?;
 
             /*
@@ -761,6 +937,175 @@ decl_module! {
             Ok(())
         }
 
+        /// Assign a forum working group worker as a moderator of the category, or remove
+        /// the assignment. Can only be called by the forum working group lead.
+        #[weight = T::WeightInfo::set_category_moderator()]
+        fn set_category_moderator(
+            origin,
+            category_id: CategoryId,
+            moderator_id: T::ModeratorId,
+            is_moderator: bool
+        ) -> DispatchResult {
+
+            // Check that its a valid signature
+            let who = ensure_signed(origin)?;
+
+            // Signed by the forum working group lead
+            ensure!(T::ModeratorRegistry::is_forum_lead(&who), ERROR_ORIGIN_NOT_FORUM_LEAD);
+
+            // Category exists
+            ensure!(<CategoryById<T>>::contains_key(category_id), ERROR_CATEGORY_DOES_NOT_EXIST);
+
+            // Only active workers can become moderators, the departed workers can be removed
+            if is_moderator {
+                ensure!(
+                    T::ModeratorRegistry::forum_worker_account(&moderator_id).is_some(),
+                    ERROR_NOT_FORUM_WORKER
+                );
+            }
+
+            /*
+             * Here we are safe to mutate
+             */
+
+            if is_moderator {
+                <CategoryModerators<T>>::insert(category_id, moderator_id, true);
+            } else {
+                <CategoryModerators<T>>::remove(category_id, moderator_id);
+            }
+
+            // Generate event
+            Self::deposit_event(RawEvent::CategoryModeratorUpdated(category_id, moderator_id, is_moderator));
+
+            Ok(())
+        }
+
+        /// Lock or unlock the thread. Locked threads stay visible, but do not accept new posts.
+        /// The `moderator_id` is the forum working group worker id of the category moderator,
+        /// `None` for the forum sudo.
+        #[weight = T::WeightInfo::set_thread_lock_status()]
+        fn set_thread_lock_status(
+            origin,
+            moderator_id: Option<T::ModeratorId>,
+            thread_id: T::ThreadId,
+            locked: bool
+        ) -> DispatchResult {
+
+            // Check that its a valid signature
+            let who = ensure_signed(origin)?;
+
+            // Make sure thread exists and is mutable
+            let thread = Self::ensure_thread_is_mutable(thread_id)?;
+
+            // Signed by forum SUDO or a moderator of the thread category
+            Self::ensure_can_moderate_category(&who, &moderator_id, thread.category_id)?;
+
+            ensure!(<LockedThreads<T>>::get(thread_id) != locked, ERROR_THREAD_LOCK_STATUS_NOT_CHANGED);
+
+            /*
+             * Here we are safe to mutate
+             */
+
+            if locked {
+                <LockedThreads<T>>::insert(thread_id, true);
+            } else {
+                <LockedThreads<T>>::remove(thread_id);
+            }
+
+            // Generate event
+            Self::deposit_event(RawEvent::ThreadLockStatusUpdated(thread_id, locked));
+
+            Ok(())
+        }
+
+        /// Pin the thread to the top of its category, or unpin it. The `moderator_id` is the forum
+        /// working group worker id of the category moderator, `None` for the forum sudo.
+        #[weight = T::WeightInfo::set_thread_pin_status()]
+        fn set_thread_pin_status(
+            origin,
+            moderator_id: Option<T::ModeratorId>,
+            thread_id: T::ThreadId,
+            pinned: bool
+        ) -> DispatchResult {
+
+            // Check that its a valid signature
+            let who = ensure_signed(origin)?;
+
+            // Make sure thread exists and is mutable
+            let thread = Self::ensure_thread_is_mutable(thread_id)?;
+
+            // Signed by forum SUDO or a moderator of the thread category
+            Self::ensure_can_moderate_category(&who, &moderator_id, thread.category_id)?;
+
+            let mut pinned_thread_ids = <PinnedThreadIds<T>>::get(thread.category_id);
+
+            ensure!(pinned_thread_ids.contains(&thread_id) != pinned, ERROR_THREAD_PIN_STATUS_NOT_CHANGED);
+
+            if pinned {
+                ensure!(
+                    pinned_thread_ids.len() < MAX_PINNED_THREADS_PER_CATEGORY,
+                    ERROR_MAX_PINNED_THREADS_EXCEEDED
+                );
+            }
+
+            /*
+             * Here we are safe to mutate
+             */
+
+            if pinned {
+                pinned_thread_ids.push(thread_id);
+            } else {
+                pinned_thread_ids.retain(|id| *id != thread_id);
+            }
+
+            <PinnedThreadIds<T>>::insert(thread.category_id, pinned_thread_ids);
+
+            // Generate event
+            Self::deposit_event(RawEvent::ThreadPinStatusUpdated(thread_id, pinned));
+
+            Ok(())
+        }
+
+        /// React to the post, replacing the previous reaction of the forum user if any.
+        /// No reaction removes the previous one.
+        #[weight = T::WeightInfo::react_to_post()]
+        fn react_to_post(origin, post_id: T::PostId, reaction: Option<PostReaction>) -> DispatchResult {
+
+            // Check that its a valid signature
+            let who = ensure_signed(origin)?;
+
+            // Check that account is forum member
+            Self::ensure_is_forum_member(&who)?;
+
+            // Make sure post exists and is mutable
+            Self::ensure_post_is_mutable(post_id)?;
+
+            /*
+             * Here we are safe to mutate
+             */
+
+            if let Some(old_reaction) = <PostReactionByAccount<T>>::get(post_id, &who) {
+                <PostReactionCount<T>>::mutate(post_id, old_reaction, |count| {
+                    *count = count.saturating_sub(1)
+                });
+            }
+
+            match reaction {
+                Some(new_reaction) => {
+                    <PostReactionByAccount<T>>::insert(post_id, &who, new_reaction);
+                    <PostReactionCount<T>>::mutate(post_id, new_reaction, |count| {
+                        *count = count.saturating_add(1)
+                    });
+                }
+                None => <PostReactionByAccount<T>>::remove(post_id, &who),
+            }
+
+            // Generate event
+            Self::deposit_event(RawEvent::PostReactionUpdated(post_id, who, reaction));
+
+            Ok(())
+        }
+
     }
 }
 
@@ -882,6 +1227,40 @@ impl<T: Trait> Module<T> {
         Ok(())
     }
 
+    // The forum sudo can moderate everywhere. Active forum working group workers can moderate
+    // in the categories (and their subcategories) they were assigned to by the lead. Only the
+    // given moderator is looked up on the category tree path, the departed workers are rejected.
+    fn ensure_can_moderate_category(
+        account_id: &T::AccountId,
+        moderator_id: &Option<T::ModeratorId>,
+        category_id: CategoryId,
+    ) -> DispatchResult {
+        let moderator_id = match moderator_id {
+            Some(moderator_id) => moderator_id,
+            None => {
+                ensure!(
+                    <ForumSudo<T>>::get().as_ref() == Some(account_id),
+                    ERROR_ORIGIN_NOT_FORUM_MODERATOR
+                );
+
+                return Ok(());
+            }
+        };
+
+        ensure!(
+            T::ModeratorRegistry::forum_worker_account(moderator_id).as_ref() == Some(account_id),
+            ERROR_ORIGIN_NOT_FORUM_MODERATOR
+        );
+
+        let is_category_moderator = Self::build_category_tree_path(category_id)
+            .iter()
+            .any(|category| <CategoryModerators<T>>::contains_key(category.id, moderator_id));
+
+        ensure!(is_category_moderator, ERROR_ORIGIN_NOT_FORUM_MODERATOR);
+
+        Ok(())
+    }
+
     fn ensure_is_forum_member(
         account_id: &T::AccountId,
     ) -> Result<ForumUser<T::AccountId>, &'static str> {
@@ -26,6 +26,13 @@ pub mod registry {
         trait Store for Module<T: Trait> as MockForumUserRegistry {
             pub ForumUserById get(fn forum_user_by_id) config(): map hasher(blake2_128_concat)
                 T::AccountId => Member<T::AccountId>;
+
+            pub ForumWorkers get(fn forum_workers): map hasher(blake2_128_concat)
+                u64 => Option<T::AccountId>;
+
+            pub NextForumWorkerId get(fn next_forum_worker_id): u64;
+
+            pub ForumLead get(fn forum_lead): Option<T::AccountId>;
         }
     }
 
@@ -37,6 +44,18 @@ pub mod registry {
         pub fn add_member(member: &Member<T::AccountId>) {
             <ForumUserById<T>>::insert(member.id.clone(), member.clone());
         }
+
+        pub fn add_forum_worker(worker_id: u64, id: &T::AccountId) {
+            <ForumWorkers<T>>::insert(worker_id, id);
+        }
+
+        pub fn remove_forum_worker(worker_id: u64) {
+            <ForumWorkers<T>>::remove(worker_id);
+        }
+
+        pub fn set_forum_lead(id: &T::AccountId) {
+            <ForumLead<T>>::put(id);
+        }
     }
 
     impl<T: Trait> ForumUserRegistry<T::AccountId> for Module<T> {
@@ -49,6 +68,34 @@ pub mod registry {
                 None
             }
         }
+
+        #[cfg(feature = "runtime-benchmarks")]
+        fn add_forum_user(id: &T::AccountId) {
+            Self::add_member(&Member { id: id.clone() });
+        }
+    }
+
+    impl<T: Trait> ForumModeratorRegistry<T::AccountId, u64> for Module<T> {
+        fn forum_worker_account(id: &u64) -> Option<T::AccountId> {
+            <ForumWorkers<T>>::get(id)
+        }
+
+        fn is_forum_lead(id: &T::AccountId) -> bool {
+            <ForumLead<T>>::get().as_ref() == Some(id)
+        }
+
+        #[cfg(feature = "runtime-benchmarks")]
+        fn add_forum_worker(id: &T::AccountId, lead: bool) -> u64 {
+            let worker_id = NextForumWorkerId::get();
+            NextForumWorkerId::put(worker_id + 1);
+            Self::add_forum_worker(worker_id, id);
+
+            if lead {
+                Self::set_forum_lead(id);
+            }
+
+            worker_id
+        }
     }
 
     pub type TestMembershipRegistryModule = Module<Runtime>;
@@ -105,8 +152,11 @@ impl pallet_timestamp::Trait for Runtime {
 impl Trait for Runtime {
     type Event = ();
     type MembershipRegistry = registry::TestMembershipRegistryModule;
+    type ModeratorRegistry = registry::TestMembershipRegistryModule;
+    type ModeratorId = u64;
     type ThreadId = u64;
     type PostId = u64;
+    type WeightInfo = ();
 }
 
 #[derive(Clone)]
@@ -128,6 +178,10 @@ pub const NOT_FORUM_SUDO_ORIGIN: OriginType = OriginType::Signed(111);
 
 pub const NOT_MEMBER_ORIGIN: OriginType = OriginType::Signed(222);
 
+pub const FORUM_LEAD_ORIGIN: OriginType = OriginType::Signed(666);
+
+pub const FORUM_WORKER_ORIGIN: OriginType = OriginType::Signed(777);
+
 pub const INVLAID_CATEGORY_ID: CategoryId = 333;
 
 pub const INVLAID_THREAD_ID: RuntimeThreadId = 444;
@@ -358,7 +412,21 @@ pub fn moderate_thread(
     thread_id: RuntimeThreadId,
     rationale: Vec<u8>,
 ) -> DispatchResult {
-    TestForumModule::moderate_thread(mock_origin(forum_sudo), thread_id, rationale)
+    TestForumModule::moderate_thread(mock_origin(forum_sudo), None, thread_id, rationale)
+}
+
+pub fn moderate_thread_as_moderator(
+    moderator: OriginType,
+    moderator_id: <Runtime as Trait>::ModeratorId,
+    thread_id: RuntimeThreadId,
+    rationale: Vec<u8>,
+) -> DispatchResult {
+    TestForumModule::moderate_thread(
+        mock_origin(moderator),
+        Some(moderator_id),
+        thread_id,
+        rationale,
+    )
 }
 
 pub fn moderate_post(
@@ -366,7 +434,59 @@ pub fn moderate_post(
     post_id: RuntimePostId,
     rationale: Vec<u8>,
 ) -> DispatchResult {
-    TestForumModule::moderate_post(mock_origin(forum_sudo), post_id, rationale)
+    TestForumModule::moderate_post(mock_origin(forum_sudo), None, post_id, rationale)
+}
+
+pub fn moderate_post_as_moderator(
+    moderator: OriginType,
+    moderator_id: <Runtime as Trait>::ModeratorId,
+    post_id: RuntimePostId,
+    rationale: Vec<u8>,
+) -> DispatchResult {
+    TestForumModule::moderate_post(
+        mock_origin(moderator),
+        Some(moderator_id),
+        post_id,
+        rationale,
+    )
+}
+
+pub fn set_category_moderator(
+    forum_lead: OriginType,
+    category_id: CategoryId,
+    moderator_id: <Runtime as Trait>::ModeratorId,
+    is_moderator: bool,
+) -> DispatchResult {
+    TestForumModule::set_category_moderator(
+        mock_origin(forum_lead),
+        category_id,
+        moderator_id,
+        is_moderator,
+    )
+}
+
+pub fn set_thread_lock_status(
+    moderator: OriginType,
+    thread_id: RuntimeThreadId,
+    locked: bool,
+) -> DispatchResult {
+    TestForumModule::set_thread_lock_status(mock_origin(moderator), None, thread_id, locked)
+}
+
+pub fn set_thread_pin_status(
+    moderator: OriginType,
+    thread_id: RuntimeThreadId,
+    pinned: bool,
+) -> DispatchResult {
+    TestForumModule::set_thread_pin_status(mock_origin(moderator), None, thread_id, pinned)
+}
+
+pub fn react_to_post(
+    forum_user: OriginType,
+    post_id: RuntimePostId,
+    reaction: Option<PostReaction>,
+) -> DispatchResult {
+    TestForumModule::react_to_post(mock_origin(forum_user), post_id, reaction)
 }
 
 pub fn archive_category(forum_sudo: OriginType, category_id: CategoryId) -> DispatchResult {
@@ -617,7 +617,7 @@ fn not_forum_sudo_cannot_moderate_thread() {
         let (_, _, thread_id) = create_root_category_and_thread(origin.clone());
         assert_eq!(
             moderate_thread(NOT_FORUM_SUDO_ORIGIN, thread_id, good_rationale()),
-            Err(ERROR_ORIGIN_NOT_FORUM_SUDO)
+            Err(ERROR_ORIGIN_NOT_FORUM_MODERATOR)
         );
     });
 }
@@ -631,7 +631,394 @@ fn not_forum_sudo_cannot_moderate_post() {
         let (_, _, _, post_id) = create_root_category_and_thread_and_post(origin.clone());
         assert_eq!(
             moderate_post(NOT_FORUM_SUDO_ORIGIN, post_id, good_rationale()),
-            Err(ERROR_ORIGIN_NOT_FORUM_SUDO)
+            Err(ERROR_ORIGIN_NOT_FORUM_MODERATOR)
+        );
+    });
+}
+
+// Category moderators:
+// -----------------------------------------------------------------------------
+
+const FORUM_WORKER_ID: u64 = 1;
+
+// Returns the lead account and the worker id.
+fn forum_lead_and_worker() -> (u64, u64) {
+    let lead_account_id = match FORUM_LEAD_ORIGIN {
+        OriginType::Signed(account_id) => account_id,
+        _ => unreachable!(),
+    };
+    let worker_account_id = match FORUM_WORKER_ORIGIN {
+        OriginType::Signed(account_id) => account_id,
+        _ => unreachable!(),
+    };
+
+    registry::TestMembershipRegistryModule::set_forum_lead(&lead_account_id);
+    registry::TestMembershipRegistryModule::add_forum_worker(FORUM_WORKER_ID, &worker_account_id);
+
+    (lead_account_id, FORUM_WORKER_ID)
+}
+
+#[test]
+fn category_moderator_can_moderate_in_category_and_subcategories() {
+    let config = default_genesis_config();
+    let origin = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let (_, worker_id) = forum_lead_and_worker();
+        let (member_origin, category_id, thread_id) =
+            create_root_category_and_thread(origin.clone());
+        let subcategory_id = create_category(origin, Some(category_id));
+        let subcategory_thread_id = TestForumModule::next_thread_id();
+        assert_create_thread(member_origin.clone(), subcategory_id, Ok(()));
+        let post_id = TestForumModule::next_post_id();
+        assert_create_post(member_origin, subcategory_thread_id, Ok(()));
+
+        assert_ok!(set_category_moderator(
+            FORUM_LEAD_ORIGIN,
+            category_id,
+            worker_id,
+            true
+        ));
+        assert!(TestForumModule::category_moderators(category_id, worker_id));
+
+        assert_ok!(moderate_post_as_moderator(
+            FORUM_WORKER_ORIGIN,
+            worker_id,
+            post_id,
+            good_rationale()
+        ));
+        assert_ok!(moderate_thread_as_moderator(
+            FORUM_WORKER_ORIGIN,
+            worker_id,
+            subcategory_thread_id,
+            good_rationale()
+        ));
+        assert_ok!(moderate_thread_as_moderator(
+            FORUM_WORKER_ORIGIN,
+            worker_id,
+            thread_id,
+            good_rationale()
+        ));
+    });
+}
+
+#[test]
+fn category_moderator_cannot_moderate_in_other_categories() {
+    let config = default_genesis_config();
+    let origin = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let (_, worker_id) = forum_lead_and_worker();
+        let (_, _, thread_id) = create_root_category_and_thread(origin.clone());
+        let other_category_id = create_root_category(origin);
+
+        assert_ok!(set_category_moderator(
+            FORUM_LEAD_ORIGIN,
+            other_category_id,
+            worker_id,
+            true
+        ));
+
+        assert_err!(
+            moderate_thread_as_moderator(
+                FORUM_WORKER_ORIGIN,
+                worker_id,
+                thread_id,
+                good_rationale()
+            ),
+            ERROR_ORIGIN_NOT_FORUM_MODERATOR
+        );
+    });
+}
+
+#[test]
+fn category_moderator_loses_rights_when_removed_or_leaving_working_group() {
+    let config = default_genesis_config();
+    let origin = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let (_, worker_id) = forum_lead_and_worker();
+        let (_, category_id, thread_id) = create_root_category_and_thread(origin);
+
+        assert_ok!(set_category_moderator(
+            FORUM_LEAD_ORIGIN,
+            category_id,
+            worker_id,
+            true
+        ));
+        registry::TestMembershipRegistryModule::remove_forum_worker(worker_id);
+
+        assert_err!(
+            moderate_thread_as_moderator(
+                FORUM_WORKER_ORIGIN,
+                worker_id,
+                thread_id,
+                good_rationale()
+            ),
+            ERROR_ORIGIN_NOT_FORUM_MODERATOR
+        );
+
+        // The departed worker can be removed from the moderators.
+        assert_ok!(set_category_moderator(
+            FORUM_LEAD_ORIGIN,
+            category_id,
+            worker_id,
+            false
+        ));
+        assert!(!TestForumModule::category_moderators(
+            category_id,
+            worker_id
+        ));
+
+        // Not a moderator even as the active worker.
+        forum_lead_and_worker();
+
+        assert_err!(
+            moderate_thread_as_moderator(
+                FORUM_WORKER_ORIGIN,
+                worker_id,
+                thread_id,
+                good_rationale()
+            ),
+            ERROR_ORIGIN_NOT_FORUM_MODERATOR
+        );
+    });
+}
+
+#[test]
+fn category_moderator_cannot_act_as_other_moderator() {
+    let config = default_genesis_config();
+    let origin = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let (_, worker_id) = forum_lead_and_worker();
+        let (member_origin, category_id, thread_id) = create_root_category_and_thread(origin);
+
+        assert_ok!(set_category_moderator(
+            FORUM_LEAD_ORIGIN,
+            category_id,
+            worker_id,
+            true
+        ));
+
+        assert_err!(
+            moderate_thread_as_moderator(member_origin, worker_id, thread_id, good_rationale()),
+            ERROR_ORIGIN_NOT_FORUM_MODERATOR
+        );
+        // The moderator is not the forum sudo.
+        assert_err!(
+            moderate_thread(FORUM_WORKER_ORIGIN, thread_id, good_rationale()),
+            ERROR_ORIGIN_NOT_FORUM_MODERATOR
+        );
+    });
+}
+
+#[test]
+fn only_forum_lead_can_set_category_moderators_from_forum_workers() {
+    let config = default_genesis_config();
+    let origin = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let (_, worker_id) = forum_lead_and_worker();
+        let category_id = create_root_category(origin.clone());
+
+        assert_err!(
+            set_category_moderator(origin, category_id, worker_id, true),
+            ERROR_ORIGIN_NOT_FORUM_LEAD
+        );
+        assert_err!(
+            set_category_moderator(FORUM_LEAD_ORIGIN, INVLAID_CATEGORY_ID, worker_id, true),
+            ERROR_CATEGORY_DOES_NOT_EXIST
+        );
+        assert_err!(
+            set_category_moderator(FORUM_LEAD_ORIGIN, category_id, 999, true),
+            ERROR_NOT_FORUM_WORKER
+        );
+    });
+}
+
+// Thread locking and pinning:
+// -----------------------------------------------------------------------------
+
+#[test]
+fn locked_thread_rejects_new_posts_until_unlocked() {
+    let config = default_genesis_config();
+    let origin = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let (member_origin, _, thread_id) = create_root_category_and_thread(origin.clone());
+
+        assert_ok!(set_thread_lock_status(origin.clone(), thread_id, true));
+        assert!(TestForumModule::locked_threads(thread_id));
+        assert_err!(
+            set_thread_lock_status(origin.clone(), thread_id, true),
+            ERROR_THREAD_LOCK_STATUS_NOT_CHANGED
+        );
+
+        // Locked thread stays visible.
+        assert!(<ThreadById<Runtime>>::contains_key(thread_id));
+        assert_create_post(member_origin.clone(), thread_id, Err(ERROR_THREAD_LOCKED));
+
+        assert_ok!(set_thread_lock_status(origin, thread_id, false));
+        assert!(!TestForumModule::locked_threads(thread_id));
+        assert_create_post(member_origin, thread_id, Ok(()));
+    });
+}
+
+#[test]
+fn not_forum_moderator_cannot_lock_or_pin_thread() {
+    let config = default_genesis_config();
+    let origin = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let (member_origin, _, thread_id) = create_root_category_and_thread(origin);
+
+        assert_err!(
+            set_thread_lock_status(member_origin.clone(), thread_id, true),
+            ERROR_ORIGIN_NOT_FORUM_MODERATOR
+        );
+        assert_err!(
+            set_thread_pin_status(member_origin, thread_id, true),
+            ERROR_ORIGIN_NOT_FORUM_MODERATOR
+        );
+    });
+}
+
+#[test]
+fn pin_and_unpin_threads_successfully() {
+    let config = default_genesis_config();
+    let origin = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let (member_origin, category_id, thread_id) =
+            create_root_category_and_thread(origin.clone());
+        let other_thread_id = TestForumModule::next_thread_id();
+        assert_create_thread(member_origin, category_id, Ok(()));
+
+        assert_ok!(set_thread_pin_status(origin.clone(), other_thread_id, true));
+        assert_ok!(set_thread_pin_status(origin.clone(), thread_id, true));
+        assert_eq!(
+            TestForumModule::pinned_thread_ids(category_id),
+            vec![other_thread_id, thread_id]
+        );
+        assert_err!(
+            set_thread_pin_status(origin.clone(), thread_id, true),
+            ERROR_THREAD_PIN_STATUS_NOT_CHANGED
+        );
+
+        assert_ok!(set_thread_pin_status(
+            origin.clone(),
+            other_thread_id,
+            false
+        ));
+        assert_eq!(
+            TestForumModule::pinned_thread_ids(category_id),
+            vec![thread_id]
+        );
+
+        // Moderated threads are unpinned.
+        assert_ok!(moderate_thread(origin, thread_id, good_rationale()));
+        assert!(TestForumModule::pinned_thread_ids(category_id).is_empty());
+    });
+}
+
+#[test]
+fn cannot_pin_more_than_max_threads_in_category() {
+    let config = default_genesis_config();
+    let origin = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let (member_origin, category_id, _) = create_root_category_and_thread(origin.clone());
+
+        for _ in 0..MAX_PINNED_THREADS_PER_CATEGORY {
+            let thread_id = TestForumModule::next_thread_id();
+            assert_create_thread(member_origin.clone(), category_id, Ok(()));
+            assert_ok!(set_thread_pin_status(origin.clone(), thread_id, true));
+        }
+
+        let thread_id = TestForumModule::next_thread_id();
+        assert_create_thread(member_origin, category_id, Ok(()));
+        assert_err!(
+            set_thread_pin_status(origin, thread_id, true),
+            ERROR_MAX_PINNED_THREADS_EXCEEDED
+        );
+    });
+}
+
+// Post reactions:
+// -----------------------------------------------------------------------------
+
+#[test]
+fn react_to_post_successfully() {
+    let config = default_genesis_config();
+    let origin = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let (member_origin, _, _, post_id) = create_root_category_and_thread_and_post(origin);
+        let member_id = match member_origin {
+            OriginType::Signed(account_id) => account_id,
+            _ => unreachable!(),
+        };
+
+        assert_ok!(react_to_post(
+            member_origin.clone(),
+            post_id,
+            Some(PostReaction::Like)
+        ));
+        assert_eq!(
+            TestForumModule::post_reaction_by_account(post_id, member_id),
+            Some(PostReaction::Like)
+        );
+        assert_eq!(
+            TestForumModule::post_reaction_count(post_id, PostReaction::Like),
+            1
+        );
+
+        // Reacting again replaces the previous reaction.
+        assert_ok!(react_to_post(
+            member_origin.clone(),
+            post_id,
+            Some(PostReaction::Dislike)
+        ));
+        assert_eq!(
+            TestForumModule::post_reaction_count(post_id, PostReaction::Like),
+            0
+        );
+        assert_eq!(
+            TestForumModule::post_reaction_count(post_id, PostReaction::Dislike),
+            1
+        );
+
+        assert_ok!(react_to_post(member_origin, post_id, None));
+        assert_eq!(
+            TestForumModule::post_reaction_by_account(post_id, member_id),
+            None
+        );
+        assert_eq!(
+            TestForumModule::post_reaction_count(post_id, PostReaction::Dislike),
+            0
+        );
+    });
+}
+
+#[test]
+fn cannot_react_to_moderated_post_or_as_not_member() {
+    let config = default_genesis_config();
+    let origin = OriginType::Signed(config.forum_sudo);
+
+    build_test_externalities(config).execute_with(|| {
+        let (member_origin, _, _, post_id) =
+            create_root_category_and_thread_and_post(origin.clone());
+
+        assert_err!(
+            react_to_post(NOT_MEMBER_ORIGIN, post_id, Some(PostReaction::Like)),
+            ERROR_NOT_FORUM_USER
+        );
+
+        assert_ok!(moderate_post(origin, post_id, good_rationale()));
+        assert_err!(
+            react_to_post(member_origin, post_id, Some(PostReaction::Like)),
+            ERROR_POST_MODERATED
         );
     });
 }
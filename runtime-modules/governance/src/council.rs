@@ -1,10 +1,17 @@
-use frame_support::{debug, decl_event, decl_module, decl_storage, ensure};
-use sp_arithmetic::traits::{One, Zero};
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+
+use codec::{Decode, Encode};
+use frame_support::traits::{Get, ReservableCurrency};
+use frame_support::weights::Weight;
+use frame_support::{debug, decl_event, decl_module, decl_storage, ensure, IterableStorageMap};
+use sp_arithmetic::traits::{One, Saturating, Zero};
+use sp_arithmetic::Percent;
 use sp_std::vec;
 use sp_std::vec::Vec;
-use system::ensure_root;
+use system::{ensure_root, ensure_signed};
 
-pub use super::election::{self, CouncilElected, Seat, Seats};
+pub use super::election::{self, Backer, CouncilElected, Seat, Seats};
 pub use common::currency::{BalanceOf, GovernanceCurrency};
 
 // Hook For announcing that council term has ended
@@ -22,12 +29,84 @@ impl<X: CouncilTermEnded> CouncilTermEnded for (X,) {
     }
 }
 
-pub trait Trait: system::Trait + recurringrewards::Trait + GovernanceCurrency {
+// Hook for refilling the council seat vacated by the recall
+pub trait CouncilSeatVacated<AccountId> {
+    fn council_seat_vacated(councilor: &AccountId);
+}
+
+impl<AccountId> CouncilSeatVacated<AccountId> for () {
+    fn council_seat_vacated(_councilor: &AccountId) {}
+}
+
+impl<AccountId, X: CouncilSeatVacated<AccountId>> CouncilSeatVacated<AccountId> for (X,) {
+    fn council_seat_vacated(councilor: &AccountId) {
+        X::council_seat_vacated(councilor);
+    }
+}
+
+// Chain of handlers.
+impl<AccountId, X: CouncilSeatVacated<AccountId>, Y: CouncilSeatVacated<AccountId>>
+    CouncilSeatVacated<AccountId> for (X, Y)
+{
+    fn council_seat_vacated(councilor: &AccountId) {
+        X::council_seat_vacated(councilor);
+        Y::council_seat_vacated(councilor);
+    }
+}
+
+pub trait Trait:
+    system::Trait + recurringrewards::Trait + GovernanceCurrency + membership::Trait
+{
     type Event: From<Event<Self>> + Into<<Self as system::Trait>::Event>;
 
     type CouncilTermEnded: CouncilTermEnded;
+
+    type CouncilSeatVacated: CouncilSeatVacated<Self::AccountId>;
+
+    /// Period in blocks during which a recall can be backed
+    type RecallPeriod: Get<Self::BlockNumber>;
+
+    /// Share of the total stake voted in the election of the active council the recall must
+    /// gather to vacate the seat
+    type RecallThreshold: Get<Percent>;
+
+    /// Minimum stake to start or back a recall. It is also the minimum stake the recall must
+    /// gather.
+    type MinRecallStake: Get<BalanceOf<Self>>;
+
+    /// Maximum number of members backing a recall, the initiator included
+    type MaxRecallBackers: Get<u32>;
+}
+
+/// Vote of no confidence against a councilor, backed by the member stakes.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize, Debug))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq)]
+pub struct Recall<AccountId, Balance, BlockNumber> {
+    /// Account which started the recall
+    pub initiator: AccountId,
+
+    /// Accounts backing the recall with their stakes, the initiator included
+    pub backers: Vec<Backer<AccountId, Balance>>,
+
+    /// Block at which the recall fails if it hasn't gathered enough stake
+    pub ends_at: BlockNumber,
+}
+
+impl<AccountId, Balance, BlockNumber> Recall<AccountId, Balance, BlockNumber>
+where
+    Balance: Zero + Saturating + Copy,
+{
+    /// Total stake backing the recall
+    pub fn total_stake(&self) -> Balance {
+        self.backers.iter().fold(Balance::zero(), |acc, backer| {
+            acc.saturating_add(backer.stake)
+        })
+    }
 }
 
+pub type RecallOf<T> =
+    Recall<<T as system::Trait>::AccountId, BalanceOf<T>, <T as system::Trait>::BlockNumber>;
+
 decl_storage! {
     trait Store for Module<T: Trait> as Council {
         pub ActiveCouncil get(fn active_council) config(): Seats<T::AccountId, BalanceOf<T>>;
@@ -51,6 +130,14 @@ decl_storage! {
 
         /// How many blocks after the reward is created, the first payout will be made
         pub FirstPayoutAfterRewardCreated get(fn first_payout_after_reward_created): T::BlockNumber;
+
+        /// Total stake of the votes cast in the election of the active council. Zero if the
+        /// council was set by the root.
+        pub ElectionVotingStake get(fn election_voting_stake): BalanceOf<T>;
+
+        /// Recalls in progress by the recalled councilor
+        pub Recalls get(fn recalls): map hasher(blake2_128_concat)
+            T::AccountId => Option<RecallOf<T>>;
     }
     add_extra_genesis {
         build(|_config: &GenesisConfig<T>| {
@@ -71,9 +158,21 @@ decl_storage! {
 
 // Event for this module.
 decl_event!(
-    pub enum Event<T> where <T as system::Trait>::BlockNumber {
+    pub enum Event<T> where
+    <T as system::Trait>::BlockNumber,
+    <T as system::Trait>::AccountId {
         CouncilTermEnded(BlockNumber),
         NewCouncilTermStarted(BlockNumber),
+        /// Params: recalled councilor, initiator
+        RecallStarted(AccountId, AccountId),
+        /// Params: recalled councilor, backer
+        RecallBacked(AccountId, AccountId),
+        /// Recall didn't gather enough stake or the council term ended.
+        RecallFailed(AccountId),
+        /// The recall succeeded, the councilor seat is vacated.
+        CouncilorRecalled(AccountId),
+        /// The vacated seat is filled with the account.
+        CouncilSeatFilled(AccountId),
     }
 );
 
@@ -134,6 +233,141 @@ impl<T: Trait> Module<T> {
         }
     }
 
+    /// Stake the recall must gather to vacate the seat: the threshold share of the total stake
+    /// voted in the election of the active council, but at least the minimum recall stake.
+    pub fn required_recall_stake() -> BalanceOf<T> {
+        let threshold_stake = T::RecallThreshold::get() * Self::election_voting_stake();
+
+        threshold_stake.max(T::MinRecallStake::get())
+    }
+
+    /// Fills a vacant seat with a zero staked council member. Fails if the account is already a
+    /// councilor.
+    pub fn fill_vacant_seat(account: T::AccountId) -> crate::DispatchResult {
+        ensure!(
+            !Self::is_councilor(&account),
+            "account is already a councilor"
+        );
+
+        Self::add_reward_relationship(&account, Self::council_mint());
+
+        let seat = Seat {
+            member: account.clone(),
+            stake: BalanceOf::<T>::zero(),
+            backers: vec![],
+        };
+
+        <ActiveCouncil<T>>::mutate(|council| council.push(seat));
+
+        Self::deposit_event(RawEvent::CouncilSeatFilled(account));
+
+        Ok(())
+    }
+
+    fn add_recall_stake(backer: &T::AccountId, stake: BalanceOf<T>) -> crate::DispatchResult {
+        ensure!(
+            <membership::Module<T>>::is_member_account(backer),
+            "only members can back a recall"
+        );
+        ensure!(stake >= T::MinRecallStake::get(), "recall stake is too low");
+        ensure!(
+            <T as GovernanceCurrency>::Currency::reserve(backer, stake).is_ok(),
+            "not enough free balance to reserve"
+        );
+
+        Ok(())
+    }
+
+    fn refund_recall_stakes(recall: &RecallOf<T>) {
+        for backer in recall.backers.iter() {
+            <T as GovernanceCurrency>::Currency::unreserve(&backer.member, backer.stake);
+        }
+    }
+
+    fn fail_recall(councilor: &T::AccountId) {
+        if let Some(recall) = <Recalls<T>>::take(councilor) {
+            Self::refund_recall_stakes(&recall);
+
+            Self::deposit_event(RawEvent::RecallFailed(councilor.clone()));
+        }
+    }
+
+    // Vacates the seat if the recall gathered enough stake
+    fn try_complete_recall(councilor: &T::AccountId) {
+        let recall = match Self::recalls(councilor) {
+            Some(recall) => recall,
+            None => return,
+        };
+
+        if recall.total_stake() < Self::required_recall_stake() {
+            return;
+        }
+
+        <Recalls<T>>::remove(councilor);
+        Self::refund_recall_stakes(&recall);
+
+        // The stakes of the vacated seat are returned: they would not be transferred to the
+        // next election.
+        if let Some(seat) = Self::active_council()
+            .into_iter()
+            .find(|seat| seat.member == *councilor)
+        {
+            <T as GovernanceCurrency>::Currency::unreserve(&seat.member, seat.stake);
+            for backer in seat.backers.iter() {
+                <T as GovernanceCurrency>::Currency::unreserve(&backer.member, backer.stake);
+            }
+        }
+
+        if RewardRelationships::<T>::contains_key(councilor) {
+            let relationship_id = RewardRelationships::<T>::take(councilor);
+            <recurringrewards::Module<T>>::remove_reward_relationship(relationship_id);
+        }
+
+        <ActiveCouncil<T>>::mutate(|council| council.retain(|seat| seat.member != *councilor));
+
+        Self::deposit_event(RawEvent::CouncilorRecalled(councilor.clone()));
+
+        T::CouncilSeatVacated::council_seat_vacated(councilor);
+    }
+
+    // Weight of failing the recalls in `on_finalize`: the expired ones, or all of them when the
+    // term ends. The stake of every backer of a failed recall is refunded.
+    fn fail_recalls_weight(now: T::BlockNumber) -> Weight {
+        let term_ends = now == Self::term_ends_at();
+
+        let mut recalls_count: Weight = 0;
+        let mut refunded_backers_count: Weight = 0;
+        for (_, recall) in <Recalls<T>>::iter() {
+            recalls_count += 1;
+            if term_ends || recall.ends_at <= now {
+                refunded_backers_count += recall.backers.len() as Weight;
+            }
+        }
+
+        T::DbWeight::get().reads_writes(
+            1 + recalls_count + refunded_backers_count,
+            recalls_count + refunded_backers_count,
+        )
+    }
+
+    // Weight of completing a recall: the stakes of all its backers are refunded.
+    fn complete_recall_weight() -> Weight {
+        let max_refunds = T::MaxRecallBackers::get() as Weight;
+
+        T::DbWeight::get().reads_writes(4 + max_refunds, 4 + max_refunds)
+    }
+
+    fn fail_expired_recalls(now: T::BlockNumber) {
+        let expired_recalls: Vec<T::AccountId> = <Recalls<T>>::iter()
+            .filter(|(_, recall)| recall.ends_at <= now)
+            .map(|(councilor, _)| councilor)
+            .collect();
+
+        for councilor in expired_recalls.iter() {
+            Self::fail_recall(councilor);
+        }
+    }
+
     fn on_term_ended(now: T::BlockNumber) {
         // Stop paying out rewards when the term ends.
         // Note: Is it not simpler to just do a single payout at end of term?
@@ -142,6 +376,14 @@ impl<T: Trait> Module<T> {
         // and a potentially more fair outcome in such a case.
         Self::remove_reward_relationships();
 
+        // The recalls of the council members lapse with the term.
+        let councilors: Vec<T::AccountId> = <Recalls<T>>::iter()
+            .map(|(councilor, _)| councilor)
+            .collect();
+        for councilor in councilors.iter() {
+            Self::fail_recall(councilor);
+        }
+
         Self::deposit_event(RawEvent::CouncilTermEnded(now));
 
         T::CouncilTermEnded::council_term_ended();
@@ -152,12 +394,94 @@ decl_module! {
     pub struct Module<T: Trait> for enum Call where origin: T::Origin {
         fn deposit_event() = default;
 
+        /// Exports const - period in blocks during which a recall can be backed.
+        const RecallPeriod: T::BlockNumber = T::RecallPeriod::get();
+
+        /// Exports const - share of the total stake voted in the council election the recall must
+        /// gather.
+        const RecallThreshold: Percent = T::RecallThreshold::get();
+
+        /// Exports const - minimum stake to start or back a recall.
+        const MinRecallStake: BalanceOf<T> = T::MinRecallStake::get();
+
+        /// Exports const - maximum number of members backing a recall.
+        const MaxRecallBackers: u32 = T::MaxRecallBackers::get();
+
+        fn on_initialize(now: T::BlockNumber) -> Weight {
+            // The recalls are failed when finalizing the block.
+            Self::fail_recalls_weight(now)
+        }
+
         fn on_finalize(now: T::BlockNumber) {
+            Self::fail_expired_recalls(now);
+
             if now == Self::term_ends_at() {
                 Self::on_term_ended(now);
             }
         }
 
+        /// Starts a recall (vote of no confidence) against the councilor by staking. Other members
+        /// back the recall with their stakes during the recall period. The councilor seat is
+        /// vacated if the recall gathers the required stake, the recall stakes are returned in
+        /// any case.
+        #[weight = 10_000_000u64.saturating_add(Module::<T>::complete_recall_weight())] // TODO: adjust weight
+        pub fn start_recall(origin, councilor: T::AccountId, stake: BalanceOf<T>) {
+            let sender = ensure_signed(origin)?;
+
+            ensure!(Self::is_councilor(&councilor), "account is not a councilor");
+            ensure!(!Self::is_term_ended(), "council term ended");
+            ensure!(!<Recalls<T>>::contains_key(&councilor), "recall already started");
+
+            Self::add_recall_stake(&sender, stake)?;
+
+            let recall = Recall {
+                initiator: sender.clone(),
+                backers: vec![Backer {
+                    member: sender.clone(),
+                    stake,
+                }],
+                ends_at: <system::Module<T>>::block_number() + T::RecallPeriod::get(),
+            };
+
+            <Recalls<T>>::insert(&councilor, recall);
+
+            Self::deposit_event(RawEvent::RecallStarted(councilor.clone(), sender));
+
+            Self::try_complete_recall(&councilor);
+        }
+
+        /// Backs the recall against the councilor by staking. Subsequent calls increase the
+        /// backing stake.
+        #[weight = 10_000_000u64.saturating_add(Module::<T>::complete_recall_weight())] // TODO: adjust weight
+        pub fn back_recall(origin, councilor: T::AccountId, stake: BalanceOf<T>) {
+            let sender = ensure_signed(origin)?;
+
+            let mut recall = Self::recalls(&councilor).ok_or("recall not found")?;
+
+            let is_backer = recall.backers.iter().any(|backer| backer.member == sender);
+            ensure!(
+                is_backer || recall.backers.len() < T::MaxRecallBackers::get() as usize,
+                "too many recall backers"
+            );
+
+            Self::add_recall_stake(&sender, stake)?;
+
+            if let Some(backer) = recall.backers.iter_mut().find(|backer| backer.member == sender) {
+                backer.stake = backer.stake.saturating_add(stake);
+            } else {
+                recall.backers.push(Backer {
+                    member: sender.clone(),
+                    stake,
+                });
+            }
+
+            <Recalls<T>>::insert(&councilor, recall);
+
+            Self::deposit_event(RawEvent::RecallBacked(councilor.clone(), sender));
+
+            Self::try_complete_recall(&councilor);
+        }
+
         // Privileged methods
 
         /// Force set a zero staked council. Stakes in existing council seats are not returned.
@@ -171,6 +495,9 @@ decl_module! {
             // Council is being replaced so remove existing reward relationships if they exist
             Self::remove_reward_relationships();
 
+            // The new council is not elected by votes.
+            <ElectionVotingStake<T>>::kill();
+
             for account in accounts.clone() {
                 Self::add_reward_relationship(&account, Self::council_mint());
             }
@@ -235,11 +562,20 @@ decl_module! {
 
         /// Sets the capacity of the the council mint, if it doesn't exist, attempts to
         /// create a new one.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <recurringrewards::Module<T>>::pay_arrears_weight().saturating_add(10_000_000)] // TODO: adjust weight
         pub fn set_council_mint_capacity(origin, capacity: minting::BalanceOf<T>) {
             ensure_root(origin)?;
 
-            minting::Module::<T>::set_mint_capacity(Self::council_mint(), capacity).map_err(<&str>::from)?;
+            let mint_id = Self::council_mint();
+            let current_capacity = <minting::Module<T>>::mints(mint_id).capacity();
+
+            minting::Module::<T>::set_mint_capacity(mint_id, capacity).map_err(<&str>::from)?;
+
+            // Rewards owed to the council members are paid first. The capacity removed is
+            // returned to the parent mint, which pays its own arrears.
+            if capacity > current_capacity {
+                <recurringrewards::Module<T>>::pay_arrears(mint_id);
+            }
         }
 
         /// Attempts to mint and transfer amount to destination account
@@ -247,8 +583,12 @@ decl_module! {
         fn spend_from_council_mint(origin, amount: minting::BalanceOf<T>, destination: T::AccountId) {
             ensure_root(origin)?;
 
-            minting::Module::<T>::transfer_tokens(Self::council_mint(), amount, &destination)
-                .map_err(<&str>::from)?;
+            minting::Module::<T>::transfer_tokens(
+                Self::council_mint(),
+                amount,
+                &destination,
+                minting::SpendingCategory::SpendingProposals,
+            ).map_err(<&str>::from)?;
         }
 
         /// Sets the council rewards which is only applied on new council being elected.
@@ -278,6 +618,7 @@ mod tests {
     use super::*;
     use crate::mock::*;
     use crate::DispatchResult;
+    use frame_support::traits::{Currency, OnFinalize};
     use frame_support::*;
 
     fn add_council_member_as_root(account: <Test as system::Trait>::AccountId) -> DispatchResult {
@@ -329,6 +670,157 @@ mod tests {
         });
     }
 
+    fn elect_staked_council() {
+        // Ensure a mint is created so we can create rewards
+        assert_ok!(Council::set_council_mint_capacity(
+            system::RawOrigin::Root.into(),
+            1000
+        ));
+
+        let _ = Balances::deposit_creating(&1, 1000);
+        assert_ok!(Balances::reserve(&1, 100));
+        let _ = Balances::deposit_creating(&2, 1000);
+        assert_ok!(Balances::reserve(&2, 100));
+
+        Council::council_elected(
+            vec![
+                Seat {
+                    member: 1,
+                    stake: 100,
+                    backers: vec![],
+                },
+                Seat {
+                    member: 2,
+                    stake: 100,
+                    backers: vec![],
+                },
+            ],
+            50,
+        );
+
+        <ElectionVotingStake<Test>>::put(200);
+
+        for account_id in 5..8 {
+            let _ = Balances::deposit_creating(&account_id, 1000);
+        }
+    }
+
+    #[test]
+    fn start_recall_fails_with_invalid_parameters() {
+        initial_test_ext().execute_with(|| {
+            elect_staked_council();
+
+            assert_err!(
+                Council::start_recall(Origin::signed(5), 3, 50),
+                "account is not a councilor"
+            );
+            assert_err!(
+                Council::start_recall(Origin::signed(100), 1, 50),
+                "only members can back a recall"
+            );
+            assert_err!(
+                Council::start_recall(Origin::signed(5), 1, 5),
+                "recall stake is too low"
+            );
+            assert_err!(
+                Council::start_recall(Origin::signed(5), 1, 5000),
+                "not enough free balance to reserve"
+            );
+
+            assert_ok!(Council::start_recall(Origin::signed(5), 1, 50));
+            assert_err!(
+                Council::start_recall(Origin::signed(6), 1, 50),
+                "recall already started"
+            );
+            assert_err!(
+                Council::back_recall(Origin::signed(6), 2, 50),
+                "recall not found"
+            );
+        });
+    }
+
+    #[test]
+    fn recall_vacates_council_seat() {
+        initial_test_ext().execute_with(|| {
+            elect_staked_council();
+
+            // Half of the total voting stake is required.
+            assert_eq!(Council::required_recall_stake(), 100);
+
+            assert_ok!(Council::start_recall(Origin::signed(5), 1, 40));
+            assert_ok!(Council::back_recall(Origin::signed(6), 1, 20));
+            assert_eq!(Council::recalls(1).unwrap().total_stake(), 60);
+            assert_eq!(Balances::reserved_balance(&5), 40);
+            assert!(Council::is_councilor(&1));
+
+            assert_ok!(Council::back_recall(Origin::signed(6), 1, 40));
+
+            assert!(!Council::is_councilor(&1));
+            assert!(Council::is_councilor(&2));
+            assert!(!RewardRelationships::<Test>::contains_key(&1));
+            assert!(Council::recalls(1).is_none());
+
+            // The recall and seat stakes are returned.
+            assert_eq!(Balances::reserved_balance(&5), 0);
+            assert_eq!(Balances::reserved_balance(&6), 0);
+            assert_eq!(Balances::reserved_balance(&1), 0);
+        });
+    }
+
+    #[test]
+    fn recall_backers_are_limited() {
+        initial_test_ext().execute_with(|| {
+            elect_staked_council();
+
+            assert_ok!(Council::start_recall(Origin::signed(5), 1, 10));
+            assert_ok!(Council::back_recall(Origin::signed(6), 1, 10));
+            assert_err!(
+                Council::back_recall(Origin::signed(7), 1, 10),
+                "too many recall backers"
+            );
+            assert_eq!(Balances::reserved_balance(&7), 0);
+
+            // Existing backers can still increase their stake.
+            assert_ok!(Council::back_recall(Origin::signed(6), 1, 10));
+            assert_eq!(Council::recalls(1).unwrap().total_stake(), 30);
+        });
+    }
+
+    #[test]
+    fn required_recall_stake_is_minimum_for_council_set_by_root() {
+        initial_test_ext().execute_with(|| {
+            elect_staked_council();
+
+            assert_ok!(Council::set_council(
+                system::RawOrigin::Root.into(),
+                vec![1, 2]
+            ));
+
+            assert_eq!(Council::election_voting_stake(), 0);
+            assert_eq!(Council::required_recall_stake(), MinRecallStake::get());
+        });
+    }
+
+    #[test]
+    fn recall_fails_after_recall_period() {
+        initial_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            elect_staked_council();
+
+            assert_ok!(Council::start_recall(Origin::signed(5), 1, 40));
+            let ends_at = Council::recalls(1).unwrap().ends_at;
+            assert_eq!(ends_at, 1 + RecallPeriod::get());
+
+            Council::on_finalize(ends_at - 1);
+            assert!(Council::recalls(1).is_some());
+
+            Council::on_finalize(ends_at);
+            assert!(Council::recalls(1).is_none());
+            assert!(Council::is_councilor(&1));
+            assert_eq!(Balances::reserved_balance(&5), 0);
+        });
+    }
+
     #[test]
     fn council_elected_test() {
         initial_test_ext().execute_with(|| {
@@ -20,6 +20,19 @@
 //! We only guard against these edge cases in the [`set_election_parameters`] call.
 //!
 //! [`set_election_parameters`]: struct.Module.html#method.set_election_parameters
+//!
+//! # Voting Modes:
+//! - `SingleChoice` - a vote is revealed for a single applicant with [`reveal`]. The applicants
+//! with the highest total stake (own stake and backing stake) form the council.
+//!
+//! - `RankedChoice` - a vote is revealed with [`reveal_ranked`] as a list of applicants ordered
+//! by preference. The council is elected using the single transferable vote method weighted by
+//! the voting stakes, the vote stake is split between the seats it was counted for.
+//! The zero council size edge cases described above apply to the `SingleChoice` mode only,
+//! no council members are elected in the `RankedChoice` mode in such case.
+//!
+//! [`reveal`]: struct.Module.html#method.reveal
+//! [`reveal_ranked`]: struct.Module.html#method.reveal_ranked
 
 // Clippy linter warning
 #![allow(clippy::type_complexity)]
@@ -34,10 +47,13 @@ use serde::{Deserialize, Serialize};
 
 use codec::{Decode, Encode};
 use frame_support::traits::{Currency, ReservableCurrency};
-use frame_support::{decl_event, decl_module, decl_storage, ensure};
-use sp_arithmetic::traits::Zero;
+use frame_support::{decl_event, decl_module, decl_storage, ensure, IterableStorageDoubleMap};
+use sp_arithmetic::helpers_128bit::multiply_by_rational;
+use sp_arithmetic::traits::{Saturating, Zero};
 use sp_runtime::traits::Hash;
+use sp_runtime::SaturatedConversion;
 use sp_std::collections::btree_map::BTreeMap;
+use sp_std::collections::btree_set::BTreeSet;
 use sp_std::ops::Add;
 use sp_std::vec;
 use sp_std::vec::Vec;
@@ -45,9 +61,10 @@ use system::{ensure_root, ensure_signed};
 
 use super::sealed_vote::SealedVote;
 use super::stake::Stake;
+use super::stv;
 
 use super::council;
-use crate::election_params::ElectionParameters;
+use crate::election_params::{ElectionParameters, VotingMode};
 pub use common::currency::{BalanceOf, GovernanceCurrency};
 
 use crate::DispatchResult;
@@ -133,6 +150,53 @@ pub struct TransferableStake<Balance> {
 // can we use a type alias to overcome name clashes of public types with other modules?
 pub type ElectionStake<T> = Stake<BalanceOf<T>>;
 
+/// Archived applicant of an election round with the votes revealed for them. In the ranked
+/// choice voting mode the votes are counted for the first choice of the ranking.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize, Debug))]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq)]
+pub struct ApplicantResult<AccountId, Balance> {
+    pub applicant: AccountId,
+    pub stake: Balance,
+    pub revealed_votes: u32,
+    pub revealed_votes_stake: Balance,
+}
+
+/// Archived results of an election round. Only the applicants who reached the voting stage
+/// are archived.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize, Debug))]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq)]
+pub struct ElectionResult<AccountId, Balance, BlockNumber> {
+    pub round: u32,
+    pub ended_at: BlockNumber,
+    pub voting_mode: VotingMode,
+    // The election was stopped by the root, no council was elected
+    pub force_stopped: bool,
+    pub applicants: Vec<ApplicantResult<AccountId, Balance>>,
+    pub unrevealed_commitments: u32,
+    pub elected: Seats<AccountId, Balance>,
+}
+
+pub type ElectionResultOf<T> = ElectionResult<
+    <T as system::Trait>::AccountId,
+    BalanceOf<T>,
+    <T as system::Trait>::BlockNumber,
+>;
+
+/// Participation of an account in an election round.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize, Debug))]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq)]
+pub struct ElectionParticipation<Balance> {
+    pub round: u32,
+    // Applicant stake if the account applied and reached the voting stage
+    pub applicant_stake: Option<Balance>,
+    pub elected: bool,
+    pub voting_stake: Balance,
+    pub revealed_voting_stake: Balance,
+}
+
+// Upper limit of the election results returned by a single query
+const MAX_ELECTION_RESULTS_QUERY_LIMIT: u32 = 100;
+
 decl_storage! {
     trait Store for Module<T: Trait> as CouncilElection {
         // Flag for wether to automatically start an election after a council term ends
@@ -158,6 +222,22 @@ decl_storage! {
         Votes get(fn votes): map hasher(blake2_128_concat)
             T::Hash => SealedVote<T::AccountId, ElectionStake<T>, T::Hash, T::AccountId>;
 
+        // Revealed rankings of the votes (ranked choice voting mode only)
+        RankedVotes get(fn ranked_votes): map hasher(blake2_128_concat)
+            T::Hash => Vec<T::AccountId>;
+
+        // Applicants of the last election who were not elected, with their stakes, ordered by
+        // priority. They fill the council seats vacated by the recall.
+        RunnersUp get(fn runners_up): Vec<(T::AccountId, BalanceOf<T>)>;
+
+        // Archived results of the ended election rounds
+        ElectionResults get(fn election_results): map hasher(twox_64_concat)
+            u32 => Option<ElectionResultOf<T>>;
+
+        // Participation of the account in the election round as an applicant or a voter
+        ParticipationHistory get(fn election_participation): double_map hasher(blake2_128_concat)
+            T::AccountId, hasher(twox_64_concat) u32 => Option<ElectionParticipation<BalanceOf<T>>>;
+
         // Current Election Parameters.
         // Should we replace all the individual values with a single ElectionParameters type?
         // Having them individually makes it more flexible to add and remove new parameters in future
@@ -170,6 +250,7 @@ decl_storage! {
         MinCouncilStake get(fn min_council_stake): BalanceOf<T>;
         NewTermDuration get(fn new_term_duration): T::BlockNumber;
         MinVotingStake get(fn min_voting_stake): BalanceOf<T>;
+        ElectionVotingMode get(fn voting_mode): VotingMode;
     }
     add_extra_genesis {
         config(election_parameters): ElectionParameters<BalanceOf<T>, T::BlockNumber>;
@@ -198,6 +279,7 @@ decl_event!(
         Applied(AccountId),
         Voted(AccountId, Hash),
         Revealed(AccountId, Hash, AccountId),
+        RankingRevealed(AccountId, Hash, Vec<AccountId>),
     }
 );
 
@@ -241,6 +323,32 @@ impl<T: Trait> Module<T> {
         }
     }
 
+    /// Returns up to `limit` archived election results ordered from newer to older rounds
+    /// starting from the `start_round` (inclusive) or from the latest round.
+    /// The limit is capped by the MAX_ELECTION_RESULTS_QUERY_LIMIT.
+    pub fn election_results_page(start_round: Option<u32>, limit: u32) -> Vec<ElectionResultOf<T>> {
+        let round = Self::round();
+        let start_round = start_round.map_or(round, |start| start.min(round));
+        let limit = limit.min(MAX_ELECTION_RESULTS_QUERY_LIMIT);
+
+        (1..=start_round)
+            .rev()
+            .filter_map(Self::election_results)
+            .take(limit as usize)
+            .collect()
+    }
+
+    /// Returns the election rounds the account participated in as an applicant or a voter,
+    /// ordered from older to newer rounds.
+    pub fn participation_history(
+        account: &T::AccountId,
+    ) -> Vec<ElectionParticipation<BalanceOf<T>>> {
+        let mut history: Vec<_> = <ParticipationHistory<T>>::iter_prefix_values(account).collect();
+        history.sort_by_key(|participation| participation.round);
+
+        history
+    }
+
     // PRIVATE MUTABLES
 
     /// Starts an election. Will fail if an election is already running
@@ -345,7 +453,37 @@ impl<T: Trait> Module<T> {
             votes.push(Self::votes(commitment));
         }
 
-        let mut new_council = Self::tally_votes(&votes);
+        let new_council = match Self::voting_mode() {
+            VotingMode::SingleChoice => Self::elect_single_choice_council(&votes),
+            VotingMode::RankedChoice => Self::elect_ranked_choice_council(&votes),
+        };
+
+        // unless we want to add more filtering criteria to what is considered a successful election
+        // other than just the minimum stake for candidacy, we have a new council!
+
+        <RunnersUp<T>>::put(Self::unelected_applicants_by_priority(&new_council));
+
+        // The recall threshold of the new council applies to the total voting stake.
+        <council::ElectionVotingStake<T>>::put(Self::total_voting_stake(&votes));
+
+        Self::archive_election(&votes, &new_council, false);
+
+        Self::teardown_election(
+            &votes,
+            &new_council,
+            true, /* unlock transferable stakes */
+        );
+
+        let new_council = new_council.into_iter().map(|(_, seat)| seat).collect();
+        T::CouncilElected::council_elected(new_council, Self::new_term_duration());
+
+        Self::deposit_event(RawEvent::CouncilElected(<system::Module<T>>::block_number()));
+    }
+
+    fn elect_single_choice_council(
+        votes: &[SealedVote<T::AccountId, Stake<BalanceOf<T>>, T::Hash, T::AccountId>],
+    ) -> BTreeMap<T::AccountId, Seat<T::AccountId, BalanceOf<T>>> {
+        let mut new_council = Self::tally_votes(votes);
 
         // Note here that applicants with zero votes dont appear in the tally.
         // Is an applicant with some votes but less total stake than another applicant with zero votes
@@ -382,19 +520,219 @@ impl<T: Trait> Module<T> {
             }
         }
 
-        // unless we want to add more filtering criteria to what is considered a successful election
-        // other than just the minimum stake for candidacy, we have a new council!
+        new_council
+    }
 
-        Self::teardown_election(
-            &votes,
-            &new_council,
-            true, /* unlock transferable stakes */
-        );
+    /// Elects the council from the revealed rankings using the single transferable vote method.
+    /// Each elected vote backs the seats its stake was counted for. A vote which ranked an elected
+    /// applicant but whose stake was not counted for any seat backs the highest ranked elected
+    /// applicant, so the whole stake of the vote is always assigned to the new council.
+    fn elect_ranked_choice_council(
+        votes: &[SealedVote<T::AccountId, Stake<BalanceOf<T>>, T::Hash, T::AccountId>],
+    ) -> BTreeMap<T::AccountId, Seat<T::AccountId, BalanceOf<T>>> {
+        // Applicants ordered by priority: higher stake first, early applicants first if stakes are equal
+        let mut candidates = Self::applicants();
+        candidates.reverse();
+        candidates.sort_by(|a, b| {
+            Self::applicant_stakes(b)
+                .total()
+                .cmp(&Self::applicant_stakes(a).total())
+        });
 
-        let new_council = new_council.into_iter().map(|(_, seat)| seat).collect();
-        T::CouncilElected::council_elected(new_council, Self::new_term_duration());
+        let ranked_votes: Vec<_> = votes
+            .iter()
+            .filter(|vote| vote.is_revealed())
+            .map(|vote| (vote, Self::ranked_votes(vote.commitment)))
+            .filter(|(_, ranking)| !ranking.is_empty())
+            .collect();
 
-        Self::deposit_event(RawEvent::CouncilElected(<system::Module<T>>::block_number()));
+        let ballots: Vec<_> = ranked_votes
+            .iter()
+            .map(|(vote, ranking)| stv::RankedBallot {
+                preferences: ranking.clone(),
+                weight: vote.stake.total().saturated_into(),
+            })
+            .collect();
+
+        let result = stv::elect(&candidates, &ballots, Self::council_size_usize());
+
+        let mut new_council: BTreeMap<T::AccountId, Seat<T::AccountId, BalanceOf<T>>> = result
+            .elected
+            .into_iter()
+            .map(|applicant| {
+                let seat = Seat {
+                    member: applicant.clone(),
+                    stake: Self::applicant_stakes(&applicant).total(),
+                    backers: Vec::new(),
+                };
+                (applicant, seat)
+            })
+            .collect();
+
+        for ((vote, ranking), backing) in ranked_votes.iter().zip(result.backing.into_iter()) {
+            let backing: Vec<_> = backing
+                .into_iter()
+                .filter(|(_, weight)| !weight.is_zero())
+                .collect();
+
+            let backing = if backing.is_empty() {
+                ranking
+                    .iter()
+                    .find(|applicant| new_council.contains_key(*applicant))
+                    .map(|applicant| vec![(applicant.clone(), 1)])
+                    .unwrap_or_default()
+            } else {
+                backing
+            };
+
+            for (applicant, stake) in Self::split_vote_stake(vote.stake.total(), &backing) {
+                if let Some(seat) = new_council.get_mut(&applicant) {
+                    seat.backers.push(Backer {
+                        member: vote.voter.clone(),
+                        stake,
+                    });
+                }
+            }
+        }
+
+        new_council
+    }
+
+    /// Applicants not in the new council with their stakes: higher stake first, early applicants
+    /// first if stakes are equal.
+    fn unelected_applicants_by_priority(
+        new_council: &BTreeMap<T::AccountId, Seat<T::AccountId, BalanceOf<T>>>,
+    ) -> Vec<(T::AccountId, BalanceOf<T>)> {
+        let mut runners_up: Vec<_> = Self::applicants()
+            .into_iter()
+            .rev()
+            .filter(|applicant| !new_council.contains_key(applicant))
+            .map(|applicant| {
+                let stake = Self::applicant_stakes(&applicant).total();
+                (applicant, stake)
+            })
+            .collect();
+
+        runners_up.sort_by(|(_, a), (_, b)| b.cmp(a));
+
+        runners_up
+    }
+
+    /// Total stake of the votes cast in the election, revealed or not.
+    fn total_voting_stake(
+        votes: &[SealedVote<T::AccountId, Stake<BalanceOf<T>>, T::Hash, T::AccountId>],
+    ) -> BalanceOf<T> {
+        votes.iter().fold(Zero::zero(), |acc, vote| {
+            acc.saturating_add(vote.stake.total())
+        })
+    }
+
+    /// Splits the vote stake between the backed applicants in proportion to the backing weights.
+    /// The rounding remainder is assigned to the first backed applicant.
+    fn split_vote_stake(
+        stake: BalanceOf<T>,
+        backing: &[(T::AccountId, u128)],
+    ) -> Vec<(T::AccountId, BalanceOf<T>)> {
+        let total_weight = backing
+            .iter()
+            .fold(0u128, |acc, (_, weight)| acc.saturating_add(*weight));
+
+        let mut remaining = stake;
+        let mut shares = Vec::new();
+
+        for (applicant, weight) in backing.iter().skip(1) {
+            let share: BalanceOf<T> =
+                multiply_by_rational(stake.saturated_into(), *weight, total_weight)
+                    .unwrap_or(0)
+                    .saturated_into();
+            let share = share.min(remaining);
+
+            remaining -= share;
+            shares.push((applicant.clone(), share));
+        }
+
+        if let Some((applicant, _)) = backing.first() {
+            shares.insert(0, (applicant.clone(), remaining));
+        }
+
+        shares
+    }
+
+    /// Archives the results of the current round and the participation of its applicants and
+    /// voters. Must be called before the election is torn down.
+    fn archive_election(
+        votes: &[SealedVote<T::AccountId, Stake<BalanceOf<T>>, T::Hash, T::AccountId>],
+        new_council: &BTreeMap<T::AccountId, Seat<T::AccountId, BalanceOf<T>>>,
+        force_stopped: bool,
+    ) {
+        let round = Self::round();
+        let mut participation: BTreeMap<T::AccountId, ElectionParticipation<BalanceOf<T>>> =
+            BTreeMap::new();
+
+        let applicants: Vec<_> = Self::applicants()
+            .into_iter()
+            .map(|applicant| {
+                let stake = Self::applicant_stakes(&applicant).total();
+                let revealed: Vec<_> = votes
+                    .iter()
+                    .filter(|vote| vote.get_vote().as_ref() == Some(&applicant))
+                    .collect();
+
+                participation.insert(
+                    applicant.clone(),
+                    ElectionParticipation {
+                        round,
+                        applicant_stake: Some(stake),
+                        elected: new_council.contains_key(&applicant),
+                        ..Default::default()
+                    },
+                );
+
+                ApplicantResult {
+                    revealed_votes: revealed.len() as u32,
+                    revealed_votes_stake: revealed.iter().fold(Zero::zero(), |acc, vote| {
+                        acc.saturating_add(vote.stake.total())
+                    }),
+                    applicant,
+                    stake,
+                }
+            })
+            .collect();
+
+        for vote in votes.iter() {
+            let voter =
+                participation
+                    .entry(vote.voter.clone())
+                    .or_insert_with(|| ElectionParticipation {
+                        round,
+                        ..Default::default()
+                    });
+
+            voter.voting_stake = voter.voting_stake.saturating_add(vote.stake.total());
+            if vote.is_revealed() {
+                voter.revealed_voting_stake = voter
+                    .revealed_voting_stake
+                    .saturating_add(vote.stake.total());
+            }
+        }
+
+        <ElectionResults<T>>::insert(
+            round,
+            ElectionResult {
+                round,
+                ended_at: <system::Module<T>>::block_number(),
+                voting_mode: Self::voting_mode(),
+                force_stopped,
+                applicants,
+                unrevealed_commitments: votes.iter().filter(|vote| !vote.is_revealed()).count()
+                    as u32,
+                elected: new_council.values().cloned().collect(),
+            },
+        );
+
+        for (account, record) in participation {
+            <ParticipationHistory<T>>::insert(account, round, record);
+        }
     }
 
     fn teardown_election(
@@ -496,6 +834,11 @@ impl<T: Trait> Module<T> {
             // TODO critical: shouldn't we slash the stake in such a case? This is the whole idea behid staking on something: people need to decide carefully and be responsible for their bahavior because they can loose their stake
             // See https://github.com/Joystream/substrate-node-joystream/issues/4
             let do_refund = match sealed_vote.get_vote() {
+                Some(_) if <RankedVotes<T>>::contains_key(sealed_vote.commitment) => {
+                    !Self::ranked_votes(sealed_vote.commitment)
+                        .iter()
+                        .any(|applicant| new_council.contains_key(applicant))
+                }
                 Some(applicant) => !new_council.contains_key(&applicant),
                 None => true,
             };
@@ -520,6 +863,7 @@ impl<T: Trait> Module<T> {
     fn clear_votes() {
         for commitment in Self::commitments() {
             <Votes<T>>::remove(commitment);
+            <RankedVotes<T>>::remove(commitment);
         }
         <Commitments<T>>::kill();
     }
@@ -793,6 +1137,52 @@ impl<T: Trait> Module<T> {
         Ok(())
     }
 
+    fn try_reveal_ranked_vote(
+        voter: T::AccountId,
+        commitment: T::Hash,
+        ranking: Vec<T::AccountId>,
+        salt: Vec<u8>,
+    ) -> DispatchResult {
+        ensure!(
+            <Votes<T>>::contains_key(&commitment),
+            "commitment not found"
+        );
+
+        let mut sealed_vote = <Votes<T>>::get(&commitment);
+
+        ensure!(sealed_vote.is_not_revealed(), "vote already revealed");
+        // only voter can reveal their own votes
+        ensure!(sealed_vote.is_owned_by(voter), "only voter can reveal vote");
+        ensure!(!ranking.is_empty(), "ranking cannot be empty");
+        ensure!(
+            ranking
+                .iter()
+                .all(|applicant| <ApplicantStakes<T>>::contains_key(applicant)),
+            "vote for non-applicant not allowed"
+        );
+
+        let unique_applicants: BTreeSet<&T::AccountId> = ranking.iter().collect();
+        ensure!(
+            unique_applicants.len() == ranking.len(),
+            "ranking contains duplicate applicants"
+        );
+
+        let mut salt = salt;
+
+        // Tries to unseal, if salt is invalid will return error
+        sealed_vote.unseal_ranking(
+            ranking.clone(),
+            &mut salt,
+            <T as system::Trait>::Hashing::hash,
+        )?;
+
+        // Update the revealed vote
+        <Votes<T>>::insert(commitment, sealed_vote);
+        <RankedVotes<T>>::insert(commitment, ranking);
+
+        Ok(())
+    }
+
     fn set_verified_election_parameters(params: ElectionParameters<BalanceOf<T>, T::BlockNumber>) {
         <AnnouncingPeriod<T>>::put(params.announcing_period);
         <VotingPeriod<T>>::put(params.voting_period);
@@ -802,6 +1192,7 @@ impl<T: Trait> Module<T> {
         CouncilSize::put(params.council_size);
         CandidacyLimit::put(params.candidacy_limit);
         <MinVotingStake<T>>::put(params.min_voting_stake);
+        ElectionVotingMode::put(params.voting_mode);
     }
 }
 
@@ -873,11 +1264,34 @@ decl_module! {
                 _ => false
             };
             ensure!(is_revealing, "election not in revealing stage");
+            ensure!(Self::voting_mode() == VotingMode::SingleChoice, "election not in single choice voting mode");
 
             Self::try_reveal_vote(sender.clone(), commitment, vote.clone(), salt)?;
             Self::deposit_event(RawEvent::Revealed(sender, commitment, vote));
         }
 
+        /// Reveals the vote committed as a SCALE encoded list of applicants ordered by preference
+        /// (followed by the salt). Available in the ranked choice voting mode only.
+        #[weight = 10_000_000] // TODO: adjust weight
+        fn reveal_ranked(origin, commitment: T::Hash, ranking: Vec<T::AccountId>, salt: Vec<u8>) {
+            let sender = ensure_signed(origin)?;
+
+            ensure!(salt.len() <= 32, "salt too large"); // at most 256 bits salt
+
+            let stage = Self::stage();
+            ensure!(Self::stage().is_some(), "election not running");
+
+            let is_revealing = match stage.unwrap() {
+                ElectionStage::Revealing(_) => true,
+                _ => false
+            };
+            ensure!(is_revealing, "election not in revealing stage");
+            ensure!(Self::voting_mode() == VotingMode::RankedChoice, "election not in ranked choice voting mode");
+
+            Self::try_reveal_ranked_vote(sender.clone(), commitment, ranking.clone(), salt)?;
+            Self::deposit_event(RawEvent::RankingRevealed(sender, commitment, ranking));
+        }
+
         #[weight = 10_000_000] // TODO: adjust weight
         fn set_stage_announcing(origin, ends_at: T::BlockNumber) {
             ensure_root(origin)?;
@@ -925,6 +1339,8 @@ decl_module! {
             // no council gets elected
             let empty_council = BTreeMap::new();
 
+            Self::archive_election(&votes, &empty_council, true);
+
             Self::teardown_election (
                 &votes,
                 &empty_council,
@@ -954,9 +1370,30 @@ impl<T: Trait> council::CouncilTermEnded for Module<T> {
     }
 }
 
+impl<T: Trait> council::CouncilSeatVacated<T::AccountId> for Module<T> {
+    /// Fills the vacated seat with the first runner-up of the last election who is still a member.
+    /// The seat stays vacant until the next election if no runners-up are left.
+    fn council_seat_vacated(_councilor: &T::AccountId) {
+        let mut runners_up = Self::runners_up();
+
+        while !runners_up.is_empty() {
+            let (runner_up, _) = runners_up.remove(0);
+
+            if <membership::Module<T>>::is_member_account(&runner_up)
+                && <council::Module<T>>::fill_vacant_seat(runner_up).is_ok()
+            {
+                break;
+            }
+        }
+
+        <RunnersUp<T>>::put(runners_up);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::council::CouncilSeatVacated;
     use crate::mock::*;
     use codec::Encode;
     use frame_support::traits::OnFinalize;
@@ -1545,6 +1982,91 @@ mod tests {
         });
     }
 
+    fn make_commitment_for_ranking(
+        ranking: &[<Test as system::Trait>::AccountId],
+        salt: &mut Vec<u8>,
+    ) -> <Test as system::Trait>::Hash {
+        let mut payload = ranking.to_vec().encode();
+        payload.append(salt);
+        <Test as system::Trait>::Hashing::hash(&payload[..])
+    }
+
+    #[test]
+    fn revealing_ranked_vote_works() {
+        initial_test_ext().execute_with(|| {
+            let ranking = vec![30 as u64, 20 as u64];
+            let salt = vec![128u8];
+            let commitment = make_commitment_for_ranking(&ranking, &mut salt.clone());
+            let voter = 10 as u64;
+
+            for applicant in ranking.iter() {
+                <ApplicantStakes<Test>>::insert(
+                    applicant,
+                    Stake {
+                        new: 0,
+                        transferred: 0,
+                    },
+                );
+            }
+
+            <Votes<Test>>::insert(
+                &commitment,
+                SealedVote::new(
+                    voter,
+                    Stake {
+                        new: 100,
+                        transferred: 0,
+                    },
+                    commitment,
+                ),
+            );
+
+            assert_ok!(Election::try_reveal_ranked_vote(
+                voter,
+                commitment,
+                ranking.clone(),
+                salt
+            ));
+            assert_eq!(<Votes<Test>>::get(commitment).get_vote().unwrap(), 30);
+            assert_eq!(Election::ranked_votes(commitment), ranking);
+        });
+    }
+
+    #[test]
+    fn revealing_ranking_with_duplicates_should_not_work() {
+        initial_test_ext().execute_with(|| {
+            let ranking = vec![20 as u64, 20 as u64];
+            let salt = vec![128u8];
+            let commitment = make_commitment_for_ranking(&ranking, &mut salt.clone());
+            let voter = 10 as u64;
+
+            <ApplicantStakes<Test>>::insert(
+                20,
+                Stake {
+                    new: 0,
+                    transferred: 0,
+                },
+            );
+
+            <Votes<Test>>::insert(
+                &commitment,
+                SealedVote::new(
+                    voter,
+                    Stake {
+                        new: 100,
+                        transferred: 0,
+                    },
+                    commitment,
+                ),
+            );
+
+            assert_err!(
+                Election::try_reveal_ranked_vote(voter, commitment, ranking, salt),
+                "ranking contains duplicate applicants"
+            );
+        });
+    }
+
     #[test]
     fn revealing_with_bad_salt_should_not_work() {
         initial_test_ext().execute_with(|| {
@@ -1744,6 +2266,83 @@ mod tests {
         });
     }
 
+    #[test]
+    fn ranked_choice_council_election_should_work() {
+        initial_test_ext().execute_with(|| {
+            CouncilSize::put(2);
+
+            <Applicants<Test>>::put(vec![100, 200, 300]);
+            for &(applicant, stake) in [(100, 10), (200, 20), (300, 30)].iter() {
+                <ApplicantStakes<Test>>::insert(
+                    applicant,
+                    Stake {
+                        new: stake,
+                        transferred: 0,
+                    },
+                );
+            }
+
+            // (voter, stake, ranking)
+            let mock = vec![
+                (10, 200, vec![100, 200]),
+                (20, 60, vec![300]),
+                (30, 40, vec![200]),
+            ];
+
+            let votes: Vec<_> = mock
+                .into_iter()
+                .map(|(voter, stake, ranking)| {
+                    let commitment = make_commitment_for_ranking(&ranking, &mut vec![voter as u8]);
+                    <RankedVotes<Test>>::insert(commitment, ranking.clone());
+
+                    SealedVote::new_unsealed(
+                        voter,
+                        Stake {
+                            new: stake,
+                            transferred: 0,
+                        },
+                        commitment,
+                        ranking[0],
+                    )
+                })
+                .collect();
+
+            let new_council = Election::elect_ranked_choice_council(&votes);
+
+            assert_eq!(new_council.len(), 2);
+
+            // 100 reaches the quota (101) and the surplus of the first vote is transferred to 200.
+            assert_eq!(
+                new_council.get(&100).unwrap(),
+                &Seat {
+                    member: 100,
+                    stake: 10,
+                    backers: vec![Backer {
+                        member: 10,
+                        stake: 118,
+                    }],
+                }
+            );
+            assert_eq!(
+                new_council.get(&200).unwrap(),
+                &Seat {
+                    member: 200,
+                    stake: 20,
+                    backers: vec![
+                        Backer {
+                            member: 10,
+                            stake: 82,
+                        },
+                        Backer {
+                            member: 30,
+                            stake: 40,
+                        },
+                    ],
+                }
+            );
+        });
+    }
+
     #[test]
     fn filter_top_staked_applicants_should_work() {
         initial_test_ext().execute_with(|| {
@@ -1965,6 +2564,188 @@ mod tests {
         });
     }
 
+    #[test]
+    fn unelected_applicants_are_ordered_by_priority() {
+        initial_test_ext().execute_with(|| {
+            // 400 applied first
+            <Applicants<Test>>::put(vec![100, 200, 300, 400]);
+            for (applicant, stake) in vec![(100, 20), (200, 10), (300, 50), (400, 20)] {
+                <ApplicantStakes<Test>>::insert(
+                    applicant,
+                    Stake {
+                        new: stake as u64,
+                        transferred: 0 as u64,
+                    },
+                );
+            }
+
+            let mut new_council: BTreeMap<u64, Seat<u64, u64>> = BTreeMap::new();
+            new_council.insert(
+                300 as u64,
+                Seat {
+                    member: 300 as u64,
+                    stake: 50 as u64,
+                    backers: vec![],
+                },
+            );
+
+            assert_eq!(
+                Election::unelected_applicants_by_priority(&new_council),
+                vec![(400, 20), (100, 20), (200, 10)]
+            );
+        });
+    }
+
+    #[test]
+    fn election_results_are_archived() {
+        initial_test_ext().execute_with(|| {
+            Round::put(3);
+            <Applicants<Test>>::put(vec![100, 200]);
+            for (applicant, stake) in vec![(100, 20), (200, 10)] {
+                <ApplicantStakes<Test>>::insert(
+                    applicant,
+                    Stake {
+                        new: stake as u64,
+                        transferred: 0 as u64,
+                    },
+                );
+            }
+
+            let mut votes = mock_votes(vec![
+                //  (voter, stake[new], stake[transferred], applicant)
+                (10, 100, 0, 100),
+                (10, 150, 50, 100),
+                (20, 200, 0, 200),
+            ]);
+            votes.push(SealedVote::new(
+                20,
+                Stake {
+                    new: 30,
+                    transferred: 0,
+                },
+                make_commitment_for_applicant(1, &mut vec![0u8]),
+            ));
+
+            let new_council = Election::tally_votes(&votes);
+            Election::archive_election(&votes, &new_council, false);
+
+            let result = Election::election_results(3).unwrap();
+            assert_eq!(result.round, 3);
+            assert!(!result.force_stopped);
+            assert_eq!(result.unrevealed_commitments, 1);
+            assert_eq!(
+                result.applicants,
+                vec![
+                    ApplicantResult {
+                        applicant: 100,
+                        stake: 20,
+                        revealed_votes: 2,
+                        revealed_votes_stake: 300,
+                    },
+                    ApplicantResult {
+                        applicant: 200,
+                        stake: 10,
+                        revealed_votes: 1,
+                        revealed_votes_stake: 200,
+                    },
+                ]
+            );
+            assert_eq!(result.elected.len(), 2);
+            assert_eq!(result.elected[0].calc_total_stake(), 320);
+
+            assert_eq!(
+                Election::participation_history(&100),
+                vec![ElectionParticipation {
+                    round: 3,
+                    applicant_stake: Some(20),
+                    elected: true,
+                    voting_stake: 0,
+                    revealed_voting_stake: 0,
+                }]
+            );
+            assert_eq!(
+                Election::participation_history(&20),
+                vec![ElectionParticipation {
+                    round: 3,
+                    applicant_stake: None,
+                    elected: false,
+                    voting_stake: 230,
+                    revealed_voting_stake: 200,
+                }]
+            );
+
+            assert_eq!(Election::election_results_page(None, 10), vec![result]);
+            assert!(Election::election_results_page(Some(2), 10).is_empty());
+        });
+    }
+
+    #[test]
+    fn total_voting_stake_includes_unrevealed_votes() {
+        initial_test_ext().execute_with(|| {
+            let mut votes = mock_votes(vec![
+                //  (voter, stake[new], stake[transferred], applicant)
+                (10, 100, 0, 100),
+                (20, 150, 50, 200),
+            ]);
+            votes.push(SealedVote::new(
+                30,
+                Stake {
+                    new: 30,
+                    transferred: 0,
+                },
+                make_commitment_for_applicant(1, &mut vec![0u8]),
+            ));
+
+            assert_eq!(Election::total_voting_stake(&votes), 330);
+        });
+    }
+
+    #[test]
+    fn force_stopped_election_is_archived() {
+        initial_test_ext().execute_with(|| {
+            let applicant = 20 as u64;
+            let _ = Balances::deposit_creating(&applicant, 1000);
+
+            assert_ok!(Election::start_election(vec![]));
+            assert_ok!(Election::try_add_applicant(applicant, 100));
+
+            assert_ok!(Election::force_stop_election(RawOrigin::Root.into()));
+
+            let result = Election::election_results(Election::round()).unwrap();
+            assert!(result.force_stopped);
+            assert!(result.elected.is_empty());
+            assert_eq!(result.applicants.len(), 1);
+            assert_eq!(Election::participation_history(&applicant).len(), 1);
+        });
+    }
+
+    #[test]
+    fn vacated_council_seat_is_filled_by_runner_up() {
+        initial_test_ext().execute_with(|| {
+            assert_ok!(Council::set_council(RawOrigin::Root.into(), vec![1, 2]));
+
+            // 100 is not a member, 2 is a councilor already
+            <RunnersUp<Test>>::put(vec![(100, 30), (2, 20), (3, 20), (4, 10)]);
+
+            <Test as council::Trait>::CouncilSeatVacated::council_seat_vacated(&1);
+
+            assert!(Council::is_councilor(&3));
+            assert_eq!(Council::active_council().len(), 3);
+            assert_eq!(Election::runners_up(), vec![(4, 10)]);
+        });
+    }
+
+    #[test]
+    fn vacated_council_seat_stays_vacant_without_runners_up() {
+        initial_test_ext().execute_with(|| {
+            assert_ok!(Council::set_council(RawOrigin::Root.into(), vec![2]));
+
+            <Test as council::Trait>::CouncilSeatVacated::council_seat_vacated(&1);
+
+            assert_eq!(Council::active_council().len(), 1);
+        });
+    }
+
     #[test]
     fn council_elected_hook_should_work() {
         initial_test_ext().execute_with(|| {
@@ -2120,6 +2901,7 @@ mod tests {
                 min_voting_stake: 6,
                 min_council_stake: 7,
                 new_term_duration: 8,
+                voting_mode: VotingMode::RankedChoice,
             };
 
             assert_ok!(Election::set_election_parameters(
@@ -2150,6 +2932,7 @@ mod tests {
                 <MinVotingStake<Test>>::get(),
                 new_parameters.min_voting_stake
             );
+            assert_eq!(ElectionVotingMode::get(), new_parameters.voting_mode);
         });
     }
 }
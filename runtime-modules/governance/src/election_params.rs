@@ -12,6 +12,23 @@ pub static MSG_COUNCIL_SIZE_CANNOT_BE_ZERO: &str = "CouncilSizeCannotBeZero";
 pub static MSG_CANDIDACY_LIMIT_WAS_LOWER_THAN_COUNCIL_SIZE: &str =
     "CandidacyWasLessThanCouncilSize";
 
+/// Defines how the votes are revealed and tallied.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Encode, Decode, PartialEq, Eq, Debug)]
+pub enum VotingMode {
+    /// Each vote is revealed for a single applicant. Top staked applicants form the council.
+    SingleChoice,
+    /// Each vote is revealed as a list of applicants ordered by preference. The council is
+    /// elected using the single transferable vote method.
+    RankedChoice,
+}
+
+impl Default for VotingMode {
+    fn default() -> Self {
+        VotingMode::SingleChoice
+    }
+}
+
 /// Combined Election parameters, as argument for set_election_parameters
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Clone, Copy, Encode, Decode, Default, PartialEq, Debug)]
@@ -24,6 +41,39 @@ pub struct ElectionParameters<Balance, BlockNumber> {
     pub new_term_duration: BlockNumber,
     pub min_council_stake: Balance,
     pub min_voting_stake: Balance,
+    pub voting_mode: VotingMode,
+}
+
+/// 'ElectionParameters' of the previous runtime versions without the voting mode. Used by the
+/// storage migrations of the stored proposals.
+#[derive(Clone, Copy, Encode, Decode, Default, PartialEq, Debug)]
+pub struct LegacyElectionParameters<Balance, BlockNumber> {
+    pub announcing_period: BlockNumber,
+    pub voting_period: BlockNumber,
+    pub revealing_period: BlockNumber,
+    pub council_size: u32,
+    pub candidacy_limit: u32,
+    pub new_term_duration: BlockNumber,
+    pub min_council_stake: Balance,
+    pub min_voting_stake: Balance,
+}
+
+impl<Balance, BlockNumber> LegacyElectionParameters<Balance, BlockNumber> {
+    /// Converts the legacy parameters, keeping the single choice voting of the previous runtime
+    /// versions.
+    pub fn migrate(self) -> ElectionParameters<Balance, BlockNumber> {
+        ElectionParameters {
+            announcing_period: self.announcing_period,
+            voting_period: self.voting_period,
+            revealing_period: self.revealing_period,
+            council_size: self.council_size,
+            candidacy_limit: self.candidacy_limit,
+            new_term_duration: self.new_term_duration,
+            min_council_stake: self.min_council_stake,
+            min_voting_stake: self.min_voting_stake,
+            voting_mode: VotingMode::SingleChoice,
+        }
+    }
 }
 
 impl<Balance, BlockNumber: PartialOrd + Zero> ElectionParameters<Balance, BlockNumber> {
@@ -7,6 +7,7 @@ pub mod election_params;
 
 mod sealed_vote;
 mod stake;
+mod stv;
 
 mod mock;
 
@@ -8,7 +8,7 @@ use sp_core::H256;
 use sp_runtime::{
     testing::Header,
     traits::{BlakeTwo256, IdentityLookup},
-    BuildStorage, Perbill,
+    BuildStorage, Perbill, Percent,
 };
 pub use system;
 
@@ -59,10 +59,21 @@ impl pallet_timestamp::Trait for Test {
     type OnTimestampSet = ();
     type MinimumPeriod = MinimumPeriod;
 }
+parameter_types! {
+    pub const RecallPeriod: u64 = 10;
+    pub const RecallThreshold: Percent = Percent::from_percent(50);
+    pub const MinRecallStake: u64 = 10;
+    pub const MaxRecallBackers: u32 = 2;
+}
 impl council::Trait for Test {
     type Event = ();
 
     type CouncilTermEnded = (Election,);
+    type CouncilSeatVacated = (Election,);
+    type RecallPeriod = RecallPeriod;
+    type RecallThreshold = RecallThreshold;
+    type MinRecallStake = MinRecallStake;
+    type MaxRecallBackers = MaxRecallBackers;
 }
 impl election::Trait for Test {
     type Event = ();
@@ -75,12 +86,21 @@ impl membership::Trait for Test {
     type SubscriptionId = u32;
     type PaidTermId = u32;
     type ActorId = u32;
+    type WeightInfo = ();
+}
+parameter_types! {
+    pub const MintSpendingEpochLength: u64 = 100;
 }
+
 impl minting::Trait for Test {
     type Currency = Balances;
     type MintId = u64;
+    type SpendingEpochLength = MintSpendingEpochLength;
+    type MintCapacityIncreased = ();
+    type WeightInfo = ();
 }
 impl recurringrewards::Trait for Test {
+    type Event = ();
     type PayoutStatusHandler = ();
     type RecipientId = u64;
     type RewardRelationshipId = u64;
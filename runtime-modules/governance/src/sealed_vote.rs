@@ -70,6 +70,34 @@ where
         }
     }
 
+    /// Unseals the vote committed as a list of choices ordered by preference. The first choice
+    /// becomes the revealed vote.
+    pub fn unseal_ranking(
+        &mut self,
+        ranking: Vec<Vote>,
+        salt: &mut Vec<u8>,
+        hasher: fn(&[u8]) -> Hash,
+    ) -> Result<(), &'static str>
+    where
+        Vote: Clone,
+    {
+        // only unseal once
+        ensure!(self.is_not_revealed(), "vote already unsealed");
+
+        let first_choice = ranking.first().cloned().ok_or("empty ranking")?;
+
+        // seralize the ranking and append the salt
+        let mut payload = ranking.encode();
+        payload.append(salt);
+
+        if self.commitment == hasher(&payload) {
+            self.vote = Some(first_choice);
+            Ok(())
+        } else {
+            Err("invalid salt")
+        }
+    }
+
     pub fn get_vote(&self) -> &Option<Vote> {
         &self.vote
     }
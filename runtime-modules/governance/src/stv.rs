@@ -0,0 +1,183 @@
+//! Single transferable vote (STV) counting used by the ranked choice voting mode.
+//!
+//! Ballot weights are the voting stakes. A candidate reaching the Droop quota is elected and the
+//! surplus of the ballots counting for the candidate is transferred to their next preferences
+//! (fractional transfer). If no candidate reaches the quota the candidate with the lowest tally
+//! is eliminated. Once the number of the remaining candidates is not greater than the number of
+//! the vacant seats all of them are elected.
+
+use sp_arithmetic::helpers_128bit::multiply_by_rational;
+use sp_std::vec;
+use sp_std::vec::Vec;
+
+/// Ranked ballot: candidates in the order of preference and the ballot weight.
+#[derive(Clone, PartialEq, Debug)]
+pub struct RankedBallot<AccountId> {
+    pub preferences: Vec<AccountId>,
+    pub weight: u128,
+}
+
+/// Result of the STV count.
+#[derive(Clone, PartialEq, Debug)]
+pub struct StvResult<AccountId> {
+    /// Elected candidates in the order of election.
+    pub elected: Vec<AccountId>,
+    /// Weights each ballot contributed to the elected candidates (in the ballots order).
+    pub backing: Vec<Vec<(AccountId, u128)>>,
+}
+
+/// Fills `seats` seats from the `candidates` according to the ranked `ballots`.
+/// Candidates must be ordered by priority: ties are resolved in favour of the candidates
+/// appearing earlier in the list.
+pub fn elect<AccountId: Clone + PartialEq>(
+    candidates: &[AccountId],
+    ballots: &[RankedBallot<AccountId>],
+    seats: usize,
+) -> StvResult<AccountId> {
+    let total_weight = ballots
+        .iter()
+        .fold(0u128, |acc, ballot| acc.saturating_add(ballot.weight));
+    let quota = total_weight / (seats as u128 + 1) + 1;
+
+    let mut hopefuls = candidates.to_vec();
+    let mut elected = Vec::new();
+    let mut values: Vec<u128> = ballots.iter().map(|ballot| ballot.weight).collect();
+    let mut backing = vec![Vec::new(); ballots.len()];
+
+    while elected.len() < seats && !hopefuls.is_empty() {
+        // Index of the hopeful each ballot currently counts for.
+        let current: Vec<Option<usize>> = ballots
+            .iter()
+            .map(|ballot| {
+                ballot
+                    .preferences
+                    .iter()
+                    .find_map(|preference| hopefuls.iter().position(|h| h == preference))
+            })
+            .collect();
+
+        let mut tallies = vec![0u128; hopefuls.len()];
+        for (ballot_index, hopeful_index) in current.iter().enumerate() {
+            if let Some(hopeful_index) = hopeful_index {
+                tallies[*hopeful_index] =
+                    tallies[*hopeful_index].saturating_add(values[ballot_index]);
+            }
+        }
+
+        if hopefuls.len() <= seats - elected.len() {
+            // Every remaining hopeful gets a seat backed by the ballots counting for them.
+            for (ballot_index, hopeful_index) in current.iter().enumerate() {
+                if let Some(hopeful_index) = hopeful_index {
+                    backing[ballot_index]
+                        .push((hopefuls[*hopeful_index].clone(), values[ballot_index]));
+                    values[ballot_index] = 0;
+                }
+            }
+
+            elected.append(&mut hopefuls);
+            break;
+        }
+
+        let best = (1..tallies.len()).fold(0, |best, index| {
+            if tallies[index] > tallies[best] {
+                index
+            } else {
+                best
+            }
+        });
+
+        if tallies[best] >= quota {
+            let tally = tallies[best];
+
+            for (ballot_index, hopeful_index) in current.iter().enumerate() {
+                if *hopeful_index == Some(best) {
+                    // Keep the quota share of the ballot value, transfer the rest.
+                    let kept = multiply_by_rational(values[ballot_index], quota, tally)
+                        .unwrap_or(values[ballot_index]);
+
+                    backing[ballot_index].push((hopefuls[best].clone(), kept));
+                    values[ballot_index] -= kept;
+                }
+            }
+
+            elected.push(hopefuls.remove(best));
+        } else {
+            let worst = (1..tallies.len()).fold(0, |worst, index| {
+                if tallies[index] <= tallies[worst] {
+                    index
+                } else {
+                    worst
+                }
+            });
+
+            hopefuls.remove(worst);
+        }
+    }
+
+    StvResult { elected, backing }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ballot(preferences: Vec<u64>, weight: u128) -> RankedBallot<u64> {
+        RankedBallot {
+            preferences,
+            weight,
+        }
+    }
+
+    #[test]
+    fn all_candidates_are_elected_when_there_are_enough_seats() {
+        let result = elect(&[1, 2], &[ballot(vec![2], 100)], 3);
+
+        assert_eq!(result.elected, vec![1, 2]);
+        assert_eq!(result.backing, vec![vec![(2, 100)]]);
+    }
+
+    #[test]
+    fn surplus_is_transferred_to_the_next_preference() {
+        // quota = 300 / 3 + 1 = 101
+        let ballots = vec![
+            ballot(vec![1, 2], 200),
+            ballot(vec![3], 60),
+            ballot(vec![4], 40),
+        ];
+
+        let result = elect(&[1, 2, 3, 4], &ballots, 2);
+
+        // 1 is elected with the surplus of 99 transferred to 2, which then beats 3 and 4.
+        assert_eq!(result.elected, vec![1, 2]);
+        assert_eq!(result.backing[0], vec![(1, 101), (2, 99)]);
+        assert!(result.backing[1].is_empty());
+        assert!(result.backing[2].is_empty());
+    }
+
+    #[test]
+    fn lowest_tally_candidate_is_eliminated() {
+        // quota = 142 / 2 + 1 = 72
+        let ballots = vec![
+            ballot(vec![1], 70),
+            ballot(vec![2], 42),
+            ballot(vec![3, 2], 30),
+        ];
+
+        let result = elect(&[1, 2, 3], &ballots, 1);
+
+        // 3 is eliminated and its ballot is transferred to 2, which reaches the quota.
+        assert_eq!(result.elected, vec![2]);
+        assert!(result.backing[0].is_empty());
+        assert_eq!(result.backing[1], vec![(2, 42)]);
+        assert_eq!(result.backing[2], vec![(2, 30)]);
+    }
+
+    #[test]
+    fn ties_are_resolved_by_candidates_priority() {
+        let ballots = vec![ballot(vec![1], 50), ballot(vec![2], 50)];
+
+        let result = elect(&[2, 1], &ballots, 1);
+
+        assert_eq!(result.elected, vec![2]);
+    }
+}
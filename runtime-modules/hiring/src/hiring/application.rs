@@ -101,6 +101,22 @@ impl<OpeningId, BlockNumber, StakeId: PartialEq + Clone>
     }
 }
 
+/// Score attached to an application by a reviewer in a review round.
+#[derive(Encode, Decode, Default, Debug, Eq, PartialEq, Clone)]
+pub struct ApplicationScore<AccountId> {
+    /// Review round the score was attached in.
+    pub round: u32,
+
+    /// Account of the reviewer.
+    pub reviewer: AccountId,
+
+    /// Score value.
+    pub score: u32,
+
+    /// Short review note.
+    pub note: Vec<u8>,
+}
+
 /// Possible status of an application
 #[derive(Encode, Decode, Debug, Eq, PartialEq, Clone, PartialOrd, Ord)]
 pub enum ApplicationStage<BlockNumber> {
@@ -152,6 +168,9 @@ pub enum ApplicationDeactivationCause {
 
     /// Opening was filled
     OpeningFilled,
+
+    /// Application was not shortlisted for the next review round
+    NotShortlisted,
 }
 
 /// OpeningStage must be default constructible because it indirectly is a value in a storage map.
@@ -119,6 +119,41 @@ pub enum BeginReviewError {
 
     /// Opening is not in acception application active stage
     OpeningNotInAcceptingApplicationsStage,
+
+    /// Opening application deadline has not been reached yet
+    ApplicationDeadlineNotReached,
+}
+
+/// Error of the set_opening_review() API method.
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub enum SetOpeningReviewError {
+    /// Opening does not exist
+    OpeningDoesNotExist,
+
+    /// Opening review period has already begun
+    OpeningReviewAlreadyBegun,
+
+    /// Number of the review rounds cannot be zero
+    ZeroReviewRounds,
+
+    /// Number of the reviewers exceeds the limit
+    TooManyReviewers,
+}
+
+/// Error of the score_application() API method.
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub enum ScoreApplicationError {
+    /// Application does not exist
+    ApplicationDoesNotExist,
+
+    /// Application is not in active stage
+    ApplicationNotActive,
+
+    /// Opening is not in review period
+    OpeningNotInReviewPeriodStage,
+
+    /// Review note is too long
+    NoteTooLong,
 }
 
 /// Error of the begin_accepting_application() API method.
@@ -157,4 +192,10 @@ pub enum AddOpeningError {
 
     /// It is not possible to stake zero.
     StakeAmountCannotBeZero(StakePurpose),
+
+    /// Application deadline must be after the opening activation.
+    ApplicationDeadlineNotAfterActivation,
+
+    /// It is not possible to provide zero 'max_hires' parameter.
+    ZeroMaxHires,
 }
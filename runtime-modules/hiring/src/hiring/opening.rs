@@ -382,6 +382,56 @@ pub enum OpeningDeactivationCause {
     Filled,
 }
 
+/// Review rounds of an opening. Each round advances a shortlist of the applications, the opening
+/// can be filled in the last round only.
+#[derive(Encode, Decode, Debug, Eq, PartialEq, Clone)]
+pub struct OpeningReview<AccountId: Ord> {
+    /// Number of the review rounds.
+    pub review_rounds: u32,
+
+    /// Current review round, starts with 1.
+    pub current_round: u32,
+
+    /// Accounts delegated to score the applications besides the opener.
+    pub reviewers: BTreeSet<AccountId>,
+}
+
+impl<AccountId: Ord> OpeningReview<AccountId> {
+    /// Whether the opening is in the last review round.
+    pub fn is_last_round(&self) -> bool {
+        self.current_round >= self.review_rounds
+    }
+}
+
+/// Openings without the review configured have a single review round.
+impl<AccountId: Ord> Default for OpeningReview<AccountId> {
+    fn default() -> Self {
+        OpeningReview {
+            review_rounds: 1,
+            current_round: 1,
+            reviewers: BTreeSet::new(),
+        }
+    }
+}
+
+/// Application deadline and capacity of an opening committed on its creation.
+#[derive(Encode, Decode, Default, Debug, Eq, PartialEq, Clone)]
+pub struct OpeningLimits<BlockNumber> {
+    /// Block after which no applications are accepted, the review period begins automatically
+    /// at this block.
+    pub application_deadline: Option<BlockNumber>,
+
+    /// Maximum number of the applications that can be hired when filling the opening.
+    pub max_hires: Option<u32>,
+}
+
+impl<BlockNumber> OpeningLimits<BlockNumber> {
+    /// Whether the opening declares any limits.
+    pub fn is_set(&self) -> bool {
+        self.application_deadline.is_some() || self.max_hires.is_some()
+    }
+}
+
 /// Defines the moment of the opening activation.
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Encode, Decode, Eq, PartialEq, Clone, Debug)]
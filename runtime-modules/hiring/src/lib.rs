@@ -8,6 +8,9 @@
 //! - cancel_opening
 //! - fill_opening
 //! - begin_review
+//! - set_opening_review
+//! - score_application
+//! - advance_review_round
 //! - begin_acception_application
 //! - unstaked
 //!
@@ -25,7 +28,7 @@ use mockall::predicate::*;
 use mockall::*;
 
 use codec::Codec;
-use frame_support::storage::IterableStorageMap;
+use frame_support::storage::{IterableStorageDoubleMap, IterableStorageMap};
 use frame_support::traits::{Currency, Imbalance};
 use frame_support::{decl_module, decl_storage, ensure, Parameter};
 use sp_arithmetic::traits::{BaseArithmetic, One, Zero};
@@ -47,6 +50,12 @@ mod test;
 
 pub use hiring::*;
 
+/// Maximum number of the reviewers delegated to score the applications of an opening.
+pub const MAX_OPENING_REVIEWERS: usize = 20;
+
+/// Maximum length of the review note attached to an application score.
+pub const MAX_REVIEW_NOTE_LENGTH: usize = 1024;
+
 /// Main trait of hiring substrate module
 pub trait Trait: system::Trait + stake::Trait + Sized {
     /// OpeningId type
@@ -95,6 +104,23 @@ decl_storage! {
         /// Internal purpose of given stake, i.e. fro what application, and whether for the role or for the application.
         pub ApplicationIdByStakingId get(fn stake_purpose_by_staking_id): map hasher(blake2_128_concat)
             T::StakeId => T::ApplicationId;
+
+        /// Review rounds and delegated reviewers of the opening.
+        pub OpeningReviewById get(fn opening_review_by_id): map hasher(blake2_128_concat)
+            T::OpeningId => OpeningReview<T::AccountId>;
+
+        /// Scores attached to the application by the reviewers.
+        pub ApplicationScoresById get(fn application_scores_by_id): map hasher(blake2_128_concat)
+            T::ApplicationId => Vec<ApplicationScore<T::AccountId>>;
+
+        /// Application deadline and maximum number of hires of the opening.
+        pub OpeningLimitsById get(fn opening_limits_by_id): map hasher(blake2_128_concat)
+            T::OpeningId => OpeningLimits<T::BlockNumber>;
+
+        /// Openings accepting applications by their application deadline block. Only the openings
+        /// due are visited when finalizing the block.
+        pub OpeningIdsByApplicationDeadline get(fn opening_ids_by_application_deadline):
+            double_map hasher(twox_64_concat) T::BlockNumber, hasher(twox_64_concat) T::OpeningId => ();
     }
 }
 
@@ -118,6 +144,17 @@ decl_module! {
                 <OpeningById<T>>::insert(opening_id, opening_accepting_applications);
             }
 
+            // Change opening from Active::AcceptingApplications stage to Active::ReviewPeriod stage
+            for (opening_id, opening, started_accepting_applicants_at_block) in Self::openings_reached_application_deadline_iterator(now) {
+                let opening_in_review_period = opening.clone_with_new_active_opening_stage(
+                    hiring::ActiveOpeningStage::ReviewPeriod {
+                        started_accepting_applicants_at_block,
+                        started_review_period_at_block: now
+                });
+
+                <OpeningById<T>>::insert(opening_id, opening_in_review_period);
+            }
+
             // Deactivate opening
             for (opening_id,
                 opening,
@@ -171,16 +208,25 @@ impl<T: Trait> Module<T> {
     /// The new Opening instance has stage WaitingToBegin, and is added to openingsById,
     /// and has identifier equal to nextOpeningId.
     /// The latter is incremented. The used identifier is returned.
+    /// Optional application deadline and maximum number of hires are saved to openingLimitsById.
+    #[allow(clippy::too_many_arguments)]
     pub fn add_opening(
         activate_at: ActivateOpeningAt<T::BlockNumber>,
         max_review_period_length: T::BlockNumber,
         application_rationing_policy: Option<ApplicationRationingPolicy>,
         application_staking_policy: Option<StakingPolicy<BalanceOf<T>, T::BlockNumber>>,
         role_staking_policy: Option<StakingPolicy<BalanceOf<T>, T::BlockNumber>>,
+        application_deadline: Option<T::BlockNumber>,
+        max_hires: Option<u32>,
         human_readable_text: Vec<u8>,
     ) -> Result<T::OpeningId, AddOpeningError> {
         let current_block_height = <system::Module<T>>::block_number();
 
+        let opening_limits = OpeningLimits {
+            application_deadline,
+            max_hires,
+        };
+
         Self::ensure_can_add_opening(
             current_block_height,
             activate_at.clone(),
@@ -188,6 +234,7 @@ impl<T: Trait> Module<T> {
             application_rationing_policy.clone(),
             application_staking_policy.clone(),
             role_staking_policy.clone(),
+            opening_limits.clone(),
         )?;
 
         // == MUTATION SAFE ==
@@ -208,6 +255,16 @@ impl<T: Trait> Module<T> {
         // Insert opening in storage
         <OpeningById<T>>::insert(new_opening_id, new_opening);
 
+        // Schedule the review period beginning at the application deadline
+        if let Some(application_deadline) = opening_limits.application_deadline {
+            <OpeningIdsByApplicationDeadline<T>>::insert(application_deadline, new_opening_id, ());
+        }
+
+        // Insert opening limits in storage
+        if opening_limits.is_set() {
+            <OpeningLimitsById<T>>::insert(new_opening_id, opening_limits);
+        }
+
         // Update NextOpeningId counter
         <NextOpeningId<T>>::mutate(|id| *id += T::OpeningId::one());
 
@@ -276,6 +333,8 @@ impl<T: Trait> Module<T> {
 
         OpeningById::<T>::insert(opening_id, new_opening);
 
+        Self::unschedule_application_deadline(opening_id);
+
         // Map with applications
         let applications_map = Self::application_id_iter_to_map(applications_added.iter());
 
@@ -350,12 +409,22 @@ impl<T: Trait> Module<T> {
             BeginReviewError::OpeningNotInAcceptingApplicationsStage
         )?;
 
+        let current_block_height = <system::Module<T>>::block_number();
+
+        // Applications are accepted until the committed deadline
+        if let Some(application_deadline) =
+            Self::opening_limits_by_id(opening_id).application_deadline
+        {
+            ensure!(
+                current_block_height >= application_deadline,
+                BeginReviewError::ApplicationDeadlineNotReached
+            );
+        }
+
         //
         // == MUTATION SAFE ==
         //
 
-        let current_block_height = <system::Module<T>>::block_number();
-
         let new_opening =
             opening.clone_with_new_active_opening_stage(hiring::ActiveOpeningStage::ReviewPeriod {
                 started_accepting_applicants_at_block,
@@ -365,9 +434,206 @@ impl<T: Trait> Module<T> {
         // Update to new opening
         <OpeningById<T>>::insert(opening_id, new_opening);
 
+        Self::unschedule_application_deadline(opening_id);
+
+        Ok(())
+    }
+
+    /// Sets the number of the review rounds and the delegated reviewers of the opening.
+    /// Applies when given opening has not begun the review period yet.
+    pub fn set_opening_review(
+        opening_id: T::OpeningId,
+        review_rounds: u32,
+        reviewers: BTreeSet<T::AccountId>,
+    ) -> Result<(), SetOpeningReviewError> {
+        // Ensure that the opening exists
+        let opening =
+            ensure_opening_exists!(T, opening_id, SetOpeningReviewError::OpeningDoesNotExist)?;
+
+        // Opening is waiting to begin or accepting applications
+        if let hiring::OpeningStage::Active { ref stage, .. } = opening.stage {
+            stage.ensure_active_opening_is_accepting_applications(
+                SetOpeningReviewError::OpeningReviewAlreadyBegun,
+            )?;
+        }
+
+        ensure!(review_rounds > 0, SetOpeningReviewError::ZeroReviewRounds);
+
+        ensure!(
+            reviewers.len() <= MAX_OPENING_REVIEWERS,
+            SetOpeningReviewError::TooManyReviewers
+        );
+
+        //
+        // == MUTATION SAFE ==
+        //
+
+        <OpeningReviewById<T>>::insert(
+            opening_id,
+            OpeningReview {
+                review_rounds,
+                current_round: 1,
+                reviewers,
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Attaches the score and the review note of the reviewer to an active application of an
+    /// opening in the review period. Replaces the score attached by the reviewer in the current
+    /// review round.
+    pub fn score_application(
+        application_id: T::ApplicationId,
+        reviewer: T::AccountId,
+        score: u32,
+        note: Vec<u8>,
+    ) -> Result<(), ScoreApplicationError> {
+        // Check that application id is valid, and if so,
+        // grab corresponding application and opening.
+        let (application, opening) = ensure_application_exists!(
+            T,
+            application_id,
+            ScoreApplicationError::ApplicationDoesNotExist,
+            auto_fetch_opening
+        )?;
+
+        // Application is active
+        ensure_eq!(
+            application.stage,
+            hiring::ApplicationStage::Active,
+            ScoreApplicationError::ApplicationNotActive
+        );
+
+        // Opening is in review period
+        let (active_stage, ..) = ensure_opening_is_active!(
+            opening.stage,
+            ScoreApplicationError::OpeningNotInReviewPeriodStage
+        )?;
+
+        active_stage.ensure_active_opening_is_in_review_period(
+            ScoreApplicationError::OpeningNotInReviewPeriodStage,
+        )?;
+
+        ensure!(
+            note.len() <= MAX_REVIEW_NOTE_LENGTH,
+            ScoreApplicationError::NoteTooLong
+        );
+
+        //
+        // == MUTATION SAFE ==
+        //
+
+        let round = Self::opening_review_by_id(application.opening_id).current_round;
+
+        <ApplicationScoresById<T>>::mutate(application_id, |scores| {
+            scores.retain(|score| score.round != round || score.reviewer != reviewer);
+
+            scores.push(ApplicationScore {
+                round,
+                reviewer,
+                score,
+                note,
+            });
+        });
+
+        Ok(())
+    }
+
+    /// Advances an opening, identified with `opening_id`, currently in the review period to the
+    /// next review round. Active applications not in the shortlist are deactivated with the
+    /// NotShortlisted cause, their stakes are unstaked using the review period unstaking periods
+    /// of the staking policies.
+    pub fn advance_review_round(
+        opening_id: T::OpeningId,
+        shortlist: BTreeSet<T::ApplicationId>,
+    ) -> Result<(), AdvanceReviewRoundError<T>> {
+        // Ensure that the opening exists
+        let opening =
+            ensure_opening_exists!(T, opening_id, AdvanceReviewRoundError::OpeningDoesNotExist)?;
+
+        let (active_stage, applications_added, _, _, _) = ensure_opening_is_active!(
+            opening.stage,
+            AdvanceReviewRoundError::OpeningNotInReviewPeriodStage
+        )?;
+
+        // Ensure opening is in review period
+        active_stage.ensure_active_opening_is_in_review_period(
+            AdvanceReviewRoundError::OpeningNotInReviewPeriodStage,
+        )?;
+
+        let mut review = Self::opening_review_by_id(opening_id);
+
+        ensure!(
+            !review.is_last_round(),
+            AdvanceReviewRoundError::NoReviewRoundsLeft
+        );
+
+        ensure!(
+            !shortlist.is_empty(),
+            AdvanceReviewRoundError::EmptyShortlist
+        );
+
+        // Ensure that all shortlisted applications exist, are active and associated with the opening
+        for application_id in &shortlist {
+            let application = ensure_application_exists!(
+                T,
+                *application_id,
+                AdvanceReviewRoundError::ApplicationDoesNotExist(*application_id)
+            )?;
+
+            ensure_eq!(
+                application.stage,
+                hiring::ApplicationStage::Active,
+                AdvanceReviewRoundError::ApplicationNotInActiveStage(*application_id)
+            );
+
+            ensure_eq!(
+                application.opening_id,
+                opening_id,
+                AdvanceReviewRoundError::ApplicationForWrongOpening(*application_id)
+            );
+        }
+
+        //
+        // == MUTATION SAFE ==
+        //
+
+        // Get unstaking periods
+        let application_stake_unstaking_period =
+            StakingPolicy::opt_staking_policy_to_review_period_expired_unstaking_period(
+                &opening.application_staking_policy,
+            );
+        let role_stake_unstaking_period =
+            StakingPolicy::opt_staking_policy_to_review_period_expired_unstaking_period(
+                &opening.role_staking_policy,
+            );
+
+        // Deactivate all applications not in the shortlist
+        let dropped_applications_map =
+            Self::application_id_iter_to_map(applications_added.difference(&shortlist));
+
+        Self::initiate_application_deactivations(
+            &dropped_applications_map,
+            application_stake_unstaking_period,
+            role_stake_unstaking_period,
+            hiring::ApplicationDeactivationCause::NotShortlisted,
+        );
+
+        review.current_round += 1;
+
+        <OpeningReviewById<T>>::insert(opening_id, review);
+
         Ok(())
     }
 
+    /// Whether the account is delegated to score the applications of the opening.
+    pub fn is_opening_reviewer(opening_id: T::OpeningId, account_id: &T::AccountId) -> bool {
+        Self::opening_review_by_id(opening_id)
+            .reviewers
+            .contains(account_id)
+    }
+
     /// Fill an opening, identified with `opening_id`, currently in the review period.
     /// Applies when given opening is in ReviewPeriod stage.
     /// Given list of applications are deactivated to under the Hired,
@@ -396,6 +662,20 @@ impl<T: Trait> Module<T> {
                 FillOpeningError::OpeningNotInReviewPeriodStage,
             )?;
 
+        // Ensure all review rounds have passed
+        ensure!(
+            Self::opening_review_by_id(opening_id).is_last_round(),
+            FillOpeningError::OpeningNotInLastReviewRound
+        );
+
+        // Ensure the number of hires respects the opening limit
+        if let Some(max_hires) = Self::opening_limits_by_id(opening_id).max_hires {
+            ensure!(
+                successful_applications.len() <= max_hires as usize,
+                FillOpeningError::MaxHiresExceeded
+            );
+        }
+
         //
         // Ensure that all unstaking periods are neither too short (0) nor redundant.
         //
@@ -832,8 +1112,9 @@ impl<T: Trait> Module<T> {
 
 /// Handles application deactivation with a cause
 pub trait ApplicationDeactivatedHandler<T: Trait> {
-    /// An application, with the given id, was fully deactivated, with the
-    /// given cause, and was put in the inactive state.
+    /// An application, with the given id, was deactivated with the given cause.
+    /// Called when the deactivation is initiated and, for the staked applications,
+    /// once more when the unstaking completes and the application is put in the inactive state.
     fn deactivated(application_id: &T::ApplicationId, cause: hiring::ApplicationDeactivationCause);
 }
 
@@ -875,6 +1156,37 @@ pub enum FillOpeningError<T: Trait> {
 
     /// Application is not for the opening
     ApplicationForWrongOpening(T::ApplicationId),
+
+    /// Opening is not in the last review round
+    OpeningNotInLastReviewRound,
+
+    /// Number of the successful applications exceeds the maximum number of hires
+    MaxHiresExceeded,
+}
+
+/// Error due to attempting to advance the opening review round.
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub enum AdvanceReviewRoundError<T: Trait> {
+    /// Opening does not exist
+    OpeningDoesNotExist,
+
+    /// Opening is not in review period
+    OpeningNotInReviewPeriodStage,
+
+    /// Opening is in the last review round already
+    NoReviewRoundsLeft,
+
+    /// Shortlist cannot be empty
+    EmptyShortlist,
+
+    /// Application does not exist
+    ApplicationDoesNotExist(T::ApplicationId),
+
+    /// Application is not in active stage
+    ApplicationNotInActiveStage(T::ApplicationId),
+
+    /// Application is not for the opening
+    ApplicationForWrongOpening(T::ApplicationId),
 }
 
 /// Product of ensure_can_add_application()
@@ -1015,6 +1327,50 @@ impl<T: Trait> Module<T> {
         })
     }
 
+    // Compute iterator of openings accepting applications with the application deadline reached.
+    // The openings due are removed from the schedule.
+    fn openings_reached_application_deadline_iterator(
+        now: T::BlockNumber,
+    ) -> impl Iterator<
+        Item = (
+            T::OpeningId,
+            Opening<BalanceOf<T>, T::BlockNumber, T::ApplicationId>,
+            T::BlockNumber,
+        ),
+    > {
+        let opening_ids = <OpeningIdsByApplicationDeadline<T>>::iter_prefix(now)
+            .map(|(opening_id, _)| opening_id)
+            .collect::<Vec<_>>();
+
+        <OpeningIdsByApplicationDeadline<T>>::remove_prefix(now);
+
+        opening_ids.into_iter().filter_map(|opening_id| {
+            let opening = <OpeningById<T>>::get(opening_id);
+
+            if let hiring::OpeningStage::Active {
+                stage:
+                    hiring::ActiveOpeningStage::AcceptingApplications {
+                        started_accepting_applicants_at_block,
+                    },
+                ..
+            } = opening.stage
+            {
+                Some((opening_id, opening, started_accepting_applicants_at_block))
+            } else {
+                None
+            }
+        })
+    }
+
+    // Removes the opening from the openings waiting for the application deadline
+    fn unschedule_application_deadline(opening_id: T::OpeningId) {
+        if let Some(application_deadline) =
+            Self::opening_limits_by_id(opening_id).application_deadline
+        {
+            <OpeningIdsByApplicationDeadline<T>>::remove(application_deadline, opening_id);
+        }
+    }
+
     // Compute iterator of openings in expired review period
     fn openings_expired_review_period_iterator(
         now: T::BlockNumber,
@@ -1213,9 +1569,7 @@ impl<T: Trait> Module<T> {
                 });
 
                 // Call handler(s)
-                if was_unstaked {
-                    T::ApplicationDeactivatedHandler::deactivated(&application_id, cause);
-                }
+                T::ApplicationDeactivatedHandler::deactivated(&application_id, cause);
 
                 // Return conclusion
                 if was_unstaked {
@@ -1412,6 +1766,7 @@ impl<T: Trait> Module<T> {
         application_rationing_policy: Option<ApplicationRationingPolicy>,
         application_staking_policy: Option<StakingPolicy<BalanceOf<T>, T::BlockNumber>>,
         role_staking_policy: Option<StakingPolicy<BalanceOf<T>, T::BlockNumber>>,
+        opening_limits: OpeningLimits<T::BlockNumber>,
     ) -> Result<(), AddOpeningError> {
         // Check that exact activation is actually in the future
         ensure!(
@@ -1422,6 +1777,23 @@ impl<T: Trait> Module<T> {
             AddOpeningError::OpeningMustActivateInTheFuture
         );
 
+        // Check that applications are accepted for at least one block
+        if let Some(application_deadline) = opening_limits.application_deadline {
+            let activation_block = match activate_at {
+                ActivateOpeningAt::ExactBlock(block_number) => block_number,
+                ActivateOpeningAt::CurrentBlock => current_block_height,
+            };
+
+            ensure!(
+                application_deadline > activation_block,
+                AddOpeningError::ApplicationDeadlineNotAfterActivation
+            );
+        }
+
+        if let Some(max_hires) = opening_limits.max_hires {
+            ensure!(max_hires > 0, AddOpeningError::ZeroMaxHires);
+        }
+
         if let Some(app_rationing_policy) = application_rationing_policy {
             ensure!(
                 app_rationing_policy.max_active_applicants > 0,
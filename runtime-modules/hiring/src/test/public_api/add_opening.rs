@@ -16,6 +16,8 @@ pub struct AddOpeningFixture<Balance> {
     pub application_rationing_policy: Option<ApplicationRationingPolicy>,
     pub application_staking_policy: Option<StakingPolicy<Balance, BlockNumber>>,
     pub role_staking_policy: Option<StakingPolicy<Balance, BlockNumber>>,
+    pub application_deadline: Option<BlockNumber>,
+    pub max_hires: Option<u32>,
     pub human_readable_text: Vec<u8>,
 }
 
@@ -27,6 +29,8 @@ impl<Balance> Default for AddOpeningFixture<Balance> {
             application_rationing_policy: None,
             application_staking_policy: None,
             role_staking_policy: None,
+            application_deadline: None,
+            max_hires: None,
             human_readable_text: HUMAN_READABLE_TEXT.to_vec(),
         }
     }
@@ -85,6 +89,16 @@ impl AddOpeningFixture<OpeningId> {
 
         let found_opening = Hiring::opening_by_id(expected_opening_id);
         assert_eq!(found_opening, expected_opening);
+
+        let expected_opening_limits = OpeningLimits {
+            application_deadline: self.application_deadline,
+            max_hires: self.max_hires,
+        };
+
+        assert_eq!(
+            Hiring::opening_limits_by_id(expected_opening_id),
+            expected_opening_limits
+        );
     }
 
     pub(crate) fn add_opening(&self) -> Result<OpeningId, AddOpeningError> {
@@ -94,6 +108,8 @@ impl AddOpeningFixture<OpeningId> {
             self.application_rationing_policy.clone(),
             self.application_staking_policy.clone(),
             self.role_staking_policy.clone(),
+            self.application_deadline,
+            self.max_hires,
             self.human_readable_text.clone(),
         )
     }
@@ -224,3 +240,41 @@ fn add_opening_succeeds_or_fails_due_to_invalid_application_rationing_policy() {
         opening_data.call_and_assert(Err(AddOpeningError::ApplicationRationingZeroMaxApplicants));
     });
 }
+
+#[test]
+fn add_opening_succeeds_with_opening_limits() {
+    build_test_externalities().execute_with(|| {
+        let mut opening_data = AddOpeningFixture::default();
+        opening_data.activate_at = ActivateOpeningAt::ExactBlock(22);
+        opening_data.application_deadline = Some(30);
+        opening_data.max_hires = Some(2);
+
+        opening_data.call_and_assert(Ok(0));
+    });
+}
+
+#[test]
+fn add_opening_fails_due_to_application_deadline_not_after_activation() {
+    build_test_externalities().execute_with(|| {
+        let mut opening_data = AddOpeningFixture::default();
+        opening_data.activate_at = ActivateOpeningAt::ExactBlock(22);
+        opening_data.application_deadline = Some(22);
+
+        opening_data.call_and_assert(Err(AddOpeningError::ApplicationDeadlineNotAfterActivation));
+
+        opening_data.activate_at = ActivateOpeningAt::CurrentBlock;
+        opening_data.application_deadline = Some(FIRST_BLOCK_HEIGHT);
+
+        opening_data.call_and_assert(Err(AddOpeningError::ApplicationDeadlineNotAfterActivation));
+    });
+}
+
+#[test]
+fn add_opening_fails_due_to_zero_max_hires() {
+    build_test_externalities().execute_with(|| {
+        let mut opening_data = AddOpeningFixture::default();
+        opening_data.max_hires = Some(0);
+
+        opening_data.call_and_assert(Err(AddOpeningError::ZeroMaxHires));
+    });
+}
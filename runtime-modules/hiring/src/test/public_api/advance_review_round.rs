@@ -0,0 +1,138 @@
+use crate::mock::*;
+use crate::test::*;
+
+use crate::test::public_api::*;
+use sp_std::collections::btree_set::BTreeSet;
+
+fn add_opening_with_applications(
+    review_rounds: u32,
+    application_count: usize,
+) -> (OpeningId, Vec<ApplicationId>) {
+    let opening_id = AddOpeningFixture::default().add_opening().unwrap();
+
+    assert!(Hiring::set_opening_review(opening_id, review_rounds, BTreeSet::new()).is_ok());
+
+    let application_fixture = AddApplicationFixture::default_for_opening(opening_id);
+    let application_ids = (0..application_count)
+        .map(|_| {
+            application_fixture
+                .add_application()
+                .unwrap()
+                .application_id_added
+        })
+        .collect();
+
+    (opening_id, application_ids)
+}
+
+#[test]
+fn advance_review_round_fails_with_no_opening() {
+    build_test_externalities().execute_with(|| {
+        assert_eq!(
+            Hiring::advance_review_round(2, BTreeSet::new()),
+            Err(AdvanceReviewRoundError::OpeningDoesNotExist)
+        );
+    });
+}
+
+#[test]
+fn advance_review_round_fails_with_opening_not_in_review_period() {
+    build_test_externalities().execute_with(|| {
+        let (opening_id, application_ids) = add_opening_with_applications(2, 1);
+
+        assert_eq!(
+            Hiring::advance_review_round(opening_id, application_ids.into_iter().collect()),
+            Err(AdvanceReviewRoundError::OpeningNotInReviewPeriodStage)
+        );
+    });
+}
+
+#[test]
+fn advance_review_round_fails_in_last_round() {
+    build_test_externalities().execute_with(|| {
+        let (opening_id, application_ids) = add_opening_with_applications(1, 1);
+
+        assert!(Hiring::begin_review(opening_id).is_ok());
+
+        assert_eq!(
+            Hiring::advance_review_round(opening_id, application_ids.into_iter().collect()),
+            Err(AdvanceReviewRoundError::NoReviewRoundsLeft)
+        );
+    });
+}
+
+#[test]
+fn advance_review_round_fails_with_invalid_shortlist() {
+    build_test_externalities().execute_with(|| {
+        let (opening_id, _) = add_opening_with_applications(2, 1);
+        let (_, other_application_ids) = add_opening_with_applications(2, 1);
+
+        assert!(Hiring::begin_review(opening_id).is_ok());
+
+        assert_eq!(
+            Hiring::advance_review_round(opening_id, BTreeSet::new()),
+            Err(AdvanceReviewRoundError::EmptyShortlist)
+        );
+
+        let mut shortlist = BTreeSet::new();
+        shortlist.insert(100);
+        assert_eq!(
+            Hiring::advance_review_round(opening_id, shortlist),
+            Err(AdvanceReviewRoundError::ApplicationDoesNotExist(100))
+        );
+
+        let other_application_id = other_application_ids[0];
+        assert_eq!(
+            Hiring::advance_review_round(opening_id, other_application_ids.into_iter().collect()),
+            Err(AdvanceReviewRoundError::ApplicationForWrongOpening(
+                other_application_id
+            ))
+        );
+    });
+}
+
+#[test]
+fn advance_review_round_succeeds() {
+    build_test_externalities().execute_with(|| {
+        let (opening_id, application_ids) = add_opening_with_applications(2, 3);
+
+        assert!(Hiring::begin_review(opening_id).is_ok());
+
+        // Opening cannot be filled before the last review round
+        let mut fill_opening_fixture = FillOpeningFixture::default_for_opening(opening_id);
+        fill_opening_fixture.successful_applications =
+            vec![application_ids[0]].into_iter().collect();
+        assert_eq!(
+            fill_opening_fixture.fill_opening(),
+            Err(FillOpeningError::OpeningNotInLastReviewRound)
+        );
+
+        let shortlist: BTreeSet<_> = vec![application_ids[0], application_ids[1]]
+            .into_iter()
+            .collect();
+        assert_eq!(Hiring::advance_review_round(opening_id, shortlist), Ok(()));
+
+        assert_eq!(Hiring::opening_review_by_id(opening_id).current_round, 2);
+        assert_eq!(
+            <ApplicationById<Test>>::get(application_ids[0]).stage,
+            ApplicationStage::Active
+        );
+        assert_eq!(
+            <ApplicationById<Test>>::get(application_ids[2]).stage,
+            ApplicationStage::Inactive {
+                deactivation_initiated: FIRST_BLOCK_HEIGHT,
+                deactivated: FIRST_BLOCK_HEIGHT,
+                cause: ApplicationDeactivationCause::NotShortlisted,
+            }
+        );
+
+        // Scores are attached in the current round
+        assert!(Hiring::score_application(application_ids[0], 10, 5, Vec::new()).is_ok());
+        assert_eq!(
+            Hiring::application_scores_by_id(application_ids[0])[0].round,
+            2
+        );
+
+        assert_eq!(fill_opening_fixture.fill_opening(), Ok(()));
+    });
+}
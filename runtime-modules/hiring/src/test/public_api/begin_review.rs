@@ -1,6 +1,8 @@
 use crate::mock::*;
 use crate::test::*;
 
+use frame_support::StorageDoubleMap;
+
 #[test]
 fn begin_review_fails_with_no_opening() {
     build_test_externalities().execute_with(|| {
@@ -53,3 +55,39 @@ fn begin_review_succeeds() {
         assert_eq!(updated_opening, expected_opening_state);
     });
 }
+
+#[test]
+fn begin_review_fails_before_application_deadline() {
+    build_test_externalities().execute_with(|| {
+        let mut opening_fixture = AddOpeningFixture::default();
+        opening_fixture.application_deadline = Some(FIRST_BLOCK_HEIGHT + 10);
+
+        let add_opening_result = opening_fixture.add_opening();
+        let opening_id = add_opening_result.unwrap();
+
+        assert_eq!(
+            Hiring::begin_review(opening_id),
+            Err(BeginReviewError::ApplicationDeadlineNotReached)
+        );
+    });
+}
+
+#[test]
+fn begin_review_on_application_deadline_unschedules_opening() {
+    build_test_externalities().execute_with(|| {
+        let application_deadline = FIRST_BLOCK_HEIGHT + 10;
+        let mut opening_fixture = AddOpeningFixture::default();
+        opening_fixture.application_deadline = Some(application_deadline);
+
+        let add_opening_result = opening_fixture.add_opening();
+        let opening_id = add_opening_result.unwrap();
+
+        System::set_block_number(application_deadline);
+
+        assert_eq!(Hiring::begin_review(opening_id), Ok(()));
+        assert!(!<OpeningIdsByApplicationDeadline<Test>>::contains_key(
+            application_deadline,
+            opening_id
+        ));
+    });
+}
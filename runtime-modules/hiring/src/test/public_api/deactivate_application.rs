@@ -166,6 +166,29 @@ fn deactivate_application_succeeds() {
     });
 }
 
+#[test]
+fn deactivate_application_calls_handler_for_non_staked_application() {
+    build_test_externalities().execute_with(|| {
+        let opening_fixture = AddOpeningFixture::default();
+        let add_opening_result = opening_fixture.add_opening();
+        let opening_id = add_opening_result.unwrap();
+
+        let application_fixture = AddApplicationFixture::default_for_opening(opening_id);
+        let app_application_result = application_fixture.add_application();
+        let application_id = app_application_result.unwrap().application_id_added;
+
+        let deactivate_application_fixture =
+            DeactivateApplicationFixture::default_for_application_id(application_id);
+
+        deactivate_application_fixture.call_and_assert(Ok(()));
+
+        TestApplicationDeactivatedHandler::assert_deactivated_application(
+            application_id,
+            ApplicationDeactivationCause::External,
+        );
+    });
+}
+
 #[test]
 fn deactivate_application_fails_with_no_application() {
     build_test_externalities().execute_with(|| {
@@ -472,3 +472,28 @@ fn fill_opening_fails_with_application_to_wrong_opening() {
         )));
     });
 }
+
+#[test]
+fn fill_opening_fails_due_to_max_hires_exceeded() {
+    build_test_externalities().execute_with(|| {
+        let mut opening_fixture = AddOpeningFixture::default();
+        opening_fixture.max_hires = Some(1);
+
+        let add_opening_result = opening_fixture.add_opening();
+        let opening_id = add_opening_result.unwrap();
+
+        let application_fixture = AddApplicationFixture::default_for_opening(opening_id);
+        let first_app_result = application_fixture.add_application();
+        let second_app_result = application_fixture.add_application();
+
+        let mut apps = BTreeSet::new();
+        apps.insert(first_app_result.unwrap().application_id_added);
+        apps.insert(second_app_result.unwrap().application_id_added);
+
+        assert!(Hiring::begin_review(opening_id).is_ok());
+
+        let mut fill_opening_fixture = FillOpeningFixture::default_for_opening(opening_id);
+        fill_opening_fixture.successful_applications = apps;
+        fill_opening_fixture.call_and_assert(Err(FillOpeningError::MaxHiresExceeded));
+    });
+}
@@ -1,5 +1,6 @@
 mod add_application;
 mod add_opening;
+mod advance_review_round;
 mod deactivate_application;
 
 mod begin_accepting_applications;
@@ -8,6 +9,8 @@ mod cancel_opening;
 mod ensure_can_add_application;
 mod fill_opening;
 mod on_finalize;
+mod score_application;
+mod set_opening_review;
 mod unstaked;
 
 pub use add_application::AddApplicationFixture;
@@ -2,6 +2,7 @@ use crate::mock::*;
 use crate::test::*;
 
 use frame_support::traits::{OnFinalize, OnInitialize};
+use frame_support::StorageDoubleMap;
 
 // Recommendation from Parity on testing on_finalize
 // https://substrate.dev/docs/en/next/development/module/tests
@@ -175,3 +176,39 @@ fn on_finalize_should_deactivate_application_with_review_period_expired_cause()
         }
     });
 }
+
+#[test]
+fn on_finalize_should_begin_review_on_application_deadline() {
+    build_test_externalities().execute_with(|| {
+        let application_deadline = FIRST_BLOCK_HEIGHT + 3;
+        let mut opening_fixture = AddOpeningFixture::default();
+        opening_fixture.application_deadline = Some(application_deadline);
+        let add_opening_result = opening_fixture.add_opening();
+        let opening_id = add_opening_result.unwrap();
+
+        assert!(<OpeningIdsByApplicationDeadline<Test>>::contains_key(
+            application_deadline,
+            opening_id
+        ));
+
+        let old_opening = <OpeningById<Test>>::get(opening_id);
+
+        run_to_block_and_finalize(application_deadline - 1);
+
+        assert_eq!(<OpeningById<Test>>::get(opening_id), old_opening);
+
+        run_to_block_and_finalize(application_deadline);
+
+        let expected_opening =
+            old_opening.clone_with_new_active_opening_stage(ActiveOpeningStage::ReviewPeriod {
+                started_accepting_applicants_at_block: FIRST_BLOCK_HEIGHT,
+                started_review_period_at_block: application_deadline,
+            });
+
+        assert_eq!(<OpeningById<Test>>::get(opening_id), expected_opening);
+        assert!(!<OpeningIdsByApplicationDeadline<Test>>::contains_key(
+            application_deadline,
+            opening_id
+        ));
+    });
+}
@@ -0,0 +1,93 @@
+use crate::mock::*;
+use crate::test::*;
+
+use crate::test::public_api::*;
+
+#[test]
+fn score_application_fails_with_no_application() {
+    build_test_externalities().execute_with(|| {
+        assert_eq!(
+            Hiring::score_application(2, 10, 5, Vec::new()),
+            Err(ScoreApplicationError::ApplicationDoesNotExist)
+        );
+    });
+}
+
+#[test]
+fn score_application_fails_with_opening_not_in_review_period() {
+    build_test_externalities().execute_with(|| {
+        let opening_id = AddOpeningFixture::default().add_opening().unwrap();
+        let application_id = AddApplicationFixture::default_for_opening(opening_id)
+            .add_application()
+            .unwrap()
+            .application_id_added;
+
+        assert_eq!(
+            Hiring::score_application(application_id, 10, 5, Vec::new()),
+            Err(ScoreApplicationError::OpeningNotInReviewPeriodStage)
+        );
+    });
+}
+
+#[test]
+fn score_application_fails_with_too_long_note() {
+    build_test_externalities().execute_with(|| {
+        let opening_id = AddOpeningFixture::default().add_opening().unwrap();
+        let application_id = AddApplicationFixture::default_for_opening(opening_id)
+            .add_application()
+            .unwrap()
+            .application_id_added;
+
+        assert!(Hiring::begin_review(opening_id).is_ok());
+
+        assert_eq!(
+            Hiring::score_application(application_id, 10, 5, vec![0u8; MAX_REVIEW_NOTE_LENGTH + 1]),
+            Err(ScoreApplicationError::NoteTooLong)
+        );
+    });
+}
+
+#[test]
+fn score_application_succeeds() {
+    build_test_externalities().execute_with(|| {
+        let opening_id = AddOpeningFixture::default().add_opening().unwrap();
+        let application_id = AddApplicationFixture::default_for_opening(opening_id)
+            .add_application()
+            .unwrap()
+            .application_id_added;
+
+        assert!(Hiring::begin_review(opening_id).is_ok());
+
+        assert_eq!(
+            Hiring::score_application(application_id, 10, 5, b"first".to_vec()),
+            Ok(())
+        );
+        assert_eq!(
+            Hiring::score_application(application_id, 11, 7, b"second".to_vec()),
+            Ok(())
+        );
+        // Rescoring replaces the score of the reviewer in the round
+        assert_eq!(
+            Hiring::score_application(application_id, 10, 8, b"third".to_vec()),
+            Ok(())
+        );
+
+        assert_eq!(
+            Hiring::application_scores_by_id(application_id),
+            vec![
+                ApplicationScore {
+                    round: 1,
+                    reviewer: 11,
+                    score: 7,
+                    note: b"second".to_vec(),
+                },
+                ApplicationScore {
+                    round: 1,
+                    reviewer: 10,
+                    score: 8,
+                    note: b"third".to_vec(),
+                },
+            ]
+        );
+    });
+}
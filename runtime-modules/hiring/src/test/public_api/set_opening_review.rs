@@ -0,0 +1,74 @@
+use crate::mock::*;
+use crate::test::*;
+
+use sp_std::collections::btree_set::BTreeSet;
+
+#[test]
+fn set_opening_review_fails_with_no_opening() {
+    build_test_externalities().execute_with(|| {
+        assert_eq!(
+            Hiring::set_opening_review(2, 2, BTreeSet::new()),
+            Err(SetOpeningReviewError::OpeningDoesNotExist)
+        );
+    });
+}
+
+#[test]
+fn set_opening_review_fails_with_invalid_parameters() {
+    build_test_externalities().execute_with(|| {
+        let opening_id = AddOpeningFixture::default().add_opening().unwrap();
+
+        assert_eq!(
+            Hiring::set_opening_review(opening_id, 0, BTreeSet::new()),
+            Err(SetOpeningReviewError::ZeroReviewRounds)
+        );
+
+        let reviewers = (0..=MAX_OPENING_REVIEWERS as u64).collect();
+        assert_eq!(
+            Hiring::set_opening_review(opening_id, 2, reviewers),
+            Err(SetOpeningReviewError::TooManyReviewers)
+        );
+    });
+}
+
+#[test]
+fn set_opening_review_fails_with_review_begun() {
+    build_test_externalities().execute_with(|| {
+        let opening_id = AddOpeningFixture::default().add_opening().unwrap();
+
+        assert!(Hiring::begin_review(opening_id).is_ok());
+
+        assert_eq!(
+            Hiring::set_opening_review(opening_id, 2, BTreeSet::new()),
+            Err(SetOpeningReviewError::OpeningReviewAlreadyBegun)
+        );
+    });
+}
+
+#[test]
+fn set_opening_review_succeeds() {
+    build_test_externalities().execute_with(|| {
+        let mut opening_fixture = AddOpeningFixture::default();
+        opening_fixture.activate_at = ActivateOpeningAt::ExactBlock(22);
+        let opening_id = opening_fixture.add_opening().unwrap();
+
+        assert_eq!(Hiring::opening_review_by_id(opening_id).review_rounds, 1);
+
+        let reviewers: BTreeSet<_> = vec![10, 11].into_iter().collect();
+        assert_eq!(
+            Hiring::set_opening_review(opening_id, 3, reviewers.clone()),
+            Ok(())
+        );
+
+        assert_eq!(
+            Hiring::opening_review_by_id(opening_id),
+            OpeningReview {
+                review_rounds: 3,
+                current_round: 1,
+                reviewers,
+            }
+        );
+        assert!(Hiring::is_opening_reviewer(opening_id, &10));
+        assert!(!Hiring::is_opening_reviewer(opening_id, &12));
+    });
+}
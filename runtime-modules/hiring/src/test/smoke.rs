@@ -41,6 +41,8 @@ fn full_hiring_workflow_successful_path() {
                 application_rationing_policy,
                 application_staking_policy,
                 role_staking_policy,
+                None,
+                None,
                 human_readable_text.clone()
             ),
             Ok(expected_opening_id)
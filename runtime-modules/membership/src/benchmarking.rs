@@ -0,0 +1,221 @@
+//! Membership module benchmarking.
+
+use super::*;
+use frame_benchmarking::{account, benchmarks};
+use system::RawOrigin;
+
+const SEED: u32 = 0;
+
+// Creates a unique handle of the given length. Unique part is the encoded next member id.
+fn handle<T: Trait>(len: u32) -> Vec<u8> {
+    let mut handle = Module::<T>::members_created().encode();
+    handle.resize(len as usize, b'h');
+
+    handle
+}
+
+fn text(len: u32) -> Vec<u8> {
+    vec![b'x'; len as usize]
+}
+
+fn member<T: Trait>(index: u32) -> (T::AccountId, T::MemberId) {
+    let account_id: T::AccountId = account("member", index, SEED);
+
+    let user_info = ValidatedUserInfo {
+        handle: handle::<T>(DEFAULT_MAX_HANDLE_LENGTH),
+        avatar_uri: text(DEFAULT_MAX_AVATAR_URI_LENGTH),
+        about: text(DEFAULT_MAX_ABOUT_TEXT_LENGTH),
+    };
+
+    let member_id = Module::<T>::insert_member(
+        &account_id,
+        &account_id,
+        &user_info,
+        EntryMethod::Genesis,
+        <system::Module<T>>::block_number(),
+        <pallet_timestamp::Module<T>>::now(),
+    )
+    .unwrap();
+
+    (account_id, member_id)
+}
+
+benchmarks! {
+    _ { }
+
+    buy_membership {
+        let h in DEFAULT_MIN_HANDLE_LENGTH .. DEFAULT_MAX_HANDLE_LENGTH;
+        let a in 0 .. DEFAULT_MAX_AVATAR_URI_LENGTH;
+        let t in 0 .. DEFAULT_MAX_ABOUT_TEXT_LENGTH;
+
+        let caller: T::AccountId = account("caller", 0, SEED);
+        let paid_terms_id = T::PaidTermId::from(DEFAULT_PAID_TERM_ID);
+        let fee = Module::<T>::paid_membership_terms_by_id(paid_terms_id).fee;
+        T::Currency::make_free_balance_be(&caller, fee + BalanceOf::<T>::from(1_000_000u32));
+
+        let handle = handle::<T>(h);
+    }: _(RawOrigin::Signed(caller), paid_terms_id, Some(handle.clone()), Some(text(a)), Some(text(t)))
+    verify {
+        assert!(<MemberIdByHandle<T>>::contains_key(handle));
+    }
+
+    change_member_about_text {
+        let t in 0 .. DEFAULT_MAX_ABOUT_TEXT_LENGTH;
+
+        let (account_id, member_id) = member::<T>(0);
+    }: _(RawOrigin::Signed(account_id), member_id, text(t))
+    verify {
+        assert_eq!(Module::<T>::membership(member_id).about, text(t));
+    }
+
+    change_member_avatar {
+        let a in 0 .. DEFAULT_MAX_AVATAR_URI_LENGTH;
+
+        let (account_id, member_id) = member::<T>(0);
+    }: _(RawOrigin::Signed(account_id), member_id, text(a))
+    verify {
+        assert_eq!(Module::<T>::membership(member_id).avatar_uri, text(a));
+    }
+
+    change_member_handle {
+        let h in DEFAULT_MIN_HANDLE_LENGTH .. DEFAULT_MAX_HANDLE_LENGTH;
+
+        let (account_id, member_id) = member::<T>(0);
+        let handle = handle::<T>(h);
+    }: _(RawOrigin::Signed(account_id), member_id, handle.clone())
+    verify {
+        assert_eq!(Module::<T>::membership(member_id).handle, handle);
+    }
+
+    update_membership {
+        let h in DEFAULT_MIN_HANDLE_LENGTH .. DEFAULT_MAX_HANDLE_LENGTH;
+        let a in 0 .. DEFAULT_MAX_AVATAR_URI_LENGTH;
+        let t in 0 .. DEFAULT_MAX_ABOUT_TEXT_LENGTH;
+
+        let (account_id, member_id) = member::<T>(0);
+        let handle = handle::<T>(h);
+    }: _(RawOrigin::Signed(account_id), member_id, Some(handle.clone()), Some(text(a)), Some(text(t)))
+    verify {
+        assert_eq!(Module::<T>::membership(member_id).handle, handle);
+    }
+
+    set_controller_account {
+        let (account_id, member_id) = member::<T>(0);
+        let new_controller_account: T::AccountId = account("controller", 0, SEED);
+    }: _(RawOrigin::Signed(account_id), member_id, new_controller_account.clone())
+    verify {
+        assert_eq!(
+            Module::<T>::membership(member_id).controller_account,
+            new_controller_account
+        );
+    }
+
+    set_root_account {
+        let (account_id, member_id) = member::<T>(0);
+        let new_root_account: T::AccountId = account("root", 0, SEED);
+    }: _(RawOrigin::Signed(account_id), member_id, new_root_account.clone())
+    verify {
+        assert_eq!(Module::<T>::membership(member_id).root_account, new_root_account);
+    }
+
+    add_screened_member {
+        let h in DEFAULT_MIN_HANDLE_LENGTH .. DEFAULT_MAX_HANDLE_LENGTH;
+        let a in 0 .. DEFAULT_MAX_AVATAR_URI_LENGTH;
+        let t in 0 .. DEFAULT_MAX_ABOUT_TEXT_LENGTH;
+
+        let screening_authority: T::AccountId = account("screener", 0, SEED);
+        <ScreeningAuthority<T>>::put(screening_authority.clone());
+
+        let new_member_account: T::AccountId = account("member", 0, SEED);
+        let handle = handle::<T>(h);
+    }: _(
+        RawOrigin::Signed(screening_authority),
+        new_member_account,
+        Some(handle.clone()),
+        Some(text(a)),
+        Some(text(t))
+    )
+    verify {
+        assert!(<MemberIdByHandle<T>>::contains_key(handle));
+    }
+
+    set_screening_authority {
+        let screening_authority: T::AccountId = account("screener", 0, SEED);
+    }: _(RawOrigin::Root, screening_authority.clone())
+    verify {
+        assert_eq!(Module::<T>::screening_authority(), screening_authority);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::{Test, TestExternalitiesBuilder};
+
+    fn build_test_externalities() -> sp_io::TestExternalities {
+        TestExternalitiesBuilder::<Test>::default().build()
+    }
+
+    #[test]
+    fn buy_membership_benchmark_succeeds() {
+        build_test_externalities().execute_with(|| {
+            assert!(test_benchmark_buy_membership::<Test>().is_ok());
+        });
+    }
+
+    #[test]
+    fn change_member_about_text_benchmark_succeeds() {
+        build_test_externalities().execute_with(|| {
+            assert!(test_benchmark_change_member_about_text::<Test>().is_ok());
+        });
+    }
+
+    #[test]
+    fn change_member_avatar_benchmark_succeeds() {
+        build_test_externalities().execute_with(|| {
+            assert!(test_benchmark_change_member_avatar::<Test>().is_ok());
+        });
+    }
+
+    #[test]
+    fn change_member_handle_benchmark_succeeds() {
+        build_test_externalities().execute_with(|| {
+            assert!(test_benchmark_change_member_handle::<Test>().is_ok());
+        });
+    }
+
+    #[test]
+    fn update_membership_benchmark_succeeds() {
+        build_test_externalities().execute_with(|| {
+            assert!(test_benchmark_update_membership::<Test>().is_ok());
+        });
+    }
+
+    #[test]
+    fn set_controller_account_benchmark_succeeds() {
+        build_test_externalities().execute_with(|| {
+            assert!(test_benchmark_set_controller_account::<Test>().is_ok());
+        });
+    }
+
+    #[test]
+    fn set_root_account_benchmark_succeeds() {
+        build_test_externalities().execute_with(|| {
+            assert!(test_benchmark_set_root_account::<Test>().is_ok());
+        });
+    }
+
+    #[test]
+    fn add_screened_member_benchmark_succeeds() {
+        build_test_externalities().execute_with(|| {
+            assert!(test_benchmark_add_screened_member::<Test>().is_ok());
+        });
+    }
+
+    #[test]
+    fn set_screening_authority_benchmark_succeeds() {
+        build_test_externalities().execute_with(|| {
+            assert!(test_benchmark_set_screening_authority::<Test>().is_ok());
+        });
+    }
+}
@@ -5,12 +5,15 @@
 // Example:  pub PaidMembershipTermsById get(paid_membership_terms_by_id) build(|config: &GenesisConfig<T>| {}
 #![allow(clippy::redundant_closure_call)]
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
 pub mod genesis;
 pub(crate) mod mock;
 mod tests;
 
 use codec::{Codec, Decode, Encode};
 use frame_support::traits::Currency;
+use frame_support::weights::Weight;
 use frame_support::{decl_event, decl_module, decl_storage, ensure, Parameter};
 use sp_arithmetic::traits::{BaseArithmetic, One};
 use sp_runtime::traits::{MaybeSerialize, Member};
@@ -25,9 +28,57 @@ use common::currency::{BalanceOf, GovernanceCurrency};
 /// Result with string error message. This exists for backward compatibility purpose.
 pub type DispatchResult = Result<(), &'static str>;
 
+/// Weight information for the membership extrinsics. Length parameters are the byte lengths of
+/// the handle, avatar uri and about text inputs.
+pub trait WeightInfo {
+    fn buy_membership(h: u32, a: u32, t: u32) -> Weight;
+    fn change_member_about_text(t: u32) -> Weight;
+    fn change_member_avatar(a: u32) -> Weight;
+    fn change_member_handle(h: u32) -> Weight;
+    fn update_membership(h: u32, a: u32, t: u32) -> Weight;
+    fn set_controller_account() -> Weight;
+    fn set_root_account() -> Weight;
+    fn add_screened_member(h: u32, a: u32, t: u32) -> Weight;
+    fn set_screening_authority() -> Weight;
+}
+
+/// Default weights, used by the tests.
+impl WeightInfo for () {
+    fn buy_membership(_h: u32, _a: u32, _t: u32) -> Weight {
+        10_000_000
+    }
+    fn change_member_about_text(_t: u32) -> Weight {
+        10_000_000
+    }
+    fn change_member_avatar(_a: u32) -> Weight {
+        10_000_000
+    }
+    fn change_member_handle(_h: u32) -> Weight {
+        10_000_000
+    }
+    fn update_membership(_h: u32, _a: u32, _t: u32) -> Weight {
+        10_000_000
+    }
+    fn set_controller_account() -> Weight {
+        10_000_000
+    }
+    fn set_root_account() -> Weight {
+        10_000_000
+    }
+    fn add_screened_member(_h: u32, _a: u32, _t: u32) -> Weight {
+        10_000_000
+    }
+    fn set_screening_authority() -> Weight {
+        10_000_000
+    }
+}
+
 pub trait Trait: system::Trait + GovernanceCurrency + pallet_timestamp::Trait {
     type Event: From<Event<Self>> + Into<<Self as system::Trait>::Event>;
 
+    /// Weight information for the extrinsics of this module.
+    type WeightInfo: WeightInfo;
+
     type MemberId: Parameter
         + Member
         + BaseArithmetic
@@ -260,7 +311,11 @@ decl_module! {
         fn deposit_event() = default;
 
         /// Non-members can buy membership
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = T::WeightInfo::buy_membership(
+            optional_text_len(handle),
+            optional_text_len(avatar_uri),
+            optional_text_len(about),
+        )]
         pub fn buy_membership(
             origin,
             paid_terms_id: T::PaidTermId,
@@ -296,7 +351,7 @@ decl_module! {
         }
 
         /// Change member's about text
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = T::WeightInfo::change_member_about_text(text.len() as u32)]
         pub fn change_member_about_text(origin, member_id: T::MemberId, text: Vec<u8>) {
             let sender = ensure_signed(origin)?;
 
@@ -308,7 +363,7 @@ decl_module! {
         }
 
         /// Change member's avatar
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = T::WeightInfo::change_member_avatar(uri.len() as u32)]
         pub fn change_member_avatar(origin, member_id: T::MemberId, uri: Vec<u8>) {
             let sender = ensure_signed(origin)?;
 
@@ -321,7 +376,7 @@ decl_module! {
 
         /// Change member's handle. Will ensure new handle is unique and old one will be available
         /// for other members to use.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = T::WeightInfo::change_member_handle(handle.len() as u32)]
         pub fn change_member_handle(origin, member_id: T::MemberId, handle: Vec<u8>) {
             let sender = ensure_signed(origin)?;
 
@@ -333,7 +388,11 @@ decl_module! {
         }
 
         /// Update member's all or some of handle, avatar and about text.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = T::WeightInfo::update_membership(
+            optional_text_len(handle),
+            optional_text_len(avatar_uri),
+            optional_text_len(about),
+        )]
         pub fn update_membership(
             origin,
             member_id: T::MemberId,
@@ -358,7 +417,7 @@ decl_module! {
             }
         }
 
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = T::WeightInfo::set_controller_account()]
         pub fn set_controller_account(origin, member_id: T::MemberId, new_controller_account: T::AccountId) {
             let sender = ensure_signed(origin)?;
 
@@ -382,7 +441,7 @@ decl_module! {
             }
         }
 
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = T::WeightInfo::set_root_account()]
         pub fn set_root_account(origin, member_id: T::MemberId, new_root_account: T::AccountId) {
             let sender = ensure_signed(origin)?;
 
@@ -406,7 +465,11 @@ decl_module! {
             }
         }
 
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = T::WeightInfo::add_screened_member(
+            optional_text_len(handle),
+            optional_text_len(avatar_uri),
+            optional_text_len(about),
+        )]
         pub fn add_screened_member(
             origin,
             new_member_account: T::AccountId,
@@ -441,7 +504,7 @@ decl_module! {
             Self::deposit_event(RawEvent::MemberRegistered(member_id, new_member_account));
         }
 
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = T::WeightInfo::set_screening_authority()]
         pub fn set_screening_authority(origin, authority: T::AccountId) {
             ensure_root(origin)?;
             <ScreeningAuthority<T>>::put(authority);
@@ -449,6 +512,11 @@ decl_module! {
     }
 }
 
+// Returns the byte length of the optional text input.
+fn optional_text_len(text: &Option<Vec<u8>>) -> u32 {
+    text.as_ref().map_or(0, |text| text.len() as u32)
+}
+
 /// Reason why a given member id does not have a given account as the controller account.
 pub enum ControllerAccountForMemberCheckFailed {
     NotMember,
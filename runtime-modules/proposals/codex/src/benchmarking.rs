@@ -0,0 +1,557 @@
+//! Proposals codex module benchmarking.
+
+use super::*;
+use codec::Encode;
+use frame_benchmarking::{account, benchmarks};
+use governance::election_params::VotingMode;
+use sp_runtime::traits::Hash;
+use sp_std::vec;
+use system::RawOrigin;
+
+const SEED: u32 = 0;
+
+// Upper bound for the benchmarked text inputs limited only on the proposal execution.
+const MAX_BYTES: u32 = 5_000;
+
+fn text(len: u32) -> Vec<u8> {
+    vec![b'x'; len as usize]
+}
+
+// Proposal title is also the discussion thread title.
+fn max_title_length<T: Trait>() -> u32 {
+    T::TitleMaxLength::get().min(T::ThreadTitleLengthLimit::get())
+}
+
+// Creates a funded member with the same root and controller account, valid as a proposer.
+fn proposer<T: Trait>() -> (T::AccountId, MemberId<T>) {
+    let account_id: T::AccountId = account("member", 0, SEED);
+    let member_id = <membership::Module<T>>::members_created();
+
+    <membership::Module<T>>::set_screening_authority(RawOrigin::Root.into(), account_id.clone())
+        .unwrap();
+
+    <membership::Module<T>>::add_screened_member(
+        RawOrigin::Signed(account_id.clone()).into(),
+        account_id.clone(),
+        Some(account_id.encode()),
+        None,
+        None,
+    )
+    .unwrap();
+
+    T::MembershipOriginValidator::prepare_actor_origin(member_id, account_id.clone());
+    CurrencyOf::<T>::make_free_balance_be(&account_id, BalanceOf::<T>::from(u32::max_value()));
+
+    (account_id, member_id)
+}
+
+// Valid proposal parameters for the 'set proposal parameters' proposal.
+fn proposal_parameters<T: Trait>() -> ProposalParameters<T::BlockNumber, BalanceOf<T>> {
+    ProposalParameters {
+        voting_period: T::BlockNumber::from(PROPOSAL_PARAMETERS_VOTING_PERIOD_MIN_VALUE),
+        grace_period: T::BlockNumber::from(PROPOSAL_PARAMETERS_GRACE_PERIOD_MAX_VALUE),
+        approval_quorum_percentage: 80,
+        approval_threshold_percentage: 80,
+        slashing_quorum_percentage: 60,
+        slashing_threshold_percentage: 80,
+        required_stake: Some(BalanceOf::<T>::from(
+            PROPOSAL_PARAMETERS_REQUIRED_STAKE_MAX_VALUE,
+        )),
+    }
+}
+
+fn assert_proposal_created<T: Trait>() {
+    assert_eq!(<proposals_engine::Module<T>>::active_proposal_count(), 1);
+}
+
+benchmarks! {
+    _ { }
+
+    create_text_proposal {
+        let t in 1 .. max_title_length::<T>();
+        let d in 1 .. T::DescriptionMaxLength::get();
+        let x in 1 .. T::TextProposalMaxLength::get();
+
+        let (account_id, member_id) = proposer::<T>();
+        let stake = proposal_types::parameters::text_proposal::<T>().required_stake;
+    }: _(RawOrigin::Signed(account_id), member_id, text(t), text(d), stake, None, text(x))
+    verify {
+        assert_proposal_created::<T>();
+    }
+
+    create_runtime_upgrade_proposal {
+        let t in 1 .. max_title_length::<T>();
+        let d in 1 .. T::DescriptionMaxLength::get();
+        let w in 1 .. T::RuntimeUpgradeWasmProposalMaxLength::get();
+
+        let (account_id, member_id) = proposer::<T>();
+        let stake = proposal_types::parameters::runtime_upgrade_proposal::<T>().required_stake;
+    }: _(RawOrigin::Signed(account_id), member_id, text(t), text(d), stake, None, text(w))
+    verify {
+        assert_proposal_created::<T>();
+    }
+
+    create_set_election_parameters_proposal {
+        let t in 1 .. max_title_length::<T>();
+        let d in 1 .. T::DescriptionMaxLength::get();
+
+        let (account_id, member_id) = proposer::<T>();
+        let stake =
+            proposal_types::parameters::set_election_parameters_proposal::<T>().required_stake;
+
+        let election_parameters = ElectionParameters {
+            announcing_period: T::BlockNumber::from(ELECTION_PARAMETERS_ANNOUNCING_PERIOD_MIN_VALUE),
+            voting_period: T::BlockNumber::from(ELECTION_PARAMETERS_VOTING_PERIOD_MIN_VALUE),
+            revealing_period: T::BlockNumber::from(ELECTION_PARAMETERS_REVEALING_PERIOD_MIN_VALUE),
+            council_size: ELECTION_PARAMETERS_COUNCIL_SIZE_MIN_VALUE,
+            candidacy_limit: ELECTION_PARAMETERS_CANDIDACY_LIMIT_MIN_VALUE,
+            new_term_duration: T::BlockNumber::from(
+                ELECTION_PARAMETERS_NEW_TERM_DURATION_MIN_VALUE
+            ),
+            min_council_stake: BalanceOfGovernanceCurrency::<T>::from(
+                ELECTION_PARAMETERS_MIN_COUNCIL_STAKE_MIN_VALUE
+            ),
+            min_voting_stake: BalanceOfGovernanceCurrency::<T>::from(
+                ELECTION_PARAMETERS_MIN_STAKE_MIN_VALUE
+            ),
+            voting_mode: VotingMode::RankedChoice,
+        };
+    }: _(
+        RawOrigin::Signed(account_id),
+        member_id,
+        text(t),
+        text(d),
+        stake,
+        None,
+        election_parameters,
+    )
+    verify {
+        assert_proposal_created::<T>();
+    }
+
+    create_set_content_working_group_mint_capacity_proposal {
+        let t in 1 .. max_title_length::<T>();
+        let d in 1 .. T::DescriptionMaxLength::get();
+
+        let (account_id, member_id) = proposer::<T>();
+        let stake = proposal_types::parameters::set_content_working_group_mint_capacity_proposal::<T>()
+            .required_stake;
+        let mint_balance = BalanceOfMint::<T>::from(CONTENT_WORKING_GROUP_MINT_CAPACITY_MAX_VALUE);
+    }: _(RawOrigin::Signed(account_id), member_id, text(t), text(d), stake, None, mint_balance)
+    verify {
+        assert_proposal_created::<T>();
+    }
+
+    create_spending_proposal {
+        let t in 1 .. max_title_length::<T>();
+        let d in 1 .. T::DescriptionMaxLength::get();
+
+        let (account_id, member_id) = proposer::<T>();
+        let stake = proposal_types::parameters::spending_proposal::<T>().required_stake;
+        let balance = BalanceOfMint::<T>::from(MAX_SPENDING_PROPOSAL_VALUE);
+        let destination: T::AccountId = account("destination", 0, SEED);
+    }: _(
+        RawOrigin::Signed(account_id),
+        member_id,
+        text(t),
+        text(d),
+        stake,
+        None,
+        balance,
+        destination,
+    )
+    verify {
+        assert_proposal_created::<T>();
+    }
+
+    create_set_validator_count_proposal {
+        let t in 1 .. max_title_length::<T>();
+        let d in 1 .. T::DescriptionMaxLength::get();
+
+        let (account_id, member_id) = proposer::<T>();
+        let stake = proposal_types::parameters::set_validator_count_proposal::<T>().required_stake;
+    }: _(
+        RawOrigin::Signed(account_id),
+        member_id,
+        text(t),
+        text(d),
+        stake,
+        None,
+        MAX_VALIDATOR_COUNT,
+    )
+    verify {
+        assert_proposal_created::<T>();
+    }
+
+    create_add_working_group_leader_opening_proposal {
+        let t in 1 .. max_title_length::<T>();
+        let d in 1 .. T::DescriptionMaxLength::get();
+        let x in 1 .. MAX_BYTES;
+
+        let (account_id, member_id) = proposer::<T>();
+        let stake = proposal_types::parameters::add_working_group_leader_opening_proposal::<T>()
+            .required_stake;
+
+        let add_opening_parameters = AddOpeningParameters {
+            activate_at: hiring::ActivateOpeningAt::CurrentBlock,
+            commitment: working_group::OpeningPolicyCommitment::default(),
+            human_readable_text: text(x),
+            working_group: WorkingGroup::Storage,
+        };
+    }: _(
+        RawOrigin::Signed(account_id),
+        member_id,
+        text(t),
+        text(d),
+        stake,
+        None,
+        add_opening_parameters,
+    )
+    verify {
+        assert_proposal_created::<T>();
+    }
+
+    create_begin_review_working_group_leader_applications_proposal {
+        let t in 1 .. max_title_length::<T>();
+        let d in 1 .. T::DescriptionMaxLength::get();
+
+        let (account_id, member_id) = proposer::<T>();
+        let stake =
+            proposal_types::parameters::begin_review_working_group_leader_applications_proposal::<T>()
+                .required_stake;
+    }: _(
+        RawOrigin::Signed(account_id),
+        member_id,
+        text(t),
+        text(d),
+        stake,
+        None,
+        Default::default(),
+        WorkingGroup::Storage
+    )
+    verify {
+        assert_proposal_created::<T>();
+    }
+
+    create_fill_working_group_leader_opening_proposal {
+        let t in 1 .. max_title_length::<T>();
+        let d in 1 .. T::DescriptionMaxLength::get();
+
+        let (account_id, member_id) = proposer::<T>();
+        let stake = proposal_types::parameters::fill_working_group_leader_opening_proposal::<T>()
+            .required_stake;
+
+        let fill_opening_parameters = FillOpeningParameters {
+            opening_id: Default::default(),
+            successful_application_id: Default::default(),
+            reward_policy: Some(working_group::RewardPolicy {
+                amount_per_payout: BalanceOfMint::<T>::from(1u32),
+                next_payment_at_block: T::BlockNumber::from(1_000u32),
+                payout_interval: Some(T::BlockNumber::from(1_000u32)),
+            }),
+            working_group: WorkingGroup::Storage,
+        };
+    }: _(
+        RawOrigin::Signed(account_id),
+        member_id,
+        text(t),
+        text(d),
+        stake,
+        None,
+        fill_opening_parameters,
+    )
+    verify {
+        assert_proposal_created::<T>();
+    }
+
+    create_set_working_group_mint_capacity_proposal {
+        let t in 1 .. max_title_length::<T>();
+        let d in 1 .. T::DescriptionMaxLength::get();
+
+        let (account_id, member_id) = proposer::<T>();
+        let stake = proposal_types::parameters::set_working_group_mint_capacity_proposal::<T>()
+            .required_stake;
+        let mint_balance = BalanceOfMint::<T>::from(WORKING_GROUP_MINT_CAPACITY_MAX_VALUE);
+    }: _(
+        RawOrigin::Signed(account_id),
+        member_id,
+        text(t),
+        text(d),
+        stake,
+        None,
+        mint_balance,
+        WorkingGroup::Storage
+    )
+    verify {
+        assert_proposal_created::<T>();
+    }
+
+    create_decrease_working_group_leader_stake_proposal {
+        let t in 1 .. max_title_length::<T>();
+        let d in 1 .. T::DescriptionMaxLength::get();
+
+        let (account_id, member_id) = proposer::<T>();
+        let stake = proposal_types::parameters::decrease_working_group_leader_stake_proposal::<T>()
+            .required_stake;
+    }: _(
+        RawOrigin::Signed(account_id),
+        member_id,
+        text(t),
+        text(d),
+        stake,
+        None,
+        Default::default(),
+        BalanceOf::<T>::from(1u32),
+        WorkingGroup::Storage
+    )
+    verify {
+        assert_proposal_created::<T>();
+    }
+
+    create_slash_working_group_leader_stake_proposal {
+        let t in 1 .. max_title_length::<T>();
+        let d in 1 .. T::DescriptionMaxLength::get();
+
+        let (account_id, member_id) = proposer::<T>();
+        let stake = proposal_types::parameters::slash_working_group_leader_stake_proposal::<T>()
+            .required_stake;
+    }: _(
+        RawOrigin::Signed(account_id),
+        member_id,
+        text(t),
+        text(d),
+        stake,
+        None,
+        Default::default(),
+        BalanceOf::<T>::from(1u32),
+        WorkingGroup::Storage
+    )
+    verify {
+        assert_proposal_created::<T>();
+    }
+
+    create_set_working_group_leader_reward_proposal {
+        let t in 1 .. max_title_length::<T>();
+        let d in 1 .. T::DescriptionMaxLength::get();
+
+        let (account_id, member_id) = proposer::<T>();
+        let stake = proposal_types::parameters::set_working_group_leader_reward_proposal::<T>()
+            .required_stake;
+    }: _(
+        RawOrigin::Signed(account_id),
+        member_id,
+        text(t),
+        text(d),
+        stake,
+        None,
+        Default::default(),
+        BalanceOfMint::<T>::from(1u32),
+        WorkingGroup::Storage
+    )
+    verify {
+        assert_proposal_created::<T>();
+    }
+
+    create_terminate_working_group_leader_role_proposal {
+        let t in 1 .. max_title_length::<T>();
+        let d in 1 .. T::DescriptionMaxLength::get();
+        let x in 1 .. MAX_BYTES;
+
+        let (account_id, member_id) = proposer::<T>();
+        let stake = proposal_types::parameters::terminate_working_group_leader_role_proposal::<T>()
+            .required_stake;
+
+        let terminate_role_parameters = TerminateRoleParameters {
+            worker_id: Default::default(),
+            rationale: text(x),
+            slash: true,
+            working_group: WorkingGroup::Storage,
+        };
+    }: _(
+        RawOrigin::Signed(account_id),
+        member_id,
+        text(t),
+        text(d),
+        stake,
+        None,
+        terminate_role_parameters
+    )
+    verify {
+        assert_proposal_created::<T>();
+    }
+
+    create_set_mint_capacity_adjustment_proposal {
+        let t in 1 .. max_title_length::<T>();
+        let d in 1 .. T::DescriptionMaxLength::get();
+
+        let (account_id, member_id) = proposer::<T>();
+        let stake = proposal_types::parameters::set_mint_capacity_adjustment_proposal::<T>()
+            .required_stake;
+        let adjustment = minting::Adjustment::Interval(minting::AdjustOnInterval {
+            block_interval: T::BlockNumber::from(100u32),
+            adjustment_type: minting::AdjustCapacityBy::Adding(
+                BalanceOfMint::<T>::from(MINT_CAPACITY_ADJUSTMENT_MAX_VALUE),
+            ),
+        });
+    }: _(
+        RawOrigin::Signed(account_id),
+        member_id,
+        text(t),
+        text(d),
+        stake,
+        None,
+        GovernedMint::WorkingGroup(WorkingGroup::Storage),
+        Some(adjustment)
+    )
+    verify {
+        assert_proposal_created::<T>();
+    }
+
+    create_set_proposal_parameters_proposal {
+        let t in 1 .. max_title_length::<T>();
+        let d in 1 .. T::DescriptionMaxLength::get();
+
+        let (account_id, member_id) = proposer::<T>();
+        let stake =
+            proposal_types::parameters::set_proposal_parameters_proposal::<T>().required_stake;
+    }: _(
+        RawOrigin::Signed(account_id),
+        member_id,
+        text(t),
+        text(d),
+        stake,
+        None,
+        ProposalKind::SetProposalParameters,
+        proposal_parameters::<T>()
+    )
+    verify {
+        assert_proposal_created::<T>();
+    }
+
+    create_batch_proposal {
+        let t in 1 .. max_title_length::<T>();
+        let d in 1 .. T::DescriptionMaxLength::get();
+        let x in 1 .. MAX_BATCH_PROPOSAL_SIZE;
+
+        let (account_id, member_id) = proposer::<T>();
+        let proposals = vec![
+            ProposalDetails::Text(text(T::TextProposalMaxLength::get()));
+            x as usize
+        ];
+        let stake = proposal_types::parameters::batch_proposal::<T>(&proposals).required_stake;
+    }: _(
+        RawOrigin::Signed(account_id),
+        member_id,
+        text(t),
+        text(d),
+        stake,
+        None,
+        proposals
+    )
+    verify {
+        assert_proposal_created::<T>();
+    }
+
+    create_amend_constitution_proposal {
+        let t in 1 .. max_title_length::<T>();
+        let d in 1 .. T::DescriptionMaxLength::get();
+        let x in 1 .. T::ConstitutionTextMaxLength::get();
+
+        let (account_id, member_id) = proposer::<T>();
+        let constitution_text = text(x);
+        let amendment = constitution::ConstitutionAmendment {
+            content_hash: T::Hashing::hash(&constitution_text),
+            content: constitution::ConstitutionContent::Text(constitution_text),
+        };
+        let stake = proposal_types::parameters::amend_constitution_proposal::<T>().required_stake;
+    }: _(
+        RawOrigin::Signed(account_id),
+        member_id,
+        text(t),
+        text(d),
+        stake,
+        None,
+        amendment
+    )
+    verify {
+        assert_proposal_created::<T>();
+    }
+
+    execute_text_proposal {
+        let x in 1 .. T::TextProposalMaxLength::get();
+    }: _(RawOrigin::Root, text(x))
+
+    execute_batch_proposal {
+        let i in 1 .. MAX_BATCH_PROPOSAL_SIZE;
+
+        let proposal_code = T::ProposalEncoder::encode_proposal(ProposalDetails::Text(text(
+            T::TextProposalMaxLength::get(),
+        )));
+    }: _(RawOrigin::Root, vec![proposal_code; i as usize])
+
+    set_proposal_parameters {
+    }: _(RawOrigin::Root, ProposalKind::SetProposalParameters, proposal_parameters::<T>())
+    verify {
+        assert_eq!(
+            Module::<T>::proposal_parameters(ProposalKind::SetProposalParameters),
+            proposal_parameters::<T>()
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{initial_test_ext, Test};
+
+    #[test]
+    fn proposals_codex_benchmarks_succeed() {
+        initial_test_ext().execute_with(|| {
+            assert!(test_benchmark_create_text_proposal::<Test>().is_ok());
+            assert!(test_benchmark_create_runtime_upgrade_proposal::<Test>().is_ok());
+            assert!(test_benchmark_create_set_election_parameters_proposal::<Test>().is_ok());
+            assert!(
+                test_benchmark_create_set_content_working_group_mint_capacity_proposal::<Test>()
+                    .is_ok()
+            );
+            assert!(test_benchmark_create_spending_proposal::<Test>().is_ok());
+            assert!(test_benchmark_create_set_validator_count_proposal::<Test>().is_ok());
+            assert!(
+                test_benchmark_create_add_working_group_leader_opening_proposal::<Test>().is_ok()
+            );
+            assert!(
+                test_benchmark_create_begin_review_working_group_leader_applications_proposal::<Test>()
+                    .is_ok()
+            );
+            assert!(
+                test_benchmark_create_fill_working_group_leader_opening_proposal::<Test>().is_ok()
+            );
+            assert!(
+                test_benchmark_create_set_working_group_mint_capacity_proposal::<Test>().is_ok()
+            );
+            assert!(
+                test_benchmark_create_decrease_working_group_leader_stake_proposal::<Test>().is_ok()
+            );
+            assert!(
+                test_benchmark_create_slash_working_group_leader_stake_proposal::<Test>().is_ok()
+            );
+            assert!(
+                test_benchmark_create_set_working_group_leader_reward_proposal::<Test>().is_ok()
+            );
+            assert!(
+                test_benchmark_create_terminate_working_group_leader_role_proposal::<Test>().is_ok()
+            );
+            assert!(
+                test_benchmark_create_set_mint_capacity_adjustment_proposal::<Test>().is_ok()
+            );
+            assert!(
+                test_benchmark_create_set_proposal_parameters_proposal::<Test>().is_ok()
+            );
+            assert!(test_benchmark_create_batch_proposal::<Test>().is_ok());
+            assert!(test_benchmark_create_amend_constitution_proposal::<Test>().is_ok());
+            assert!(test_benchmark_execute_text_proposal::<Test>().is_ok());
+            assert!(test_benchmark_execute_batch_proposal::<Test>().is_ok());
+            assert!(test_benchmark_set_proposal_parameters::<Test>().is_ok());
+        });
+    }
+}
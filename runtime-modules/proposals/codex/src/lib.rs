@@ -12,6 +12,9 @@
 //! module. For each proposal, [its crucial details](./enum.ProposalDetails.html) are saved to the
 //! `ProposalDetailsByProposalId` map.
 //!
+//! Each proposal can be created with the optional `exactly_execute_at` block. The approved
+//! proposal is executed at this block instead of after its grace period.
+//!
 //! ### General proposals
 //! - [create_text_proposal](./struct.Module.html#method.create_text_proposal)
 //! - [create_runtime_upgrade_proposal](./struct.Module.html#method.create_runtime_upgrade_proposal)
@@ -22,7 +25,8 @@
 //! - [create_spending_proposal](./struct.Module.html#method.create_spending_proposal)
 //!
 //! ### Content working group proposals
-//! - [create_set_lead_proposal](./struct.Module.html#method.create_set_lead_proposal)
+//! - [create_set_lead_proposal](./struct.Module.html#method.create_set_lead_proposal) - deprecated,
+//! the content directory working group leader is hired with the working group proposals
 //! - [create_set_content_working_group_mint_capacity_proposal](./struct.Module.html#method.create_set_content_working_group_mint_capacity_proposal)
 //!
 //! ### Working group proposals
@@ -35,9 +39,32 @@
 //! - [create_set_working_group_leader_reward_proposal](./struct.Module.html#method.create_set_working_group_leader_reward_proposal)
 //! - [create_terminate_working_group_leader_role_proposal](./struct.Module.html#method.create_terminate_working_group_leader_role_proposal)
 //!
+//! ### Mint proposals
+//! - [create_set_mint_capacity_adjustment_proposal](./struct.Module.html#method.create_set_mint_capacity_adjustment_proposal)
+//!
+//! ### Proposal parameters proposals
+//! - [create_set_proposal_parameters_proposal](./struct.Module.html#method.create_set_proposal_parameters_proposal) -
+//! sets the proposal parameters of the proposal kind
+//!
+//! ### Constitution proposals
+//! - [create_amend_constitution_proposal](./struct.Module.html#method.create_amend_constitution_proposal) -
+//! amends the constitution
+//!
+//! ### Batch proposals
+//! - [create_batch_proposal](./struct.Module.html#method.create_batch_proposal) - several
+//! proposals executed together: either all of them or none
+//!
+//! ### Proposal queries
+//! - [proposals](./struct.Module.html#method.proposals) - returns a page of the proposals with
+//! their details and voting state using the provided filter
+//! - [proposal_info](./struct.Module.html#method.proposal_info) - returns a proposal with its
+//! details and voting state
+//!
 //! ### Proposal implementations of this module
 //! - execute_text_proposal - prints the proposal to the log
 //! - execute_runtime_upgrade_proposal - sets the runtime code
+//! - execute_batch_proposal - executes the batched proposals in order
+//! - set_proposal_parameters - sets the proposal parameters of the proposal kind
 //!
 //! ### Dependencies:
 //! - [proposals engine](../substrate_proposals_engine_module/index.html)
@@ -45,6 +72,7 @@
 //! - [membership](../substrate_membership_module/index.html)
 //! - [governance](../substrate_governance_module/index.html)
 //! - [content_working_group](../substrate_content_working_group_module/index.html)
+//! - [constitution](../pallet_constitution/index.html)
 //!
 //! ### Notes
 //! The module uses [ProposalEncoder](./trait.ProposalEncoder.html) to encode the proposal using
@@ -60,13 +88,18 @@
 // Do not delete! Cannot be uncommented by default, because of Parity decl_module! issue.
 // #![warn(missing_docs)]
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
 mod proposal_types;
+mod queries;
 
 #[cfg(test)]
 mod tests;
 
-use frame_support::dispatch::DispatchResult;
+use codec::{Decode, Encode};
+use frame_support::dispatch::{DispatchResult, UnfilteredDispatchable};
 use frame_support::traits::{Currency, Get};
+use frame_support::weights::{DispatchClass, Weight};
 use frame_support::{decl_error, decl_module, decl_storage, ensure, print};
 use sp_arithmetic::traits::Zero;
 use sp_std::clone::Clone;
@@ -80,14 +113,35 @@ use governance::election_params::ElectionParameters;
 use proposals_engine::ProposalParameters;
 
 pub use crate::proposal_types::{
-    AddOpeningParameters, FillOpeningParameters, ProposalsConfigParameters, TerminateRoleParameters,
+    AddOpeningParameters, FillOpeningParameters, GovernedMint, ProposalKind,
+    ProposalsConfigParameters, TerminateRoleParameters,
 };
+use proposal_types::LegacyProposalDetailsOf;
 pub use proposal_types::{ProposalDetails, ProposalDetailsOf, ProposalEncoder};
+pub use queries::{ProposalInfo, ProposalInfoOf, ProposalStatusFilter, ProposalsFilter};
 
 // 'Set working group mint capacity' proposal limit
 const WORKING_GROUP_MINT_CAPACITY_MAX_VALUE: u32 = 5_000_000;
 // 'Set content working group mint capacity' proposal limit
 const CONTENT_WORKING_GROUP_MINT_CAPACITY_MAX_VALUE: u32 = 1_000_000;
+// Max adjustment amount for the 'set mint capacity adjustment' proposal
+const MINT_CAPACITY_ADJUSTMENT_MAX_VALUE: u32 = 5_000_000;
+// Max number of the proposals in the 'batch' proposal
+const MAX_BATCH_PROPOSAL_SIZE: u32 = 10;
+// voting_period min value for the 'set proposal parameters' proposal
+const PROPOSAL_PARAMETERS_VOTING_PERIOD_MIN_VALUE: u32 = 14400;
+// voting_period max value for the 'set proposal parameters' proposal
+const PROPOSAL_PARAMETERS_VOTING_PERIOD_MAX_VALUE: u32 = 432_000;
+// grace_period max value for the 'set proposal parameters' proposal
+const PROPOSAL_PARAMETERS_GRACE_PERIOD_MAX_VALUE: u32 = 432_000;
+// Min approval quorum and threshold percentage for the 'set proposal parameters' proposal
+const PROPOSAL_PARAMETERS_APPROVAL_PERCENTAGE_MIN_VALUE: u32 = 50;
+// Min approval quorum and threshold percentage of the 'set proposal parameters' proposal itself
+const PROPOSAL_PARAMETERS_SUPERMAJORITY_PERCENTAGE_MIN_VALUE: u32 = 66;
+// required_stake min value for the 'set proposal parameters' proposal
+const PROPOSAL_PARAMETERS_REQUIRED_STAKE_MIN_VALUE: u32 = 1;
+// required_stake max value for the 'set proposal parameters' proposal
+const PROPOSAL_PARAMETERS_REQUIRED_STAKE_MAX_VALUE: u32 = 5_000_000;
 // Max allowed value for 'spending' proposal
 const MAX_SPENDING_PROPOSAL_VALUE: u32 = 5_000_000_u32;
 // Max validator count for the 'set validator count' proposal
@@ -133,11 +187,110 @@ struct CreateProposalParameters<T: Trait> {
     pub title: Vec<u8>,
     pub description: Vec<u8>,
     pub stake_balance: Option<BalanceOf<T>>,
+    pub exactly_execute_at: Option<T::BlockNumber>,
     pub proposal_code: Vec<u8>,
     pub proposal_parameters: ProposalParameters<T::BlockNumber, BalanceOf<T>>,
     pub proposal_details: ProposalDetailsOf<T>,
 }
 
+/// Weight information for the proposals codex extrinsics. `t` and `d` are the byte lengths of
+/// the proposal title and description, other length parameters are the byte lengths of the
+/// proposal specific text inputs.
+pub trait WeightInfo {
+    fn create_text_proposal(t: u32, d: u32, x: u32) -> Weight;
+    fn create_runtime_upgrade_proposal(t: u32, d: u32, w: u32) -> Weight;
+    fn create_set_election_parameters_proposal(t: u32, d: u32) -> Weight;
+    fn create_set_content_working_group_mint_capacity_proposal(t: u32, d: u32) -> Weight;
+    fn create_spending_proposal(t: u32, d: u32) -> Weight;
+    fn create_set_lead_proposal(t: u32, d: u32) -> Weight;
+    fn create_set_validator_count_proposal(t: u32, d: u32) -> Weight;
+    fn create_add_working_group_leader_opening_proposal(t: u32, d: u32, x: u32) -> Weight;
+    fn create_begin_review_working_group_leader_applications_proposal(t: u32, d: u32) -> Weight;
+    fn create_fill_working_group_leader_opening_proposal(t: u32, d: u32) -> Weight;
+    fn create_set_working_group_mint_capacity_proposal(t: u32, d: u32) -> Weight;
+    fn create_decrease_working_group_leader_stake_proposal(t: u32, d: u32) -> Weight;
+    fn create_slash_working_group_leader_stake_proposal(t: u32, d: u32) -> Weight;
+    fn create_set_working_group_leader_reward_proposal(t: u32, d: u32) -> Weight;
+    fn create_terminate_working_group_leader_role_proposal(t: u32, d: u32, x: u32) -> Weight;
+    fn create_set_mint_capacity_adjustment_proposal(t: u32, d: u32) -> Weight;
+    fn create_set_proposal_parameters_proposal(t: u32, d: u32) -> Weight;
+    fn create_batch_proposal(t: u32, d: u32, x: u32) -> Weight;
+    fn create_amend_constitution_proposal(t: u32, d: u32, x: u32) -> Weight;
+    fn execute_text_proposal(x: u32) -> Weight;
+    fn execute_batch_proposal(i: u32) -> Weight;
+    fn set_proposal_parameters() -> Weight;
+}
+
+/// Default weights, used by the tests.
+impl WeightInfo for () {
+    fn create_text_proposal(_t: u32, _d: u32, _x: u32) -> Weight {
+        10_000_000
+    }
+    fn create_runtime_upgrade_proposal(_t: u32, _d: u32, _w: u32) -> Weight {
+        10_000_000
+    }
+    fn create_set_election_parameters_proposal(_t: u32, _d: u32) -> Weight {
+        10_000_000
+    }
+    fn create_set_content_working_group_mint_capacity_proposal(_t: u32, _d: u32) -> Weight {
+        10_000_000
+    }
+    fn create_spending_proposal(_t: u32, _d: u32) -> Weight {
+        10_000_000
+    }
+    fn create_set_lead_proposal(_t: u32, _d: u32) -> Weight {
+        10_000_000
+    }
+    fn create_set_validator_count_proposal(_t: u32, _d: u32) -> Weight {
+        10_000_000
+    }
+    fn create_add_working_group_leader_opening_proposal(_t: u32, _d: u32, _x: u32) -> Weight {
+        10_000_000
+    }
+    fn create_begin_review_working_group_leader_applications_proposal(_t: u32, _d: u32) -> Weight {
+        10_000_000
+    }
+    fn create_fill_working_group_leader_opening_proposal(_t: u32, _d: u32) -> Weight {
+        10_000_000
+    }
+    fn create_set_working_group_mint_capacity_proposal(_t: u32, _d: u32) -> Weight {
+        10_000_000
+    }
+    fn create_decrease_working_group_leader_stake_proposal(_t: u32, _d: u32) -> Weight {
+        10_000_000
+    }
+    fn create_slash_working_group_leader_stake_proposal(_t: u32, _d: u32) -> Weight {
+        10_000_000
+    }
+    fn create_set_working_group_leader_reward_proposal(_t: u32, _d: u32) -> Weight {
+        10_000_000
+    }
+    fn create_terminate_working_group_leader_role_proposal(_t: u32, _d: u32, _x: u32) -> Weight {
+        10_000_000
+    }
+    fn create_set_mint_capacity_adjustment_proposal(_t: u32, _d: u32) -> Weight {
+        10_000_000
+    }
+    fn create_set_proposal_parameters_proposal(_t: u32, _d: u32) -> Weight {
+        10_000_000
+    }
+    fn create_batch_proposal(_t: u32, _d: u32, _x: u32) -> Weight {
+        10_000_000
+    }
+    fn create_amend_constitution_proposal(_t: u32, _d: u32, _x: u32) -> Weight {
+        10_000_000
+    }
+    fn execute_text_proposal(_x: u32) -> Weight {
+        10_000_000
+    }
+    fn execute_batch_proposal(_i: u32) -> Weight {
+        10_000_000
+    }
+    fn set_proposal_parameters() -> Weight {
+        10_000_000
+    }
+}
+
 /// 'Proposals codex' substrate module Trait
 pub trait Trait:
     system::Trait
@@ -147,6 +300,7 @@ pub trait Trait:
     + governance::election::Trait
     + content_working_group::Trait
     + staking::Trait
+    + constitution::Trait
 {
     /// Defines max allowed text proposal length.
     type TextProposalMaxLength: Get<u32>;
@@ -163,6 +317,9 @@ pub trait Trait:
 
     /// Encodes the proposal usint its details
     type ProposalEncoder: ProposalEncoder<Self>;
+
+    /// Weight information for the extrinsics of this module.
+    type WeightInfo: WeightInfo;
 }
 
 /// Balance alias for `stake` module
@@ -242,7 +399,8 @@ decl_error! {
         /// Invalid working group mint capacity parameter
         InvalidWorkingGroupMintCapacity,
 
-        /// Invalid 'set lead proposal' parameter - proposed lead cannot be a councilor
+        /// Invalid 'set lead proposal' parameter - proposed lead cannot be a councilor.
+        /// Not used since the 'set lead' proposal deprecation, kept for the error indices.
         InvalidSetLeadParameterCannotBeCouncilor,
 
         /// Invalid 'slash stake proposal' parameter - cannot slash by zero balance.
@@ -250,129 +408,73 @@ decl_error! {
 
         /// Invalid 'decrease stake proposal' parameter - cannot decrease by zero balance.
         DecreasingStakeIsZero,
-    }
-}
 
-// Storage for the proposals codex module
-decl_storage! {
-    pub trait Store for Module<T: Trait> as ProposalCodex{
-        /// Map proposal id to its discussion thread id
-        pub ThreadIdByProposalId get(fn thread_id_by_proposal_id):
-            map hasher(blake2_128_concat) T::ProposalId => T::ThreadId;
+        /// Invalid 'set mint capacity adjustment' proposal parameter - the adjustment amount
+        /// exceeds the limit or the adjustment interval is zero.
+        InvalidMintCapacityAdjustment,
 
-        /// Map proposal id to proposal details
-        pub ProposalDetailsByProposalId get(fn proposal_details_by_proposal_id):
-            map hasher(blake2_128_concat) T::ProposalId => ProposalDetailsOf<T>;
+        /// Provided 'batch' proposal contains no proposals
+        BatchProposalIsEmpty,
 
-        /// Voting period for the 'set validator count' proposal
-        pub SetValidatorCountProposalVotingPeriod get(fn set_validator_count_proposal_voting_period)
-            config(): T::BlockNumber;
+        /// The number of the proposals in the 'batch' proposal exceeded the limit
+        BatchProposalSizeExceeded,
 
-        /// Grace period for the 'set validator count' proposal
-        pub SetValidatorCountProposalGracePeriod get(fn set_validator_count_proposal_grace_period)
-            config(): T::BlockNumber;
+        /// The 'batch' proposal cannot contain batch, runtime upgrade or deprecated proposals
+        InvalidBatchProposalItem,
 
-        /// Voting period for the 'runtime upgrade' proposal
-        pub RuntimeUpgradeProposalVotingPeriod get(fn runtime_upgrade_proposal_voting_period)
-            config(): T::BlockNumber;
+        /// The batched proposal code cannot be decoded
+        InvalidBatchProposalCode,
 
-        /// Grace period for the 'runtime upgrade' proposal
-        pub RuntimeUpgradeProposalGracePeriod get(fn runtime_upgrade_proposal_grace_period)
-            config(): T::BlockNumber;
+        /// Invalid 'set proposal parameters' proposal parameter - voting period is out of bounds
+        InvalidProposalParametersVotingPeriod,
 
-        /// Voting period for the 'set election parameters' proposal
-        pub SetElectionParametersProposalVotingPeriod get(fn set_election_parameters_proposal_voting_period)
-            config(): T::BlockNumber;
+        /// Invalid 'set proposal parameters' proposal parameter - grace period exceeds the limit
+        InvalidProposalParametersGracePeriod,
 
-        /// Grace period for the 'set election parameters' proposal
-        pub SetElectionParametersProposalGracePeriod get(fn set_election_parameters_proposal_grace_period)
-            config(): T::BlockNumber;
+        /// Invalid 'set proposal parameters' proposal parameter - quorum or threshold percentage
+        /// is out of bounds
+        InvalidProposalParametersPercentage,
 
-        /// Voting period for the 'text' proposal
-        pub TextProposalVotingPeriod get(fn text_proposal_voting_period) config(): T::BlockNumber;
+        /// Invalid 'set proposal parameters' proposal parameter - required stake is out of bounds
+        InvalidProposalParametersRequiredStake,
 
-        /// Grace period for the 'text' proposal
-        pub TextProposalGracePeriod get(fn text_proposal_grace_period) config(): T::BlockNumber;
+        /// The 'set lead' proposal is deprecated: the content directory working group leader is
+        /// hired with the working group proposals
+        SetLeadProposalIsDeprecated,
 
-        /// Voting period for the 'set content working group mint capacity' proposal
-        pub SetContentWorkingGroupMintCapacityProposalVotingPeriod get(fn set_content_working_group_mint_capacity_proposal_voting_period)
-            config(): T::BlockNumber;
-
-        /// Grace period for the 'set content working group mint capacity' proposal
-        pub SetContentWorkingGroupMintCapacityProposalGracePeriod get(fn set_content_working_group_mint_capacity_proposal_grace_period)
-            config(): T::BlockNumber;
-
-        /// Voting period for the 'set lead' proposal
-        pub SetLeadProposalVotingPeriod get(fn set_lead_proposal_voting_period)
-            config(): T::BlockNumber;
-
-        /// Grace period for the 'set lead' proposal
-        pub SetLeadProposalGracePeriod get(fn set_lead_proposal_grace_period)
-            config(): T::BlockNumber;
-
-        /// Voting period for the 'spending' proposal
-        pub SpendingProposalVotingPeriod get(fn spending_proposal_voting_period) config(): T::BlockNumber;
-
-        /// Grace period for the 'spending' proposal
-        pub SpendingProposalGracePeriod get(fn spending_proposal_grace_period) config(): T::BlockNumber;
-
-        /// Voting period for the 'add working group opening' proposal
-        pub AddWorkingGroupOpeningProposalVotingPeriod get(fn add_working_group_opening_proposal_voting_period) config(): T::BlockNumber;
-
-        /// Grace period for the 'add working group opening' proposal
-        pub AddWorkingGroupOpeningProposalGracePeriod get(fn add_working_group_opening_proposal_grace_period) config(): T::BlockNumber;
-
-        /// Voting period for the 'begin review working group leader applications' proposal
-        pub BeginReviewWorkingGroupLeaderApplicationsProposalVotingPeriod get(fn begin_review_working_group_leader_applications_proposal_voting_period) config(): T::BlockNumber;
-
-        /// Grace period for the 'begin review working group leader applications' proposal
-        pub BeginReviewWorkingGroupLeaderApplicationsProposalGracePeriod get(fn begin_review_working_group_leader_applications_proposal_grace_period) config(): T::BlockNumber;
-
-        /// Voting period for the 'fill working group leader opening' proposal
-        pub FillWorkingGroupLeaderOpeningProposalVotingPeriod get(fn fill_working_group_leader_opening_proposal_voting_period) config(): T::BlockNumber;
-
-        /// Grace period for the 'fill working group leader opening' proposal
-        pub FillWorkingGroupLeaderOpeningProposalGracePeriod get(fn fill_working_group_leader_opening_proposal_grace_period) config(): T::BlockNumber;
-
-        /// Voting period for the 'set working group mint capacity' proposal
-        pub SetWorkingGroupMintCapacityProposalVotingPeriod get(fn set_working_group_mint_capacity_proposal_voting_period)
-            config(): T::BlockNumber;
-
-        /// Grace period for the 'set working group mint capacity' proposal
-        pub SetWorkingGroupMintCapacityProposalGracePeriod get(fn set_working_group_mint_capacity_proposal_grace_period)
-            config(): T::BlockNumber;
-
-        /// Voting period for the 'decrease working group leader stake' proposal
-        pub DecreaseWorkingGroupLeaderStakeProposalVotingPeriod get(fn decrease_working_group_leader_stake_proposal_voting_period)
-            config(): T::BlockNumber;
-
-        /// Grace period for the 'decrease working group leader stake' proposal
-        pub DecreaseWorkingGroupLeaderStakeProposalGracePeriod get(fn decrease_working_group_leader_stake_proposal_grace_period)
-            config(): T::BlockNumber;
-
-        /// Voting period for the 'slash working group leader stake' proposal
-        pub SlashWorkingGroupLeaderStakeProposalVotingPeriod get(fn slash_working_group_leader_stake_proposal_voting_period)
-            config(): T::BlockNumber;
-
-        /// Grace period for the 'slash working group leader stake' proposal
-        pub SlashWorkingGroupLeaderStakeProposalGracePeriod get(fn slash_working_group_leader_stake_proposal_grace_period)
-            config(): T::BlockNumber;
+        /// Invalid 'set mint capacity adjustment' proposal parameter - the first adjustment block
+        /// should be after the latest proposal execution block
+        MintCapacityAdjustmentBeforeExecution,
+    }
+}
 
-        /// Voting period for the 'set working group leader reward' proposal
-        pub SetWorkingGroupLeaderRewardProposalVotingPeriod get(fn set_working_group_leader_reward_proposal_voting_period)
-            config(): T::BlockNumber;
+// Storage for the proposals codex module
+decl_storage! {
+    pub trait Store for Module<T: Trait> as ProposalCodex{
+        /// Map proposal id to its discussion thread id
+        pub ThreadIdByProposalId get(fn thread_id_by_proposal_id):
+            map hasher(blake2_128_concat) T::ProposalId => T::ThreadId;
 
-        /// Grace period for the 'set working group leader reward' proposal
-        pub SetWorkingGroupLeaderRewardProposalGracePeriod get(fn set_working_group_leader_reward_proposal_grace_period)
-            config(): T::BlockNumber;
+        /// Map proposal id to proposal details
+        pub ProposalDetailsByProposalId get(fn proposal_details_by_proposal_id):
+            map hasher(blake2_128_concat) T::ProposalId => ProposalDetailsOf<T>;
 
-        /// Voting period for the 'terminate working group leader role' proposal
-        pub TerminateWorkingGroupLeaderRoleProposalVotingPeriod get(fn terminate_working_group_leader_role_proposal_voting_period)
-            config(): T::BlockNumber;
+        /// Map proposal kind to its proposal parameters
+        pub ProposalParametersByKind get(fn proposal_parameters_by_kind):
+            map hasher(blake2_128_concat) ProposalKind =>
+                Option<ProposalParameters<T::BlockNumber, BalanceOf<T>>>;
 
-        /// Grace period for the 'terminate working group leader role' proposal
-        pub TerminateWorkingGroupLeaderRoleProposalGracePeriod get(fn terminate_working_group_leader_role_proposal_grace_period)
-            config(): T::BlockNumber;
+        /// Whether the proposal details stored by the previous runtime versions were migrated.
+        pub ProposalDetailsMigrated get(fn proposal_details_migrated): bool;
+    }
+    add_extra_genesis {
+        config(proposals_config_parameters): ProposalsConfigParameters;
+        build(|config: &GenesisConfig| {
+            Module::<T>::set_config_values(config.proposals_config_parameters);
+
+            // Nothing to migrate on the new chain.
+            ProposalDetailsMigrated::put(true);
+        });
     }
 }
 
@@ -389,26 +491,30 @@ decl_module! {
         const RuntimeUpgradeWasmProposalMaxLength: u32 = T::RuntimeUpgradeWasmProposalMaxLength::get();
 
         /// Create 'Text (signal)' proposal type.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::create_text_proposal(
+            title.len() as u32,
+            description.len() as u32,
+            text.len() as u32,
+        )]
         pub fn create_text_proposal(
             origin,
             member_id: MemberId<T>,
             title: Vec<u8>,
             description: Vec<u8>,
             stake_balance: Option<BalanceOf<T>>,
+            exactly_execute_at: Option<T::BlockNumber>,
             text: Vec<u8>,
         ) {
-            ensure!(!text.is_empty(), Error::<T>::TextProposalIsEmpty);
-            ensure!(text.len() as u32 <=  T::TextProposalMaxLength::get(),
-                Error::<T>::TextProposalSizeExceeded);
-
             let proposal_details = ProposalDetails::Text(text);
+            Self::ensure_proposal_details_valid(&proposal_details)?;
+
             let params = CreateProposalParameters{
                 origin,
                 member_id,
                 title,
                 description,
                 stake_balance,
+                exactly_execute_at,
                 proposal_details: proposal_details.clone(),
                 proposal_parameters: proposal_types::parameters::text_proposal::<T>(),
                 proposal_code: T::ProposalEncoder::encode_proposal(proposal_details)
@@ -419,26 +525,30 @@ decl_module! {
 
         /// Create 'Runtime upgrade' proposal type. Runtime upgrade can be initiated only by
         /// members from the hardcoded list `RuntimeUpgradeProposalAllowedProposers`
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::create_runtime_upgrade_proposal(
+            title.len() as u32,
+            description.len() as u32,
+            wasm.len() as u32,
+        )]
         pub fn create_runtime_upgrade_proposal(
             origin,
             member_id: MemberId<T>,
             title: Vec<u8>,
             description: Vec<u8>,
             stake_balance: Option<BalanceOf<T>>,
+            exactly_execute_at: Option<T::BlockNumber>,
             wasm: Vec<u8>,
         ) {
-            ensure!(!wasm.is_empty(), Error::<T>::RuntimeProposalIsEmpty);
-            ensure!(wasm.len() as u32 <= T::RuntimeUpgradeWasmProposalMaxLength::get(),
-                Error::<T>::RuntimeProposalSizeExceeded);
-
             let proposal_details = ProposalDetails::RuntimeUpgrade(wasm);
+            Self::ensure_proposal_details_valid(&proposal_details)?;
+
             let params = CreateProposalParameters{
                 origin,
                 member_id,
                 title,
                 description,
                 stake_balance,
+                exactly_execute_at,
                 proposal_details: proposal_details.clone(),
                 proposal_parameters: proposal_types::parameters::runtime_upgrade_proposal::<T>(),
                 proposal_code: T::ProposalEncoder::encode_proposal(proposal_details)
@@ -449,26 +559,29 @@ decl_module! {
 
         /// Create 'Set election parameters' proposal type. This proposal uses `set_election_parameters()`
         /// extrinsic from the `governance::election module`.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::create_set_election_parameters_proposal(
+            title.len() as u32,
+            description.len() as u32,
+        )]
         pub fn create_set_election_parameters_proposal(
             origin,
             member_id: MemberId<T>,
             title: Vec<u8>,
             description: Vec<u8>,
             stake_balance: Option<BalanceOf<T>>,
+            exactly_execute_at: Option<T::BlockNumber>,
             election_parameters: ElectionParameters<BalanceOfGovernanceCurrency<T>, T::BlockNumber>,
         ) {
-            election_parameters.ensure_valid()?;
-
-            Self::ensure_council_election_parameters_valid(&election_parameters)?;
-
             let proposal_details = ProposalDetails::SetElectionParameters(election_parameters);
+            Self::ensure_proposal_details_valid(&proposal_details)?;
+
             let params = CreateProposalParameters{
                 origin,
                 member_id,
                 title,
                 description,
                 stake_balance,
+                exactly_execute_at,
                 proposal_details: proposal_details.clone(),
                 proposal_parameters: proposal_types::parameters::set_election_parameters_proposal::<T>(),
                 proposal_code: T::ProposalEncoder::encode_proposal(proposal_details)
@@ -478,28 +591,30 @@ decl_module! {
         }
 
         /// Create 'Set content working group mint capacity' proposal type.
-        /// This proposal uses `set_mint_capacity()` extrinsic from the `content-working-group`  module.
-        #[weight = 10_000_000] // TODO: adjust weight
+        /// This proposal uses `set_mint_capacity()` extrinsic of the content directory working group.
+        #[weight = <T as Trait>::WeightInfo::create_set_content_working_group_mint_capacity_proposal(
+            title.len() as u32,
+            description.len() as u32,
+        )]
         pub fn create_set_content_working_group_mint_capacity_proposal(
             origin,
             member_id: MemberId<T>,
             title: Vec<u8>,
             description: Vec<u8>,
             stake_balance: Option<BalanceOf<T>>,
+            exactly_execute_at: Option<T::BlockNumber>,
             mint_balance: BalanceOfMint<T>,
         ) {
-            ensure!(
-                mint_balance <= <BalanceOfMint<T>>::from(CONTENT_WORKING_GROUP_MINT_CAPACITY_MAX_VALUE),
-                Error::<T>::InvalidContentWorkingGroupMintCapacity
-            );
-
             let proposal_details = ProposalDetails::SetContentWorkingGroupMintCapacity(mint_balance);
+            Self::ensure_proposal_details_valid(&proposal_details)?;
+
             let params = CreateProposalParameters{
                 origin,
                 member_id,
                 title,
                 description,
                 stake_balance,
+                exactly_execute_at,
                 proposal_details: proposal_details.clone(),
                 proposal_parameters: proposal_types::parameters::set_content_working_group_mint_capacity_proposal::<T>(),
                 proposal_code: T::ProposalEncoder::encode_proposal(proposal_details)
@@ -510,29 +625,30 @@ decl_module! {
 
         /// Create 'Spending' proposal type.
         /// This proposal uses `spend_from_council_mint()` extrinsic from the `governance::council`  module.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::create_spending_proposal(
+            title.len() as u32,
+            description.len() as u32,
+        )]
         pub fn create_spending_proposal(
             origin,
             member_id: MemberId<T>,
             title: Vec<u8>,
             description: Vec<u8>,
             stake_balance: Option<BalanceOf<T>>,
+            exactly_execute_at: Option<T::BlockNumber>,
             balance: BalanceOfMint<T>,
             destination: T::AccountId,
         ) {
-            ensure!(balance != BalanceOfMint::<T>::zero(), Error::<T>::InvalidSpendingProposalBalance);
-            ensure!(
-                balance <= <BalanceOfMint<T>>::from(MAX_SPENDING_PROPOSAL_VALUE),
-                Error::<T>::InvalidSpendingProposalBalance
-            );
-
             let proposal_details = ProposalDetails::Spending(balance, destination);
+            Self::ensure_proposal_details_valid(&proposal_details)?;
+
             let params = CreateProposalParameters{
                 origin,
                 member_id,
                 title,
                 description,
                 stake_balance,
+                exactly_execute_at,
                 proposal_details: proposal_details.clone(),
                 proposal_parameters: proposal_types::parameters::spending_proposal::<T>(),
                 proposal_code: T::ProposalEncoder::encode_proposal(proposal_details)
@@ -542,30 +658,31 @@ decl_module! {
         }
 
         /// Create 'Set lead' proposal type.
-        /// This proposal uses `replace_lead()` extrinsic from the `content_working_group`  module.
-        #[weight = 10_000_000] // TODO: adjust weight
+        /// Deprecated: the content working group lead was replaced by the content directory working
+        /// group leader, the proposal creation fails.
+        #[weight = <T as Trait>::WeightInfo::create_set_lead_proposal(
+            title.len() as u32,
+            description.len() as u32,
+        )]
         pub fn create_set_lead_proposal(
             origin,
             member_id: MemberId<T>,
             title: Vec<u8>,
             description: Vec<u8>,
             stake_balance: Option<BalanceOf<T>>,
+            exactly_execute_at: Option<T::BlockNumber>,
             new_lead: Option<(T::MemberId, T::AccountId)>
         ) {
-            if let Some(lead) = new_lead.clone() {
-                let account_id = lead.1;
-                ensure!(
-                    !<governance::council::Module<T>>::is_councilor(&account_id),
-                    Error::<T>::InvalidSetLeadParameterCannotBeCouncilor
-                );
-            }
             let proposal_details = ProposalDetails::SetLead(new_lead);
+            Self::ensure_proposal_details_valid(&proposal_details)?;
+
             let params = CreateProposalParameters{
                 origin,
                 member_id,
                 title,
                 description,
                 stake_balance,
+                exactly_execute_at,
                 proposal_details: proposal_details.clone(),
                 proposal_parameters: proposal_types::parameters::set_lead_proposal::<T>(),
                 proposal_code: T::ProposalEncoder::encode_proposal(proposal_details)
@@ -576,32 +693,29 @@ decl_module! {
 
         /// Create 'Evict storage provider' proposal type.
         /// This proposal uses `set_validator_count()` extrinsic from the Substrate `staking`  module.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::create_set_validator_count_proposal(
+            title.len() as u32,
+            description.len() as u32,
+        )]
         pub fn create_set_validator_count_proposal(
             origin,
             member_id: MemberId<T>,
             title: Vec<u8>,
             description: Vec<u8>,
             stake_balance: Option<BalanceOf<T>>,
+            exactly_execute_at: Option<T::BlockNumber>,
             new_validator_count: u32,
         ) {
-            ensure!(
-                new_validator_count >= <staking::Module<T>>::minimum_validator_count(),
-                Error::<T>::InvalidValidatorCount
-            );
-
-            ensure!(
-                new_validator_count <= MAX_VALIDATOR_COUNT,
-                Error::<T>::InvalidValidatorCount
-            );
-
             let proposal_details = ProposalDetails::SetValidatorCount(new_validator_count);
+            Self::ensure_proposal_details_valid(&proposal_details)?;
+
             let params = CreateProposalParameters{
                 origin,
                 member_id,
                 title,
                 description,
                 stake_balance,
+                exactly_execute_at,
                 proposal_details: proposal_details.clone(),
                 proposal_parameters: proposal_types::parameters::set_validator_count_proposal::<T>(),
                 proposal_code: T::ProposalEncoder::encode_proposal(proposal_details)
@@ -612,13 +726,18 @@ decl_module! {
 
         /// Create 'Add working group leader opening' proposal type.
         /// This proposal uses `add_opening()` extrinsic from the Joystream `working group` module.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::create_add_working_group_leader_opening_proposal(
+            title.len() as u32,
+            description.len() as u32,
+            add_opening_parameters.human_readable_text.len() as u32,
+        )]
         pub fn create_add_working_group_leader_opening_proposal(
             origin,
             member_id: MemberId<T>,
             title: Vec<u8>,
             description: Vec<u8>,
             stake_balance: Option<BalanceOf<T>>,
+            exactly_execute_at: Option<T::BlockNumber>,
             add_opening_parameters: AddOpeningParameters<T::BlockNumber, BalanceOfGovernanceCurrency<T>>,
         ) {
 
@@ -629,6 +748,7 @@ decl_module! {
                 title,
                 description,
                 stake_balance,
+                exactly_execute_at,
                 proposal_details: proposal_details.clone(),
                 proposal_parameters: proposal_types::parameters::add_working_group_leader_opening_proposal::<T>(),
                 proposal_code: T::ProposalEncoder::encode_proposal(proposal_details)
@@ -639,13 +759,17 @@ decl_module! {
 
         /// Create 'Begin review working group leader applications' proposal type.
         /// This proposal uses `begin_applicant_review()` extrinsic from the Joystream `working group` module.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::create_begin_review_working_group_leader_applications_proposal(
+            title.len() as u32,
+            description.len() as u32,
+        )]
         pub fn create_begin_review_working_group_leader_applications_proposal(
             origin,
             member_id: MemberId<T>,
             title: Vec<u8>,
             description: Vec<u8>,
             stake_balance: Option<BalanceOf<T>>,
+            exactly_execute_at: Option<T::BlockNumber>,
             opening_id: working_group::OpeningId<T>,
             working_group: WorkingGroup,
         ) {
@@ -657,6 +781,7 @@ decl_module! {
                 title,
                 description,
                 stake_balance,
+                exactly_execute_at,
                 proposal_details: proposal_details.clone(),
                 proposal_parameters: proposal_types::parameters::begin_review_working_group_leader_applications_proposal::<T>(),
                 proposal_code: T::ProposalEncoder::encode_proposal(proposal_details)
@@ -667,13 +792,17 @@ decl_module! {
 
         /// Create 'Fill working group leader opening' proposal type.
         /// This proposal uses `fill_opening()` extrinsic from the Joystream `working group` module.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::create_fill_working_group_leader_opening_proposal(
+            title.len() as u32,
+            description.len() as u32,
+        )]
         pub fn create_fill_working_group_leader_opening_proposal(
             origin,
             member_id: MemberId<T>,
             title: Vec<u8>,
             description: Vec<u8>,
             stake_balance: Option<BalanceOf<T>>,
+            exactly_execute_at: Option<T::BlockNumber>,
             fill_opening_parameters: FillOpeningParameters<
                 T::BlockNumber,
                 BalanceOfMint<T>,
@@ -689,6 +818,7 @@ decl_module! {
                 title,
                 description,
                 stake_balance,
+                exactly_execute_at,
                 proposal_details: proposal_details.clone(),
                 proposal_parameters: proposal_types::parameters::fill_working_group_leader_opening_proposal::<T>(),
                 proposal_code: T::ProposalEncoder::encode_proposal(proposal_details)
@@ -699,28 +829,30 @@ decl_module! {
 
         /// Create 'Set working group mint capacity' proposal type.
         /// This proposal uses `set_mint_capacity()` extrinsic from the `working-group`  module.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::create_set_working_group_mint_capacity_proposal(
+            title.len() as u32,
+            description.len() as u32,
+        )]
         pub fn create_set_working_group_mint_capacity_proposal(
             origin,
             member_id: MemberId<T>,
             title: Vec<u8>,
             description: Vec<u8>,
             stake_balance: Option<BalanceOf<T>>,
+            exactly_execute_at: Option<T::BlockNumber>,
             mint_balance: BalanceOfMint<T>,
             working_group: WorkingGroup,
         ) {
-            ensure!(
-                mint_balance <= <BalanceOfMint<T>>::from(WORKING_GROUP_MINT_CAPACITY_MAX_VALUE),
-                Error::<T>::InvalidWorkingGroupMintCapacity
-            );
-
             let proposal_details = ProposalDetails::SetWorkingGroupMintCapacity(mint_balance, working_group);
+            Self::ensure_proposal_details_valid(&proposal_details)?;
+
             let params = CreateProposalParameters{
                 origin,
                 member_id,
                 title,
                 description,
                 stake_balance,
+                exactly_execute_at,
                 proposal_details: proposal_details.clone(),
                 proposal_parameters: proposal_types::parameters::set_working_group_mint_capacity_proposal::<T>(),
                 proposal_code: T::ProposalEncoder::encode_proposal(proposal_details)
@@ -731,25 +863,27 @@ decl_module! {
 
         /// Create 'decrease working group leader stake' proposal type.
         /// This proposal uses `decrease_stake()` extrinsic from the `working-group`  module.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::create_decrease_working_group_leader_stake_proposal(
+            title.len() as u32,
+            description.len() as u32,
+        )]
         pub fn create_decrease_working_group_leader_stake_proposal(
             origin,
             member_id: MemberId<T>,
             title: Vec<u8>,
             description: Vec<u8>,
             stake_balance: Option<BalanceOf<T>>,
+            exactly_execute_at: Option<T::BlockNumber>,
             worker_id: working_group::WorkerId<T>,
             decreasing_stake: BalanceOf<T>,
             working_group: WorkingGroup,
         ) {
-
-            ensure!(decreasing_stake != Zero::zero(), Error::<T>::DecreasingStakeIsZero);
-
             let proposal_details = ProposalDetails::DecreaseWorkingGroupLeaderStake(
                 worker_id,
                 decreasing_stake,
                 working_group
             );
+            Self::ensure_proposal_details_valid(&proposal_details)?;
 
             let params = CreateProposalParameters{
                 origin,
@@ -757,6 +891,7 @@ decl_module! {
                 title,
                 description,
                 stake_balance,
+                exactly_execute_at,
                 proposal_details: proposal_details.clone(),
                 proposal_parameters: proposal_types::parameters::decrease_working_group_leader_stake_proposal::<T>(),
                 proposal_code: T::ProposalEncoder::encode_proposal(proposal_details)
@@ -767,25 +902,27 @@ decl_module! {
 
         /// Create 'slash working group leader stake' proposal type.
         /// This proposal uses `slash_stake()` extrinsic from the `working-group`  module.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::create_slash_working_group_leader_stake_proposal(
+            title.len() as u32,
+            description.len() as u32,
+        )]
         pub fn create_slash_working_group_leader_stake_proposal(
             origin,
             member_id: MemberId<T>,
             title: Vec<u8>,
             description: Vec<u8>,
             stake_balance: Option<BalanceOf<T>>,
+            exactly_execute_at: Option<T::BlockNumber>,
             worker_id: working_group::WorkerId<T>,
             slashing_stake: BalanceOf<T>,
             working_group: WorkingGroup,
         ) {
-
-            ensure!(slashing_stake != Zero::zero(), Error::<T>::SlashingStakeIsZero);
-
             let proposal_details = ProposalDetails::SlashWorkingGroupLeaderStake(
                 worker_id,
                 slashing_stake,
                 working_group
             );
+            Self::ensure_proposal_details_valid(&proposal_details)?;
 
             let params = CreateProposalParameters{
                 origin,
@@ -793,6 +930,7 @@ decl_module! {
                 title,
                 description,
                 stake_balance,
+                exactly_execute_at,
                 proposal_details: proposal_details.clone(),
                 proposal_parameters: proposal_types::parameters::slash_working_group_leader_stake_proposal::<T>(),
                 proposal_code: T::ProposalEncoder::encode_proposal(proposal_details)
@@ -803,13 +941,17 @@ decl_module! {
 
         /// Create 'set working group leader reward' proposal type.
         /// This proposal uses `update_reward_amount()` extrinsic from the `working-group`  module.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::create_set_working_group_leader_reward_proposal(
+            title.len() as u32,
+            description.len() as u32,
+        )]
         pub fn create_set_working_group_leader_reward_proposal(
             origin,
             member_id: MemberId<T>,
             title: Vec<u8>,
             description: Vec<u8>,
             stake_balance: Option<BalanceOf<T>>,
+            exactly_execute_at: Option<T::BlockNumber>,
             worker_id: working_group::WorkerId<T>,
             reward_amount: BalanceOfMint<T>,
             working_group: WorkingGroup,
@@ -827,6 +969,7 @@ decl_module! {
                 title,
                 description,
                 stake_balance,
+                exactly_execute_at,
                 proposal_details: proposal_details.clone(),
                 proposal_parameters: proposal_types::parameters::set_working_group_leader_reward_proposal::<T>(),
                 proposal_code: T::ProposalEncoder::encode_proposal(proposal_details)
@@ -837,13 +980,18 @@ decl_module! {
 
         /// Create 'terminate working group leader rolw' proposal type.
         /// This proposal uses `terminate_role()` extrinsic from the `working-group`  module.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::create_terminate_working_group_leader_role_proposal(
+            title.len() as u32,
+            description.len() as u32,
+            terminate_role_parameters.rationale.len() as u32,
+        )]
         pub fn create_terminate_working_group_leader_role_proposal(
             origin,
             member_id: MemberId<T>,
             title: Vec<u8>,
             description: Vec<u8>,
             stake_balance: Option<BalanceOf<T>>,
+            exactly_execute_at: Option<T::BlockNumber>,
             terminate_role_parameters: TerminateRoleParameters<working_group::WorkerId<T>>,
         ) {
             let proposal_details = ProposalDetails::TerminateWorkingGroupLeaderRole(terminate_role_parameters);
@@ -854,6 +1002,7 @@ decl_module! {
                 title,
                 description,
                 stake_balance,
+                exactly_execute_at,
                 proposal_details: proposal_details.clone(),
                 proposal_parameters: proposal_types::parameters::terminate_working_group_leader_role_proposal::<T>(),
                 proposal_code: T::ProposalEncoder::encode_proposal(proposal_details)
@@ -862,11 +1011,153 @@ decl_module! {
             Self::create_proposal(params)?;
         }
 
+        /// Create 'set mint capacity adjustment' proposal type. No adjustment pauses the mint.
+        /// This proposal uses `set_mint_adjustment()` extrinsic from the `token-minting`  module.
+        #[weight = <T as Trait>::WeightInfo::create_set_mint_capacity_adjustment_proposal(
+            title.len() as u32,
+            description.len() as u32,
+        )]
+        pub fn create_set_mint_capacity_adjustment_proposal(
+            origin,
+            member_id: MemberId<T>,
+            title: Vec<u8>,
+            description: Vec<u8>,
+            stake_balance: Option<BalanceOf<T>>,
+            exactly_execute_at: Option<T::BlockNumber>,
+            mint: GovernedMint,
+            adjustment: Option<minting::Adjustment<BalanceOfMint<T>, T::BlockNumber>>,
+        ) {
+            let proposal_details = ProposalDetails::SetMintCapacityAdjustment(mint, adjustment);
+            Self::ensure_proposal_details_valid(&proposal_details)?;
+
+            let params = CreateProposalParameters{
+                origin,
+                member_id,
+                title,
+                description,
+                stake_balance,
+                exactly_execute_at,
+                proposal_details: proposal_details.clone(),
+                proposal_parameters: proposal_types::parameters::set_mint_capacity_adjustment_proposal::<T>(),
+                proposal_code: T::ProposalEncoder::encode_proposal(proposal_details)
+            };
+
+            Self::create_proposal(params)?;
+        }
+
+        /// Create 'set proposal parameters' proposal type. The new proposal parameters apply to the
+        /// proposals created after the execution.
+        /// This proposal uses `set_proposal_parameters()` extrinsic from this module.
+        #[weight = <T as Trait>::WeightInfo::create_set_proposal_parameters_proposal(
+            title.len() as u32,
+            description.len() as u32,
+        )]
+        pub fn create_set_proposal_parameters_proposal(
+            origin,
+            member_id: MemberId<T>,
+            title: Vec<u8>,
+            description: Vec<u8>,
+            stake_balance: Option<BalanceOf<T>>,
+            exactly_execute_at: Option<T::BlockNumber>,
+            proposal_kind: ProposalKind,
+            proposal_parameters: ProposalParameters<T::BlockNumber, BalanceOf<T>>,
+        ) {
+            let proposal_details =
+                ProposalDetails::SetProposalParameters(proposal_kind, proposal_parameters);
+            Self::ensure_proposal_details_valid(&proposal_details)?;
+
+            let params = CreateProposalParameters{
+                origin,
+                member_id,
+                title,
+                description,
+                stake_balance,
+                exactly_execute_at,
+                proposal_details: proposal_details.clone(),
+                proposal_parameters: proposal_types::parameters::set_proposal_parameters_proposal::<T>(),
+                proposal_code: T::ProposalEncoder::encode_proposal(proposal_details)
+            };
+
+            Self::create_proposal(params)?;
+        }
+
+        /// Create 'batch' proposal type. The batched proposals are executed together: either all
+        /// of them or none. Proposal parameters are the strictest of the batched proposals.
+        /// This proposal uses `execute_batch_proposal()` extrinsic from this module.
+        #[weight = <T as Trait>::WeightInfo::create_batch_proposal(
+            title.len() as u32,
+            description.len() as u32,
+            proposals.encode().len() as u32,
+        )]
+        pub fn create_batch_proposal(
+            origin,
+            member_id: MemberId<T>,
+            title: Vec<u8>,
+            description: Vec<u8>,
+            stake_balance: Option<BalanceOf<T>>,
+            exactly_execute_at: Option<T::BlockNumber>,
+            proposals: Vec<ProposalDetailsOf<T>>,
+        ) {
+            let proposal_parameters = proposal_types::parameters::batch_proposal::<T>(&proposals);
+
+            let proposal_details = ProposalDetails::Batch(proposals);
+            Self::ensure_proposal_details_valid(&proposal_details)?;
+
+            let params = CreateProposalParameters{
+                origin,
+                member_id,
+                title,
+                description,
+                stake_balance,
+                exactly_execute_at,
+                proposal_details: proposal_details.clone(),
+                proposal_parameters,
+                proposal_code: T::ProposalEncoder::encode_proposal(proposal_details)
+            };
+
+            Self::create_proposal(params)?;
+        }
+
+        /// Create 'amend constitution' proposal type. The amendment becomes the new version of the
+        /// constitution.
+        /// This proposal uses `amend_constitution()` extrinsic from the `constitution` module.
+        #[weight = <T as Trait>::WeightInfo::create_amend_constitution_proposal(
+            title.len() as u32,
+            description.len() as u32,
+            amendment.encode().len() as u32,
+        )]
+        pub fn create_amend_constitution_proposal(
+            origin,
+            member_id: MemberId<T>,
+            title: Vec<u8>,
+            description: Vec<u8>,
+            stake_balance: Option<BalanceOf<T>>,
+            exactly_execute_at: Option<T::BlockNumber>,
+            amendment: constitution::ConstitutionAmendmentOf<T>,
+        ) {
+            let proposal_details = ProposalDetails::AmendConstitution(amendment);
+            Self::ensure_proposal_details_valid(&proposal_details)?;
+
+            let params = CreateProposalParameters{
+                origin,
+                member_id,
+                title,
+                description,
+                stake_balance,
+                exactly_execute_at,
+                proposal_details: proposal_details.clone(),
+                proposal_parameters: proposal_types::parameters::amend_constitution_proposal::<T>(),
+                proposal_code: T::ProposalEncoder::encode_proposal(proposal_details)
+            };
+
+            Self::create_proposal(params)?;
+        }
+
 
 // *************** Extrinsic to execute
 
         /// Text proposal extrinsic. Should be used as callable object to pass to the `engine` module.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::execute_text_proposal(text.len() as u32)]
         pub fn execute_text_proposal(
             origin,
             text: Vec<u8>,
@@ -881,7 +1172,8 @@ decl_module! {
 
         /// Runtime upgrade proposal extrinsic.
         /// Should be used as callable object to pass to the `engine` module.
-        #[weight = 10_000_000] // TODO: adjust weight
+        // Same as the `system::set_code()` weight: the runtime upgrade occupies the whole block.
+        #[weight = (T::MaximumBlockWeight::get(), DispatchClass::Operational)]
         pub fn execute_runtime_upgrade_proposal(
             origin,
             wasm: Vec<u8>,
@@ -895,6 +1187,42 @@ decl_module! {
 
             print("Runtime upgrade proposal execution finished.");
         }
+
+        /// Batch proposal extrinsic. Should be used as callable object to pass to the `engine` module.
+        /// Dispatches the encoded proposal calls in order and stops on the first failure. The
+        /// `engine` module reverts the storage changes of the failed execution.
+        #[weight = <T as Trait>::WeightInfo::execute_batch_proposal(proposal_codes.len() as u32)]
+        pub fn execute_batch_proposal(
+            origin,
+            proposal_codes: Vec<Vec<u8>>,
+        ) {
+            ensure_root(origin)?;
+
+            for proposal_code in proposal_codes {
+                let proposal_call =
+                    <T as proposals_engine::Trait>::DispatchableCallCode::decode(&mut &proposal_code[..])
+                        .map_err(|_| Error::<T>::InvalidBatchProposalCode)?;
+
+                proposal_call
+                    .dispatch_bypass_filter(T::Origin::from(system::RawOrigin::Root))
+                    .map_err(|err| err.error)?;
+            }
+        }
+
+        /// Sets the proposal parameters of the proposal kind.
+        /// Should be used as callable object to pass to the `engine` module.
+        #[weight = <T as Trait>::WeightInfo::set_proposal_parameters()]
+        pub fn set_proposal_parameters(
+            origin,
+            proposal_kind: ProposalKind,
+            proposal_parameters: ProposalParameters<T::BlockNumber, BalanceOf<T>>,
+        ) {
+            ensure_root(origin)?;
+
+            Self::ensure_proposal_parameters_valid(proposal_kind, &proposal_parameters)?;
+
+            <ProposalParametersByKind<T>>::insert(proposal_kind, proposal_parameters);
+        }
     }
 }
 
@@ -909,6 +1237,12 @@ impl<T: Trait> Module<T> {
             &params.title,
             &params.description,
             params.stake_balance,
+            params.exactly_execute_at,
+        )?;
+
+        Self::ensure_mint_capacity_adjustments_after_execution(
+            &params.proposal_details,
+            Self::latest_execution_block(&params.proposal_parameters, params.exactly_execute_at),
         )?;
 
         <proposals_discussion::Module<T>>::ensure_can_create_thread(
@@ -928,6 +1262,7 @@ impl<T: Trait> Module<T> {
             params.title,
             params.description,
             params.stake_balance,
+            params.exactly_execute_at,
             params.proposal_code,
         )?;
 
@@ -1040,98 +1375,440 @@ impl<T: Trait> Module<T> {
         Ok(())
     }
 
+    // validates the proposal details using the checks of its proposal type
+    fn ensure_proposal_details_valid(proposal_details: &ProposalDetailsOf<T>) -> DispatchResult {
+        match proposal_details {
+            ProposalDetails::Text(text) => {
+                ensure!(!text.is_empty(), Error::<T>::TextProposalIsEmpty);
+                ensure!(
+                    text.len() as u32 <= T::TextProposalMaxLength::get(),
+                    Error::<T>::TextProposalSizeExceeded
+                );
+            }
+            ProposalDetails::RuntimeUpgrade(wasm) => {
+                ensure!(!wasm.is_empty(), Error::<T>::RuntimeProposalIsEmpty);
+                ensure!(
+                    wasm.len() as u32 <= T::RuntimeUpgradeWasmProposalMaxLength::get(),
+                    Error::<T>::RuntimeProposalSizeExceeded
+                );
+            }
+            ProposalDetails::SetElectionParameters(election_parameters) => {
+                election_parameters.ensure_valid()?;
+
+                Self::ensure_council_election_parameters_valid(election_parameters)?;
+            }
+            ProposalDetails::Spending(balance, _) => {
+                ensure!(
+                    *balance != BalanceOfMint::<T>::zero(),
+                    Error::<T>::InvalidSpendingProposalBalance
+                );
+                ensure!(
+                    *balance <= <BalanceOfMint<T>>::from(MAX_SPENDING_PROPOSAL_VALUE),
+                    Error::<T>::InvalidSpendingProposalBalance
+                );
+            }
+            ProposalDetails::SetLead(..) => {
+                return Err(Error::<T>::SetLeadProposalIsDeprecated.into());
+            }
+            ProposalDetails::SetContentWorkingGroupMintCapacity(mint_balance) => {
+                ensure!(
+                    *mint_balance
+                        <= <BalanceOfMint<T>>::from(CONTENT_WORKING_GROUP_MINT_CAPACITY_MAX_VALUE),
+                    Error::<T>::InvalidContentWorkingGroupMintCapacity
+                );
+            }
+            ProposalDetails::SetValidatorCount(new_validator_count) => {
+                ensure!(
+                    *new_validator_count >= <staking::Module<T>>::minimum_validator_count(),
+                    Error::<T>::InvalidValidatorCount
+                );
+                ensure!(
+                    *new_validator_count <= MAX_VALIDATOR_COUNT,
+                    Error::<T>::InvalidValidatorCount
+                );
+            }
+            ProposalDetails::SetWorkingGroupMintCapacity(mint_balance, _) => {
+                ensure!(
+                    *mint_balance
+                        <= <BalanceOfMint<T>>::from(WORKING_GROUP_MINT_CAPACITY_MAX_VALUE),
+                    Error::<T>::InvalidWorkingGroupMintCapacity
+                );
+            }
+            ProposalDetails::DecreaseWorkingGroupLeaderStake(_, decreasing_stake, _) => {
+                ensure!(
+                    *decreasing_stake != Zero::zero(),
+                    Error::<T>::DecreasingStakeIsZero
+                );
+            }
+            ProposalDetails::SlashWorkingGroupLeaderStake(_, slashing_stake, _) => {
+                ensure!(
+                    *slashing_stake != Zero::zero(),
+                    Error::<T>::SlashingStakeIsZero
+                );
+            }
+            ProposalDetails::SetMintCapacityAdjustment(_, adjustment) => {
+                if let Some(adjustment) = adjustment {
+                    Self::ensure_mint_capacity_adjustment_valid(*adjustment)?;
+                }
+            }
+            ProposalDetails::SetProposalParameters(proposal_kind, proposal_parameters) => {
+                Self::ensure_proposal_parameters_valid(*proposal_kind, proposal_parameters)?;
+            }
+            ProposalDetails::AmendConstitution(amendment) => {
+                <constitution::Module<T>>::ensure_amendment_valid(amendment)?;
+            }
+            ProposalDetails::Batch(proposals) => {
+                ensure!(!proposals.is_empty(), Error::<T>::BatchProposalIsEmpty);
+                ensure!(
+                    proposals.len() as u32 <= MAX_BATCH_PROPOSAL_SIZE,
+                    Error::<T>::BatchProposalSizeExceeded
+                );
+
+                for proposal_details in proposals {
+                    match proposal_details {
+                        // The runtime upgrade weight is not accounted for in the batch
+                        // execution weight.
+                        ProposalDetails::Batch(..)
+                        | ProposalDetails::RuntimeUpgrade(..)
+                        | ProposalDetails::EvictStorageProvider(..)
+                        | ProposalDetails::SetStorageRoleParameters(..) => {
+                            return Err(Error::<T>::InvalidBatchProposalItem.into());
+                        }
+                        _ => Self::ensure_proposal_details_valid(proposal_details)?,
+                    }
+                }
+            }
+            ProposalDetails::AddWorkingGroupLeaderOpening(..)
+            | ProposalDetails::BeginReviewWorkingGroupLeaderApplications(..)
+            | ProposalDetails::FillWorkingGroupLeaderOpening(..)
+            | ProposalDetails::SetWorkingGroupLeaderReward(..)
+            | ProposalDetails::TerminateWorkingGroupLeaderRole(..)
+            | ProposalDetails::EvictStorageProvider(..)
+            | ProposalDetails::SetStorageRoleParameters(..) => {}
+        }
+
+        Ok(())
+    }
+
+    // Returns the block at which the proposal created now is executed at the latest.
+    fn latest_execution_block(
+        proposal_parameters: &ProposalParameters<T::BlockNumber, BalanceOf<T>>,
+        exactly_execute_at: Option<T::BlockNumber>,
+    ) -> T::BlockNumber {
+        exactly_execute_at.unwrap_or_else(|| {
+            <system::Module<T>>::block_number()
+                + proposal_parameters.voting_period
+                + proposal_parameters.grace_period
+        })
+    }
+
+    // ensures the absolute first adjustments of the 'set mint capacity adjustment' proposals,
+    // the batched ones included, are not passed by the proposal execution
+    fn ensure_mint_capacity_adjustments_after_execution(
+        proposal_details: &ProposalDetailsOf<T>,
+        latest_execution_block: T::BlockNumber,
+    ) -> DispatchResult {
+        match proposal_details {
+            ProposalDetails::SetMintCapacityAdjustment(
+                _,
+                Some(minting::Adjustment::IntervalAfterFirstAdjustmentAbsolute(
+                    _,
+                    first_adjustment_at,
+                )),
+            ) => {
+                ensure!(
+                    *first_adjustment_at > latest_execution_block,
+                    Error::<T>::MintCapacityAdjustmentBeforeExecution
+                );
+            }
+            ProposalDetails::Batch(proposals) => {
+                for proposal_details in proposals {
+                    Self::ensure_mint_capacity_adjustments_after_execution(
+                        proposal_details,
+                        latest_execution_block,
+                    )?;
+                }
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    // validates the adjustment for the 'set mint capacity adjustment' proposal
+    fn ensure_mint_capacity_adjustment_valid(
+        adjustment: minting::Adjustment<BalanceOfMint<T>, T::BlockNumber>,
+    ) -> DispatchResult {
+        let adjust_on_interval = match adjustment {
+            minting::Adjustment::Interval(adjust_on_interval) => adjust_on_interval,
+            minting::Adjustment::IntervalAfterFirstAdjustmentAbsolute(adjust_on_interval, _) => {
+                adjust_on_interval
+            }
+            minting::Adjustment::IntervalAfterFirstAdjustmentRelative(adjust_on_interval, _) => {
+                adjust_on_interval
+            }
+        };
+
+        ensure!(
+            !adjust_on_interval.block_interval.is_zero(),
+            Error::<T>::InvalidMintCapacityAdjustment
+        );
+
+        let amount = match adjust_on_interval.adjustment_type {
+            minting::AdjustCapacityBy::Setting(amount) => amount,
+            minting::AdjustCapacityBy::Adding(amount) => amount,
+            minting::AdjustCapacityBy::Reducing(amount) => amount,
+        };
+
+        ensure!(
+            amount <= <BalanceOfMint<T>>::from(MINT_CAPACITY_ADJUSTMENT_MAX_VALUE),
+            Error::<T>::InvalidMintCapacityAdjustment
+        );
+
+        Ok(())
+    }
+
+    // validates the proposal parameters for the 'set proposal parameters' proposal
+    fn ensure_proposal_parameters_valid(
+        proposal_kind: ProposalKind,
+        proposal_parameters: &ProposalParameters<T::BlockNumber, BalanceOf<T>>,
+    ) -> DispatchResult {
+        ensure!(
+            proposal_parameters.voting_period
+                >= T::BlockNumber::from(PROPOSAL_PARAMETERS_VOTING_PERIOD_MIN_VALUE),
+            Error::<T>::InvalidProposalParametersVotingPeriod
+        );
+        ensure!(
+            proposal_parameters.voting_period
+                <= T::BlockNumber::from(PROPOSAL_PARAMETERS_VOTING_PERIOD_MAX_VALUE),
+            Error::<T>::InvalidProposalParametersVotingPeriod
+        );
+        ensure!(
+            proposal_parameters.grace_period
+                <= T::BlockNumber::from(PROPOSAL_PARAMETERS_GRACE_PERIOD_MAX_VALUE),
+            Error::<T>::InvalidProposalParametersGracePeriod
+        );
+
+        // Changing the proposal parameters and the constitution requires a supermajority.
+        let approval_percentage_min_value = match proposal_kind {
+            ProposalKind::SetProposalParameters | ProposalKind::AmendConstitution => {
+                PROPOSAL_PARAMETERS_SUPERMAJORITY_PERCENTAGE_MIN_VALUE
+            }
+            _ => PROPOSAL_PARAMETERS_APPROVAL_PERCENTAGE_MIN_VALUE,
+        };
+
+        for approval_percentage in [
+            proposal_parameters.approval_quorum_percentage,
+            proposal_parameters.approval_threshold_percentage,
+        ]
+        .iter()
+        {
+            ensure!(
+                *approval_percentage >= approval_percentage_min_value
+                    && *approval_percentage <= 100,
+                Error::<T>::InvalidProposalParametersPercentage
+            );
+        }
+
+        for slashing_percentage in [
+            proposal_parameters.slashing_quorum_percentage,
+            proposal_parameters.slashing_threshold_percentage,
+        ]
+        .iter()
+        {
+            ensure!(
+                *slashing_percentage > 0 && *slashing_percentage <= 100,
+                Error::<T>::InvalidProposalParametersPercentage
+            );
+        }
+
+        let required_stake = proposal_parameters
+            .required_stake
+            .ok_or(Error::<T>::InvalidProposalParametersRequiredStake)?;
+
+        ensure!(
+            required_stake >= <BalanceOf<T>>::from(PROPOSAL_PARAMETERS_REQUIRED_STAKE_MIN_VALUE),
+            Error::<T>::InvalidProposalParametersRequiredStake
+        );
+        ensure!(
+            required_stake <= <BalanceOf<T>>::from(PROPOSAL_PARAMETERS_REQUIRED_STAKE_MAX_VALUE),
+            Error::<T>::InvalidProposalParametersRequiredStake
+        );
+
+        Ok(())
+    }
+
     /// Sets config values for the proposals.
     /// Should be called on the migration to the new runtime version.
     pub fn set_config_values(p: ProposalsConfigParameters) {
-        <SetValidatorCountProposalVotingPeriod<T>>::put(T::BlockNumber::from(
-            p.set_validator_count_proposal_voting_period,
-        ));
-        <SetValidatorCountProposalGracePeriod<T>>::put(T::BlockNumber::from(
-            p.set_validator_count_proposal_grace_period,
-        ));
-        <RuntimeUpgradeProposalVotingPeriod<T>>::put(T::BlockNumber::from(
-            p.runtime_upgrade_proposal_voting_period,
-        ));
-        <RuntimeUpgradeProposalGracePeriod<T>>::put(T::BlockNumber::from(
-            p.runtime_upgrade_proposal_grace_period,
-        ));
-        <TextProposalVotingPeriod<T>>::put(T::BlockNumber::from(p.text_proposal_voting_period));
-        <TextProposalGracePeriod<T>>::put(T::BlockNumber::from(p.text_proposal_grace_period));
-        <SetElectionParametersProposalVotingPeriod<T>>::put(T::BlockNumber::from(
-            p.set_election_parameters_proposal_voting_period,
-        ));
-        <SetElectionParametersProposalGracePeriod<T>>::put(T::BlockNumber::from(
-            p.set_election_parameters_proposal_grace_period,
-        ));
-        <SetContentWorkingGroupMintCapacityProposalVotingPeriod<T>>::put(T::BlockNumber::from(
-            p.set_content_working_group_mint_capacity_proposal_voting_period,
-        ));
-        <SetContentWorkingGroupMintCapacityProposalGracePeriod<T>>::put(T::BlockNumber::from(
-            p.set_content_working_group_mint_capacity_proposal_grace_period,
-        ));
-        <SetLeadProposalVotingPeriod<T>>::put(T::BlockNumber::from(
-            p.set_lead_proposal_voting_period,
-        ));
-        <SetLeadProposalGracePeriod<T>>::put(T::BlockNumber::from(
-            p.set_lead_proposal_grace_period,
-        ));
-        <SpendingProposalVotingPeriod<T>>::put(T::BlockNumber::from(
-            p.spending_proposal_voting_period,
-        ));
-        <SpendingProposalGracePeriod<T>>::put(T::BlockNumber::from(
-            p.spending_proposal_grace_period,
-        ));
-        <AddWorkingGroupOpeningProposalVotingPeriod<T>>::put(T::BlockNumber::from(
-            p.add_working_group_opening_proposal_voting_period,
-        ));
-        <AddWorkingGroupOpeningProposalGracePeriod<T>>::put(T::BlockNumber::from(
-            p.add_working_group_opening_proposal_grace_period,
-        ));
-        <BeginReviewWorkingGroupLeaderApplicationsProposalVotingPeriod<T>>::put(
-            T::BlockNumber::from(
-                p.begin_review_working_group_leader_applications_proposal_voting_period,
-            ),
-        );
-        <BeginReviewWorkingGroupLeaderApplicationsProposalGracePeriod<T>>::put(
-            T::BlockNumber::from(
-                p.begin_review_working_group_leader_applications_proposal_grace_period,
-            ),
+        for proposal_kind in ProposalKind::all() {
+            let (voting_period, grace_period) = p.voting_and_grace_periods(proposal_kind);
+
+            <ProposalParametersByKind<T>>::insert(
+                proposal_kind,
+                proposal_types::parameters::config_proposal_parameters::<T>(
+                    &p,
+                    proposal_kind,
+                    T::BlockNumber::from(voting_period),
+                    T::BlockNumber::from(grace_period),
+                ),
+            );
+        }
+    }
+
+    /// Moves the voting and grace periods stored per proposal by the previous runtime versions
+    /// to the proposal parameters. Not configured periods and the proposal kinds added by the
+    /// later runtime versions get the default values. Migrated proposal kinds are skipped.
+    pub fn migrate_proposal_parameters() -> Weight {
+        let default_config = ProposalsConfigParameters::default();
+        let proposal_kinds = ProposalKind::all();
+        let mut migrated_kinds_count: Weight = 0;
+
+        for proposal_kind in proposal_kinds.iter() {
+            if <ProposalParametersByKind<T>>::contains_key(proposal_kind) {
+                continue;
+            }
+
+            migrated_kinds_count += 1;
+
+            let (default_voting_period, default_grace_period) =
+                default_config.voting_and_grace_periods(*proposal_kind);
+
+            let take_period = |suffix: &[u8]| {
+                Self::legacy_periods_storage_prefix(*proposal_kind).and_then(|prefix| {
+                    frame_support::storage::migration::take_storage_value::<T::BlockNumber>(
+                        b"ProposalCodex",
+                        &[prefix.as_bytes(), suffix].concat(),
+                        &[],
+                    )
+                })
+            };
+            let voting_period = take_period(b"VotingPeriod");
+            let grace_period = take_period(b"GracePeriod");
+
+            let (voting_period, grace_period) = match voting_period {
+                Some(voting_period) if !voting_period.is_zero() => {
+                    (voting_period, grace_period.unwrap_or_else(Zero::zero))
+                }
+                _ => (
+                    T::BlockNumber::from(default_voting_period),
+                    T::BlockNumber::from(default_grace_period),
+                ),
+            };
+
+            <ProposalParametersByKind<T>>::insert(
+                proposal_kind,
+                proposal_types::parameters::config_proposal_parameters::<T>(
+                    &default_config,
+                    *proposal_kind,
+                    voting_period,
+                    grace_period,
+                ),
+            );
+        }
+
+        let kinds_count = proposal_kinds.len() as Weight;
+
+        T::DbWeight::get().reads_writes(
+            kinds_count + 2 * migrated_kinds_count,
+            3 * migrated_kinds_count,
+        )
+    }
+
+    /// Converts the proposal details stored by the previous runtime versions. The proposals not
+    /// finalized yet get their code encoded again from the converted details, as the code
+    /// encoded by the previous runtime versions no longer decodes. Does nothing once migrated.
+    pub fn migrate_proposal_details() -> Weight {
+        if Self::proposal_details_migrated() {
+            return T::DbWeight::get().reads(1);
+        }
+
+        <ProposalDetailsByProposalId<T>>::translate(
+            |_, proposal_details: LegacyProposalDetailsOf<T>| Some(proposal_details.migrate()),
         );
-        <FillWorkingGroupLeaderOpeningProposalVotingPeriod<T>>::put(T::BlockNumber::from(
-            p.fill_working_group_leader_opening_proposal_voting_period,
-        ));
-        <FillWorkingGroupLeaderOpeningProposalGracePeriod<T>>::put(T::BlockNumber::from(
-            p.fill_working_group_leader_opening_proposal_grace_period,
-        ));
-        <SetWorkingGroupMintCapacityProposalVotingPeriod<T>>::put(T::BlockNumber::from(
-            p.set_working_group_mint_capacity_proposal_voting_period,
-        ));
-        <SetWorkingGroupMintCapacityProposalGracePeriod<T>>::put(T::BlockNumber::from(
-            p.set_working_group_mint_capacity_proposal_grace_period,
-        ));
-        <DecreaseWorkingGroupLeaderStakeProposalVotingPeriod<T>>::put(T::BlockNumber::from(
-            p.decrease_working_group_leader_stake_proposal_voting_period,
-        ));
-        <DecreaseWorkingGroupLeaderStakeProposalGracePeriod<T>>::put(T::BlockNumber::from(
-            p.decrease_working_group_leader_stake_proposal_grace_period,
-        ));
-        <SlashWorkingGroupLeaderStakeProposalVotingPeriod<T>>::put(T::BlockNumber::from(
-            p.slash_working_group_leader_stake_proposal_voting_period,
-        ));
-        <SlashWorkingGroupLeaderStakeProposalGracePeriod<T>>::put(T::BlockNumber::from(
-            p.slash_working_group_leader_stake_proposal_grace_period,
-        ));
-        <SetWorkingGroupLeaderRewardProposalVotingPeriod<T>>::put(T::BlockNumber::from(
-            p.set_working_group_leader_reward_proposal_voting_period,
-        ));
-        <SetWorkingGroupLeaderRewardProposalGracePeriod<T>>::put(T::BlockNumber::from(
-            p.set_working_group_leader_reward_proposal_grace_period,
-        ));
-        <TerminateWorkingGroupLeaderRoleProposalVotingPeriod<T>>::put(T::BlockNumber::from(
-            p.terminate_working_group_leader_role_proposal_voting_period,
-        ));
-        <TerminateWorkingGroupLeaderRoleProposalGracePeriod<T>>::put(T::BlockNumber::from(
-            p.terminate_working_group_leader_role_proposal_grace_period,
-        ));
+
+        let mut encoded_proposals_count: Weight = 0;
+
+        let not_finalized_proposal_ids = <proposals_engine::ActiveProposalIds<T>>::iter()
+            .chain(<proposals_engine::PendingExecutionProposalIds<T>>::iter())
+            .map(|(proposal_id, _)| proposal_id)
+            .collect::<Vec<_>>();
+
+        for proposal_id in not_finalized_proposal_ids {
+            if <ProposalDetailsByProposalId<T>>::contains_key(proposal_id) {
+                let proposal_code = T::ProposalEncoder::encode_proposal(
+                    Self::proposal_details_by_proposal_id(proposal_id),
+                );
+
+                <proposals_engine::DispatchableCallCode<T>>::insert(proposal_id, proposal_code);
+
+                encoded_proposals_count += 1;
+            }
+        }
+
+        ProposalDetailsMigrated::put(true);
+
+        // Proposals are never removed.
+        let proposals_count = Weight::from(<proposals_engine::Module<T>>::proposal_count());
+
+        T::DbWeight::get().reads_writes(
+            2 + 2 * proposals_count + encoded_proposals_count,
+            1 + proposals_count + encoded_proposals_count,
+        )
+    }
+
+    // Storage item name prefix of the voting and grace periods stored per proposal by the
+    // previous runtime versions.
+    fn legacy_periods_storage_prefix(proposal_kind: ProposalKind) -> Option<&'static str> {
+        match proposal_kind {
+            ProposalKind::Text => Some("TextProposal"),
+            ProposalKind::RuntimeUpgrade => Some("RuntimeUpgradeProposal"),
+            ProposalKind::SetElectionParameters => Some("SetElectionParametersProposal"),
+            ProposalKind::Spending => Some("SpendingProposal"),
+            ProposalKind::SetLead => Some("SetLeadProposal"),
+            ProposalKind::SetContentWorkingGroupMintCapacity => {
+                Some("SetContentWorkingGroupMintCapacityProposal")
+            }
+            ProposalKind::SetValidatorCount => Some("SetValidatorCountProposal"),
+            ProposalKind::AddWorkingGroupLeaderOpening => Some("AddWorkingGroupOpeningProposal"),
+            ProposalKind::BeginReviewWorkingGroupLeaderApplications => {
+                Some("BeginReviewWorkingGroupLeaderApplicationsProposal")
+            }
+            ProposalKind::FillWorkingGroupLeaderOpening => {
+                Some("FillWorkingGroupLeaderOpeningProposal")
+            }
+            ProposalKind::SetWorkingGroupMintCapacity => {
+                Some("SetWorkingGroupMintCapacityProposal")
+            }
+            ProposalKind::DecreaseWorkingGroupLeaderStake => {
+                Some("DecreaseWorkingGroupLeaderStakeProposal")
+            }
+            ProposalKind::SlashWorkingGroupLeaderStake => {
+                Some("SlashWorkingGroupLeaderStakeProposal")
+            }
+            ProposalKind::SetWorkingGroupLeaderReward => {
+                Some("SetWorkingGroupLeaderRewardProposal")
+            }
+            ProposalKind::TerminateWorkingGroupLeaderRole => {
+                Some("TerminateWorkingGroupLeaderRoleProposal")
+            }
+            ProposalKind::SetMintCapacityAdjustment => Some("SetMintCapacityAdjustmentProposal"),
+            ProposalKind::SetProposalParameters | ProposalKind::AmendConstitution => None,
+        }
+    }
+
+    /// Proposal parameters of the proposal kind. Not configured proposal kinds get the default
+    /// parameters with zero voting and grace periods.
+    pub fn proposal_parameters(
+        proposal_kind: ProposalKind,
+    ) -> ProposalParameters<T::BlockNumber, BalanceOf<T>> {
+        Self::proposal_parameters_by_kind(proposal_kind).unwrap_or_else(|| {
+            proposal_types::parameters::config_proposal_parameters::<T>(
+                &ProposalsConfigParameters::default(),
+                proposal_kind,
+                Zero::zero(),
+                Zero::zero(),
+            )
+        })
     }
 }
@@ -5,10 +5,12 @@ pub(crate) mod parameters;
 use codec::{Decode, Encode};
 #[cfg(feature = "std")]
 use serde::{Deserialize, Serialize};
+use sp_std::vec;
 use sp_std::vec::Vec;
 
-use crate::ElectionParameters;
+use crate::{ElectionParameters, ProposalParameters};
 use common::working_group::WorkingGroup;
+use governance::election_params::LegacyElectionParameters;
 
 /// Encodes proposal using its details information.
 pub trait ProposalEncoder<T: crate::Trait> {
@@ -27,6 +29,8 @@ pub type ProposalDetailsOf<T> = ProposalDetails<
     working_group::ApplicationId<T>,
     crate::BalanceOf<T>,
     working_group::WorkerId<T>,
+    <T as system::Trait>::Hash,
+    <T as constitution::Trait>::ContentId,
 >;
 
 /// Proposal details provide voters the information required for the perceived voting.
@@ -42,6 +46,8 @@ pub enum ProposalDetails<
     ApplicationId,
     StakeBalance,
     WorkerId,
+    Hash,
+    ContentId,
 > {
     /// The text of the `text` proposal
     Text(Vec<u8>),
@@ -99,6 +105,37 @@ pub enum ProposalDetails<
 
     /// Fire the working group leader with possible slashing.
     TerminateWorkingGroupLeaderRole(TerminateRoleParameters<WorkerId>),
+
+    /// Set the capacity adjustment of the mint. No adjustment pauses the mint.
+    SetMintCapacityAdjustment(
+        GovernedMint,
+        Option<minting::Adjustment<MintedBalance, BlockNumber>>,
+    ),
+
+    /// Proposals executed together: either all of them or none.
+    Batch(
+        Vec<
+            ProposalDetails<
+                MintedBalance,
+                CurrencyBalance,
+                BlockNumber,
+                AccountId,
+                MemberId,
+                OpeningId,
+                ApplicationId,
+                StakeBalance,
+                WorkerId,
+                Hash,
+                ContentId,
+            >,
+        >,
+    ),
+
+    /// Set the proposal parameters of the proposal kind. Applies to the new proposals only.
+    SetProposalParameters(ProposalKind, ProposalParameters<BlockNumber, StakeBalance>),
+
+    /// The new version of the constitution for the `amend constitution` proposal
+    AmendConstitution(constitution::ConstitutionAmendment<Hash, ContentId>),
 }
 
 impl<
@@ -111,6 +148,8 @@ impl<
         ApplicationId,
         StakeBalance,
         WorkerId,
+        Hash,
+        ContentId,
     > Default
     for ProposalDetails<
         MintedBalance,
@@ -122,6 +161,8 @@ impl<
         ApplicationId,
         StakeBalance,
         WorkerId,
+        Hash,
+        ContentId,
     >
 {
     fn default() -> Self {
@@ -129,6 +170,369 @@ impl<
     }
 }
 
+impl<
+        MintedBalance,
+        CurrencyBalance,
+        BlockNumber,
+        AccountId,
+        MemberId,
+        OpeningId,
+        ApplicationId,
+        StakeBalance,
+        WorkerId,
+        Hash,
+        ContentId,
+    >
+    ProposalDetails<
+        MintedBalance,
+        CurrencyBalance,
+        BlockNumber,
+        AccountId,
+        MemberId,
+        OpeningId,
+        ApplicationId,
+        StakeBalance,
+        WorkerId,
+        Hash,
+        ContentId,
+    >
+{
+    /// Index of the proposal details variant. Matches the SCALE-encoded enum discriminant.
+    pub fn variant_index(&self) -> u8 {
+        match self {
+            ProposalDetails::Text(..) => 0,
+            ProposalDetails::RuntimeUpgrade(..) => 1,
+            ProposalDetails::SetElectionParameters(..) => 2,
+            ProposalDetails::Spending(..) => 3,
+            ProposalDetails::SetLead(..) => 4,
+            ProposalDetails::SetContentWorkingGroupMintCapacity(..) => 5,
+            ProposalDetails::EvictStorageProvider(..) => 6,
+            ProposalDetails::SetValidatorCount(..) => 7,
+            ProposalDetails::SetStorageRoleParameters(..) => 8,
+            ProposalDetails::AddWorkingGroupLeaderOpening(..) => 9,
+            ProposalDetails::BeginReviewWorkingGroupLeaderApplications(..) => 10,
+            ProposalDetails::FillWorkingGroupLeaderOpening(..) => 11,
+            ProposalDetails::SetWorkingGroupMintCapacity(..) => 12,
+            ProposalDetails::DecreaseWorkingGroupLeaderStake(..) => 13,
+            ProposalDetails::SlashWorkingGroupLeaderStake(..) => 14,
+            ProposalDetails::SetWorkingGroupLeaderReward(..) => 15,
+            ProposalDetails::TerminateWorkingGroupLeaderRole(..) => 16,
+            ProposalDetails::SetMintCapacityAdjustment(..) => 17,
+            ProposalDetails::Batch(..) => 18,
+            ProposalDetails::SetProposalParameters(..) => 19,
+            ProposalDetails::AmendConstitution(..) => 20,
+        }
+    }
+
+    /// Kind of the proposal defining its proposal parameters. Batch and deprecated proposals
+    /// have no kind.
+    pub fn proposal_kind(&self) -> Option<ProposalKind> {
+        match self {
+            ProposalDetails::Text(..) => Some(ProposalKind::Text),
+            ProposalDetails::RuntimeUpgrade(..) => Some(ProposalKind::RuntimeUpgrade),
+            ProposalDetails::SetElectionParameters(..) => Some(ProposalKind::SetElectionParameters),
+            ProposalDetails::Spending(..) => Some(ProposalKind::Spending),
+            ProposalDetails::SetLead(..) => Some(ProposalKind::SetLead),
+            ProposalDetails::SetContentWorkingGroupMintCapacity(..) => {
+                Some(ProposalKind::SetContentWorkingGroupMintCapacity)
+            }
+            ProposalDetails::SetValidatorCount(..) => Some(ProposalKind::SetValidatorCount),
+            ProposalDetails::AddWorkingGroupLeaderOpening(..) => {
+                Some(ProposalKind::AddWorkingGroupLeaderOpening)
+            }
+            ProposalDetails::BeginReviewWorkingGroupLeaderApplications(..) => {
+                Some(ProposalKind::BeginReviewWorkingGroupLeaderApplications)
+            }
+            ProposalDetails::FillWorkingGroupLeaderOpening(..) => {
+                Some(ProposalKind::FillWorkingGroupLeaderOpening)
+            }
+            ProposalDetails::SetWorkingGroupMintCapacity(..) => {
+                Some(ProposalKind::SetWorkingGroupMintCapacity)
+            }
+            ProposalDetails::DecreaseWorkingGroupLeaderStake(..) => {
+                Some(ProposalKind::DecreaseWorkingGroupLeaderStake)
+            }
+            ProposalDetails::SlashWorkingGroupLeaderStake(..) => {
+                Some(ProposalKind::SlashWorkingGroupLeaderStake)
+            }
+            ProposalDetails::SetWorkingGroupLeaderReward(..) => {
+                Some(ProposalKind::SetWorkingGroupLeaderReward)
+            }
+            ProposalDetails::TerminateWorkingGroupLeaderRole(..) => {
+                Some(ProposalKind::TerminateWorkingGroupLeaderRole)
+            }
+            ProposalDetails::SetMintCapacityAdjustment(..) => {
+                Some(ProposalKind::SetMintCapacityAdjustment)
+            }
+            ProposalDetails::SetProposalParameters(..) => Some(ProposalKind::SetProposalParameters),
+            ProposalDetails::AmendConstitution(..) => Some(ProposalKind::AmendConstitution),
+            ProposalDetails::Batch(..)
+            | ProposalDetails::EvictStorageProvider(..)
+            | ProposalDetails::SetStorageRoleParameters(..) => None,
+        }
+    }
+}
+
+/// _LegacyProposalDetails_ alias for type simplification
+pub(crate) type LegacyProposalDetailsOf<T> = LegacyProposalDetails<
+    crate::BalanceOfMint<T>,
+    crate::BalanceOfGovernanceCurrency<T>,
+    <T as system::Trait>::BlockNumber,
+    <T as system::Trait>::AccountId,
+    crate::MemberId<T>,
+    working_group::OpeningId<T>,
+    working_group::ApplicationId<T>,
+    crate::BalanceOf<T>,
+    working_group::WorkerId<T>,
+>;
+
+/// 'ProposalDetails' of the previous runtime versions. Used by the storage migration.
+#[derive(Encode, Decode)]
+pub(crate) enum LegacyProposalDetails<
+    MintedBalance,
+    CurrencyBalance,
+    BlockNumber,
+    AccountId,
+    MemberId,
+    OpeningId,
+    ApplicationId,
+    StakeBalance,
+    WorkerId,
+> {
+    Text(Vec<u8>),
+    RuntimeUpgrade(Vec<u8>),
+    SetElectionParameters(LegacyElectionParameters<CurrencyBalance, BlockNumber>),
+    Spending(MintedBalance, AccountId),
+    SetLead(Option<(MemberId, AccountId)>),
+    SetContentWorkingGroupMintCapacity(MintedBalance),
+    EvictStorageProvider(AccountId),
+    SetValidatorCount(u32),
+    SetStorageRoleParameters(RoleParameters<CurrencyBalance, BlockNumber>),
+    AddWorkingGroupLeaderOpening(LegacyAddOpeningParameters<BlockNumber, CurrencyBalance>),
+    BeginReviewWorkingGroupLeaderApplications(OpeningId, WorkingGroup),
+    FillWorkingGroupLeaderOpening(
+        FillOpeningParameters<BlockNumber, MintedBalance, OpeningId, ApplicationId>,
+    ),
+    SetWorkingGroupMintCapacity(MintedBalance, WorkingGroup),
+    DecreaseWorkingGroupLeaderStake(WorkerId, StakeBalance, WorkingGroup),
+    SlashWorkingGroupLeaderStake(WorkerId, StakeBalance, WorkingGroup),
+    SetWorkingGroupLeaderReward(WorkerId, MintedBalance, WorkingGroup),
+    TerminateWorkingGroupLeaderRole(TerminateRoleParameters<WorkerId>),
+}
+
+impl<
+        MintedBalance,
+        CurrencyBalance,
+        BlockNumber,
+        AccountId,
+        MemberId,
+        OpeningId,
+        ApplicationId,
+        StakeBalance,
+        WorkerId,
+    >
+    LegacyProposalDetails<
+        MintedBalance,
+        CurrencyBalance,
+        BlockNumber,
+        AccountId,
+        MemberId,
+        OpeningId,
+        ApplicationId,
+        StakeBalance,
+        WorkerId,
+    >
+{
+    // Converts the legacy proposal details.
+    pub(crate) fn migrate<Hash, ContentId>(
+        self,
+    ) -> ProposalDetails<
+        MintedBalance,
+        CurrencyBalance,
+        BlockNumber,
+        AccountId,
+        MemberId,
+        OpeningId,
+        ApplicationId,
+        StakeBalance,
+        WorkerId,
+        Hash,
+        ContentId,
+    > {
+        match self {
+            LegacyProposalDetails::Text(text) => ProposalDetails::Text(text),
+            LegacyProposalDetails::RuntimeUpgrade(wasm_code) => {
+                ProposalDetails::RuntimeUpgrade(wasm_code)
+            }
+            LegacyProposalDetails::SetElectionParameters(election_parameters) => {
+                ProposalDetails::SetElectionParameters(election_parameters.migrate())
+            }
+            LegacyProposalDetails::Spending(balance, destination) => {
+                ProposalDetails::Spending(balance, destination)
+            }
+            LegacyProposalDetails::SetLead(new_lead) => ProposalDetails::SetLead(new_lead),
+            LegacyProposalDetails::SetContentWorkingGroupMintCapacity(mint_balance) => {
+                ProposalDetails::SetContentWorkingGroupMintCapacity(mint_balance)
+            }
+            LegacyProposalDetails::EvictStorageProvider(account_id) => {
+                ProposalDetails::EvictStorageProvider(account_id)
+            }
+            LegacyProposalDetails::SetValidatorCount(validator_count) => {
+                ProposalDetails::SetValidatorCount(validator_count)
+            }
+            LegacyProposalDetails::SetStorageRoleParameters(role_parameters) => {
+                ProposalDetails::SetStorageRoleParameters(role_parameters)
+            }
+            LegacyProposalDetails::AddWorkingGroupLeaderOpening(add_opening_params) => {
+                ProposalDetails::AddWorkingGroupLeaderOpening(add_opening_params.migrate())
+            }
+            LegacyProposalDetails::BeginReviewWorkingGroupLeaderApplications(
+                opening_id,
+                working_group,
+            ) => ProposalDetails::BeginReviewWorkingGroupLeaderApplications(
+                opening_id,
+                working_group,
+            ),
+            LegacyProposalDetails::FillWorkingGroupLeaderOpening(fill_opening_params) => {
+                ProposalDetails::FillWorkingGroupLeaderOpening(fill_opening_params)
+            }
+            LegacyProposalDetails::SetWorkingGroupMintCapacity(mint_balance, working_group) => {
+                ProposalDetails::SetWorkingGroupMintCapacity(mint_balance, working_group)
+            }
+            LegacyProposalDetails::DecreaseWorkingGroupLeaderStake(
+                worker_id,
+                decreasing_stake,
+                working_group,
+            ) => ProposalDetails::DecreaseWorkingGroupLeaderStake(
+                worker_id,
+                decreasing_stake,
+                working_group,
+            ),
+            LegacyProposalDetails::SlashWorkingGroupLeaderStake(
+                worker_id,
+                slashing_stake,
+                working_group,
+            ) => ProposalDetails::SlashWorkingGroupLeaderStake(
+                worker_id,
+                slashing_stake,
+                working_group,
+            ),
+            LegacyProposalDetails::SetWorkingGroupLeaderReward(
+                worker_id,
+                reward_amount,
+                working_group,
+            ) => ProposalDetails::SetWorkingGroupLeaderReward(
+                worker_id,
+                reward_amount,
+                working_group,
+            ),
+            LegacyProposalDetails::TerminateWorkingGroupLeaderRole(terminate_role_params) => {
+                ProposalDetails::TerminateWorkingGroupLeaderRole(terminate_role_params)
+            }
+        }
+    }
+}
+
+/// Mint with the capacity governed by the proposals.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Copy, Clone, PartialEq, Eq, Debug)]
+pub enum GovernedMint {
+    /// The council mint.
+    Council,
+
+    /// The content working group mint. Since the curators moved to the content directory
+    /// working group, it is the mint of that working group.
+    ContentWorkingGroup,
+
+    /// The mint of the working group.
+    WorkingGroup(WorkingGroup),
+}
+
+/// Kind of the proposal with its own proposal parameters.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Copy, Clone, PartialEq, Eq, Debug)]
+pub enum ProposalKind {
+    /// The 'text' proposal
+    Text,
+
+    /// The 'runtime upgrade' proposal
+    RuntimeUpgrade,
+
+    /// The 'set election parameters' proposal
+    SetElectionParameters,
+
+    /// The 'spending' proposal
+    Spending,
+
+    /// The 'set lead' proposal
+    SetLead,
+
+    /// The 'set content working group mint capacity' proposal
+    SetContentWorkingGroupMintCapacity,
+
+    /// The 'set validator count' proposal
+    SetValidatorCount,
+
+    /// The 'add working group leader opening' proposal
+    AddWorkingGroupLeaderOpening,
+
+    /// The 'begin review working group leader applications' proposal
+    BeginReviewWorkingGroupLeaderApplications,
+
+    /// The 'fill working group leader opening' proposal
+    FillWorkingGroupLeaderOpening,
+
+    /// The 'set working group mint capacity' proposal
+    SetWorkingGroupMintCapacity,
+
+    /// The 'decrease working group leader stake' proposal
+    DecreaseWorkingGroupLeaderStake,
+
+    /// The 'slash working group leader stake' proposal
+    SlashWorkingGroupLeaderStake,
+
+    /// The 'set working group leader reward' proposal
+    SetWorkingGroupLeaderReward,
+
+    /// The 'terminate working group leader role' proposal
+    TerminateWorkingGroupLeaderRole,
+
+    /// The 'set mint capacity adjustment' proposal
+    SetMintCapacityAdjustment,
+
+    /// The 'set proposal parameters' proposal
+    SetProposalParameters,
+
+    /// The 'amend constitution' proposal
+    AmendConstitution,
+}
+
+impl ProposalKind {
+    /// All proposal kinds.
+    pub fn all() -> Vec<ProposalKind> {
+        vec![
+            ProposalKind::Text,
+            ProposalKind::RuntimeUpgrade,
+            ProposalKind::SetElectionParameters,
+            ProposalKind::Spending,
+            ProposalKind::SetLead,
+            ProposalKind::SetContentWorkingGroupMintCapacity,
+            ProposalKind::SetValidatorCount,
+            ProposalKind::AddWorkingGroupLeaderOpening,
+            ProposalKind::BeginReviewWorkingGroupLeaderApplications,
+            ProposalKind::FillWorkingGroupLeaderOpening,
+            ProposalKind::SetWorkingGroupMintCapacity,
+            ProposalKind::DecreaseWorkingGroupLeaderStake,
+            ProposalKind::SlashWorkingGroupLeaderStake,
+            ProposalKind::SetWorkingGroupLeaderReward,
+            ProposalKind::TerminateWorkingGroupLeaderRole,
+            ProposalKind::SetMintCapacityAdjustment,
+            ProposalKind::SetProposalParameters,
+            ProposalKind::AmendConstitution,
+        ]
+    }
+}
+
 /// Parameters for the 'terminate the leader position' proposal.
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Encode, Decode, Clone, PartialEq, Debug)]
@@ -180,6 +584,27 @@ pub struct AddOpeningParameters<BlockNumber, Balance> {
     pub working_group: WorkingGroup,
 }
 
+/// 'AddOpeningParameters' of the previous runtime versions. Used by the storage migration.
+#[derive(Encode, Decode)]
+pub(crate) struct LegacyAddOpeningParameters<BlockNumber, Balance> {
+    pub(crate) activate_at: hiring::ActivateOpeningAt<BlockNumber>,
+    pub(crate) commitment: working_group::LegacyOpeningPolicyCommitment<BlockNumber, Balance>,
+    pub(crate) human_readable_text: Vec<u8>,
+    pub(crate) working_group: WorkingGroup,
+}
+
+impl<BlockNumber, Balance> LegacyAddOpeningParameters<BlockNumber, Balance> {
+    // Converts the legacy parameters, leaving the new opening policy variables unset.
+    pub(crate) fn migrate(self) -> AddOpeningParameters<BlockNumber, Balance> {
+        AddOpeningParameters {
+            activate_at: self.activate_at,
+            commitment: self.commitment.migrate(),
+            human_readable_text: self.human_readable_text,
+            working_group: self.working_group,
+        }
+    }
+}
+
 /// ********** Deprecated during the Nicaea release.
 /// It is kept only for backward compatibility in the Pioneer. **********
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
@@ -220,7 +645,8 @@ pub struct RoleParameters<Balance, BlockNumber> {
 }
 
 /// Contains proposal config parameters. Default values are used by migration and genesis config.
-#[derive(Copy, Clone)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Copy, Clone, PartialEq, Debug)]
 pub struct ProposalsConfigParameters {
     /// 'Set validator count' proposal voting period
     pub set_validator_count_proposal_voting_period: u32,
@@ -228,89 +654,269 @@ pub struct ProposalsConfigParameters {
     /// 'Set validator count' proposal grace period
     pub set_validator_count_proposal_grace_period: u32,
 
+    /// 'Set validator count' proposal approval quorum percentage
+    pub set_validator_count_proposal_approval_quorum_percentage: u32,
+
+    /// 'Set validator count' proposal approval threshold percentage
+    pub set_validator_count_proposal_approval_threshold_percentage: u32,
+
+    /// 'Set validator count' proposal required stake
+    pub set_validator_count_proposal_required_stake: u32,
+
     /// 'Runtime upgrade' proposal voting period
     pub runtime_upgrade_proposal_voting_period: u32,
 
     /// 'Runtime upgrade' proposal grace period
     pub runtime_upgrade_proposal_grace_period: u32,
 
+    /// 'Runtime upgrade' proposal approval quorum percentage
+    pub runtime_upgrade_proposal_approval_quorum_percentage: u32,
+
+    /// 'Runtime upgrade' proposal approval threshold percentage
+    pub runtime_upgrade_proposal_approval_threshold_percentage: u32,
+
+    /// 'Runtime upgrade' proposal required stake
+    pub runtime_upgrade_proposal_required_stake: u32,
+
     /// 'Text' proposal voting period
     pub text_proposal_voting_period: u32,
 
     /// 'Text' proposal grace period
     pub text_proposal_grace_period: u32,
 
+    /// 'Text' proposal approval quorum percentage
+    pub text_proposal_approval_quorum_percentage: u32,
+
+    /// 'Text' proposal approval threshold percentage
+    pub text_proposal_approval_threshold_percentage: u32,
+
+    /// 'Text' proposal required stake
+    pub text_proposal_required_stake: u32,
+
     /// 'Set election parameters' proposal voting period
     pub set_election_parameters_proposal_voting_period: u32,
 
     /// 'Set election parameters' proposal grace period
     pub set_election_parameters_proposal_grace_period: u32,
 
+    /// 'Set election parameters' proposal approval quorum percentage
+    pub set_election_parameters_proposal_approval_quorum_percentage: u32,
+
+    /// 'Set election parameters' proposal approval threshold percentage
+    pub set_election_parameters_proposal_approval_threshold_percentage: u32,
+
+    /// 'Set election parameters' proposal required stake
+    pub set_election_parameters_proposal_required_stake: u32,
+
     /// 'Set content working group mint capacity' proposal voting period
     pub set_content_working_group_mint_capacity_proposal_voting_period: u32,
 
     /// 'Set content working group mint capacity' proposal grace period
     pub set_content_working_group_mint_capacity_proposal_grace_period: u32,
 
+    /// 'Set content working group mint capacity' proposal approval quorum percentage
+    pub set_content_working_group_mint_capacity_proposal_approval_quorum_percentage: u32,
+
+    /// 'Set content working group mint capacity' proposal approval threshold percentage
+    pub set_content_working_group_mint_capacity_proposal_approval_threshold_percentage: u32,
+
+    /// 'Set content working group mint capacity' proposal required stake
+    pub set_content_working_group_mint_capacity_proposal_required_stake: u32,
+
     /// 'Set lead' proposal voting period
     pub set_lead_proposal_voting_period: u32,
 
     /// 'Set lead' proposal grace period
     pub set_lead_proposal_grace_period: u32,
 
+    /// 'Set lead' proposal approval quorum percentage
+    pub set_lead_proposal_approval_quorum_percentage: u32,
+
+    /// 'Set lead' proposal approval threshold percentage
+    pub set_lead_proposal_approval_threshold_percentage: u32,
+
+    /// 'Set lead' proposal required stake
+    pub set_lead_proposal_required_stake: u32,
+
     /// 'Spending' proposal voting period
     pub spending_proposal_voting_period: u32,
 
     /// 'Spending' proposal grace period
     pub spending_proposal_grace_period: u32,
 
+    /// 'Spending' proposal approval quorum percentage
+    pub spending_proposal_approval_quorum_percentage: u32,
+
+    /// 'Spending' proposal approval threshold percentage
+    pub spending_proposal_approval_threshold_percentage: u32,
+
+    /// 'Spending' proposal required stake
+    pub spending_proposal_required_stake: u32,
+
     /// 'Add working group opening' proposal voting period
     pub add_working_group_opening_proposal_voting_period: u32,
 
     /// 'Add working group opening' proposal grace period
     pub add_working_group_opening_proposal_grace_period: u32,
 
+    /// 'Add working group opening' proposal approval quorum percentage
+    pub add_working_group_opening_proposal_approval_quorum_percentage: u32,
+
+    /// 'Add working group opening' proposal approval threshold percentage
+    pub add_working_group_opening_proposal_approval_threshold_percentage: u32,
+
+    /// 'Add working group opening' proposal required stake
+    pub add_working_group_opening_proposal_required_stake: u32,
+
     /// 'Begin review working group leader applications' proposal voting period
     pub begin_review_working_group_leader_applications_proposal_voting_period: u32,
 
     /// 'Begin review working group leader applications' proposal grace period
     pub begin_review_working_group_leader_applications_proposal_grace_period: u32,
 
+    /// 'Begin review working group leader applications' proposal approval quorum percentage
+    pub begin_review_working_group_leader_applications_proposal_approval_quorum_percentage: u32,
+
+    /// 'Begin review working group leader applications' proposal approval threshold percentage
+    pub begin_review_working_group_leader_applications_proposal_approval_threshold_percentage: u32,
+
+    /// 'Begin review working group leader applications' proposal required stake
+    pub begin_review_working_group_leader_applications_proposal_required_stake: u32,
+
     /// 'Fill working group leader opening' proposal voting period
     pub fill_working_group_leader_opening_proposal_voting_period: u32,
 
     /// 'Fill working group leader opening' proposal grace period
     pub fill_working_group_leader_opening_proposal_grace_period: u32,
 
+    /// 'Fill working group leader opening' proposal approval quorum percentage
+    pub fill_working_group_leader_opening_proposal_approval_quorum_percentage: u32,
+
+    /// 'Fill working group leader opening' proposal approval threshold percentage
+    pub fill_working_group_leader_opening_proposal_approval_threshold_percentage: u32,
+
+    /// 'Fill working group leader opening' proposal required stake
+    pub fill_working_group_leader_opening_proposal_required_stake: u32,
+
     /// 'Set working group mint capacity' proposal voting period
     pub set_working_group_mint_capacity_proposal_voting_period: u32,
 
     /// 'Set working group mint capacity' proposal grace period
     pub set_working_group_mint_capacity_proposal_grace_period: u32,
 
+    /// 'Set working group mint capacity' proposal approval quorum percentage
+    pub set_working_group_mint_capacity_proposal_approval_quorum_percentage: u32,
+
+    /// 'Set working group mint capacity' proposal approval threshold percentage
+    pub set_working_group_mint_capacity_proposal_approval_threshold_percentage: u32,
+
+    /// 'Set working group mint capacity' proposal required stake
+    pub set_working_group_mint_capacity_proposal_required_stake: u32,
+
     /// 'Decrease working group leader stake' proposal voting period
     pub decrease_working_group_leader_stake_proposal_voting_period: u32,
 
     /// 'Decrease working group leader stake' proposal grace period
     pub decrease_working_group_leader_stake_proposal_grace_period: u32,
 
+    /// 'Decrease working group leader stake' proposal approval quorum percentage
+    pub decrease_working_group_leader_stake_proposal_approval_quorum_percentage: u32,
+
+    /// 'Decrease working group leader stake' proposal approval threshold percentage
+    pub decrease_working_group_leader_stake_proposal_approval_threshold_percentage: u32,
+
+    /// 'Decrease working group leader stake' proposal required stake
+    pub decrease_working_group_leader_stake_proposal_required_stake: u32,
+
     /// 'Slash working group leader stake' proposal voting period
     pub slash_working_group_leader_stake_proposal_voting_period: u32,
 
     /// 'Slash working group leader stake' proposal grace period
     pub slash_working_group_leader_stake_proposal_grace_period: u32,
 
+    /// 'Slash working group leader stake' proposal approval quorum percentage
+    pub slash_working_group_leader_stake_proposal_approval_quorum_percentage: u32,
+
+    /// 'Slash working group leader stake' proposal approval threshold percentage
+    pub slash_working_group_leader_stake_proposal_approval_threshold_percentage: u32,
+
+    /// 'Slash working group leader stake' proposal required stake
+    pub slash_working_group_leader_stake_proposal_required_stake: u32,
+
     /// 'Set working group leader reward' proposal voting period
     pub set_working_group_leader_reward_proposal_voting_period: u32,
 
     /// 'Set working group leader reward' proposal grace period
     pub set_working_group_leader_reward_proposal_grace_period: u32,
 
+    /// 'Set working group leader reward' proposal approval quorum percentage
+    pub set_working_group_leader_reward_proposal_approval_quorum_percentage: u32,
+
+    /// 'Set working group leader reward' proposal approval threshold percentage
+    pub set_working_group_leader_reward_proposal_approval_threshold_percentage: u32,
+
+    /// 'Set working group leader reward' proposal required stake
+    pub set_working_group_leader_reward_proposal_required_stake: u32,
+
     /// 'Terminate working group leader role' proposal voting period
     pub terminate_working_group_leader_role_proposal_voting_period: u32,
 
     /// 'Terminate working group leader role' proposal grace period
     pub terminate_working_group_leader_role_proposal_grace_period: u32,
+
+    /// 'Terminate working group leader role' proposal approval quorum percentage
+    pub terminate_working_group_leader_role_proposal_approval_quorum_percentage: u32,
+
+    /// 'Terminate working group leader role' proposal approval threshold percentage
+    pub terminate_working_group_leader_role_proposal_approval_threshold_percentage: u32,
+
+    /// 'Terminate working group leader role' proposal required stake
+    pub terminate_working_group_leader_role_proposal_required_stake: u32,
+
+    /// 'Set mint capacity adjustment' proposal voting period
+    pub set_mint_capacity_adjustment_proposal_voting_period: u32,
+
+    /// 'Set mint capacity adjustment' proposal grace period
+    pub set_mint_capacity_adjustment_proposal_grace_period: u32,
+
+    /// 'Set mint capacity adjustment' proposal approval quorum percentage
+    pub set_mint_capacity_adjustment_proposal_approval_quorum_percentage: u32,
+
+    /// 'Set mint capacity adjustment' proposal approval threshold percentage
+    pub set_mint_capacity_adjustment_proposal_approval_threshold_percentage: u32,
+
+    /// 'Set mint capacity adjustment' proposal required stake
+    pub set_mint_capacity_adjustment_proposal_required_stake: u32,
+
+    /// 'Set proposal parameters' proposal voting period
+    pub set_proposal_parameters_proposal_voting_period: u32,
+
+    /// 'Set proposal parameters' proposal grace period
+    pub set_proposal_parameters_proposal_grace_period: u32,
+
+    /// 'Set proposal parameters' proposal approval quorum percentage
+    pub set_proposal_parameters_proposal_approval_quorum_percentage: u32,
+
+    /// 'Set proposal parameters' proposal approval threshold percentage
+    pub set_proposal_parameters_proposal_approval_threshold_percentage: u32,
+
+    /// 'Set proposal parameters' proposal required stake
+    pub set_proposal_parameters_proposal_required_stake: u32,
+
+    /// 'Amend constitution' proposal voting period
+    pub amend_constitution_proposal_voting_period: u32,
+
+    /// 'Amend constitution' proposal grace period
+    pub amend_constitution_proposal_grace_period: u32,
+
+    /// 'Amend constitution' proposal approval quorum percentage
+    pub amend_constitution_proposal_approval_quorum_percentage: u32,
+
+    /// 'Amend constitution' proposal approval threshold percentage
+    pub amend_constitution_proposal_approval_threshold_percentage: u32,
+
+    /// 'Amend constitution' proposal required stake
+    pub amend_constitution_proposal_required_stake: u32,
 }
 
 impl Default for ProposalsConfigParameters {
@@ -318,34 +924,96 @@ impl Default for ProposalsConfigParameters {
         ProposalsConfigParameters {
             set_validator_count_proposal_voting_period: 43200u32,
             set_validator_count_proposal_grace_period: 0u32,
+            set_validator_count_proposal_approval_quorum_percentage: 66u32,
+            set_validator_count_proposal_approval_threshold_percentage: 80u32,
+            set_validator_count_proposal_required_stake: 100_000_u32,
             runtime_upgrade_proposal_voting_period: 72000u32,
             runtime_upgrade_proposal_grace_period: 72000u32,
+            runtime_upgrade_proposal_approval_quorum_percentage: 80u32,
+            runtime_upgrade_proposal_approval_threshold_percentage: 100u32,
+            runtime_upgrade_proposal_required_stake: 1_000_000_u32,
             text_proposal_voting_period: 72000u32,
             text_proposal_grace_period: 0u32,
+            text_proposal_approval_quorum_percentage: 60u32,
+            text_proposal_approval_threshold_percentage: 80u32,
+            text_proposal_required_stake: 25000u32,
             set_election_parameters_proposal_voting_period: 72000u32,
             set_election_parameters_proposal_grace_period: 201_601_u32,
+            set_election_parameters_proposal_approval_quorum_percentage: 66u32,
+            set_election_parameters_proposal_approval_threshold_percentage: 80u32,
+            set_election_parameters_proposal_required_stake: 200_000_u32,
             set_content_working_group_mint_capacity_proposal_voting_period: 43200u32,
             set_content_working_group_mint_capacity_proposal_grace_period: 0u32,
+            set_content_working_group_mint_capacity_proposal_approval_quorum_percentage: 60u32,
+            set_content_working_group_mint_capacity_proposal_approval_threshold_percentage: 75u32,
+            set_content_working_group_mint_capacity_proposal_required_stake: 50000u32,
             set_lead_proposal_voting_period: 43200u32,
             set_lead_proposal_grace_period: 0u32,
+            set_lead_proposal_approval_quorum_percentage: 60u32,
+            set_lead_proposal_approval_threshold_percentage: 75u32,
+            set_lead_proposal_required_stake: 50000u32,
             spending_proposal_voting_period: 72000u32,
             spending_proposal_grace_period: 14400u32,
+            spending_proposal_approval_quorum_percentage: 60u32,
+            spending_proposal_approval_threshold_percentage: 80u32,
+            spending_proposal_required_stake: 25000u32,
             add_working_group_opening_proposal_voting_period: 72000u32,
             add_working_group_opening_proposal_grace_period: 0u32,
+            add_working_group_opening_proposal_approval_quorum_percentage: 60u32,
+            add_working_group_opening_proposal_approval_threshold_percentage: 80u32,
+            add_working_group_opening_proposal_required_stake: 100_000_u32,
             begin_review_working_group_leader_applications_proposal_voting_period: 43200u32,
             begin_review_working_group_leader_applications_proposal_grace_period: 14400u32,
+            begin_review_working_group_leader_applications_proposal_approval_quorum_percentage:
+                60u32,
+            begin_review_working_group_leader_applications_proposal_approval_threshold_percentage:
+                75u32,
+            begin_review_working_group_leader_applications_proposal_required_stake: 25000u32,
             fill_working_group_leader_opening_proposal_voting_period: 43200u32,
             fill_working_group_leader_opening_proposal_grace_period: 0u32,
+            fill_working_group_leader_opening_proposal_approval_quorum_percentage: 60u32,
+            fill_working_group_leader_opening_proposal_approval_threshold_percentage: 75u32,
+            fill_working_group_leader_opening_proposal_required_stake: 50000u32,
             set_working_group_mint_capacity_proposal_voting_period: 43200u32,
             set_working_group_mint_capacity_proposal_grace_period: 0u32,
+            set_working_group_mint_capacity_proposal_approval_quorum_percentage: 60u32,
+            set_working_group_mint_capacity_proposal_approval_threshold_percentage: 75u32,
+            set_working_group_mint_capacity_proposal_required_stake: 50000u32,
             decrease_working_group_leader_stake_proposal_voting_period: 43200u32,
             decrease_working_group_leader_stake_proposal_grace_period: 0u32,
+            decrease_working_group_leader_stake_proposal_approval_quorum_percentage: 60u32,
+            decrease_working_group_leader_stake_proposal_approval_threshold_percentage: 75u32,
+            decrease_working_group_leader_stake_proposal_required_stake: 50000u32,
             slash_working_group_leader_stake_proposal_voting_period: 43200u32,
             slash_working_group_leader_stake_proposal_grace_period: 0u32,
+            slash_working_group_leader_stake_proposal_approval_quorum_percentage: 60u32,
+            slash_working_group_leader_stake_proposal_approval_threshold_percentage: 75u32,
+            slash_working_group_leader_stake_proposal_required_stake: 50000u32,
             set_working_group_leader_reward_proposal_voting_period: 43200u32,
             set_working_group_leader_reward_proposal_grace_period: 0u32,
+            set_working_group_leader_reward_proposal_approval_quorum_percentage: 60u32,
+            set_working_group_leader_reward_proposal_approval_threshold_percentage: 75u32,
+            set_working_group_leader_reward_proposal_required_stake: 50000u32,
             terminate_working_group_leader_role_proposal_voting_period: 72200u32,
             terminate_working_group_leader_role_proposal_grace_period: 0u32,
+            terminate_working_group_leader_role_proposal_approval_quorum_percentage: 66u32,
+            terminate_working_group_leader_role_proposal_approval_threshold_percentage: 80u32,
+            terminate_working_group_leader_role_proposal_required_stake: 100_000_u32,
+            set_mint_capacity_adjustment_proposal_voting_period: 43200u32,
+            set_mint_capacity_adjustment_proposal_grace_period: 0u32,
+            set_mint_capacity_adjustment_proposal_approval_quorum_percentage: 60u32,
+            set_mint_capacity_adjustment_proposal_approval_threshold_percentage: 75u32,
+            set_mint_capacity_adjustment_proposal_required_stake: 50000u32,
+            set_proposal_parameters_proposal_voting_period: 72000u32,
+            set_proposal_parameters_proposal_grace_period: 14400u32,
+            set_proposal_parameters_proposal_approval_quorum_percentage: 80u32,
+            set_proposal_parameters_proposal_approval_threshold_percentage: 80u32,
+            set_proposal_parameters_proposal_required_stake: 200_000_u32,
+            amend_constitution_proposal_voting_period: 72000u32,
+            amend_constitution_proposal_grace_period: 14400u32,
+            amend_constitution_proposal_approval_quorum_percentage: 80u32,
+            amend_constitution_proposal_approval_threshold_percentage: 100u32,
+            amend_constitution_proposal_required_stake: 200_000_u32,
         }
     }
 }
@@ -354,6 +1022,7 @@ impl ProposalsConfigParameters {
     /// Set same voting_period for all proposals. For proposals
     /// that by default have 0 grace period remain with 0 grace period.
     /// All remaining proposals get assigned grace_period.
+    /// The approval percentages and required stakes are the default ones.
     pub fn with_grace_and_voting_periods(grace_period: u32, voting_period: u32) -> Self {
         ProposalsConfigParameters {
             set_validator_count_proposal_voting_period: voting_period,
@@ -386,6 +1055,187 @@ impl ProposalsConfigParameters {
             set_working_group_leader_reward_proposal_grace_period: 0,
             terminate_working_group_leader_role_proposal_voting_period: voting_period,
             terminate_working_group_leader_role_proposal_grace_period: 0,
+            set_mint_capacity_adjustment_proposal_voting_period: voting_period,
+            set_mint_capacity_adjustment_proposal_grace_period: 0,
+            set_proposal_parameters_proposal_voting_period: voting_period,
+            set_proposal_parameters_proposal_grace_period: grace_period,
+            amend_constitution_proposal_voting_period: voting_period,
+            amend_constitution_proposal_grace_period: grace_period,
+            ..ProposalsConfigParameters::default()
+        }
+    }
+
+    /// Voting and grace periods of the proposal kind.
+    pub fn voting_and_grace_periods(&self, proposal_kind: ProposalKind) -> (u32, u32) {
+        match proposal_kind {
+            ProposalKind::Text => (
+                self.text_proposal_voting_period,
+                self.text_proposal_grace_period,
+            ),
+            ProposalKind::RuntimeUpgrade => (
+                self.runtime_upgrade_proposal_voting_period,
+                self.runtime_upgrade_proposal_grace_period,
+            ),
+            ProposalKind::SetElectionParameters => (
+                self.set_election_parameters_proposal_voting_period,
+                self.set_election_parameters_proposal_grace_period,
+            ),
+            ProposalKind::Spending => (
+                self.spending_proposal_voting_period,
+                self.spending_proposal_grace_period,
+            ),
+            ProposalKind::SetLead => (
+                self.set_lead_proposal_voting_period,
+                self.set_lead_proposal_grace_period,
+            ),
+            ProposalKind::SetContentWorkingGroupMintCapacity => (
+                self.set_content_working_group_mint_capacity_proposal_voting_period,
+                self.set_content_working_group_mint_capacity_proposal_grace_period,
+            ),
+            ProposalKind::SetValidatorCount => (
+                self.set_validator_count_proposal_voting_period,
+                self.set_validator_count_proposal_grace_period,
+            ),
+            ProposalKind::AddWorkingGroupLeaderOpening => (
+                self.add_working_group_opening_proposal_voting_period,
+                self.add_working_group_opening_proposal_grace_period,
+            ),
+            ProposalKind::BeginReviewWorkingGroupLeaderApplications => (
+                self.begin_review_working_group_leader_applications_proposal_voting_period,
+                self.begin_review_working_group_leader_applications_proposal_grace_period,
+            ),
+            ProposalKind::FillWorkingGroupLeaderOpening => (
+                self.fill_working_group_leader_opening_proposal_voting_period,
+                self.fill_working_group_leader_opening_proposal_grace_period,
+            ),
+            ProposalKind::SetWorkingGroupMintCapacity => (
+                self.set_working_group_mint_capacity_proposal_voting_period,
+                self.set_working_group_mint_capacity_proposal_grace_period,
+            ),
+            ProposalKind::DecreaseWorkingGroupLeaderStake => (
+                self.decrease_working_group_leader_stake_proposal_voting_period,
+                self.decrease_working_group_leader_stake_proposal_grace_period,
+            ),
+            ProposalKind::SlashWorkingGroupLeaderStake => (
+                self.slash_working_group_leader_stake_proposal_voting_period,
+                self.slash_working_group_leader_stake_proposal_grace_period,
+            ),
+            ProposalKind::SetWorkingGroupLeaderReward => (
+                self.set_working_group_leader_reward_proposal_voting_period,
+                self.set_working_group_leader_reward_proposal_grace_period,
+            ),
+            ProposalKind::TerminateWorkingGroupLeaderRole => (
+                self.terminate_working_group_leader_role_proposal_voting_period,
+                self.terminate_working_group_leader_role_proposal_grace_period,
+            ),
+            ProposalKind::SetMintCapacityAdjustment => (
+                self.set_mint_capacity_adjustment_proposal_voting_period,
+                self.set_mint_capacity_adjustment_proposal_grace_period,
+            ),
+            ProposalKind::SetProposalParameters => (
+                self.set_proposal_parameters_proposal_voting_period,
+                self.set_proposal_parameters_proposal_grace_period,
+            ),
+            ProposalKind::AmendConstitution => (
+                self.amend_constitution_proposal_voting_period,
+                self.amend_constitution_proposal_grace_period,
+            ),
+        }
+    }
+
+    /// Approval quorum and threshold percentages and required stake of the proposal kind.
+    pub fn approval_percentages_and_stake(&self, proposal_kind: ProposalKind) -> (u32, u32, u32) {
+        match proposal_kind {
+            ProposalKind::Text => (
+                self.text_proposal_approval_quorum_percentage,
+                self.text_proposal_approval_threshold_percentage,
+                self.text_proposal_required_stake,
+            ),
+            ProposalKind::RuntimeUpgrade => (
+                self.runtime_upgrade_proposal_approval_quorum_percentage,
+                self.runtime_upgrade_proposal_approval_threshold_percentage,
+                self.runtime_upgrade_proposal_required_stake,
+            ),
+            ProposalKind::SetElectionParameters => (
+                self.set_election_parameters_proposal_approval_quorum_percentage,
+                self.set_election_parameters_proposal_approval_threshold_percentage,
+                self.set_election_parameters_proposal_required_stake,
+            ),
+            ProposalKind::Spending => (
+                self.spending_proposal_approval_quorum_percentage,
+                self.spending_proposal_approval_threshold_percentage,
+                self.spending_proposal_required_stake,
+            ),
+            ProposalKind::SetLead => (
+                self.set_lead_proposal_approval_quorum_percentage,
+                self.set_lead_proposal_approval_threshold_percentage,
+                self.set_lead_proposal_required_stake,
+            ),
+            ProposalKind::SetContentWorkingGroupMintCapacity => (
+                self.set_content_working_group_mint_capacity_proposal_approval_quorum_percentage,
+                self.set_content_working_group_mint_capacity_proposal_approval_threshold_percentage,
+                self.set_content_working_group_mint_capacity_proposal_required_stake,
+            ),
+            ProposalKind::SetValidatorCount => (
+                self.set_validator_count_proposal_approval_quorum_percentage,
+                self.set_validator_count_proposal_approval_threshold_percentage,
+                self.set_validator_count_proposal_required_stake,
+            ),
+            ProposalKind::AddWorkingGroupLeaderOpening => (
+                self.add_working_group_opening_proposal_approval_quorum_percentage,
+                self.add_working_group_opening_proposal_approval_threshold_percentage,
+                self.add_working_group_opening_proposal_required_stake,
+            ),
+            ProposalKind::BeginReviewWorkingGroupLeaderApplications => (
+                self.begin_review_working_group_leader_applications_proposal_approval_quorum_percentage,
+                self.begin_review_working_group_leader_applications_proposal_approval_threshold_percentage,
+                self.begin_review_working_group_leader_applications_proposal_required_stake,
+            ),
+            ProposalKind::FillWorkingGroupLeaderOpening => (
+                self.fill_working_group_leader_opening_proposal_approval_quorum_percentage,
+                self.fill_working_group_leader_opening_proposal_approval_threshold_percentage,
+                self.fill_working_group_leader_opening_proposal_required_stake,
+            ),
+            ProposalKind::SetWorkingGroupMintCapacity => (
+                self.set_working_group_mint_capacity_proposal_approval_quorum_percentage,
+                self.set_working_group_mint_capacity_proposal_approval_threshold_percentage,
+                self.set_working_group_mint_capacity_proposal_required_stake,
+            ),
+            ProposalKind::DecreaseWorkingGroupLeaderStake => (
+                self.decrease_working_group_leader_stake_proposal_approval_quorum_percentage,
+                self.decrease_working_group_leader_stake_proposal_approval_threshold_percentage,
+                self.decrease_working_group_leader_stake_proposal_required_stake,
+            ),
+            ProposalKind::SlashWorkingGroupLeaderStake => (
+                self.slash_working_group_leader_stake_proposal_approval_quorum_percentage,
+                self.slash_working_group_leader_stake_proposal_approval_threshold_percentage,
+                self.slash_working_group_leader_stake_proposal_required_stake,
+            ),
+            ProposalKind::SetWorkingGroupLeaderReward => (
+                self.set_working_group_leader_reward_proposal_approval_quorum_percentage,
+                self.set_working_group_leader_reward_proposal_approval_threshold_percentage,
+                self.set_working_group_leader_reward_proposal_required_stake,
+            ),
+            ProposalKind::TerminateWorkingGroupLeaderRole => (
+                self.terminate_working_group_leader_role_proposal_approval_quorum_percentage,
+                self.terminate_working_group_leader_role_proposal_approval_threshold_percentage,
+                self.terminate_working_group_leader_role_proposal_required_stake,
+            ),
+            ProposalKind::SetMintCapacityAdjustment => (
+                self.set_mint_capacity_adjustment_proposal_approval_quorum_percentage,
+                self.set_mint_capacity_adjustment_proposal_approval_threshold_percentage,
+                self.set_mint_capacity_adjustment_proposal_required_stake,
+            ),
+            ProposalKind::SetProposalParameters => (
+                self.set_proposal_parameters_proposal_approval_quorum_percentage,
+                self.set_proposal_parameters_proposal_approval_threshold_percentage,
+                self.set_proposal_parameters_proposal_required_stake,
+            ),
+            ProposalKind::AmendConstitution => (
+                self.amend_constitution_proposal_approval_quorum_percentage,
+                self.amend_constitution_proposal_approval_threshold_percentage,
+                self.amend_constitution_proposal_required_stake,
+            ),
         }
     }
 }
@@ -1,213 +1,171 @@
-use crate::{BalanceOf, Module, ProposalParameters};
+use crate::proposal_types::{ProposalKind, ProposalsConfigParameters};
+use crate::{BalanceOf, Module, ProposalDetails, ProposalDetailsOf, ProposalParameters};
 
-// Proposal parameters for the 'Set validator count' proposal
-pub(crate) fn set_validator_count_proposal<T: crate::Trait>(
+// Proposal parameters of the proposal kind from the config with the provided voting and grace
+// periods. Used to initialize the proposal parameters storage.
+pub(crate) fn config_proposal_parameters<T: crate::Trait>(
+    config: &ProposalsConfigParameters,
+    proposal_kind: ProposalKind,
+    voting_period: T::BlockNumber,
+    grace_period: T::BlockNumber,
 ) -> ProposalParameters<T::BlockNumber, BalanceOf<T>> {
+    let (approval_quorum_percentage, approval_threshold_percentage, required_stake) =
+        config.approval_percentages_and_stake(proposal_kind);
+
     ProposalParameters {
-        voting_period: <Module<T>>::set_validator_count_proposal_voting_period(),
-        grace_period: <Module<T>>::set_validator_count_proposal_grace_period(),
-        approval_quorum_percentage: 66,
-        approval_threshold_percentage: 80,
+        voting_period,
+        grace_period,
+        approval_quorum_percentage,
+        approval_threshold_percentage,
         slashing_quorum_percentage: 60,
         slashing_threshold_percentage: 80,
-        required_stake: Some(<BalanceOf<T>>::from(100_000_u32)),
+        required_stake: Some(<BalanceOf<T>>::from(required_stake)),
     }
 }
 
+// Proposal parameters for the 'Set validator count' proposal
+pub(crate) fn set_validator_count_proposal<T: crate::Trait>(
+) -> ProposalParameters<T::BlockNumber, BalanceOf<T>> {
+    <Module<T>>::proposal_parameters(ProposalKind::SetValidatorCount)
+}
+
 // Proposal parameters for the upgrade runtime proposal
 pub(crate) fn runtime_upgrade_proposal<T: crate::Trait>(
 ) -> ProposalParameters<T::BlockNumber, BalanceOf<T>> {
-    ProposalParameters {
-        voting_period: <Module<T>>::runtime_upgrade_proposal_voting_period(),
-        grace_period: <Module<T>>::runtime_upgrade_proposal_grace_period(),
-        approval_quorum_percentage: 80,
-        approval_threshold_percentage: 100,
-        slashing_quorum_percentage: 60,
-        slashing_threshold_percentage: 80,
-        required_stake: Some(<BalanceOf<T>>::from(1_000_000_u32)),
-    }
+    <Module<T>>::proposal_parameters(ProposalKind::RuntimeUpgrade)
 }
 
 // Proposal parameters for the text proposal
 pub(crate) fn text_proposal<T: crate::Trait>() -> ProposalParameters<T::BlockNumber, BalanceOf<T>> {
-    ProposalParameters {
-        voting_period: <Module<T>>::text_proposal_voting_period(),
-        grace_period: <Module<T>>::text_proposal_grace_period(),
-        approval_quorum_percentage: 60,
-        approval_threshold_percentage: 80,
-        slashing_quorum_percentage: 60,
-        slashing_threshold_percentage: 80,
-        required_stake: Some(<BalanceOf<T>>::from(25000u32)),
-    }
+    <Module<T>>::proposal_parameters(ProposalKind::Text)
 }
 
 // Proposal parameters for the 'Set Election Parameters' proposal
 pub(crate) fn set_election_parameters_proposal<T: crate::Trait>(
 ) -> ProposalParameters<T::BlockNumber, BalanceOf<T>> {
-    ProposalParameters {
-        voting_period: <Module<T>>::set_election_parameters_proposal_voting_period(),
-        grace_period: <Module<T>>::set_election_parameters_proposal_grace_period(),
-        approval_quorum_percentage: 66,
-        approval_threshold_percentage: 80,
-        slashing_quorum_percentage: 60,
-        slashing_threshold_percentage: 80,
-        required_stake: Some(<BalanceOf<T>>::from(200_000_u32)),
-    }
+    <Module<T>>::proposal_parameters(ProposalKind::SetElectionParameters)
 }
 
 // Proposal parameters for the 'Set content working group mint capacity' proposal
 pub(crate) fn set_content_working_group_mint_capacity_proposal<T: crate::Trait>(
 ) -> ProposalParameters<T::BlockNumber, BalanceOf<T>> {
-    ProposalParameters {
-        voting_period: <Module<T>>::set_content_working_group_mint_capacity_proposal_voting_period(
-        ),
-        grace_period: <Module<T>>::set_content_working_group_mint_capacity_proposal_grace_period(),
-        approval_quorum_percentage: 60,
-        approval_threshold_percentage: 75,
-        slashing_quorum_percentage: 60,
-        slashing_threshold_percentage: 80,
-        required_stake: Some(<BalanceOf<T>>::from(50000u32)),
-    }
+    <Module<T>>::proposal_parameters(ProposalKind::SetContentWorkingGroupMintCapacity)
 }
 
 // Proposal parameters for the 'Spending' proposal
 pub(crate) fn spending_proposal<T: crate::Trait>(
 ) -> ProposalParameters<T::BlockNumber, BalanceOf<T>> {
-    ProposalParameters {
-        voting_period: <Module<T>>::spending_proposal_voting_period(),
-        grace_period: <Module<T>>::spending_proposal_grace_period(),
-        approval_quorum_percentage: 60,
-        approval_threshold_percentage: 80,
-        slashing_quorum_percentage: 60,
-        slashing_threshold_percentage: 80,
-        required_stake: Some(<BalanceOf<T>>::from(25000u32)),
-    }
+    <Module<T>>::proposal_parameters(ProposalKind::Spending)
 }
 
 // Proposal parameters for the 'Set lead' proposal
 pub(crate) fn set_lead_proposal<T: crate::Trait>(
 ) -> ProposalParameters<T::BlockNumber, BalanceOf<T>> {
-    ProposalParameters {
-        voting_period: <Module<T>>::set_lead_proposal_voting_period(),
-        grace_period: <Module<T>>::set_lead_proposal_grace_period(),
-        approval_quorum_percentage: 60,
-        approval_threshold_percentage: 75,
-        slashing_quorum_percentage: 60,
-        slashing_threshold_percentage: 80,
-        required_stake: Some(<BalanceOf<T>>::from(50000u32)),
-    }
+    <Module<T>>::proposal_parameters(ProposalKind::SetLead)
 }
 
 // Proposal parameters for the 'Add working group leader' proposal
 pub(crate) fn add_working_group_leader_opening_proposal<T: crate::Trait>(
 ) -> ProposalParameters<T::BlockNumber, BalanceOf<T>> {
-    ProposalParameters {
-        voting_period: <Module<T>>::add_working_group_opening_proposal_voting_period(),
-        grace_period: <Module<T>>::add_working_group_opening_proposal_grace_period(),
-        approval_quorum_percentage: 60,
-        approval_threshold_percentage: 80,
-        slashing_quorum_percentage: 60,
-        slashing_threshold_percentage: 80,
-        required_stake: Some(<BalanceOf<T>>::from(100_000_u32)),
-    }
+    <Module<T>>::proposal_parameters(ProposalKind::AddWorkingGroupLeaderOpening)
 }
 
 // Proposal parameters for the 'Begin review working group leader applications' proposal
 pub(crate) fn begin_review_working_group_leader_applications_proposal<T: crate::Trait>(
 ) -> ProposalParameters<T::BlockNumber, BalanceOf<T>> {
-    ProposalParameters {
-        voting_period:
-            <Module<T>>::begin_review_working_group_leader_applications_proposal_voting_period(),
-        grace_period:
-            <Module<T>>::begin_review_working_group_leader_applications_proposal_grace_period(),
-        approval_quorum_percentage: 60,
-        approval_threshold_percentage: 75,
-        slashing_quorum_percentage: 60,
-        slashing_threshold_percentage: 80,
-        required_stake: Some(<BalanceOf<T>>::from(25000u32)),
-    }
+    <Module<T>>::proposal_parameters(ProposalKind::BeginReviewWorkingGroupLeaderApplications)
 }
 
 // Proposal parameters for the 'Fill working group leader opening' proposal
 pub(crate) fn fill_working_group_leader_opening_proposal<T: crate::Trait>(
 ) -> ProposalParameters<T::BlockNumber, BalanceOf<T>> {
-    ProposalParameters {
-        voting_period: <Module<T>>::fill_working_group_leader_opening_proposal_voting_period(),
-        grace_period: <Module<T>>::fill_working_group_leader_opening_proposal_grace_period(),
-        approval_quorum_percentage: 60,
-        approval_threshold_percentage: 75,
-        slashing_quorum_percentage: 60,
-        slashing_threshold_percentage: 80,
-        required_stake: Some(<BalanceOf<T>>::from(50000u32)),
-    }
+    <Module<T>>::proposal_parameters(ProposalKind::FillWorkingGroupLeaderOpening)
 }
 
 // Proposal parameters for the 'Set working group mint capacity' proposal
 pub(crate) fn set_working_group_mint_capacity_proposal<T: crate::Trait>(
 ) -> ProposalParameters<T::BlockNumber, BalanceOf<T>> {
-    ProposalParameters {
-        voting_period: <Module<T>>::set_working_group_mint_capacity_proposal_voting_period(),
-        grace_period: <Module<T>>::set_working_group_mint_capacity_proposal_grace_period(),
-        approval_quorum_percentage: 60,
-        approval_threshold_percentage: 75,
-        slashing_quorum_percentage: 60,
-        slashing_threshold_percentage: 80,
-        required_stake: Some(<BalanceOf<T>>::from(50000u32)),
-    }
+    <Module<T>>::proposal_parameters(ProposalKind::SetWorkingGroupMintCapacity)
 }
 
 // Proposal parameters for the 'Decrease working group leader stake' proposal
 pub(crate) fn decrease_working_group_leader_stake_proposal<T: crate::Trait>(
 ) -> ProposalParameters<T::BlockNumber, BalanceOf<T>> {
-    ProposalParameters {
-        voting_period: <Module<T>>::decrease_working_group_leader_stake_proposal_voting_period(),
-        grace_period: <Module<T>>::decrease_working_group_leader_stake_proposal_grace_period(),
-        approval_quorum_percentage: 60,
-        approval_threshold_percentage: 75,
-        slashing_quorum_percentage: 60,
-        slashing_threshold_percentage: 80,
-        required_stake: Some(<BalanceOf<T>>::from(50000u32)),
-    }
+    <Module<T>>::proposal_parameters(ProposalKind::DecreaseWorkingGroupLeaderStake)
 }
 
 // Proposal parameters for the 'Slash working group leader stake' proposal
 pub(crate) fn slash_working_group_leader_stake_proposal<T: crate::Trait>(
 ) -> ProposalParameters<T::BlockNumber, BalanceOf<T>> {
-    ProposalParameters {
-        voting_period: <Module<T>>::slash_working_group_leader_stake_proposal_voting_period(),
-        grace_period: <Module<T>>::slash_working_group_leader_stake_proposal_grace_period(),
-        approval_quorum_percentage: 60,
-        approval_threshold_percentage: 75,
-        slashing_quorum_percentage: 60,
-        slashing_threshold_percentage: 80,
-        required_stake: Some(<BalanceOf<T>>::from(50000u32)),
-    }
+    <Module<T>>::proposal_parameters(ProposalKind::SlashWorkingGroupLeaderStake)
 }
 
 // Proposal parameters for the 'Set working group leader reward' proposal
 pub(crate) fn set_working_group_leader_reward_proposal<T: crate::Trait>(
 ) -> ProposalParameters<T::BlockNumber, BalanceOf<T>> {
-    ProposalParameters {
-        voting_period: <Module<T>>::set_working_group_leader_reward_proposal_voting_period(),
-        grace_period: <Module<T>>::set_working_group_leader_reward_proposal_grace_period(),
-        approval_quorum_percentage: 60,
-        approval_threshold_percentage: 75,
-        slashing_quorum_percentage: 60,
-        slashing_threshold_percentage: 80,
-        required_stake: Some(<BalanceOf<T>>::from(50000u32)),
-    }
+    <Module<T>>::proposal_parameters(ProposalKind::SetWorkingGroupLeaderReward)
 }
 
 // Proposal parameters for the 'Terminate working group leader role' proposal
 pub(crate) fn terminate_working_group_leader_role_proposal<T: crate::Trait>(
 ) -> ProposalParameters<T::BlockNumber, BalanceOf<T>> {
-    ProposalParameters {
-        voting_period: <Module<T>>::terminate_working_group_leader_role_proposal_voting_period(),
-        grace_period: <Module<T>>::terminate_working_group_leader_role_proposal_grace_period(),
-        approval_quorum_percentage: 66,
-        approval_threshold_percentage: 80,
-        slashing_quorum_percentage: 60,
-        slashing_threshold_percentage: 80,
-        required_stake: Some(<BalanceOf<T>>::from(100_000_u32)),
+    <Module<T>>::proposal_parameters(ProposalKind::TerminateWorkingGroupLeaderRole)
+}
+
+// Proposal parameters for the 'Set mint capacity adjustment' proposal
+pub(crate) fn set_mint_capacity_adjustment_proposal<T: crate::Trait>(
+) -> ProposalParameters<T::BlockNumber, BalanceOf<T>> {
+    <Module<T>>::proposal_parameters(ProposalKind::SetMintCapacityAdjustment)
+}
+
+// Proposal parameters for the 'Set proposal parameters' proposal
+pub(crate) fn set_proposal_parameters_proposal<T: crate::Trait>(
+) -> ProposalParameters<T::BlockNumber, BalanceOf<T>> {
+    <Module<T>>::proposal_parameters(ProposalKind::SetProposalParameters)
+}
+
+// Proposal parameters for the 'Amend constitution' proposal
+pub(crate) fn amend_constitution_proposal<T: crate::Trait>(
+) -> ProposalParameters<T::BlockNumber, BalanceOf<T>> {
+    <Module<T>>::proposal_parameters(ProposalKind::AmendConstitution)
+}
+
+// Proposal parameters for the 'Batch' proposal: the strictest parameters of the batched proposals
+pub(crate) fn batch_proposal<T: crate::Trait>(
+    proposals: &[ProposalDetailsOf<T>],
+) -> ProposalParameters<T::BlockNumber, BalanceOf<T>> {
+    proposals.iter().map(proposal_parameters::<T>).fold(
+        ProposalParameters::default(),
+        |strictest, parameters| ProposalParameters {
+            voting_period: strictest.voting_period.max(parameters.voting_period),
+            grace_period: strictest.grace_period.max(parameters.grace_period),
+            approval_quorum_percentage: strictest
+                .approval_quorum_percentage
+                .max(parameters.approval_quorum_percentage),
+            approval_threshold_percentage: strictest
+                .approval_threshold_percentage
+                .max(parameters.approval_threshold_percentage),
+            slashing_quorum_percentage: strictest
+                .slashing_quorum_percentage
+                .max(parameters.slashing_quorum_percentage),
+            slashing_threshold_percentage: strictest
+                .slashing_threshold_percentage
+                .max(parameters.slashing_threshold_percentage),
+            required_stake: strictest.required_stake.max(parameters.required_stake),
+        },
+    )
+}
+
+// Proposal parameters for the proposal details
+fn proposal_parameters<T: crate::Trait>(
+    proposal_details: &ProposalDetailsOf<T>,
+) -> ProposalParameters<T::BlockNumber, BalanceOf<T>> {
+    match proposal_details {
+        ProposalDetails::Batch(proposals) => batch_proposal::<T>(proposals),
+        // Deprecated proposals cannot be created and get no parameters.
+        _ => proposal_details
+            .proposal_kind()
+            .map(<Module<T>>::proposal_parameters)
+            .unwrap_or_default(),
     }
 }
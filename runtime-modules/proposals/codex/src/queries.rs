@@ -0,0 +1,152 @@
+#![warn(missing_docs)]
+
+use codec::{Decode, Encode};
+use frame_support::StorageMap;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+use sp_std::vec::Vec;
+
+use proposals_engine::{Proposal, VoteKind};
+
+use crate::{BalanceOf, MemberId, Module, ProposalDetailsOf, Trait};
+
+// Max proposal number returned by a single proposals query.
+const MAX_PROPOSALS_QUERY_LIMIT: u32 = 100;
+
+/// Proposal status group used by the proposals query.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ProposalStatusFilter {
+    /// Proposals open for voting.
+    Active,
+
+    /// Approved proposals waiting for the grace period expiration.
+    PendingExecution,
+
+    /// Proposals with the final decision made, except approved proposals pending execution.
+    Finalized,
+}
+
+/// Filter for the proposals query. Empty fields match any proposal.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Debug)]
+pub struct ProposalsFilter<MemberId> {
+    /// Proposal status group.
+    pub status: Option<ProposalStatusFilter>,
+
+    /// Member id of the proposer.
+    pub proposer_id: Option<MemberId>,
+
+    /// Index of the `ProposalDetails` variant (see `ProposalDetails::variant_index()`).
+    pub details_variant: Option<u8>,
+}
+
+/// Proposal with its details and voting state.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Debug)]
+pub struct ProposalInfo<ProposalId, BlockNumber, MemberId, Balance, StakeId, AccountId, Details> {
+    /// Proposal id.
+    pub proposal_id: ProposalId,
+
+    /// Proposal data from the `engine` module, including the voting results.
+    pub proposal: Proposal<BlockNumber, MemberId, Balance, StakeId, AccountId>,
+
+    /// Proposal details.
+    pub details: Details,
+
+    /// Votes for the proposal: voter member ids with their vote kinds.
+    pub votes: Vec<(MemberId, VoteKind)>,
+
+    /// Blocks left until the voting period expires. None if the proposal is not active.
+    pub remaining_voting_period: Option<BlockNumber>,
+
+    /// Blocks left until the grace period expires. None if the proposal is not pending execution.
+    pub remaining_grace_period: Option<BlockNumber>,
+}
+
+/// _ProposalInfo_ alias for type simplification
+pub type ProposalInfoOf<T> = ProposalInfo<
+    <T as proposals_engine::Trait>::ProposalId,
+    <T as system::Trait>::BlockNumber,
+    MemberId<T>,
+    BalanceOf<T>,
+    <T as stake::Trait>::StakeId,
+    <T as system::Trait>::AccountId,
+    ProposalDetailsOf<T>,
+>;
+
+impl<T: Trait> Module<T> {
+    /// Returns up to `limit` proposals matching the filter. Proposals are ordered from newer to
+    /// older ones starting from the `start_id` (inclusive) or from the latest proposal if `None`.
+    /// The limit is capped by the MAX_PROPOSALS_QUERY_LIMIT.
+    pub fn proposals(
+        filter: ProposalsFilter<MemberId<T>>,
+        start_id: Option<u32>,
+        limit: u32,
+    ) -> Vec<ProposalInfoOf<T>> {
+        let proposal_count = <proposals_engine::Module<T>>::proposal_count();
+        let start_id = start_id.map_or(proposal_count, |id| id.min(proposal_count));
+        let limit = limit.min(MAX_PROPOSALS_QUERY_LIMIT);
+
+        (1..=start_id)
+            .rev()
+            .filter_map(|id| Self::proposal_info(T::ProposalId::from(id)))
+            .filter(|proposal_info| Self::proposal_matches_filter(proposal_info, &filter))
+            .take(limit as usize)
+            .collect()
+    }
+
+    /// Returns the proposal with its details and voting state. Returns None if the proposal
+    /// doesn't exist.
+    pub fn proposal_info(proposal_id: T::ProposalId) -> Option<ProposalInfoOf<T>> {
+        if !<proposals_engine::Proposals<T>>::contains_key(proposal_id) {
+            return None;
+        }
+
+        let proposal = <proposals_engine::Module<T>>::proposals(proposal_id);
+        let now = <system::Module<T>>::block_number();
+
+        Some(ProposalInfo {
+            proposal_id,
+            details: Self::proposal_details_by_proposal_id(proposal_id),
+            votes: <proposals_engine::Module<T>>::proposal_votes(proposal_id),
+            remaining_voting_period: proposal.remaining_voting_period(now),
+            remaining_grace_period: proposal.remaining_grace_period(now),
+            proposal,
+        })
+    }
+
+    // Checks the proposal against all filter fields.
+    fn proposal_matches_filter(
+        proposal_info: &ProposalInfoOf<T>,
+        filter: &ProposalsFilter<MemberId<T>>,
+    ) -> bool {
+        let proposal_id = proposal_info.proposal_id;
+
+        let status_matches = match filter.status {
+            Some(ProposalStatusFilter::Active) => {
+                <proposals_engine::ActiveProposalIds<T>>::contains_key(proposal_id)
+            }
+            Some(ProposalStatusFilter::PendingExecution) => {
+                <proposals_engine::PendingExecutionProposalIds<T>>::contains_key(proposal_id)
+            }
+            Some(ProposalStatusFilter::Finalized) => {
+                !<proposals_engine::ActiveProposalIds<T>>::contains_key(proposal_id)
+                    && !<proposals_engine::PendingExecutionProposalIds<T>>::contains_key(
+                        proposal_id,
+                    )
+            }
+            None => true,
+        };
+
+        let proposer_matches = filter.proposer_id.as_ref().map_or(true, |proposer_id| {
+            *proposer_id == proposal_info.proposal.proposer_id
+        });
+
+        let details_matches = filter.details_variant.map_or(true, |variant_index| {
+            variant_index == proposal_info.details.variant_index()
+        });
+
+        status_matches && proposer_matches && details_matches
+    }
+}
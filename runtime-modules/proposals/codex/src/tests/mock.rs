@@ -6,12 +6,13 @@ use sp_runtime::curve::PiecewiseLinear;
 use sp_runtime::{
     testing::Header,
     traits::{BlakeTwo256, IdentityLookup},
-    Perbill,
+    Perbill, Percent,
 };
 use sp_staking::SessionIndex;
 pub use system;
 
-use crate::{ProposalDetailsOf, ProposalEncoder};
+use crate::{ProposalDetails, ProposalDetailsOf, ProposalEncoder};
+use codec::Encode;
 use proposals_engine::VotersParameters;
 use sp_runtime::testing::TestXt;
 
@@ -50,6 +51,7 @@ impl membership::Trait for Test {
     type PaidTermId = u64;
     type SubscriptionId = u64;
     type ActorId = u64;
+    type WeightInfo = ();
 }
 
 parameter_types! {
@@ -78,6 +80,7 @@ parameter_types! {
     pub const TitleMaxLength: u32 = 100;
     pub const DescriptionMaxLength: u32 = 10000;
     pub const MaxActiveProposalLimit: u32 = 100;
+    pub const MaxExactExecutionDelay: u64 = 1000;
 }
 
 impl proposals_engine::Trait for Test {
@@ -92,7 +95,9 @@ impl proposals_engine::Trait for Test {
     type TitleMaxLength = TitleMaxLength;
     type DescriptionMaxLength = DescriptionMaxLength;
     type MaxActiveProposalLimit = MaxActiveProposalLimit;
+    type MaxExactExecutionDelay = MaxExactExecutionDelay;
     type DispatchableCallCode = crate::Call<Test>;
+    type WeightInfo = ();
 }
 
 impl Default for crate::Call<Test> {
@@ -101,14 +106,33 @@ impl Default for crate::Call<Test> {
     }
 }
 
+parameter_types! {
+    pub const MintSpendingEpochLength: u64 = 100;
+}
+
 impl minting::Trait for Test {
     type Currency = Balances;
     type MintId = u64;
+    type SpendingEpochLength = MintSpendingEpochLength;
+    type MintCapacityIncreased = ();
+    type WeightInfo = ();
+}
+
+parameter_types! {
+    pub const RecallPeriod: u64 = 10;
+    pub const RecallThreshold: Percent = Percent::from_percent(50);
+    pub const MinRecallStake: u64 = 10;
+    pub const MaxRecallBackers: u32 = 100;
 }
 
 impl governance::council::Trait for Test {
     type Event = ();
     type CouncilTermEnded = ();
+    type CouncilSeatVacated = ();
+    type RecallPeriod = RecallPeriod;
+    type RecallThreshold = RecallThreshold;
+    type MinRecallStake = MinRecallStake;
+    type MaxRecallBackers = MaxRecallBackers;
 }
 
 impl common::origin::ActorOriginValidator<Origin, u64, u64> for () {
@@ -135,6 +159,7 @@ impl proposals_discussion::Trait for Test {
     type ThreadTitleLengthLimit = ThreadTitleLengthLimit;
     type PostLengthLimit = PostLengthLimit;
     type MaxThreadInARowNumber = MaxThreadInARowNumber;
+    type WeightInfo = ();
 }
 
 pub struct MockVotersParameters;
@@ -156,9 +181,11 @@ impl governance::election::Trait for Test {
 
 impl content_working_group::Trait for Test {
     type Event = ();
+    type WeightInfo = ();
 }
 
 impl recurring_rewards::Trait for Test {
+    type Event = ();
     type PayoutStatusHandler = ();
     type RecipientId = u64;
     type RewardRelationshipId = u64;
@@ -168,6 +195,7 @@ impl versioned_store_permissions::Trait for Test {
     type Credential = u64;
     type CredentialChecker = ();
     type CreateClassPermissionsChecker = ();
+    type WeightInfo = ();
 }
 
 impl versioned_store::Trait for Test {
@@ -244,16 +272,40 @@ impl staking::SessionInterface<u64> for Test {
     }
 }
 
+parameter_types! {
+    pub const ConstitutionTextMaxLength: u32 = 1000;
+}
+
+impl constitution::ContentExistenceValidator<u64> for () {
+    fn content_exists(_content_id: &u64) -> bool {
+        true
+    }
+}
+
+impl constitution::Trait for Test {
+    type Event = ();
+    type ContentId = u64;
+    type ContentExistenceValidator = ();
+    type ConstitutionTextMaxLength = ConstitutionTextMaxLength;
+    type WeightInfo = ();
+}
+
 impl crate::Trait for Test {
     type TextProposalMaxLength = TextProposalMaxLength;
     type RuntimeUpgradeWasmProposalMaxLength = RuntimeUpgradeWasmProposalMaxLength;
     type MembershipOriginValidator = ();
     type ProposalEncoder = ();
+    type WeightInfo = ();
 }
 
 impl ProposalEncoder<Test> for () {
-    fn encode_proposal(_proposal_details: ProposalDetailsOf<Test>) -> Vec<u8> {
-        Vec::new()
+    fn encode_proposal(proposal_details: ProposalDetailsOf<Test>) -> Vec<u8> {
+        match proposal_details {
+            ProposalDetails::Text(text) => {
+                crate::Call::<Test>::execute_text_proposal(text).encode()
+            }
+            _ => Vec::new(),
+        }
     }
 }
 
@@ -1,12 +1,15 @@
 mod mock;
 
+use codec::Encode;
 use frame_support::dispatch::{DispatchError, DispatchResult};
 use frame_support::storage::StorageMap;
 use frame_support::traits::Currency;
+use sp_core::H256;
+use sp_runtime::traits::{BlakeTwo256, Hash};
 use system::RawOrigin;
 
 use common::working_group::WorkingGroup;
-use governance::election_params::ElectionParameters;
+use governance::election_params::{ElectionParameters, VotingMode};
 use hiring::ActivateOpeningAt;
 use proposals_engine::ProposalParameters;
 use working_group::OpeningPolicyCommitment;
@@ -40,7 +43,7 @@ where
     invalid_stake_call: InvalidStakeCall,
     successful_call: SuccessfulCall,
     proposal_parameters: ProposalParameters<u64, u64>,
-    proposal_details: ProposalDetails<u64, u64, u64, u64, u64, u64, u64, u64, u64>,
+    proposal_details: ProposalDetails<u64, u64, u64, u64, u64, u64, u64, u64, u64, H256, u64>,
 }
 
 impl<InsufficientRightsCall, EmptyStakeCall, InvalidStakeCall, SuccessfulCall>
@@ -110,6 +113,7 @@ fn create_text_proposal_common_checks_succeed() {
                     b"title".to_vec(),
                     b"body".to_vec(),
                     None,
+                    None,
                     b"text".to_vec(),
                 )
             },
@@ -120,6 +124,7 @@ fn create_text_proposal_common_checks_succeed() {
                     b"title".to_vec(),
                     b"body".to_vec(),
                     None,
+                    None,
                     b"text".to_vec(),
                 )
             },
@@ -130,6 +135,7 @@ fn create_text_proposal_common_checks_succeed() {
                     b"title".to_vec(),
                     b"body".to_vec(),
                     Some(<BalanceOf<Test>>::from(5000u32)),
+                    None,
                     b"text".to_vec(),
                 )
             },
@@ -140,6 +146,7 @@ fn create_text_proposal_common_checks_succeed() {
                     b"title".to_vec(),
                     b"body".to_vec(),
                     Some(<BalanceOf<Test>>::from(25000u32)),
+                    None,
                     b"text".to_vec(),
                 )
             },
@@ -163,6 +170,7 @@ fn create_text_proposal_codex_call_fails_with_incorrect_text_size() {
                 b"title".to_vec(),
                 b"body".to_vec(),
                 None,
+                None,
                 long_text,
             ),
             Err(Error::<Test>::TextProposalSizeExceeded.into())
@@ -175,6 +183,7 @@ fn create_text_proposal_codex_call_fails_with_incorrect_text_size() {
                 b"title".to_vec(),
                 b"body".to_vec(),
                 None,
+                None,
                 Vec::new(),
             ),
             Err(Error::<Test>::TextProposalIsEmpty.into())
@@ -182,6 +191,41 @@ fn create_text_proposal_codex_call_fails_with_incorrect_text_size() {
     });
 }
 
+#[test]
+fn create_text_proposal_with_exact_execution_block_succeeds() {
+    initial_test_ext().execute_with(|| {
+        increase_total_balance_issuance_using_account_id(1, 500000);
+
+        let voting_period =
+            crate::proposal_types::parameters::text_proposal::<Test>().voting_period;
+        let voting_period_end = System::block_number() + voting_period;
+
+        let create_text_proposal = |exactly_execute_at| {
+            ProposalCodex::create_text_proposal(
+                RawOrigin::Signed(1).into(),
+                1,
+                b"title".to_vec(),
+                b"body".to_vec(),
+                Some(<BalanceOf<Test>>::from(25000u32)),
+                exactly_execute_at,
+                b"text".to_vec(),
+            )
+        };
+
+        assert_eq!(
+            create_text_proposal(Some(voting_period_end)),
+            Err(proposals_engine::Error::<Test>::InvalidExactExecutionBlock.into())
+        );
+
+        assert_eq!(create_text_proposal(Some(voting_period_end + 1)), Ok(()));
+
+        assert_eq!(
+            ProposalsEngine::proposals(1).exactly_execute_at,
+            Some(voting_period_end + 1)
+        );
+    });
+}
+
 #[test]
 fn create_runtime_upgrade_common_checks_succeed() {
     initial_test_ext().execute_with(|| {
@@ -195,6 +239,7 @@ fn create_runtime_upgrade_common_checks_succeed() {
                     b"title".to_vec(),
                     b"body".to_vec(),
                     None,
+                    None,
                     b"wasm".to_vec(),
                 )
             },
@@ -205,6 +250,7 @@ fn create_runtime_upgrade_common_checks_succeed() {
                     b"title".to_vec(),
                     b"body".to_vec(),
                     None,
+                    None,
                     b"wasm".to_vec(),
                 )
             },
@@ -215,6 +261,7 @@ fn create_runtime_upgrade_common_checks_succeed() {
                     b"title".to_vec(),
                     b"body".to_vec(),
                     Some(<BalanceOf<Test>>::from(500u32)),
+                    None,
                     b"wasm".to_vec(),
                 )
             },
@@ -225,6 +272,7 @@ fn create_runtime_upgrade_common_checks_succeed() {
                     b"title".to_vec(),
                     b"body".to_vec(),
                     Some(<BalanceOf<Test>>::from(1_000_000_u32)),
+                    None,
                     b"wasm".to_vec(),
                 )
             },
@@ -248,6 +296,7 @@ fn create_upgrade_runtime_proposal_codex_call_fails_with_incorrect_wasm_size() {
                 b"title".to_vec(),
                 b"body".to_vec(),
                 None,
+                None,
                 long_wasm,
             ),
             Err(Error::<Test>::RuntimeProposalSizeExceeded.into())
@@ -260,6 +309,7 @@ fn create_upgrade_runtime_proposal_codex_call_fails_with_incorrect_wasm_size() {
                 b"title".to_vec(),
                 b"body".to_vec(),
                 None,
+                None,
                 Vec::new(),
             ),
             Err(Error::<Test>::RuntimeProposalIsEmpty.into())
@@ -280,6 +330,7 @@ fn create_set_election_parameters_proposal_common_checks_succeed() {
                     b"title".to_vec(),
                     b"body".to_vec(),
                     None,
+                    None,
                     get_valid_election_parameters(),
                 )
             },
@@ -290,6 +341,7 @@ fn create_set_election_parameters_proposal_common_checks_succeed() {
                     b"title".to_vec(),
                     b"body".to_vec(),
                     None,
+                    None,
                     get_valid_election_parameters(),
                 )
             },
@@ -300,6 +352,7 @@ fn create_set_election_parameters_proposal_common_checks_succeed() {
                     b"title".to_vec(),
                     b"body".to_vec(),
                     Some(<BalanceOf<Test>>::from(50000u32)),
+                    None,
                     get_valid_election_parameters(),
                 )
             },
@@ -310,6 +363,7 @@ fn create_set_election_parameters_proposal_common_checks_succeed() {
                     b"title".to_vec(),
                     b"body".to_vec(),
                     Some(<BalanceOf<Test>>::from(200_000_u32)),
+                    None,
                     get_valid_election_parameters(),
                 )
             },
@@ -334,6 +388,7 @@ fn assert_failed_election_parameters_call(
             b"title".to_vec(),
             b"body".to_vec(),
             Some(<BalanceOf<Test>>::from(3750u32)),
+            None,
             election_parameters,
         ),
         Err(error)
@@ -350,6 +405,7 @@ fn get_valid_election_parameters() -> ElectionParameters<u64, u64> {
         new_term_duration: 14400,
         min_council_stake: 1,
         min_voting_stake: 1,
+        voting_mode: VotingMode::SingleChoice,
     }
 }
 
@@ -483,6 +539,7 @@ fn create_content_working_group_mint_capacity_proposal_fails_with_invalid_parame
                 b"title".to_vec(),
                 b"body".to_vec(),
                 Some(<BalanceOf<Test>>::from(50000u32)),
+                None,
                 (crate::CONTENT_WORKING_GROUP_MINT_CAPACITY_MAX_VALUE + 1) as u64,
             ),
             Err(Error::<Test>::InvalidContentWorkingGroupMintCapacity.into())
@@ -503,6 +560,7 @@ fn create_set_content_working_group_mint_capacity_proposal_common_checks_succeed
                     b"title".to_vec(),
                     b"body".to_vec(),
                     None,
+                    None,
                     0,
                 )
             },
@@ -513,6 +571,7 @@ fn create_set_content_working_group_mint_capacity_proposal_common_checks_succeed
                     b"title".to_vec(),
                     b"body".to_vec(),
                     None,
+                    None,
                     0,
                 )
             },
@@ -523,6 +582,7 @@ fn create_set_content_working_group_mint_capacity_proposal_common_checks_succeed
                     b"title".to_vec(),
                     b"body".to_vec(),
                     Some(<BalanceOf<Test>>::from(5000u32)),
+                    None,
                     0,
                 )
             },
@@ -533,6 +593,7 @@ fn create_set_content_working_group_mint_capacity_proposal_common_checks_succeed
                     b"title".to_vec(),
                     b"body".to_vec(),
                     Some(<BalanceOf<Test>>::from(50000u32)),
+                    None,
                     10,
                 )
             },
@@ -556,6 +617,7 @@ fn create_spending_proposal_common_checks_succeed() {
                     b"title".to_vec(),
                     b"body".to_vec(),
                     None,
+                    None,
                     20,
                     10,
                 )
@@ -567,6 +629,7 @@ fn create_spending_proposal_common_checks_succeed() {
                     b"title".to_vec(),
                     b"body".to_vec(),
                     None,
+                    None,
                     20,
                     10,
                 )
@@ -578,6 +641,7 @@ fn create_spending_proposal_common_checks_succeed() {
                     b"title".to_vec(),
                     b"body".to_vec(),
                     Some(<BalanceOf<Test>>::from(5000u32)),
+                    None,
                     20,
                     10,
                 )
@@ -589,6 +653,7 @@ fn create_spending_proposal_common_checks_succeed() {
                     b"title".to_vec(),
                     b"body".to_vec(),
                     Some(<BalanceOf<Test>>::from(25000u32)),
+                    None,
                     100,
                     2,
                 )
@@ -612,6 +677,7 @@ fn create_spending_proposal_call_fails_with_incorrect_balance() {
                 b"title".to_vec(),
                 b"body".to_vec(),
                 Some(<BalanceOf<Test>>::from(1250u32)),
+                None,
                 0,
                 2,
             ),
@@ -625,6 +691,7 @@ fn create_spending_proposal_call_fails_with_incorrect_balance() {
                 b"title".to_vec(),
                 b"body".to_vec(),
                 Some(<BalanceOf<Test>>::from(1250u32)),
+                None,
                 5000001,
                 2,
             ),
@@ -634,81 +701,36 @@ fn create_spending_proposal_call_fails_with_incorrect_balance() {
 }
 
 #[test]
-fn create_set_lead_proposal_fails_with_proposed_councilor() {
+fn create_set_lead_proposal_fails_as_deprecated() {
     initial_test_ext().execute_with(|| {
         increase_total_balance_issuance_using_account_id(1, 500000);
 
-        let lead_account_id = 20;
-        <governance::council::Module<Test>>::set_council(
-            RawOrigin::Root.into(),
-            vec![lead_account_id],
-        )
-        .unwrap();
-
         assert_eq!(
             ProposalCodex::create_set_lead_proposal(
                 RawOrigin::Signed(1).into(),
                 1,
                 b"title".to_vec(),
                 b"body".to_vec(),
-                Some(<BalanceOf<Test>>::from(1250u32)),
-                Some((20, lead_account_id)),
+                Some(<BalanceOf<Test>>::from(50000u32)),
+                None,
+                Some((20, 10)),
             ),
-            Err(Error::<Test>::InvalidSetLeadParameterCannotBeCouncilor.into())
+            Err(Error::<Test>::SetLeadProposalIsDeprecated.into())
         );
-    });
-}
-
-#[test]
-fn create_set_lead_proposal_common_checks_succeed() {
-    initial_test_ext().execute_with(|| {
-        increase_total_balance_issuance(500000);
 
-        let proposal_fixture = ProposalTestFixture {
-            insufficient_rights_call: || {
-                ProposalCodex::create_set_lead_proposal(
-                    RawOrigin::None.into(),
-                    1,
-                    b"title".to_vec(),
-                    b"body".to_vec(),
-                    None,
-                    Some((20, 10)),
-                )
-            },
-            empty_stake_call: || {
-                ProposalCodex::create_set_lead_proposal(
-                    RawOrigin::Signed(1).into(),
-                    1,
-                    b"title".to_vec(),
-                    b"body".to_vec(),
-                    None,
-                    Some((20, 10)),
-                )
-            },
-            invalid_stake_call: || {
-                ProposalCodex::create_set_lead_proposal(
-                    RawOrigin::Signed(1).into(),
-                    1,
-                    b"title".to_vec(),
-                    b"body".to_vec(),
-                    Some(<BalanceOf<Test>>::from(5000u32)),
-                    Some((20, 10)),
-                )
-            },
-            successful_call: || {
-                ProposalCodex::create_set_lead_proposal(
-                    RawOrigin::Signed(1).into(),
-                    1,
-                    b"title".to_vec(),
-                    b"body".to_vec(),
-                    Some(<BalanceOf<Test>>::from(50000u32)),
-                    Some((20, 10)),
-                )
-            },
-            proposal_parameters: crate::proposal_types::parameters::set_lead_proposal::<Test>(),
-            proposal_details: ProposalDetails::SetLead(Some((20, 10))),
-        };
-        proposal_fixture.check_all();
+        // The deprecated proposal cannot be batched as well.
+        assert_eq!(
+            ProposalCodex::create_batch_proposal(
+                RawOrigin::Signed(1).into(),
+                1,
+                b"title".to_vec(),
+                b"body".to_vec(),
+                Some(<BalanceOf<Test>>::from(50000u32)),
+                None,
+                vec![ProposalDetails::SetLead(Some((20, 10)))],
+            ),
+            Err(Error::<Test>::SetLeadProposalIsDeprecated.into())
+        );
     });
 }
 
@@ -725,6 +747,7 @@ fn create_set_validator_count_proposal_common_checks_succeed() {
                     b"title".to_vec(),
                     b"body".to_vec(),
                     None,
+                    None,
                     4,
                 )
             },
@@ -735,6 +758,7 @@ fn create_set_validator_count_proposal_common_checks_succeed() {
                     b"title".to_vec(),
                     b"body".to_vec(),
                     None,
+                    None,
                     4,
                 )
             },
@@ -745,6 +769,7 @@ fn create_set_validator_count_proposal_common_checks_succeed() {
                     b"title".to_vec(),
                     b"body".to_vec(),
                     Some(<BalanceOf<Test>>::from(5000u32)),
+                    None,
                     4,
                 )
             },
@@ -755,6 +780,7 @@ fn create_set_validator_count_proposal_common_checks_succeed() {
                     b"title".to_vec(),
                     b"body".to_vec(),
                     Some(<BalanceOf<Test>>::from(100_000_u32)),
+                    None,
                     4,
                 )
             },
@@ -777,6 +803,7 @@ fn create_set_validator_count_proposal_failed_with_invalid_validator_count() {
                 b"title".to_vec(),
                 b"body".to_vec(),
                 Some(<BalanceOf<Test>>::from(500u32)),
+                None,
                 3,
             ),
             Err(Error::<Test>::InvalidValidatorCount.into())
@@ -789,6 +816,7 @@ fn create_set_validator_count_proposal_failed_with_invalid_validator_count() {
                 b"title".to_vec(),
                 b"body".to_vec(),
                 Some(<BalanceOf<Test>>::from(1001u32)),
+                None,
                 3,
             ),
             Err(Error::<Test>::InvalidValidatorCount.into())
@@ -802,133 +830,73 @@ fn set_default_proposal_parameters_succeeded() {
         let p = ProposalsConfigParameters::default();
 
         // nothing is set
-        assert_eq!(<SetValidatorCountProposalVotingPeriod<Test>>::get(), 0);
+        assert_eq!(
+            ProposalCodex::proposal_parameters_by_kind(ProposalKind::SetValidatorCount),
+            None
+        );
 
         ProposalCodex::set_config_values(p);
 
+        for proposal_kind in ProposalKind::all() {
+            let (voting_period, grace_period) = p.voting_and_grace_periods(proposal_kind);
+            let proposal_parameters = ProposalCodex::proposal_parameters_by_kind(proposal_kind)
+                .expect("Proposal parameters are set");
+
+            assert_eq!(proposal_parameters.voting_period, voting_period as u64);
+            assert_eq!(proposal_parameters.grace_period, grace_period as u64);
+
+            let (approval_quorum_percentage, approval_threshold_percentage, required_stake) =
+                p.approval_percentages_and_stake(proposal_kind);
+            assert_eq!(
+                proposal_parameters.approval_quorum_percentage,
+                approval_quorum_percentage
+            );
+            assert_eq!(
+                proposal_parameters.approval_threshold_percentage,
+                approval_threshold_percentage
+            );
+            assert_eq!(
+                proposal_parameters.required_stake,
+                Some(required_stake as u64)
+            );
+            assert_eq!(
+                ProposalCodex::proposal_parameters(proposal_kind),
+                proposal_parameters
+            );
+        }
+
         assert_eq!(
-            <SetValidatorCountProposalVotingPeriod<Test>>::get(),
-            p.set_validator_count_proposal_voting_period as u64
-        );
-        assert_eq!(
-            <SetValidatorCountProposalGracePeriod<Test>>::get(),
-            p.set_validator_count_proposal_grace_period as u64
-        );
-        assert_eq!(
-            <RuntimeUpgradeProposalVotingPeriod<Test>>::get(),
-            p.runtime_upgrade_proposal_voting_period as u64
-        );
-        assert_eq!(
-            <RuntimeUpgradeProposalGracePeriod<Test>>::get(),
-            p.runtime_upgrade_proposal_grace_period as u64
-        );
-        assert_eq!(
-            <TextProposalVotingPeriod<Test>>::get(),
-            p.text_proposal_voting_period as u64
-        );
-        assert_eq!(
-            <TextProposalGracePeriod<Test>>::get(),
-            p.text_proposal_grace_period as u64
-        );
-        assert_eq!(
-            <SetElectionParametersProposalVotingPeriod<Test>>::get(),
-            p.set_election_parameters_proposal_voting_period as u64
-        );
-        assert_eq!(
-            <SetElectionParametersProposalGracePeriod<Test>>::get(),
-            p.set_election_parameters_proposal_grace_period as u64
-        );
-        assert_eq!(
-            <SetContentWorkingGroupMintCapacityProposalVotingPeriod<Test>>::get(),
-            p.set_content_working_group_mint_capacity_proposal_voting_period as u64
-        );
-        assert_eq!(
-            <SetContentWorkingGroupMintCapacityProposalGracePeriod<Test>>::get(),
-            p.set_content_working_group_mint_capacity_proposal_grace_period as u64
-        );
-        assert_eq!(
-            <SetLeadProposalVotingPeriod<Test>>::get(),
-            p.set_lead_proposal_voting_period as u64
-        );
-        assert_eq!(
-            <SetLeadProposalGracePeriod<Test>>::get(),
-            p.set_lead_proposal_grace_period as u64
-        );
-        assert_eq!(
-            <SpendingProposalVotingPeriod<Test>>::get(),
-            p.spending_proposal_voting_period as u64
-        );
-        assert_eq!(
-            <SpendingProposalGracePeriod<Test>>::get(),
-            p.spending_proposal_grace_period as u64
-        );
-        assert_eq!(
-            <AddWorkingGroupOpeningProposalVotingPeriod<Test>>::get(),
-            p.add_working_group_opening_proposal_voting_period as u64
-        );
-        assert_eq!(
-            <AddWorkingGroupOpeningProposalGracePeriod<Test>>::get(),
-            p.add_working_group_opening_proposal_grace_period as u64
-        );
-        assert_eq!(
-            <BeginReviewWorkingGroupLeaderApplicationsProposalVotingPeriod<Test>>::get(),
-            p.begin_review_working_group_leader_applications_proposal_voting_period as u64
-        );
-        assert_eq!(
-            <BeginReviewWorkingGroupLeaderApplicationsProposalGracePeriod<Test>>::get(),
-            p.begin_review_working_group_leader_applications_proposal_grace_period as u64
-        );
-        assert_eq!(
-            <FillWorkingGroupLeaderOpeningProposalVotingPeriod<Test>>::get(),
-            p.fill_working_group_leader_opening_proposal_voting_period as u64
-        );
-        assert_eq!(
-            <FillWorkingGroupLeaderOpeningProposalGracePeriod<Test>>::get(),
-            p.fill_working_group_leader_opening_proposal_grace_period as u64
-        );
-        assert_eq!(
-            <SetWorkingGroupMintCapacityProposalVotingPeriod<Test>>::get(),
-            p.set_working_group_mint_capacity_proposal_voting_period as u64
-        );
-        assert_eq!(
-            <SetWorkingGroupMintCapacityProposalGracePeriod<Test>>::get(),
-            p.set_working_group_mint_capacity_proposal_grace_period as u64
-        );
-        assert_eq!(
-            <DecreaseWorkingGroupLeaderStakeProposalVotingPeriod<Test>>::get(),
-            p.decrease_working_group_leader_stake_proposal_voting_period as u64
-        );
-        assert_eq!(
-            <DecreaseWorkingGroupLeaderStakeProposalGracePeriod<Test>>::get(),
-            p.decrease_working_group_leader_stake_proposal_grace_period as u64
-        );
-        assert_eq!(
-            <SlashWorkingGroupLeaderStakeProposalVotingPeriod<Test>>::get(),
-            p.slash_working_group_leader_stake_proposal_voting_period as u64
-        );
-        assert_eq!(
-            <SlashWorkingGroupLeaderStakeProposalGracePeriod<Test>>::get(),
-            p.slash_working_group_leader_stake_proposal_grace_period as u64
-        );
-        assert_eq!(
-            <SetWorkingGroupLeaderRewardProposalVotingPeriod<Test>>::get(),
-            p.set_working_group_leader_reward_proposal_voting_period as u64
-        );
-        assert_eq!(
-            <SetWorkingGroupLeaderRewardProposalGracePeriod<Test>>::get(),
-            p.set_working_group_leader_reward_proposal_grace_period as u64
-        );
-        assert_eq!(
-            <TerminateWorkingGroupLeaderRoleProposalVotingPeriod<Test>>::get(),
-            p.terminate_working_group_leader_role_proposal_voting_period as u64
-        );
-        assert_eq!(
-            <TerminateWorkingGroupLeaderRoleProposalGracePeriod<Test>>::get(),
-            p.terminate_working_group_leader_role_proposal_grace_period as u64
+            crate::proposal_types::parameters::runtime_upgrade_proposal::<Test>(),
+            ProposalParameters {
+                voting_period: p.runtime_upgrade_proposal_voting_period as u64,
+                grace_period: p.runtime_upgrade_proposal_grace_period as u64,
+                approval_quorum_percentage: 80,
+                approval_threshold_percentage: 100,
+                slashing_quorum_percentage: 60,
+                slashing_threshold_percentage: 80,
+                required_stake: Some(1_000_000),
+            }
         );
     });
 }
 
+#[test]
+fn set_config_values_sets_configured_approval_percentages_and_stake() {
+    initial_test_ext().execute_with(|| {
+        let mut config = ProposalsConfigParameters::default();
+        config.text_proposal_approval_quorum_percentage = 50;
+        config.text_proposal_approval_threshold_percentage = 70;
+        config.text_proposal_required_stake = 500;
+        ProposalCodex::set_config_values(config);
+
+        let parameters = crate::proposal_types::parameters::text_proposal::<Test>();
+
+        assert_eq!(parameters.approval_quorum_percentage, 50);
+        assert_eq!(parameters.approval_threshold_percentage, 70);
+        assert_eq!(parameters.required_stake, Some(500));
+    });
+}
+
 #[test]
 fn create_add_working_group_leader_opening_proposal_common_checks_succeed() {
     initial_test_ext().execute_with(|| {
@@ -949,6 +917,7 @@ fn create_add_working_group_leader_opening_proposal_common_checks_succeed() {
                     b"title".to_vec(),
                     b"body".to_vec(),
                     None,
+                    None,
                     add_opening_parameters.clone(),
                 )
             },
@@ -959,6 +928,7 @@ fn create_add_working_group_leader_opening_proposal_common_checks_succeed() {
                     b"title".to_vec(),
                     b"body".to_vec(),
                     None,
+                    None,
                     add_opening_parameters.clone(),
                 )
             },
@@ -969,6 +939,7 @@ fn create_add_working_group_leader_opening_proposal_common_checks_succeed() {
                     b"title".to_vec(),
                     b"body".to_vec(),
                     Some(<BalanceOf<Test>>::from(5000u32)),
+                    None,
                     add_opening_parameters.clone(),
                 )
             },
@@ -979,6 +950,7 @@ fn create_add_working_group_leader_opening_proposal_common_checks_succeed() {
                     b"title".to_vec(),
                     b"body".to_vec(),
                     Some(<BalanceOf<Test>>::from(100_000_u32)),
+                    None,
                     add_opening_parameters.clone(),
                 )
             },
@@ -1006,6 +978,7 @@ fn create_begin_review_working_group_leader_applications_proposal_common_checks_
                     b"title".to_vec(),
                     b"body".to_vec(),
                     None,
+                    None,
                     opening_id,
                     WorkingGroup::Storage
                 )
@@ -1017,6 +990,7 @@ fn create_begin_review_working_group_leader_applications_proposal_common_checks_
                     b"title".to_vec(),
                     b"body".to_vec(),
                     None,
+                    None,
                     opening_id,
                     WorkingGroup::Storage
                 )
@@ -1028,6 +1002,7 @@ fn create_begin_review_working_group_leader_applications_proposal_common_checks_
                     b"title".to_vec(),
                     b"body".to_vec(),
                     Some(<BalanceOf<Test>>::from(5000u32)),
+                    None,
                     opening_id,
                     WorkingGroup::Storage
                 )
@@ -1039,6 +1014,7 @@ fn create_begin_review_working_group_leader_applications_proposal_common_checks_
                     b"title".to_vec(),
                     b"body".to_vec(),
                     Some(<BalanceOf<Test>>::from(25000u32)),
+                    None,
                     opening_id,
                     WorkingGroup::Storage
                 )
@@ -1075,6 +1051,7 @@ fn create_fill_working_group_leader_opening_proposal_common_checks_succeed() {
                     b"title".to_vec(),
                     b"body".to_vec(),
                     None,
+                    None,
                     fill_opening_parameters.clone()
                 )
             },
@@ -1085,6 +1062,7 @@ fn create_fill_working_group_leader_opening_proposal_common_checks_succeed() {
                     b"title".to_vec(),
                     b"body".to_vec(),
                     None,
+                    None,
                     fill_opening_parameters.clone()
                 )
             },
@@ -1095,6 +1073,7 @@ fn create_fill_working_group_leader_opening_proposal_common_checks_succeed() {
                     b"title".to_vec(),
                     b"body".to_vec(),
                     Some(<BalanceOf<Test>>::from(5000u32)),
+                    None,
                     fill_opening_parameters.clone()
                 )
             },
@@ -1105,6 +1084,7 @@ fn create_fill_working_group_leader_opening_proposal_common_checks_succeed() {
                     b"title".to_vec(),
                     b"body".to_vec(),
                     Some(<BalanceOf<Test>>::from(50000u32)),
+                    None,
                     fill_opening_parameters.clone()
                 )
             },
@@ -1129,6 +1109,7 @@ fn create_working_group_mint_capacity_proposal_fails_with_invalid_parameters() {
                 b"title".to_vec(),
                 b"body".to_vec(),
                 Some(<BalanceOf<Test>>::from(50000u32)),
+                None,
                 (crate::WORKING_GROUP_MINT_CAPACITY_MAX_VALUE + 1) as u64,
                 WorkingGroup::Storage,
             ),
@@ -1150,6 +1131,7 @@ fn create_set_working_group_mint_capacity_proposal_common_checks_succeed() {
                     b"title".to_vec(),
                     b"body".to_vec(),
                     None,
+                    None,
                     0,
                     WorkingGroup::Storage,
                 )
@@ -1161,6 +1143,7 @@ fn create_set_working_group_mint_capacity_proposal_common_checks_succeed() {
                     b"title".to_vec(),
                     b"body".to_vec(),
                     None,
+                    None,
                     0,
                     WorkingGroup::Storage,
                 )
@@ -1172,6 +1155,7 @@ fn create_set_working_group_mint_capacity_proposal_common_checks_succeed() {
                     b"title".to_vec(),
                     b"body".to_vec(),
                     Some(<BalanceOf<Test>>::from(5000u32)),
+                    None,
                     0,
                     WorkingGroup::Storage,
                 )
@@ -1183,6 +1167,7 @@ fn create_set_working_group_mint_capacity_proposal_common_checks_succeed() {
                     b"title".to_vec(),
                     b"body".to_vec(),
                     Some(<BalanceOf<Test>>::from(50000u32)),
+                    None,
                     10,
                     WorkingGroup::Storage,
                 )
@@ -1212,6 +1197,7 @@ fn create_decrease_working_group_leader_stake_proposal_common_checks_succeed() {
                     b"title".to_vec(),
                     b"body".to_vec(),
                     None,
+                    None,
                     0,
                     10,
                     WorkingGroup::Storage,
@@ -1224,6 +1210,7 @@ fn create_decrease_working_group_leader_stake_proposal_common_checks_succeed() {
                     b"title".to_vec(),
                     b"body".to_vec(),
                     None,
+                    None,
                     0,
                     10,
                     WorkingGroup::Storage,
@@ -1236,6 +1223,7 @@ fn create_decrease_working_group_leader_stake_proposal_common_checks_succeed() {
                     b"title".to_vec(),
                     b"body".to_vec(),
                     Some(<BalanceOf<Test>>::from(5000u32)),
+                    None,
                     0,
                     10,
                     WorkingGroup::Storage,
@@ -1248,6 +1236,7 @@ fn create_decrease_working_group_leader_stake_proposal_common_checks_succeed() {
                     b"title".to_vec(),
                     b"body".to_vec(),
                     Some(<BalanceOf<Test>>::from(50000u32)),
+                    None,
                     10,
                     10,
                     WorkingGroup::Storage,
@@ -1280,6 +1269,7 @@ fn create_slash_working_group_leader_stake_proposal_common_checks_succeed() {
                     b"title".to_vec(),
                     b"body".to_vec(),
                     None,
+                    None,
                     0,
                     10,
                     WorkingGroup::Storage,
@@ -1292,6 +1282,7 @@ fn create_slash_working_group_leader_stake_proposal_common_checks_succeed() {
                     b"title".to_vec(),
                     b"body".to_vec(),
                     None,
+                    None,
                     0,
                     10,
                     WorkingGroup::Storage,
@@ -1304,6 +1295,7 @@ fn create_slash_working_group_leader_stake_proposal_common_checks_succeed() {
                     b"title".to_vec(),
                     b"body".to_vec(),
                     Some(<BalanceOf<Test>>::from(5000u32)),
+                    None,
                     0,
                     10,
                     WorkingGroup::Storage,
@@ -1316,6 +1308,7 @@ fn create_slash_working_group_leader_stake_proposal_common_checks_succeed() {
                     b"title".to_vec(),
                     b"body".to_vec(),
                     Some(<BalanceOf<Test>>::from(50000u32)),
+                    None,
                     10,
                     10,
                     WorkingGroup::Storage,
@@ -1354,6 +1347,7 @@ fn slash_stake_with_zero_staking_balance_fails() {
                 b"title".to_vec(),
                 b"body".to_vec(),
                 Some(<BalanceOf<Test>>::from(50000u32)),
+                None,
                 10,
                 0,
                 WorkingGroup::Storage,
@@ -1382,6 +1376,7 @@ fn decrease_stake_with_zero_staking_balance_fails() {
                 b"title".to_vec(),
                 b"body".to_vec(),
                 Some(<BalanceOf<Test>>::from(50000u32)),
+                None,
                 10,
                 0,
                 WorkingGroup::Storage,
@@ -1402,6 +1397,7 @@ fn create_set_working_group_leader_reward_proposal_common_checks_succeed() {
                     b"title".to_vec(),
                     b"body".to_vec(),
                     None,
+                    None,
                     0,
                     10,
                     WorkingGroup::Storage,
@@ -1414,6 +1410,7 @@ fn create_set_working_group_leader_reward_proposal_common_checks_succeed() {
                     b"title".to_vec(),
                     b"body".to_vec(),
                     None,
+                    None,
                     0,
                     10,
                     WorkingGroup::Storage,
@@ -1426,6 +1423,7 @@ fn create_set_working_group_leader_reward_proposal_common_checks_succeed() {
                     b"title".to_vec(),
                     b"body".to_vec(),
                     Some(<BalanceOf<Test>>::from(5000u32)),
+                    None,
                     0,
                     10,
                     WorkingGroup::Storage,
@@ -1438,6 +1436,7 @@ fn create_set_working_group_leader_reward_proposal_common_checks_succeed() {
                     b"title".to_vec(),
                     b"body".to_vec(),
                     Some(<BalanceOf<Test>>::from(50000u32)),
+                    None,
                     10,
                     10,
                     WorkingGroup::Storage,
@@ -1476,6 +1475,7 @@ fn create_terminate_working_group_leader_role_proposal_common_checks_succeed() {
                     b"title".to_vec(),
                     b"body".to_vec(),
                     None,
+                    None,
                     terminate_role_parameters.clone(),
                 )
             },
@@ -1486,6 +1486,7 @@ fn create_terminate_working_group_leader_role_proposal_common_checks_succeed() {
                     b"title".to_vec(),
                     b"body".to_vec(),
                     None,
+                    None,
                     terminate_role_parameters.clone(),
                 )
             },
@@ -1496,6 +1497,7 @@ fn create_terminate_working_group_leader_role_proposal_common_checks_succeed() {
                     b"title".to_vec(),
                     b"body".to_vec(),
                     Some(<BalanceOf<Test>>::from(5000u32)),
+                    None,
                     terminate_role_parameters.clone(),
                 )
             },
@@ -1506,6 +1508,7 @@ fn create_terminate_working_group_leader_role_proposal_common_checks_succeed() {
                     b"title".to_vec(),
                     b"body".to_vec(),
                     Some(<BalanceOf<Test>>::from(100_000_u32)),
+                    None,
                     terminate_role_parameters.clone(),
                 )
             },
@@ -1520,3 +1523,964 @@ fn create_terminate_working_group_leader_role_proposal_common_checks_succeed() {
         proposal_fixture.check_all();
     });
 }
+
+#[test]
+fn create_set_mint_capacity_adjustment_proposal_fails_with_invalid_parameters() {
+    initial_test_ext().execute_with(|| {
+        increase_total_balance_issuance_using_account_id(1, 500000);
+
+        let exceeding_adjustment = minting::Adjustment::Interval(minting::AdjustOnInterval {
+            block_interval: 100,
+            adjustment_type: minting::AdjustCapacityBy::Adding(
+                (crate::MINT_CAPACITY_ADJUSTMENT_MAX_VALUE + 1) as u64,
+            ),
+        });
+
+        assert_eq!(
+            ProposalCodex::create_set_mint_capacity_adjustment_proposal(
+                RawOrigin::Signed(1).into(),
+                1,
+                b"title".to_vec(),
+                b"body".to_vec(),
+                Some(<BalanceOf<Test>>::from(50000u32)),
+                None,
+                GovernedMint::Council,
+                Some(exceeding_adjustment),
+            ),
+            Err(Error::<Test>::InvalidMintCapacityAdjustment.into())
+        );
+
+        let zero_interval_adjustment = minting::Adjustment::Interval(minting::AdjustOnInterval {
+            block_interval: 0,
+            adjustment_type: minting::AdjustCapacityBy::Adding(100),
+        });
+
+        assert_eq!(
+            ProposalCodex::create_set_mint_capacity_adjustment_proposal(
+                RawOrigin::Signed(1).into(),
+                1,
+                b"title".to_vec(),
+                b"body".to_vec(),
+                Some(<BalanceOf<Test>>::from(50000u32)),
+                None,
+                GovernedMint::WorkingGroup(WorkingGroup::Storage),
+                Some(zero_interval_adjustment),
+            ),
+            Err(Error::<Test>::InvalidMintCapacityAdjustment.into())
+        );
+    });
+}
+
+#[test]
+fn create_set_mint_capacity_adjustment_proposal_fails_with_first_adjustment_before_execution() {
+    initial_test_ext().execute_with(|| {
+        increase_total_balance_issuance_using_account_id(1, 500000);
+
+        let proposal_parameters =
+            crate::proposal_types::parameters::set_mint_capacity_adjustment_proposal::<Test>();
+        let latest_execution_block = System::block_number()
+            + proposal_parameters.voting_period
+            + proposal_parameters.grace_period;
+
+        let adjustment_at = |first_adjustment_at| {
+            minting::Adjustment::IntervalAfterFirstAdjustmentAbsolute(
+                minting::AdjustOnInterval {
+                    block_interval: 100,
+                    adjustment_type: minting::AdjustCapacityBy::Adding(100),
+                },
+                first_adjustment_at,
+            )
+        };
+
+        assert_eq!(
+            ProposalCodex::create_set_mint_capacity_adjustment_proposal(
+                RawOrigin::Signed(1).into(),
+                1,
+                b"title".to_vec(),
+                b"body".to_vec(),
+                Some(<BalanceOf<Test>>::from(50000u32)),
+                None,
+                GovernedMint::Council,
+                Some(adjustment_at(latest_execution_block)),
+            ),
+            Err(Error::<Test>::MintCapacityAdjustmentBeforeExecution.into())
+        );
+
+        // The exact execution block is the latest execution block.
+        assert_eq!(
+            ProposalCodex::create_set_mint_capacity_adjustment_proposal(
+                RawOrigin::Signed(1).into(),
+                1,
+                b"title".to_vec(),
+                b"body".to_vec(),
+                Some(<BalanceOf<Test>>::from(50000u32)),
+                Some(latest_execution_block + 10),
+                GovernedMint::Council,
+                Some(adjustment_at(latest_execution_block + 1)),
+            ),
+            Err(Error::<Test>::MintCapacityAdjustmentBeforeExecution.into())
+        );
+
+        assert_eq!(
+            ProposalCodex::create_set_mint_capacity_adjustment_proposal(
+                RawOrigin::Signed(1).into(),
+                1,
+                b"title".to_vec(),
+                b"body".to_vec(),
+                Some(<BalanceOf<Test>>::from(50000u32)),
+                None,
+                GovernedMint::Council,
+                Some(adjustment_at(latest_execution_block + 1)),
+            ),
+            Ok(())
+        );
+    });
+}
+
+#[test]
+fn create_set_mint_capacity_adjustment_proposal_common_checks_succeed() {
+    initial_test_ext().execute_with(|| {
+        increase_total_balance_issuance(500000);
+
+        let proposal_fixture = ProposalTestFixture {
+            insufficient_rights_call: || {
+                ProposalCodex::create_set_mint_capacity_adjustment_proposal(
+                    RawOrigin::None.into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    None,
+                    None,
+                    GovernedMint::WorkingGroup(WorkingGroup::Storage),
+                    Some(get_valid_mint_capacity_adjustment()),
+                )
+            },
+            empty_stake_call: || {
+                ProposalCodex::create_set_mint_capacity_adjustment_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    None,
+                    None,
+                    GovernedMint::WorkingGroup(WorkingGroup::Storage),
+                    Some(get_valid_mint_capacity_adjustment()),
+                )
+            },
+            invalid_stake_call: || {
+                ProposalCodex::create_set_mint_capacity_adjustment_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    Some(<BalanceOf<Test>>::from(5000u32)),
+                    None,
+                    GovernedMint::WorkingGroup(WorkingGroup::Storage),
+                    Some(get_valid_mint_capacity_adjustment()),
+                )
+            },
+            successful_call: || {
+                ProposalCodex::create_set_mint_capacity_adjustment_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    Some(<BalanceOf<Test>>::from(50000u32)),
+                    None,
+                    GovernedMint::WorkingGroup(WorkingGroup::Storage),
+                    Some(get_valid_mint_capacity_adjustment()),
+                )
+            },
+            proposal_parameters:
+                crate::proposal_types::parameters::set_mint_capacity_adjustment_proposal::<Test>(),
+            proposal_details: ProposalDetails::SetMintCapacityAdjustment(
+                GovernedMint::WorkingGroup(WorkingGroup::Storage),
+                Some(get_valid_mint_capacity_adjustment()),
+            ),
+        };
+        proposal_fixture.check_all();
+    });
+}
+
+fn get_valid_mint_capacity_adjustment() -> minting::Adjustment<u64, u64> {
+    minting::Adjustment::Interval(minting::AdjustOnInterval {
+        block_interval: 100,
+        adjustment_type: minting::AdjustCapacityBy::Setting(5000),
+    })
+}
+
+fn get_valid_proposal_parameters() -> ProposalParameters<u64, u64> {
+    ProposalParameters {
+        voting_period: 14400,
+        grace_period: 0,
+        approval_quorum_percentage: 70,
+        approval_threshold_percentage: 80,
+        slashing_quorum_percentage: 60,
+        slashing_threshold_percentage: 80,
+        required_stake: Some(30000),
+    }
+}
+
+#[test]
+fn create_set_proposal_parameters_proposal_common_checks_succeed() {
+    initial_test_ext().execute_with(|| {
+        increase_total_balance_issuance_using_account_id(1, 500000);
+
+        let proposal_fixture = ProposalTestFixture {
+            insufficient_rights_call: || {
+                ProposalCodex::create_set_proposal_parameters_proposal(
+                    RawOrigin::None.into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    None,
+                    None,
+                    ProposalKind::Text,
+                    get_valid_proposal_parameters(),
+                )
+            },
+            empty_stake_call: || {
+                ProposalCodex::create_set_proposal_parameters_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    None,
+                    None,
+                    ProposalKind::Text,
+                    get_valid_proposal_parameters(),
+                )
+            },
+            invalid_stake_call: || {
+                ProposalCodex::create_set_proposal_parameters_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    Some(<BalanceOf<Test>>::from(5000u32)),
+                    None,
+                    ProposalKind::Text,
+                    get_valid_proposal_parameters(),
+                )
+            },
+            successful_call: || {
+                ProposalCodex::create_set_proposal_parameters_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    Some(<BalanceOf<Test>>::from(200_000_u32)),
+                    None,
+                    ProposalKind::Text,
+                    get_valid_proposal_parameters(),
+                )
+            },
+            proposal_parameters:
+                crate::proposal_types::parameters::set_proposal_parameters_proposal::<Test>(),
+            proposal_details: ProposalDetails::SetProposalParameters(
+                ProposalKind::Text,
+                get_valid_proposal_parameters(),
+            ),
+        };
+        proposal_fixture.check_all();
+    });
+}
+
+#[test]
+fn create_set_proposal_parameters_proposal_fails_with_invalid_parameters() {
+    initial_test_ext().execute_with(|| {
+        increase_total_balance_issuance_using_account_id(1, 500000);
+
+        let create_set_proposal_parameters_proposal = |proposal_kind, proposal_parameters| {
+            ProposalCodex::create_set_proposal_parameters_proposal(
+                RawOrigin::Signed(1).into(),
+                1,
+                b"title".to_vec(),
+                b"body".to_vec(),
+                Some(<BalanceOf<Test>>::from(200_000_u32)),
+                None,
+                proposal_kind,
+                proposal_parameters,
+            )
+        };
+
+        let valid_parameters = get_valid_proposal_parameters();
+
+        assert_eq!(
+            create_set_proposal_parameters_proposal(
+                ProposalKind::Text,
+                ProposalParameters {
+                    voting_period: (crate::PROPOSAL_PARAMETERS_VOTING_PERIOD_MIN_VALUE - 1) as u64,
+                    ..valid_parameters
+                }
+            ),
+            Err(Error::<Test>::InvalidProposalParametersVotingPeriod.into())
+        );
+
+        assert_eq!(
+            create_set_proposal_parameters_proposal(
+                ProposalKind::Text,
+                ProposalParameters {
+                    voting_period: (crate::PROPOSAL_PARAMETERS_VOTING_PERIOD_MAX_VALUE + 1) as u64,
+                    ..valid_parameters
+                }
+            ),
+            Err(Error::<Test>::InvalidProposalParametersVotingPeriod.into())
+        );
+
+        assert_eq!(
+            create_set_proposal_parameters_proposal(
+                ProposalKind::Text,
+                ProposalParameters {
+                    grace_period: (crate::PROPOSAL_PARAMETERS_GRACE_PERIOD_MAX_VALUE + 1) as u64,
+                    ..valid_parameters
+                }
+            ),
+            Err(Error::<Test>::InvalidProposalParametersGracePeriod.into())
+        );
+
+        assert_eq!(
+            create_set_proposal_parameters_proposal(
+                ProposalKind::Text,
+                ProposalParameters {
+                    approval_quorum_percentage: 49,
+                    ..valid_parameters
+                }
+            ),
+            Err(Error::<Test>::InvalidProposalParametersPercentage.into())
+        );
+
+        assert_eq!(
+            create_set_proposal_parameters_proposal(
+                ProposalKind::Text,
+                ProposalParameters {
+                    approval_threshold_percentage: 101,
+                    ..valid_parameters
+                }
+            ),
+            Err(Error::<Test>::InvalidProposalParametersPercentage.into())
+        );
+
+        assert_eq!(
+            create_set_proposal_parameters_proposal(
+                ProposalKind::Text,
+                ProposalParameters {
+                    slashing_quorum_percentage: 0,
+                    ..valid_parameters
+                }
+            ),
+            Err(Error::<Test>::InvalidProposalParametersPercentage.into())
+        );
+
+        // The 'set proposal parameters' and 'amend constitution' proposals require a
+        // supermajority.
+        assert_eq!(
+            create_set_proposal_parameters_proposal(
+                ProposalKind::SetProposalParameters,
+                ProposalParameters {
+                    approval_threshold_percentage: 60,
+                    ..valid_parameters
+                }
+            ),
+            Err(Error::<Test>::InvalidProposalParametersPercentage.into())
+        );
+
+        assert_eq!(
+            create_set_proposal_parameters_proposal(
+                ProposalKind::AmendConstitution,
+                ProposalParameters {
+                    approval_threshold_percentage: 60,
+                    ..valid_parameters
+                }
+            ),
+            Err(Error::<Test>::InvalidProposalParametersPercentage.into())
+        );
+
+        assert_eq!(
+            create_set_proposal_parameters_proposal(
+                ProposalKind::Text,
+                ProposalParameters {
+                    required_stake: None,
+                    ..valid_parameters
+                }
+            ),
+            Err(Error::<Test>::InvalidProposalParametersRequiredStake.into())
+        );
+
+        assert_eq!(
+            create_set_proposal_parameters_proposal(
+                ProposalKind::Text,
+                ProposalParameters {
+                    required_stake: Some(
+                        (crate::PROPOSAL_PARAMETERS_REQUIRED_STAKE_MAX_VALUE + 1) as u64
+                    ),
+                    ..valid_parameters
+                }
+            ),
+            Err(Error::<Test>::InvalidProposalParametersRequiredStake.into())
+        );
+    });
+}
+
+#[test]
+fn set_proposal_parameters_changes_proposal_parameters() {
+    initial_test_ext().execute_with(|| {
+        let proposal_parameters = get_valid_proposal_parameters();
+
+        assert_eq!(
+            ProposalCodex::set_proposal_parameters(
+                RawOrigin::Signed(1).into(),
+                ProposalKind::Text,
+                proposal_parameters
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+
+        assert_eq!(
+            ProposalCodex::set_proposal_parameters(
+                RawOrigin::Root.into(),
+                ProposalKind::Text,
+                ProposalParameters {
+                    voting_period: 0,
+                    ..proposal_parameters
+                }
+            ),
+            Err(Error::<Test>::InvalidProposalParametersVotingPeriod.into())
+        );
+
+        assert_eq!(
+            ProposalCodex::set_proposal_parameters(
+                RawOrigin::Root.into(),
+                ProposalKind::Text,
+                proposal_parameters
+            ),
+            Ok(())
+        );
+
+        assert_eq!(
+            crate::proposal_types::parameters::text_proposal::<Test>(),
+            proposal_parameters
+        );
+        assert_ne!(
+            crate::proposal_types::parameters::spending_proposal::<Test>(),
+            proposal_parameters
+        );
+    });
+}
+
+#[test]
+fn migrate_proposal_parameters_moves_proposal_periods() {
+    initial_test_ext().execute_with(|| {
+        let put_period = |item: &[u8], period: u64| {
+            frame_support::storage::migration::put_storage_value(
+                b"ProposalCodex",
+                item,
+                &[],
+                period,
+            );
+        };
+        put_period(b"TextProposalVotingPeriod", 100);
+        put_period(b"TextProposalGracePeriod", 10);
+        put_period(b"SpendingProposalVotingPeriod", 0);
+
+        ProposalCodex::migrate_proposal_parameters();
+
+        let text_parameters = crate::proposal_types::parameters::text_proposal::<Test>();
+        assert_eq!(text_parameters.voting_period, 100);
+        assert_eq!(text_parameters.grace_period, 10);
+        assert_eq!(text_parameters.approval_quorum_percentage, 60);
+
+        // Not configured periods get the default values.
+        let default_config = ProposalsConfigParameters::default();
+        let spending_parameters = crate::proposal_types::parameters::spending_proposal::<Test>();
+        assert_eq!(
+            spending_parameters.voting_period,
+            default_config.spending_proposal_voting_period as u64
+        );
+        assert_eq!(
+            spending_parameters.grace_period,
+            default_config.spending_proposal_grace_period as u64
+        );
+
+        // The periods are moved.
+        assert_eq!(
+            frame_support::storage::migration::get_storage_value::<u64>(
+                b"ProposalCodex",
+                b"TextProposalVotingPeriod",
+                &[]
+            ),
+            None
+        );
+
+        // Migrated once.
+        put_period(b"TextProposalVotingPeriod", 200);
+        ProposalCodex::migrate_proposal_parameters();
+        assert_eq!(
+            crate::proposal_types::parameters::text_proposal::<Test>().voting_period,
+            100
+        );
+    });
+}
+
+#[test]
+fn migrate_proposal_details_converts_legacy_details() {
+    initial_test_ext().execute_with(|| {
+        increase_total_balance_issuance(500000);
+        let _imbalance = <Test as stake::Trait>::Currency::deposit_creating(&1, 150000);
+
+        assert_eq!(
+            ProposalCodex::create_text_proposal(
+                RawOrigin::Signed(1).into(),
+                1,
+                b"title".to_vec(),
+                b"body".to_vec(),
+                Some(<BalanceOf<Test>>::from(25000u32)),
+                None,
+                b"text".to_vec(),
+            ),
+            Ok(())
+        );
+
+        // The code encoded by the previous runtime version.
+        <proposals_engine::DispatchableCallCode<Test>>::insert(1, b"legacy code".to_vec());
+
+        let legacy_election_parameters = governance::election_params::LegacyElectionParameters {
+            announcing_period: 14400,
+            voting_period: 14400,
+            revealing_period: 14400,
+            council_size: 4,
+            candidacy_limit: 25,
+            new_term_duration: 14400,
+            min_council_stake: 1,
+            min_voting_stake: 1,
+        };
+        let legacy_details: crate::proposal_types::LegacyProposalDetailsOf<Test> =
+            crate::proposal_types::LegacyProposalDetails::SetElectionParameters(
+                legacy_election_parameters,
+            );
+        frame_support::storage::unhashed::put(
+            &<crate::ProposalDetailsByProposalId<Test>>::hashed_key_for(2),
+            &legacy_details,
+        );
+
+        let legacy_details: crate::proposal_types::LegacyProposalDetailsOf<Test> =
+            crate::proposal_types::LegacyProposalDetails::AddWorkingGroupLeaderOpening(
+                crate::proposal_types::LegacyAddOpeningParameters {
+                    activate_at: ActivateOpeningAt::CurrentBlock,
+                    commitment: working_group::LegacyOpeningPolicyCommitment::default(),
+                    human_readable_text: b"opening".to_vec(),
+                    working_group: WorkingGroup::Storage,
+                },
+            );
+        frame_support::storage::unhashed::put(
+            &<crate::ProposalDetailsByProposalId<Test>>::hashed_key_for(3),
+            &legacy_details,
+        );
+
+        ProposalCodex::migrate_proposal_details();
+
+        assert_eq!(
+            <crate::ProposalDetailsByProposalId<Test>>::get(1),
+            ProposalDetails::Text(b"text".to_vec())
+        );
+        assert_eq!(
+            <crate::ProposalDetailsByProposalId<Test>>::get(2),
+            ProposalDetails::SetElectionParameters(get_valid_election_parameters())
+        );
+        assert_eq!(
+            <crate::ProposalDetailsByProposalId<Test>>::get(3),
+            ProposalDetails::AddWorkingGroupLeaderOpening(AddOpeningParameters {
+                activate_at: ActivateOpeningAt::CurrentBlock,
+                commitment: OpeningPolicyCommitment::default(),
+                human_readable_text: b"opening".to_vec(),
+                working_group: WorkingGroup::Storage,
+            })
+        );
+
+        // The active proposal code is encoded again.
+        assert_eq!(
+            ProposalsEngine::proposal_codes(1),
+            crate::Call::<Test>::execute_text_proposal(b"text".to_vec()).encode()
+        );
+        assert!(ProposalCodex::proposal_details_migrated());
+    });
+}
+
+fn get_valid_constitution_amendment() -> constitution::ConstitutionAmendment<H256, u64> {
+    let text = b"constitution".to_vec();
+
+    constitution::ConstitutionAmendment {
+        content_hash: BlakeTwo256::hash(&text),
+        content: constitution::ConstitutionContent::Text(text),
+    }
+}
+
+#[test]
+fn create_amend_constitution_proposal_common_checks_succeed() {
+    initial_test_ext().execute_with(|| {
+        increase_total_balance_issuance_using_account_id(1, 500000);
+
+        let proposal_fixture = ProposalTestFixture {
+            insufficient_rights_call: || {
+                ProposalCodex::create_amend_constitution_proposal(
+                    RawOrigin::None.into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    None,
+                    None,
+                    get_valid_constitution_amendment(),
+                )
+            },
+            empty_stake_call: || {
+                ProposalCodex::create_amend_constitution_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    None,
+                    None,
+                    get_valid_constitution_amendment(),
+                )
+            },
+            invalid_stake_call: || {
+                ProposalCodex::create_amend_constitution_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    Some(<BalanceOf<Test>>::from(5000u32)),
+                    None,
+                    get_valid_constitution_amendment(),
+                )
+            },
+            successful_call: || {
+                ProposalCodex::create_amend_constitution_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    Some(<BalanceOf<Test>>::from(200_000_u32)),
+                    None,
+                    get_valid_constitution_amendment(),
+                )
+            },
+            proposal_parameters:
+                crate::proposal_types::parameters::amend_constitution_proposal::<Test>(),
+            proposal_details: ProposalDetails::AmendConstitution(
+                get_valid_constitution_amendment(),
+            ),
+        };
+        proposal_fixture.check_all();
+    });
+}
+
+#[test]
+fn create_amend_constitution_proposal_fails_with_invalid_amendment() {
+    initial_test_ext().execute_with(|| {
+        increase_total_balance_issuance_using_account_id(1, 500000);
+
+        let create_amend_constitution_proposal = |amendment| {
+            ProposalCodex::create_amend_constitution_proposal(
+                RawOrigin::Signed(1).into(),
+                1,
+                b"title".to_vec(),
+                b"body".to_vec(),
+                Some(<BalanceOf<Test>>::from(200_000_u32)),
+                None,
+                amendment,
+            )
+        };
+
+        assert_eq!(
+            create_amend_constitution_proposal(constitution::ConstitutionAmendment {
+                content_hash: BlakeTwo256::hash(&[]),
+                content: constitution::ConstitutionContent::Text(Vec::new()),
+            }),
+            Err(constitution::Error::<Test>::ConstitutionTextIsEmpty.into())
+        );
+
+        assert_eq!(
+            create_amend_constitution_proposal(constitution::ConstitutionAmendment {
+                content_hash: H256::default(),
+                ..get_valid_constitution_amendment()
+            }),
+            Err(constitution::Error::<Test>::ContentHashMismatch.into())
+        );
+
+        // The uploaded document is not verified.
+        assert_eq!(
+            create_amend_constitution_proposal(constitution::ConstitutionAmendment {
+                content_hash: H256::default(),
+                content: constitution::ConstitutionContent::DataObject(1),
+            }),
+            Ok(())
+        );
+    });
+}
+
+fn get_valid_batch_proposals(
+) -> Vec<ProposalDetails<u64, u64, u64, u64, u64, u64, u64, u64, u64, H256, u64>> {
+    vec![
+        ProposalDetails::SetWorkingGroupMintCapacity(100, WorkingGroup::Storage),
+        ProposalDetails::TerminateWorkingGroupLeaderRole(TerminateRoleParameters {
+            worker_id: 10,
+            rationale: Vec::new(),
+            slash: false,
+            working_group: WorkingGroup::Storage,
+        }),
+    ]
+}
+
+#[test]
+fn create_batch_proposal_common_checks_succeed() {
+    initial_test_ext().execute_with(|| {
+        increase_total_balance_issuance(500000);
+
+        let proposal_fixture = ProposalTestFixture {
+            insufficient_rights_call: || {
+                ProposalCodex::create_batch_proposal(
+                    RawOrigin::None.into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    None,
+                    None,
+                    get_valid_batch_proposals(),
+                )
+            },
+            empty_stake_call: || {
+                ProposalCodex::create_batch_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    None,
+                    None,
+                    get_valid_batch_proposals(),
+                )
+            },
+            invalid_stake_call: || {
+                ProposalCodex::create_batch_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    Some(<BalanceOf<Test>>::from(50000u32)),
+                    None,
+                    get_valid_batch_proposals(),
+                )
+            },
+            successful_call: || {
+                ProposalCodex::create_batch_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    Some(<BalanceOf<Test>>::from(100_000_u32)),
+                    None,
+                    get_valid_batch_proposals(),
+                )
+            },
+            proposal_parameters: crate::proposal_types::parameters::batch_proposal::<Test>(
+                &get_valid_batch_proposals(),
+            ),
+            proposal_details: ProposalDetails::Batch(get_valid_batch_proposals()),
+        };
+        proposal_fixture.check_all();
+    });
+}
+
+#[test]
+fn batch_proposal_parameters_are_the_strictest_of_batched_proposals() {
+    initial_test_ext().execute_with(|| {
+        let mut config = ProposalsConfigParameters::default();
+        config.set_working_group_mint_capacity_proposal_voting_period = 100;
+        config.set_working_group_mint_capacity_proposal_grace_period = 20;
+        config.terminate_working_group_leader_role_proposal_voting_period = 50;
+        config.terminate_working_group_leader_role_proposal_grace_period = 10;
+        ProposalCodex::set_config_values(config);
+
+        let parameters =
+            crate::proposal_types::parameters::batch_proposal::<Test>(&get_valid_batch_proposals());
+
+        assert_eq!(
+            parameters,
+            ProposalParameters {
+                voting_period: 100,
+                grace_period: 20,
+                approval_quorum_percentage: 66,
+                approval_threshold_percentage: 80,
+                slashing_quorum_percentage: 60,
+                slashing_threshold_percentage: 80,
+                required_stake: Some(100_000),
+            }
+        );
+    });
+}
+
+#[test]
+fn create_batch_proposal_fails_with_invalid_parameters() {
+    initial_test_ext().execute_with(|| {
+        increase_total_balance_issuance_using_account_id(1, 500000);
+
+        let create_batch_proposal = |proposals| {
+            ProposalCodex::create_batch_proposal(
+                RawOrigin::Signed(1).into(),
+                1,
+                b"title".to_vec(),
+                b"body".to_vec(),
+                Some(<BalanceOf<Test>>::from(100_000_u32)),
+                None,
+                proposals,
+            )
+        };
+
+        assert_eq!(
+            create_batch_proposal(Vec::new()),
+            Err(Error::<Test>::BatchProposalIsEmpty.into())
+        );
+
+        assert_eq!(
+            create_batch_proposal(vec![
+                ProposalDetails::Text(b"text".to_vec());
+                (crate::MAX_BATCH_PROPOSAL_SIZE + 1) as usize
+            ]),
+            Err(Error::<Test>::BatchProposalSizeExceeded.into())
+        );
+
+        assert_eq!(
+            create_batch_proposal(vec![ProposalDetails::Batch(get_valid_batch_proposals())]),
+            Err(Error::<Test>::InvalidBatchProposalItem.into())
+        );
+
+        assert_eq!(
+            create_batch_proposal(vec![ProposalDetails::EvictStorageProvider(1)]),
+            Err(Error::<Test>::InvalidBatchProposalItem.into())
+        );
+
+        assert_eq!(
+            create_batch_proposal(vec![ProposalDetails::RuntimeUpgrade(b"wasm".to_vec())]),
+            Err(Error::<Test>::InvalidBatchProposalItem.into())
+        );
+
+        assert_eq!(
+            create_batch_proposal(vec![
+                ProposalDetails::Text(b"text".to_vec()),
+                ProposalDetails::Spending(0, 2),
+            ]),
+            Err(Error::<Test>::InvalidSpendingProposalBalance.into())
+        );
+    });
+}
+
+#[test]
+fn execute_batch_proposal_dispatches_proposal_codes() {
+    initial_test_ext().execute_with(|| {
+        let text_proposal_code =
+            crate::Call::<Test>::execute_text_proposal(b"text".to_vec()).encode();
+
+        assert_eq!(
+            ProposalCodex::execute_batch_proposal(
+                RawOrigin::Signed(1).into(),
+                vec![text_proposal_code.clone()]
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+
+        assert_eq!(
+            ProposalCodex::execute_batch_proposal(
+                RawOrigin::Root.into(),
+                vec![text_proposal_code.clone(), Vec::new()]
+            ),
+            Err(Error::<Test>::InvalidBatchProposalCode.into())
+        );
+
+        assert_eq!(
+            ProposalCodex::execute_batch_proposal(
+                RawOrigin::Root.into(),
+                vec![text_proposal_code.clone(), text_proposal_code]
+            ),
+            Ok(())
+        );
+    });
+}
+
+#[test]
+fn proposals_query_returns_filtered_proposals_with_details() {
+    initial_test_ext().execute_with(|| {
+        increase_total_balance_issuance_using_account_id(1, 500000);
+
+        for text in [b"text1", b"text2"].iter() {
+            assert_eq!(
+                ProposalCodex::create_text_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    Some(<BalanceOf<Test>>::from(25000u32)),
+                    None,
+                    text.to_vec(),
+                ),
+                Ok(())
+            );
+        }
+
+        assert_eq!(
+            ProposalCodex::create_set_validator_count_proposal(
+                RawOrigin::Signed(1).into(),
+                1,
+                b"title".to_vec(),
+                b"body".to_vec(),
+                Some(<BalanceOf<Test>>::from(100_000_u32)),
+                None,
+                10,
+            ),
+            Ok(())
+        );
+
+        let all_proposals = ProposalCodex::proposals(ProposalsFilter::default(), None, 10);
+        let proposal_ids = all_proposals
+            .iter()
+            .map(|info| info.proposal_id)
+            .collect::<Vec<_>>();
+        assert_eq!(proposal_ids, vec![3, 2, 1]);
+
+        let text_proposal_filter = ProposalsFilter {
+            details_variant: Some(
+                ProposalDetails::<u64, u64, u64, u64, u64, u64, u64, u64, u64>::Text(Vec::new())
+                    .variant_index(),
+            ),
+            ..ProposalsFilter::default()
+        };
+        let text_proposals = ProposalCodex::proposals(text_proposal_filter.clone(), None, 10);
+        assert_eq!(text_proposals.len(), 2);
+        assert_eq!(
+            text_proposals[0].details,
+            ProposalDetails::Text(b"text2".to_vec())
+        );
+        assert_eq!(
+            text_proposals[0].remaining_voting_period,
+            Some(text_proposals[0].proposal.parameters.voting_period)
+        );
+        assert_eq!(text_proposals[0].remaining_grace_period, None);
+
+        let paged_proposals = ProposalCodex::proposals(text_proposal_filter, Some(2), 1);
+        assert_eq!(paged_proposals.len(), 1);
+        assert_eq!(paged_proposals[0].proposal_id, 2);
+
+        let finalized_proposals_filter = ProposalsFilter {
+            status: Some(ProposalStatusFilter::Finalized),
+            ..ProposalsFilter::default()
+        };
+        assert!(ProposalCodex::proposals(finalized_proposals_filter, None, 10).is_empty());
+
+        let other_proposer_filter = ProposalsFilter {
+            proposer_id: Some(2),
+            ..ProposalsFilter::default()
+        };
+        assert!(ProposalCodex::proposals(other_proposer_filter, None, 10).is_empty());
+
+        assert!(ProposalCodex::proposal_info(4).is_none());
+    });
+}
@@ -0,0 +1,97 @@
+//! Proposals discussion module benchmarking.
+
+use super::*;
+use codec::Encode;
+use frame_benchmarking::{account, benchmarks};
+use sp_std::vec;
+use system::RawOrigin;
+
+const SEED: u32 = 0;
+
+fn text(len: u32) -> Vec<u8> {
+    vec![b'x'; len as usize]
+}
+
+// Creates a member with the same root and controller account, valid as a post author.
+fn member<T: Trait>() -> (T::AccountId, MemberId<T>) {
+    let account_id: T::AccountId = account("member", 0, SEED);
+    let member_id = <membership::Module<T>>::members_created();
+
+    <membership::Module<T>>::set_screening_authority(RawOrigin::Root.into(), account_id.clone())
+        .unwrap();
+
+    <membership::Module<T>>::add_screened_member(
+        RawOrigin::Signed(account_id.clone()).into(),
+        account_id.clone(),
+        Some(account_id.encode()),
+        None,
+        None,
+    )
+    .unwrap();
+
+    T::PostAuthorOriginValidator::prepare_actor_origin(member_id, account_id.clone());
+
+    (account_id, member_id)
+}
+
+fn create_thread<T: Trait>(author_id: MemberId<T>) -> T::ThreadId {
+    Module::<T>::create_thread(author_id, text(T::ThreadTitleLengthLimit::get())).unwrap()
+}
+
+benchmarks! {
+    _ { }
+
+    add_post {
+        let t in 1 .. T::PostLengthLimit::get();
+
+        let (account_id, member_id) = member::<T>();
+        let thread_id = create_thread::<T>(member_id);
+        let post_id = T::PostId::from(Module::<T>::post_count() + 1);
+    }: _(RawOrigin::Signed(account_id), member_id, thread_id, text(t))
+    verify {
+        assert!(<PostThreadIdByPostId<T>>::contains_key(thread_id, post_id));
+    }
+
+    update_post {
+        let t in 1 .. T::PostLengthLimit::get();
+
+        let (account_id, member_id) = member::<T>();
+        let thread_id = create_thread::<T>(member_id);
+        let post_id = T::PostId::from(Module::<T>::post_count() + 1);
+
+        Module::<T>::add_post(
+            RawOrigin::Signed(account_id.clone()).into(),
+            member_id,
+            thread_id,
+            text(T::PostLengthLimit::get()),
+        )?;
+    }: {
+        let result = Module::<T>::update_post(
+            RawOrigin::Signed(account_id).into(),
+            member_id,
+            thread_id,
+            post_id,
+            text(t),
+        );
+
+        // The runtime can disable the post editing with the zero edition limit. The rejected call
+        // is the benchmarked path in that case.
+        if T::MaxPostEditionNumber::get() > 0 {
+            result?;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::mock::{initial_test_ext, Test};
+
+    #[test]
+    fn proposals_discussion_benchmarks_succeed() {
+        initial_test_ext().execute_with(|| {
+            assert!(test_benchmark_add_post::<Test>().is_ok());
+            assert!(test_benchmark_update_post::<Test>().is_ok());
+        });
+    }
+}
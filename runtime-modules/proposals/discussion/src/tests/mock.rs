@@ -77,6 +77,7 @@ impl membership::Trait for Test {
     type PaidTermId = u64;
     type SubscriptionId = u64;
     type ActorId = u64;
+    type WeightInfo = ();
 }
 
 impl crate::Trait for Test {
@@ -88,10 +89,13 @@ impl crate::Trait for Test {
     type ThreadTitleLengthLimit = ThreadTitleLengthLimit;
     type PostLengthLimit = PostLengthLimit;
     type MaxThreadInARowNumber = MaxThreadInARowNumber;
+    type WeightInfo = ();
 }
 
 impl ActorOriginValidator<Origin, u64, u64> for () {
     fn ensure_actor_origin(origin: Origin, actor_id: u64) -> Result<u64, &'static str> {
+        let (origin, signed_origin) = common::origin::double_origin::<Test>(origin);
+
         if system::ensure_none(origin).is_ok() {
             return Ok(1);
         }
@@ -100,6 +104,15 @@ impl ActorOriginValidator<Origin, u64, u64> for () {
             return Ok(1);
         }
 
+        // Registered members are valid authors (used by the benchmarks).
+        if let Ok(account_id) = system::ensure_signed(signed_origin) {
+            if membership::Module::<Test>::ensure_member_controller_account(&account_id, &actor_id)
+                .is_ok()
+            {
+                return Ok(account_id);
+            }
+        }
+
         Err("Invalid author")
     }
 }
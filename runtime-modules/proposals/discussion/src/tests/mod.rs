@@ -1,4 +1,4 @@
-mod mock;
+pub(crate) mod mock;
 
 use frame_support::dispatch::{DispatchError, DispatchResult};
 use system::RawOrigin;
@@ -0,0 +1,107 @@
+//! Proposals engine module benchmarking.
+
+use super::*;
+use codec::Encode;
+use frame_benchmarking::{account, benchmarks};
+use sp_std::vec;
+
+const SEED: u32 = 0;
+
+// Stake of the benchmarked proposals. Covers the cancellation and rejection fees.
+const STAKE: u32 = 100_000;
+
+// Creates a member with the same root and controller account.
+fn member<T: Trait>(index: u32) -> (T::AccountId, MemberId<T>) {
+    let account_id: T::AccountId = account("member", index, SEED);
+    let member_id = <membership::Module<T>>::members_created();
+
+    <membership::Module<T>>::set_screening_authority(RawOrigin::Root.into(), account_id.clone())
+        .unwrap();
+
+    <membership::Module<T>>::add_screened_member(
+        RawOrigin::Signed(account_id.clone()).into(),
+        account_id.clone(),
+        Some(account_id.encode()),
+        None,
+        None,
+    )
+    .unwrap();
+
+    (account_id, member_id)
+}
+
+// Creates the staked proposal with the longest title and description.
+fn create_proposal<T: Trait>() -> (T::AccountId, MemberId<T>, T::ProposalId) {
+    let (account_id, member_id) = member::<T>(0);
+    T::ProposerOriginValidator::prepare_actor_origin(member_id, account_id.clone());
+
+    let stake = BalanceOf::<T>::from(STAKE);
+    CurrencyOf::<T>::make_free_balance_be(&account_id, stake + stake);
+
+    let parameters = ProposalParameters {
+        voting_period: T::BlockNumber::from(1_000u32),
+        grace_period: T::BlockNumber::from(1_000u32),
+        approval_quorum_percentage: 50,
+        approval_threshold_percentage: 50,
+        slashing_quorum_percentage: 50,
+        slashing_threshold_percentage: 50,
+        required_stake: Some(stake),
+    };
+
+    let proposal_id = Module::<T>::create_proposal(
+        account_id.clone(),
+        member_id,
+        parameters,
+        vec![b't'; T::TitleMaxLength::get() as usize],
+        vec![b'd'; T::DescriptionMaxLength::get() as usize],
+        Some(stake),
+        None,
+        Vec::new(),
+    )
+    .unwrap();
+
+    (account_id, member_id, proposal_id)
+}
+
+benchmarks! {
+    _ { }
+
+    vote {
+        let (_, _, proposal_id) = create_proposal::<T>();
+
+        let (voter_account, voter_id) = member::<T>(1);
+        T::VoterOriginValidator::prepare_actor_origin(voter_id, voter_account.clone());
+    }: _(RawOrigin::Signed(voter_account), voter_id, proposal_id, VoteKind::Approve)
+    verify {
+        assert!(<VoteExistsByProposalByVoter<T>>::contains_key(proposal_id, voter_id));
+    }
+
+    cancel_proposal {
+        let (account_id, member_id, proposal_id) = create_proposal::<T>();
+    }: _(RawOrigin::Signed(account_id), member_id, proposal_id)
+    verify {
+        assert!(!<ActiveProposalIds<T>>::contains_key(proposal_id));
+    }
+
+    veto_proposal {
+        let (_, _, proposal_id) = create_proposal::<T>();
+    }: _(RawOrigin::Root, proposal_id)
+    verify {
+        assert!(!<ActiveProposalIds<T>>::contains_key(proposal_id));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::mock::{initial_test_ext, Test};
+
+    #[test]
+    fn proposals_engine_benchmarks_succeed() {
+        initial_test_ext().execute_with(|| {
+            assert!(test_benchmark_vote::<Test>().is_ok());
+            assert!(test_benchmark_cancel_proposal::<Test>().is_ok());
+            assert!(test_benchmark_veto_proposal::<Test>().is_ok());
+        });
+    }
+}
@@ -16,6 +16,9 @@
 //! _voting period_. Votes can be [different](./enum.VoteKind.html). When the proposal gets enough votes
 //! to be slashed or approved or _voting period_ ends - the proposal becomes _Finalized_. If the proposal
 //! got approved and _grace period_ passed - the  `engine` module tries to execute the proposal.
+//! The proposal created with the _exact execution block_ is executed at this block instead of after
+//! its _grace period_ and expires if it is not approved by then.
+//! The execution is transactional: storage changes of the failed proposal execution are reverted.
 //! The final [approved status](./enum.ApprovedProposalStatus.html) of the proposal defines
 //! an overall proposal outcome.
 //!
@@ -53,6 +56,7 @@
 //! - [ensure_create_proposal_parameters_are_valid](./struct.Module.html#method.ensure_create_proposal_parameters_are_valid) - ensures that we can create the proposal
 //! - [refund_proposal_stake](./struct.Module.html#method.refund_proposal_stake) - a callback for _StakingHandlerEvents_
 //! - [reset_active_proposals](./trait.Module.html#method.reset_active_proposals) - resets voting results for active proposals
+//! - [proposal_votes](./struct.Module.html#method.proposal_votes) - returns voter ids with their votes for the proposal
 //!
 //! ## Usage
 //!
@@ -87,6 +91,7 @@
 //!                 &parameters,
 //!                 &title,
 //!                 &description,
+//!                 None,
 //!                 None
 //!             )?;
 //!             <engine::Module<T>>::create_proposal(
@@ -96,6 +101,7 @@
 //!                 title,
 //!                 description,
 //!                 None,
+//!                 None,
 //!                 encoded_proposal_code
 //!             )?;
 //!         }
@@ -124,13 +130,18 @@ pub use types::{VoteKind, VotersParameters};
 
 pub(crate) mod types;
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
 #[cfg(test)]
 mod tests;
 
 use codec::Decode;
 use frame_support::dispatch::{DispatchError, DispatchResult, UnfilteredDispatchable};
-use frame_support::storage::IterableStorageMap;
+use frame_support::storage::{
+    with_transaction, IterableStorageDoubleMap, IterableStorageMap, TransactionOutcome,
+};
 use frame_support::traits::{Currency, Get};
+use frame_support::weights::Weight;
 use frame_support::{
     decl_error, decl_event, decl_module, decl_storage, ensure, print, Parameter, StorageDoubleMap,
 };
@@ -142,6 +153,26 @@ use common::origin::ActorOriginValidator;
 
 type MemberId<T> = <T as membership::Trait>::MemberId;
 
+/// Weight information for the proposals engine extrinsics.
+pub trait WeightInfo {
+    fn vote() -> Weight;
+    fn cancel_proposal() -> Weight;
+    fn veto_proposal() -> Weight;
+}
+
+/// Default weights, used by the tests.
+impl WeightInfo for () {
+    fn vote() -> Weight {
+        10_000_000
+    }
+    fn cancel_proposal() -> Weight {
+        10_000_000
+    }
+    fn veto_proposal() -> Weight {
+        10_000_000
+    }
+}
+
 /// Proposals engine trait.
 pub trait Trait:
     system::Trait + pallet_timestamp::Trait + stake::Trait + membership::Trait
@@ -183,8 +214,14 @@ pub trait Trait:
     /// Defines max simultaneous active proposals number.
     type MaxActiveProposalLimit: Get<u32>;
 
+    /// Defines max allowed delay of the exact execution block after the end of the voting period.
+    type MaxExactExecutionDelay: Get<Self::BlockNumber>;
+
     /// Proposals executable code. Can be instantiated by external module Call enum members.
     type DispatchableCallCode: Parameter + UnfilteredDispatchable<Origin = Self::Origin> + Default;
+
+    /// Weight information for the extrinsics of this module.
+    type WeightInfo: WeightInfo;
 }
 
 decl_event!(
@@ -265,6 +302,12 @@ decl_error! {
 
         /// Require root origin in extrinsics
         RequireRootOrigin,
+
+        /// Exact execution block should be after the end of the voting period
+        InvalidExactExecutionBlock,
+
+        /// Exact execution block is too far after the end of the voting period
+        ExactExecutionBlockTooFar,
     }
 }
 
@@ -300,6 +343,16 @@ decl_storage! {
         /// Map proposal id by stake id. Required by StakingEventsHandler callback call
         pub StakesProposals get(fn stakes_proposals): map hasher(blake2_128_concat)
             T::StakeId =>  T::ProposalId;
+
+        /// Whether the proposals created by the previous runtime versions got the exact
+        /// execution block.
+        pub ExactExecutionBlockMigrated get(fn exact_execution_block_migrated): bool;
+    }
+    add_extra_genesis {
+        build(|_: &GenesisConfig| {
+            // Nothing to migrate on the new chain.
+            ExactExecutionBlockMigrated::put(true);
+        });
     }
 }
 
@@ -327,8 +380,12 @@ decl_module! {
         /// Exports const -  max simultaneous active proposals number.
         const MaxActiveProposalLimit: u32 = T::MaxActiveProposalLimit::get();
 
+        /// Exports const -  max allowed delay of the exact execution block after the end of the
+        /// voting period.
+        const MaxExactExecutionDelay: T::BlockNumber = T::MaxExactExecutionDelay::get();
+
         /// Vote extrinsic. Conditions:  origin must allow votes.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::vote()]
         pub fn vote(origin, voter_id: MemberId<T>, proposal_id: T::ProposalId, vote: VoteKind)  {
             T::VoterOriginValidator::ensure_actor_origin(
                 origin,
@@ -357,7 +414,7 @@ decl_module! {
         }
 
         /// Cancel a proposal by its original proposer.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::cancel_proposal()]
         pub fn cancel_proposal(origin, proposer_id: MemberId<T>, proposal_id: T::ProposalId) {
             T::ProposerOriginValidator::ensure_actor_origin(
                 origin,
@@ -376,7 +433,7 @@ decl_module! {
         }
 
         /// Veto a proposal. Must be root.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::veto_proposal()]
         pub fn veto_proposal(origin, proposal_id: T::ProposalId) {
             ensure_root(origin)?;
 
@@ -427,6 +484,7 @@ impl<T: Trait> Module<T> {
         title: Vec<u8>,
         description: Vec<u8>,
         stake_balance: Option<types::BalanceOf<T>>,
+        exactly_execute_at: Option<T::BlockNumber>,
         encoded_dispatchable_call_code: Vec<u8>,
     ) -> Result<T::ProposalId, DispatchError> {
         Self::ensure_create_proposal_parameters_are_valid(
@@ -434,6 +492,7 @@ impl<T: Trait> Module<T> {
             &title,
             &description,
             stake_balance,
+            exactly_execute_at,
         )?;
 
         // checks passed
@@ -469,6 +528,7 @@ impl<T: Trait> Module<T> {
             proposer_id,
             status: ProposalStatus::Active(stake_data),
             voting_results: VotingResults::default(),
+            exactly_execute_at,
         };
 
         <Proposals<T>>::insert(proposal_id, new_proposal);
@@ -487,11 +547,13 @@ impl<T: Trait> Module<T> {
     /// - max active proposal
     /// - provided parameters: approval_threshold_percentage and slashing_threshold_percentage > 0
     /// - provided stake balance and parameters.required_stake are valid
+    /// - provided exact execution block is after the end of the voting period and not too far
     pub fn ensure_create_proposal_parameters_are_valid(
         parameters: &ProposalParameters<T::BlockNumber, types::BalanceOf<T>>,
         title: &[u8],
         description: &[u8],
         stake_balance: Option<types::BalanceOf<T>>,
+        exactly_execute_at: Option<T::BlockNumber>,
     ) -> DispatchResult {
         ensure!(!title.is_empty(), Error::<T>::EmptyTitleProvided);
         ensure!(
@@ -539,6 +601,21 @@ impl<T: Trait> Module<T> {
             return Err(Error::<T>::StakeShouldBeEmpty.into());
         }
 
+        // the proposal can be approved until the end of its voting period
+        if let Some(execution_block) = exactly_execute_at {
+            let voting_period_end = Self::current_block() + parameters.voting_period;
+
+            ensure!(
+                execution_block > voting_period_end,
+                Error::<T>::InvalidExactExecutionBlock
+            );
+
+            ensure!(
+                execution_block <= voting_period_end + T::MaxExactExecutionDelay::get(),
+                Error::<T>::ExactExecutionBlockTooFar
+            );
+        }
+
         Ok(())
     }
 
@@ -574,6 +651,39 @@ impl<T: Trait> Module<T> {
         }
     }
 
+    /// Returns votes for the proposal: voter member ids with their vote kinds.
+    pub fn proposal_votes(proposal_id: T::ProposalId) -> Vec<(MemberId<T>, VoteKind)> {
+        <VoteExistsByProposalByVoter<T>>::iter_prefix(proposal_id).collect()
+    }
+
+    /// Sets no exact execution block for the proposals created by the previous runtime versions,
+    /// which stored the proposals without it. Does nothing once migrated.
+    pub fn migrate_exact_execution_block() -> Weight {
+        if Self::exact_execution_block_migrated() {
+            return T::DbWeight::get().reads(1);
+        }
+
+        // Proposals are never removed.
+        let proposals_count = Weight::from(Self::proposal_count());
+
+        <Proposals<T>>::translate(|_, proposal: LegacyProposalOf<T>| {
+            Some(Proposal {
+                parameters: proposal.parameters,
+                proposer_id: proposal.proposer_id,
+                title: proposal.title,
+                description: proposal.description,
+                created_at: proposal.created_at,
+                status: proposal.status,
+                voting_results: proposal.voting_results,
+                exactly_execute_at: None,
+            })
+        });
+
+        ExactExecutionBlockMigrated::put(true);
+
+        T::DbWeight::get().reads_writes(1 + proposals_count, 1 + proposals_count)
+    }
+
     /// Resets voting results for active proposals.
     /// Possible application includes new council elections.
     pub fn reset_active_proposals() {
@@ -641,7 +751,7 @@ impl<T: Trait> Module<T> {
         );
     }
 
-    // Executes approved proposal code
+    // Executes approved proposal code. Storage changes of the failed execution are reverted.
     fn execute_proposal(approved_proposal: ApprovedProposal<T>) {
         let proposal_code = Self::proposal_codes(approved_proposal.proposal_id);
 
@@ -649,9 +759,18 @@ impl<T: Trait> Module<T> {
 
         let approved_proposal_status = match proposal_code_result {
             Ok(proposal_code) => {
-                if let Err(dispatch_error) =
-                    proposal_code.dispatch_bypass_filter(T::Origin::from(RawOrigin::Root))
-                {
+                let execution_result = with_transaction(|| {
+                    let result =
+                        proposal_code.dispatch_bypass_filter(T::Origin::from(RawOrigin::Root));
+
+                    if result.is_ok() {
+                        TransactionOutcome::Commit(result)
+                    } else {
+                        TransactionOutcome::Rollback(result)
+                    }
+                });
+
+                if let Err(dispatch_error) = execution_result {
                     ApprovedProposalStatus::failed_execution(Self::parse_dispatch_error(
                         dispatch_error.error,
                     ))
@@ -836,6 +955,15 @@ type ApprovedProposal<T> = ApprovedProposalData<
     <T as system::Trait>::AccountId,
 >;
 
+// Simplification of the 'LegacyProposal' type
+type LegacyProposalOf<T> = types::LegacyProposal<
+    <T as system::Trait>::BlockNumber,
+    MemberId<T>,
+    types::BalanceOf<T>,
+    <T as stake::Trait>::StakeId,
+    <T as system::Trait>::AccountId,
+>;
+
 // Simplification of the 'Proposal' type
 type ProposalOf<T> = Proposal<
     <T as system::Trait>::BlockNumber,
@@ -82,6 +82,7 @@ parameter_types! {
     pub const TitleMaxLength: u32 = 100;
     pub const DescriptionMaxLength: u32 = 10000;
     pub const MaxActiveProposalLimit: u32 = 100;
+    pub const MaxExactExecutionDelay: u64 = 1000;
 }
 
 impl membership::Trait for Test {
@@ -90,6 +91,7 @@ impl membership::Trait for Test {
     type PaidTermId = u64;
     type SubscriptionId = u64;
     type ActorId = u64;
+    type WeightInfo = ();
 }
 
 impl crate::Trait for Test {
@@ -104,7 +106,9 @@ impl crate::Trait for Test {
     type TitleMaxLength = TitleMaxLength;
     type DescriptionMaxLength = DescriptionMaxLength;
     type MaxActiveProposalLimit = MaxActiveProposalLimit;
+    type MaxExactExecutionDelay = MaxExactExecutionDelay;
     type DispatchableCallCode = proposals::Call<Test>;
+    type WeightInfo = ();
 }
 
 impl Default for proposals::Call<Test> {
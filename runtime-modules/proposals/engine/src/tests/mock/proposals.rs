@@ -1,9 +1,16 @@
 //! Contains executable proposal extrinsic mocks
 
-use frame_support::decl_module;
+use frame_support::{decl_module, decl_storage};
 use sp_std::vec::Vec;
 pub trait Trait: system::Trait {}
 
+decl_storage! {
+    trait Store for Module<T: Trait> as ProposalsMock {
+        /// Counter of the proposal extrinsic calls that changed the storage
+        pub StorageChangesCount get(fn storage_changes_count): u32;
+    }
+}
+
 decl_module! {
     pub struct Module<T: Trait> for enum Call where origin: T::Origin {
         /// Working extrinsic test
@@ -15,5 +22,13 @@ decl_module! {
         pub fn faulty_proposal(_origin, _title: Vec<u8>, _description: Vec<u8>,) {
              Err("ExecutionFailed")?
         }
+
+        /// Broken extrinsic test, changing the storage before the failure
+        #[weight = 10_000_000]
+        pub fn storage_changing_faulty_proposal(_origin) {
+            StorageChangesCount::mutate(|count| *count += 1);
+
+            Err("ExecutionFailed")?
+        }
     }
 }
@@ -71,6 +71,7 @@ struct DummyProposalFixture {
     title: Vec<u8>,
     description: Vec<u8>,
     stake_balance: Option<BalanceOf<Test>>,
+    exactly_execute_at: Option<u64>,
 }
 
 impl Default for DummyProposalFixture {
@@ -96,6 +97,7 @@ impl Default for DummyProposalFixture {
             title,
             description,
             stake_balance: None,
+            exactly_execute_at: None,
         }
     }
 }
@@ -124,6 +126,13 @@ impl DummyProposalFixture {
         }
     }
 
+    fn with_exactly_execute_at(self, exactly_execute_at: u64) -> Self {
+        DummyProposalFixture {
+            exactly_execute_at: Some(exactly_execute_at),
+            ..self
+        }
+    }
+
     fn with_proposal_code(self, proposal_code: Vec<u8>) -> Self {
         DummyProposalFixture {
             proposal_code,
@@ -139,6 +148,7 @@ impl DummyProposalFixture {
             self.title,
             self.description,
             self.stake_balance,
+            self.exactly_execute_at,
             self.proposal_code,
         );
         assert_eq!(proposal_id_result, result);
@@ -347,6 +357,7 @@ fn proposal_execution_succeeds() {
                     rejections: 0,
                     slashes: 0,
                 },
+                exactly_execute_at: None,
             }
         );
 
@@ -399,11 +410,43 @@ fn proposal_execution_failed() {
                     rejections: 0,
                     slashes: 0,
                 },
+                exactly_execute_at: None,
             }
         )
     });
 }
 
+#[test]
+fn failed_proposal_execution_reverts_storage_changes() {
+    initial_test_ext().execute_with(|| {
+        let faulty_proposal = mock::proposals::Call::<Test>::storage_changing_faulty_proposal();
+
+        let dummy_proposal =
+            DummyProposalFixture::default().with_proposal_code(faulty_proposal.encode());
+
+        let proposal_id = dummy_proposal.create_proposal_and_assert(Ok(1)).unwrap();
+
+        let mut vote_generator = VoteGenerator::new(proposal_id);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+
+        run_to_block_and_finalize(2);
+
+        let proposal = <crate::Proposals<Test>>::get(proposal_id);
+
+        assert_eq!(
+            proposal.status,
+            ProposalStatus::approved(
+                ApprovedProposalStatus::failed_execution("ExecutionFailed"),
+                0
+            )
+        );
+        assert_eq!(mock::proposals::Module::<Test>::storage_changes_count(), 0);
+    });
+}
+
 #[test]
 fn voting_results_calculation_succeeds() {
     initial_test_ext().execute_with(|| {
@@ -602,6 +645,7 @@ fn cancel_proposal_succeeds() {
                 title: b"title".to_vec(),
                 description: b"description".to_vec(),
                 voting_results: VotingResults::default(),
+                exactly_execute_at: None,
             }
         )
     });
@@ -670,6 +714,7 @@ fn veto_proposal_succeeds() {
                 title: b"title".to_vec(),
                 description: b"description".to_vec(),
                 voting_results: VotingResults::default(),
+                exactly_execute_at: None,
             }
         );
 
@@ -813,6 +858,7 @@ fn create_proposal_and_expire_it() {
                 title: b"title".to_vec(),
                 description: b"description".to_vec(),
                 voting_results: VotingResults::default(),
+                exactly_execute_at: None,
             }
         )
     });
@@ -858,6 +904,7 @@ fn proposal_execution_postponed_because_of_grace_period() {
                     rejections: 0,
                     slashes: 0,
                 },
+                exactly_execute_at: None,
             }
         );
     });
@@ -903,6 +950,7 @@ fn proposal_execution_vetoed_successfully_during_the_grace_period() {
                     rejections: 0,
                     slashes: 0,
                 },
+                exactly_execute_at: None,
             }
         );
 
@@ -926,6 +974,7 @@ fn proposal_execution_vetoed_successfully_during_the_grace_period() {
                     rejections: 0,
                     slashes: 0,
                 },
+                exactly_execute_at: None,
             }
         );
 
@@ -972,6 +1021,7 @@ fn proposal_execution_succeeds_after_the_grace_period() {
                 rejections: 0,
                 slashes: 0,
             },
+            exactly_execute_at: None,
         };
 
         assert_eq!(proposal, expected_proposal);
@@ -991,6 +1041,183 @@ fn proposal_execution_succeeds_after_the_grace_period() {
     });
 }
 
+#[test]
+fn create_proposal_fails_with_invalid_exact_execution_block() {
+    initial_test_ext().execute_with(|| {
+        let parameters_fixture = ProposalParametersFixture::default();
+
+        let dummy_proposal = DummyProposalFixture::default()
+            .with_parameters(parameters_fixture.params())
+            .with_exactly_execute_at(3);
+
+        dummy_proposal
+            .create_proposal_and_assert(Err(Error::<Test>::InvalidExactExecutionBlock.into()));
+
+        let dummy_proposal = DummyProposalFixture::default()
+            .with_parameters(parameters_fixture.params())
+            .with_exactly_execute_at(4);
+
+        dummy_proposal.create_proposal_and_assert(Ok(1));
+    });
+}
+
+#[test]
+fn create_proposal_fails_with_too_far_exact_execution_block() {
+    initial_test_ext().execute_with(|| {
+        let parameters_fixture = ProposalParametersFixture::default();
+        let voting_period_end = parameters_fixture.params().voting_period;
+        let max_execution_block = voting_period_end + MaxExactExecutionDelay::get();
+
+        let dummy_proposal = DummyProposalFixture::default()
+            .with_parameters(parameters_fixture.params())
+            .with_exactly_execute_at(max_execution_block + 1);
+
+        dummy_proposal
+            .create_proposal_and_assert(Err(Error::<Test>::ExactExecutionBlockTooFar.into()));
+
+        let dummy_proposal = DummyProposalFixture::default()
+            .with_parameters(parameters_fixture.params())
+            .with_exactly_execute_at(max_execution_block);
+
+        dummy_proposal.create_proposal_and_assert(Ok(1));
+    });
+}
+
+#[test]
+fn proposal_execution_succeeds_at_the_exact_execution_block() {
+    initial_test_ext().execute_with(|| {
+        let parameters_fixture = ProposalParametersFixture::default();
+        let dummy_proposal = DummyProposalFixture::default()
+            .with_parameters(parameters_fixture.params())
+            .with_exactly_execute_at(6);
+        let proposal_id = dummy_proposal.create_proposal_and_assert(Ok(1)).unwrap();
+
+        let mut vote_generator = VoteGenerator::new(proposal_id);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+
+        // zero grace period doesn't apply to the proposal with the exact execution block
+        run_to_block_and_finalize(5);
+
+        assert!(<PendingExecutionProposalIds<Test>>::contains_key(
+            proposal_id
+        ));
+
+        let mut proposal = <crate::Proposals<Test>>::get(proposal_id);
+
+        let mut expected_proposal = Proposal {
+            parameters: parameters_fixture.params(),
+            proposer_id: 1,
+            created_at: 0,
+            status: ProposalStatus::approved(ApprovedProposalStatus::PendingExecution, 0),
+            title: b"title".to_vec(),
+            description: b"description".to_vec(),
+            voting_results: VotingResults {
+                abstentions: 0,
+                approvals: 4,
+                rejections: 0,
+                slashes: 0,
+            },
+            exactly_execute_at: Some(6),
+        };
+
+        assert_eq!(proposal, expected_proposal);
+        assert_eq!(proposal.remaining_grace_period(5), Some(1));
+
+        run_to_block_and_finalize(6);
+
+        proposal = <crate::Proposals<Test>>::get(proposal_id);
+
+        expected_proposal.status = ProposalStatus::approved(ApprovedProposalStatus::Executed, 0);
+
+        assert_eq!(proposal, expected_proposal);
+
+        assert!(!<PendingExecutionProposalIds<Test>>::contains_key(
+            proposal_id
+        ));
+    });
+}
+
+#[test]
+fn proposal_with_exact_execution_block_expires_without_approval() {
+    initial_test_ext().execute_with(|| {
+        let parameters_fixture = ProposalParametersFixture::default();
+        let dummy_proposal = DummyProposalFixture::default()
+            .with_parameters(parameters_fixture.params())
+            .with_exactly_execute_at(6);
+        let proposal_id = dummy_proposal.create_proposal_and_assert(Ok(1)).unwrap();
+
+        run_to_block_and_finalize(8);
+
+        let proposal = <crate::Proposals<Test>>::get(proposal_id);
+
+        assert_eq!(
+            proposal.status,
+            ProposalStatus::finalized_successfully(ProposalDecisionStatus::Expired, 3)
+        );
+        assert!(!<PendingExecutionProposalIds<Test>>::contains_key(
+            proposal_id
+        ));
+    });
+}
+
+#[test]
+fn migrate_exact_execution_block_updates_proposals() {
+    initial_test_ext().execute_with(|| {
+        let parameters = ProposalParametersFixture::default().params();
+        let legacy_proposal = types::LegacyProposal::<u64, u64, u64, u64, u64> {
+            parameters: parameters.clone(),
+            proposer_id: 1,
+            title: b"title".to_vec(),
+            description: b"description".to_vec(),
+            created_at: 0,
+            status: ProposalStatus::Active(None),
+            voting_results: VotingResults::default(),
+        };
+
+        frame_support::storage::unhashed::put(
+            &<crate::Proposals<Test>>::hashed_key_for(1u32),
+            &legacy_proposal,
+        );
+        ProposalCount::put(1);
+
+        assert!(!ProposalsEngine::exact_execution_block_migrated());
+
+        ProposalsEngine::migrate_exact_execution_block();
+
+        assert!(ProposalsEngine::exact_execution_block_migrated());
+        assert_eq!(
+            <crate::Proposals<Test>>::get(1u32),
+            Proposal {
+                parameters,
+                proposer_id: 1,
+                created_at: 0,
+                status: ProposalStatus::Active(None),
+                title: b"title".to_vec(),
+                description: b"description".to_vec(),
+                voting_results: VotingResults::default(),
+                exactly_execute_at: None,
+            }
+        );
+    });
+}
+
+#[test]
+fn genesis_config_marks_exact_execution_block_migrated() {
+    let mut storage = system::GenesisConfig::default()
+        .build_storage::<Test>()
+        .unwrap();
+    crate::GenesisConfig {}
+        .assimilate_storage::<Test>(&mut storage)
+        .unwrap();
+
+    sp_io::TestExternalities::from(storage).execute_with(|| {
+        assert!(ProposalsEngine::exact_execution_block_migrated());
+    });
+}
+
 #[test]
 fn create_proposal_fails_on_exceeding_max_active_proposals_count() {
     initial_test_ext().execute_with(|| {
@@ -1073,6 +1300,7 @@ fn create_dummy_proposal_succeeds_with_stake() {
                 title: b"title".to_vec(),
                 description: b"description".to_vec(),
                 voting_results: VotingResults::default(),
+                exactly_execute_at: None,
             }
         )
     });
@@ -1170,6 +1398,7 @@ fn finalize_expired_proposal_and_check_stake_removing_with_balance_checks_succee
             title: b"title".to_vec(),
             description: b"description".to_vec(),
             voting_results: VotingResults::default(),
+            exactly_execute_at: None,
         };
 
         assert_eq!(proposal, expected_proposal);
@@ -1243,6 +1472,7 @@ fn proposal_cancellation_with_slashes_with_balance_checks_succeeds() {
             title: b"title".to_vec(),
             description: b"description".to_vec(),
             voting_results: VotingResults::default(),
+            exactly_execute_at: None,
         };
 
         assert_eq!(proposal, expected_proposal);
@@ -1411,6 +1641,7 @@ fn finalize_proposal_using_stake_mocks_failed() {
                     title: b"title".to_vec(),
                     description: b"description".to_vec(),
                     voting_results: VotingResults::default(),
+                    exactly_execute_at: None,
                 }
             );
         });
@@ -1499,6 +1730,24 @@ fn proposal_reset_succeeds() {
     });
 }
 
+#[test]
+fn proposal_votes_are_returned_with_voter_ids() {
+    initial_test_ext().execute_with(|| {
+        let dummy_proposal = DummyProposalFixture::default();
+        let proposal_id = dummy_proposal.create_proposal_and_assert(Ok(1)).unwrap();
+
+        let mut vote_generator = VoteGenerator::new(proposal_id);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Reject);
+
+        let mut votes = ProposalsEngine::proposal_votes(proposal_id);
+        votes.sort_by_key(|(voter_id, _)| *voter_id);
+
+        assert_eq!(votes, vec![(1, VoteKind::Approve), (2, VoteKind::Reject)]);
+        assert!(ProposalsEngine::proposal_votes(proposal_id + 1).is_empty());
+    });
+}
+
 #[test]
 fn proposal_counters_are_valid() {
     initial_test_ext().execute_with(|| {
@@ -8,6 +8,7 @@ use frame_support::dispatch::DispatchResult;
 use frame_support::traits::Currency;
 #[cfg(feature = "std")]
 use serde::{Deserialize, Serialize};
+use sp_arithmetic::traits::Saturating;
 use sp_runtime::Perbill;
 use sp_std::boxed::Box;
 use sp_std::cmp::PartialOrd;
@@ -149,6 +150,22 @@ pub struct Proposal<BlockNumber, ProposerId, Balance, StakeId, AccountId> {
 
     /// Curring voting result for the proposal
     pub voting_results: VotingResults,
+
+    /// Block to execute the approved proposal at instead of waiting for the grace period.
+    pub exactly_execute_at: Option<BlockNumber>,
+}
+
+/// 'Proposal' of the previous runtime versions without the exact execution block. Used by the
+/// storage migration.
+#[derive(Encode, Decode)]
+pub(crate) struct LegacyProposal<BlockNumber, ProposerId, Balance, StakeId, AccountId> {
+    pub(crate) parameters: ProposalParameters<BlockNumber, Balance>,
+    pub(crate) proposer_id: ProposerId,
+    pub(crate) title: Vec<u8>,
+    pub(crate) description: Vec<u8>,
+    pub(crate) created_at: BlockNumber,
+    pub(crate) status: ProposalStatus<BlockNumber, StakeId, AccountId>,
+    pub(crate) voting_results: VotingResults,
 }
 
 impl<BlockNumber, ProposerId, Balance, StakeId, AccountId>
@@ -163,12 +180,23 @@ where
         now >= self.created_at + self.parameters.voting_period
     }
 
+    /// Returns whether the exact execution block is set and reached by now.
+    pub fn is_exact_execution_block_reached(&self, now: BlockNumber) -> bool {
+        self.exactly_execute_at
+            .map_or(false, |execution_block| now >= execution_block)
+    }
+
     /// Returns whether grace period expired by now.
     /// Grace period can be expired only if proposal is finalized with Approved status.
+    /// The grace period of the proposal with the exact execution block lasts until this block.
     /// Returns false otherwise.
     pub fn is_grace_period_expired(&self, now: BlockNumber) -> bool {
         if let ProposalStatus::Finalized(finalized_status) = self.status.clone() {
             if let ProposalDecisionStatus::Approved(_) = finalized_status.proposal_status {
+                if self.exactly_execute_at.is_some() {
+                    return self.is_exact_execution_block_reached(now);
+                }
+
                 return now >= finalized_status.finalized_at + self.parameters.grace_period;
             }
         }
@@ -223,6 +251,40 @@ where
     }
 }
 
+impl<BlockNumber, ProposerId, Balance, StakeId, AccountId>
+    Proposal<BlockNumber, ProposerId, Balance, StakeId, AccountId>
+where
+    BlockNumber: Add<Output = BlockNumber> + Saturating + Copy,
+{
+    /// Returns the number of blocks left until the voting period expires.
+    /// Returns None if the proposal is not active.
+    pub fn remaining_voting_period(&self, now: BlockNumber) -> Option<BlockNumber> {
+        if let ProposalStatus::Active(_) = self.status {
+            Some((self.created_at + self.parameters.voting_period).saturating_sub(now))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the number of blocks left until the grace period expires.
+    /// Returns None if the proposal is not approved or was already executed.
+    pub fn remaining_grace_period(&self, now: BlockNumber) -> Option<BlockNumber> {
+        if let ProposalStatus::Finalized(ref finalized_status) = self.status {
+            if let ProposalDecisionStatus::Approved(ApprovedProposalStatus::PendingExecution) =
+                finalized_status.proposal_status
+            {
+                let execution_block = self.exactly_execute_at.unwrap_or_else(|| {
+                    finalized_status.finalized_at + self.parameters.grace_period
+                });
+
+                return Some(execution_block.saturating_sub(now));
+            }
+        }
+
+        None
+    }
+}
+
 /// Provides data for the voting.
 pub trait VotersParameters {
     /// Defines maximum voters count for the proposal
@@ -246,9 +308,11 @@ where
     StakeId: Clone,
     AccountId: Clone,
 {
-    // Proposal has been expired and quorum not reached.
+    // Proposal has been expired and quorum not reached. The proposal with the exact execution
+    // block expires when the block is reached.
     pub fn is_expired(&self) -> bool {
         self.proposal.is_voting_period_expired(self.now)
+            || self.proposal.is_exact_execution_block_reached(self.now)
     }
 
     // Approval quorum reached for the proposal. Compares predefined parameter with actual
@@ -431,6 +495,43 @@ mod tests {
         assert!(!proposal.is_grace_period_expired(3));
     }
 
+    #[test]
+    fn proposal_remaining_voting_period_is_calculated_correctly() {
+        let mut proposal = ProposalObject::default();
+
+        proposal.created_at = 1;
+        proposal.parameters.voting_period = 3;
+
+        assert_eq!(proposal.remaining_voting_period(2), Some(2));
+        assert_eq!(proposal.remaining_voting_period(5), Some(0));
+
+        proposal.status =
+            ProposalStatus::finalized_successfully(ProposalDecisionStatus::Expired, 4);
+
+        assert_eq!(proposal.remaining_voting_period(2), None);
+    }
+
+    #[test]
+    fn proposal_remaining_grace_period_is_calculated_correctly() {
+        let mut proposal = ProposalObject::default();
+
+        proposal.parameters.grace_period = 3;
+
+        assert_eq!(proposal.remaining_grace_period(1), None);
+
+        proposal.status = ProposalStatus::finalized_successfully(
+            ProposalDecisionStatus::Approved(ApprovedProposalStatus::PendingExecution),
+            2,
+        );
+
+        assert_eq!(proposal.remaining_grace_period(3), Some(2));
+        assert_eq!(proposal.remaining_grace_period(6), Some(0));
+
+        proposal.status = ProposalStatus::approved(ApprovedProposalStatus::Executed, 5);
+
+        assert_eq!(proposal.remaining_grace_period(6), None);
+    }
+
     #[test]
     fn proposal_grace_period_not_expired_because_of_not_approved_proposal() {
         let mut proposal = ProposalObject::default();
@@ -9,17 +9,27 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use codec::{Codec, Decode, Encode};
-use frame_support::storage::IterableStorageMap;
-use frame_support::{decl_module, decl_storage, ensure, Parameter};
+use frame_support::storage::{IterableStorageDoubleMap, IterableStorageMap};
+use frame_support::traits::Get;
+use frame_support::weights::Weight;
+use frame_support::{decl_event, decl_module, decl_storage, ensure, Parameter};
 use sp_arithmetic::traits::{BaseArithmetic, One, Zero};
 use sp_runtime::traits::{MaybeSerialize, Member};
+use sp_std::vec::Vec;
 
-use minting::BalanceOf;
+use minting::{BalanceOf, SpendingCategory};
 
 mod mock;
 mod tests;
 
+/// The maximum number of reward relationships whose arrears are paid on a mint top-up. The
+/// remaining arrears are paid on the next top-ups or on the payouts of their reward relationships.
+pub const MAX_ARREARS_PAID_PER_TOP_UP: u32 = 20;
+
 pub trait Trait: system::Trait + minting::Trait {
+    /// The overarching event type.
+    type Event: From<Event<Self>> + Into<<Self as system::Trait>::Event>;
+
     type PayoutStatusHandler: PayoutStatusHandler<Self>;
 
     /// Type of identifier for recipients.
@@ -116,6 +126,19 @@ pub struct RewardRelationship<AccountId, Balance, BlockNumber, MintId, Recipient
     total_reward_missed: Balance,
 }
 
+/// Reward owed to the account of a reward relationship for the failed payouts.
+#[derive(Encode, Decode, Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct RewardArrears<AccountId, Balance, MintId> {
+    /// Mint owing the reward
+    pub mint_id: MintId,
+
+    /// Destination account for the owed reward
+    pub account: AccountId,
+
+    /// Total owed reward
+    pub amount: Balance,
+}
+
 impl<AccountId: Clone, Balance: Clone, BlockNumber: Clone, MintId: Clone, RecipientId: Clone>
     RewardRelationship<AccountId, Balance, BlockNumber, MintId, RecipientId>
 {
@@ -152,11 +175,49 @@ decl_storage! {
             T::RewardRelationshipId => RewardRelationship<T::AccountId, BalanceOf<T>, T::BlockNumber, T::MintId, T::RecipientId>;
 
         RewardRelationshipsCreated get(fn reward_relationships_created): T::RewardRelationshipId;
+
+        /// Active reward relationships scheduled for a payout at the block. Only the payouts due
+        /// are visited when finalizing the block.
+        pub RewardRelationshipIdsByPaymentBlock get(fn reward_relationship_ids_by_payment_block):
+            double_map hasher(twox_64_concat) T::BlockNumber, hasher(twox_64_concat) T::RewardRelationshipId => ();
+
+        /// Whether the payouts of the existing reward relationships were scheduled.
+        pub PaymentScheduleMigrated get(fn payment_schedule_migrated): bool;
+
+        /// Rewards owed for the payouts failed because of an insufficient mint capacity.
+        /// Arrears outlive the reward relationship.
+        pub RewardRelationshipArrears get(fn reward_relationship_arrears): map hasher(blake2_128_concat)
+            T::RewardRelationshipId => RewardArrears<T::AccountId, BalanceOf<T>, T::MintId>;
+
+        /// Reward relationships with arrears by the mint owing them.
+        pub RewardRelationshipIdsWithArrearsByMint get(fn reward_relationship_ids_with_arrears_by_mint):
+            double_map hasher(twox_64_concat) T::MintId, hasher(twox_64_concat) T::RewardRelationshipId => ();
+    }
+}
+
+decl_event! {
+    pub enum Event<T> where
+        Balance = BalanceOf<T>,
+        RewardRelationshipId = <T as Trait>::RewardRelationshipId,
+    {
+        /// Emits on recording a failed payout as owed.
+        /// Params:
+        /// - reward relationship id
+        /// - failed payout amount
+        /// - total owed amount
+        ArrearsRecorded(RewardRelationshipId, Balance, Balance),
+
+        /// Emits on paying the owed reward.
+        /// Params:
+        /// - reward relationship id
+        /// - paid amount
+        ArrearsPaid(RewardRelationshipId, Balance),
     }
 }
 
 decl_module! {
     pub struct Module<T: Trait> for enum Call where origin: T::Origin {
+        fn deposit_event() = default;
 
         fn on_finalize(now: T::BlockNumber) {
             Self::do_payouts(now);
@@ -205,6 +266,11 @@ impl<T: Trait> Module<T> {
 
         let relationship_id = Self::reward_relationships_created();
         <RewardRelationshipsCreated<T>>::put(relationship_id + One::one());
+        <RewardRelationshipIdsByPaymentBlock<T>>::insert(
+            next_payment_at_block,
+            relationship_id,
+            (),
+        );
         <RewardRelationships<T>>::insert(
             relationship_id,
             RewardRelationship {
@@ -224,7 +290,10 @@ impl<T: Trait> Module<T> {
     /// Removes a relationship from RewardRelashionships and its recipient.
     pub fn remove_reward_relationship(id: T::RewardRelationshipId) {
         if <RewardRelationships<T>>::contains_key(&id) {
-            <Recipients<T>>::remove(<RewardRelationships<T>>::take(&id).recipient);
+            let relationship = <RewardRelationships<T>>::take(&id);
+
+            Self::unschedule_payment(id, &relationship);
+            <Recipients<T>>::remove(relationship.recipient);
         }
     }
 
@@ -244,6 +313,7 @@ impl<T: Trait> Module<T> {
             let activated_relationship =
                 reward_relationship.clone_activated(&next_payment_at_block);
 
+            Self::schedule_payment(id, &activated_relationship);
             RewardRelationships::<T>::insert(id, activated_relationship);
 
             // We activated
@@ -261,6 +331,7 @@ impl<T: Trait> Module<T> {
         let deactivated = if reward_relationship.is_active() {
             let deactivated_relationship = reward_relationship.clone_deactivated();
 
+            Self::unschedule_payment(id, &reward_relationship);
             RewardRelationships::<T>::insert(id, deactivated_relationship);
 
             // Was deactivated
@@ -304,7 +375,9 @@ impl<T: Trait> Module<T> {
                     RewardsError::NextPaymentNotInFuture
                 );
             }
+            Self::unschedule_payment(id, &relationship);
             relationship.next_payment_at_block = next_payout_at_block;
+            Self::schedule_payment(id, &relationship);
         }
         if let Some(payout_interval) = new_payout_interval {
             relationship.payout_interval = payout_interval;
@@ -314,6 +387,87 @@ impl<T: Trait> Module<T> {
         Ok(())
     }
 
+    /// Schedules the upcoming payouts of the reward relationships created by the previous
+    /// runtime versions, which visited every relationship on each block.
+    /// Does nothing once migrated.
+    pub fn migrate_payment_schedule() -> Weight {
+        if Self::payment_schedule_migrated() {
+            return T::DbWeight::get().reads(1);
+        }
+
+        let now = <system::Module<T>>::block_number();
+        let mut relationships_count: Weight = 0;
+        let mut scheduled_count: Weight = 0;
+
+        for (relationship_id, relationship) in <RewardRelationships<T>>::iter() {
+            relationships_count += 1;
+
+            // Payouts in the past would never be made.
+            if let Some(next_payment_at_block) = relationship.next_payment_at_block {
+                if next_payment_at_block >= now {
+                    Self::schedule_payment(relationship_id, &relationship);
+                    scheduled_count += 1;
+                }
+            }
+        }
+
+        PaymentScheduleMigrated::put(true);
+
+        T::DbWeight::get().reads_writes(2 + relationships_count, 1 + scheduled_count)
+    }
+
+    /// Records the failed payout of the reward relationship as owed by its mint.
+    pub fn record_arrears(id: T::RewardRelationshipId, amount: BalanceOf<T>) {
+        if amount.is_zero() || !<RewardRelationships<T>>::contains_key(&id) {
+            return;
+        }
+
+        let relationship = Self::reward_relationships(&id);
+
+        let mut arrears = Self::reward_relationship_arrears(&id);
+        arrears.mint_id = relationship.mint_id;
+        arrears.account = relationship.account;
+        arrears.amount += amount;
+
+        let total_owed = arrears.amount;
+
+        <RewardRelationshipIdsWithArrearsByMint<T>>::insert(arrears.mint_id, id, ());
+        <RewardRelationshipArrears<T>>::insert(id, arrears);
+
+        Self::deposit_event(RawEvent::ArrearsRecorded(id, amount, total_owed));
+    }
+
+    /// Pays the rewards owed by the mint as far as its capacity allows, visiting at most
+    /// `MAX_ARREARS_PAID_PER_TOP_UP` reward relationships. The arrears paid in full are removed,
+    /// so the next call continues with the remaining ones. Should be called after topping up
+    /// the mint.
+    pub fn pay_arrears(mint_id: T::MintId) {
+        let owed_relationship_ids: Vec<T::RewardRelationshipId> =
+            <RewardRelationshipIdsWithArrearsByMint<T>>::iter_prefix(mint_id)
+                .take(MAX_ARREARS_PAID_PER_TOP_UP as usize)
+                .map(|(relationship_id, _)| relationship_id)
+                .collect();
+
+        for relationship_id in owed_relationship_ids {
+            // The mint capacity is exhausted.
+            if !Self::try_to_pay_arrears(relationship_id) {
+                break;
+            }
+        }
+    }
+
+    /// Returns the maximum weight of `pay_arrears`.
+    pub fn pay_arrears_weight() -> Weight {
+        let max_paid_count = MAX_ARREARS_PAID_PER_TOP_UP as Weight;
+
+        T::DbWeight::get().reads_writes(1 + 7 * max_paid_count, 6 * max_paid_count)
+    }
+
+    /// Returns the reward owed for the reward relationship.
+    pub fn owed_reward(id: T::RewardRelationshipId) -> BalanceOf<T> {
+        Self::reward_relationship_arrears(id).amount
+    }
+
     /*
     For all relationships where next_payment_at_block is set and matches current block height,
     a call to pay_reward is made for the suitable amount, recipient and source.
@@ -323,7 +477,24 @@ impl<T: Trait> Module<T> {
     Otherwise, analogous steps for failure.
     */
     fn do_payouts(now: T::BlockNumber) {
-        for (relationship_id, ref mut relationship) in <RewardRelationships<T>>::iter() {
+        let due_relationship_ids: Vec<T::RewardRelationshipId> =
+            <RewardRelationshipIdsByPaymentBlock<T>>::iter_prefix(now)
+                .map(|(relationship_id, _)| relationship_id)
+                .collect();
+
+        <RewardRelationshipIdsByPaymentBlock<T>>::remove_prefix(now);
+
+        for relationship_id in due_relationship_ids {
+            if !<RewardRelationships<T>>::contains_key(&relationship_id) {
+                continue;
+            }
+
+            // The owed reward is paid ahead of the scheduled payout, as far as the mint capacity
+            // allows.
+            Self::try_to_pay_arrears(relationship_id);
+
+            let mut relationship = Self::reward_relationships(&relationship_id);
+
             assert!(<Recipients<T>>::contains_key(&relationship.recipient));
 
             let mut recipient = Self::recipients(relationship.recipient);
@@ -342,6 +513,7 @@ impl<T: Trait> Module<T> {
                     relationship.mint_id,
                     payout,
                     &relationship.account,
+                    SpendingCategory::Rewards,
                 )
                 .is_err()
                 {
@@ -382,6 +554,7 @@ impl<T: Trait> Module<T> {
                 } else {
                     relationship.next_payment_at_block = None;
                 }
+                Self::schedule_payment(relationship_id, &relationship);
 
                 <Recipients<T>>::insert(relationship.recipient, recipient);
                 <RewardRelationships<T>>::insert(relationship_id, relationship);
@@ -390,7 +563,104 @@ impl<T: Trait> Module<T> {
     }
 }
 
+impl<T: Trait> minting::MintCapacityIncreased<T::MintId> for Module<T> {
+    fn mint_capacity_increased(mint_id: T::MintId) {
+        Self::pay_arrears(mint_id);
+    }
+
+    fn mint_capacity_increased_weight() -> Weight {
+        Self::pay_arrears_weight()
+    }
+}
+
 impl<T: Trait> Module<T> {
+    fn schedule_payment(
+        id: T::RewardRelationshipId,
+        relationship: &RewardRelationship<
+            T::AccountId,
+            BalanceOf<T>,
+            T::BlockNumber,
+            T::MintId,
+            T::RecipientId,
+        >,
+    ) {
+        if let Some(next_payment_at_block) = relationship.next_payment_at_block {
+            <RewardRelationshipIdsByPaymentBlock<T>>::insert(next_payment_at_block, id, ());
+        }
+    }
+
+    fn unschedule_payment(
+        id: T::RewardRelationshipId,
+        relationship: &RewardRelationship<
+            T::AccountId,
+            BalanceOf<T>,
+            T::BlockNumber,
+            T::MintId,
+            T::RecipientId,
+        >,
+    ) {
+        if let Some(next_payment_at_block) = relationship.next_payment_at_block {
+            <RewardRelationshipIdsByPaymentBlock<T>>::remove(next_payment_at_block, id);
+        }
+    }
+
+    // Pays the reward owed for the reward relationship up to the mint capacity. Returns whether
+    // nothing is owed anymore.
+    fn try_to_pay_arrears(id: T::RewardRelationshipId) -> bool {
+        if !<RewardRelationshipArrears<T>>::contains_key(&id) {
+            return true;
+        }
+
+        let mut arrears = Self::reward_relationship_arrears(&id);
+
+        if !<minting::Module<T>>::mint_exists(arrears.mint_id) {
+            return false;
+        }
+
+        let paid_amount = sp_std::cmp::min(
+            arrears.amount,
+            <minting::Module<T>>::mints(arrears.mint_id).capacity(),
+        );
+
+        if paid_amount.is_zero()
+            || <minting::Module<T>>::transfer_tokens(
+                arrears.mint_id,
+                paid_amount,
+                &arrears.account,
+                SpendingCategory::Rewards,
+            )
+            .is_err()
+        {
+            return false;
+        }
+
+        arrears.amount -= paid_amount;
+
+        let paid_in_full = arrears.amount.is_zero();
+
+        if paid_in_full {
+            <RewardRelationshipIdsWithArrearsByMint<T>>::remove(arrears.mint_id, id);
+            <RewardRelationshipArrears<T>>::remove(&id);
+        } else {
+            <RewardRelationshipArrears<T>>::insert(id, arrears);
+        }
+
+        // The missed reward stats are kept as a history of the failed payouts.
+        if <RewardRelationships<T>>::contains_key(&id) {
+            let mut relationship = Self::reward_relationships(&id);
+            relationship.total_reward_received += paid_amount;
+
+            <Recipients<T>>::mutate(relationship.recipient, |recipient| {
+                recipient.total_reward_received += paid_amount;
+            });
+            <RewardRelationships<T>>::insert(id, relationship);
+        }
+
+        Self::deposit_event(RawEvent::ArrearsPaid(id, paid_amount));
+
+        paid_in_full
+    }
+
     fn ensure_reward_relationship_exists(
         id: &T::RewardRelationshipId,
     ) -> Result<
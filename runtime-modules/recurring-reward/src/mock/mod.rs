@@ -70,14 +70,22 @@ impl balances::Trait for Test {
 }
 
 impl Trait for Test {
+    type Event = ();
     type PayoutStatusHandler = MockStatusHandler;
     type RecipientId = u64;
     type RewardRelationshipId = u64;
 }
 
+parameter_types! {
+    pub const MintSpendingEpochLength: u64 = 100;
+}
+
 impl minting::Trait for Test {
     type Currency = Balances;
     type MintId = u64;
+    type SpendingEpochLength = MintSpendingEpochLength;
+    type MintCapacityIncreased = Rewards;
+    type WeightInfo = ();
 }
 
 pub fn build_test_externalities() -> sp_io::TestExternalities {
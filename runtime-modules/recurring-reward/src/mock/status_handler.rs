@@ -1,7 +1,7 @@
 #![cfg(test)]
 
 use super::Test;
-use crate::{PayoutStatusHandler, Trait};
+use crate::{Module, PayoutStatusHandler, Trait};
 use std::cell::RefCell;
 
 struct StatusHandlerState<T: Trait> {
@@ -56,9 +56,11 @@ impl PayoutStatusHandler<Test> for MockStatusHandler {
         });
     }
 
-    fn payout_failed(id: u64, _destination_account: &u64, _amount: u64) {
+    fn payout_failed(id: u64, _destination_account: &u64, amount: u64) {
         STATUS_HANDLER_STATE.with(|cell| {
             cell.borrow_mut().failures.push(id);
         });
+
+        Module::<Test>::record_arrears(id, amount);
     }
 }
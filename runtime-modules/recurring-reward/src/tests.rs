@@ -2,7 +2,7 @@
 
 use super::*;
 use crate::mock::*;
-use frame_support::traits::Currency;
+use frame_support::traits::{Currency, OnFinalize};
 
 fn create_new_mint_with_capacity(capacity: u64) -> u64 {
     let mint_id = Minting::add_mint(capacity, None).ok().unwrap();
@@ -257,3 +257,336 @@ fn activate_and_deactivate_relationship() {
         assert!(!Rewards::try_to_activate_relationship(relationship_id, next_payout_at).unwrap());
     });
 }
+
+#[test]
+fn payouts_are_scheduled_by_block() {
+    build_test_externalities().execute_with(|| {
+        System::set_block_number(10000);
+        let recipient_account: u64 = 1;
+        let mint_id = create_new_mint_with_capacity(1000000);
+        let recipient_id = Rewards::add_recipient();
+        let next_payout_at: u64 = 12222;
+        let interval: u64 = 600;
+
+        let relationship_id = Rewards::add_reward_relationship(
+            mint_id,
+            recipient_id,
+            recipient_account,
+            1000,
+            next_payout_at,
+            Some(interval),
+        )
+        .unwrap();
+
+        assert!(<RewardRelationshipIdsByPaymentBlock<Test>>::contains_key(
+            next_payout_at,
+            relationship_id
+        ));
+
+        // The payout is rescheduled after the interval.
+        Rewards::do_payouts(next_payout_at);
+        let rescheduled_at = next_payout_at + interval;
+        assert!(!<RewardRelationshipIdsByPaymentBlock<Test>>::contains_key(
+            next_payout_at,
+            relationship_id
+        ));
+        assert!(<RewardRelationshipIdsByPaymentBlock<Test>>::contains_key(
+            rescheduled_at,
+            relationship_id
+        ));
+
+        // Deactivated relationships are unscheduled.
+        assert!(Rewards::try_to_deactivate_relationship(relationship_id).unwrap());
+        assert!(!<RewardRelationshipIdsByPaymentBlock<Test>>::contains_key(
+            rescheduled_at,
+            relationship_id
+        ));
+
+        // Activated relationships are scheduled.
+        assert!(Rewards::try_to_activate_relationship(relationship_id, next_payout_at).unwrap());
+        assert!(<RewardRelationshipIdsByPaymentBlock<Test>>::contains_key(
+            next_payout_at,
+            relationship_id
+        ));
+
+        // Updating the next payment reschedules the payout.
+        assert!(Rewards::set_reward_relationship(
+            relationship_id,
+            None,
+            None,
+            Some(Some(rescheduled_at)),
+            None
+        )
+        .is_ok());
+        assert!(!<RewardRelationshipIdsByPaymentBlock<Test>>::contains_key(
+            next_payout_at,
+            relationship_id
+        ));
+        assert!(<RewardRelationshipIdsByPaymentBlock<Test>>::contains_key(
+            rescheduled_at,
+            relationship_id
+        ));
+
+        // Removed relationships are unscheduled.
+        Rewards::remove_reward_relationship(relationship_id);
+        assert!(!<RewardRelationshipIdsByPaymentBlock<Test>>::contains_key(
+            rescheduled_at,
+            relationship_id
+        ));
+    });
+}
+
+#[test]
+fn payment_schedule_migration_works() {
+    build_test_externalities().execute_with(|| {
+        System::set_block_number(10000);
+        let recipient_account: u64 = 1;
+        let mint_id = create_new_mint_with_capacity(1000000);
+        let recipient_id = Rewards::add_recipient();
+        let payout: u64 = 1000;
+        let next_payout_at: u64 = 12222;
+
+        let relationship_id = Rewards::add_reward_relationship(
+            mint_id,
+            recipient_id,
+            recipient_account,
+            payout,
+            next_payout_at,
+            None,
+        )
+        .unwrap();
+
+        // Relationships created by the previous runtime versions are not scheduled.
+        <RewardRelationshipIdsByPaymentBlock<Test>>::remove(next_payout_at, relationship_id);
+        assert!(!Rewards::payment_schedule_migrated());
+
+        Rewards::migrate_payment_schedule();
+
+        assert!(Rewards::payment_schedule_migrated());
+        assert!(<RewardRelationshipIdsByPaymentBlock<Test>>::contains_key(
+            next_payout_at,
+            relationship_id
+        ));
+
+        let starting_balance = Balances::free_balance(&recipient_account);
+        Rewards::do_payouts(next_payout_at);
+        assert_eq!(
+            Balances::free_balance(&recipient_account),
+            starting_balance + payout
+        );
+    });
+}
+
+#[test]
+fn arrears_are_paid_when_mint_is_topped_up() {
+    build_test_externalities().execute_with(|| {
+        System::set_block_number(10000);
+        let recipient_account: u64 = 1;
+        let mint_id = create_new_mint_with_capacity(0);
+        let recipient_id = Rewards::add_recipient();
+        let payout: u64 = 1000;
+        let next_payout_at: u64 = 12222;
+        let interval: u64 = 600;
+
+        let relationship_id = Rewards::add_reward_relationship(
+            mint_id,
+            recipient_id,
+            recipient_account,
+            payout,
+            next_payout_at,
+            Some(interval),
+        )
+        .unwrap();
+
+        // The failed payouts are owed.
+        Rewards::do_payouts(next_payout_at);
+        Rewards::do_payouts(next_payout_at + interval);
+        assert_eq!(Rewards::owed_reward(relationship_id), 2 * payout);
+        assert!(
+            <RewardRelationshipIdsWithArrearsByMint<Test>>::contains_key(mint_id, relationship_id)
+        );
+
+        // Arrears are paid up to the mint capacity.
+        assert!(Minting::set_mint_capacity(mint_id, payout).is_ok());
+        Rewards::pay_arrears(mint_id);
+        assert_eq!(Rewards::owed_reward(relationship_id), payout);
+        assert_eq!(Balances::free_balance(&recipient_account), payout);
+        assert!(
+            <RewardRelationshipIdsWithArrearsByMint<Test>>::contains_key(mint_id, relationship_id)
+        );
+
+        assert!(Minting::set_mint_capacity(mint_id, 2 * payout).is_ok());
+        Rewards::pay_arrears(mint_id);
+        assert_eq!(Rewards::owed_reward(relationship_id), 0);
+        assert!(!<RewardRelationshipArrears<Test>>::contains_key(
+            relationship_id
+        ));
+        assert!(
+            !<RewardRelationshipIdsWithArrearsByMint<Test>>::contains_key(mint_id, relationship_id)
+        );
+        assert_eq!(Balances::free_balance(&recipient_account), 2 * payout);
+
+        let relationship = Rewards::reward_relationships(&relationship_id);
+        assert_eq!(relationship.total_reward_received, 2 * payout);
+        assert_eq!(relationship.total_reward_missed, 2 * payout);
+
+        let recipient = Rewards::recipients(&recipient_id);
+        assert_eq!(recipient.total_reward_received, 2 * payout);
+    });
+}
+
+#[test]
+fn arrears_are_paid_on_scheduled_mint_adjustment() {
+    build_test_externalities().execute_with(|| {
+        System::set_block_number(10000);
+        let recipient_account: u64 = 1;
+        let payout: u64 = 1000;
+        let next_payout_at: u64 = 12222;
+        let adjustment_at: u64 = 15000;
+
+        let mint_id = Minting::add_mint(
+            0,
+            Some(minting::Adjustment::Interval(minting::AdjustOnInterval {
+                block_interval: adjustment_at - 10000,
+                adjustment_type: minting::AdjustCapacityBy::Adding(payout),
+            })),
+        )
+        .unwrap();
+        let recipient_id = Rewards::add_recipient();
+
+        let relationship_id = Rewards::add_reward_relationship(
+            mint_id,
+            recipient_id,
+            recipient_account,
+            payout,
+            next_payout_at,
+            None,
+        )
+        .unwrap();
+
+        Rewards::do_payouts(next_payout_at);
+        assert_eq!(Rewards::owed_reward(relationship_id), payout);
+
+        <Minting as OnFinalize<u64>>::on_finalize(adjustment_at);
+
+        assert_eq!(Rewards::owed_reward(relationship_id), 0);
+        assert_eq!(Balances::free_balance(&recipient_account), payout);
+    });
+}
+
+#[test]
+fn arrears_are_paid_when_capacity_is_returned_to_parent_mint() {
+    build_test_externalities().execute_with(|| {
+        System::set_block_number(10000);
+        let recipient_account: u64 = 1;
+        let payout: u64 = 1000;
+        let next_payout_at: u64 = 12222;
+
+        let parent_mint_id = create_new_mint_with_capacity(0);
+        let child_mint_id = create_new_mint_with_capacity(payout);
+        assert!(Minting::set_parent_mint(child_mint_id, Some(parent_mint_id)).is_ok());
+        let recipient_id = Rewards::add_recipient();
+
+        let relationship_id = Rewards::add_reward_relationship(
+            parent_mint_id,
+            recipient_id,
+            recipient_account,
+            payout,
+            next_payout_at,
+            None,
+        )
+        .unwrap();
+
+        Rewards::do_payouts(next_payout_at);
+        assert_eq!(Rewards::owed_reward(relationship_id), payout);
+
+        assert!(Minting::set_mint_capacity(child_mint_id, 0).is_ok());
+
+        assert_eq!(Rewards::owed_reward(relationship_id), 0);
+        assert_eq!(Balances::free_balance(&recipient_account), payout);
+    });
+}
+
+#[test]
+fn arrears_are_paid_ahead_of_scheduled_payout() {
+    build_test_externalities().execute_with(|| {
+        System::set_block_number(10000);
+        let recipient_account: u64 = 1;
+        let mint_id = create_new_mint_with_capacity(0);
+        let recipient_id = Rewards::add_recipient();
+        let payout: u64 = 1000;
+        let next_payout_at: u64 = 12222;
+        let interval: u64 = 600;
+
+        let relationship_id = Rewards::add_reward_relationship(
+            mint_id,
+            recipient_id,
+            recipient_account,
+            payout,
+            next_payout_at,
+            Some(interval),
+        )
+        .unwrap();
+
+        Rewards::do_payouts(next_payout_at);
+        assert_eq!(Rewards::owed_reward(relationship_id), payout);
+
+        // The capacity covers only the arrears, so the scheduled payout is owed instead.
+        assert!(Minting::set_mint_capacity(mint_id, payout).is_ok());
+        Rewards::do_payouts(next_payout_at + interval);
+        assert_eq!(Balances::free_balance(&recipient_account), payout);
+        assert_eq!(Rewards::owed_reward(relationship_id), payout);
+
+        // The arrears are paid first as far as the mint capacity allows.
+        Rewards::do_payouts(next_payout_at + 2 * interval);
+        assert_eq!(Balances::free_balance(&recipient_account), payout);
+        assert_eq!(Rewards::owed_reward(relationship_id), 2 * payout);
+        assert_eq!(MockStatusHandler::failures(), 3);
+
+        // Arrears outlive the reward relationship.
+        Rewards::remove_reward_relationship(relationship_id);
+        assert!(Minting::set_mint_capacity(mint_id, 2 * payout).is_ok());
+        Rewards::pay_arrears(mint_id);
+        assert_eq!(Balances::free_balance(&recipient_account), 3 * payout);
+        assert_eq!(Rewards::owed_reward(relationship_id), 0);
+    });
+}
+
+#[test]
+fn arrears_paid_per_top_up_are_limited() {
+    build_test_externalities().execute_with(|| {
+        System::set_block_number(10000);
+        let mint_id = create_new_mint_with_capacity(0);
+        let payout: u64 = 1000;
+        let next_payout_at: u64 = 12222;
+        let relationships_count = MAX_ARREARS_PAID_PER_TOP_UP as u64 + 1;
+
+        for recipient_account in 0..relationships_count {
+            let recipient_id = Rewards::add_recipient();
+
+            Rewards::add_reward_relationship(
+                mint_id,
+                recipient_id,
+                recipient_account,
+                payout,
+                next_payout_at,
+                None,
+            )
+            .unwrap();
+        }
+
+        Rewards::do_payouts(next_payout_at);
+
+        let owed_count =
+            || <RewardRelationshipIdsWithArrearsByMint<Test>>::iter_prefix(mint_id).count() as u64;
+        assert_eq!(owed_count(), relationships_count);
+
+        assert!(Minting::set_mint_capacity(mint_id, relationships_count * payout).is_ok());
+        Rewards::pay_arrears(mint_id);
+        assert_eq!(owed_count(), 1);
+
+        // The next top-up continues with the remaining arrears.
+        Rewards::pay_arrears(mint_id);
+        assert_eq!(owed_count(), 0);
+    });
+}
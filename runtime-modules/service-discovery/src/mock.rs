@@ -37,6 +37,7 @@ impl_outer_event! {
         balances<T>,
         membership_mod<T>,
         working_group_mod StorageWorkingGroupInstance <T>,
+        recurringrewards<T>,
         system<T>,
     }
 }
@@ -92,9 +93,16 @@ impl hiring::Trait for Test {
     type StakeHandlerProvider = hiring::Module<Self>;
 }
 
+parameter_types! {
+    pub const MintSpendingEpochLength: u64 = 100;
+}
+
 impl minting::Trait for Test {
     type Currency = Balances;
     type MintId = u64;
+    type SpendingEpochLength = MintSpendingEpochLength;
+    type MintCapacityIncreased = ();
+    type WeightInfo = ();
 }
 
 impl stake::Trait for Test {
@@ -111,6 +119,7 @@ impl membership::Trait for Test {
     type PaidTermId = u64;
     type SubscriptionId = u64;
     type ActorId = u64;
+    type WeightInfo = ();
 }
 
 impl common::currency::GovernanceCurrency for Test {
@@ -126,6 +135,7 @@ impl balances::Trait for Test {
 }
 
 impl recurringrewards::Trait for Test {
+    type Event = MetaEvent;
     type PayoutStatusHandler = ();
     type RecipientId = u64;
     type RewardRelationshipId = u64;
@@ -138,6 +148,7 @@ parameter_types! {
 impl working_group::Trait<StorageWorkingGroupInstance> for Test {
     type Event = MetaEvent;
     type MaxWorkerNumberLimit = MaxWorkerNumberLimit;
+    type WeightInfo = ();
 }
 
 impl pallet_timestamp::Trait for Test {
@@ -0,0 +1,130 @@
+use super::*;
+use crate::data_directory::*;
+use frame_benchmarking::benchmarks;
+use frame_support::traits::Get;
+
+fn quota() -> StorageQuota {
+    StorageQuota {
+        size_limit: 1_000_000,
+        objects_limit: 100,
+    }
+}
+
+benchmarks! {
+    _ { }
+
+    add_content {
+        let i in 1 .. MAX_BYTES;
+
+        storage_provider::<T>(StorageProviderId::<T>::default());
+
+        let (account_id, member_id) = member::<T>();
+        let type_id = active_data_object_type::<T>();
+        let content_id = content_id::<T>(0);
+    }: _(RawOrigin::Signed(account_id), member_id, None, content_id, type_id, 1_000, text(i), None)
+    verify {
+        assert!(<DataObjectByContentId<T>>::contains_key(content_id));
+    }
+
+    accept_content {
+        let content_id = content_id::<T>(0);
+        let (account_id, storage_provider_id) = add_pending_content::<T>(content_id);
+        let merkle_root = T::Hashing::hash(b"merkle root");
+    }: _(RawOrigin::Signed(account_id), storage_provider_id, content_id, Some(merkle_root))
+    verify {
+        assert!(Module::<T>::is_known_content_id(&content_id));
+        assert_eq!(Module::<T>::merkle_root_by_content_id(content_id), Some(merkle_root));
+    }
+
+    reject_content {
+        let content_id = content_id::<T>(0);
+        let (account_id, storage_provider_id) = add_pending_content::<T>(content_id);
+    }: _(RawOrigin::Signed(account_id), storage_provider_id, content_id)
+    verify {
+        let data_object = Module::<T>::data_object_by_content_id(content_id).unwrap();
+        assert_eq!(data_object.liaison_judgement, LiaisonJudgement::Rejected);
+    }
+
+    delete_content {
+        let content_id = content_id::<T>(0);
+        add_pending_content::<T>(content_id);
+
+        let owner = Module::<T>::data_object_by_content_id(content_id).unwrap().owner;
+        let account_id: T::AccountId = account("member", 0, SEED);
+    }: _(RawOrigin::Signed(account_id), owner, content_id)
+    verify {
+        assert!(Module::<T>::is_content_deleted(&content_id));
+    }
+
+    delete_content_as_lead {
+        let content_id = content_id::<T>(0);
+        add_pending_content::<T>(content_id);
+
+        let lead_account_id = lead::<T>();
+    }: _(RawOrigin::Signed(lead_account_id), content_id)
+    verify {
+        assert!(Module::<T>::is_content_deleted(&content_id));
+    }
+
+    remove_known_content_id {
+        let content_id = content_id::<T>(0);
+        Module::<T>::add_known_content_id(content_id);
+    }: _(RawOrigin::Root, content_id)
+    verify {
+        assert_eq!(Module::<T>::known_content_ids_count(), 0);
+    }
+
+    inject_data_objects {
+        let o in 1 .. T::MaxObjectsPerInjection::get();
+
+        let objects: DataObjectsMap<T> = (0..o)
+            .map(|index| (content_id::<T>(index), data_object::<T>()))
+            .collect();
+    }: _(RawOrigin::Root, objects)
+    verify {
+        assert_eq!(Module::<T>::known_content_ids_count(), o as u64);
+    }
+
+    set_default_quota {
+        let quota = quota();
+    }: _(RawOrigin::Root, quota)
+    verify {
+        assert_eq!(Module::<T>::default_quota(), quota);
+    }
+
+    set_member_quota {
+        let member_id = MemberId::<T>::default();
+    }: _(RawOrigin::Root, member_id, Some(quota()))
+    verify {
+        assert_eq!(Module::<T>::member_quota(member_id), Some(quota()));
+    }
+
+    set_channel_quota {
+        let channel_id = T::ChannelId::default();
+    }: _(RawOrigin::Root, channel_id, Some(quota()))
+    verify {
+        assert_eq!(Module::<T>::channel_quota(channel_id), Some(quota()));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::mock::{with_default_mock_builder, Test};
+
+    #[test]
+    fn data_directory_benchmarks_succeed() {
+        with_default_mock_builder(|| {
+            assert!(test_benchmark_add_content::<Test>().is_ok());
+            assert!(test_benchmark_accept_content::<Test>().is_ok());
+            assert!(test_benchmark_reject_content::<Test>().is_ok());
+            assert!(test_benchmark_delete_content::<Test>().is_ok());
+            assert!(test_benchmark_delete_content_as_lead::<Test>().is_ok());
+            assert!(test_benchmark_remove_known_content_id::<Test>().is_ok());
+            assert!(test_benchmark_inject_data_objects::<Test>().is_ok());
+            assert!(test_benchmark_set_default_quota::<Test>().is_ok());
+            assert!(test_benchmark_set_member_quota::<Test>().is_ok());
+            assert!(test_benchmark_set_channel_quota::<Test>().is_ok());
+        });
+    }
+}
@@ -0,0 +1,201 @@
+use super::*;
+use crate::data_directory::MerkleRootByContentId;
+use crate::data_object_storage_registry::*;
+use frame_benchmarking::benchmarks;
+use frame_support::traits::{Currency, Get};
+use sp_runtime::traits::One;
+
+// Upper bound for the benchmarked Merkle proof length (the content of 2^30 chunks).
+const MAX_MERKLE_PROOF_LENGTH: u32 = 30;
+
+// Role stake of the storage provider slashed for the failed challenge.
+const STAKE: u32 = 1_000;
+
+// Adds the relationship between the content and its liaison.
+fn create_relationship<T: Trait>(
+    ready: bool,
+) -> (
+    T::AccountId,
+    StorageProviderId<T>,
+    T::DataObjectStorageRelationshipId,
+) {
+    let content_id = content_id::<T>(0);
+    let (account_id, storage_provider_id) = add_pending_content::<T>(content_id);
+    let relationship_id = Module::<T>::next_relationship_id();
+
+    Module::<T>::add_relationship(
+        RawOrigin::Signed(account_id.clone()).into(),
+        storage_provider_id,
+        content_id,
+    )
+    .unwrap();
+
+    if ready {
+        Module::<T>::set_relationship_ready(
+            RawOrigin::Signed(account_id.clone()).into(),
+            storage_provider_id,
+            relationship_id,
+        )
+        .unwrap();
+    }
+
+    (account_id, storage_provider_id, relationship_id)
+}
+
+// Challenges the storage provider to prove it keeps the content chunk.
+fn storage_challenge<T: Trait>(
+    content_id: T::ContentId,
+    storage_provider_id: StorageProviderId<T>,
+    chunk_index: u64,
+) -> (StorageChallengeId, StorageChallenge<T>) {
+    let challenge_id = Module::<T>::next_storage_challenge_id();
+    NextStorageChallengeId::put(challenge_id + 1);
+
+    let challenge = StorageChallenge {
+        content_id,
+        storage_provider_id,
+        chunk_index,
+        deadline: <system::Module<T>>::block_number() + One::one(),
+    };
+
+    (challenge_id, challenge)
+}
+
+// Stakes for the storage provider role.
+fn stake_for_role<T: Trait>(storage_provider_id: StorageProviderId<T>, account_id: T::AccountId) {
+    let stake_id = <stake::Module<T>>::create_stake();
+    let balance = working_group::BalanceOf::<T>::from(STAKE);
+
+    <T as stake::Trait>::Currency::make_free_balance_be(&account_id, balance);
+    <stake::Module<T>>::stake_from_account(&stake_id, &account_id, balance).unwrap();
+
+    <working_group::WorkerById<T, StorageWorkingGroupInstance>>::mutate(
+        storage_provider_id,
+        |worker| {
+            worker.role_stake_profile = Some(working_group::RoleStakeProfile::new(
+                &stake_id, &None, &None,
+            ));
+        },
+    );
+}
+
+benchmarks! {
+    _ { }
+
+    add_relationship {
+        let content_id = content_id::<T>(0);
+        let (account_id, storage_provider_id) = add_pending_content::<T>(content_id);
+        let relationship_id = Module::<T>::next_relationship_id();
+    }: _(RawOrigin::Signed(account_id), storage_provider_id, content_id)
+    verify {
+        assert!(<Relationships<T>>::contains_key(relationship_id));
+    }
+
+    set_relationship_ready {
+        let (account_id, storage_provider_id, relationship_id) = create_relationship::<T>(false);
+    }: _(RawOrigin::Signed(account_id), storage_provider_id, relationship_id)
+    verify {
+        assert!(Module::<T>::relationships(relationship_id).unwrap().ready);
+    }
+
+    unset_relationship_ready {
+        let (account_id, storage_provider_id, relationship_id) = create_relationship::<T>(true);
+    }: _(RawOrigin::Signed(account_id), storage_provider_id, relationship_id)
+    verify {
+        assert!(!Module::<T>::relationships(relationship_id).unwrap().ready);
+    }
+
+    submit_storage_proof {
+        let i in 1 .. T::StorageProofChunkSize::get();
+        let p in 0 .. MAX_MERKLE_PROOF_LENGTH;
+
+        let content_id = content_id::<T>(0);
+        let (account_id, storage_provider_id) = add_pending_content::<T>(content_id);
+
+        // The last chunk of the content with 2^p chunks has a sibling on every tree level.
+        let chunks_count = 1u64 << p;
+        let chunk_size = u64::from(T::StorageProofChunkSize::get());
+        <DataObjectByContentId<T>>::mutate(content_id, |data_object| {
+            if let Some(data_object) = data_object {
+                data_object.size = (chunks_count - 1) * chunk_size + u64::from(i);
+            }
+        });
+
+        let chunk = text(i);
+        let proof: Vec<T::Hash> = (0..p).map(|index| T::Hashing::hash_of(&index)).collect();
+        let merkle_root = proof.iter().fold(Module::<T>::merkle_leaf(&chunk), |node, sibling| {
+            Module::<T>::merkle_node(*sibling, node)
+        });
+        <MerkleRootByContentId<T>>::insert(content_id, merkle_root);
+
+        let (challenge_id, challenge) =
+            storage_challenge::<T>(content_id, storage_provider_id, chunks_count - 1);
+        <StorageChallenges<T>>::insert(challenge_id, challenge);
+    }: _(RawOrigin::Signed(account_id), storage_provider_id, challenge_id, chunk, proof)
+    verify {
+        assert!(!<StorageChallenges<T>>::contains_key(challenge_id));
+    }
+
+    slash_for_failed_challenge {
+        let content_id = content_id::<T>(0);
+        let (account_id, storage_provider_id) = add_pending_content::<T>(content_id);
+        stake_for_role::<T>(storage_provider_id, account_id);
+
+        let (challenge_id, challenge) = storage_challenge::<T>(content_id, storage_provider_id, 0);
+        <FailedStorageChallenges<T>>::insert(challenge_id, challenge);
+
+        let lead_account_id = lead::<T>();
+    }: _(RawOrigin::Signed(lead_account_id), challenge_id, working_group::BalanceOf::<T>::from(STAKE))
+    verify {
+        assert!(!<FailedStorageChallenges<T>>::contains_key(challenge_id));
+    }
+
+    check_content_replication {
+        let c in 1 .. T::MaxReplicationChecksPerBlock::get();
+
+        // The liaison is not a live storage provider, so every checked content gets a replica.
+        storage_provider::<T>(StorageProviderId::<T>::from(1u32));
+
+        for index in 0..c {
+            let content_id = content_id::<T>(index);
+
+            <DataObjectByContentId<T>>::insert(content_id, data_object::<T>());
+            <crate::data_directory::Module<T>>::add_known_content_id(content_id);
+        }
+    }: { Module::<T>::check_content_replication(); }
+    verify {
+        assert!(!Module::<T>::relationships_by_content_id(content_id::<T>(c - 1)).is_empty());
+    }
+
+    dismiss_failed_challenge {
+        let content_id = content_id::<T>(0);
+        let (_, storage_provider_id) = add_pending_content::<T>(content_id);
+
+        let (challenge_id, challenge) = storage_challenge::<T>(content_id, storage_provider_id, 0);
+        <FailedStorageChallenges<T>>::insert(challenge_id, challenge);
+
+        let lead_account_id = lead::<T>();
+    }: _(RawOrigin::Signed(lead_account_id), challenge_id)
+    verify {
+        assert!(!<FailedStorageChallenges<T>>::contains_key(challenge_id));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::mock::{with_default_mock_builder, Test};
+
+    #[test]
+    fn data_object_storage_registry_benchmarks_succeed() {
+        with_default_mock_builder(|| {
+            assert!(test_benchmark_add_relationship::<Test>().is_ok());
+            assert!(test_benchmark_set_relationship_ready::<Test>().is_ok());
+            assert!(test_benchmark_unset_relationship_ready::<Test>().is_ok());
+            assert!(test_benchmark_submit_storage_proof::<Test>().is_ok());
+            assert!(test_benchmark_slash_for_failed_challenge::<Test>().is_ok());
+            assert!(test_benchmark_dismiss_failed_challenge::<Test>().is_ok());
+            assert!(test_benchmark_check_content_replication::<Test>().is_ok());
+        });
+    }
+}
@@ -0,0 +1,77 @@
+use super::*;
+use crate::data_object_type_registry::*;
+use frame_benchmarking::benchmarks;
+
+fn data_object_type(description_length: u32, active: bool) -> DataObjectType {
+    DataObjectType {
+        description: text(description_length),
+        active,
+        replication_factor: 1,
+        max_object_size: None,
+    }
+}
+
+fn insert_data_object_type<T: Trait>(active: bool) -> T::DataObjectTypeId {
+    let type_id = Module::<T>::next_data_object_type_id();
+
+    <DataObjectTypes<T>>::insert(type_id, data_object_type(MAX_BYTES, active));
+    <NextDataObjectTypeId<T>>::put(type_id + T::DataObjectTypeId::from(1u32));
+
+    type_id
+}
+
+benchmarks! {
+    _ { }
+
+    register_data_object_type {
+        let d in 1 .. MAX_BYTES;
+
+        let account_id = lead::<T>();
+        let type_id = Module::<T>::next_data_object_type_id();
+    }: _(RawOrigin::Signed(account_id), data_object_type(d, true))
+    verify {
+        assert!(<DataObjectTypes<T>>::contains_key(type_id));
+    }
+
+    update_data_object_type {
+        let d in 1 .. MAX_BYTES;
+
+        let account_id = lead::<T>();
+        let type_id = insert_data_object_type::<T>(true);
+    }: _(RawOrigin::Signed(account_id), type_id, data_object_type(d, false))
+    verify {
+        assert_eq!(Module::<T>::data_object_types(type_id), Some(data_object_type(d, false)));
+    }
+
+    activate_data_object_type {
+        let account_id = lead::<T>();
+        let type_id = insert_data_object_type::<T>(false);
+    }: _(RawOrigin::Signed(account_id), type_id)
+    verify {
+        assert!(Module::<T>::data_object_types(type_id).unwrap().active);
+    }
+
+    deactivate_data_object_type {
+        let account_id = lead::<T>();
+        let type_id = insert_data_object_type::<T>(true);
+    }: _(RawOrigin::Signed(account_id), type_id)
+    verify {
+        assert!(!Module::<T>::data_object_types(type_id).unwrap().active);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::mock::{with_default_mock_builder, Test};
+
+    #[test]
+    fn data_object_type_registry_benchmarks_succeed() {
+        with_default_mock_builder(|| {
+            assert!(test_benchmark_register_data_object_type::<Test>().is_ok());
+            assert!(test_benchmark_update_data_object_type::<Test>().is_ok());
+            assert!(test_benchmark_activate_data_object_type::<Test>().is_ok());
+            assert!(test_benchmark_deactivate_data_object_type::<Test>().is_ok());
+        });
+    }
+}
@@ -0,0 +1,149 @@
+//! Storage modules benchmarking.
+
+mod data_directory;
+mod data_object_storage_registry;
+mod data_object_type_registry;
+
+use codec::{Decode, Encode};
+use frame_benchmarking::account;
+use sp_runtime::traits::Hash;
+use sp_std::vec;
+use sp_std::vec::Vec;
+use system::RawOrigin;
+
+use crate::data_directory::{DataObject, DataObjectInternal, LiaisonJudgement};
+use crate::data_directory::{DataObjectByContentId, StorageProviderHelper};
+use crate::data_object_type_registry::{DataObjectType, DataObjectTypes};
+use crate::{MemberId, StorageProviderId, StorageWorkingGroupInstance};
+
+const SEED: u32 = 0;
+
+// Upper bound for the benchmarked text inputs.
+const MAX_BYTES: u32 = 5_000;
+
+// Length of the CIDv0 IPFS content id.
+const IPFS_CONTENT_ID_LENGTH: u32 = 46;
+
+fn text(len: u32) -> Vec<u8> {
+    vec![b'x'; len as usize]
+}
+
+// The content id type has no numeric conversion bound, so the ids are decoded from the hashes of
+// their indices.
+fn content_id<T: crate::data_directory::Trait>(index: u32) -> T::ContentId {
+    T::ContentId::decode(&mut T::Hashing::hash_of(&index).as_ref()).unwrap()
+}
+
+// Creates a member with the same root and controller account.
+fn member<T: membership::Trait>() -> (T::AccountId, MemberId<T>) {
+    let account_id: T::AccountId = account("member", 0, SEED);
+    let member_id = <membership::Module<T>>::members_created();
+
+    <membership::Module<T>>::set_screening_authority(RawOrigin::Root.into(), account_id.clone())
+        .unwrap();
+
+    <membership::Module<T>>::add_screened_member(
+        RawOrigin::Signed(account_id.clone()).into(),
+        account_id.clone(),
+        Some(account_id.encode()),
+        None,
+        None,
+    )
+    .unwrap();
+
+    (account_id, member_id)
+}
+
+// Inserts the storage working group worker. The hiring itself is benchmarked by the working group
+// module.
+fn insert_worker<T: working_group::Trait<StorageWorkingGroupInstance>>(
+    worker_id: StorageProviderId<T>,
+    role_account_id: T::AccountId,
+) {
+    <working_group::WorkerById<T, StorageWorkingGroupInstance>>::insert(
+        worker_id,
+        working_group::Worker {
+            member_id: MemberId::<T>::default(),
+            role_account_id,
+            reward_relationship: None,
+            role_stake_profile: None,
+        },
+    );
+
+    if worker_id >= <working_group::NextWorkerId<T, StorageWorkingGroupInstance>>::get() {
+        <working_group::NextWorkerId<T, StorageWorkingGroupInstance>>::put(
+            worker_id + StorageProviderId::<T>::from(1u32),
+        );
+    }
+}
+
+// Sets the storage working group lead, returns the lead role account.
+fn lead<T: working_group::Trait<StorageWorkingGroupInstance>>() -> T::AccountId {
+    let account_id: T::AccountId = account("lead", 0, SEED);
+    let worker_id = <working_group::NextWorkerId<T, StorageWorkingGroupInstance>>::get();
+
+    insert_worker::<T>(worker_id, account_id.clone());
+    <working_group::CurrentLead<T, StorageWorkingGroupInstance>>::put(worker_id);
+
+    account_id
+}
+
+// Creates the storage provider eligible for the content assignment, returns its role account.
+fn storage_provider<T: crate::data_directory::Trait>(
+    storage_provider_id: StorageProviderId<T>,
+) -> T::AccountId {
+    let account_id: T::AccountId = account("storage_provider", 0, SEED);
+
+    insert_worker::<T>(storage_provider_id, account_id.clone());
+    T::StorageProviderHelper::prepare_storage_provider(storage_provider_id);
+
+    account_id
+}
+
+// Activates the first data object type, the same way the type registry does on initialization.
+fn active_data_object_type<T: crate::data_object_type_registry::Trait>() -> T::DataObjectTypeId {
+    let type_id = <crate::data_object_type_registry::Module<T>>::first_data_object_type_id();
+
+    <DataObjectTypes<T>>::insert(type_id, DataObjectType::default());
+
+    type_id
+}
+
+fn data_object<T: crate::data_directory::Trait>() -> DataObject<T> {
+    DataObjectInternal {
+        owner: MemberId::<T>::default(),
+        added_at: common::current_block_time::<T>(),
+        type_id: active_data_object_type::<T>(),
+        size: 1_000,
+        liaison: StorageProviderId::<T>::default(),
+        liaison_judgement: LiaisonJudgement::Accepted,
+        ipfs_content_id: text(IPFS_CONTENT_ID_LENGTH),
+    }
+}
+
+// Adds the content awaiting the liaison judgement, returns the liaison role account and id.
+fn add_pending_content<T: crate::data_directory::Trait>(
+    content_id: T::ContentId,
+) -> (T::AccountId, StorageProviderId<T>) {
+    storage_provider::<T>(StorageProviderId::<T>::default());
+
+    let (account_id, member_id) = member::<T>();
+
+    <crate::data_directory::Module<T>>::add_content(
+        RawOrigin::Signed(account_id).into(),
+        member_id,
+        None,
+        content_id,
+        active_data_object_type::<T>(),
+        1_000,
+        text(IPFS_CONTENT_ID_LENGTH),
+        None,
+    )
+    .unwrap();
+
+    // The storage provider helper picks the liaison, make sure it is a worker.
+    let liaison = <DataObjectByContentId<T>>::get(content_id).unwrap().liaison;
+    let liaison_account_id = storage_provider::<T>(liaison);
+
+    (liaison_account_id, liaison)
+}
@@ -10,6 +10,11 @@
 //!
 //! ### Public extrinsic
 //! - [add_content](./struct.Module.html#method.add_content) - Adds the content to the system.
+//! - [delete_content](./struct.Module.html#method.delete_content) - Deletes the content by its owner.
+//! - [delete_content_as_lead](./struct.Module.html#method.delete_content_as_lead) - Deletes the content by the root or the storage working group leader.
+//! - [set_default_quota](./struct.Module.html#method.set_default_quota) - Sets the default storage quota.
+//! - [set_member_quota](./struct.Module.html#method.set_member_quota) - Sets the member storage quota.
+//! - [set_channel_quota](./struct.Module.html#method.set_channel_quota) - Sets the channel storage quota.
 //!
 //! ### Private extrinsics
 //! - accept_content - Storage provider accepts a content.
@@ -17,14 +22,49 @@
 //! - remove_known_content_id - Removes the content id from the list of known content ids. Requires root privileges.
 //! - set_known_content_id - Sets the content id from the list of known content ids. Requires root privileges.
 //!
+//! ## Known content ids
+//!
+//! The accepted content ids are indexed by the consecutive indices, so they can be iterated by
+//! pages ([known_content_ids_page](./struct.Module.html#method.known_content_ids_page)) without
+//! loading them all. Removing the content id moves the last indexed content id to its index.
+//!
+//! ## Storage quotas
+//!
+//! The total size and the number of the data objects are limited per member and per channel
+//! (when the content is added for the channel). The members and channels without the individual
+//! quota use the default one. The quotas are set by the root or the storage working group leader.
+//! The usage is reserved on adding the content, released on the content rejection and reserved
+//! again if the rejected content gets accepted. Data object types can limit the data object size.
+//!
+//! ## Content deletion
+//!
+//! The content can be deleted by its owner, the storage working group leader or the root. The data
+//! object is kept in the directory marked as deleted, its storage usage is released, it is removed
+//! from the known content ids and the `ContentDeletionHandler` cleans up the related state (eg.:
+//! the storage relationships). The content in use (eg.: the constitution document) cannot be
+//! deleted, this is checked by the `ContentDeletionValidator`. The deleted content cannot be
+//! judged or deleted again. The liaison and the replica storage providers learn about the
+//! deletion from the events and can garbage-collect the content.
+//!
+//! ## Storage proofs
+//!
+//! The content can be added with the Merkle root of its chunks (see the _Data object storage
+//! registry_ module). The liaison confirms the committed root on accepting the content, or commits
+//! the root of the received content when the owner didn't. The storage providers keeping the
+//! content are challenged to prove they hold it against this root.
+//!
 
 // Do not delete! Cannot be uncommented by default, because of Parity decl_module! issue.
 //#![warn(missing_docs)]
 
 use codec::{Decode, Encode};
 use frame_support::dispatch::DispatchResult;
+use frame_support::storage::migration::take_storage_value;
 use frame_support::traits::Get;
-use frame_support::{decl_error, decl_event, decl_module, decl_storage, ensure, Parameter};
+use frame_support::weights::Weight;
+use frame_support::{
+    decl_error, decl_event, decl_module, decl_storage, ensure, IterableStorageMap, Parameter,
+};
 use sp_runtime::traits::{MaybeSerialize, Member};
 use sp_std::collections::btree_map::BTreeMap;
 use sp_std::vec::Vec;
@@ -40,6 +80,57 @@ use crate::data_object_type_registry;
 use crate::data_object_type_registry::IsActiveDataObjectType;
 use crate::{MemberId, StorageProviderId, StorageWorkingGroup, StorageWorkingGroupInstance};
 
+const DEFAULT_QUOTA_SIZE_LIMIT: u64 = 10 * 1024 * 1024 * 1024;
+const DEFAULT_QUOTA_OBJECTS_LIMIT: u64 = 1_000;
+
+/// Weight information for the _Data directory_ extrinsics. Length parameters are measured in bytes.
+pub trait WeightInfo {
+    fn add_content(i: u32) -> Weight;
+    fn accept_content() -> Weight;
+    fn reject_content() -> Weight;
+    fn remove_known_content_id() -> Weight;
+    fn delete_content() -> Weight;
+    fn delete_content_as_lead() -> Weight;
+    fn inject_data_objects(o: u32) -> Weight;
+    fn set_default_quota() -> Weight;
+    fn set_member_quota() -> Weight;
+    fn set_channel_quota() -> Weight;
+}
+
+/// Default weights, used by the tests.
+impl WeightInfo for () {
+    fn add_content(_i: u32) -> Weight {
+        10_000_000
+    }
+    fn accept_content() -> Weight {
+        10_000_000
+    }
+    fn reject_content() -> Weight {
+        10_000_000
+    }
+    fn remove_known_content_id() -> Weight {
+        10_000_000
+    }
+    fn delete_content() -> Weight {
+        10_000_000
+    }
+    fn delete_content_as_lead() -> Weight {
+        10_000_000
+    }
+    fn inject_data_objects(_o: u32) -> Weight {
+        10_000_000
+    }
+    fn set_default_quota() -> Weight {
+        10_000_000
+    }
+    fn set_member_quota() -> Weight {
+        10_000_000
+    }
+    fn set_channel_quota() -> Weight {
+        10_000_000
+    }
+}
+
 /// The _Data directory_ main _Trait_.
 pub trait Trait:
     pallet_timestamp::Trait
@@ -54,6 +145,18 @@ pub trait Trait:
     /// Content id.
     type ContentId: Parameter + Member + MaybeSerialize + Copy + Ord + Default;
 
+    /// Id of the channel the content can be added for.
+    type ChannelId: Parameter + Member + MaybeSerialize + Copy + Ord + Default;
+
+    /// Validates the channel ownership.
+    type ChannelOwnershipValidator: ChannelOwnershipValidator<Self>;
+
+    /// Cleans up the state related to the deleted content.
+    type ContentDeletionHandler: ContentDeletionHandler<Self>;
+
+    /// Verifies whether the content can be deleted.
+    type ContentDeletionValidator: ContentDeletionValidator<Self>;
+
     /// Provides random storage provider id.
     type StorageProviderHelper: StorageProviderHelper<Self>;
 
@@ -64,6 +167,9 @@ pub trait Trait:
     type MemberOriginValidator: ActorOriginValidator<Self::Origin, MemberId<Self>, Self::AccountId>;
 
     type MaxObjectsPerInjection: Get<u32>;
+
+    /// Weight information for the extrinsics of this module.
+    type WeightInfo: WeightInfo;
 }
 
 decl_error! {
@@ -85,7 +191,31 @@ decl_error! {
         RequireRootOrigin,
 
         /// DataObject Injection Failed. Too Many DataObjects.
-        DataObjectsInjectionExceededLimit
+        DataObjectsInjectionExceededLimit,
+
+        /// Only the channel owner can add the content for the channel.
+        ChannelOwnerRequired,
+
+        /// Data object size exceeds the limit of the data object type.
+        DataObjectSizeLimitExceeded,
+
+        /// Member storage quota exceeded.
+        MemberQuotaExceeded,
+
+        /// Channel storage quota exceeded.
+        ChannelQuotaExceeded,
+
+        /// Only the content owner can delete the content.
+        OwnerRequired,
+
+        /// The content was deleted.
+        ContentDeleted,
+
+        /// The content is in use (eg.: by the constitution) and cannot be deleted.
+        ContentIsUndeletable,
+
+        /// The Merkle root doesn't match the one committed on adding the content.
+        MerkleRootMismatch,
     }
 }
 
@@ -147,14 +277,117 @@ pub struct DataObjectInternal<MemberId, BlockNumber, Moment, DataObjectTypeId, S
 /// A map collection of unique DataObjects keyed by the ContentId
 pub type DataObjectsMap<T> = BTreeMap<<T as Trait>::ContentId, DataObject<T>>;
 
+/// Storage limits of the member or the channel.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Encode, Decode, Default, PartialEq, Eq, Debug)]
+pub struct StorageQuota {
+    /// Maximum total size of the data objects in bytes.
+    pub size_limit: u64,
+
+    /// Maximum number of the data objects.
+    pub objects_limit: u64,
+}
+
+/// Storage usage of the member or the channel.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Encode, Decode, Default, PartialEq, Eq, Debug)]
+pub struct StorageUsage {
+    /// Total size of the data objects in bytes.
+    pub size: u64,
+
+    /// Number of the data objects.
+    pub objects: u64,
+}
+
+impl StorageUsage {
+    /// Verifies that one more data object of the given size fits the quota.
+    pub fn fits(&self, quota: &StorageQuota, size: u64) -> bool {
+        self.objects < quota.objects_limit && self.size.saturating_add(size) <= quota.size_limit
+    }
+
+    fn reserve(&mut self, size: u64) {
+        self.size = self.size.saturating_add(size);
+        self.objects = self.objects.saturating_add(1);
+    }
+
+    fn release(&mut self, size: u64) {
+        self.size = self.size.saturating_sub(size);
+        self.objects = self.objects.saturating_sub(1);
+    }
+}
+
 decl_storage! {
     trait Store for Module<T: Trait> as DataDirectory {
-        /// List of ids known to the system.
-        pub KnownContentIds get(fn known_content_ids) config(): Vec<T::ContentId> = Vec::new();
+        /// Number of the content ids known to the system.
+        pub KnownContentIdsCount get(fn known_content_ids_count): u64;
+
+        /// Content ids known to the system by their index (from 0 to KnownContentIdsCount - 1).
+        pub KnownContentIdByIndex get(fn known_content_id_by_index):
+            map hasher(twox_64_concat) u64 => Option<T::ContentId>;
+
+        /// Indices of the content ids known to the system.
+        pub KnownContentIdIndex get(fn known_content_id_index):
+            map hasher(blake2_128_concat) T::ContentId => Option<u64>;
 
         /// Maps data objects by their content id.
         pub DataObjectByContentId get(fn data_object_by_content_id) config():
             map hasher(blake2_128_concat) T::ContentId => Option<DataObject<T>>;
+
+        /// Maps the content added for the channels to the channel ids.
+        pub ChannelIdByContentId get(fn channel_id_by_content_id):
+            map hasher(blake2_128_concat) T::ContentId => Option<T::ChannelId>;
+
+        /// Blocks at which the content was deleted. The data objects of the deleted content are kept.
+        pub ContentDeletedAt get(fn content_deleted_at):
+            map hasher(blake2_128_concat) T::ContentId => Option<T::BlockNumber>;
+
+        /// Merkle roots of the content chunks committed on adding or accepting the content.
+        pub MerkleRootByContentId get(fn merkle_root_by_content_id):
+            map hasher(blake2_128_concat) T::ContentId => Option<T::Hash>;
+
+        /// Storage quota of the members and channels without the individual quota.
+        pub DefaultQuota get(fn default_quota): StorageQuota = StorageQuota {
+            size_limit: DEFAULT_QUOTA_SIZE_LIMIT,
+            objects_limit: DEFAULT_QUOTA_OBJECTS_LIMIT,
+        };
+
+        /// Individual member storage quotas.
+        pub MemberQuotas get(fn member_quota):
+            map hasher(blake2_128_concat) MemberId<T> => Option<StorageQuota>;
+
+        /// Individual channel storage quotas.
+        pub ChannelQuotas get(fn channel_quota):
+            map hasher(blake2_128_concat) T::ChannelId => Option<StorageQuota>;
+
+        /// Storage usage of the members.
+        pub MemberStorageUsage get(fn member_storage_usage) build(|config: &GenesisConfig<T>| {
+            let mut usage: BTreeMap<MemberId<T>, StorageUsage> = BTreeMap::new();
+
+            for (_, data_object) in config.data_object_by_content_id.iter() {
+                if data_object.liaison_judgement != LiaisonJudgement::Rejected {
+                    usage.entry(data_object.owner).or_default().reserve(data_object.size);
+                }
+            }
+
+            usage.into_iter().collect::<Vec<_>>()
+        }): map hasher(blake2_128_concat) MemberId<T> => StorageUsage;
+
+        /// Storage usage of the channels.
+        pub ChannelStorageUsage get(fn channel_storage_usage):
+            map hasher(blake2_128_concat) T::ChannelId => StorageUsage;
+
+        /// Whether the storage usage of the content added by the previous runtime versions was
+        /// counted.
+        pub MemberStorageUsageMigrated get(fn member_storage_usage_migrated)
+            build(|_: &GenesisConfig<T>| true): bool;
+    }
+    add_extra_genesis {
+        config(known_content_ids): Vec<T::ContentId>;
+        build(|config: &GenesisConfig<T>| {
+            for content_id in config.known_content_ids.iter() {
+                <Module<T>>::add_known_content_id(*content_id);
+            }
+        });
     }
 }
 
@@ -162,6 +395,7 @@ decl_event! {
     /// _Data directory_ events
     pub enum Event<T> where
         <T as Trait>::ContentId,
+        <T as Trait>::ChannelId,
         MemberId = MemberId<T>,
         StorageProviderId = StorageProviderId<T>
     {
@@ -182,6 +416,29 @@ decl_event! {
         /// - Id of the relationship.
         /// - Id of the storage provider.
         ContentRejected(ContentId, StorageProviderId),
+
+        /// Emits on the content deletion.
+        /// Params:
+        /// - Id of the content.
+        /// - Id of the liaison storage provider.
+        ContentDeleted(ContentId, StorageProviderId),
+
+        /// Emits on updating the default storage quota.
+        /// Params:
+        /// - New default storage quota.
+        DefaultQuotaUpdated(StorageQuota),
+
+        /// Emits on updating the member storage quota.
+        /// Params:
+        /// - Id of the member.
+        /// - New member storage quota (None - the default quota).
+        MemberQuotaUpdated(MemberId, Option<StorageQuota>),
+
+        /// Emits on updating the channel storage quota.
+        /// Params:
+        /// - Id of the channel.
+        /// - New channel storage quota (None - the default quota).
+        ChannelQuotaUpdated(ChannelId, Option<StorageQuota>),
     }
 }
 
@@ -197,16 +454,20 @@ decl_module! {
         /// Maximum objects allowed per inject_data_objects() transaction
         const MaxObjectsPerInjection: u32 = T::MaxObjectsPerInjection::get();
 
-        /// Adds the content to the system. Member id should match its origin. The created DataObject
-        /// awaits liaison to accept or reject it.
-        #[weight = 10_000_000] // TODO: adjust weight
+        /// Adds the content to the system. Member id should match its origin. The content can be
+        /// added for the channel owned by the member. The Merkle root of the content chunks
+        /// allows to challenge the storage providers to prove they keep the content.
+        /// The created DataObject awaits liaison to accept or reject it.
+        #[weight = <T as Trait>::WeightInfo::add_content(ipfs_content_id.len() as u32)]
         pub fn add_content(
             origin,
             member_id: MemberId<T>,
+            channel_id: Option<T::ChannelId>,
             content_id: T::ContentId,
             type_id: <T as data_object_type_registry::Trait>::DataObjectTypeId,
             size: u64,
-            ipfs_content_id: Vec<u8>
+            ipfs_content_id: Vec<u8>,
+            merkle_root: Option<T::Hash>
         ) {
             T::MemberOriginValidator::ensure_actor_origin(
                 origin,
@@ -219,6 +480,14 @@ decl_module! {
             ensure!(!<DataObjectByContentId<T>>::contains_key(content_id),
                 Error::<T>::DataObjectAlreadyAdded);
 
+            if let Some(channel_id) = channel_id {
+                ensure!(T::ChannelOwnershipValidator::is_channel_owner(&member_id, &channel_id),
+                    Error::<T>::ChannelOwnerRequired);
+            }
+
+            Self::ensure_data_object_size_fits_type(type_id, size)?;
+            Self::ensure_storage_quotas(member_id, channel_id, size)?;
+
             let liaison = T::StorageProviderHelper::get_random_storage_provider()?;
 
             // Let's create the entry then
@@ -237,31 +506,54 @@ decl_module! {
             //
 
             <DataObjectByContentId<T>>::insert(&content_id, data);
+
+            if let Some(channel_id) = channel_id {
+                <ChannelIdByContentId<T>>::insert(&content_id, channel_id);
+            }
+
+            if let Some(merkle_root) = merkle_root {
+                <MerkleRootByContentId<T>>::insert(&content_id, merkle_root);
+            }
+
+            Self::update_storage_usage(content_id, member_id, size, StorageUsage::reserve);
+
             Self::deposit_event(RawEvent::ContentAdded(content_id, member_id));
         }
 
         /// Storage provider accepts a content. Requires signed storage provider account and its id.
-        /// The LiaisonJudgement can be updated, but only by the liaison.
-        #[weight = 10_000_000] // TODO: adjust weight
+        /// The LiaisonJudgement can be updated, but only by the liaison. The liaison confirms the
+        /// Merkle root committed by the owner or commits the root of the received content.
+        #[weight = <T as Trait>::WeightInfo::accept_content()]
         pub(crate) fn accept_content(
             origin,
             storage_provider_id: StorageProviderId<T>,
-            content_id: T::ContentId
+            content_id: T::ContentId,
+            merkle_root: Option<T::Hash>
         ) {
             <StorageWorkingGroup<T>>::ensure_worker_signed(origin, &storage_provider_id)?;
 
+            let committed_merkle_root = Self::merkle_root_by_content_id(content_id);
+
+            if committed_merkle_root.is_some() {
+                ensure!(merkle_root == committed_merkle_root, Error::<T>::MerkleRootMismatch);
+            }
+
             // == MUTATION SAFE ==
 
             Self::update_content_judgement(&storage_provider_id, content_id, LiaisonJudgement::Accepted)?;
 
-            <KnownContentIds<T>>::mutate(|ids| ids.push(content_id));
+            if let (None, Some(merkle_root)) = (committed_merkle_root, merkle_root) {
+                <MerkleRootByContentId<T>>::insert(&content_id, merkle_root);
+            }
+
+            Self::add_known_content_id(content_id);
 
             Self::deposit_event(RawEvent::ContentAccepted(content_id, storage_provider_id));
         }
 
         /// Storage provider rejects a content. Requires signed storage provider account and its id.
         /// The LiaisonJudgement can be updated, but only by the liaison.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::reject_content()]
         pub(crate) fn reject_content(
             origin,
             storage_provider_id: StorageProviderId<T>,
@@ -275,20 +567,53 @@ decl_module! {
             Self::deposit_event(RawEvent::ContentRejected(content_id, storage_provider_id));
         }
 
+        /// Deletes the content. Member id should match its origin and the content owner.
+        #[weight = <T as Trait>::WeightInfo::delete_content()]
+        pub fn delete_content(origin, member_id: MemberId<T>, content_id: T::ContentId) {
+            T::MemberOriginValidator::ensure_actor_origin(
+                origin,
+                member_id,
+            )?;
+
+            let data = Self::data_object_by_content_id(&content_id).ok_or(Error::<T>::CidNotFound)?;
+
+            ensure!(data.owner == member_id, Error::<T>::OwnerRequired);
+
+            Self::ensure_content_not_deleted(&content_id)?;
+
+            Self::ensure_content_deletable(&content_id)?;
+
+            // == MUTATION SAFE ==
+
+            Self::delete_data_object(content_id, data);
+        }
+
+        /// Deletes the content. Requires root or storage working group leader privileges.
+        #[weight = <T as Trait>::WeightInfo::delete_content_as_lead()]
+        pub fn delete_content_as_lead(origin, content_id: T::ContentId) {
+            Self::ensure_root_or_lead(origin)?;
+
+            let data = Self::data_object_by_content_id(&content_id).ok_or(Error::<T>::CidNotFound)?;
+
+            Self::ensure_content_not_deleted(&content_id)?;
+
+            Self::ensure_content_deletable(&content_id)?;
+
+            // == MUTATION SAFE ==
+
+            Self::delete_data_object(content_id, data);
+        }
+
         // Sudo methods
 
         /// Removes the content id from the list of known content ids. Requires root privileges.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::remove_known_content_id()]
         fn remove_known_content_id(origin, content_id: T::ContentId) {
             ensure_root(origin)?;
 
             // == MUTATION SAFE ==
 
-            let upd_content_ids: Vec<T::ContentId> = Self::known_content_ids()
-                .into_iter()
-                .filter(|&id| id != content_id)
-                .collect();
-            <KnownContentIds<T>>::put(upd_content_ids);
+            Self::remove_known_content_id_from_index(content_id);
         }
 
         /// Injects a set of data objects and their corresponding content id into the directory.
@@ -296,7 +621,7 @@ decl_module! {
         /// The number of objects that can be added per call is limited to prevent the dispatch
         /// from causing the block production to fail if it takes too much time to process.
         /// Existing data objects will be overwritten.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::inject_data_objects(objects.len() as u32)]
         pub(crate) fn inject_data_objects(origin, objects: DataObjectsMap<T>) {
             ensure_root(origin)?;
 
@@ -304,22 +629,171 @@ decl_module! {
             ensure!(objects.len() <= T::MaxObjectsPerInjection::get() as usize, Error::<T>::DataObjectsInjectionExceededLimit);
 
             for (id, object) in objects.into_iter() {
-                // append to known content ids
-                // duplicates will be removed at the end
-                <KnownContentIds<T>>::mutate(|ids| ids.push(id));
+                // release the storage usage of the overwritten data object
+                if let Some(existing) = Self::data_object_by_content_id(id) {
+                    if existing.liaison_judgement != LiaisonJudgement::Rejected
+                        && !Self::is_content_deleted(&id) {
+                        Self::update_storage_usage(id, existing.owner, existing.size, StorageUsage::release);
+                    }
+                    <ChannelIdByContentId<T>>::remove(id);
+                    <ContentDeletedAt<T>>::remove(id);
+                }
+
+                if object.liaison_judgement != LiaisonJudgement::Rejected {
+                    Self::update_storage_usage(id, object.owner, object.size, StorageUsage::reserve);
+                }
+
+                // known content ids are not duplicated
+                Self::add_known_content_id(id);
                 <DataObjectByContentId<T>>::insert(id, object);
             }
+        }
+
+        /// Sets the storage quota of the members and channels without the individual quota.
+        /// Requires root or storage working group leader privileges.
+        #[weight = <T as Trait>::WeightInfo::set_default_quota()]
+        pub fn set_default_quota(origin, quota: StorageQuota) {
+            Self::ensure_root_or_lead(origin)?;
+
+            // == MUTATION SAFE ==
+
+            DefaultQuota::put(quota);
+
+            Self::deposit_event(RawEvent::DefaultQuotaUpdated(quota));
+        }
+
+        /// Sets the member storage quota, None resets it to the default quota.
+        /// Requires root or storage working group leader privileges.
+        #[weight = <T as Trait>::WeightInfo::set_member_quota()]
+        pub fn set_member_quota(origin, member_id: MemberId<T>, quota: Option<StorageQuota>) {
+            Self::ensure_root_or_lead(origin)?;
+
+            // == MUTATION SAFE ==
+
+            <MemberQuotas<T>>::mutate(member_id, |member_quota| *member_quota = quota);
+
+            Self::deposit_event(RawEvent::MemberQuotaUpdated(member_id, quota));
+        }
 
-            // remove duplicate ids
-            <KnownContentIds<T>>::mutate(|ids| {
-                ids.sort();
-                ids.dedup();
-            });
+        /// Sets the channel storage quota, None resets it to the default quota.
+        /// Requires root or storage working group leader privileges.
+        #[weight = <T as Trait>::WeightInfo::set_channel_quota()]
+        pub fn set_channel_quota(origin, channel_id: T::ChannelId, quota: Option<StorageQuota>) {
+            Self::ensure_root_or_lead(origin)?;
+
+            // == MUTATION SAFE ==
+
+            <ChannelQuotas<T>>::mutate(channel_id, |channel_quota| *channel_quota = quota);
+
+            Self::deposit_event(RawEvent::ChannelQuotaUpdated(channel_id, quota));
         }
     }
 }
 
 impl<T: Trait> Module<T> {
+    /// Returns up to `limit` known content ids starting from the `start` index.
+    pub fn known_content_ids_page(start: u64, limit: u64) -> Vec<T::ContentId> {
+        let end = start
+            .saturating_add(limit)
+            .min(Self::known_content_ids_count());
+
+        (start..end)
+            .filter_map(Self::known_content_id_by_index)
+            .collect()
+    }
+
+    /// Verifies that the content was deleted.
+    pub fn is_content_deleted(content_id: &T::ContentId) -> bool {
+        <ContentDeletedAt<T>>::contains_key(content_id)
+    }
+
+    /// Verifies that the content id is known to the system.
+    pub fn is_known_content_id(content_id: &T::ContentId) -> bool {
+        <KnownContentIdIndex<T>>::contains_key(content_id)
+    }
+
+    /// Moves the content ids from the `KnownContentIds` vector used by the previous runtime
+    /// versions to the index. Does nothing once the vector is migrated.
+    pub fn migrate_known_content_ids() -> Weight {
+        let known_content_ids: Vec<T::ContentId> =
+            match take_storage_value(b"DataDirectory", b"KnownContentIds", &[]) {
+                Some(known_content_ids) => known_content_ids,
+                None => return T::DbWeight::get().reads(1),
+            };
+
+        let count = known_content_ids.len() as Weight;
+
+        for content_id in known_content_ids {
+            Self::add_known_content_id(content_id);
+        }
+
+        T::DbWeight::get().reads_writes(1 + 2 * count, 1 + 3 * count)
+    }
+
+    /// Counts the storage usage of the members for the content added by the previous runtime
+    /// versions, which did not track it. The rejected content is not counted, the content of the
+    /// previous runtime versions has no channel. Does nothing once migrated.
+    pub fn migrate_member_storage_usage() -> Weight {
+        if Self::member_storage_usage_migrated() {
+            return T::DbWeight::get().reads(1);
+        }
+
+        let mut usage: BTreeMap<MemberId<T>, StorageUsage> = BTreeMap::new();
+        let mut data_objects_count: Weight = 0;
+
+        for (_, data_object) in <DataObjectByContentId<T>>::iter() {
+            data_objects_count += 1;
+
+            if data_object.liaison_judgement != LiaisonJudgement::Rejected {
+                usage
+                    .entry(data_object.owner)
+                    .or_default()
+                    .reserve(data_object.size);
+            }
+        }
+
+        let members_count = usage.len() as Weight;
+
+        for (member_id, member_usage) in usage {
+            <MemberStorageUsage<T>>::insert(member_id, member_usage);
+        }
+
+        MemberStorageUsageMigrated::put(true);
+
+        T::DbWeight::get().reads_writes(1 + data_objects_count, 1 + members_count)
+    }
+
+    pub(crate) fn add_known_content_id(content_id: T::ContentId) {
+        if Self::is_known_content_id(&content_id) {
+            return;
+        }
+
+        let index = Self::known_content_ids_count();
+
+        <KnownContentIdByIndex<T>>::insert(index, content_id);
+        <KnownContentIdIndex<T>>::insert(content_id, index);
+        KnownContentIdsCount::put(index + 1);
+    }
+
+    fn remove_known_content_id_from_index(content_id: T::ContentId) {
+        let index = match <KnownContentIdIndex<T>>::take(content_id) {
+            Some(index) => index,
+            None => return,
+        };
+
+        let last_index = Self::known_content_ids_count().saturating_sub(1);
+
+        // Move the last content id to the index of the removed one.
+        if let Some(last_content_id) = <KnownContentIdByIndex<T>>::take(last_index) {
+            if index != last_index {
+                <KnownContentIdByIndex<T>>::insert(index, last_content_id);
+                <KnownContentIdIndex<T>>::insert(last_content_id, index);
+            }
+        }
+
+        KnownContentIdsCount::put(last_index);
+    }
+
     fn update_content_judgement(
         storage_provider_id: &StorageProviderId<T>,
         content_id: T::ContentId,
@@ -334,22 +808,158 @@ impl<T: Trait> Module<T> {
             Error::<T>::LiaisonRequired
         );
 
+        Self::ensure_content_not_deleted(&content_id)?;
+
+        // The rejected content does not count towards the storage usage.
+        let was_rejected = data.liaison_judgement == LiaisonJudgement::Rejected;
+        let is_rejected = judgement == LiaisonJudgement::Rejected;
+
+        if !was_rejected && is_rejected {
+            Self::update_storage_usage(content_id, data.owner, data.size, StorageUsage::release);
+        } else if was_rejected && !is_rejected {
+            Self::update_storage_usage(content_id, data.owner, data.size, StorageUsage::reserve);
+        }
+
         data.liaison_judgement = judgement;
         <DataObjectByContentId<T>>::insert(content_id, data);
 
         Ok(())
     }
+
+    fn delete_data_object(content_id: T::ContentId, data: DataObject<T>) {
+        if data.liaison_judgement != LiaisonJudgement::Rejected {
+            Self::update_storage_usage(content_id, data.owner, data.size, StorageUsage::release);
+        }
+
+        // The data object is kept, no storage challenges are issued for the deleted content.
+        <ContentDeletedAt<T>>::insert(content_id, <system::Module<T>>::block_number());
+        <MerkleRootByContentId<T>>::remove(content_id);
+        Self::remove_known_content_id_from_index(content_id);
+
+        T::ContentDeletionHandler::content_deleted(&content_id);
+
+        Self::deposit_event(RawEvent::ContentDeleted(content_id, data.liaison));
+    }
+
+    fn ensure_content_not_deleted(content_id: &T::ContentId) -> DispatchResult {
+        ensure!(
+            !Self::is_content_deleted(content_id),
+            Error::<T>::ContentDeleted
+        );
+
+        Ok(())
+    }
+
+    fn ensure_content_deletable(content_id: &T::ContentId) -> DispatchResult {
+        ensure!(
+            T::ContentDeletionValidator::can_delete_content(content_id),
+            Error::<T>::ContentIsUndeletable
+        );
+
+        Ok(())
+    }
+
+    fn ensure_root_or_lead(origin: T::Origin) -> DispatchResult {
+        match ensure_root(origin.clone()) {
+            Ok(()) => Ok(()),
+            Err(_) => <StorageWorkingGroup<T>>::ensure_origin_is_active_leader(origin),
+        }
+    }
+
+    fn ensure_data_object_size_fits_type(
+        type_id: <T as data_object_type_registry::Trait>::DataObjectTypeId,
+        size: u64,
+    ) -> DispatchResult {
+        let max_object_size = <data_object_type_registry::Module<T>>::data_object_types(type_id)
+            .and_then(|data_object_type| data_object_type.max_object_size);
+
+        if let Some(max_object_size) = max_object_size {
+            ensure!(
+                size <= max_object_size,
+                Error::<T>::DataObjectSizeLimitExceeded
+            );
+        }
+
+        Ok(())
+    }
+
+    fn ensure_storage_quotas(
+        member_id: MemberId<T>,
+        channel_id: Option<T::ChannelId>,
+        size: u64,
+    ) -> DispatchResult {
+        let member_quota = Self::member_quota(member_id).unwrap_or_else(Self::default_quota);
+        ensure!(
+            Self::member_storage_usage(member_id).fits(&member_quota, size),
+            Error::<T>::MemberQuotaExceeded
+        );
+
+        if let Some(channel_id) = channel_id {
+            let channel_quota = Self::channel_quota(channel_id).unwrap_or_else(Self::default_quota);
+            ensure!(
+                Self::channel_storage_usage(channel_id).fits(&channel_quota, size),
+                Error::<T>::ChannelQuotaExceeded
+            );
+        }
+
+        Ok(())
+    }
+
+    // Updates the storage usage of the content owner and the content channel.
+    fn update_storage_usage(
+        content_id: T::ContentId,
+        owner: MemberId<T>,
+        size: u64,
+        update: fn(&mut StorageUsage, u64),
+    ) {
+        <MemberStorageUsage<T>>::mutate(owner, |usage| update(usage, size));
+
+        if let Some(channel_id) = Self::channel_id_by_content_id(content_id) {
+            <ChannelStorageUsage<T>>::mutate(channel_id, |usage| update(usage, size));
+        }
+    }
 }
 
 /// Provides random storage provider id. We use it when assign the content to the storage provider.
 pub trait StorageProviderHelper<T: Trait> {
     /// Provides random storage provider id.
     fn get_random_storage_provider() -> Result<StorageProviderId<T>, &'static str>;
+
+    /// Provides the ids of the storage providers able to keep the content: active storage working
+    /// group workers with a valid service discovery record.
+    fn get_live_storage_providers() -> Vec<StorageProviderId<T>>;
+
+    /// Makes the storage provider eligible for the content assignment (eg.: registers its
+    /// service discovery record). Used to prepare the benchmarks state.
+    #[cfg(feature = "runtime-benchmarks")]
+    fn prepare_storage_provider(_storage_provider_id: StorageProviderId<T>) {}
+}
+
+/// Content channel ownership validator.
+pub trait ChannelOwnershipValidator<T: Trait> {
+    /// Verifies that the member owns the channel.
+    fn is_channel_owner(member_id: &MemberId<T>, channel_id: &T::ChannelId) -> bool;
+}
+
+/// Handles the content deletion.
+pub trait ContentDeletionHandler<T: Trait> {
+    /// Cleans up the state related to the deleted content.
+    fn content_deleted(content_id: &T::ContentId);
+}
+
+impl<T: Trait> ContentDeletionHandler<T> for () {
+    fn content_deleted(_content_id: &T::ContentId) {}
+}
+
+/// Validates the content deletion.
+pub trait ContentDeletionValidator<T: Trait> {
+    /// Verifies that the content is not in use and can be deleted.
+    fn can_delete_content(content_id: &T::ContentId) -> bool;
 }
 
 /// Content access helper.
 pub trait ContentIdExists<T: Trait> {
-    /// Verifies the content existence.
+    /// Verifies the content existence. The deleted content does not exist.
     fn has_content(id: &T::ContentId) -> bool;
 
     /// Returns the data object for the provided content id.
@@ -359,9 +969,14 @@ pub trait ContentIdExists<T: Trait> {
 impl<T: Trait> ContentIdExists<T> for Module<T> {
     fn has_content(content_id: &T::ContentId) -> bool {
         Self::data_object_by_content_id(*content_id).is_some()
+            && !Self::is_content_deleted(content_id)
     }
 
     fn get_data_object(content_id: &T::ContentId) -> Result<DataObject<T>, &'static str> {
+        if Self::is_content_deleted(content_id) {
+            return Err(Error::<T>::ContentDeleted.into());
+        }
+
         match Self::data_object_by_content_id(*content_id) {
             Some(data) => Ok(data),
             None => Err(Error::<T>::LiaisonRequired.into()),
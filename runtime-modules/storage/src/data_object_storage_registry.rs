@@ -12,6 +12,37 @@
 //! - [add_relationship](./struct.Module.html#method.add_relationship) - Add storage provider-to-content relationship.
 //! - [set_relationship_ready](./struct.Module.html#method.set_relationship_ready)- Activates storage provider-to-content relationship.
 //! - [unset_relationship_ready](./struct.Module.html#method.unset_relationship_ready) - Deactivates storage provider-to-content relationship.
+//! - [submit_storage_proof](./struct.Module.html#method.submit_storage_proof) - Responds to the storage challenge.
+//! - [slash_for_failed_challenge](./struct.Module.html#method.slash_for_failed_challenge) - Slashes the storage provider failing the challenge.
+//! - [dismiss_failed_challenge](./struct.Module.html#method.dismiss_failed_challenge) - Dismisses the failed challenge without slashing.
+//!
+//! The module removes the relationships of the deleted content as the _Data directory_
+//! `ContentDeletionHandler`.
+//!
+//! ## Replication
+//!
+//! Every data object type defines the number of the storage providers that should keep its
+//! data objects. On each block finalization the module checks the replication of a limited number
+//! of the accepted content ids (cycling through the known content ids). The liaison and the storage
+//! providers with a relationship for the content count as replicas only while they remain active
+//! storage working group workers with a valid service discovery record. When the content lacks
+//! replicas the module creates relationships for the additional storage providers. The providers
+//! are picked deterministically from the live storage providers, starting at a position derived
+//! from the content id hash. Content ids still lacking replicas are tracked until enough storage
+//! providers become available.
+//!
+//! ## Storage challenges
+//!
+//! The content can be added with the Merkle root of its chunks (`StorageProofChunkSize` bytes each,
+//! the last one can be shorter), the liaison confirms it or commits its own on accepting the content.
+//! The leaves are the hashes of the chunks prefixed with `0x00`, the parent node is the hash of its
+//! children pair prefixed with `0x01` and the last node of the odd-length level is promoted to the
+//! next level as is. The prefixes keep the leaves and the internal nodes apart. Every `ChallengePeriod` blocks the module picks random content with the committed root,
+//! a random storage provider keeping it (the liaison or the provider with the ready relationship)
+//! and a random chunk. The challenged storage provider should submit the chunk with its Merkle proof
+//! within the `ChallengeResponsePeriod`. The role stake of the storage provider failing the challenge
+//! is slashed by the `FailedChallengeSlash` amount, or the failed challenge awaits the storage
+//! working group leader decision when the automatic slashing is disabled (zero amount) or fails.
 //!
 
 // Clippy linter requirement.
@@ -24,16 +55,62 @@
 
 use codec::{Codec, Decode, Encode};
 use frame_support::dispatch::DispatchResult;
+use frame_support::traits::{Get, Randomness};
+use frame_support::weights::Weight;
 use frame_support::{decl_error, decl_event, decl_module, decl_storage, ensure, Parameter};
-use sp_arithmetic::traits::BaseArithmetic;
-use sp_runtime::traits::{MaybeSerialize, Member};
+use sp_arithmetic::traits::{BaseArithmetic, One, Zero};
+use sp_runtime::traits::{Hash, MaybeSerialize, Member};
+use sp_std::vec;
 use sp_std::vec::Vec;
+use working_group::BalanceOf;
 
-use crate::data_directory::{self, ContentIdExists};
+use crate::data_directory::{self, ContentIdExists, LiaisonJudgement, StorageProviderHelper};
+use crate::data_object_type_registry;
 use crate::{StorageProviderId, StorageWorkingGroup, StorageWorkingGroupInstance};
 
 const DEFAULT_FIRST_RELATIONSHIP_ID: u8 = 1;
 
+// Prefixes of the hashed Merkle tree leaves and internal nodes.
+const MERKLE_LEAF_PREFIX: u8 = 0x00;
+const MERKLE_NODE_PREFIX: u8 = 0x01;
+
+/// Weight information for the _Data object storage registry_ extrinsics.
+pub trait WeightInfo {
+    fn add_relationship() -> Weight;
+    fn set_relationship_ready() -> Weight;
+    fn unset_relationship_ready() -> Weight;
+    fn submit_storage_proof(i: u32, p: u32) -> Weight;
+    fn slash_for_failed_challenge() -> Weight;
+    fn dismiss_failed_challenge() -> Weight;
+    /// `c` - number of the checked content ids.
+    fn check_content_replication(c: u32) -> Weight;
+}
+
+/// Default weights, used by the tests.
+impl WeightInfo for () {
+    fn add_relationship() -> Weight {
+        10_000_000
+    }
+    fn set_relationship_ready() -> Weight {
+        10_000_000
+    }
+    fn unset_relationship_ready() -> Weight {
+        10_000_000
+    }
+    fn submit_storage_proof(_i: u32, _p: u32) -> Weight {
+        10_000_000
+    }
+    fn slash_for_failed_challenge() -> Weight {
+        10_000_000
+    }
+    fn dismiss_failed_challenge() -> Weight {
+        10_000_000
+    }
+    fn check_content_replication(_c: u32) -> Weight {
+        10_000_000
+    }
+}
+
 /// The _Data object storage registry_ main _Trait_.
 pub trait Trait:
     pallet_timestamp::Trait
@@ -56,6 +133,31 @@ pub trait Trait:
 
     /// Ensures that a content exists
     type ContentIdExists: data_directory::ContentIdExists<Self>;
+
+    /// Maximum number of the content ids checked for the replication per block.
+    type MaxReplicationChecksPerBlock: Get<u32>;
+
+    /// Randomness source used to pick the storage challenges.
+    type Randomness: Randomness<Self::Hash>;
+
+    /// Number of blocks between the storage challenge rounds.
+    type ChallengePeriod: Get<Self::BlockNumber>;
+
+    /// Maximum number of the storage challenges issued per round.
+    type ChallengesPerPeriod: Get<u32>;
+
+    /// Number of blocks the storage provider has to respond to the storage challenge.
+    type ChallengeResponsePeriod: Get<Self::BlockNumber>;
+
+    /// Size of the content chunks the committed Merkle root is built from.
+    type StorageProofChunkSize: Get<u32>;
+
+    /// Role stake slashed from the storage provider failing the challenge.
+    /// Zero disables the automatic slashing.
+    type FailedChallengeSlash: Get<BalanceOf<Self>>;
+
+    /// Weight information for the extrinsics of this module.
+    type WeightInfo: WeightInfo;
 }
 
 decl_error! {
@@ -72,6 +174,18 @@ decl_error! {
 
         /// Require root origin in extrinsics
         RequireRootOrigin,
+
+        /// No storage challenge found for this ID.
+        StorageChallengeNotFound,
+
+        /// Only the challenged storage provider may respond to the challenge.
+        OnlyChallengedStorageProviderMayRespond,
+
+        /// Chunk or its Merkle proof doesn't match the committed Merkle root.
+        InvalidStorageProof,
+
+        /// No failed storage challenge found for this ID.
+        FailedStorageChallengeNotFound,
     }
 }
 
@@ -88,6 +202,25 @@ pub struct DataObjectStorageRelationship<T: Trait> {
     pub ready: bool,
 }
 
+/// Type for the storage challenge id.
+pub type StorageChallengeId = u64;
+
+/// Challenges the storage provider to prove it keeps the content chunk.
+#[derive(Clone, Encode, Decode, PartialEq, Debug)]
+pub struct StorageChallenge<T: Trait> {
+    /// Content id.
+    pub content_id: <T as data_directory::Trait>::ContentId,
+
+    /// Challenged storage provider id.
+    pub storage_provider_id: StorageProviderId<T>,
+
+    /// Index of the requested content chunk.
+    pub chunk_index: u64,
+
+    /// Last block to respond to the challenge.
+    pub deadline: T::BlockNumber,
+}
+
 decl_storage! {
     trait Store for Module<T: Trait> as DataObjectStorageRegistry {
 
@@ -105,6 +238,29 @@ decl_storage! {
         /// Keeps a list of storage relationships per content id.
         pub RelationshipsByContentId get(fn relationships_by_content_id): map hasher(blake2_128_concat)
             T::ContentId => Vec<T::DataObjectStorageRelationshipId>;
+
+        /// Content ids lacking the live storage providers to meet the replication factor of their
+        /// data object type. Maps to the number of the live replicas.
+        pub UnderReplicatedContentIds get(fn under_replicated_content_ids): map hasher(blake2_128_concat)
+            T::ContentId => Option<u32>;
+
+        /// Index of the known content id to start the next replication check with.
+        pub ReplicationCheckCursor get(fn replication_check_cursor): u32;
+
+        /// Defines next storage challenge id.
+        pub NextStorageChallengeId get(fn next_storage_challenge_id): StorageChallengeId;
+
+        /// Storage challenges awaiting the response.
+        pub StorageChallenges get(fn storage_challenges): map hasher(twox_64_concat)
+            StorageChallengeId => Option<StorageChallenge<T>>;
+
+        /// Ids of the storage challenges by their deadlines.
+        pub StorageChallengeIdsByDeadline get(fn storage_challenge_ids_by_deadline):
+            map hasher(twox_64_concat) T::BlockNumber => Vec<StorageChallengeId>;
+
+        /// Failed storage challenges awaiting the storage working group leader decision.
+        pub FailedStorageChallenges get(fn failed_storage_challenges): map hasher(twox_64_concat)
+            StorageChallengeId => Option<StorageChallenge<T>>;
     }
 }
 
@@ -113,7 +269,9 @@ decl_event! {
     pub enum Event<T> where
         <T as data_directory::Trait>::ContentId,
         <T as Trait>::DataObjectStorageRelationshipId,
-        StorageProviderId = StorageProviderId<T>
+        <T as system::Trait>::BlockNumber,
+        StorageProviderId = StorageProviderId<T>,
+        Balance = BalanceOf<T>
     {
         /// Emits on adding of the data object storage relationship.
         /// Params:
@@ -127,6 +285,61 @@ decl_event! {
         /// - Id of the relationship.
         /// - Current state of the relationship (True=Active).
         DataObjectStorageRelationshipReadyUpdated(DataObjectStorageRelationshipId, bool),
+
+        /// Emits on removing of the data object storage relationship of the deleted content.
+        /// Params:
+        /// - Id of the relationship.
+        /// - Id of the content.
+        /// - Id of the storage provider.
+        DataObjectStorageRelationshipRemoved(DataObjectStorageRelationshipId, ContentId, StorageProviderId),
+
+        /// Emits when the content has fewer live replicas than its data object type requires. Emits
+        /// again only when the number of the live replicas changes.
+        /// Params:
+        /// - Id of the content.
+        /// - Number of the live replicas.
+        /// - Replication factor of the data object type.
+        ContentUnderReplicated(ContentId, u32, u32),
+
+        /// Emits on assigning an additional storage provider to the under-replicated content.
+        /// Params:
+        /// - Id of the content.
+        /// - Id of the storage provider.
+        /// - Id of the created relationship.
+        ReplicaAssigned(ContentId, StorageProviderId, DataObjectStorageRelationshipId),
+
+        /// Emits on challenging the storage provider to prove it keeps the content chunk.
+        /// Params:
+        /// - Id of the storage challenge.
+        /// - Id of the content.
+        /// - Id of the storage provider.
+        /// - Index of the content chunk.
+        /// - Last block to respond.
+        StorageChallengeIssued(StorageChallengeId, ContentId, StorageProviderId, u64, BlockNumber),
+
+        /// Emits on the valid storage proof.
+        /// Params:
+        /// - Id of the storage challenge.
+        StorageChallengePassed(StorageChallengeId),
+
+        /// Emits when the storage provider doesn't respond to the challenge in time.
+        /// Params:
+        /// - Id of the storage challenge.
+        /// - Id of the content.
+        /// - Id of the storage provider.
+        StorageChallengeFailed(StorageChallengeId, ContentId, StorageProviderId),
+
+        /// Emits on slashing the storage provider for the failed challenge.
+        /// Params:
+        /// - Id of the storage challenge.
+        /// - Id of the storage provider.
+        /// - Slashed role stake balance.
+        FailedStorageChallengeSlashed(StorageChallengeId, StorageProviderId, Balance),
+
+        /// Emits on dismissing the failed challenge without slashing.
+        /// Params:
+        /// - Id of the storage challenge.
+        FailedStorageChallengeDismissed(StorageChallengeId),
     }
 }
 
@@ -139,9 +352,45 @@ decl_module! {
         /// Predefined errors.
         type Error = Error<T>;
 
+        /// Maximum number of the content ids checked for the replication per block.
+        const MaxReplicationChecksPerBlock: u32 = T::MaxReplicationChecksPerBlock::get();
+
+        /// Number of blocks between the storage challenge rounds.
+        const ChallengePeriod: T::BlockNumber = T::ChallengePeriod::get();
+
+        /// Maximum number of the storage challenges issued per round.
+        const ChallengesPerPeriod: u32 = T::ChallengesPerPeriod::get();
+
+        /// Number of blocks the storage provider has to respond to the storage challenge.
+        const ChallengeResponsePeriod: T::BlockNumber = T::ChallengeResponsePeriod::get();
+
+        /// Size of the content chunks the committed Merkle root is built from.
+        const StorageProofChunkSize: u32 = T::StorageProofChunkSize::get();
+
+        /// Role stake slashed from the storage provider failing the challenge.
+        const FailedChallengeSlash: BalanceOf<T> = T::FailedChallengeSlash::get();
+
+        /// Accounts for the replication checks performed on the block finalization.
+        fn on_initialize() -> Weight {
+            <T as Trait>::WeightInfo::check_content_replication(
+                Self::replication_checks_count() as u32,
+            )
+        }
+
+        fn on_finalize(now: T::BlockNumber) {
+            Self::expire_storage_challenges(now);
+
+            let challenge_period = T::ChallengePeriod::get();
+            if !challenge_period.is_zero() && (now % challenge_period).is_zero() {
+                Self::issue_storage_challenges(now);
+            }
+
+            Self::check_content_replication();
+        }
+
         /// Add storage provider-to-content relationship. The storage provider should be registered
         /// in the storage working group.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::add_relationship()]
         pub fn add_relationship(origin, storage_provider_id: StorageProviderId<T>, cid: T::ContentId) {
             // Origin should match storage provider.
             <StorageWorkingGroup<T>>::ensure_worker_signed(origin, &storage_provider_id)?;
@@ -149,28 +398,11 @@ decl_module! {
             // Content ID must exist
             ensure!(T::ContentIdExists::has_content(&cid), Error::<T>::CidNotFound);
 
-            // Create new ID, data.
-            let new_id = Self::next_relationship_id();
-            let dosr: DataObjectStorageRelationship<T> = DataObjectStorageRelationship {
-                content_id: cid,
-                storage_provider_id,
-                ready: false,
-            };
-
             //
             // == MUTATION SAFE ==
             //
 
-            <Relationships<T>>::insert(new_id, dosr);
-            <NextRelationshipId<T>>::mutate(|n| {
-                *n += T::DataObjectStorageRelationshipId::from(1);
-            });
-
-            // Also add the DOSR to the list of DOSRs for the CID. Uniqueness is guaranteed
-            // by the map, so we can just append the new_id to the list.
-            let mut dosr_list = Self::relationships_by_content_id(cid);
-            dosr_list.push(new_id);
-            <RelationshipsByContentId<T>>::insert(cid, dosr_list);
+            let new_id = Self::create_relationship(cid, storage_provider_id);
 
             // Emit event
             Self::deposit_event(
@@ -180,7 +412,7 @@ decl_module! {
 
         /// Activates storage provider-to-content relationship. The storage provider should be registered
         /// in the storage working group. A storage provider may flip their own ready state, but nobody else.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::set_relationship_ready()]
         pub fn set_relationship_ready(
             origin,
             storage_provider_id: StorageProviderId<T>,
@@ -191,7 +423,7 @@ decl_module! {
 
         /// Deactivates storage provider-to-content relationship. The storage provider should be registered
         /// in the storage working group. A storage provider may flip their own ready state, but nobody else.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::unset_relationship_ready()]
         pub fn unset_relationship_ready(
             origin,
             storage_provider_id: StorageProviderId<T>,
@@ -199,10 +431,509 @@ decl_module! {
         ) {
             Self::toggle_dosr_ready(origin, storage_provider_id, id, false)?;
         }
+
+        /// Responds to the storage challenge with the requested content chunk and its Merkle proof
+        /// (the sibling hashes from the chunk leaf up to the root). Can be resubmitted until the
+        /// challenge deadline.
+        #[weight = <T as Trait>::WeightInfo::submit_storage_proof(
+            chunk.len() as u32,
+            proof.len() as u32,
+        )]
+        pub fn submit_storage_proof(
+            origin,
+            storage_provider_id: StorageProviderId<T>,
+            challenge_id: StorageChallengeId,
+            chunk: Vec<u8>,
+            proof: Vec<T::Hash>
+        ) {
+            <StorageWorkingGroup<T>>::ensure_worker_signed(origin, &storage_provider_id)?;
+
+            let challenge = Self::storage_challenges(challenge_id)
+                .ok_or(Error::<T>::StorageChallengeNotFound)?;
+
+            ensure!(
+                challenge.storage_provider_id == storage_provider_id,
+                Error::<T>::OnlyChallengedStorageProviderMayRespond
+            );
+
+            let merkle_root =
+                <data_directory::Module<T>>::merkle_root_by_content_id(challenge.content_id)
+                    .ok_or(Error::<T>::CidNotFound)?;
+            let data_object = T::ContentIdExists::get_data_object(&challenge.content_id)
+                .map_err(|_| Error::<T>::CidNotFound)?;
+
+            ensure!(
+                Self::verify_storage_proof(
+                    merkle_root,
+                    data_object.size,
+                    challenge.chunk_index,
+                    &chunk,
+                    &proof
+                ),
+                Error::<T>::InvalidStorageProof
+            );
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            <StorageChallenges<T>>::remove(challenge_id);
+
+            Self::deposit_event(RawEvent::StorageChallengePassed(challenge_id));
+        }
+
+        /// Slashes the role stake of the storage provider failing the challenge.
+        /// Requires the storage working group leader origin.
+        #[weight = <T as Trait>::WeightInfo::slash_for_failed_challenge()]
+        pub fn slash_for_failed_challenge(
+            origin,
+            challenge_id: StorageChallengeId,
+            balance: BalanceOf<T>
+        ) {
+            <StorageWorkingGroup<T>>::ensure_origin_is_active_leader(origin)?;
+
+            let challenge = Self::failed_storage_challenges(challenge_id)
+                .ok_or(Error::<T>::FailedStorageChallengeNotFound)?;
+
+            <StorageWorkingGroup<T>>::slash_worker_stake(challenge.storage_provider_id, balance)?;
+
+            <FailedStorageChallenges<T>>::remove(challenge_id);
+
+            Self::deposit_event(RawEvent::FailedStorageChallengeSlashed(
+                challenge_id,
+                challenge.storage_provider_id,
+                balance,
+            ));
+        }
+
+        /// Dismisses the failed challenge without slashing the storage provider.
+        /// Requires the storage working group leader origin.
+        #[weight = <T as Trait>::WeightInfo::dismiss_failed_challenge()]
+        pub fn dismiss_failed_challenge(origin, challenge_id: StorageChallengeId) {
+            <StorageWorkingGroup<T>>::ensure_origin_is_active_leader(origin)?;
+
+            ensure!(
+                <FailedStorageChallenges<T>>::contains_key(challenge_id),
+                Error::<T>::FailedStorageChallengeNotFound
+            );
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            <FailedStorageChallenges<T>>::remove(challenge_id);
+
+            Self::deposit_event(RawEvent::FailedStorageChallengeDismissed(challenge_id));
+        }
     }
 }
 
 impl<T: Trait> Module<T> {
+    fn create_relationship(
+        cid: T::ContentId,
+        storage_provider_id: StorageProviderId<T>,
+    ) -> T::DataObjectStorageRelationshipId {
+        // Create new ID, data.
+        let new_id = Self::next_relationship_id();
+        let dosr: DataObjectStorageRelationship<T> = DataObjectStorageRelationship {
+            content_id: cid,
+            storage_provider_id,
+            ready: false,
+        };
+
+        <Relationships<T>>::insert(new_id, dosr);
+        <NextRelationshipId<T>>::mutate(|n| {
+            *n += T::DataObjectStorageRelationshipId::from(1);
+        });
+
+        // Also add the DOSR to the list of DOSRs for the CID. Uniqueness is guaranteed
+        // by the map, so we can just append the new_id to the list.
+        let mut dosr_list = Self::relationships_by_content_id(cid);
+        dosr_list.push(new_id);
+        <RelationshipsByContentId<T>>::insert(cid, dosr_list);
+
+        new_id
+    }
+
+    // Number of the known content ids checked for the replication in the block.
+    fn replication_checks_count() -> u64 {
+        <data_directory::Module<T>>::known_content_ids_count()
+            .min(T::MaxReplicationChecksPerBlock::get().into())
+    }
+
+    // Checks the replication of the next batch of the known content ids.
+    pub(crate) fn check_content_replication() {
+        let known_content_ids_count = <data_directory::Module<T>>::known_content_ids_count();
+
+        if known_content_ids_count == 0 {
+            return;
+        }
+
+        let mut live_storage_providers =
+            <T as data_directory::Trait>::StorageProviderHelper::get_live_storage_providers();
+        live_storage_providers.sort();
+        live_storage_providers.dedup();
+
+        let checks = Self::replication_checks_count();
+        let cursor = u64::from(Self::replication_check_cursor()) % known_content_ids_count;
+
+        for offset in 0..checks {
+            let index = (cursor + offset) % known_content_ids_count;
+
+            if let Some(content_id) = <data_directory::Module<T>>::known_content_id_by_index(index)
+            {
+                Self::ensure_content_replication(content_id, &live_storage_providers);
+            }
+        }
+
+        ReplicationCheckCursor::put(((cursor + checks) % known_content_ids_count) as u32);
+    }
+
+    // Assigns additional live storage providers to the accepted content lacking replicas.
+    // Expects the sorted live storage provider ids.
+    fn ensure_content_replication(
+        content_id: T::ContentId,
+        live_storage_providers: &[StorageProviderId<T>],
+    ) {
+        let data_object = match T::ContentIdExists::get_data_object(&content_id) {
+            Ok(data_object) => data_object,
+            Err(_) => return,
+        };
+
+        // Only the accepted content is available for the replication.
+        if data_object.liaison_judgement != LiaisonJudgement::Accepted {
+            return;
+        }
+
+        let replication_factor =
+            match <data_object_type_registry::Module<T>>::data_object_types(data_object.type_id) {
+                Some(data_object_type) => data_object_type.replication_factor,
+                None => return,
+            };
+
+        let mut replicas: Vec<StorageProviderId<T>> = Self::relationships_by_content_id(content_id)
+            .into_iter()
+            .filter_map(Self::relationships)
+            .map(|dosr| dosr.storage_provider_id)
+            .chain(sp_std::iter::once(data_object.liaison))
+            .filter(|id| live_storage_providers.binary_search(id).is_ok())
+            .collect();
+        replicas.sort();
+        replicas.dedup();
+
+        let mut replica_count = replicas.len() as u32;
+
+        if replica_count >= replication_factor {
+            <UnderReplicatedContentIds<T>>::remove(content_id);
+            return;
+        }
+
+        // Reports the content once per replica count change.
+        if Self::under_replicated_content_ids(content_id) != Some(replica_count) {
+            Self::deposit_event(RawEvent::ContentUnderReplicated(
+                content_id,
+                replica_count,
+                replication_factor,
+            ));
+        }
+
+        let candidates: Vec<StorageProviderId<T>> = live_storage_providers
+            .iter()
+            .filter(|id| replicas.binary_search(*id).is_err())
+            .copied()
+            .collect();
+
+        if !candidates.is_empty() {
+            // Spread the content between the storage providers, starting at the position
+            // derived from the content id.
+            let seed = Self::hash_to_u64(T::Hashing::hash_of(&content_id));
+            let start = (seed % candidates.len() as u64) as usize;
+
+            let missing = (replication_factor - replica_count) as usize;
+
+            for index in 0..candidates.len().min(missing) {
+                let storage_provider_id = candidates[(start + index) % candidates.len()];
+                let relationship_id = Self::create_relationship(content_id, storage_provider_id);
+
+                replica_count += 1;
+
+                Self::deposit_event(RawEvent::ReplicaAssigned(
+                    content_id,
+                    storage_provider_id,
+                    relationship_id,
+                ));
+            }
+        }
+
+        if replica_count < replication_factor {
+            <UnderReplicatedContentIds<T>>::insert(content_id, replica_count);
+        } else {
+            <UnderReplicatedContentIds<T>>::remove(content_id);
+        }
+    }
+
+    /// Returns the Merkle tree leaf of the content chunk.
+    pub fn merkle_leaf(chunk: &[u8]) -> T::Hash {
+        let mut leaf = Vec::with_capacity(chunk.len() + 1);
+        leaf.push(MERKLE_LEAF_PREFIX);
+        leaf.extend_from_slice(chunk);
+
+        T::Hashing::hash(&leaf)
+    }
+
+    /// Returns the Merkle tree node of the children pair.
+    pub fn merkle_node(left: T::Hash, right: T::Hash) -> T::Hash {
+        T::Hashing::hash_of(&(MERKLE_NODE_PREFIX, left, right))
+    }
+
+    /// Returns the number of the content chunks the Merkle root is built from.
+    pub fn storage_proof_chunks_count(size: u64) -> u64 {
+        let chunk_size = Self::storage_proof_chunk_size();
+
+        let chunks_count = size / chunk_size + if size % chunk_size == 0 { 0 } else { 1 };
+
+        chunks_count.max(1)
+    }
+
+    /// Computes the Merkle root of the content chunks to commit on adding the content.
+    pub fn storage_proof_merkle_root(data: &[u8]) -> T::Hash {
+        Self::merkle_tree_levels(data)
+            .pop()
+            .and_then(|root_level| root_level.first().copied())
+            .unwrap_or_default()
+    }
+
+    /// Computes the Merkle proof of the content chunk to respond to the storage challenge.
+    pub fn storage_proof(data: &[u8], chunk_index: u64) -> Vec<T::Hash> {
+        let levels = Self::merkle_tree_levels(data);
+        let mut index = chunk_index as usize;
+        let mut proof = Vec::new();
+
+        for level in levels.iter().take(levels.len().saturating_sub(1)) {
+            if let Some(sibling) = level.get(index ^ 1) {
+                proof.push(*sibling);
+            }
+
+            index /= 2;
+        }
+
+        proof
+    }
+
+    /// Verifies the content chunk and its Merkle proof against the committed Merkle root.
+    pub fn verify_storage_proof(
+        merkle_root: T::Hash,
+        size: u64,
+        chunk_index: u64,
+        chunk: &[u8],
+        proof: &[T::Hash],
+    ) -> bool {
+        let chunks_count = Self::storage_proof_chunks_count(size);
+
+        if chunk_index >= chunks_count {
+            return false;
+        }
+
+        let chunk_size = Self::storage_proof_chunk_size();
+        let chunk_start = chunk_index * chunk_size;
+        if chunk.len() as u64 != chunk_size.min(size - chunk_start) {
+            return false;
+        }
+
+        let mut node = Self::merkle_leaf(chunk);
+        let mut index = chunk_index;
+        let mut width = chunks_count;
+        let mut proof = proof.iter();
+
+        while width > 1 {
+            // The last node of the odd-length level has no sibling.
+            if index ^ 1 < width {
+                let sibling = match proof.next() {
+                    Some(sibling) => *sibling,
+                    None => return false,
+                };
+
+                node = if index % 2 == 0 {
+                    Self::merkle_node(node, sibling)
+                } else {
+                    Self::merkle_node(sibling, node)
+                };
+            }
+
+            index /= 2;
+            width = (width + 1) / 2;
+        }
+
+        proof.next().is_none() && node == merkle_root
+    }
+
+    fn storage_proof_chunk_size() -> u64 {
+        u64::from(T::StorageProofChunkSize::get().max(1))
+    }
+
+    // Returns the Merkle tree levels from the chunk leaves to the root.
+    fn merkle_tree_levels(data: &[u8]) -> Vec<Vec<T::Hash>> {
+        let mut level: Vec<T::Hash> = if data.is_empty() {
+            vec![Self::merkle_leaf(data)]
+        } else {
+            data.chunks(Self::storage_proof_chunk_size() as usize)
+                .map(Self::merkle_leaf)
+                .collect()
+        };
+
+        let mut levels = Vec::new();
+
+        while level.len() > 1 {
+            let next_level = level
+                .chunks(2)
+                .map(|pair| match pair {
+                    [left, right] => Self::merkle_node(*left, *right),
+                    _ => pair[0],
+                })
+                .collect();
+
+            levels.push(level);
+            level = next_level;
+        }
+
+        levels.push(level);
+
+        levels
+    }
+
+    // Challenges random storage providers to prove they keep random content chunks.
+    fn issue_storage_challenges(now: T::BlockNumber) {
+        let known_content_ids_count = <data_directory::Module<T>>::known_content_ids_count();
+
+        if known_content_ids_count == 0 {
+            return;
+        }
+
+        let response_period = T::ChallengeResponsePeriod::get().max(One::one());
+        let deadline = now + response_period;
+
+        for round_index in 0..T::ChallengesPerPeriod::get() {
+            let random_number = |choice: u8| {
+                let subject = (b"storage_challenge", round_index, choice).encode();
+
+                Self::hash_to_u64(T::Randomness::random(&subject))
+            };
+
+            let index = random_number(0) % known_content_ids_count;
+            let content_id = match <data_directory::Module<T>>::known_content_id_by_index(index) {
+                Some(content_id) => content_id,
+                None => continue,
+            };
+
+            // Only the content with the committed Merkle root can be challenged.
+            if !<data_directory::MerkleRootByContentId<T>>::contains_key(content_id) {
+                continue;
+            }
+
+            let data_object = match T::ContentIdExists::get_data_object(&content_id) {
+                Ok(data_object) => data_object,
+                Err(_) => continue,
+            };
+
+            if data_object.liaison_judgement != LiaisonJudgement::Accepted {
+                continue;
+            }
+
+            let mut storage_providers: Vec<StorageProviderId<T>> =
+                Self::relationships_by_content_id(content_id)
+                    .into_iter()
+                    .filter_map(Self::relationships)
+                    .filter(|dosr| dosr.ready)
+                    .map(|dosr| dosr.storage_provider_id)
+                    .chain(sp_std::iter::once(data_object.liaison))
+                    .filter(|id| {
+                        <working_group::WorkerById<T, StorageWorkingGroupInstance>>::contains_key(
+                            id,
+                        )
+                    })
+                    .collect();
+            storage_providers.sort();
+            storage_providers.dedup();
+
+            if storage_providers.is_empty() {
+                continue;
+            }
+
+            let storage_provider_id =
+                storage_providers[(random_number(1) % storage_providers.len() as u64) as usize];
+            let chunk_index = random_number(2) % Self::storage_proof_chunks_count(data_object.size);
+
+            let challenge_id = Self::next_storage_challenge_id();
+            NextStorageChallengeId::put(challenge_id + 1);
+
+            <StorageChallenges<T>>::insert(
+                challenge_id,
+                StorageChallenge {
+                    content_id,
+                    storage_provider_id,
+                    chunk_index,
+                    deadline,
+                },
+            );
+            <StorageChallengeIdsByDeadline<T>>::mutate(deadline, |ids| ids.push(challenge_id));
+
+            Self::deposit_event(RawEvent::StorageChallengeIssued(
+                challenge_id,
+                content_id,
+                storage_provider_id,
+                chunk_index,
+                deadline,
+            ));
+        }
+    }
+
+    // Fails the unanswered storage challenges with the deadline passed.
+    fn expire_storage_challenges(now: T::BlockNumber) {
+        for challenge_id in <StorageChallengeIdsByDeadline<T>>::take(now) {
+            let challenge = match <StorageChallenges<T>>::take(challenge_id) {
+                Some(challenge) => challenge,
+                None => continue,
+            };
+
+            // The challenge for the deleted content is void.
+            if !<data_directory::MerkleRootByContentId<T>>::contains_key(challenge.content_id) {
+                continue;
+            }
+
+            Self::deposit_event(RawEvent::StorageChallengeFailed(
+                challenge_id,
+                challenge.content_id,
+                challenge.storage_provider_id,
+            ));
+
+            let slash = T::FailedChallengeSlash::get();
+
+            if !slash.is_zero()
+                && <StorageWorkingGroup<T>>::slash_worker_stake(
+                    challenge.storage_provider_id,
+                    slash,
+                )
+                .is_ok()
+            {
+                Self::deposit_event(RawEvent::FailedStorageChallengeSlashed(
+                    challenge_id,
+                    challenge.storage_provider_id,
+                    slash,
+                ));
+            } else {
+                <FailedStorageChallenges<T>>::insert(challenge_id, challenge);
+            }
+        }
+    }
+
+    // Converts the hash prefix into the number.
+    fn hash_to_u64(hash: T::Hash) -> u64 {
+        hash.as_ref()
+            .iter()
+            .take(8)
+            .fold(0u64, |acc, byte| (acc << 8) | u64::from(*byte))
+    }
+
     fn toggle_dosr_ready(
         origin: T::Origin,
         storage_provider_id: StorageProviderId<T>,
@@ -232,3 +963,19 @@ impl<T: Trait> Module<T> {
         Ok(())
     }
 }
+
+impl<T: Trait> data_directory::ContentDeletionHandler<T> for Module<T> {
+    fn content_deleted(content_id: &T::ContentId) {
+        for id in <RelationshipsByContentId<T>>::take(content_id) {
+            if let Some(dosr) = <Relationships<T>>::take(id) {
+                Self::deposit_event(RawEvent::DataObjectStorageRelationshipRemoved(
+                    id,
+                    *content_id,
+                    dosr.storage_provider_id,
+                ));
+            }
+        }
+
+        <UnderReplicatedContentIds<T>>::remove(content_id);
+    }
+}
@@ -24,8 +24,11 @@
 
 use codec::{Codec, Decode, Encode};
 use frame_support::dispatch::DispatchError;
+use frame_support::traits::Get;
 use frame_support::weights::Weight;
-use frame_support::{decl_error, decl_event, decl_module, decl_storage, Parameter};
+use frame_support::{
+    decl_error, decl_event, decl_module, decl_storage, IterableStorageMap, Parameter,
+};
 use sp_arithmetic::traits::BaseArithmetic;
 use sp_runtime::traits::{MaybeSerialize, Member};
 use sp_std::vec::Vec;
@@ -34,6 +37,32 @@ use crate::{StorageWorkingGroup, StorageWorkingGroupInstance};
 
 const DEFAULT_TYPE_DESCRIPTION: &str = "Default data object type for audio and video content.";
 const DEFAULT_FIRST_DATA_OBJECT_TYPE_ID: u8 = 1;
+const DEFAULT_REPLICATION_FACTOR: u32 = 1;
+
+/// Weight information for the _Data object type registry_ extrinsics. Length parameters are
+/// measured in bytes.
+pub trait WeightInfo {
+    fn register_data_object_type(d: u32) -> Weight;
+    fn update_data_object_type(d: u32) -> Weight;
+    fn activate_data_object_type() -> Weight;
+    fn deactivate_data_object_type() -> Weight;
+}
+
+/// Default weights, used by the tests.
+impl WeightInfo for () {
+    fn register_data_object_type(_d: u32) -> Weight {
+        10_000_000
+    }
+    fn update_data_object_type(_d: u32) -> Weight {
+        10_000_000
+    }
+    fn activate_data_object_type() -> Weight {
+        10_000_000
+    }
+    fn deactivate_data_object_type() -> Weight {
+        10_000_000
+    }
+}
 
 /// The _Data object type registry_ main _Trait_.
 pub trait Trait: system::Trait + working_group::Trait<StorageWorkingGroupInstance> {
@@ -49,6 +78,9 @@ pub trait Trait: system::Trait + working_group::Trait<StorageWorkingGroupInstanc
         + Copy
         + MaybeSerialize
         + PartialEq;
+
+    /// Weight information for the extrinsics of this module.
+    type WeightInfo: WeightInfo;
 }
 
 decl_error! {
@@ -70,6 +102,12 @@ pub struct DataObjectType {
 
     /// Active/Disabled flag.
     pub active: bool,
+
+    /// Number of the live storage providers that should keep the data objects of this type.
+    pub replication_factor: u32,
+
+    /// Maximum size of the data objects of this type in bytes (None - unlimited).
+    pub max_object_size: Option<u64>,
 }
 
 impl Default for DataObjectType {
@@ -77,10 +115,20 @@ impl Default for DataObjectType {
         DataObjectType {
             description: DEFAULT_TYPE_DESCRIPTION.as_bytes().to_vec(),
             active: true,
+            replication_factor: DEFAULT_REPLICATION_FACTOR,
+            max_object_size: None,
         }
     }
 }
 
+/// 'DataObjectType' of the previous runtime versions without the replication factor and the
+/// object size limit. Used by the storage migration.
+#[derive(Encode, Decode)]
+struct LegacyDataObjectType {
+    description: Vec<u8>,
+    active: bool,
+}
+
 decl_storage! {
     trait Store for Module<T: Trait> as DataObjectTypeRegistry {
         /// Data object type ids should start at this value.
@@ -94,6 +142,9 @@ decl_storage! {
         /// Mapping of Data object types.
         pub DataObjectTypes get(fn data_object_types): map hasher(blake2_128_concat)
             T::DataObjectTypeId => Option<DataObjectType>;
+
+        /// Whether the data object types stored by the previous runtime versions were migrated.
+        pub DataObjectTypesMigrated get(fn data_object_types_migrated) build(|_: &GenesisConfig<T>| true): bool;
     }
 }
 
@@ -136,7 +187,9 @@ decl_module! {
         }
 
         /// Registers the new data object type. Requires leader privileges.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::register_data_object_type(
+            data_object_type.description.len() as u32,
+        )]
         pub fn register_data_object_type(origin, data_object_type: DataObjectType) {
             <StorageWorkingGroup<T>>::ensure_origin_is_active_leader(origin)?;
 
@@ -144,6 +197,8 @@ decl_module! {
             let do_type: DataObjectType = DataObjectType {
                 description: data_object_type.description.clone(),
                 active: data_object_type.active,
+                replication_factor: data_object_type.replication_factor,
+                max_object_size: data_object_type.max_object_size,
             };
 
             //
@@ -157,7 +212,9 @@ decl_module! {
         }
 
         /// Updates existing data object type. Requires leader privileges.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::update_data_object_type(
+            data_object_type.description.len() as u32,
+        )]
         pub fn update_data_object_type(origin, id: T::DataObjectTypeId, data_object_type: DataObjectType) {
             <StorageWorkingGroup<T>>::ensure_origin_is_active_leader(origin)?;
 
@@ -165,6 +222,8 @@ decl_module! {
 
             do_type.description = data_object_type.description.clone();
             do_type.active = data_object_type.active;
+            do_type.replication_factor = data_object_type.replication_factor;
+            do_type.max_object_size = data_object_type.max_object_size;
 
             //
             // == MUTATION SAFE ==
@@ -176,7 +235,7 @@ decl_module! {
         }
 
         /// Activates existing data object type. Requires leader privileges.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::activate_data_object_type()]
         pub fn activate_data_object_type(origin, id: T::DataObjectTypeId) {
             <StorageWorkingGroup<T>>::ensure_origin_is_active_leader(origin)?;
 
@@ -194,7 +253,7 @@ decl_module! {
         }
 
         /// Deactivates existing data object type. Requires leader privileges.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::deactivate_data_object_type()]
         pub fn deactivate_data_object_type(origin, id: T::DataObjectTypeId) {
             <StorageWorkingGroup<T>>::ensure_origin_is_active_leader(origin)?;
 
@@ -214,6 +273,29 @@ decl_module! {
 }
 
 impl<T: Trait> Module<T> {
+    /// Converts the data object types stored by the previous runtime versions. The types get the
+    /// default replication factor and no object size limit. Does nothing once migrated.
+    pub fn migrate_data_object_types() -> Weight {
+        if Self::data_object_types_migrated() {
+            return T::DbWeight::get().reads(1);
+        }
+
+        <DataObjectTypes<T>>::translate(|_, data_object_type: LegacyDataObjectType| {
+            Some(DataObjectType {
+                description: data_object_type.description,
+                active: data_object_type.active,
+                replication_factor: DEFAULT_REPLICATION_FACTOR,
+                max_object_size: None,
+            })
+        });
+
+        DataObjectTypesMigrated::put(true);
+
+        let types_count = <DataObjectTypes<T>>::iter().count() as Weight;
+
+        T::DbWeight::get().reads_writes(1 + 2 * types_count, 1 + types_count)
+    }
+
     fn ensure_data_object_type(id: T::DataObjectTypeId) -> Result<DataObjectType, DispatchError> {
         Self::data_object_types(&id).ok_or_else(|| Error::<T>::DataObjectTypeNotFound.into())
     }
@@ -5,6 +5,8 @@ pub mod data_directory;
 pub mod data_object_storage_registry;
 pub mod data_object_type_registry;
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
 mod tests;
 
 // The storage working group instance alias.
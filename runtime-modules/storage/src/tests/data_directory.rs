@@ -1,10 +1,14 @@
 #![cfg(test)]
 
 use frame_support::dispatch::DispatchError;
+use frame_support::storage::migration::{get_storage_value, put_storage_value};
+use frame_support::{StorageMap, StorageValue};
+use sp_core::H256;
 use sp_std::collections::btree_map::BTreeMap;
 use system::RawOrigin;
 
 use super::mock::*;
+use crate::data_directory::ContentIdExists;
 
 #[test]
 fn succeed_adding_content() {
@@ -15,10 +19,12 @@ fn succeed_adding_content() {
         let res = TestDataDirectory::add_content(
             Origin::signed(sender),
             member_id,
+            None,
             1,
             1234,
             0,
             vec![1, 3, 3, 7],
+            None,
         );
         assert!(res.is_ok());
     });
@@ -32,10 +38,12 @@ fn add_content_fails_with_invalid_origin() {
         let res = TestDataDirectory::add_content(
             RawOrigin::Root.into(),
             member_id,
+            None,
             1,
             1234,
             0,
             vec![1, 3, 3, 7],
+            None,
         );
         assert_eq!(res, Err(DispatchError::Other("Bad origin")));
     });
@@ -57,10 +65,12 @@ fn accept_and_reject_content_fail_with_invalid_storage_provider() {
         let res = TestDataDirectory::add_content(
             Origin::signed(sender),
             member_id,
+            None,
             1,
             1234,
             0,
             vec![1, 2, 3, 4],
+            None,
         );
         assert!(res.is_ok());
 
@@ -79,6 +89,7 @@ fn accept_and_reject_content_fail_with_invalid_storage_provider() {
             Origin::signed(storage_provider_account_id),
             storage_provider_id,
             content_id,
+            None,
         );
         assert_eq!(res, Err(working_group::Error::<Test, crate::StorageWorkingGroupInstance>::WorkerDoesNotExist.into()));
 
@@ -107,10 +118,12 @@ fn accept_content_as_liaison() {
         let res = TestDataDirectory::add_content(
             Origin::signed(sender),
             member_id,
+            None,
             1,
             1234,
             0,
             vec![1, 2, 3, 4],
+            None,
         );
         assert!(res.is_ok());
 
@@ -128,8 +141,12 @@ fn accept_content_as_liaison() {
         let (storage_provider_account_id, storage_provider_id) = hire_storage_provider();
 
         // Accepting content should not work with some random origin
-        let res =
-            TestDataDirectory::accept_content(Origin::signed(55), storage_provider_id, content_id);
+        let res = TestDataDirectory::accept_content(
+            Origin::signed(55),
+            storage_provider_id,
+            content_id,
+            None,
+        );
         assert!(res.is_err());
 
         // However, with the liaison as origin it should.
@@ -137,11 +154,75 @@ fn accept_content_as_liaison() {
             Origin::signed(storage_provider_account_id),
             storage_provider_id,
             content_id,
+            None,
         );
         assert_eq!(res, Ok(()));
     });
 }
 
+#[test]
+fn liaison_confirms_or_commits_merkle_root() {
+    with_default_mock_builder(|| {
+        let member_id = 1u64;
+        let committed_merkle_root = H256::from_low_u64_be(1);
+        let received_merkle_root = H256::from_low_u64_be(2);
+
+        let (storage_provider_account_id, storage_provider_id) = hire_storage_provider();
+
+        // The owner commits the Merkle root.
+        let res = TestDataDirectory::add_content(
+            Origin::signed(1),
+            member_id,
+            None,
+            1,
+            1,
+            1234,
+            vec![1, 2, 3, 4],
+            Some(committed_merkle_root),
+        );
+        assert!(res.is_ok());
+
+        for merkle_root in vec![None, Some(received_merkle_root)] {
+            assert_eq!(
+                TestDataDirectory::accept_content(
+                    Origin::signed(storage_provider_account_id),
+                    storage_provider_id,
+                    1,
+                    merkle_root,
+                ),
+                Err(data_directory::Error::<Test>::MerkleRootMismatch.into())
+            );
+        }
+
+        let res = TestDataDirectory::accept_content(
+            Origin::signed(storage_provider_account_id),
+            storage_provider_id,
+            1,
+            Some(committed_merkle_root),
+        );
+        assert!(res.is_ok());
+        assert_eq!(
+            TestDataDirectory::merkle_root_by_content_id(1),
+            Some(committed_merkle_root)
+        );
+
+        // The liaison commits the Merkle root of the received content.
+        assert!(add_content(member_id, None, 2, 1234).is_ok());
+
+        let res = TestDataDirectory::accept_content(
+            Origin::signed(storage_provider_account_id),
+            storage_provider_id,
+            2,
+            Some(received_merkle_root),
+        );
+        assert!(res.is_ok());
+        assert_eq!(
+            TestDataDirectory::merkle_root_by_content_id(2),
+            Some(received_merkle_root)
+        );
+    });
+}
+
 #[test]
 fn reject_content_as_liaison() {
     with_default_mock_builder(|| {
@@ -158,10 +239,12 @@ fn reject_content_as_liaison() {
         let res = TestDataDirectory::add_content(
             Origin::signed(sender),
             member_id,
+            None,
             1,
             1234,
             0,
             vec![1, 2, 3, 4],
+            None,
         );
         assert!(res.is_ok());
 
@@ -197,7 +280,7 @@ fn reject_content_as_liaison() {
 fn data_object_injection_works() {
     with_default_mock_builder(|| {
         // No objects in directory before injection
-        assert_eq!(TestDataDirectory::known_content_ids(), vec![]);
+        assert_eq!(TestDataDirectory::known_content_ids_count(), 0);
 
         // new objects to inject into the directory
         let mut objects = BTreeMap::new();
@@ -225,7 +308,7 @@ fn data_object_injection_works() {
         assert!(res.is_ok());
 
         assert_eq!(
-            TestDataDirectory::known_content_ids(),
+            TestDataDirectory::known_content_ids_page(0, 10),
             vec![content_id_1, content_id_2]
         );
 
@@ -254,19 +337,23 @@ fn data_object_injection_overwrites_and_removes_duplicate_ids() {
         let res = TestDataDirectory::add_content(
             Origin::signed(sender),
             member_id,
+            None,
             content_id_1,
             1,
             10,
             vec![8, 8, 8, 8],
+            None,
         );
         assert!(res.is_ok());
         let res = TestDataDirectory::add_content(
             Origin::signed(sender),
             member_id,
+            None,
             content_id_2,
             2,
             20,
             vec![9, 9, 9, 9],
+            None,
         );
         assert!(res.is_ok());
 
@@ -305,7 +392,7 @@ fn data_object_injection_overwrites_and_removes_duplicate_ids() {
         assert!(res.is_ok());
 
         assert_eq!(
-            TestDataDirectory::known_content_ids(),
+            TestDataDirectory::known_content_ids_page(0, 10),
             vec![content_id_1, content_id_2]
         );
 
@@ -318,5 +405,513 @@ fn data_object_injection_overwrites_and_removes_duplicate_ids() {
             TestDataDirectory::data_object_by_content_id(content_id_2),
             Some(object2)
         );
+
+        // The storage usage moves to the owners of the injected objects.
+        assert_eq!(
+            TestDataDirectory::member_storage_usage(member_id),
+            data_directory::StorageUsage::default()
+        );
+        assert_eq!(
+            TestDataDirectory::member_storage_usage(5),
+            data_directory::StorageUsage {
+                size: 6666,
+                objects: 1
+            }
+        );
+    });
+}
+
+fn add_content(
+    member_id: u64,
+    channel_id: Option<u64>,
+    content_id: u64,
+    size: u64,
+) -> frame_support::dispatch::DispatchResult {
+    TestDataDirectory::add_content(
+        Origin::signed(member_id),
+        member_id,
+        channel_id,
+        content_id,
+        1,
+        size,
+        vec![1, 2, 3, 4],
+        None,
+    )
+}
+
+#[test]
+fn add_content_fails_when_member_quota_exceeded() {
+    with_default_mock_builder(|| {
+        let member_id = 1u64;
+        let quota = data_directory::StorageQuota {
+            size_limit: 100,
+            objects_limit: 2,
+        };
+
+        let res =
+            TestDataDirectory::set_member_quota(RawOrigin::Root.into(), member_id, Some(quota));
+        assert!(res.is_ok());
+        assert_eq!(TestDataDirectory::member_quota(member_id), Some(quota));
+
+        // The size limit.
+        assert_eq!(
+            add_content(member_id, None, 1, 101),
+            Err(data_directory::Error::<Test>::MemberQuotaExceeded.into())
+        );
+
+        assert!(add_content(member_id, None, 1, 60).is_ok());
+        assert!(add_content(member_id, None, 2, 40).is_ok());
+
+        // The objects limit.
+        let res = TestDataDirectory::set_member_quota(
+            RawOrigin::Root.into(),
+            member_id,
+            Some(data_directory::StorageQuota {
+                size_limit: 1000,
+                objects_limit: 2,
+            }),
+        );
+        assert!(res.is_ok());
+        assert_eq!(
+            add_content(member_id, None, 3, 1),
+            Err(data_directory::Error::<Test>::MemberQuotaExceeded.into())
+        );
+
+        assert_eq!(
+            TestDataDirectory::member_storage_usage(member_id),
+            data_directory::StorageUsage {
+                size: 100,
+                objects: 2
+            }
+        );
+
+        // Other members use the default quota.
+        assert!(add_content(2, None, 4, 101).is_ok());
+    });
+}
+
+#[test]
+fn add_content_for_the_channel_respects_channel_ownership_and_quota() {
+    with_default_mock_builder(|| {
+        let member_id = 1u64;
+        let channel_id = 1u64;
+
+        // The channel is owned by another member.
+        assert_eq!(
+            add_content(member_id, Some(2), 1, 10),
+            Err(data_directory::Error::<Test>::ChannelOwnerRequired.into())
+        );
+
+        let res = TestDataDirectory::set_channel_quota(
+            RawOrigin::Root.into(),
+            channel_id,
+            Some(data_directory::StorageQuota {
+                size_limit: 10,
+                objects_limit: 10,
+            }),
+        );
+        assert!(res.is_ok());
+
+        assert!(add_content(member_id, Some(channel_id), 1, 10).is_ok());
+        assert_eq!(
+            TestDataDirectory::channel_id_by_content_id(1),
+            Some(channel_id)
+        );
+        assert_eq!(
+            TestDataDirectory::channel_storage_usage(channel_id),
+            data_directory::StorageUsage {
+                size: 10,
+                objects: 1
+            }
+        );
+
+        assert_eq!(
+            add_content(member_id, Some(channel_id), 2, 1),
+            Err(data_directory::Error::<Test>::ChannelQuotaExceeded.into())
+        );
+
+        // The member quota is not affected by the channel quota.
+        assert!(add_content(member_id, None, 2, 1).is_ok());
+        assert_eq!(
+            TestDataDirectory::member_storage_usage(member_id),
+            data_directory::StorageUsage {
+                size: 11,
+                objects: 2
+            }
+        );
+    });
+}
+
+#[test]
+fn add_content_fails_when_data_object_type_size_limit_exceeded() {
+    with_default_mock_builder(|| {
+        <data_object_type_registry::DataObjectTypes<Test>>::insert(
+            1,
+            TestDataObjectType {
+                description: b"limited".to_vec(),
+                active: true,
+                replication_factor: 1,
+                max_object_size: Some(100),
+            },
+        );
+
+        assert_eq!(
+            add_content(1, None, 1, 101),
+            Err(data_directory::Error::<Test>::DataObjectSizeLimitExceeded.into())
+        );
+        assert!(add_content(1, None, 1, 100).is_ok());
+    });
+}
+
+#[test]
+fn rejecting_content_releases_storage_usage() {
+    with_default_mock_builder(|| {
+        let member_id = 1u64;
+        let channel_id = 1u64;
+
+        assert!(add_content(member_id, Some(channel_id), 1, 1234).is_ok());
+
+        let (storage_provider_account_id, storage_provider_id) = hire_storage_provider();
+
+        let res = TestDataDirectory::reject_content(
+            Origin::signed(storage_provider_account_id),
+            storage_provider_id,
+            1,
+        );
+        assert!(res.is_ok());
+
+        assert_eq!(
+            TestDataDirectory::member_storage_usage(member_id),
+            data_directory::StorageUsage::default()
+        );
+        assert_eq!(
+            TestDataDirectory::channel_storage_usage(channel_id),
+            data_directory::StorageUsage::default()
+        );
+
+        // Accepting the rejected content reserves the storage again.
+        let res = TestDataDirectory::accept_content(
+            Origin::signed(storage_provider_account_id),
+            storage_provider_id,
+            1,
+            None,
+        );
+        assert!(res.is_ok());
+
+        let usage = data_directory::StorageUsage {
+            size: 1234,
+            objects: 1,
+        };
+        assert_eq!(TestDataDirectory::member_storage_usage(member_id), usage);
+        assert_eq!(TestDataDirectory::channel_storage_usage(channel_id), usage);
+    });
+}
+
+#[test]
+fn set_quotas_requires_root_or_lead() {
+    with_default_mock_builder(|| {
+        let quota = data_directory::StorageQuota {
+            size_limit: 100,
+            objects_limit: 1,
+        };
+
+        let res = TestDataDirectory::set_default_quota(Origin::signed(1), quota);
+        assert!(res.is_err());
+
+        let res = TestDataDirectory::set_member_quota(Origin::signed(1), 1, Some(quota));
+        assert!(res.is_err());
+
+        let res = TestDataDirectory::set_channel_quota(Origin::signed(1), 1, Some(quota));
+        assert!(res.is_err());
+
+        // The storage working group lead.
+        let (lead_account_id, lead_worker_id) = hire_storage_provider();
+        <working_group::CurrentLead<Test, StorageWorkingGroupInstance>>::put(lead_worker_id);
+
+        let res = TestDataDirectory::set_default_quota(Origin::signed(lead_account_id), quota);
+        assert!(res.is_ok());
+        assert_eq!(TestDataDirectory::default_quota(), quota);
+
+        // Resetting the member quota to the default one.
+        let res = TestDataDirectory::set_member_quota(RawOrigin::Root.into(), 1, Some(quota));
+        assert!(res.is_ok());
+        let res = TestDataDirectory::set_member_quota(Origin::signed(lead_account_id), 1, None);
+        assert!(res.is_ok());
+        assert_eq!(TestDataDirectory::member_quota(1), None);
+    });
+}
+
+#[test]
+fn owner_can_delete_content() {
+    with_default_mock_builder(|| {
+        run_to_block(1);
+
+        let member_id = 1u64;
+        let channel_id = 1u64;
+        let content_id = 1u64;
+
+        assert!(add_content(member_id, Some(channel_id), content_id, 1234).is_ok());
+
+        let (storage_provider_account_id, storage_provider_id) = hire_storage_provider();
+
+        let res = TestDataDirectory::accept_content(
+            Origin::signed(storage_provider_account_id),
+            storage_provider_id,
+            content_id,
+            None,
+        );
+        assert!(res.is_ok());
+
+        let res = TestDataObjectStorageRegistry::add_relationship(
+            Origin::signed(storage_provider_account_id),
+            storage_provider_id,
+            content_id,
+        );
+        assert!(res.is_ok());
+        let relationship_id = TEST_FIRST_RELATIONSHIP_ID;
+
+        // Only the owner can delete the content.
+        assert_eq!(
+            TestDataDirectory::delete_content(Origin::signed(2), 2, content_id),
+            Err(data_directory::Error::<Test>::OwnerRequired.into())
+        );
+
+        run_to_block(2);
+
+        let res = TestDataDirectory::delete_content(Origin::signed(1), member_id, content_id);
+        assert!(res.is_ok());
+
+        // The data object is kept and the content is marked as deleted.
+        assert!(TestDataDirectory::data_object_by_content_id(content_id).is_some());
+        assert!(TestDataDirectory::is_content_deleted(&content_id));
+        assert_eq!(TestDataDirectory::content_deleted_at(content_id), Some(2));
+        assert!(!TestDataDirectory::has_content(&content_id));
+        assert_eq!(
+            TestDataDirectory::channel_id_by_content_id(content_id),
+            Some(channel_id)
+        );
+        assert_eq!(TestDataDirectory::known_content_ids_count(), 0);
+        assert_eq!(
+            TestDataDirectory::member_storage_usage(member_id),
+            data_directory::StorageUsage::default()
+        );
+        assert_eq!(
+            TestDataDirectory::channel_storage_usage(channel_id),
+            data_directory::StorageUsage::default()
+        );
+
+        // The storage relationships are removed.
+        assert!(TestDataObjectStorageRegistry::relationships_by_content_id(content_id).is_empty());
+        assert_eq!(
+            TestDataObjectStorageRegistry::relationships(relationship_id),
+            None
+        );
+
+        let events: Vec<MetaEvent> = System::events()
+            .into_iter()
+            .map(|record| record.event)
+            .collect();
+        assert!(events.contains(&MetaEvent::data_object_storage_registry(
+            data_object_storage_registry::RawEvent::DataObjectStorageRelationshipRemoved(
+                relationship_id,
+                content_id,
+                storage_provider_id
+            )
+        )));
+        assert_eq!(
+            events.last(),
+            Some(&MetaEvent::data_directory(
+                data_directory::RawEvent::ContentDeleted(content_id, storage_provider_id)
+            ))
+        );
+
+        // The content is already deleted.
+        assert_eq!(
+            TestDataDirectory::delete_content(Origin::signed(1), member_id, content_id),
+            Err(data_directory::Error::<Test>::ContentDeleted.into())
+        );
+        assert_eq!(
+            TestDataDirectory::accept_content(
+                Origin::signed(storage_provider_account_id),
+                storage_provider_id,
+                content_id,
+                None,
+            ),
+            Err(data_directory::Error::<Test>::ContentDeleted.into())
+        );
+    });
+}
+
+#[test]
+fn lead_can_delete_content() {
+    with_default_mock_builder(|| {
+        let content_id = 1u64;
+
+        assert!(add_content(1, None, content_id, 1234).is_ok());
+
+        let res = TestDataDirectory::delete_content_as_lead(Origin::signed(1), content_id);
+        assert!(res.is_err());
+
+        let (lead_account_id, lead_worker_id) = hire_storage_provider();
+        <working_group::CurrentLead<Test, StorageWorkingGroupInstance>>::put(lead_worker_id);
+
+        let res =
+            TestDataDirectory::delete_content_as_lead(Origin::signed(lead_account_id), content_id);
+        assert!(res.is_ok());
+        assert!(TestDataDirectory::is_content_deleted(&content_id));
+        assert_eq!(
+            TestDataDirectory::member_storage_usage(1),
+            data_directory::StorageUsage::default()
+        );
+
+        // The root can delete the content as well.
+        let content_id = 2u64;
+        assert!(add_content(1, None, content_id, 1234).is_ok());
+
+        let res = TestDataDirectory::delete_content_as_lead(RawOrigin::Root.into(), content_id);
+        assert!(res.is_ok());
+        assert!(TestDataDirectory::is_content_deleted(&content_id));
+    });
+}
+
+#[test]
+fn content_in_use_cannot_be_deleted() {
+    with_default_mock_builder(|| {
+        let member_id = 1u64;
+        let content_id = TEST_MOCK_UNDELETABLE_CID;
+
+        assert!(add_content(member_id, None, content_id, 1234).is_ok());
+
+        assert_eq!(
+            TestDataDirectory::delete_content(Origin::signed(1), member_id, content_id),
+            Err(data_directory::Error::<Test>::ContentIsUndeletable.into())
+        );
+        assert_eq!(
+            TestDataDirectory::delete_content_as_lead(RawOrigin::Root.into(), content_id),
+            Err(data_directory::Error::<Test>::ContentIsUndeletable.into())
+        );
+
+        assert!(!TestDataDirectory::is_content_deleted(&content_id));
+        assert!(TestDataDirectory::has_content(&content_id));
+    });
+}
+
+#[test]
+fn known_content_ids_are_paged_and_reindexed_on_removal() {
+    with_default_mock_builder(|| {
+        let objects: BTreeMap<_, _> = (1..=5u64)
+            .map(|content_id| {
+                let object = data_directory::DataObjectInternal {
+                    type_id: 1,
+                    size: 1234,
+                    added_at: data_directory::BlockAndTime {
+                        block: 10,
+                        time: 1024,
+                    },
+                    owner: 1,
+                    liaison: TEST_MOCK_LIAISON_STORAGE_PROVIDER_ID,
+                    liaison_judgement: data_directory::LiaisonJudgement::Pending,
+                    ipfs_content_id: b"IPFS_CONTENT_ID".to_vec(),
+                };
+
+                (content_id, object)
+            })
+            .collect();
+
+        let res = TestDataDirectory::inject_data_objects(RawOrigin::Root.into(), objects);
+        assert!(res.is_ok());
+
+        assert_eq!(TestDataDirectory::known_content_ids_count(), 5);
+        assert_eq!(TestDataDirectory::known_content_ids_page(0, 2), vec![1, 2]);
+        assert_eq!(TestDataDirectory::known_content_ids_page(4, 2), vec![5]);
+        assert!(TestDataDirectory::known_content_ids_page(5, 2).is_empty());
+
+        // The last content id takes the index of the removed one.
+        let res = TestDataDirectory::remove_known_content_id(RawOrigin::Root.into(), 2);
+        assert!(res.is_ok());
+
+        assert_eq!(TestDataDirectory::known_content_ids_count(), 4);
+        assert_eq!(
+            TestDataDirectory::known_content_ids_page(0, 10),
+            vec![1, 5, 3, 4]
+        );
+        assert!(!TestDataDirectory::is_known_content_id(&2));
+    });
+}
+
+#[test]
+fn known_content_ids_vector_migration_works() {
+    with_default_mock_builder(|| {
+        put_storage_value(
+            b"DataDirectory",
+            b"KnownContentIds",
+            &[],
+            vec![3u64, 1u64, 3u64],
+        );
+
+        TestDataDirectory::migrate_known_content_ids();
+
+        assert_eq!(TestDataDirectory::known_content_ids_page(0, 10), vec![3, 1]);
+        assert_eq!(
+            get_storage_value::<Vec<u64>>(b"DataDirectory", b"KnownContentIds", &[]),
+            None
+        );
+
+        // Migrating again does nothing.
+        TestDataDirectory::migrate_known_content_ids();
+
+        assert_eq!(TestDataDirectory::known_content_ids_count(), 2);
+    });
+}
+
+#[test]
+fn migrate_member_storage_usage_counts_existing_content() {
+    with_default_mock_builder(|| {
+        let data_object = |size, liaison_judgement| data_directory::DataObjectInternal {
+            type_id: 1,
+            size,
+            added_at: data_directory::BlockAndTime {
+                block: 10,
+                time: 1000,
+            },
+            owner: 5,
+            liaison: TEST_MOCK_LIAISON_STORAGE_PROVIDER_ID,
+            liaison_judgement,
+            ipfs_content_id: vec![5, 6, 7],
+        };
+
+        // The content added by the previous runtime version.
+        <data_directory::DataObjectByContentId<Test>>::insert(
+            1,
+            data_object(100, data_directory::LiaisonJudgement::Accepted),
+        );
+        <data_directory::DataObjectByContentId<Test>>::insert(
+            2,
+            data_object(200, data_directory::LiaisonJudgement::Pending),
+        );
+        <data_directory::DataObjectByContentId<Test>>::insert(
+            3,
+            data_object(400, data_directory::LiaisonJudgement::Rejected),
+        );
+        data_directory::MemberStorageUsageMigrated::put(false);
+
+        TestDataDirectory::migrate_member_storage_usage();
+
+        assert_eq!(
+            TestDataDirectory::member_storage_usage(5),
+            data_directory::StorageUsage {
+                size: 300,
+                objects: 2
+            }
+        );
+
+        // Migrating again does nothing.
+        <data_directory::DataObjectByContentId<Test>>::insert(
+            4,
+            data_object(800, data_directory::LiaisonJudgement::Accepted),
+        );
+        TestDataDirectory::migrate_member_storage_usage();
+
+        assert_eq!(TestDataDirectory::member_storage_usage(5).size, 300);
     });
 }
@@ -1,5 +1,9 @@
 #![cfg(test)]
 
+use frame_support::traits::Currency;
+use frame_support::{StorageMap, StorageValue};
+use sp_core::H256;
+
 use super::mock::*;
 
 #[test]
@@ -162,3 +166,502 @@ fn test_toggle_ready() {
         );
     });
 }
+
+const REPLICATED_CONTENT_ID: u64 = 7;
+const REPLICATED_DATA_OBJECT_TYPE_ID: u64 = 5;
+
+fn hire_storage_providers(storage_provider_ids: &[u32]) {
+    for storage_provider_id in storage_provider_ids {
+        let storage_provider = working_group::Worker {
+            member_id: 1,
+            role_account_id: *storage_provider_id as u64,
+            reward_relationship: None,
+            role_stake_profile: None,
+        };
+
+        <working_group::WorkerById<Test, crate::StorageWorkingGroupInstance>>::insert(
+            storage_provider_id,
+            storage_provider,
+        );
+    }
+}
+
+// Adds the content accepted by the mock liaison (storage provider 1).
+fn add_accepted_content(replication_factor: u32) {
+    add_accepted_content_with_merkle_root(replication_factor, None);
+}
+
+fn add_accepted_content_with_merkle_root(replication_factor: u32, merkle_root: Option<H256>) {
+    <data_object_type_registry::DataObjectTypes<Test>>::insert(
+        REPLICATED_DATA_OBJECT_TYPE_ID,
+        TestDataObjectType {
+            description: b"replicated".to_vec(),
+            active: true,
+            replication_factor,
+            max_object_size: None,
+        },
+    );
+
+    assert!(TestDataDirectory::add_content(
+        Origin::signed(1),
+        1,
+        None,
+        REPLICATED_CONTENT_ID,
+        REPLICATED_DATA_OBJECT_TYPE_ID,
+        1234,
+        vec![1, 3, 3, 7],
+        merkle_root,
+    )
+    .is_ok());
+
+    // The liaison confirms the committed Merkle root.
+    assert!(TestDataDirectory::accept_content(
+        Origin::signed(1),
+        1,
+        REPLICATED_CONTENT_ID,
+        merkle_root
+    )
+    .is_ok());
+}
+
+fn replica_storage_provider_ids() -> Vec<u32> {
+    let mut storage_provider_ids: Vec<u32> =
+        TestDataObjectStorageRegistry::relationships_by_content_id(REPLICATED_CONTENT_ID)
+            .into_iter()
+            .filter_map(TestDataObjectStorageRegistry::relationships)
+            .map(|dosr| dosr.storage_provider_id)
+            .collect();
+    storage_provider_ids.sort();
+
+    storage_provider_ids
+}
+
+fn replica_assigned_events() -> Vec<u32> {
+    System::events()
+        .into_iter()
+        .filter_map(|record| match record.event {
+            MetaEvent::data_object_storage_registry(
+                data_object_storage_registry::RawEvent::ReplicaAssigned(_, storage_provider_id, _),
+            ) => Some(storage_provider_id),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn content_is_replicated_to_additional_storage_providers() {
+    with_default_mock_builder(|| {
+        run_to_block(1);
+
+        hire_storage_providers(&[1, 2, 3]);
+        add_accepted_content(3);
+
+        run_to_block(2);
+
+        assert_eq!(replica_storage_provider_ids(), vec![2, 3]);
+        assert_eq!(replica_assigned_events().len(), 2);
+        assert!(System::events().iter().any(|record| record.event
+            == MetaEvent::data_object_storage_registry(
+                data_object_storage_registry::RawEvent::ContentUnderReplicated(
+                    REPLICATED_CONTENT_ID,
+                    1,
+                    3
+                )
+            )));
+        assert_eq!(
+            TestDataObjectStorageRegistry::under_replicated_content_ids(REPLICATED_CONTENT_ID),
+            None
+        );
+
+        // Replicated content is left as is.
+        run_to_block(3);
+
+        assert_eq!(replica_storage_provider_ids(), vec![2, 3]);
+        assert_eq!(replica_assigned_events().len(), 2);
+    });
+}
+
+#[test]
+fn default_replication_factor_keeps_the_content_with_the_liaison() {
+    with_default_mock_builder(|| {
+        run_to_block(1);
+
+        hire_storage_providers(&[1, 2, 3]);
+        add_accepted_content(1);
+
+        run_to_block(2);
+
+        assert!(replica_storage_provider_ids().is_empty());
+        assert!(replica_assigned_events().is_empty());
+    });
+}
+
+#[test]
+fn content_is_rehomed_when_storage_provider_leaves() {
+    with_default_mock_builder(|| {
+        run_to_block(1);
+
+        hire_storage_providers(&[1, 2, 3]);
+        add_accepted_content(2);
+
+        run_to_block(2);
+
+        let replicas = replica_storage_provider_ids();
+        assert_eq!(replicas.len(), 1);
+
+        // The replica storage provider leaves the working group.
+        let leaving_storage_provider_id = replicas[0];
+        <working_group::WorkerById<Test, crate::StorageWorkingGroupInstance>>::remove(
+            leaving_storage_provider_id,
+        );
+
+        run_to_block(3);
+
+        let remaining_storage_provider_id = if leaving_storage_provider_id == 2 {
+            3
+        } else {
+            2
+        };
+
+        assert_eq!(
+            replica_assigned_events(),
+            vec![leaving_storage_provider_id, remaining_storage_provider_id]
+        );
+    });
+}
+
+#[test]
+fn under_replicated_content_is_tracked_until_storage_providers_are_available() {
+    with_default_mock_builder(|| {
+        run_to_block(1);
+
+        hire_storage_providers(&[1, 2]);
+        add_accepted_content(3);
+
+        run_to_block(2);
+
+        assert_eq!(replica_storage_provider_ids(), vec![2]);
+        assert_eq!(
+            TestDataObjectStorageRegistry::under_replicated_content_ids(REPLICATED_CONTENT_ID),
+            Some(2)
+        );
+
+        hire_storage_providers(&[3]);
+
+        run_to_block(3);
+
+        assert_eq!(replica_storage_provider_ids(), vec![2, 3]);
+        assert_eq!(
+            TestDataObjectStorageRegistry::under_replicated_content_ids(REPLICATED_CONTENT_ID),
+            None
+        );
+    });
+}
+
+#[test]
+fn under_replicated_content_is_reported_once_per_replica_count() {
+    with_default_mock_builder(|| {
+        run_to_block(1);
+
+        hire_storage_providers(&[1]);
+        add_accepted_content(3);
+
+        run_to_block(4);
+
+        let under_replicated_events = || {
+            System::events()
+                .into_iter()
+                .filter(|record| {
+                    matches!(
+                        record.event,
+                        MetaEvent::data_object_storage_registry(
+                            data_object_storage_registry::RawEvent::ContentUnderReplicated(..)
+                        )
+                    )
+                })
+                .count()
+        };
+
+        assert_eq!(under_replicated_events(), 1);
+
+        // The liaison leaves the working group.
+        <working_group::WorkerById<Test, crate::StorageWorkingGroupInstance>>::remove(1);
+
+        run_to_block(6);
+
+        assert_eq!(under_replicated_events(), 2);
+    });
+}
+
+// 4 chunks of the 4 bytes (the mock chunk size) and the last one of 3 bytes.
+const CHALLENGED_CONTENT: &[u8] = b"joystream content!!";
+
+fn add_challenged_content() {
+    let merkle_root = TestDataObjectStorageRegistry::storage_proof_merkle_root(CHALLENGED_CONTENT);
+
+    add_accepted_content_with_merkle_root(1, Some(merkle_root));
+
+    // The mock content size is 1234, update it to match the challenged content.
+    <data_directory::DataObjectByContentId<Test>>::mutate(REPLICATED_CONTENT_ID, |data_object| {
+        if let Some(data_object) = data_object {
+            data_object.size = CHALLENGED_CONTENT.len() as u64;
+        }
+    });
+}
+
+fn chunk(index: u64) -> Vec<u8> {
+    CHALLENGED_CONTENT
+        .chunks(4)
+        .nth(index as usize)
+        .unwrap()
+        .to_vec()
+}
+
+// Issues the storage challenge at the block 10 (the mock challenge period), returns its id.
+fn issue_storage_challenge() -> data_object_storage_registry::StorageChallengeId {
+    run_to_block(11);
+
+    let challenge_id = 0;
+    let challenge = TestDataObjectStorageRegistry::storage_challenges(challenge_id).unwrap();
+
+    assert_eq!(challenge.content_id, REPLICATED_CONTENT_ID);
+    assert_eq!(challenge.storage_provider_id, 1);
+    assert_eq!(challenge.deadline, 15);
+    assert!(System::events().iter().any(|record| record.event
+        == MetaEvent::data_object_storage_registry(
+            data_object_storage_registry::RawEvent::StorageChallengeIssued(
+                challenge_id,
+                REPLICATED_CONTENT_ID,
+                1,
+                challenge.chunk_index,
+                15
+            )
+        )));
+
+    challenge_id
+}
+
+#[test]
+fn storage_proof_verification_works() {
+    with_default_mock_builder(|| {
+        let size = CHALLENGED_CONTENT.len() as u64;
+        let merkle_root =
+            TestDataObjectStorageRegistry::storage_proof_merkle_root(CHALLENGED_CONTENT);
+
+        assert_eq!(
+            TestDataObjectStorageRegistry::storage_proof_chunks_count(size),
+            5
+        );
+
+        for chunk_index in 0..5 {
+            let proof =
+                TestDataObjectStorageRegistry::storage_proof(CHALLENGED_CONTENT, chunk_index);
+
+            assert!(TestDataObjectStorageRegistry::verify_storage_proof(
+                merkle_root,
+                size,
+                chunk_index,
+                &chunk(chunk_index),
+                &proof
+            ));
+
+            // The chunk doesn't match.
+            assert!(!TestDataObjectStorageRegistry::verify_storage_proof(
+                merkle_root,
+                size,
+                chunk_index,
+                b"fake",
+                &proof
+            ));
+
+            // The proof is incomplete.
+            assert!(!TestDataObjectStorageRegistry::verify_storage_proof(
+                merkle_root,
+                size,
+                chunk_index,
+                &chunk(chunk_index),
+                &proof[1..]
+            ));
+        }
+
+        // The chunk index is out of range.
+        assert!(!TestDataObjectStorageRegistry::verify_storage_proof(
+            merkle_root,
+            size,
+            5,
+            &[],
+            &[]
+        ));
+    });
+}
+
+#[test]
+fn storage_provider_passes_the_storage_challenge() {
+    with_default_mock_builder(|| {
+        run_to_block(1);
+
+        hire_storage_providers(&[1, 2]);
+        add_challenged_content();
+
+        let challenge_id = issue_storage_challenge();
+        let chunk_index = TestDataObjectStorageRegistry::storage_challenges(challenge_id)
+            .unwrap()
+            .chunk_index;
+        let proof = TestDataObjectStorageRegistry::storage_proof(CHALLENGED_CONTENT, chunk_index);
+
+        let res = TestDataObjectStorageRegistry::submit_storage_proof(
+            Origin::signed(2),
+            2,
+            challenge_id,
+            chunk(chunk_index),
+            proof.clone(),
+        );
+        assert_eq!(
+            res,
+            Err(
+                data_object_storage_registry::Error::<Test>::OnlyChallengedStorageProviderMayRespond
+                    .into()
+            )
+        );
+
+        let res = TestDataObjectStorageRegistry::submit_storage_proof(
+            Origin::signed(1),
+            1,
+            challenge_id,
+            b"fake".to_vec(),
+            proof.clone(),
+        );
+        assert_eq!(
+            res,
+            Err(data_object_storage_registry::Error::<Test>::InvalidStorageProof.into())
+        );
+
+        let res = TestDataObjectStorageRegistry::submit_storage_proof(
+            Origin::signed(1),
+            1,
+            challenge_id,
+            chunk(chunk_index),
+            proof,
+        );
+        assert!(res.is_ok());
+        assert_eq!(
+            System::events().last().unwrap().event,
+            MetaEvent::data_object_storage_registry(
+                data_object_storage_registry::RawEvent::StorageChallengePassed(challenge_id)
+            )
+        );
+        assert_eq!(
+            TestDataObjectStorageRegistry::storage_challenges(challenge_id),
+            None
+        );
+
+        run_to_block(16);
+
+        assert_eq!(
+            TestDataObjectStorageRegistry::failed_storage_challenges(challenge_id),
+            None
+        );
+    });
+}
+
+#[test]
+fn failed_storage_challenge_slashes_the_role_stake() {
+    with_default_mock_builder(|| {
+        run_to_block(1);
+
+        hire_storage_providers(&[1]);
+        add_challenged_content();
+
+        // Stake for the storage provider role.
+        let stake_id = stake::Module::<Test>::create_stake();
+        let _ = Balances::deposit_creating(&1, 100);
+        assert!(stake::Module::<Test>::stake_from_account(&stake_id, &1, 100).is_ok());
+
+        <working_group::WorkerById<Test, crate::StorageWorkingGroupInstance>>::mutate(
+            1,
+            |worker| {
+                worker.role_stake_profile = Some(working_group::RoleStakeProfile::new(
+                    &stake_id, &None, &None,
+                ));
+            },
+        );
+
+        let challenge_id = issue_storage_challenge();
+
+        run_to_block(16);
+
+        assert!(System::events().iter().any(|record| record.event
+            == MetaEvent::data_object_storage_registry(
+                data_object_storage_registry::RawEvent::StorageChallengeFailed(
+                    challenge_id,
+                    REPLICATED_CONTENT_ID,
+                    1
+                )
+            )));
+        assert!(System::events().iter().any(|record| record.event
+            == MetaEvent::data_object_storage_registry(
+                data_object_storage_registry::RawEvent::FailedStorageChallengeSlashed(
+                    challenge_id,
+                    1,
+                    10
+                )
+            )));
+        assert_eq!(stake::Module::<Test>::stake_pool_balance(), 90);
+        assert_eq!(
+            TestDataObjectStorageRegistry::failed_storage_challenges(challenge_id),
+            None
+        );
+    });
+}
+
+#[test]
+fn failed_storage_challenge_awaits_the_lead_decision() {
+    with_default_mock_builder(|| {
+        run_to_block(1);
+
+        // The storage provider has no role stake to slash automatically.
+        hire_storage_providers(&[1, 5]);
+        <working_group::CurrentLead<Test, crate::StorageWorkingGroupInstance>>::put(5);
+        add_challenged_content();
+
+        let challenge_id = issue_storage_challenge();
+
+        run_to_block(16);
+
+        assert!(TestDataObjectStorageRegistry::failed_storage_challenges(challenge_id).is_some());
+
+        let res = TestDataObjectStorageRegistry::dismiss_failed_challenge(
+            Origin::signed(1),
+            challenge_id,
+        );
+        assert!(res.is_err());
+
+        let res = TestDataObjectStorageRegistry::slash_for_failed_challenge(
+            Origin::signed(5),
+            challenge_id,
+            10,
+        );
+        assert_eq!(
+            res,
+            Err(working_group::Error::<Test, crate::StorageWorkingGroupInstance>::NoWorkerStakeProfile.into())
+        );
+
+        let res = TestDataObjectStorageRegistry::dismiss_failed_challenge(
+            Origin::signed(5),
+            challenge_id,
+        );
+        assert!(res.is_ok());
+        assert_eq!(
+            System::events().last().unwrap().event,
+            MetaEvent::data_object_storage_registry(
+                data_object_storage_registry::RawEvent::FailedStorageChallengeDismissed(
+                    challenge_id
+                )
+            )
+        );
+        assert_eq!(
+            TestDataObjectStorageRegistry::failed_storage_challenges(challenge_id),
+            None
+        );
+    });
+}
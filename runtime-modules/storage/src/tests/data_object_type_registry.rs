@@ -62,6 +62,8 @@ fn succeed_register() {
         let data: TestDataObjectType = TestDataObjectType {
             description: "foo".as_bytes().to_vec(),
             active: false,
+            replication_factor: 1,
+            max_object_size: None,
         };
         let res = TestDataObjectTypeRegistry::register_data_object_type(
             RawOrigin::Signed(DEFAULT_LEADER_ACCOUNT_ID).into(),
@@ -87,6 +89,8 @@ fn activate_data_object_type_fails_with_invalid_lead() {
         let data: TestDataObjectType = TestDataObjectType {
             description: "foo".as_bytes().to_vec(),
             active: false,
+            replication_factor: 1,
+            max_object_size: None,
         };
         let id_res = TestDataObjectTypeRegistry::register_data_object_type(
             RawOrigin::Signed(DEFAULT_LEADER_ACCOUNT_ID).into(),
@@ -127,6 +131,8 @@ fn deactivate_data_object_type_fails_with_invalid_lead() {
         let data: TestDataObjectType = TestDataObjectType {
             description: "foo".as_bytes().to_vec(),
             active: true,
+            replication_factor: 1,
+            max_object_size: None,
         };
         let id_res = TestDataObjectTypeRegistry::register_data_object_type(
             RawOrigin::Signed(DEFAULT_LEADER_ACCOUNT_ID).into(),
@@ -167,6 +173,8 @@ fn update_data_object_type_fails_with_invalid_lead() {
         let data: TestDataObjectType = TestDataObjectType {
             description: "foo".as_bytes().to_vec(),
             active: false,
+            replication_factor: 1,
+            max_object_size: None,
         };
         let id_res = TestDataObjectTypeRegistry::register_data_object_type(
             RawOrigin::Signed(DEFAULT_LEADER_ACCOUNT_ID).into(),
@@ -178,6 +186,8 @@ fn update_data_object_type_fails_with_invalid_lead() {
         let updated1: TestDataObjectType = TestDataObjectType {
             description: "bar".as_bytes().to_vec(),
             active: false,
+            replication_factor: 1,
+            max_object_size: None,
         };
 
         let invalid_leader_account_id = 2;
@@ -212,6 +222,8 @@ fn update_existing() {
         let data: TestDataObjectType = TestDataObjectType {
             description: "foo".as_bytes().to_vec(),
             active: false,
+            replication_factor: 1,
+            max_object_size: None,
         };
         let id_res = TestDataObjectTypeRegistry::register_data_object_type(
             RawOrigin::Signed(DEFAULT_LEADER_ACCOUNT_ID).into(),
@@ -226,6 +238,8 @@ fn update_existing() {
         let updated1: TestDataObjectType = TestDataObjectType {
             description: "bar".as_bytes().to_vec(),
             active: false,
+            replication_factor: 1,
+            max_object_size: None,
         };
         let res = TestDataObjectTypeRegistry::update_data_object_type(
             RawOrigin::Signed(DEFAULT_LEADER_ACCOUNT_ID).into(),
@@ -238,13 +252,19 @@ fn update_existing() {
         let updated3: TestDataObjectType = TestDataObjectType {
             description: "bar".as_bytes().to_vec(),
             active: false,
+            replication_factor: 3,
+            max_object_size: None,
         };
         let res = TestDataObjectTypeRegistry::update_data_object_type(
             RawOrigin::Signed(DEFAULT_LEADER_ACCOUNT_ID).into(),
             dot_id,
-            updated3,
+            updated3.clone(),
         );
         assert!(res.is_ok());
+        assert_eq!(
+            TestDataObjectTypeRegistry::data_object_types(dot_id),
+            Some(updated3)
+        );
         assert_eq!(
             *System::events().last().unwrap(),
             EventRecord {
@@ -265,6 +285,8 @@ fn register_data_object_type_failed_with_no_lead() {
         let data: TestDataObjectType = TestDataObjectType {
             description: "foo".as_bytes().to_vec(),
             active: false,
+            replication_factor: 1,
+            max_object_size: None,
         };
         let id_res = TestDataObjectTypeRegistry::register_data_object_type(
             RawOrigin::Signed(DEFAULT_LEADER_ACCOUNT_ID).into(),
@@ -292,6 +314,8 @@ fn activate_existing() {
         let data: TestDataObjectType = TestDataObjectType {
             description: "foo".as_bytes().to_vec(),
             active: false,
+            replication_factor: 1,
+            max_object_size: None,
         };
         let id_res = TestDataObjectTypeRegistry::register_data_object_type(
             RawOrigin::Signed(DEFAULT_LEADER_ACCOUNT_ID).into(),
@@ -351,3 +375,28 @@ fn activate_existing() {
         assert!(!data.unwrap().active);
     });
 }
+
+#[test]
+fn migrate_data_object_types_adds_default_replication_factor() {
+    with_default_mock_builder(|| {
+        // The data object type stored by the previous runtime version: description and flag.
+        frame_support::storage::unhashed::put(
+            &<data_object_type_registry::DataObjectTypes<Test>>::hashed_key_for(7),
+            &(b"legacy".to_vec(), true),
+        );
+        data_object_type_registry::DataObjectTypesMigrated::put(false);
+
+        TestDataObjectTypeRegistry::migrate_data_object_types();
+
+        assert_eq!(
+            TestDataObjectTypeRegistry::data_object_types(7),
+            Some(TestDataObjectType {
+                description: b"legacy".to_vec(),
+                active: true,
+                replication_factor: 1,
+                max_object_size: None,
+            })
+        );
+        assert!(TestDataObjectTypeRegistry::data_object_types_migrated());
+    });
+}
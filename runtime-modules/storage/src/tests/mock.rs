@@ -1,12 +1,12 @@
 #![cfg(test)]
 
 use frame_support::storage::StorageMap;
-use frame_support::traits::{OnFinalize, OnInitialize};
+use frame_support::traits::{OnFinalize, OnInitialize, Randomness};
 use frame_support::{impl_outer_event, impl_outer_origin, parameter_types};
 use sp_core::H256;
 use sp_runtime::{
     testing::Header,
-    traits::{BlakeTwo256, IdentityLookup},
+    traits::{BlakeTwo256, Hash, IdentityLookup},
     Perbill,
 };
 
@@ -38,6 +38,7 @@ impl_outer_event! {
         balances<T>,
         members<T>,
         working_group_mod StorageWorkingGroupInstance <T>,
+        recurringrewards<T>,
         system<T>,
     }
 }
@@ -49,6 +50,7 @@ pub const TEST_FIRST_METADATA_ID: u64 = 4000;
 
 pub const TEST_MOCK_LIAISON_STORAGE_PROVIDER_ID: u32 = 1;
 pub const TEST_MOCK_EXISTING_CID: u64 = 42;
+pub const TEST_MOCK_UNDELETABLE_CID: u64 = 43;
 
 pub struct AnyDataObjectTypeIsActive {}
 impl<T: data_object_type_registry::Trait> IsActiveDataObjectType<T> for AnyDataObjectTypeIsActive {
@@ -60,7 +62,8 @@ impl<T: data_object_type_registry::Trait> IsActiveDataObjectType<T> for AnyDataO
 pub struct MockContent {}
 impl ContentIdExists<Test> for MockContent {
     fn has_content(which: &<Test as data_directory::Trait>::ContentId) -> bool {
-        *which == TEST_MOCK_EXISTING_CID
+        // The content added to the data directory exists as well (used by the benchmarks).
+        *which == TEST_MOCK_EXISTING_CID || TestDataDirectory::has_content(which)
     }
 
     fn get_data_object(
@@ -79,11 +82,20 @@ impl ContentIdExists<Test> for MockContent {
                 liaison_judgement: data_directory::LiaisonJudgement::Pending,
                 ipfs_content_id: vec![],
             }),
-            _ => Err("nope, missing"),
+            // Fall back to the data directory for the content added by the tests.
+            _ => TestDataDirectory::get_data_object(which),
         }
     }
 }
 
+// Deterministic randomness depending on the subject and the block number.
+pub struct TestRandomness;
+impl Randomness<H256> for TestRandomness {
+    fn random(subject: &[u8]) -> H256 {
+        BlakeTwo256::hash_of(&(subject, System::block_number()))
+    }
+}
+
 // Workaround for https://github.com/rust-lang/rust/issues/26925 . Remove when sorted.
 #[derive(Clone, PartialEq, Eq, Debug)]
 pub struct Test;
@@ -94,6 +106,12 @@ parameter_types! {
     pub const AvailableBlockRatio: Perbill = Perbill::one();
     pub const MinimumPeriod: u64 = 5;
     pub const MaxObjectsPerInjection: u32 = 5;
+    pub const MaxReplicationChecksPerBlock: u32 = 5;
+    pub const ChallengePeriod: u64 = 10;
+    pub const ChallengesPerPeriod: u32 = 1;
+    pub const ChallengeResponsePeriod: u64 = 5;
+    pub const StorageProofChunkSize: u32 = 4;
+    pub const FailedChallengeSlash: u64 = 10;
 }
 
 impl system::Trait for Test {
@@ -153,26 +171,51 @@ parameter_types! {
 impl working_group::Trait<StorageWorkingGroupInstance> for Test {
     type Event = MetaEvent;
     type MaxWorkerNumberLimit = MaxWorkerNumberLimit;
+    type WeightInfo = ();
 }
 
 impl data_object_type_registry::Trait for Test {
     type Event = MetaEvent;
     type DataObjectTypeId = u64;
+    type WeightInfo = ();
 }
 
 impl data_directory::Trait for Test {
     type Event = MetaEvent;
     type ContentId = u64;
+    type ChannelId = u64;
+    type ChannelOwnershipValidator = ();
+    type ContentDeletionHandler = TestDataObjectStorageRegistry;
+    type ContentDeletionValidator = ();
     type StorageProviderHelper = ();
     type IsActiveDataObjectType = AnyDataObjectTypeIsActive;
     type MemberOriginValidator = ();
     type MaxObjectsPerInjection = MaxObjectsPerInjection;
+    type WeightInfo = ();
 }
 
 impl crate::data_directory::StorageProviderHelper<Test> for () {
     fn get_random_storage_provider() -> Result<u32, &'static str> {
         Ok(1)
     }
+
+    fn get_live_storage_providers() -> Vec<u32> {
+        working_group::Module::<Test, StorageWorkingGroupInstance>::get_all_worker_ids()
+    }
+}
+
+// The channel is owned by the member with the same id.
+impl crate::data_directory::ChannelOwnershipValidator<Test> for () {
+    fn is_channel_owner(member_id: &u64, channel_id: &u64) -> bool {
+        member_id == channel_id
+    }
+}
+
+// The content with the predefined id is in use and cannot be deleted.
+impl crate::data_directory::ContentDeletionValidator<Test> for () {
+    fn can_delete_content(content_id: &u64) -> bool {
+        *content_id != TEST_MOCK_UNDELETABLE_CID
+    }
 }
 
 impl common::origin::ActorOriginValidator<Origin, u64, u64> for () {
@@ -187,6 +230,14 @@ impl data_object_storage_registry::Trait for Test {
     type Event = MetaEvent;
     type DataObjectStorageRelationshipId = u64;
     type ContentIdExists = MockContent;
+    type MaxReplicationChecksPerBlock = MaxReplicationChecksPerBlock;
+    type Randomness = TestRandomness;
+    type ChallengePeriod = ChallengePeriod;
+    type ChallengesPerPeriod = ChallengesPerPeriod;
+    type ChallengeResponsePeriod = ChallengeResponsePeriod;
+    type StorageProofChunkSize = StorageProofChunkSize;
+    type FailedChallengeSlash = FailedChallengeSlash;
+    type WeightInfo = ();
 }
 
 impl membership::Trait for Test {
@@ -195,6 +246,7 @@ impl membership::Trait for Test {
     type SubscriptionId = u32;
     type PaidTermId = u32;
     type ActorId = u32;
+    type WeightInfo = ();
 }
 
 impl stake::Trait for Test {
@@ -205,12 +257,20 @@ impl stake::Trait for Test {
     type SlashId = u64;
 }
 
+parameter_types! {
+    pub const MintSpendingEpochLength: u64 = 100;
+}
+
 impl minting::Trait for Test {
     type Currency = Balances;
     type MintId = u64;
+    type SpendingEpochLength = MintSpendingEpochLength;
+    type MintCapacityIncreased = ();
+    type WeightInfo = ();
 }
 
 impl recurringrewards::Trait for Test {
+    type Event = MetaEvent;
     type PayoutStatusHandler = ();
     type RecipientId = u64;
     type RewardRelationshipId = u64;
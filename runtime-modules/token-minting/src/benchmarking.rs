@@ -0,0 +1,38 @@
+//! Token minting module benchmarking.
+
+use super::*;
+use frame_benchmarking::benchmarks;
+use system::RawOrigin;
+
+fn interval_adjustment<T: Trait>() -> Adjustment<BalanceOf<T>, T::BlockNumber> {
+    Adjustment::Interval(AdjustOnInterval {
+        block_interval: 100u32.into(),
+        adjustment_type: AdjustCapacityBy::Adding(1000u32.into()),
+    })
+}
+
+benchmarks! {
+    _ { }
+
+    // The worst case replaces the scheduled adjustment of the mint.
+    set_mint_adjustment {
+        let mint_id = <Module<T>>::add_mint(0u32.into(), Some(interval_adjustment::<T>()))
+            .expect("Mint is created");
+    }: _(RawOrigin::Root, mint_id, Some(interval_adjustment::<T>()))
+    verify {
+        assert!(<Module<T>>::mints(mint_id).next_adjustment().is_some());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::{build_test_externalities, Test};
+
+    #[test]
+    fn minting_benchmarks_succeed() {
+        build_test_externalities().execute_with(|| {
+            assert!(test_benchmark_set_mint_adjustment::<Test>().is_ok());
+        });
+    }
+}
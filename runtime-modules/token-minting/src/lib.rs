@@ -7,12 +7,19 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use codec::{Codec, Decode, Encode};
-use frame_support::storage::IterableStorageMap;
-use frame_support::traits::Currency;
+use frame_support::storage::{IterableStorageDoubleMap, IterableStorageMap};
+use frame_support::traits::{Currency, Get};
+use frame_support::weights::Weight;
 use frame_support::{decl_module, decl_storage, ensure, Parameter};
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
 use sp_arithmetic::traits::{BaseArithmetic, One, Zero};
 use sp_runtime::traits::{MaybeSerialize, Member};
+use sp_std::vec::Vec;
+use system::ensure_root;
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
 mod mint;
 mod mock;
 mod tests;
@@ -32,6 +39,44 @@ pub trait Trait: system::Trait {
         + Copy
         + MaybeSerialize
         + PartialEq;
+
+    /// The number of blocks in a spending epoch of the mints.
+    type SpendingEpochLength: Get<Self::BlockNumber>;
+
+    /// Handles the mint capacity top-ups not requested by the other modules: the scheduled
+    /// adjustments and the capacity returned by the child mints.
+    type MintCapacityIncreased: MintCapacityIncreased<Self::MintId>;
+
+    /// Weight information for the extrinsics of this module.
+    type WeightInfo: WeightInfo;
+}
+
+/// Weight information for the minting extrinsics.
+pub trait WeightInfo {
+    fn set_mint_adjustment() -> Weight;
+}
+
+/// Default weights, used by the tests.
+impl WeightInfo for () {
+    fn set_mint_adjustment() -> Weight {
+        10_000_000
+    }
+}
+
+/// Hook for the mint capacity top-ups made by the module itself.
+pub trait MintCapacityIncreased<MintId> {
+    fn mint_capacity_increased(mint_id: MintId);
+
+    /// The maximum weight of handling a top-up, accounted when initializing the block.
+    fn mint_capacity_increased_weight() -> Weight;
+}
+
+impl<MintId> MintCapacityIncreased<MintId> for () {
+    fn mint_capacity_increased(_mint_id: MintId) {}
+
+    fn mint_capacity_increased_weight() -> Weight {
+        0
+    }
 }
 
 pub type BalanceOf<T> =
@@ -41,6 +86,15 @@ pub type BalanceOf<T> =
 pub enum GeneralError {
     MintNotFound,
     NextAdjustmentInPast,
+    NotEnoughParentCapacity,
+}
+
+/// Errors that can arise from attempt to set the parent of a mint.
+#[derive(PartialEq, Eq, Debug)]
+pub enum MintHierarchyError {
+    MintNotFound,
+    ParentMintNotFound,
+    MintIsOwnAncestor,
 }
 
 /// Errors that can arise from attempt to mint and transfer tokens from a mint to
@@ -80,6 +134,17 @@ impl From<GeneralError> for &'static str {
         match err {
             GeneralError::MintNotFound => "MintNotFound",
             GeneralError::NextAdjustmentInPast => "NextAdjustmentInPast",
+            GeneralError::NotEnoughParentCapacity => "NotEnoughParentCapacity",
+        }
+    }
+}
+
+impl From<MintHierarchyError> for &'static str {
+    fn from(err: MintHierarchyError) -> &'static str {
+        match err {
+            MintHierarchyError::MintNotFound => "MintNotFound",
+            MintHierarchyError::ParentMintNotFound => "ParentMintNotFound",
+            MintHierarchyError::MintIsOwnAncestor => "MintIsOwnAncestor",
         }
     }
 }
@@ -93,8 +158,9 @@ impl From<TransferError> for &'static str {
     }
 }
 
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Encode, Decode, Copy, Clone, Debug, Eq, PartialEq)]
-pub enum Adjustment<Balance: Zero, BlockNumber> {
+pub enum Adjustment<Balance, BlockNumber> {
     // First adjustment will be after AdjustOnInterval.block_interval
     Interval(AdjustOnInterval<Balance, BlockNumber>),
     // First Adjustment will be at absolute blocknumber
@@ -103,6 +169,42 @@ pub enum Adjustment<Balance: Zero, BlockNumber> {
     IntervalAfterFirstAdjustmentRelative(AdjustOnInterval<Balance, BlockNumber>, BlockNumber),
 }
 
+/// Recipient category of the tokens minted.
+#[derive(Encode, Decode, Copy, Clone, Debug, Eq, PartialEq)]
+pub enum SpendingCategory {
+    /// Recurring rewards of the workers and the council members
+    Rewards,
+    /// Spending proposals approved by the council
+    SpendingProposals,
+}
+
+/// Tokens minted by a mint within a spending epoch, by the recipient category.
+#[derive(Encode, Decode, Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct MintSpending<Balance> {
+    /// Tokens minted for the recurring rewards
+    pub rewards: Balance,
+
+    /// Tokens minted for the spending proposals
+    pub spending_proposals: Balance,
+}
+
+impl<Balance: BaseArithmetic + Copy> MintSpending<Balance> {
+    /// Adds the amount to the spending of the category.
+    pub fn add(&mut self, category: SpendingCategory, amount: Balance) {
+        match category {
+            SpendingCategory::Rewards => self.rewards = self.rewards.saturating_add(amount),
+            SpendingCategory::SpendingProposals => {
+                self.spending_proposals = self.spending_proposals.saturating_add(amount)
+            }
+        }
+    }
+
+    /// Total tokens minted.
+    pub fn total(&self) -> Balance {
+        self.rewards.saturating_add(self.spending_proposals)
+    }
+}
+
 decl_storage! {
     trait Store for Module<T: Trait> as TokenMint {
         /// Mints
@@ -110,48 +212,190 @@ decl_storage! {
 
         /// The number of mints created.
         pub MintsCreated get(fn mints_created): T::MintId;
+
+        /// Mints scheduled for a capacity adjustment at the block. Only the mints due are
+        /// visited when finalizing the block.
+        pub MintIdsByAdjustmentBlock get(fn mint_ids_by_adjustment_block): double_map
+            hasher(twox_64_concat) T::BlockNumber, hasher(twox_64_concat) T::MintId => ();
+
+        /// Whether the adjustments of the existing mints were scheduled.
+        pub AdjustmentScheduleMigrated get(fn adjustment_schedule_migrated): bool;
+
+        /// The parent of the mint. Capacity top-ups of the mint are drawn from its parent.
+        pub ParentMintIds get(fn parent_mint_id): map hasher(blake2_128_concat)
+            T::MintId => Option<T::MintId>;
+
+        /// Child mints by the parent mint.
+        pub ChildMintIds: double_map hasher(blake2_128_concat) T::MintId,
+            hasher(blake2_128_concat) T::MintId => ();
+
+        /// Tokens minted by the mint within the spending epoch.
+        pub MintSpendingByEpoch get(fn mint_spending): double_map hasher(blake2_128_concat) T::MintId,
+            hasher(twox_64_concat) T::BlockNumber => MintSpending<BalanceOf<T>>;
     }
 }
 // pub Account: map hasher(blake2_128_concat) T::AccountId => AccountData<T::Balance>;
 decl_module! {
     pub struct Module<T: Trait> for enum Call where origin: T::Origin {
+        fn on_initialize(now: T::BlockNumber) -> Weight {
+            // The mints due are updated when finalizing the block. The mint and its parent can
+            // both be topped up.
+            let due_mints_count = <MintIdsByAdjustmentBlock<T>>::iter_prefix(now).count() as Weight;
+
+            T::DbWeight::get()
+                .reads_writes(1 + 4 * due_mints_count, 1 + 3 * due_mints_count)
+                .saturating_add(
+                    due_mints_count.saturating_mul(
+                        2 * T::MintCapacityIncreased::mint_capacity_increased_weight(),
+                    ),
+                )
+        }
+
         fn on_finalize(now: T::BlockNumber) {
             Self::update_mints(now);
         }
+
+        /// Sets the capacity adjustment of the mint, replacing its upcoming adjustment.
+        /// Passing no adjustment pauses the mint.
+        #[weight = <T as Trait>::WeightInfo::set_mint_adjustment()]
+        pub fn set_mint_adjustment(
+            origin,
+            mint_id: T::MintId,
+            adjustment: Option<Adjustment<BalanceOf<T>, T::BlockNumber>>
+        ) {
+            ensure_root(origin)?;
+
+            Self::change_mint_adjustment(mint_id, adjustment).map_err(<&str>::from)?;
+        }
     }
 }
 
 impl<T: Trait> Module<T> {
     fn update_mints(now: T::BlockNumber) {
-        // Are we reading value from storage twice?
-        for (mint_id, ref mut mint) in <Mints<T>>::iter() {
+        let due_mint_ids: Vec<T::MintId> = <MintIdsByAdjustmentBlock<T>>::iter_prefix(now)
+            .map(|(mint_id, _)| mint_id)
+            .collect();
+
+        <MintIdsByAdjustmentBlock<T>>::remove_prefix(now);
+
+        for mint_id in due_mint_ids {
+            if !<Mints<T>>::contains_key(&mint_id) {
+                continue;
+            }
+
+            let mut mint = Self::mints(&mint_id);
+            let previous_capacity = mint.capacity();
+
             if mint.maybe_do_capacity_adjustment(now) {
+                let topped_up_parent_mint_id =
+                    Self::settle_adjustment_with_parent(mint_id, &mut mint, previous_capacity);
+                let topped_up = mint.capacity() > previous_capacity;
+
+                Self::schedule_adjustment(mint_id, &mint);
                 <Mints<T>>::insert(&mint_id, mint);
+
+                if topped_up {
+                    T::MintCapacityIncreased::mint_capacity_increased(mint_id);
+                }
+
+                if let Some(parent_mint_id) = topped_up_parent_mint_id {
+                    T::MintCapacityIncreased::mint_capacity_increased(parent_mint_id);
+                }
             }
         }
     }
 
-    /// Adds a new mint with given settings to mints, and returns new MintId.
-    pub fn add_mint(
-        initial_capacity: BalanceOf<T>,
-        adjustment: Option<Adjustment<BalanceOf<T>, T::BlockNumber>>,
-    ) -> Result<T::MintId, GeneralError> {
-        let now = <system::Module<T>>::block_number();
+    // Draws the capacity added by the adjustment of a child mint from its parent, as far as the
+    // parent capacity allows. The capacity removed is returned to the parent. Returns the parent
+    // if its capacity was increased.
+    fn settle_adjustment_with_parent(
+        mint_id: T::MintId,
+        mint: &mut Mint<BalanceOf<T>, T::BlockNumber>,
+        previous_capacity: BalanceOf<T>,
+    ) -> Option<T::MintId> {
+        let parent_mint_id = Self::parent_mint_id(mint_id)?;
+
+        let mut parent_mint = Self::mints(&parent_mint_id);
+        let adjusted_capacity = mint.capacity();
+
+        if adjusted_capacity > previous_capacity {
+            let drawn_capacity = sp_std::cmp::min(
+                adjusted_capacity - previous_capacity,
+                parent_mint.capacity(),
+            );
+
+            parent_mint.set_capacity(parent_mint.capacity() - drawn_capacity);
+            mint.set_capacity(previous_capacity + drawn_capacity);
+        } else {
+            parent_mint
+                .set_capacity(parent_mint.capacity() + (previous_capacity - adjusted_capacity));
+        }
+
+        <Mints<T>>::insert(&parent_mint_id, parent_mint);
+
+        if adjusted_capacity < previous_capacity {
+            Some(parent_mint_id)
+        } else {
+            None
+        }
+    }
 
-        // Ensure the next adjustment if set, is in the future
-        if let Some(adjustment) = adjustment {
-            if let Adjustment::IntervalAfterFirstAdjustmentAbsolute(_, first_adjustment_in) =
-                adjustment
-            {
-                ensure!(
-                    first_adjustment_in > now,
-                    GeneralError::NextAdjustmentInPast
-                );
+    fn schedule_adjustment(mint_id: T::MintId, mint: &Mint<BalanceOf<T>, T::BlockNumber>) {
+        if let Some(next_adjustment) = mint.next_adjustment() {
+            <MintIdsByAdjustmentBlock<T>>::insert(next_adjustment.at_block, mint_id, ());
+        }
+    }
+
+    fn unschedule_adjustment(mint_id: T::MintId, mint: &Mint<BalanceOf<T>, T::BlockNumber>) {
+        if let Some(next_adjustment) = mint.next_adjustment() {
+            <MintIdsByAdjustmentBlock<T>>::remove(next_adjustment.at_block, mint_id);
+        }
+    }
+
+    /// Schedules the upcoming adjustments of the mints created by the previous runtime
+    /// versions, which visited every mint on each block. Does nothing once migrated.
+    pub fn migrate_adjustment_schedule() -> Weight {
+        if Self::adjustment_schedule_migrated() {
+            return T::DbWeight::get().reads(1);
+        }
+
+        let now = <system::Module<T>>::block_number();
+        let mut mints_count: Weight = 0;
+        let mut scheduled_count: Weight = 0;
+
+        for (mint_id, mint) in <Mints<T>>::iter() {
+            mints_count += 1;
+
+            // Adjustments in the past would never be made.
+            if let Some(next_adjustment) = mint.next_adjustment() {
+                if next_adjustment.at_block >= now {
+                    Self::schedule_adjustment(mint_id, &mint);
+                    scheduled_count += 1;
+                }
             }
         }
 
-        // Determine next adjutment
-        let next_adjustment = adjustment.map(|adjustment| match adjustment {
+        AdjustmentScheduleMigrated::put(true);
+
+        T::DbWeight::get().reads_writes(2 + mints_count, 1 + scheduled_count)
+    }
+
+    // Determines the upcoming adjustment of a mint, ensures the first adjustment if set,
+    // is in the future.
+    fn next_adjustment_from(
+        adjustment: Option<Adjustment<BalanceOf<T>, T::BlockNumber>>,
+        now: T::BlockNumber,
+    ) -> Result<Option<NextAdjustment<BalanceOf<T>, T::BlockNumber>>, GeneralError> {
+        if let Some(Adjustment::IntervalAfterFirstAdjustmentAbsolute(_, first_adjustment_in)) =
+            adjustment
+        {
+            ensure!(
+                first_adjustment_in > now,
+                GeneralError::NextAdjustmentInPast
+            );
+        }
+
+        Ok(adjustment.map(|adjustment| match adjustment {
             Adjustment::Interval(adjust_on_interval) => NextAdjustment {
                 at_block: now + adjust_on_interval.block_interval,
                 adjustment: adjust_on_interval,
@@ -170,29 +414,115 @@ impl<T: Trait> Module<T> {
                 adjustment: adjust_on_interval,
                 at_block: now + first_adjustment_after,
             },
-        });
+        }))
+    }
+
+    /// Adds a new mint with given settings to mints, and returns new MintId.
+    pub fn add_mint(
+        initial_capacity: BalanceOf<T>,
+        adjustment: Option<Adjustment<BalanceOf<T>, T::BlockNumber>>,
+    ) -> Result<T::MintId, GeneralError> {
+        let now = <system::Module<T>>::block_number();
+        let next_adjustment = Self::next_adjustment_from(adjustment, now)?;
 
         // get next mint_id and increment total number of mints created
         let mint_id = Self::mints_created();
         <MintsCreated<T>>::put(mint_id + One::one());
 
-        <Mints<T>>::insert(mint_id, Mint::new(initial_capacity, next_adjustment, now));
+        let mint = Mint::new(initial_capacity, next_adjustment, now);
+        Self::schedule_adjustment(mint_id, &mint);
+        <Mints<T>>::insert(mint_id, mint);
 
         Ok(mint_id)
     }
 
     /// Removes a mint. Passing a non existent mint has no side effects.
+    /// Child mints of the removed mint are left without a parent.
     pub fn remove_mint(mint_id: T::MintId) {
-        <Mints<T>>::remove(&mint_id);
+        if <Mints<T>>::contains_key(&mint_id) {
+            Self::unschedule_adjustment(mint_id, &<Mints<T>>::take(&mint_id));
+
+            if let Some(parent_mint_id) = <ParentMintIds<T>>::take(&mint_id) {
+                <ChildMintIds<T>>::remove(parent_mint_id, mint_id);
+            }
+
+            for child_mint_id in Self::child_mint_ids(mint_id) {
+                <ParentMintIds<T>>::remove(child_mint_id);
+            }
+            <ChildMintIds<T>>::remove_prefix(mint_id);
+        }
+    }
+
+    /// Sets or clears the parent of a mint. Capacity top-ups of the mint are drawn from
+    /// its parent afterwards.
+    pub fn set_parent_mint(
+        mint_id: T::MintId,
+        parent_mint_id: Option<T::MintId>,
+    ) -> Result<(), MintHierarchyError> {
+        ensure!(
+            <Mints<T>>::contains_key(&mint_id),
+            MintHierarchyError::MintNotFound
+        );
+
+        if let Some(parent_mint_id) = parent_mint_id {
+            ensure!(
+                <Mints<T>>::contains_key(&parent_mint_id),
+                MintHierarchyError::ParentMintNotFound
+            );
+
+            let mut ancestor_mint_id = Some(parent_mint_id);
+            while let Some(ancestor) = ancestor_mint_id {
+                ensure!(ancestor != mint_id, MintHierarchyError::MintIsOwnAncestor);
+                ancestor_mint_id = Self::parent_mint_id(ancestor);
+            }
+        }
+
+        if let Some(previous_parent_mint_id) = Self::parent_mint_id(mint_id) {
+            <ChildMintIds<T>>::remove(previous_parent_mint_id, mint_id);
+        }
+
+        if let Some(parent_mint_id) = parent_mint_id {
+            <ParentMintIds<T>>::insert(mint_id, parent_mint_id);
+            <ChildMintIds<T>>::insert(parent_mint_id, mint_id, ());
+        } else {
+            <ParentMintIds<T>>::remove(mint_id);
+        }
+
+        Ok(())
+    }
+
+    /// Returns the child mints of a mint.
+    pub fn child_mint_ids(mint_id: T::MintId) -> Vec<T::MintId> {
+        <ChildMintIds<T>>::iter_prefix(mint_id)
+            .map(|(child_mint_id, _)| child_mint_id)
+            .collect()
+    }
+
+    /// Returns the spending epoch of the block.
+    pub fn spending_epoch(block_number: T::BlockNumber) -> T::BlockNumber {
+        let epoch_length = T::SpendingEpochLength::get();
+
+        if epoch_length.is_zero() {
+            Zero::zero()
+        } else {
+            block_number / epoch_length
+        }
+    }
+
+    /// Returns the current spending epoch.
+    pub fn current_spending_epoch() -> T::BlockNumber {
+        Self::spending_epoch(<system::Module<T>>::block_number())
     }
 
     /// Tries to transfer exact requested amount from mint to a recipient account id.
     /// Returns error if amount exceeds mint capacity or the specified mint doesn't exist.
     /// Transfering amount of zero has no side effects. Return nothing on success.
+    /// The amount is recorded as spent on the category within the current spending epoch.
     pub fn transfer_tokens(
         mint_id: T::MintId,
         requested_amount: BalanceOf<T>,
         recipient: &T::AccountId,
+        category: SpendingCategory,
     ) -> Result<(), TransferError> {
         if requested_amount == Zero::zero() {
             return Ok(());
@@ -210,6 +540,10 @@ impl<T: Trait> Module<T> {
 
         <Mints<T>>::insert(&mint_id, mint);
 
+        <MintSpendingByEpoch<T>>::mutate(mint_id, Self::current_spending_epoch(), |spending| {
+            spending.add(category, requested_amount)
+        });
+
         // Deposit into recipient account
         T::Currency::deposit_creating(recipient, requested_amount);
 
@@ -217,6 +551,9 @@ impl<T: Trait> Module<T> {
     }
 
     /// Provided mint exists, sets its capacity to specied value, return error otherwise.
+    /// The capacity added to a child mint is drawn from its parent, returns error if the parent
+    /// capacity is insufficient. The capacity removed from a child mint is returned to its parent.
+    /// Only the parent top-up is passed to the `MintCapacityIncreased` hook.
     pub fn set_mint_capacity(
         mint_id: T::MintId,
         capacity: BalanceOf<T>,
@@ -226,9 +563,57 @@ impl<T: Trait> Module<T> {
             GeneralError::MintNotFound
         );
 
-        <Mints<T>>::mutate(&mint_id, |mint| {
-            mint.set_capacity(capacity);
-        });
+        let mut mint = Self::mints(&mint_id);
+        let mut topped_up_parent_mint_id = None;
+
+        if let Some(parent_mint_id) = Self::parent_mint_id(mint_id) {
+            let mut parent_mint = Self::mints(&parent_mint_id);
+            let current_capacity = mint.capacity();
+
+            if capacity > current_capacity {
+                parent_mint
+                    .transfer_capacity_to(&mut mint, capacity - current_capacity)
+                    .map_err(|_| GeneralError::NotEnoughParentCapacity)?;
+            } else if capacity < current_capacity {
+                parent_mint.set_capacity(parent_mint.capacity() + (current_capacity - capacity));
+                topped_up_parent_mint_id = Some(parent_mint_id);
+            }
+
+            <Mints<T>>::insert(&parent_mint_id, parent_mint);
+        }
+
+        mint.set_capacity(capacity);
+        <Mints<T>>::insert(&mint_id, mint);
+
+        // The top-up of the mint itself is handled by the caller.
+        if let Some(parent_mint_id) = topped_up_parent_mint_id {
+            T::MintCapacityIncreased::mint_capacity_increased(parent_mint_id);
+        }
+
+        Ok(())
+    }
+
+    /// Provided mint exists, replaces its upcoming capacity adjustment, return error otherwise.
+    /// Passing no adjustment pauses the mint.
+    pub fn change_mint_adjustment(
+        mint_id: T::MintId,
+        adjustment: Option<Adjustment<BalanceOf<T>, T::BlockNumber>>,
+    ) -> Result<(), GeneralError> {
+        ensure!(
+            <Mints<T>>::contains_key(&mint_id),
+            GeneralError::MintNotFound
+        );
+
+        let now = <system::Module<T>>::block_number();
+        let next_adjustment = Self::next_adjustment_from(adjustment, now)?;
+
+        let mut mint = Self::mints(&mint_id);
+
+        Self::unschedule_adjustment(mint_id, &mint);
+        mint.set_next_adjustment(next_adjustment);
+        Self::schedule_adjustment(mint_id, &mint);
+
+        <Mints<T>>::insert(&mint_id, mint);
 
         Ok(())
     }
@@ -1,9 +1,12 @@
 use codec::{Decode, Encode};
 use frame_support::ensure;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
 use sp_arithmetic::traits::{BaseArithmetic, Zero};
 
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Encode, Decode, Copy, Clone, Debug, Eq, PartialEq)]
-pub enum AdjustCapacityBy<Balance: Zero> {
+pub enum AdjustCapacityBy<Balance> {
     /// Set capacity of mint to specific value
     Setting(Balance),
     /// Add to the capacity of the mint
@@ -12,8 +15,9 @@ pub enum AdjustCapacityBy<Balance: Zero> {
     Reducing(Balance),
 }
 
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Encode, Decode, Copy, Clone, Debug, Eq, PartialEq)]
-pub struct AdjustOnInterval<Balance: Zero, BlockNumber> {
+pub struct AdjustOnInterval<Balance, BlockNumber> {
     pub block_interval: BlockNumber,
     pub adjustment_type: AdjustCapacityBy<Balance>,
 }
@@ -115,6 +119,13 @@ where
         self.next_adjustment
     }
 
+    pub fn set_next_adjustment(
+        &mut self,
+        next_adjustment: Option<NextAdjustment<Balance, BlockNumber>>,
+    ) {
+        self.next_adjustment = next_adjustment;
+    }
+
     pub fn maybe_do_capacity_adjustment(&mut self, now: BlockNumber) -> bool {
         self.next_adjustment.map_or(false, |next_adjustment| {
             if now != next_adjustment.at_block {
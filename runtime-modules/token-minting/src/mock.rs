@@ -67,9 +67,16 @@ impl balances::Trait for Test {
     type AccountStore = System;
 }
 
+parameter_types! {
+    pub const MintSpendingEpochLength: u64 = 100;
+}
+
 impl Trait for Test {
     type Currency = Balances;
     type MintId = u64;
+    type SpendingEpochLength = MintSpendingEpochLength;
+    type MintCapacityIncreased = ();
+    type WeightInfo = ();
 }
 
 pub fn build_test_externalities() -> sp_io::TestExternalities {
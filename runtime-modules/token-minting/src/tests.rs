@@ -2,6 +2,8 @@
 
 use super::*;
 use crate::mock::*;
+use frame_support::traits::Get;
+use frame_support::{assert_noop, assert_ok};
 
 #[test]
 fn adding_and_removing_mints() {
@@ -42,7 +44,7 @@ fn minting() {
 
         let mint_id = Minting::add_mint(capacity, None).ok().unwrap();
 
-        assert!(Minting::transfer_tokens(mint_id, 1000, &1).is_ok());
+        assert!(Minting::transfer_tokens(mint_id, 1000, &1, SpendingCategory::Rewards).is_ok());
 
         assert_eq!(Balances::free_balance(&1), 1000);
 
@@ -58,7 +60,7 @@ fn minting_exact() {
         let mint_id = Minting::add_mint(capacity, None).ok().unwrap();
 
         assert_eq!(
-            Minting::transfer_tokens(mint_id, 2000, &1),
+            Minting::transfer_tokens(mint_id, 2000, &1, SpendingCategory::Rewards),
             Err(TransferError::NotEnoughCapacity)
         );
     });
@@ -200,3 +202,249 @@ fn adjustment_first_interval() {
         );
     });
 }
+
+#[test]
+fn adjustments_are_scheduled_by_block() {
+    build_test_externalities().execute_with(|| {
+        System::set_block_number(0);
+        let adjustment = AdjustOnInterval {
+            adjustment_type: AdjustCapacityBy::Adding(500),
+            block_interval: 100,
+        };
+
+        let mint_id = Minting::add_mint(5000, Some(Adjustment::Interval(adjustment)))
+            .ok()
+            .unwrap();
+
+        assert!(<MintIdsByAdjustmentBlock<Test>>::contains_key(100, mint_id));
+
+        // The adjustment is rescheduled after the interval.
+        Minting::update_mints(100);
+        assert!(!<MintIdsByAdjustmentBlock<Test>>::contains_key(
+            100, mint_id
+        ));
+        assert!(<MintIdsByAdjustmentBlock<Test>>::contains_key(200, mint_id));
+
+        // Removed mints are unscheduled.
+        Minting::remove_mint(mint_id);
+        assert!(!<MintIdsByAdjustmentBlock<Test>>::contains_key(
+            200, mint_id
+        ));
+    });
+}
+
+#[test]
+fn adjustment_schedule_migration_works() {
+    build_test_externalities().execute_with(|| {
+        System::set_block_number(0);
+        let adjustment = AdjustOnInterval {
+            adjustment_type: AdjustCapacityBy::Adding(500),
+            block_interval: 100,
+        };
+
+        let mint_id = Minting::add_mint(5000, Some(Adjustment::Interval(adjustment)))
+            .ok()
+            .unwrap();
+
+        // Mints created by the previous runtime versions are not scheduled.
+        <MintIdsByAdjustmentBlock<Test>>::remove(100, mint_id);
+        assert!(!Minting::adjustment_schedule_migrated());
+
+        Minting::migrate_adjustment_schedule();
+
+        assert!(Minting::adjustment_schedule_migrated());
+        assert!(<MintIdsByAdjustmentBlock<Test>>::contains_key(100, mint_id));
+
+        Minting::update_mints(100);
+        assert_eq!(Minting::get_mint_capacity(mint_id).ok().unwrap(), 5500);
+    });
+}
+
+#[test]
+fn child_mint_capacity_is_drawn_from_parent() {
+    build_test_externalities().execute_with(|| {
+        let parent_mint_id = Minting::add_mint(1000, None).unwrap();
+        let child_mint_id = Minting::add_mint(0, None).unwrap();
+
+        assert!(Minting::set_parent_mint(child_mint_id, Some(parent_mint_id)).is_ok());
+        assert_eq!(Minting::parent_mint_id(child_mint_id), Some(parent_mint_id));
+        assert_eq!(Minting::child_mint_ids(parent_mint_id), vec![child_mint_id]);
+
+        assert!(Minting::set_mint_capacity(child_mint_id, 600).is_ok());
+        assert_eq!(Minting::get_mint_capacity(child_mint_id), Ok(600));
+        assert_eq!(Minting::get_mint_capacity(parent_mint_id), Ok(400));
+
+        assert_eq!(
+            Minting::set_mint_capacity(child_mint_id, 1200),
+            Err(GeneralError::NotEnoughParentCapacity)
+        );
+        assert_eq!(Minting::get_mint_capacity(child_mint_id), Ok(600));
+
+        // The removed capacity is returned to the parent.
+        assert!(Minting::set_mint_capacity(child_mint_id, 100).is_ok());
+        assert_eq!(Minting::get_mint_capacity(parent_mint_id), Ok(900));
+
+        // Children of the removed mint are left without a parent.
+        Minting::remove_mint(parent_mint_id);
+        assert_eq!(Minting::parent_mint_id(child_mint_id), None);
+        assert!(Minting::set_mint_capacity(child_mint_id, 5000).is_ok());
+    });
+}
+
+#[test]
+fn child_mint_adjustment_is_drawn_from_parent() {
+    build_test_externalities().execute_with(|| {
+        System::set_block_number(1);
+
+        let adjustment = AdjustOnInterval {
+            adjustment_type: AdjustCapacityBy::Adding(300),
+            block_interval: 100,
+        };
+
+        let parent_mint_id = Minting::add_mint(500, None).unwrap();
+        let child_mint_id = Minting::add_mint(0, Some(Adjustment::Interval(adjustment))).unwrap();
+        assert!(Minting::set_parent_mint(child_mint_id, Some(parent_mint_id)).is_ok());
+
+        Minting::update_mints(101);
+        assert_eq!(Minting::get_mint_capacity(child_mint_id), Ok(300));
+        assert_eq!(Minting::get_mint_capacity(parent_mint_id), Ok(200));
+
+        // Drawn only as far as the parent capacity allows.
+        Minting::update_mints(201);
+        assert_eq!(Minting::get_mint_capacity(child_mint_id), Ok(500));
+        assert_eq!(Minting::get_mint_capacity(parent_mint_id), Ok(0));
+    });
+}
+
+#[test]
+fn mint_cannot_be_its_own_ancestor() {
+    build_test_externalities().execute_with(|| {
+        let first_mint_id = Minting::add_mint(0, None).unwrap();
+        let second_mint_id = Minting::add_mint(0, None).unwrap();
+
+        assert_eq!(
+            Minting::set_parent_mint(first_mint_id, Some(first_mint_id)),
+            Err(MintHierarchyError::MintIsOwnAncestor)
+        );
+
+        assert!(Minting::set_parent_mint(second_mint_id, Some(first_mint_id)).is_ok());
+        assert_eq!(
+            Minting::set_parent_mint(first_mint_id, Some(second_mint_id)),
+            Err(MintHierarchyError::MintIsOwnAncestor)
+        );
+
+        // Moving the child to another parent
+        assert!(Minting::set_parent_mint(second_mint_id, None).is_ok());
+        assert!(Minting::child_mint_ids(first_mint_id).is_empty());
+        assert!(Minting::set_parent_mint(first_mint_id, Some(second_mint_id)).is_ok());
+    });
+}
+
+#[test]
+fn spending_is_recorded_by_epoch_and_category() {
+    build_test_externalities().execute_with(|| {
+        let mint_id = Minting::add_mint(5000, None).unwrap();
+
+        System::set_block_number(10);
+        assert!(Minting::transfer_tokens(mint_id, 100, &1, SpendingCategory::Rewards).is_ok());
+        assert!(Minting::transfer_tokens(mint_id, 200, &1, SpendingCategory::Rewards).is_ok());
+        assert!(
+            Minting::transfer_tokens(mint_id, 400, &2, SpendingCategory::SpendingProposals).is_ok()
+        );
+
+        let epoch = Minting::current_spending_epoch();
+        assert_eq!(
+            Minting::mint_spending(mint_id, epoch),
+            MintSpending {
+                rewards: 300,
+                spending_proposals: 400,
+            }
+        );
+
+        System::set_block_number(10 + MintSpendingEpochLength::get());
+        let next_epoch = Minting::current_spending_epoch();
+        assert_eq!(next_epoch, epoch + 1);
+
+        assert!(
+            Minting::transfer_tokens(mint_id, 50, &3, SpendingCategory::SpendingProposals).is_ok()
+        );
+        assert_eq!(Minting::mint_spending(mint_id, next_epoch).total(), 50);
+        assert_eq!(Minting::mint_spending(mint_id, epoch).total(), 700);
+    });
+}
+
+#[test]
+fn mint_adjustment_can_be_changed_and_paused() {
+    build_test_externalities().execute_with(|| {
+        System::set_block_number(0);
+        let adjustment = AdjustOnInterval {
+            adjustment_type: AdjustCapacityBy::Adding(500),
+            block_interval: 100,
+        };
+
+        let mint_id = Minting::add_mint(5000, Some(Adjustment::Interval(adjustment)))
+            .ok()
+            .unwrap();
+
+        System::set_block_number(10);
+        let changed_adjustment = AdjustOnInterval {
+            adjustment_type: AdjustCapacityBy::Setting(1000),
+            block_interval: 50,
+        };
+
+        assert_ok!(Minting::set_mint_adjustment(
+            system::RawOrigin::Root.into(),
+            mint_id,
+            Some(Adjustment::Interval(changed_adjustment))
+        ));
+
+        assert!(!<MintIdsByAdjustmentBlock<Test>>::contains_key(
+            100, mint_id
+        ));
+        assert!(<MintIdsByAdjustmentBlock<Test>>::contains_key(60, mint_id));
+
+        Minting::update_mints(60);
+        assert_eq!(Minting::get_mint_capacity(mint_id).ok().unwrap(), 1000);
+
+        // Paused mints are not adjusted.
+        assert_ok!(Minting::set_mint_adjustment(
+            system::RawOrigin::Root.into(),
+            mint_id,
+            None
+        ));
+
+        assert!(!<MintIdsByAdjustmentBlock<Test>>::contains_key(
+            110, mint_id
+        ));
+        assert_eq!(Minting::get_mint_next_adjustment(mint_id), Ok(None));
+    });
+}
+
+#[test]
+fn mint_adjustment_cannot_be_set_in_the_past() {
+    build_test_externalities().execute_with(|| {
+        System::set_block_number(10);
+        let adjustment = AdjustOnInterval {
+            adjustment_type: AdjustCapacityBy::Adding(500),
+            block_interval: 100,
+        };
+
+        let mint_id = Minting::add_mint(5000, None).ok().unwrap();
+
+        assert_noop!(
+            Minting::set_mint_adjustment(
+                system::RawOrigin::Root.into(),
+                mint_id,
+                Some(Adjustment::IntervalAfterFirstAdjustmentAbsolute(
+                    adjustment, 5
+                ))
+            ),
+            "NextAdjustmentInPast"
+        );
+
+        assert_noop!(
+            Minting::set_mint_adjustment(system::RawOrigin::Signed(1).into(), mint_id, None),
+            sp_runtime::DispatchError::BadOrigin
+        );
+    });
+}
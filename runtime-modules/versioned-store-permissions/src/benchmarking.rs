@@ -0,0 +1,308 @@
+use super::*;
+use frame_benchmarking::benchmarks;
+use frame_support::StorageMap;
+use sp_std::vec;
+use system::RawOrigin;
+use versioned_store::PropertyType;
+
+// Root origin bypasses the credential checks, so the benchmarks do not depend on the
+// `CredentialChecker` and `CreateClassPermissionsChecker` implementations.
+
+const MAX_CREDENTIALS: u32 = 100;
+const MAX_PROPERTIES: u32 = 100;
+const MAX_OPERATIONS: u32 = 100;
+const MAX_TEXT_LENGTH: u16 = 1_000;
+
+fn text(length: u32) -> Vec<u8> {
+    vec![b'x'; length as usize]
+}
+
+fn text_value() -> PropertyValue {
+    PropertyValue::Text(text(MAX_TEXT_LENGTH as u32))
+}
+
+fn credentials<T: Trait>(count: u32) -> CredentialSet<T::Credential> {
+    (0..count)
+        .map(T::Credential::from)
+        .collect::<Vec<_>>()
+        .into()
+}
+
+fn property<T: Trait>(index: u32) -> Property {
+    let description_length =
+        <versioned_store::Module<T>>::property_description_constraint().max() as u32;
+
+    Property {
+        prop_type: PropertyType::Text(MAX_TEXT_LENGTH),
+        required: false,
+        name: index.to_le_bytes().to_vec(),
+        description: text(description_length),
+    }
+}
+
+fn property_values(count: u32) -> Vec<ClassPropertyValue> {
+    (0..count)
+        .map(|index| ClassPropertyValue {
+            in_class_index: index as u16,
+            value: text_value(),
+        })
+        .collect()
+}
+
+// Returns the minimum and maximum class name lengths.
+fn class_name_lengths<T: Trait>() -> (u32, u32) {
+    let constraint = <versioned_store::Module<T>>::class_name_constraint();
+
+    (constraint.min as u32, constraint.max() as u32)
+}
+
+// Returns the minimum and maximum class description lengths.
+fn class_description_lengths<T: Trait>() -> (u32, u32) {
+    let constraint = <versioned_store::Module<T>>::class_description_constraint();
+
+    (constraint.min as u32, constraint.max() as u32)
+}
+
+// Creates a class with default permissions and a single schema with `properties` properties.
+fn create_class_with_schema<T: Trait>(properties: u32) -> ClassId {
+    let (_, name_length) = class_name_lengths::<T>();
+    let (_, description_length) = class_description_lengths::<T>();
+    let class_id = <versioned_store::Module<T>>::next_class_id();
+
+    Module::<T>::create_class_with_default_permissions(
+        RawOrigin::Root.into(),
+        text(name_length),
+        text(description_length),
+    )
+    .unwrap();
+
+    Module::<T>::add_class_schema(
+        RawOrigin::Root.into(),
+        None,
+        class_id,
+        Vec::new(),
+        (0..properties).map(property::<T>).collect(),
+    )
+    .unwrap();
+
+    class_id
+}
+
+fn add_entity<T: Trait>(class_id: ClassId) -> EntityId {
+    let entity_id = <versioned_store::Module<T>>::next_entity_id();
+
+    Module::<T>::create_entity(RawOrigin::Root.into(), None, class_id).unwrap();
+
+    entity_id
+}
+
+benchmarks! {
+    _ { }
+
+    set_class_admins {
+        let c in 1 .. MAX_CREDENTIALS;
+
+        let class_id = create_class_with_schema::<T>(1);
+    }: _(RawOrigin::Root, class_id, credentials::<T>(c))
+    verify {
+        assert_eq!(Module::<T>::class_permissions_by_class_id(class_id).admins, credentials::<T>(c));
+    }
+
+    set_class_entity_permissions {
+        let c in 1 .. MAX_CREDENTIALS;
+
+        let class_id = create_class_with_schema::<T>(1);
+        let entity_permissions = EntityPermissions {
+            update: credentials::<T>(c),
+            maintainer_has_all_permissions: true,
+        };
+    }: _(RawOrigin::Root, None, class_id, entity_permissions.clone())
+    verify {
+        assert_eq!(
+            Module::<T>::class_permissions_by_class_id(class_id).entity_permissions,
+            entity_permissions
+        );
+    }
+
+    set_class_entities_can_be_created {
+        let class_id = create_class_with_schema::<T>(1);
+    }: _(RawOrigin::Root, None, class_id, true)
+    verify {
+        assert!(Module::<T>::class_permissions_by_class_id(class_id).entities_can_be_created);
+    }
+
+    set_class_add_schemas_set {
+        let c in 1 .. MAX_CREDENTIALS;
+
+        let class_id = create_class_with_schema::<T>(1);
+    }: _(RawOrigin::Root, None, class_id, credentials::<T>(c))
+    verify {
+        assert_eq!(Module::<T>::class_permissions_by_class_id(class_id).add_schemas, credentials::<T>(c));
+    }
+
+    set_class_create_entities_set {
+        let c in 1 .. MAX_CREDENTIALS;
+
+        let class_id = create_class_with_schema::<T>(1);
+    }: _(RawOrigin::Root, None, class_id, credentials::<T>(c))
+    verify {
+        assert_eq!(
+            Module::<T>::class_permissions_by_class_id(class_id).create_entities,
+            credentials::<T>(c)
+        );
+    }
+
+    set_class_reference_constraint {
+        let r in 1 .. MAX_PROPERTIES;
+
+        let class_id = create_class_with_schema::<T>(1);
+        let constraint = ReferenceConstraint::Restricted(
+            (0..r)
+                .map(|index| PropertyOfClass {
+                    class_id,
+                    property_index: index as u16,
+                })
+                .collect(),
+        );
+    }: _(RawOrigin::Root, None, class_id, constraint.clone())
+    verify {
+        assert_eq!(Module::<T>::class_permissions_by_class_id(class_id).reference_constraint, constraint);
+    }
+
+    create_class {
+        let n in (class_name_lengths::<T>().0) .. (class_name_lengths::<T>().1);
+        let d in (class_description_lengths::<T>().0) .. (class_description_lengths::<T>().1);
+
+        let class_id = <versioned_store::Module<T>>::next_class_id();
+    }: _(RawOrigin::Root, text(n), text(d), ClassPermissionsType::<T>::default())
+    verify {
+        assert!(<ClassPermissionsByClassId<T>>::contains_key(class_id));
+    }
+
+    create_class_with_default_permissions {
+        let n in (class_name_lengths::<T>().0) .. (class_name_lengths::<T>().1);
+        let d in (class_description_lengths::<T>().0) .. (class_description_lengths::<T>().1);
+
+        let class_id = <versioned_store::Module<T>>::next_class_id();
+    }: _(RawOrigin::Root, text(n), text(d))
+    verify {
+        assert!(<ClassPermissionsByClassId<T>>::contains_key(class_id));
+    }
+
+    add_class_schema {
+        let e in 1 .. MAX_PROPERTIES;
+        let p in 1 .. MAX_PROPERTIES;
+
+        let class_id = create_class_with_schema::<T>(e);
+        let existing_properties = (0..e).map(|index| index as u16).collect();
+        let new_properties = (e..e + p).map(property::<T>).collect();
+    }: _(RawOrigin::Root, None, class_id, existing_properties, new_properties)
+    verify {
+        assert_eq!(<versioned_store::Module<T>>::class_by_id(class_id).schemas.len(), 2);
+    }
+
+    create_entity {
+        let class_id = create_class_with_schema::<T>(1);
+        let entity_id = <versioned_store::Module<T>>::next_entity_id();
+    }: _(RawOrigin::Root, None, class_id)
+    verify {
+        assert!(<versioned_store::EntityById>::contains_key(entity_id));
+    }
+
+    add_schema_support_to_entity {
+        let v in 1 .. MAX_PROPERTIES;
+
+        let class_id = create_class_with_schema::<T>(v);
+        let entity_id = add_entity::<T>(class_id);
+    }: _(RawOrigin::Root, None, false, entity_id, 0, property_values(v))
+    verify {
+        assert_eq!(<versioned_store::Module<T>>::entity_by_id(entity_id).values.len(), v as usize);
+    }
+
+    update_entity_property_values {
+        let v in 1 .. MAX_PROPERTIES;
+
+        let class_id = create_class_with_schema::<T>(v);
+        let entity_id = add_entity::<T>(class_id);
+
+        Module::<T>::add_schema_support_to_entity(
+            RawOrigin::Root.into(),
+            None,
+            false,
+            entity_id,
+            0,
+            property_values(v),
+        )
+        .unwrap();
+    }: _(RawOrigin::Root, None, false, entity_id, property_values(v))
+    verify {
+        assert_eq!(<versioned_store::Module<T>>::entity_by_id(entity_id).values.len(), v as usize);
+    }
+
+    transaction {
+        let o in 1 .. MAX_OPERATIONS;
+        let v in 1 .. MAX_PROPERTIES;
+
+        let class_id = create_class_with_schema::<T>(MAX_PROPERTIES);
+        let entity_id = add_entity::<T>(class_id);
+
+        Module::<T>::add_schema_support_to_entity(
+            RawOrigin::Root.into(),
+            None,
+            false,
+            entity_id,
+            0,
+            property_values(MAX_PROPERTIES),
+        )
+        .unwrap();
+
+        // The property values are distributed between the update operations.
+        let operations = (0..o)
+            .map(|operation_index| Operation {
+                with_credential: None,
+                as_entity_maintainer: false,
+                operation_type: OperationType::UpdatePropertyValues(UpdatePropertyValuesOperation {
+                    entity_id: ParameterizedEntity::ExistingEntity(entity_id),
+                    new_parametrized_property_values: (0..v)
+                        .filter(|index| index % o == operation_index)
+                        .map(|index| ParametrizedClassPropertyValue {
+                            in_class_index: index as u16,
+                            value: ParametrizedPropertyValue::PropertyValue(text_value()),
+                        })
+                        .collect(),
+                }),
+            })
+            .collect::<Vec<_>>();
+    }: _(RawOrigin::Root, operations)
+    verify {
+        assert_eq!(
+            <versioned_store::Module<T>>::entity_by_id(entity_id).values.len(),
+            MAX_PROPERTIES as usize
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::{with_test_externalities, Runtime};
+
+    #[test]
+    fn versioned_store_permissions_benchmarks_succeed() {
+        with_test_externalities(|| {
+            assert!(test_benchmark_set_class_admins::<Runtime>().is_ok());
+            assert!(test_benchmark_set_class_entity_permissions::<Runtime>().is_ok());
+            assert!(test_benchmark_set_class_entities_can_be_created::<Runtime>().is_ok());
+            assert!(test_benchmark_set_class_add_schemas_set::<Runtime>().is_ok());
+            assert!(test_benchmark_set_class_create_entities_set::<Runtime>().is_ok());
+            assert!(test_benchmark_set_class_reference_constraint::<Runtime>().is_ok());
+            assert!(test_benchmark_create_class::<Runtime>().is_ok());
+            assert!(test_benchmark_create_class_with_default_permissions::<Runtime>().is_ok());
+            assert!(test_benchmark_add_class_schema::<Runtime>().is_ok());
+            assert!(test_benchmark_create_entity::<Runtime>().is_ok());
+            assert!(test_benchmark_add_schema_support_to_entity::<Runtime>().is_ok());
+            assert!(test_benchmark_update_entity_property_values::<Runtime>().is_ok());
+            assert!(test_benchmark_transaction::<Runtime>().is_ok());
+        });
+    }
+}
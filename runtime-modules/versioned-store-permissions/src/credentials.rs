@@ -45,6 +45,10 @@ impl<Credential: Ord> CredentialSet<Credential> {
     pub fn is_empty(&self) -> bool {
         self.0.is_empty()
     }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
 }
 
 /// Type, derived from dispatchable call, identifies the caller
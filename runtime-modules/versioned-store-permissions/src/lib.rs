@@ -2,6 +2,7 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use codec::Codec;
+use frame_support::weights::Weight;
 use frame_support::{decl_module, decl_storage, ensure, Parameter};
 use sp_arithmetic::traits::BaseArithmetic;
 use sp_runtime::traits::{MaybeSerialize, Member};
@@ -15,6 +16,8 @@ pub type DispatchResult = Result<(), &'static str>;
 // EntityId, ClassId -> should be configured on versioned_store::Trait
 pub use versioned_store::{ClassId, ClassPropertyValue, EntityId, Property, PropertyValue};
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
 mod constraint;
 mod credentials;
 mod mock;
@@ -64,6 +67,72 @@ impl<T: Trait> CreateClassPermissionsChecker<T> for () {
 pub type ClassPermissionsType<T> =
     ClassPermissions<ClassId, <T as Trait>::Credential, u16, <T as system::Trait>::BlockNumber>;
 
+/// Weight information for the versioned store permissions extrinsics.
+pub trait WeightInfo {
+    /// `c` - number of the credentials in the set.
+    fn set_class_admins(c: u32) -> Weight;
+    fn set_class_entity_permissions(c: u32) -> Weight;
+    fn set_class_entities_can_be_created() -> Weight;
+    fn set_class_add_schemas_set(c: u32) -> Weight;
+    fn set_class_create_entities_set(c: u32) -> Weight;
+    /// `r` - number of the properties in the restricted reference constraint.
+    fn set_class_reference_constraint(r: u32) -> Weight;
+    /// `n` - class name length, `d` - class description length.
+    fn create_class(n: u32, d: u32) -> Weight;
+    fn create_class_with_default_permissions(n: u32, d: u32) -> Weight;
+    /// `e` - number of the existing properties, `p` - number of the new properties.
+    fn add_class_schema(e: u32, p: u32) -> Weight;
+    fn create_entity() -> Weight;
+    /// `v` - number of the property values.
+    fn add_schema_support_to_entity(v: u32) -> Weight;
+    fn update_entity_property_values(v: u32) -> Weight;
+    /// `o` - number of the operations, `v` - total number of the property values in the operations.
+    fn transaction(o: u32, v: u32) -> Weight;
+}
+
+/// Default weights, used by the tests.
+impl WeightInfo for () {
+    fn set_class_admins(_c: u32) -> Weight {
+        10_000_000
+    }
+    fn set_class_entity_permissions(_c: u32) -> Weight {
+        10_000_000
+    }
+    fn set_class_entities_can_be_created() -> Weight {
+        10_000_000
+    }
+    fn set_class_add_schemas_set(_c: u32) -> Weight {
+        10_000_000
+    }
+    fn set_class_create_entities_set(_c: u32) -> Weight {
+        10_000_000
+    }
+    fn set_class_reference_constraint(_r: u32) -> Weight {
+        10_000_000
+    }
+    fn create_class(_n: u32, _d: u32) -> Weight {
+        10_000_000
+    }
+    fn create_class_with_default_permissions(_n: u32, _d: u32) -> Weight {
+        10_000_000
+    }
+    fn add_class_schema(_e: u32, _p: u32) -> Weight {
+        10_000_000
+    }
+    fn create_entity() -> Weight {
+        10_000_000
+    }
+    fn add_schema_support_to_entity(_v: u32) -> Weight {
+        10_000_000
+    }
+    fn update_entity_property_values(_v: u32) -> Weight {
+        10_000_000
+    }
+    fn transaction(_o: u32, _v: u32) -> Weight {
+        10_000_000
+    }
+}
+
 pub trait Trait: system::Trait + versioned_store::Trait {
     /// Type that represents an actor or group of actors in the system.
     type Credential: Parameter
@@ -83,6 +152,9 @@ pub trait Trait: system::Trait + versioned_store::Trait {
 
     /// External type used to check if an account has permission to create new Classes.
     type CreateClassPermissionsChecker: CreateClassPermissionsChecker<Self>;
+
+    /// Weight information for the extrinsics of this module.
+    type WeightInfo: WeightInfo;
 }
 
 decl_storage! {
@@ -101,7 +173,7 @@ decl_module! {
     pub struct Module<T: Trait> for enum Call where origin: T::Origin {
 
         /// Sets the admins for a class
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::set_class_admins(admins.len() as u32)]
         fn set_class_admins(
             origin,
             class_id: ClassId,
@@ -122,7 +194,7 @@ decl_module! {
         }
 
         // Methods for updating concrete permissions
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::set_class_entity_permissions(entity_permissions.update.len() as u32)]
         fn set_class_entity_permissions(
             origin,
             with_credential: Option<T::Credential>,
@@ -143,7 +215,7 @@ decl_module! {
             )
         }
 
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::set_class_entities_can_be_created()]
         fn set_class_entities_can_be_created(
             origin,
             with_credential: Option<T::Credential>,
@@ -164,7 +236,7 @@ decl_module! {
             )
         }
 
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::set_class_add_schemas_set(credential_set.len() as u32)]
         fn set_class_add_schemas_set(
             origin,
             with_credential: Option<T::Credential>,
@@ -185,7 +257,7 @@ decl_module! {
             )
         }
 
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::set_class_create_entities_set(credential_set.len() as u32)]
         fn set_class_create_entities_set(
             origin,
             with_credential: Option<T::Credential>,
@@ -206,7 +278,7 @@ decl_module! {
             )
         }
 
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::set_class_reference_constraint(reference_constraint_len(&constraint))]
         fn set_class_reference_constraint(
             origin,
             with_credential: Option<T::Credential>,
@@ -247,7 +319,7 @@ decl_module! {
         // }
 
         // Permissioned proxy calls to versioned store
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::create_class(name.len() as u32, description.len() as u32)]
         pub fn create_class(
             origin,
             name: Vec<u8>,
@@ -277,7 +349,10 @@ decl_module! {
             }
         }
 
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::create_class_with_default_permissions(
+            name.len() as u32,
+            description.len() as u32,
+        )]
         pub fn create_class_with_default_permissions(
             origin,
             name: Vec<u8>,
@@ -286,7 +361,10 @@ decl_module! {
             Self::create_class(origin, name, description, ClassPermissions::default())
         }
 
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::add_class_schema(
+            existing_properties.len() as u32,
+            new_properties.len() as u32,
+        )]
         pub fn add_class_schema(
             origin,
             with_credential: Option<T::Credential>,
@@ -315,7 +393,7 @@ decl_module! {
 
         /// Creates a new entity of type class_id. The maintainer is set to be either None if the origin is root, or the provided credential
         /// associated with signer.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::create_entity()]
         pub fn create_entity(
             origin,
             with_credential: Option<T::Credential>,
@@ -326,7 +404,7 @@ decl_module! {
             Ok(())
         }
 
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::add_schema_support_to_entity(property_values.len() as u32)]
         pub fn add_schema_support_to_entity(
             origin,
             with_credential: Option<T::Credential>,
@@ -339,7 +417,7 @@ decl_module! {
             Self::do_add_schema_support_to_entity(&raw_origin, with_credential, as_entity_maintainer, entity_id, schema_id, property_values)
         }
 
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::update_entity_property_values(property_values.len() as u32)]
         pub fn update_entity_property_values(
             origin,
             with_credential: Option<T::Credential>,
@@ -351,7 +429,10 @@ decl_module! {
             Self::do_update_entity_property_values(&raw_origin, with_credential, as_entity_maintainer, entity_id, property_values)
         }
 
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait>::WeightInfo::transaction(
+            operations.len() as u32,
+            operations_property_values_len(&operations),
+        )]
         pub fn transaction(origin, operations: Vec<Operation<T::Credential>>) -> DispatchResult {
             // This map holds the EntityId of the entity created as a result of executing a CreateEntity Operation
             // keyed by the indexed of the operation, in the operations vector.
@@ -656,3 +737,27 @@ impl<T: Trait> Module<T> {
         Ok(())
     }
 }
+
+// Returns the number of the properties in the restricted reference constraint.
+fn reference_constraint_len(constraint: &ReferenceConstraint<ClassId, u16>) -> u32 {
+    match constraint {
+        ReferenceConstraint::Restricted(properties) => properties.len() as u32,
+        _ => 0,
+    }
+}
+
+// Returns the total number of the property values in the transaction operations.
+fn operations_property_values_len<Credential>(operations: &[Operation<Credential>]) -> u32 {
+    operations
+        .iter()
+        .map(|operation| match &operation.operation_type {
+            OperationType::CreateEntity(_) => 0,
+            OperationType::UpdatePropertyValues(operation) => {
+                operation.new_parametrized_property_values.len() as u32
+            }
+            OperationType::AddSchemaSupportToEntity(operation) => {
+                operation.parametrized_property_values.len() as u32
+            }
+        })
+        .sum()
+}
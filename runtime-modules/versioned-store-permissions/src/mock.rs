@@ -68,6 +68,7 @@ impl Trait for Runtime {
     type Credential = u64;
     type CredentialChecker = MockCredentialChecker;
     type CreateClassPermissionsChecker = MockCreateClassPermissionsChecker;
+    type WeightInfo = ();
 }
 
 pub const MEMBER_ONE_WITH_CREDENTIAL_ZERO: u64 = 100;
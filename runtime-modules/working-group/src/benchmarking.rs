@@ -0,0 +1,441 @@
+//! Working group module benchmarking.
+
+use super::*;
+use codec::Encode;
+use frame_benchmarking::{account, benchmarks_instance};
+use system::RawOrigin;
+
+const SEED: u32 = 0;
+
+// Upper bound for the benchmarked text inputs.
+const MAX_BYTES: u32 = 5_000;
+
+// Role and application stake amount of the benchmarked applications.
+const STAKE: u32 = 1_000;
+
+// Relaxes all input constraints, so the whole benchmarked text length range is valid.
+fn set_relaxed_constraints<I: Instance>() {
+    let constraint = InputValidationLengthConstraint::new(1, MAX_BYTES as u16 - 1);
+
+    <OpeningHumanReadableText<I>>::put(constraint);
+    <WorkerApplicationHumanReadableText<I>>::put(constraint);
+    <WorkerExitRationaleText<I>>::put(constraint);
+}
+
+fn text(len: u32) -> Vec<u8> {
+    vec![b'x'; len as usize]
+}
+
+// Creates a member with the same root and controller account.
+fn member<T: membership::Trait>(index: u32) -> (T::AccountId, T::MemberId) {
+    let account_id: T::AccountId = account("member", index, SEED);
+    let member_id = <membership::Module<T>>::members_created();
+
+    <membership::Module<T>>::set_screening_authority(RawOrigin::Root.into(), account_id.clone())
+        .unwrap();
+
+    <membership::Module<T>>::add_screened_member(
+        RawOrigin::Signed(account_id.clone()).into(),
+        account_id.clone(),
+        Some(account_id.encode()),
+        None,
+        None,
+    )
+    .unwrap();
+
+    (account_id, member_id)
+}
+
+fn staking_policy<T: stake::Trait>() -> hiring::StakingPolicy<BalanceOf<T>, T::BlockNumber> {
+    hiring::StakingPolicy {
+        amount: BalanceOf::<T>::from(STAKE),
+        amount_mode: hiring::StakingAmountLimitMode::AtLeast,
+        crowded_out_unstaking_period_length: None,
+        review_period_expired_unstaking_period_length: None,
+    }
+}
+
+// Staked openings are the worst case for the whole hiring flow.
+fn opening_policy_commitment<T: stake::Trait>(
+) -> OpeningPolicyCommitment<T::BlockNumber, BalanceOf<T>> {
+    OpeningPolicyCommitment {
+        max_review_period_length: T::BlockNumber::from(1_000u32),
+        application_staking_policy: Some(staking_policy::<T>()),
+        role_staking_policy: Some(staking_policy::<T>()),
+        ..OpeningPolicyCommitment::default()
+    }
+}
+
+fn reward_policy<T: minting::Trait>() -> RewardPolicy<minting::BalanceOf<T>, T::BlockNumber> {
+    RewardPolicy {
+        amount_per_payout: minting::BalanceOf::<T>::from(100u32),
+        next_payment_at_block: <system::Module<T>>::block_number() + T::BlockNumber::from(1_000u32),
+        payout_interval: Some(T::BlockNumber::from(1_000u32)),
+    }
+}
+
+fn add_opening<T: Trait<I>, I: Instance>(
+    origin: RawOrigin<T::AccountId>,
+    activate_at: hiring::ActivateOpeningAt<T::BlockNumber>,
+    opening_type: OpeningType,
+) -> OpeningId<T> {
+    let opening_id = <NextOpeningId<T, I>>::get();
+
+    Module::<T, I>::add_opening(
+        origin.into(),
+        activate_at,
+        opening_policy_commitment::<T>(),
+        text(MAX_BYTES),
+        opening_type,
+    )
+    .unwrap();
+
+    opening_id
+}
+
+// Applies on the opening with both stakes as a newly created member.
+fn apply<T: Trait<I>, I: Instance>(
+    opening_id: OpeningId<T>,
+    member_index: u32,
+) -> (T::AccountId, ApplicationId<T>) {
+    let (account_id, member_id) = member::<T>(member_index);
+    CurrencyOf::<T>::make_free_balance_be(&account_id, BalanceOf::<T>::from(3 * STAKE));
+
+    let application_id = <NextApplicationId<T, I>>::get();
+
+    Module::<T, I>::apply_on_opening(
+        RawOrigin::Signed(account_id.clone()).into(),
+        member_id,
+        opening_id,
+        account_id.clone(),
+        Some(BalanceOf::<T>::from(STAKE)),
+        Some(BalanceOf::<T>::from(STAKE)),
+        text(MAX_BYTES),
+    )
+    .unwrap();
+
+    (account_id, application_id)
+}
+
+fn fill_opening<T: Trait<I>, I: Instance>(
+    origin: RawOrigin<T::AccountId>,
+    opening_id: OpeningId<T>,
+    application_ids: ApplicationIdSet<T>,
+) -> WorkerId<T> {
+    let worker_id = <NextWorkerId<T, I>>::get();
+
+    Module::<T, I>::begin_applicant_review(origin.clone().into(), opening_id).unwrap();
+    Module::<T, I>::fill_opening(
+        origin.into(),
+        opening_id,
+        application_ids,
+        Some(reward_policy::<T>()),
+    )
+    .unwrap();
+
+    worker_id
+}
+
+// Hires the staked and rewarded leader. Returns the leader role account and the worker id.
+fn hire_lead<T: Trait<I>, I: Instance>() -> (T::AccountId, WorkerId<T>) {
+    let opening_id = add_opening::<T, I>(
+        RawOrigin::Root,
+        hiring::ActivateOpeningAt::CurrentBlock,
+        OpeningType::Leader,
+    );
+    let (account_id, application_id) = apply::<T, I>(opening_id, 0);
+
+    let mut application_ids = ApplicationIdSet::<T>::new();
+    application_ids.insert(application_id);
+
+    let worker_id = fill_opening::<T, I>(RawOrigin::Root, opening_id, application_ids);
+
+    (account_id, worker_id)
+}
+
+benchmarks_instance! {
+    _ { }
+
+    update_role_account {
+        set_relaxed_constraints::<I>();
+        let (lead_account, lead_id) = hire_lead::<T, I>();
+        let new_role_account: T::AccountId = account("role", 0, SEED);
+    }: _(RawOrigin::Signed(lead_account), lead_id, new_role_account.clone())
+    verify {
+        assert_eq!(
+            Module::<T, I>::worker_by_id(lead_id).role_account_id,
+            new_role_account
+        );
+    }
+
+    update_reward_account {
+        set_relaxed_constraints::<I>();
+        let (lead_account, lead_id) = hire_lead::<T, I>();
+        let new_reward_account: T::AccountId = account("reward", 0, SEED);
+    }: _(RawOrigin::Signed(lead_account), lead_id, new_reward_account)
+
+    update_reward_amount {
+        set_relaxed_constraints::<I>();
+        let (_, lead_id) = hire_lead::<T, I>();
+    }: _(RawOrigin::Root, lead_id, minting::BalanceOf::<T>::from(200u32))
+
+    leave_role {
+        let r in 1 .. MAX_BYTES;
+
+        set_relaxed_constraints::<I>();
+        let (lead_account, lead_id) = hire_lead::<T, I>();
+    }: _(RawOrigin::Signed(lead_account), lead_id, text(r))
+    verify {
+        assert!(!<WorkerById<T, I>>::contains_key(lead_id));
+    }
+
+    terminate_role {
+        let r in 1 .. MAX_BYTES;
+
+        set_relaxed_constraints::<I>();
+        let (_, lead_id) = hire_lead::<T, I>();
+    }: _(RawOrigin::Root, lead_id, text(r), true)
+    verify {
+        assert!(!<WorkerById<T, I>>::contains_key(lead_id));
+    }
+
+    add_opening {
+        let t in 1 .. MAX_BYTES;
+
+        set_relaxed_constraints::<I>();
+        let opening_id = <NextOpeningId<T, I>>::get();
+    }: _(
+        RawOrigin::Root,
+        hiring::ActivateOpeningAt::CurrentBlock,
+        opening_policy_commitment::<T>(),
+        text(t),
+        OpeningType::Leader
+    )
+    verify {
+        assert!(<OpeningById<T, I>>::contains_key(opening_id));
+    }
+
+    accept_applications {
+        set_relaxed_constraints::<I>();
+        let activate_at = <system::Module<T>>::block_number() + T::BlockNumber::from(1_000u32);
+        let opening_id = add_opening::<T, I>(
+            RawOrigin::Root,
+            hiring::ActivateOpeningAt::ExactBlock(activate_at),
+            OpeningType::Leader,
+        );
+    }: _(RawOrigin::Root, opening_id)
+
+    apply_on_opening {
+        let t in 1 .. MAX_BYTES;
+
+        set_relaxed_constraints::<I>();
+        let opening_id = add_opening::<T, I>(
+            RawOrigin::Root,
+            hiring::ActivateOpeningAt::CurrentBlock,
+            OpeningType::Leader,
+        );
+
+        let (account_id, member_id) = member::<T>(0);
+        CurrencyOf::<T>::make_free_balance_be(&account_id, BalanceOf::<T>::from(3 * STAKE));
+
+        let application_id = <NextApplicationId<T, I>>::get();
+    }: _(
+        RawOrigin::Signed(account_id.clone()),
+        member_id,
+        opening_id,
+        account_id,
+        Some(BalanceOf::<T>::from(STAKE)),
+        Some(BalanceOf::<T>::from(STAKE)),
+        text(t)
+    )
+    verify {
+        assert!(<ApplicationById<T, I>>::contains_key(application_id));
+    }
+
+    withdraw_application {
+        set_relaxed_constraints::<I>();
+        let opening_id = add_opening::<T, I>(
+            RawOrigin::Root,
+            hiring::ActivateOpeningAt::CurrentBlock,
+            OpeningType::Leader,
+        );
+        let (account_id, application_id) = apply::<T, I>(opening_id, 0);
+    }: _(RawOrigin::Signed(account_id), application_id)
+
+    terminate_application {
+        set_relaxed_constraints::<I>();
+        let (lead_account, _) = hire_lead::<T, I>();
+        let opening_id = add_opening::<T, I>(
+            RawOrigin::Signed(lead_account.clone()),
+            hiring::ActivateOpeningAt::CurrentBlock,
+            OpeningType::Worker,
+        );
+        let (_, application_id) = apply::<T, I>(opening_id, 1);
+    }: _(RawOrigin::Signed(lead_account), application_id)
+
+    begin_applicant_review {
+        set_relaxed_constraints::<I>();
+        let opening_id = add_opening::<T, I>(
+            RawOrigin::Root,
+            hiring::ActivateOpeningAt::CurrentBlock,
+            OpeningType::Leader,
+        );
+        apply::<T, I>(opening_id, 0);
+    }: _(RawOrigin::Root, opening_id)
+
+    fill_opening {
+        let a in 1 .. (T::MaxWorkerNumberLimit::get() - 1);
+
+        set_relaxed_constraints::<I>();
+        let (lead_account, _) = hire_lead::<T, I>();
+        let opening_id = add_opening::<T, I>(
+            RawOrigin::Signed(lead_account.clone()),
+            hiring::ActivateOpeningAt::CurrentBlock,
+            OpeningType::Worker,
+        );
+
+        let mut application_ids = ApplicationIdSet::<T>::new();
+        for i in 1 .. a + 1 {
+            let (_, application_id) = apply::<T, I>(opening_id, i);
+            application_ids.insert(application_id);
+        }
+
+        Module::<T, I>::begin_applicant_review(
+            RawOrigin::Signed(lead_account.clone()).into(),
+            opening_id,
+        )?;
+    }: _(RawOrigin::Signed(lead_account), opening_id, application_ids, Some(reward_policy::<T>()))
+    verify {
+        assert_eq!(Module::<T, I>::active_worker_count(), a + 1);
+    }
+
+    set_opening_review {
+        let r in 0 .. (hiring::MAX_OPENING_REVIEWERS as u32);
+
+        set_relaxed_constraints::<I>();
+        let opening_id = add_opening::<T, I>(
+            RawOrigin::Root,
+            hiring::ActivateOpeningAt::CurrentBlock,
+            OpeningType::Leader,
+        );
+
+        let reviewers = (0 .. r)
+            .map(|i| account("reviewer", i, SEED))
+            .collect::<BTreeSet<T::AccountId>>();
+    }: _(RawOrigin::Root, opening_id, 2, reviewers)
+
+    score_application {
+        let t in 0 .. (hiring::MAX_REVIEW_NOTE_LENGTH as u32);
+
+        set_relaxed_constraints::<I>();
+        let opening_id = add_opening::<T, I>(
+            RawOrigin::Root,
+            hiring::ActivateOpeningAt::CurrentBlock,
+            OpeningType::Leader,
+        );
+
+        let reviewer: T::AccountId = account("reviewer", 0, SEED);
+        let mut reviewers = BTreeSet::new();
+        reviewers.insert(reviewer.clone());
+        Module::<T, I>::set_opening_review(RawOrigin::Root.into(), opening_id, 1, reviewers)?;
+
+        let (_, application_id) = apply::<T, I>(opening_id, 0);
+        Module::<T, I>::begin_applicant_review(RawOrigin::Root.into(), opening_id)?;
+    }: _(RawOrigin::Signed(reviewer), application_id, 10, text(t))
+
+    advance_review_round {
+        let a in 1 .. (T::MaxWorkerNumberLimit::get() - 1);
+
+        set_relaxed_constraints::<I>();
+        let opening_id = add_opening::<T, I>(
+            RawOrigin::Root,
+            hiring::ActivateOpeningAt::CurrentBlock,
+            OpeningType::Leader,
+        );
+        Module::<T, I>::set_opening_review(
+            RawOrigin::Root.into(),
+            opening_id,
+            2,
+            BTreeSet::new(),
+        )?;
+
+        let mut application_ids = ApplicationIdSet::<T>::new();
+        for i in 1 .. a + 1 {
+            let (_, application_id) = apply::<T, I>(opening_id, i);
+            application_ids.insert(application_id);
+        }
+        // Not shortlisted application to be unstaked.
+        apply::<T, I>(opening_id, 0);
+
+        Module::<T, I>::begin_applicant_review(RawOrigin::Root.into(), opening_id)?;
+    }: _(RawOrigin::Root, opening_id, application_ids)
+    verify {
+        let hiring_opening_id = Module::<T, I>::opening_by_id(opening_id).hiring_opening_id;
+        assert_eq!(hiring::Module::<T>::opening_review_by_id(hiring_opening_id).current_round, 2);
+    }
+
+    slash_stake {
+        set_relaxed_constraints::<I>();
+        let (_, lead_id) = hire_lead::<T, I>();
+    }: _(RawOrigin::Root, lead_id, BalanceOf::<T>::from(STAKE))
+
+    decrease_stake {
+        set_relaxed_constraints::<I>();
+        let (_, lead_id) = hire_lead::<T, I>();
+    }: _(RawOrigin::Root, lead_id, BalanceOf::<T>::from(STAKE / 2))
+
+    increase_stake {
+        set_relaxed_constraints::<I>();
+        let (lead_account, lead_id) = hire_lead::<T, I>();
+    }: _(RawOrigin::Signed(lead_account), lead_id, BalanceOf::<T>::from(STAKE / 2))
+
+    set_mint_capacity {
+        let new_capacity = minting::BalanceOf::<T>::from(1_000_000u32);
+    }: _(RawOrigin::Root, new_capacity)
+    verify {
+        assert_eq!(<minting::Module<T>>::mints(Module::<T, I>::mint()).capacity(), new_capacity);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::mock::{build_test_externalities, Test, TestWorkingGroupInstance};
+
+    #[test]
+    fn working_group_benchmarks_succeed() {
+        build_test_externalities().execute_with(|| {
+            assert!(test_benchmark_update_role_account::<Test, TestWorkingGroupInstance>().is_ok());
+            assert!(
+                test_benchmark_update_reward_account::<Test, TestWorkingGroupInstance>().is_ok()
+            );
+            assert!(
+                test_benchmark_update_reward_amount::<Test, TestWorkingGroupInstance>().is_ok()
+            );
+            assert!(test_benchmark_leave_role::<Test, TestWorkingGroupInstance>().is_ok());
+            assert!(test_benchmark_terminate_role::<Test, TestWorkingGroupInstance>().is_ok());
+            assert!(test_benchmark_add_opening::<Test, TestWorkingGroupInstance>().is_ok());
+            assert!(test_benchmark_accept_applications::<Test, TestWorkingGroupInstance>().is_ok());
+            assert!(test_benchmark_apply_on_opening::<Test, TestWorkingGroupInstance>().is_ok());
+            assert!(
+                test_benchmark_withdraw_application::<Test, TestWorkingGroupInstance>().is_ok()
+            );
+            assert!(
+                test_benchmark_terminate_application::<Test, TestWorkingGroupInstance>().is_ok()
+            );
+            assert!(
+                test_benchmark_begin_applicant_review::<Test, TestWorkingGroupInstance>().is_ok()
+            );
+            assert!(test_benchmark_fill_opening::<Test, TestWorkingGroupInstance>().is_ok());
+            assert!(test_benchmark_set_opening_review::<Test, TestWorkingGroupInstance>().is_ok());
+            assert!(test_benchmark_score_application::<Test, TestWorkingGroupInstance>().is_ok());
+            assert!(
+                test_benchmark_advance_review_round::<Test, TestWorkingGroupInstance>().is_ok()
+            );
+            assert!(test_benchmark_slash_stake::<Test, TestWorkingGroupInstance>().is_ok());
+            assert!(test_benchmark_decrease_stake::<Test, TestWorkingGroupInstance>().is_ok());
+            assert!(test_benchmark_increase_stake::<Test, TestWorkingGroupInstance>().is_ok());
+            assert!(test_benchmark_set_mint_capacity::<Test, TestWorkingGroupInstance>().is_ok());
+        });
+    }
+}
@@ -305,6 +305,72 @@ decl_error! {
 
         /// Minting error: NextAdjustmentInPast
         MintingErrorNextAdjustmentInPast,
+
+        /// Minting error: NotEnoughParentCapacity
+        MintingErrorNotEnoughParentCapacity,
+
+        /// Opening is not in the last review round.
+        FullWorkerOpeningOpeningNotInLastReviewRound,
+
+        /// Opening review cannot be changed after the review period began.
+        SetOpeningReviewAlreadyBegun,
+
+        /// Opening must have at least one review round.
+        SetOpeningReviewZeroReviewRounds,
+
+        /// Too many opening reviewers.
+        SetOpeningReviewTooManyReviewers,
+
+        /// Signer is not the reviewer of the opening.
+        NotOpeningReviewer,
+
+        /// Application is not active.
+        ScoreWorkerApplicationApplicationNotActive,
+
+        /// Opening is not in the review period.
+        ScoreWorkerApplicationOpeningNotInReviewPeriodStage,
+
+        /// Review note is too long.
+        ScoreWorkerApplicationNoteTooLong,
+
+        /// Opening is not in the review period.
+        AdvanceReviewRoundOpeningNotInReviewPeriodStage,
+
+        /// Opening is in the last review round already.
+        AdvanceReviewRoundNoReviewRoundsLeft,
+
+        /// Shortlist cannot be empty.
+        AdvanceReviewRoundEmptyShortlist,
+
+        /// Shortlisted worker application does not exist.
+        ShortlistedWorkerApplicationDoesNotExist,
+
+        /// Shortlisted application is not active.
+        AdvanceReviewRoundApplicationNotActive,
+
+        /// Shortlisted application is not for the opening.
+        AdvanceReviewRoundApplicationForWrongOpening,
+
+        /// Invalid OpeningPolicyCommitment parameter:
+        /// auto_fill_on_review_period_expiry should be non-zero.
+        AutoFillOnReviewPeriodExpiryHiresIsZero,
+
+        /// Application deadline should be after the opening activation.
+        AddWorkerOpeningApplicationDeadlineNotAfterActivation,
+
+        /// Invalid OpeningPolicyCommitment parameter:
+        /// max_hires should be non-zero.
+        AddWorkerOpeningZeroMaxHires,
+
+        /// Review cannot begin before the application deadline.
+        BeginWorkerApplicantReviewApplicationDeadlineNotReached,
+
+        /// Number of the successful applications exceeds the opening max hires.
+        FullWorkerOpeningMaxHiresExceeded,
+
+        /// Opening filled automatically on the review period expiry cannot have several review
+        /// rounds.
+        SetOpeningReviewMultipleRoundsWithAutoFill,
     }
 }
 
@@ -368,6 +434,10 @@ impl<T: Trait<I>, I: Instance> sp_std::convert::From<WrappedError<hiring::AddOpe
                     Error::AddWorkerOpeningApplicationStakeCannotBeZero
                 }
             },
+            hiring::AddOpeningError::ApplicationDeadlineNotAfterActivation => {
+                Error::AddWorkerOpeningApplicationDeadlineNotAfterActivation
+            }
+            hiring::AddOpeningError::ZeroMaxHires => Error::AddWorkerOpeningZeroMaxHires,
         }
     }
 }
@@ -383,6 +453,9 @@ impl<T: Trait<I>, I: Instance> sp_std::convert::From<WrappedError<hiring::BeginR
             hiring::BeginReviewError::OpeningNotInAcceptingApplicationsStage => {
                 Error::BeginWorkerApplicantReviewOpeningOpeningIsNotWaitingToBegin
             }
+            hiring::BeginReviewError::ApplicationDeadlineNotReached => {
+                Error::BeginWorkerApplicantReviewApplicationDeadlineNotReached
+            }
         }
     }
 }
@@ -449,6 +522,78 @@ impl<T: Trait<I>, I: Instance> sp_std::convert::From<WrappedError<hiring::FillOp
             hiring::FillOpeningError::<T>::ApplicationForWrongOpening(_application_id) => {
                 Error::FillWorkerOpeningApplicationForWrongOpening
             }
+            hiring::FillOpeningError::<T>::OpeningNotInLastReviewRound => {
+                Error::FullWorkerOpeningOpeningNotInLastReviewRound
+            }
+            hiring::FillOpeningError::<T>::MaxHiresExceeded => {
+                Error::FullWorkerOpeningMaxHiresExceeded
+            }
+        }
+    }
+}
+
+impl<T: Trait<I>, I: Instance> sp_std::convert::From<WrappedError<hiring::SetOpeningReviewError>>
+    for Error<T, I>
+{
+    fn from(wrapper: WrappedError<hiring::SetOpeningReviewError>) -> Self {
+        match wrapper.error {
+            hiring::SetOpeningReviewError::OpeningDoesNotExist => Error::OpeningDoesNotExist,
+            hiring::SetOpeningReviewError::OpeningReviewAlreadyBegun => {
+                Error::SetOpeningReviewAlreadyBegun
+            }
+            hiring::SetOpeningReviewError::ZeroReviewRounds => {
+                Error::SetOpeningReviewZeroReviewRounds
+            }
+            hiring::SetOpeningReviewError::TooManyReviewers => {
+                Error::SetOpeningReviewTooManyReviewers
+            }
+        }
+    }
+}
+
+impl<T: Trait<I>, I: Instance> sp_std::convert::From<WrappedError<hiring::ScoreApplicationError>>
+    for Error<T, I>
+{
+    fn from(wrapper: WrappedError<hiring::ScoreApplicationError>) -> Self {
+        match wrapper.error {
+            hiring::ScoreApplicationError::ApplicationDoesNotExist => {
+                Error::WorkerApplicationDoesNotExist
+            }
+            hiring::ScoreApplicationError::ApplicationNotActive => {
+                Error::ScoreWorkerApplicationApplicationNotActive
+            }
+            hiring::ScoreApplicationError::OpeningNotInReviewPeriodStage => {
+                Error::ScoreWorkerApplicationOpeningNotInReviewPeriodStage
+            }
+            hiring::ScoreApplicationError::NoteTooLong => Error::ScoreWorkerApplicationNoteTooLong,
+        }
+    }
+}
+
+impl<T: Trait<I>, I: Instance>
+    sp_std::convert::From<WrappedError<hiring::AdvanceReviewRoundError<T>>> for Error<T, I>
+{
+    fn from(wrapper: WrappedError<hiring::AdvanceReviewRoundError<T>>) -> Self {
+        match wrapper.error {
+            hiring::AdvanceReviewRoundError::<T>::OpeningDoesNotExist => Error::OpeningDoesNotExist,
+            hiring::AdvanceReviewRoundError::<T>::OpeningNotInReviewPeriodStage => {
+                Error::AdvanceReviewRoundOpeningNotInReviewPeriodStage
+            }
+            hiring::AdvanceReviewRoundError::<T>::NoReviewRoundsLeft => {
+                Error::AdvanceReviewRoundNoReviewRoundsLeft
+            }
+            hiring::AdvanceReviewRoundError::<T>::EmptyShortlist => {
+                Error::AdvanceReviewRoundEmptyShortlist
+            }
+            hiring::AdvanceReviewRoundError::<T>::ApplicationDoesNotExist(_application_id) => {
+                Error::ShortlistedWorkerApplicationDoesNotExist
+            }
+            hiring::AdvanceReviewRoundError::<T>::ApplicationNotInActiveStage(_application_id) => {
+                Error::AdvanceReviewRoundApplicationNotActive
+            }
+            hiring::AdvanceReviewRoundError::<T>::ApplicationForWrongOpening(_application_id) => {
+                Error::AdvanceReviewRoundApplicationForWrongOpening
+            }
         }
     }
 }
@@ -679,6 +824,9 @@ impl<T: Trait<I>, I: Instance> sp_std::convert::From<WrappedError<minting::Gener
         match wrapper.error {
             minting::GeneralError::MintNotFound => Error::CannotFindMint,
             minting::GeneralError::NextAdjustmentInPast => Error::MintingErrorNextAdjustmentInPast,
+            minting::GeneralError::NotEnoughParentCapacity => {
+                Error::MintingErrorNotEnoughParentCapacity
+            }
         }
     }
 }
@@ -23,6 +23,12 @@
 //! - [withdraw_application](./struct.Module.html#method.withdraw_application) - Withdraw the worker/lead application.
 //! - [terminate_application](./struct.Module.html#method.terminate_application) - Terminate the worker/lead application.
 //! - [apply_on_opening](./struct.Module.html#method.apply_on_opening) - Apply on a worker/lead opening.
+//! - [set_opening_review](./struct.Module.html#method.set_opening_review) - Set the review rounds and the reviewers of the worker/lead opening.
+//! - [score_application](./struct.Module.html#method.score_application) - Score the worker/lead application by the reviewer.
+//! - [advance_review_round](./struct.Module.html#method.advance_review_round) - Advance the worker/lead opening review to the next round with a shortlist.
+//!
+//! Openings committing to the automatic fill are filled with the top staked applicants when their
+//! review period expires.
 //!
 //! ### Roles lifecycle
 //!
@@ -46,6 +52,8 @@
 // Do not delete! Cannot be uncommented by default, because of Parity decl_module! issue.
 //#![warn(missing_docs)]
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
 #[cfg(test)]
 mod tests;
 mod types;
@@ -53,23 +61,24 @@ mod types;
 mod errors;
 
 use frame_support::dispatch::{DispatchError, DispatchResult};
-use frame_support::storage::IterableStorageMap;
+use frame_support::storage::{IterableStorageDoubleMap, IterableStorageMap};
 use frame_support::traits::{Currency, ExistenceRequirement, Get, Imbalance, WithdrawReasons};
+use frame_support::weights::Weight;
 use frame_support::{decl_event, decl_module, decl_storage, ensure, print, StorageValue};
-use sp_arithmetic::traits::{Bounded, One, Zero};
+use sp_arithmetic::traits::{Bounded, One, Saturating, UniqueSaturatedInto, Zero};
 use sp_std::collections::{btree_map::BTreeMap, btree_set::BTreeSet};
 use sp_std::vec;
 use sp_std::vec::Vec;
 use system::{ensure_root, ensure_signed};
 
-use crate::types::ExitInitiationOrigin;
+use crate::types::{ExitInitiationOrigin, LegacyOpening};
 use common::constraints::InputValidationLengthConstraint;
 use errors::WrappedError;
 
 pub use errors::Error;
 pub use types::{
-    Application, Opening, OpeningPolicyCommitment, OpeningType, RewardPolicy, RoleStakeProfile,
-    Worker,
+    Application, LegacyOpeningPolicyCommitment, Opening, OpeningPolicyCommitment, OpeningType,
+    RewardPolicy, RoleStakeProfile, Worker,
 };
 
 /// Stake identifier in staking module
@@ -78,6 +87,11 @@ pub type StakeId<T> = <T as stake::Trait>::StakeId;
 /// Member identifier in membership::member module
 pub type MemberId<T> = <T as membership::Trait>::MemberId;
 
+/// Opening policy commitment alias. Names the extrinsic parameter type in the metadata apart from
+/// the content working group 'OpeningPolicyCommitment', which has a different layout.
+pub type WorkingGroupOpeningPolicyCommitment<BlockNumber, Balance> =
+    OpeningPolicyCommitment<BlockNumber, Balance>;
+
 /// Workaround for BTreeSet type
 pub type ApplicationIdSet<T> = BTreeSet<ApplicationId<T>>;
 
@@ -143,10 +157,106 @@ type OpeningOf<T> = Opening<
     ApplicationId<T>,
 >;
 
+// Type simplification
+type LegacyOpeningOf<T> = LegacyOpening<
+    <T as hiring::Trait>::OpeningId,
+    <T as system::Trait>::BlockNumber,
+    BalanceOf<T>,
+    ApplicationId<T>,
+>;
+
 // Type simplification
 type ApplicationOf<T> =
     Application<<T as system::Trait>::AccountId, OpeningId<T>, MemberId<T>, HiringApplicationId<T>>;
 
+/// Weight information for the working group extrinsics. Length parameters are the byte lengths
+/// of the text inputs.
+pub trait WeightInfo {
+    fn update_role_account() -> Weight;
+    fn update_reward_account() -> Weight;
+    fn update_reward_amount() -> Weight;
+    fn leave_role(r: u32) -> Weight;
+    fn terminate_role(r: u32) -> Weight;
+    fn add_opening(t: u32) -> Weight;
+    fn accept_applications() -> Weight;
+    fn apply_on_opening(t: u32) -> Weight;
+    fn withdraw_application() -> Weight;
+    fn terminate_application() -> Weight;
+    fn begin_applicant_review() -> Weight;
+    /// `a` - number of the successful applications.
+    fn fill_opening(a: u32) -> Weight;
+    /// `r` - number of the reviewers.
+    fn set_opening_review(r: u32) -> Weight;
+    fn score_application(t: u32) -> Weight;
+    /// `a` - number of the shortlisted applications.
+    fn advance_review_round(a: u32) -> Weight;
+    fn slash_stake() -> Weight;
+    fn decrease_stake() -> Weight;
+    fn increase_stake() -> Weight;
+    fn set_mint_capacity() -> Weight;
+}
+
+/// Default weights, used by the tests.
+impl WeightInfo for () {
+    fn update_role_account() -> Weight {
+        10_000_000
+    }
+    fn update_reward_account() -> Weight {
+        10_000_000
+    }
+    fn update_reward_amount() -> Weight {
+        10_000_000
+    }
+    fn leave_role(_r: u32) -> Weight {
+        10_000_000
+    }
+    fn terminate_role(_r: u32) -> Weight {
+        10_000_000
+    }
+    fn add_opening(_t: u32) -> Weight {
+        10_000_000
+    }
+    fn accept_applications() -> Weight {
+        10_000_000
+    }
+    fn apply_on_opening(_t: u32) -> Weight {
+        10_000_000
+    }
+    fn withdraw_application() -> Weight {
+        10_000_000
+    }
+    fn terminate_application() -> Weight {
+        10_000_000
+    }
+    fn begin_applicant_review() -> Weight {
+        10_000_000
+    }
+    fn fill_opening(_a: u32) -> Weight {
+        10_000_000
+    }
+    fn set_opening_review(_r: u32) -> Weight {
+        10_000_000
+    }
+    fn score_application(_t: u32) -> Weight {
+        10_000_000
+    }
+    fn advance_review_round(_a: u32) -> Weight {
+        10_000_000
+    }
+    fn slash_stake() -> Weight {
+        10_000_000
+    }
+    fn decrease_stake() -> Weight {
+        10_000_000
+    }
+    fn increase_stake() -> Weight {
+        10_000_000
+    }
+    fn set_mint_capacity() -> Weight {
+        10_000_000
+    }
+}
+
 /// The _Working group_ main _Trait_
 pub trait Trait<I: Instance>:
     system::Trait
@@ -161,6 +271,9 @@ pub trait Trait<I: Instance>:
 
     /// Defines max workers number in the working group.
     type MaxWorkerNumberLimit: Get<u32>;
+
+    /// Weight information for the extrinsics of this module.
+    type WeightInfo: WeightInfo;
 }
 
 decl_event!(
@@ -175,6 +288,7 @@ decl_event!(
         RationaleText = Vec<u8>,
         MintBalanceOf = minting::BalanceOf<T>,
         <T as minting::Trait>::MintId,
+        ApplicationDeactivationCause = hiring::ApplicationDeactivationCause,
     {
         /// Emits on setting the leader.
         /// Params:
@@ -246,6 +360,12 @@ decl_event!(
         /// - Worker application id
         ApplicationTerminated(ApplicationId),
 
+        /// Emits on deactivating the application for the worker/lead opening in the hiring module.
+        /// Params:
+        /// - Worker application id
+        /// - Deactivation cause
+        ApplicationDeactivated(ApplicationId, ApplicationDeactivationCause),
+
         /// Emits on beginning the application review for the worker/lead opening.
         /// Params:
         /// - Opening id
@@ -257,6 +377,28 @@ decl_event!(
         /// - Worker application id to the worker id dictionary
         OpeningFilled(OpeningId, ApplicationIdToWorkerIdMap),
 
+        /// Emits on setting the review rounds and the reviewers of the worker/lead opening.
+        /// Params:
+        /// - Opening id
+        OpeningReviewSet(OpeningId),
+
+        /// Emits on scoring the application for the worker/lead opening.
+        /// Params:
+        /// - Worker application id
+        /// - Reviewer account id
+        ApplicationScored(ApplicationId, AccountId),
+
+        /// Emits on advancing the worker/lead opening review to the next round.
+        /// Params:
+        /// - Opening id
+        /// - New review round
+        ReviewRoundAdvanced(OpeningId, u32),
+
+        /// Emits on failing to fill the worker/lead opening on the review period expiry.
+        /// Params:
+        /// - Opening id
+        OpeningAutoFillFailed(OpeningId),
+
         /// Emits on increasing the worker/lead stake.
         /// Params:
         /// - worker/lead id.
@@ -325,6 +467,24 @@ decl_storage! {
         /// Required by StakingEventsHandler callback call to refund the balance on unstaking.
         pub MemberIdByHiringApplicationId get(fn member_id_by_hiring_application_id):
             map hasher(blake2_128_concat) HiringApplicationId<T> =>  MemberId<T>;
+
+        /// Maps hiring application id to the worker application id.
+        /// Required by the ApplicationDeactivatedHandler callback to clean up the deactivated applications.
+        pub ApplicationIdByHiringApplicationId get(fn application_id_by_hiring_application_id):
+            map hasher(blake2_128_concat) HiringApplicationId<T> => ApplicationId<T>;
+
+        /// Openings to be filled automatically by the block their review period expires at. Only
+        /// the openings due are visited when initializing the block.
+        pub OpeningIdsToAutoFillByBlock get(fn opening_ids_to_auto_fill_by_block):
+            double_map hasher(twox_64_concat) T::BlockNumber, hasher(twox_64_concat) OpeningId<T> => ();
+
+        /// Whether the openings stored by the previous runtime versions are migrated to the
+        /// current opening policy commitment.
+        pub OpeningPolicyCommitmentMigrated get(fn opening_policy_commitment_migrated): bool;
+
+        /// Whether the applications stored by the previous runtime versions are indexed by their
+        /// hiring application ids.
+        pub ApplicationIdByHiringApplicationIdMigrated get(fn application_id_by_hiring_application_id_migrated): bool;
     }
         add_extra_genesis {
         config(phantom): sp_std::marker::PhantomData<I>;
@@ -354,10 +514,16 @@ decl_module! {
         /// Exports const -  max simultaneous active worker number.
         const MaxWorkerNumberLimit: u32 = T::MaxWorkerNumberLimit::get();
 
+        /// Fills the openings with the automatic fill policy, which review period expires in
+        /// this block, before the hiring module deactivates them.
+        fn on_initialize(now: T::BlockNumber) -> Weight {
+            Self::auto_fill_openings(now)
+        }
+
         // ****************** Roles lifecycle **********************
 
         /// Update the associated role account of the active worker/lead.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait<I>>::WeightInfo::update_role_account()]
         pub fn update_role_account(
             origin,
             worker_id: WorkerId<T>,
@@ -385,7 +551,7 @@ decl_module! {
         }
 
         /// Update the reward account associated with a set reward relationship for the active worker.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait<I>>::WeightInfo::update_reward_account()]
         pub fn update_reward_account(
             origin,
             worker_id: WorkerId<T>,
@@ -417,7 +583,7 @@ decl_module! {
 
         /// Update the reward amount associated with a set reward relationship for the active worker.
         /// Require signed leader origin or the root (to update leader reward amount).
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait<I>>::WeightInfo::update_reward_amount()]
         pub fn update_reward_amount(
             origin,
             worker_id: WorkerId<T>,
@@ -451,7 +617,7 @@ decl_module! {
         }
 
         /// Leave the role by the active worker.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait<I>>::WeightInfo::leave_role(rationale_text.len() as u32)]
         pub fn leave_role(
             origin,
             worker_id: WorkerId<T>,
@@ -474,7 +640,7 @@ decl_module! {
 
         /// Terminate the active worker by the lead.
         /// Require signed leader origin or the root (to terminate the leader role).
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait<I>>::WeightInfo::terminate_role(rationale_text.len() as u32)]
         pub fn terminate_role(
             origin,
             worker_id: WorkerId<T>,
@@ -512,11 +678,11 @@ decl_module! {
 
         /// Add an opening for a worker role.
         /// Require signed leader origin or the root (to add opening for the leader position).
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait<I>>::WeightInfo::add_opening(human_readable_text.len() as u32)]
         pub fn add_opening(
             origin,
             activate_at: hiring::ActivateOpeningAt<T::BlockNumber>,
-            commitment: OpeningPolicyCommitment<T::BlockNumber, BalanceOf<T>>,
+            commitment: WorkingGroupOpeningPolicyCommitment<T::BlockNumber, BalanceOf<T>>,
             human_readable_text: Vec<u8>,
             opening_type: OpeningType,
         ){
@@ -544,6 +710,8 @@ decl_module! {
                     commitment.application_rationing_policy,
                     commitment.application_staking_policy,
                     commitment.role_staking_policy,
+                    commitment.application_deadline,
+                    commitment.max_hires,
                     human_readable_text,
             ))?;
 
@@ -557,6 +725,16 @@ decl_module! {
                 opening_type,
             };
 
+            // The review period begins at the application deadline.
+            if let Some(application_deadline) =
+                new_opening_by_id.policy_commitment.application_deadline {
+                Self::schedule_opening_auto_fill(
+                    new_opening_id,
+                    &new_opening_by_id,
+                    application_deadline
+                );
+            }
+
             OpeningById::<T, I>::insert(new_opening_id, new_opening_by_id);
 
             // Update NextOpeningId
@@ -568,7 +746,7 @@ decl_module! {
 
         /// Begin accepting worker applications to an opening that is active.
         /// Require signed leader origin or the root (to accept applications for the leader position).
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait<I>>::WeightInfo::accept_applications()]
         pub fn accept_applications(origin, opening_id: OpeningId<T>)  {
             // Ensure opening exists in this working group
             // NB: Even though call to hiring module will have implicit check for
@@ -595,7 +773,7 @@ decl_module! {
         }
 
         /// Apply on a worker opening.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait<I>>::WeightInfo::apply_on_opening(human_readable_text.len() as u32)]
         pub fn apply_on_opening(
             origin,
             member_id: T::MemberId,
@@ -677,6 +855,9 @@ decl_module! {
             // Store application
             ApplicationById::<T, I>::insert(new_application_id, application);
 
+            // Save application id to clean up the application on its deactivation.
+            ApplicationIdByHiringApplicationId::<T, I>::insert(hiring_application_id, new_application_id);
+
             // Update next application identifier value
             NextApplicationId::<T, I>::mutate(|id| *id += <ApplicationId<T> as One>::one());
 
@@ -690,7 +871,7 @@ decl_module! {
         }
 
         /// Withdraw the worker application. Can be done by the worker itself only.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait<I>>::WeightInfo::withdraw_application()]
         pub fn withdraw_application(
             origin,
             application_id: ApplicationId<T>
@@ -726,7 +907,7 @@ decl_module! {
         }
 
         /// Terminate the worker application. Can be done by the lead only.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait<I>>::WeightInfo::terminate_application()]
         pub fn terminate_application(
             origin,
             application_id: ApplicationId<T>
@@ -758,7 +939,7 @@ decl_module! {
 
         /// Begin reviewing, and therefore not accepting new applications.
         /// Require signed leader origin or the root (to begin review applications for the leader position).
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait<I>>::WeightInfo::begin_applicant_review()]
         pub fn begin_applicant_review(origin, opening_id: OpeningId<T>) {
             // Ensure opening exists
             // NB: Even though call to hiring modul will have implicit check for
@@ -778,13 +959,19 @@ decl_module! {
                 hiring::Module::<T>::begin_review(opening.hiring_opening_id)
                 )?;
 
+            Self::schedule_opening_auto_fill(
+                opening_id,
+                &opening,
+                <system::Module<T>>::block_number()
+            );
+
             // Trigger event
             Self::deposit_event(RawEvent::BeganApplicationReview(opening_id));
         }
 
         /// Fill opening for worker/lead.
         /// Require signed leader origin or the root (to fill opening for the leader position).
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait<I>>::WeightInfo::fill_opening(successful_application_ids.len() as u32)]
         pub fn fill_opening(
             origin,
             opening_id: OpeningId<T>,
@@ -796,19 +983,6 @@ decl_module! {
 
             Self::ensure_origin_for_opening_type(origin, opening.opening_type)?;
 
-            let potential_worker_number =
-                Self::active_worker_count() + (successful_application_ids.len() as u32);
-
-            ensure!(
-                potential_worker_number <= T::MaxWorkerNumberLimit::get(),
-                Error::<T, I>::MaxActiveWorkerNumberExceeded
-            );
-
-            // Cannot hire a lead when another leader exists.
-            if matches!(opening.opening_type, OpeningType::Leader) {
-                ensure!(!<CurrentLead<T,I>>::exists(), Error::<T, I>::CannotHireLeaderWhenLeaderExists);
-            }
-
             // Ensure a mint exists if lead is providing a reward for positions being filled
             let create_reward_settings = if let Some(policy) = reward_policy {
 
@@ -846,80 +1020,153 @@ decl_module! {
                 Error::<T, I>::SuccessfulWorkerApplicationDoesNotExist
             );
 
-            // Attempt to fill opening
-            let successful_application_ids = successful_iter
-                                            .clone()
-                                            .map(|(successful_application, _, _)| successful_application.hiring_application_id)
-                                            .collect::<BTreeSet<_>>();
+            // NB: Combined ensure check and mutation
+            Self::fill_opening_with_applications(
+                opening_id,
+                &opening,
+                successful_iter.collect(),
+                create_reward_settings
+            )?;
+        }
 
-            // Check for a single application for a leader.
-            if matches!(opening.opening_type, OpeningType::Leader) {
-                ensure!(successful_application_ids.len() == 1, Error::<T, I>::CannotHireMultipleLeaders);
-            }
+        /// Set the number of the review rounds and the delegated reviewers of the opening.
+        /// Applies before the review period begins.
+        /// Require signed leader origin or the root (to set the review for the leader position).
+        #[weight = <T as Trait<I>>::WeightInfo::set_opening_review(reviewers.len() as u32)]
+        pub fn set_opening_review(
+            origin,
+            opening_id: OpeningId<T>,
+            review_rounds: u32,
+            reviewers: BTreeSet<T::AccountId>
+        ) {
+            // Ensure opening exists
+            let (opening, _) = Self::ensure_opening_exists(&opening_id)?;
+
+            Self::ensure_origin_for_opening_type(origin, opening.opening_type)?;
+
+            // The opening is filled automatically in the last review round only.
+            ensure!(
+                opening.policy_commitment.auto_fill_on_review_period_expiry.is_none()
+                    || review_rounds <= 1,
+                Error::<T, I>::SetOpeningReviewMultipleRoundsWithAutoFill
+            );
+
+            //
+            // == MUTATION SAFE ==
+            //
 
             // NB: Combined ensure check and mutation in hiring module
             ensure_on_wrapped_error!(
-                hiring::Module::<T>::fill_opening(
+                hiring::Module::<T>::set_opening_review(
                     opening.hiring_opening_id,
-                    successful_application_ids,
-                    opening.policy_commitment.fill_opening_successful_applicant_application_stake_unstaking_period,
-                    opening.policy_commitment.fill_opening_failed_applicant_application_stake_unstaking_period,
-                    opening.policy_commitment.fill_opening_failed_applicant_role_stake_unstaking_period
+                    review_rounds,
+                    reviewers
                 )
             )?;
 
+            // Trigger event
+            Self::deposit_event(RawEvent::OpeningReviewSet(opening_id));
+        }
+
+        /// Attach the score and the review note to the application in the current review round.
+        /// Require signed origin of the opening reviewer or of the leader (for the worker openings).
+        #[weight = <T as Trait<I>>::WeightInfo::score_application(note.len() as u32)]
+        pub fn score_application(
+            origin,
+            application_id: ApplicationId<T>,
+            score: u32,
+            note: Vec<u8>
+        ) {
+            let signer = ensure_signed(origin)?;
+
+            // Ensure application exists
+            let (application, _, opening) = Self::ensure_application_exists(&application_id)?;
+
+            Self::ensure_is_opening_reviewer(&opening, &signer)?;
+
             //
             // == MUTATION SAFE ==
             //
 
-            // Process successful applications
-            let application_id_to_worker_id = Self::fulfill_successful_applications(
-                &opening,
-                create_reward_settings,
-                successful_iter.collect()
-            );
+            // NB: Combined ensure check and mutation in hiring module
+            ensure_on_wrapped_error!(
+                hiring::Module::<T>::score_application(
+                    application.hiring_application_id,
+                    signer.clone(),
+                    score,
+                    note
+                )
+            )?;
 
             // Trigger event
-            Self::deposit_event(RawEvent::OpeningFilled(opening_id, application_id_to_worker_id));
+            Self::deposit_event(RawEvent::ApplicationScored(application_id, signer));
         }
 
-        // ****************** Stakes **********************
-
-        /// Slashes the worker stake, demands a leader origin. No limits, no actions on zero stake.
-        /// If slashing balance greater than the existing stake - stake is slashed to zero.
-        /// Require signed leader origin or the root (to slash the leader stake).
-        #[weight = 10_000_000] // TODO: adjust weight
-        pub fn slash_stake(origin, worker_id: WorkerId<T>, balance: BalanceOf<T>) {
-            // Ensure lead is set or it is the council terminating the leader.
-            Self::ensure_origin_for_leader(origin, worker_id)?;
+        /// Advance the opening review to the next round. All active applications not in the
+        /// shortlist are deactivated.
+        /// Require signed leader origin or the root (to advance the review for the leader position).
+        #[weight = <T as Trait<I>>::WeightInfo::advance_review_round(
+            shortlisted_application_ids.len() as u32
+        )]
+        pub fn advance_review_round(
+            origin,
+            opening_id: OpeningId<T>,
+            shortlisted_application_ids: ApplicationIdSet<T>
+        ) {
+            // Ensure opening exists
+            let (opening, _) = Self::ensure_opening_exists(&opening_id)?;
 
-            // Ensuring worker actually exists.
-            let worker = Self::ensure_worker_exists(&worker_id)?;
+            Self::ensure_origin_for_opening_type(origin, opening.opening_type)?;
 
-            ensure!(balance != <BalanceOf<T>>::zero(), Error::<T, I>::StakeBalanceCannotBeZero);
+            // Ensure all shortlisted applications exist
+            let shortlisted_hiring_application_ids = shortlisted_application_ids
+                .iter()
+                .map(|application_id| {
+                    ensure!(
+                        ApplicationById::<T, I>::contains_key(application_id),
+                        Error::<T, I>::ShortlistedWorkerApplicationDoesNotExist
+                    );
 
-            let stake_profile = worker.role_stake_profile.ok_or(Error::<T, I>::NoWorkerStakeProfile)?;
+                    Ok(ApplicationById::<T, I>::get(application_id).hiring_application_id)
+                })
+                .collect::<Result<BTreeSet<_>, Error<T, I>>>()?;
 
             //
             // == MUTATION SAFE ==
             //
 
-            // This external module call both checks and mutates the state.
+            // NB: Combined ensure check and mutation in hiring module
             ensure_on_wrapped_error!(
-                <stake::Module<T>>::slash_immediate(
-                    &stake_profile.stake_id,
-                    balance,
-                    false
+                hiring::Module::<T>::advance_review_round(
+                    opening.hiring_opening_id,
+                    shortlisted_hiring_application_ids
                 )
             )?;
 
-            Self::deposit_event(RawEvent::StakeSlashed(worker_id));
+            let new_review_round =
+                hiring::Module::<T>::opening_review_by_id(opening.hiring_opening_id).current_round;
+
+            // Trigger event
+            Self::deposit_event(RawEvent::ReviewRoundAdvanced(opening_id, new_review_round));
+        }
+
+        // ****************** Stakes **********************
+
+        /// Slashes the worker stake, demands a leader origin. No limits, no actions on zero stake.
+        /// If slashing balance greater than the existing stake - stake is slashed to zero.
+        /// Require signed leader origin or the root (to slash the leader stake).
+        #[weight = <T as Trait<I>>::WeightInfo::slash_stake()]
+        pub fn slash_stake(origin, worker_id: WorkerId<T>, balance: BalanceOf<T>) {
+            // Ensure lead is set or it is the council terminating the leader.
+            Self::ensure_origin_for_leader(origin, worker_id)?;
+
+            Self::slash_worker_stake(worker_id, balance)?;
         }
 
         /// Decreases the worker/lead stake and returns the remainder to the worker role_account_id.
         /// Can be decreased to zero, no actions on zero stake.
         /// Require signed leader origin or the root (to decrease the leader stake).
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait<I>>::WeightInfo::decrease_stake()]
         pub fn decrease_stake(origin, worker_id: WorkerId<T>, balance: BalanceOf<T>) {
             // Ensure lead is set or it is the council terminating the leader.
             Self::ensure_origin_for_leader(origin, worker_id)?;
@@ -948,7 +1195,7 @@ decl_module! {
 
         /// Increases the worker/lead stake, demands a worker origin. Transfers tokens from the worker
         /// role_account_id to the stake. No limits on the stake.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait<I>>::WeightInfo::increase_stake()]
         pub fn increase_stake(origin, worker_id: WorkerId<T>, balance: BalanceOf<T>) {
             // Checks worker origin, worker existence
             let worker = Self::ensure_worker_signed(origin, &worker_id)?;
@@ -974,7 +1221,8 @@ decl_module! {
         }
 
         /// Sets the capacity to enable working group budget. Requires root origin.
-        #[weight = 10_000_000] // TODO: adjust weight
+        #[weight = <T as Trait<I>>::WeightInfo::set_mint_capacity()
+            .saturating_add(<recurringrewards::Module<T>>::pay_arrears_weight())]
         pub fn set_mint_capacity(
             origin,
             new_capacity: minting::BalanceOf<T>
@@ -1000,6 +1248,12 @@ decl_module! {
                     <minting::Module<T>>::set_mint_capacity(mint_id, new_capacity)
                 )?;
 
+                // Rewards owed to the workers are paid first. The capacity removed is returned to
+                // the parent mint, which pays its own arrears.
+                if new_capacity > current_capacity {
+                    <recurringrewards::Module<T>>::pay_arrears(mint_id);
+                }
+
                 Self::deposit_event(RawEvent::MintCapacityChanged(mint_id, new_capacity));
             }
         }
@@ -1101,6 +1355,13 @@ impl<T: Trait<I>, I: Instance> Module<T, I> {
             );
         }
 
+        if let Some(hires) = policy_commitment.auto_fill_on_review_period_expiry {
+            ensure!(
+                hires > 0,
+                Error::<T, I>::AutoFillOnReviewPeriodExpiryHiresIsZero
+            );
+        }
+
         Ok(())
     }
 
@@ -1137,6 +1398,25 @@ impl<T: Trait<I>, I: Instance> Module<T, I> {
         Ok(exit_origin)
     }
 
+    // Checks that the account may score the applications of the opening: the delegated opening
+    // reviewers can score any opening, the leader can score the worker openings.
+    fn ensure_is_opening_reviewer(
+        opening: &OpeningOf<T>,
+        account_id: &T::AccountId,
+    ) -> DispatchResult {
+        if hiring::Module::<T>::is_opening_reviewer(opening.hiring_opening_id, account_id) {
+            return Ok(());
+        }
+
+        if matches!(opening.opening_type, OpeningType::Worker)
+            && Self::ensure_is_lead_account(account_id.clone()).is_ok()
+        {
+            return Ok(());
+        }
+
+        Err(Error::<T, I>::NotOpeningReviewer.into())
+    }
+
     fn ensure_lead_is_set() -> Result<WorkerId<T>, Error<T, I>> {
         let leader_worker_id = Self::current_lead();
 
@@ -1355,6 +1635,10 @@ impl<T: Trait<I>, I: Instance> Module<T, I> {
 
         let member_id = Module::<T, I>::member_id_by_hiring_application_id(hiring_application_id);
 
+        // Notify the hiring module: the application is deactivated once all its stakes are
+        // unstaked, which cleans up the member id.
+        hiring::Module::<T>::unstaked(stake_id);
+
         if membership::MembershipById::<T>::contains_key(member_id) {
             let member_profile = membership::MembershipById::<T>::get(member_id);
             let refunding_result = CurrencyOf::<T>::resolve_into_existing(
@@ -1402,6 +1686,38 @@ impl<T: Trait<I>, I: Instance> Module<T, I> {
             .collect()
     }
 
+    /// Slashes the worker stake without the origin checks. Used by the `slash_stake` extrinsic
+    /// and by the other modules slashing the workers (e.g. for the failed duties).
+    /// If slashing balance greater than the existing stake - stake is slashed to zero.
+    pub fn slash_worker_stake(worker_id: WorkerId<T>, balance: BalanceOf<T>) -> DispatchResult {
+        // Ensuring worker actually exists.
+        let worker = Self::ensure_worker_exists(&worker_id)?;
+
+        ensure!(
+            balance != <BalanceOf<T>>::zero(),
+            Error::<T, I>::StakeBalanceCannotBeZero
+        );
+
+        let stake_profile = worker
+            .role_stake_profile
+            .ok_or(Error::<T, I>::NoWorkerStakeProfile)?;
+
+        //
+        // == MUTATION SAFE ==
+        //
+
+        // This external module call both checks and mutates the state.
+        ensure_on_wrapped_error!(<stake::Module<T>>::slash_immediate(
+            &stake_profile.stake_id,
+            balance,
+            false
+        ))?;
+
+        Self::deposit_event(RawEvent::StakeSlashed(worker_id));
+
+        Ok(())
+    }
+
     fn make_stake_opt_imbalance(
         opt_balance: &Option<BalanceOf<T>>,
         source_account: &T::AccountId,
@@ -1482,6 +1798,29 @@ impl<T: Trait<I>, I: Instance> Module<T, I> {
         Ok(())
     }
 
+    /// Initializes the working group instance added by a runtime upgrade. Does nothing for the
+    /// instances initialized at genesis or by a previous runtime upgrade.
+    pub fn initialize_on_runtime_upgrade(
+        opening_human_readable_text_constraint: InputValidationLengthConstraint,
+        worker_application_human_readable_text_constraint: InputValidationLengthConstraint,
+        worker_exit_rationale_text_constraint: InputValidationLengthConstraint,
+        working_group_mint_capacity: minting::BalanceOf<T>,
+    ) -> Weight {
+        // The mint is created on the initialization.
+        if <Mint<T, I>>::exists() {
+            return T::DbWeight::get().reads(1);
+        }
+
+        Self::initialize_working_group(
+            opening_human_readable_text_constraint,
+            worker_application_human_readable_text_constraint,
+            worker_exit_rationale_text_constraint,
+            working_group_mint_capacity,
+        );
+
+        T::DbWeight::get().reads_writes(3, 8)
+    }
+
     // Initialize working group constraints and mint.
     pub(crate) fn initialize_working_group(
         opening_human_readable_text_constraint: InputValidationLengthConstraint,
@@ -1504,6 +1843,349 @@ impl<T: Trait<I>, I: Instance> Module<T, I> {
             worker_application_human_readable_text_constraint,
         );
         <WorkerExitRationaleText<I>>::put(worker_exit_rationale_text_constraint);
+
+        // Openings are created with the current opening policy commitment.
+        <OpeningPolicyCommitmentMigrated<I>>::put(true);
+
+        // Applications are indexed by their hiring application ids on creation.
+        <ApplicationIdByHiringApplicationIdMigrated<I>>::put(true);
+    }
+
+    // Fills the opening with the existing applications: checks the worker limits, fills the
+    // hiring opening and creates the workers.
+    fn fill_opening_with_applications(
+        opening_id: OpeningId<T>,
+        opening: &OpeningOf<T>,
+        successful_applications: Vec<ApplicationInfo<T>>,
+        reward_settings: Option<RewardSettings<T>>,
+    ) -> DispatchResult {
+        let potential_worker_number =
+            Self::active_worker_count() + (successful_applications.len() as u32);
+
+        ensure!(
+            potential_worker_number <= T::MaxWorkerNumberLimit::get(),
+            Error::<T, I>::MaxActiveWorkerNumberExceeded
+        );
+
+        if matches!(opening.opening_type, OpeningType::Leader) {
+            // Cannot hire a lead when another leader exists.
+            ensure!(
+                !<CurrentLead<T, I>>::exists(),
+                Error::<T, I>::CannotHireLeaderWhenLeaderExists
+            );
+
+            // Check for a single application for a leader.
+            ensure!(
+                successful_applications.len() == 1,
+                Error::<T, I>::CannotHireMultipleLeaders
+            );
+        }
+
+        let successful_hiring_application_ids = successful_applications
+            .iter()
+            .map(|(successful_application, _, _)| successful_application.hiring_application_id)
+            .collect::<BTreeSet<_>>();
+
+        // NB: Combined ensure check and mutation in hiring module
+        ensure_on_wrapped_error!(hiring::Module::<T>::fill_opening(
+            opening.hiring_opening_id,
+            successful_hiring_application_ids,
+            opening
+                .policy_commitment
+                .fill_opening_successful_applicant_application_stake_unstaking_period,
+            opening
+                .policy_commitment
+                .fill_opening_failed_applicant_application_stake_unstaking_period,
+            opening
+                .policy_commitment
+                .fill_opening_failed_applicant_role_stake_unstaking_period
+        ))?;
+
+        //
+        // == MUTATION SAFE ==
+        //
+
+        // Process successful applications
+        let application_id_to_worker_id = Self::fulfill_successful_applications(
+            opening,
+            reward_settings,
+            successful_applications,
+        );
+
+        // Trigger event
+        Self::deposit_event(RawEvent::OpeningFilled(
+            opening_id,
+            application_id_to_worker_id,
+        ));
+
+        Ok(())
+    }
+
+    // Schedules the automatic fill of the opening, if committed to, at its review period expiry.
+    fn schedule_opening_auto_fill(
+        opening_id: OpeningId<T>,
+        opening: &OpeningOf<T>,
+        started_review_period_at_block: T::BlockNumber,
+    ) {
+        if opening
+            .policy_commitment
+            .auto_fill_on_review_period_expiry
+            .is_some()
+        {
+            let review_period_expires_at = started_review_period_at_block
+                .saturating_add(opening.policy_commitment.max_review_period_length);
+
+            <OpeningIdsToAutoFillByBlock<T, I>>::insert(review_period_expires_at, opening_id, ());
+        }
+    }
+
+    // Fills the openings with the automatic fill policy, which review period expires at the
+    // given block. Returns the consumed weight.
+    fn auto_fill_openings(now: T::BlockNumber) -> Weight {
+        let opening_ids = <OpeningIdsToAutoFillByBlock<T, I>>::iter_prefix(now)
+            .map(|(opening_id, _)| opening_id)
+            .collect::<Vec<_>>();
+
+        <OpeningIdsToAutoFillByBlock<T, I>>::remove_prefix(now);
+
+        let mut weight = T::DbWeight::get()
+            .reads_writes(opening_ids.len() as Weight, opening_ids.len() as Weight);
+
+        for opening_id in opening_ids {
+            let opening = Self::opening_by_id(opening_id);
+            let hiring_opening = hiring::OpeningById::<T>::get(opening.hiring_opening_id);
+
+            weight = weight.saturating_add(T::DbWeight::get().reads(2));
+
+            // Openings filled or cancelled in time are deactivated.
+            let review_period_expires_now = match hiring_opening.stage {
+                hiring::OpeningStage::Active {
+                    stage:
+                        hiring::ActiveOpeningStage::ReviewPeriod {
+                            started_review_period_at_block,
+                            ..
+                        },
+                    ..
+                } => {
+                    started_review_period_at_block + hiring_opening.max_review_period_length == now
+                }
+                _ => false,
+            };
+
+            if !review_period_expires_now {
+                continue;
+            }
+
+            let hires = opening
+                .policy_commitment
+                .auto_fill_on_review_period_expiry
+                .unwrap_or_default()
+                .min(
+                    opening
+                        .policy_commitment
+                        .max_hires
+                        .unwrap_or_else(u32::max_value),
+                );
+
+            let successful_applications = Self::top_staked_active_applications(&opening, hires);
+
+            weight = weight
+                .saturating_add(T::DbWeight::get().reads(3 * opening.applications.len() as Weight));
+
+            if successful_applications.is_empty() {
+                continue;
+            }
+
+            let hired = successful_applications.len() as u32;
+
+            let reward_settings = opening
+                .policy_commitment
+                .auto_fill_reward_policy
+                .clone()
+                .map(|policy| {
+                    // The committed balance is converted to the mint balance.
+                    let amount_per_payout: u128 = policy.amount_per_payout.unique_saturated_into();
+
+                    (
+                        Self::mint(),
+                        RewardPolicy {
+                            amount_per_payout: amount_per_payout.unique_saturated_into(),
+                            next_payment_at_block: policy
+                                .next_payment_at_block
+                                .max(now + One::one()),
+                            payout_interval: policy.payout_interval,
+                        },
+                    )
+                });
+
+            // The hiring module deactivates the opening in this block on failure.
+            if Self::fill_opening_with_applications(
+                opening_id,
+                &opening,
+                successful_applications,
+                reward_settings,
+            )
+            .is_ok()
+            {
+                weight = weight.saturating_add(<T as Trait<I>>::WeightInfo::fill_opening(hired));
+            } else {
+                Self::deposit_event(RawEvent::OpeningAutoFillFailed(opening_id));
+            }
+        }
+
+        weight
+    }
+
+    // Selects the active applications of the opening with the largest combined application and
+    // role stake. Earlier applications win the ties. Respects the worker limits.
+    fn top_staked_active_applications(
+        opening: &OpeningOf<T>,
+        hires: u32,
+    ) -> Vec<ApplicationInfo<T>> {
+        let hires = match opening.opening_type {
+            OpeningType::Leader => {
+                if <CurrentLead<T, I>>::exists() {
+                    0
+                } else {
+                    hires.min(1)
+                }
+            }
+            OpeningType::Worker => hires
+                .min(T::MaxWorkerNumberLimit::get().saturating_sub(Self::active_worker_count())),
+        };
+
+        let stake_amount = |opt_stake_id: Option<StakeId<T>>| -> BalanceOf<T> {
+            opt_stake_id
+                .map(
+                    |stake_id| match <stake::Module<T>>::stakes(stake_id).staking_status {
+                        stake::StakingStatus::Staked(staked_state) => staked_state.staked_amount,
+                        _ => Zero::zero(),
+                    },
+                )
+                .unwrap_or_else(Zero::zero)
+        };
+
+        let mut ranked_applications = opening
+            .applications
+            .iter()
+            .filter_map(|application_id| {
+                let application = Self::application_by_id(application_id);
+                let hiring_application =
+                    hiring::ApplicationById::<T>::get(application.hiring_application_id);
+
+                if hiring_application.stage != hiring::ApplicationStage::Active {
+                    return None;
+                }
+
+                let combined_stake = stake_amount(hiring_application.active_application_staking_id)
+                    .saturating_add(stake_amount(hiring_application.active_role_staking_id));
+
+                Some((
+                    combined_stake,
+                    hiring_application.application_index_in_opening,
+                    (application, *application_id, opening.clone()),
+                ))
+            })
+            .collect::<Vec<_>>();
+
+        // Larger stake first, then earlier application first.
+        ranked_applications.sort_by(|(stake_a, index_a, _), (stake_b, index_b, _)| {
+            stake_b.cmp(stake_a).then(index_a.cmp(index_b))
+        });
+
+        ranked_applications
+            .into_iter()
+            .take(hires as usize)
+            .map(|(_, _, application_info)| application_info)
+            .collect()
+    }
+
+    /// Moves the opening policy commitments of the openings created by the previous runtime
+    /// versions to the current format. Does nothing once migrated.
+    pub fn migrate_opening_policy_commitments() -> Weight {
+        if Self::opening_policy_commitment_migrated() {
+            return T::DbWeight::get().reads(1);
+        }
+
+        // Openings are never removed.
+        let openings_count: Weight = Self::next_opening_id().unique_saturated_into();
+
+        <OpeningById<T, I>>::translate(|_, opening: LegacyOpeningOf<T>| {
+            Some(Opening {
+                hiring_opening_id: opening.hiring_opening_id,
+                applications: opening.applications,
+                policy_commitment: opening.policy_commitment.migrate(),
+                opening_type: opening.opening_type,
+            })
+        });
+
+        <OpeningPolicyCommitmentMigrated<I>>::put(true);
+
+        T::DbWeight::get().reads_writes(1 + openings_count, 1 + openings_count)
+    }
+
+    /// Indexes the applications created by the previous runtime versions by their hiring
+    /// application ids. Does nothing once migrated.
+    pub fn migrate_application_ids_by_hiring_application_id() -> Weight {
+        if Self::application_id_by_hiring_application_id_migrated() {
+            return T::DbWeight::get().reads(1);
+        }
+
+        let mut applications_count: Weight = 0;
+
+        for (application_id, application) in <ApplicationById<T, I>>::iter() {
+            <ApplicationIdByHiringApplicationId<T, I>>::insert(
+                application.hiring_application_id,
+                application_id,
+            );
+
+            applications_count += 1;
+        }
+
+        <ApplicationIdByHiringApplicationIdMigrated<I>>::put(true);
+
+        T::DbWeight::get().reads_writes(1 + applications_count, 1 + applications_count)
+    }
+
+    /// Cleans up the worker/lead application deactivated in the hiring module. Returns false if
+    /// the hiring application doesn't belong to this working group.
+    pub fn application_deactivated(
+        hiring_application_id: &HiringApplicationId<T>,
+        cause: hiring::ApplicationDeactivationCause,
+    ) -> bool {
+        if !<MemberIdByHiringApplicationId<T, I>>::contains_key(hiring_application_id) {
+            return false;
+        }
+
+        // Hired applications are kept along with the member id to refund the role stake.
+        if cause == hiring::ApplicationDeactivationCause::Hired {
+            return true;
+        }
+
+        // The handler is called once more for the staked applications on the unstaking completion.
+        if <ApplicationIdByHiringApplicationId<T, I>>::contains_key(hiring_application_id) {
+            let application_id =
+                <ApplicationIdByHiringApplicationId<T, I>>::take(hiring_application_id);
+            let application = <ApplicationById<T, I>>::take(application_id);
+
+            <OpeningById<T, I>>::mutate(application.opening_id, |opening| {
+                opening.applications.remove(&application_id);
+            });
+
+            Self::deposit_event(RawEvent::ApplicationDeactivated(application_id, cause));
+        }
+
+        // Member id is kept to refund the pending application stake.
+        let hiring_application = <hiring::ApplicationById<T>>::get(hiring_application_id);
+        let no_stakes_left = matches!(
+            hiring_application.stage,
+            hiring::ApplicationStage::Inactive { .. }
+        );
+        if no_stakes_left {
+            <MemberIdByHiringApplicationId<T, I>>::remove(hiring_application_id);
+        }
+
+        true
     }
 
     // Set worker id as a leader id.
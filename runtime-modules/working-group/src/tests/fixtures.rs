@@ -782,6 +782,7 @@ impl EventFixture {
             Vec<u8>,
             u64,
             u64,
+            hiring::ApplicationDeactivationCause,
             TestWorkingGroupInstance,
         >,
     ) {
@@ -31,6 +31,7 @@ impl_outer_event! {
         balances<T>,
         working_group TestWorkingGroupInstance <T>,
         membership_mod<T>,
+        recurringrewards<T>,
         system<T>,
     }
 }
@@ -79,13 +80,20 @@ impl system::Trait for Test {
 impl hiring::Trait for Test {
     type OpeningId = u64;
     type ApplicationId = u64;
-    type ApplicationDeactivatedHandler = ();
+    type ApplicationDeactivatedHandler = ApplicationDeactivatedHandler;
     type StakeHandlerProvider = hiring::Module<Self>;
 }
 
+parameter_types! {
+    pub const MintSpendingEpochLength: u64 = 100;
+}
+
 impl minting::Trait for Test {
     type Currency = Balances;
     type MintId = u64;
+    type SpendingEpochLength = MintSpendingEpochLength;
+    type MintCapacityIncreased = ();
+    type WeightInfo = ();
 }
 
 impl stake::Trait for Test {
@@ -102,6 +110,7 @@ impl membership::Trait for Test {
     type PaidTermId = u64;
     type SubscriptionId = u64;
     type ActorId = u64;
+    type WeightInfo = ();
 }
 
 impl common::currency::GovernanceCurrency for Test {
@@ -123,6 +132,7 @@ impl balances::Trait for Test {
 }
 
 impl recurringrewards::Trait for Test {
+    type Event = TestEvent;
     type PayoutStatusHandler = ();
     type RecipientId = u64;
     type RewardRelationshipId = u64;
@@ -138,6 +148,7 @@ parameter_types! {
 impl Trait<TestWorkingGroupInstance> for Test {
     type Event = TestEvent;
     type MaxWorkerNumberLimit = MaxWorkerNumberLimit;
+    type WeightInfo = ();
 }
 
 pub type Membership = membership::Module<Test>;
@@ -220,6 +231,15 @@ impl<T: stake::Trait + crate::Trait<TestWorkingGroupInstance>> stake::StakingEve
     }
 }
 
+pub struct ApplicationDeactivatedHandler;
+
+impl hiring::ApplicationDeactivatedHandler<Test> for ApplicationDeactivatedHandler {
+    /// Clean up the deactivated application of the test working group
+    fn deactivated(application_id: &u64, cause: hiring::ApplicationDeactivationCause) {
+        TestWorkingGroup::application_deactivated(application_id, cause);
+    }
+}
+
 // Recommendation from Parity on testing on_finalize
 // https://substrate.dev/docs/en/next/development/module/tests
 pub fn run_to_block(n: u64) {
@@ -1,20 +1,25 @@
 mod fixtures;
 mod hiring_workflow;
-mod mock;
+pub(crate) mod mock;
 
 use frame_support::dispatch::DispatchError;
 use frame_support::storage::{StorageMap, StorageValue};
-use std::collections::BTreeMap;
+use frame_support::traits::OnFinalize;
+use std::collections::{BTreeMap, BTreeSet};
 use system::RawOrigin;
 
 use crate::tests::hiring_workflow::HiringWorkflow;
 use crate::types::{OpeningPolicyCommitment, OpeningType, RewardPolicy};
-use crate::{Error, RawEvent, Worker};
+use crate::{
+    ApplicationById, ApplicationIdByHiringApplicationId, Error, MemberIdByHiringApplicationId,
+    RawEvent, Worker,
+};
 use common::constraints::InputValidationLengthConstraint;
 use fixtures::*;
 use mock::{
-    build_test_externalities, run_to_block, Test, TestWorkingGroup, TestWorkingGroupInstance,
-    WORKING_GROUP_CONSTRAINT_DIFF, WORKING_GROUP_CONSTRAINT_MIN, WORKING_GROUP_MINT_CAPACITY,
+    build_test_externalities, run_to_block, System, Test, TestEvent, TestWorkingGroup,
+    TestWorkingGroupInstance, WORKING_GROUP_CONSTRAINT_DIFF, WORKING_GROUP_CONSTRAINT_MIN,
+    WORKING_GROUP_MINT_CAPACITY,
 };
 
 #[test]
@@ -593,6 +598,205 @@ fn withdraw_worker_application_succeeds() {
     });
 }
 
+fn assert_application_removed(opening_id: u64, application_id: u64, hiring_application_id: u64) {
+    assert!(!ApplicationById::<Test, TestWorkingGroupInstance>::contains_key(application_id));
+    assert!(!ApplicationIdByHiringApplicationId::<
+        Test,
+        TestWorkingGroupInstance,
+    >::contains_key(hiring_application_id));
+    assert!(!MemberIdByHiringApplicationId::<
+        Test,
+        TestWorkingGroupInstance,
+    >::contains_key(hiring_application_id));
+    assert!(!TestWorkingGroup::opening_by_id(opening_id)
+        .applications
+        .contains(&application_id));
+}
+
+#[test]
+fn withdraw_worker_application_cleans_up_application() {
+    build_test_externalities().execute_with(|| {
+        run_to_block(1);
+
+        HireLeadFixture::default().hire_lead();
+
+        let add_opening_fixture = AddWorkerOpeningFixture::default();
+        let opening_id = add_opening_fixture.call_and_assert(Ok(()));
+
+        let apply_on_opening_fixture =
+            ApplyOnWorkerOpeningFixture::default_for_opening_id(opening_id);
+        let application_id = apply_on_opening_fixture.call_and_assert(Ok(()));
+
+        let hiring_application_id =
+            TestWorkingGroup::application_by_id(application_id).hiring_application_id;
+        assert_eq!(
+            TestWorkingGroup::application_id_by_hiring_application_id(hiring_application_id),
+            application_id
+        );
+
+        let withdraw_application_fixture =
+            WithdrawApplicationFixture::default_for_application_id(application_id);
+        withdraw_application_fixture.call_and_assert(Ok(()));
+
+        assert!(System::events().iter().any(|record| record.event
+            == TestEvent::working_group_TestWorkingGroupInstance(
+                RawEvent::ApplicationDeactivated(
+                    application_id,
+                    hiring::ApplicationDeactivationCause::External,
+                )
+            )));
+
+        assert_application_removed(opening_id, application_id, hiring_application_id);
+    });
+}
+
+#[test]
+fn withdraw_staked_worker_application_cleans_up_application_after_unstaking() {
+    build_test_externalities().execute_with(|| {
+        run_to_block(1);
+
+        let stake_balance = 100;
+        let unstaking_period = 3;
+
+        increase_total_balance_issuance_using_account_id(1, 10000);
+        let balance = get_balance(1);
+
+        HireLeadFixture::default().hire_lead();
+
+        let add_opening_fixture =
+            AddWorkerOpeningFixture::default().with_policy_commitment(OpeningPolicyCommitment {
+                application_staking_policy: Some(hiring::StakingPolicy {
+                    amount: stake_balance,
+                    ..hiring::StakingPolicy::default()
+                }),
+                exit_role_application_stake_unstaking_period: Some(unstaking_period),
+                ..OpeningPolicyCommitment::default()
+            });
+        let opening_id = add_opening_fixture.call_and_assert(Ok(()));
+
+        let apply_on_opening_fixture =
+            ApplyOnWorkerOpeningFixture::default_for_opening_id(opening_id)
+                .with_application_stake(stake_balance);
+        let application_id = apply_on_opening_fixture.call_and_assert(Ok(()));
+
+        let hiring_application_id =
+            TestWorkingGroup::application_by_id(application_id).hiring_application_id;
+
+        let withdraw_application_fixture =
+            WithdrawApplicationFixture::default_for_application_id(application_id);
+        withdraw_application_fixture.call_and_assert(Ok(()));
+
+        // The member id is kept until the application stake is refunded.
+        assert!(MemberIdByHiringApplicationId::<
+            Test,
+            TestWorkingGroupInstance,
+        >::contains_key(hiring_application_id));
+
+        for _ in 0..unstaking_period {
+            <stake::Module<Test> as OnFinalize<u64>>::on_finalize(System::block_number());
+        }
+
+        assert_eq!(get_balance(1), balance);
+        assert!(matches!(
+            hiring::Module::<Test>::application_by_id(hiring_application_id).stage,
+            hiring::ApplicationStage::Inactive { .. }
+        ));
+        assert_application_removed(opening_id, application_id, hiring_application_id);
+    });
+}
+
+#[test]
+fn terminate_worker_application_cleans_up_application() {
+    build_test_externalities().execute_with(|| {
+        run_to_block(1);
+
+        HireLeadFixture::default().hire_lead();
+
+        let add_opening_fixture = AddWorkerOpeningFixture::default();
+        let opening_id = add_opening_fixture.call_and_assert(Ok(()));
+
+        let apply_on_opening_fixture =
+            ApplyOnWorkerOpeningFixture::default_for_opening_id(opening_id);
+        let application_id = apply_on_opening_fixture.call_and_assert(Ok(()));
+
+        let hiring_application_id =
+            TestWorkingGroup::application_by_id(application_id).hiring_application_id;
+
+        let terminate_application_fixture =
+            TerminateApplicationFixture::default_for_application_id(application_id);
+        terminate_application_fixture.call_and_assert(Ok(()));
+
+        assert!(System::events().iter().any(|record| record.event
+            == TestEvent::working_group_TestWorkingGroupInstance(
+                RawEvent::ApplicationDeactivated(
+                    application_id,
+                    hiring::ApplicationDeactivationCause::External,
+                )
+            )));
+
+        assert_application_removed(opening_id, application_id, hiring_application_id);
+    });
+}
+
+#[test]
+fn fill_opening_keeps_hired_and_cleans_up_not_hired_applications() {
+    build_test_externalities().execute_with(|| {
+        run_to_block(1);
+
+        HireLeadFixture::default().hire_lead();
+
+        let add_opening_fixture = AddWorkerOpeningFixture::default();
+        let opening_id = add_opening_fixture.call_and_assert(Ok(()));
+
+        let hired_application_id =
+            ApplyOnWorkerOpeningFixture::default_for_opening_id(opening_id).call_and_assert(Ok(()));
+        let not_hired_application_id =
+            ApplyOnWorkerOpeningFixture::default_for_opening_id(opening_id)
+                .with_origin(RawOrigin::Signed(2), 2)
+                .call_and_assert(Ok(()));
+
+        let hired_hiring_application_id =
+            TestWorkingGroup::application_by_id(hired_application_id).hiring_application_id;
+        let not_hired_hiring_application_id =
+            TestWorkingGroup::application_by_id(not_hired_application_id).hiring_application_id;
+
+        BeginReviewWorkerApplicationsFixture::default_for_opening_id(opening_id)
+            .call_and_assert(Ok(()));
+
+        FillWorkerOpeningFixture::default_for_ids(opening_id, vec![hired_application_id])
+            .call_and_assert(Ok(()));
+
+        assert!(
+            ApplicationById::<Test, TestWorkingGroupInstance>::contains_key(hired_application_id)
+        );
+        assert!(MemberIdByHiringApplicationId::<
+            Test,
+            TestWorkingGroupInstance,
+        >::contains_key(hired_hiring_application_id));
+        assert!(TestWorkingGroup::opening_by_id(opening_id)
+            .applications
+            .contains(&hired_application_id));
+
+        assert_application_removed(
+            opening_id,
+            not_hired_application_id,
+            not_hired_hiring_application_id,
+        );
+    });
+}
+
+#[test]
+fn application_deactivated_ignores_application_of_other_group() {
+    build_test_externalities().execute_with(|| {
+        let invalid_hiring_application_id = 66;
+
+        assert!(!TestWorkingGroup::application_deactivated(
+            &invalid_hiring_application_id,
+            hiring::ApplicationDeactivationCause::External
+        ));
+    });
+}
+
 #[test]
 fn withdraw_worker_application_fails_invalid_application_id() {
     build_test_externalities().execute_with(|| {
@@ -659,9 +863,13 @@ fn withdraw_worker_application_fails_with_hiring_error() {
             ApplyOnWorkerOpeningFixture::default_for_opening_id(opening_id);
         let application_id = apply_on_opening_fixture.call_and_assert(Ok(()));
 
+        BeginReviewWorkerApplicationsFixture::default_for_opening_id(opening_id)
+            .call_and_assert(Ok(()));
+        FillWorkerOpeningFixture::default_for_ids(opening_id, vec![application_id])
+            .call_and_assert(Ok(()));
+
         let withdraw_application_fixture =
             WithdrawApplicationFixture::default_for_application_id(application_id);
-        withdraw_application_fixture.call_and_assert(Ok(()));
         withdraw_application_fixture.call_and_assert(Err(
             Error::<Test, TestWorkingGroupInstance>::WithdrawWorkerApplicationApplicationNotActive
                 .into(),
@@ -764,9 +972,13 @@ fn terminate_worker_application_fails_with_hiring_error() {
             ApplyOnWorkerOpeningFixture::default_for_opening_id(opening_id);
         let application_id = apply_on_opening_fixture.call_and_assert(Ok(()));
 
+        BeginReviewWorkerApplicationsFixture::default_for_opening_id(opening_id)
+            .call_and_assert(Ok(()));
+        FillWorkerOpeningFixture::default_for_ids(opening_id, vec![application_id])
+            .call_and_assert(Ok(()));
+
         let terminate_application_fixture =
             TerminateApplicationFixture::default_for_application_id(application_id);
-        terminate_application_fixture.call_and_assert(Ok(()));
         terminate_application_fixture.call_and_assert(Err(
             Error::<Test, TestWorkingGroupInstance>::WithdrawWorkerApplicationApplicationNotActive
                 .into(),
@@ -2276,6 +2488,54 @@ fn ensure_setting_genesis_constraints_succeeds() {
     });
 }
 
+#[test]
+fn initialize_on_runtime_upgrade_succeeds() {
+    build_test_externalities().execute_with(|| {
+        let genesis_mint_id = TestWorkingGroup::mint();
+        let new_constraint = InputValidationLengthConstraint::new(2, 10);
+
+        // Initialized at genesis.
+        TestWorkingGroup::initialize_on_runtime_upgrade(
+            new_constraint,
+            new_constraint,
+            new_constraint,
+            100,
+        );
+
+        assert_eq!(TestWorkingGroup::mint(), genesis_mint_id);
+        assert_ne!(
+            TestWorkingGroup::opening_human_readable_text(),
+            new_constraint
+        );
+
+        // Added by the runtime upgrade.
+        <crate::Mint<Test, TestWorkingGroupInstance>>::kill();
+
+        TestWorkingGroup::initialize_on_runtime_upgrade(
+            new_constraint,
+            new_constraint,
+            new_constraint,
+            100,
+        );
+
+        let mint_id = TestWorkingGroup::mint();
+        assert_ne!(mint_id, genesis_mint_id);
+        assert_eq!(<minting::Module<Test>>::mints(mint_id).capacity(), 100);
+        assert_eq!(
+            TestWorkingGroup::opening_human_readable_text(),
+            new_constraint
+        );
+        assert_eq!(
+            TestWorkingGroup::application_human_readable_text(),
+            new_constraint
+        );
+        assert_eq!(
+            TestWorkingGroup::worker_exit_rationale_text(),
+            new_constraint
+        );
+    });
+}
+
 #[test]
 fn active_worker_counter_works_successfully() {
     build_test_externalities().execute_with(|| {
@@ -2352,3 +2612,576 @@ fn fill_opening_cannot_hire_more_workers_using_several_applicationst_han_allows_
         hiring_workflow.execute()
     });
 }
+
+fn setup_worker_opening_with_review(review_rounds: u32, reviewers: Vec<u64>) -> u64 {
+    HireLeadFixture::default().hire_lead();
+
+    let opening_id = AddWorkerOpeningFixture::default().call_and_assert(Ok(()));
+
+    assert_eq!(
+        TestWorkingGroup::set_opening_review(
+            RawOrigin::Signed(1).into(),
+            opening_id,
+            review_rounds,
+            reviewers.into_iter().collect()
+        ),
+        Ok(())
+    );
+
+    opening_id
+}
+
+#[test]
+fn set_opening_review_succeeds() {
+    build_test_externalities().execute_with(|| {
+        run_to_block(1);
+
+        let opening_id = setup_worker_opening_with_review(3, vec![5, 6]);
+
+        let review = hiring::Module::<Test>::opening_review_by_id(opening_id);
+        assert_eq!(review.review_rounds, 3);
+        assert_eq!(review.current_round, 1);
+        assert!(review.reviewers.contains(&5));
+        assert!(review.reviewers.contains(&6));
+
+        EventFixture::assert_last_crate_event(RawEvent::OpeningReviewSet(opening_id));
+    });
+}
+
+#[test]
+fn set_opening_review_fails_with_invalid_origin_or_parameters() {
+    build_test_externalities().execute_with(|| {
+        HireLeadFixture::default().hire_lead();
+
+        let opening_id = AddWorkerOpeningFixture::default().call_and_assert(Ok(()));
+
+        assert_eq!(
+            TestWorkingGroup::set_opening_review(
+                RawOrigin::Signed(2).into(),
+                opening_id,
+                2,
+                BTreeSet::new()
+            ),
+            Err(Error::<Test, TestWorkingGroupInstance>::IsNotLeadAccount.into())
+        );
+
+        assert_eq!(
+            TestWorkingGroup::set_opening_review(
+                RawOrigin::Signed(1).into(),
+                opening_id,
+                0,
+                BTreeSet::new()
+            ),
+            Err(Error::<Test, TestWorkingGroupInstance>::SetOpeningReviewZeroReviewRounds.into())
+        );
+
+        BeginReviewWorkerApplicationsFixture::default_for_opening_id(opening_id)
+            .call_and_assert(Ok(()));
+
+        assert_eq!(
+            TestWorkingGroup::set_opening_review(
+                RawOrigin::Signed(1).into(),
+                opening_id,
+                2,
+                BTreeSet::new()
+            ),
+            Err(Error::<Test, TestWorkingGroupInstance>::SetOpeningReviewAlreadyBegun.into())
+        );
+    });
+}
+
+#[test]
+fn score_application_succeeds_for_reviewer_and_lead() {
+    build_test_externalities().execute_with(|| {
+        run_to_block(1);
+
+        let opening_id = setup_worker_opening_with_review(1, vec![5]);
+
+        let application_id = ApplyOnWorkerOpeningFixture::default_for_opening_id(opening_id)
+            .with_origin(RawOrigin::Signed(2), 2)
+            .call_and_assert(Ok(()));
+
+        assert_eq!(
+            TestWorkingGroup::score_application(
+                RawOrigin::Signed(5).into(),
+                application_id,
+                10,
+                Vec::new()
+            ),
+            Err(Error::<Test, TestWorkingGroupInstance>::ScoreWorkerApplicationOpeningNotInReviewPeriodStage.into())
+        );
+
+        BeginReviewWorkerApplicationsFixture::default_for_opening_id(opening_id)
+            .call_and_assert(Ok(()));
+
+        assert_eq!(
+            TestWorkingGroup::score_application(
+                RawOrigin::Signed(5).into(),
+                application_id,
+                10,
+                b"note".to_vec()
+            ),
+            Ok(())
+        );
+        EventFixture::assert_last_crate_event(RawEvent::ApplicationScored(application_id, 5));
+
+        assert_eq!(
+            TestWorkingGroup::score_application(
+                RawOrigin::Signed(1).into(),
+                application_id,
+                7,
+                Vec::new()
+            ),
+            Ok(())
+        );
+
+        let hiring_application_id =
+            TestWorkingGroup::application_by_id(application_id).hiring_application_id;
+        let scores = hiring::Module::<Test>::application_scores_by_id(hiring_application_id);
+        assert_eq!(scores.len(), 2);
+        assert_eq!(scores[0].reviewer, 5);
+        assert_eq!(scores[0].score, 10);
+        assert_eq!(scores[0].note, b"note".to_vec());
+        assert_eq!(scores[1].reviewer, 1);
+    });
+}
+
+#[test]
+fn score_application_fails_with_not_reviewer() {
+    build_test_externalities().execute_with(|| {
+        let opening_id = setup_worker_opening_with_review(1, vec![5]);
+
+        let application_id = ApplyOnWorkerOpeningFixture::default_for_opening_id(opening_id)
+            .with_origin(RawOrigin::Signed(2), 2)
+            .call_and_assert(Ok(()));
+
+        BeginReviewWorkerApplicationsFixture::default_for_opening_id(opening_id)
+            .call_and_assert(Ok(()));
+
+        assert_eq!(
+            TestWorkingGroup::score_application(
+                RawOrigin::Signed(3).into(),
+                application_id,
+                10,
+                Vec::new()
+            ),
+            Err(Error::<Test, TestWorkingGroupInstance>::NotOpeningReviewer.into())
+        );
+    });
+}
+
+#[test]
+fn advance_review_round_succeeds() {
+    build_test_externalities().execute_with(|| {
+        run_to_block(1);
+
+        let opening_id = setup_worker_opening_with_review(2, Vec::new());
+
+        let shortlisted_application_id =
+            ApplyOnWorkerOpeningFixture::default_for_opening_id(opening_id).call_and_assert(Ok(()));
+        let dropped_application_id =
+            ApplyOnWorkerOpeningFixture::default_for_opening_id(opening_id)
+                .with_origin(RawOrigin::Signed(3), 3)
+                .call_and_assert(Ok(()));
+
+        let dropped_hiring_application_id =
+            TestWorkingGroup::application_by_id(dropped_application_id).hiring_application_id;
+
+        BeginReviewWorkerApplicationsFixture::default_for_opening_id(opening_id)
+            .call_and_assert(Ok(()));
+
+        FillWorkerOpeningFixture::default_for_ids(opening_id, vec![shortlisted_application_id])
+            .call_and_assert(Err(
+            Error::<Test, TestWorkingGroupInstance>::FullWorkerOpeningOpeningNotInLastReviewRound
+                .into(),
+        ));
+
+        let mut shortlist = BTreeSet::new();
+        shortlist.insert(shortlisted_application_id);
+        assert_eq!(
+            TestWorkingGroup::advance_review_round(
+                RawOrigin::Signed(1).into(),
+                opening_id,
+                shortlist
+            ),
+            Ok(())
+        );
+
+        EventFixture::assert_last_crate_event(RawEvent::ReviewRoundAdvanced(opening_id, 2));
+
+        let dropped_application =
+            hiring::ApplicationById::<Test>::get(dropped_hiring_application_id);
+        assert!(matches!(
+            dropped_application.stage,
+            hiring::ApplicationStage::Inactive {
+                cause: hiring::ApplicationDeactivationCause::NotShortlisted,
+                ..
+            }
+        ));
+        assert!(
+            !ApplicationById::<Test, TestWorkingGroupInstance>::contains_key(
+                dropped_application_id
+            )
+        );
+
+        FillWorkerOpeningFixture::default_for_ids(opening_id, vec![shortlisted_application_id])
+            .call_and_assert(Ok(()));
+    });
+}
+
+#[test]
+fn advance_review_round_fails_with_invalid_shortlist() {
+    build_test_externalities().execute_with(|| {
+        let opening_id = setup_worker_opening_with_review(2, Vec::new());
+
+        BeginReviewWorkerApplicationsFixture::default_for_opening_id(opening_id)
+            .call_and_assert(Ok(()));
+
+        assert_eq!(
+            TestWorkingGroup::advance_review_round(
+                RawOrigin::Signed(1).into(),
+                opening_id,
+                BTreeSet::new()
+            ),
+            Err(Error::<Test, TestWorkingGroupInstance>::AdvanceReviewRoundEmptyShortlist.into())
+        );
+
+        let mut shortlist = BTreeSet::new();
+        shortlist.insert(100);
+        assert_eq!(
+            TestWorkingGroup::advance_review_round(
+                RawOrigin::Signed(1).into(),
+                opening_id,
+                shortlist
+            ),
+            Err(
+                Error::<Test, TestWorkingGroupInstance>::ShortlistedWorkerApplicationDoesNotExist
+                    .into()
+            )
+        );
+    });
+}
+
+#[test]
+fn add_opening_fails_with_zero_auto_fill_hires() {
+    build_test_externalities().execute_with(|| {
+        HireLeadFixture::default().hire_lead();
+
+        let add_opening_fixture =
+            AddWorkerOpeningFixture::default().with_policy_commitment(OpeningPolicyCommitment {
+                auto_fill_on_review_period_expiry: Some(0),
+                ..OpeningPolicyCommitment::default()
+            });
+        add_opening_fixture.call_and_assert(Err(
+            Error::<Test, TestWorkingGroupInstance>::AutoFillOnReviewPeriodExpiryHiresIsZero.into(),
+        ));
+    });
+}
+
+fn setup_auto_fill_opening(application_stake: Option<u64>) -> u64 {
+    setup_auto_fill_opening_with_reward(application_stake, None)
+}
+
+fn setup_auto_fill_opening_with_reward(
+    application_stake: Option<u64>,
+    reward_policy: Option<RewardPolicy<u64, u64>>,
+) -> u64 {
+    HireLeadFixture::default().hire_lead();
+
+    AddWorkerOpeningFixture::default()
+        .with_policy_commitment(OpeningPolicyCommitment {
+            max_review_period_length: 5,
+            application_staking_policy: application_stake.map(|amount| hiring::StakingPolicy {
+                amount,
+                amount_mode: hiring::StakingAmountLimitMode::AtLeast,
+                ..hiring::StakingPolicy::default()
+            }),
+            auto_fill_on_review_period_expiry: Some(1),
+            auto_fill_reward_policy: reward_policy,
+            ..OpeningPolicyCommitment::default()
+        })
+        .call_and_assert(Ok(()))
+}
+
+#[test]
+fn opening_is_auto_filled_by_stake_on_review_period_expiry() {
+    build_test_externalities().execute_with(|| {
+        run_to_block(1);
+
+        let opening_id = setup_auto_fill_opening(Some(100));
+
+        increase_total_balance_issuance_using_account_id(2, 1000);
+        increase_total_balance_issuance_using_account_id(3, 1000);
+
+        ApplyOnWorkerOpeningFixture::default_for_opening_id(opening_id)
+            .with_origin(RawOrigin::Signed(2), 2)
+            .with_application_stake(100)
+            .call_and_assert(Ok(()));
+        let top_staked_application_id =
+            ApplyOnWorkerOpeningFixture::default_for_opening_id(opening_id)
+                .with_origin(RawOrigin::Signed(3), 3)
+                .with_application_stake(200)
+                .call_and_assert(Ok(()));
+
+        BeginReviewWorkerApplicationsFixture::default_for_opening_id(opening_id)
+            .call_and_assert(Ok(()));
+
+        assert!(crate::OpeningIdsToAutoFillByBlock::<
+            Test,
+            TestWorkingGroupInstance,
+        >::contains_key(6, opening_id));
+
+        let worker_id = TestWorkingGroup::next_worker_id();
+
+        run_to_block(5);
+        assert!(!<crate::WorkerById<Test, TestWorkingGroupInstance>>::contains_key(worker_id));
+
+        run_to_block(6);
+
+        let mut application_id_to_worker_id = BTreeMap::new();
+        application_id_to_worker_id.insert(top_staked_application_id, worker_id);
+        EventFixture::assert_last_crate_event(RawEvent::OpeningFilled(
+            opening_id,
+            application_id_to_worker_id,
+        ));
+
+        assert_eq!(TestWorkingGroup::worker_by_id(worker_id).member_id, 3);
+        assert!(TestWorkingGroup::worker_by_id(worker_id)
+            .reward_relationship
+            .is_none());
+        assert!(!crate::OpeningIdsToAutoFillByBlock::<
+            Test,
+            TestWorkingGroupInstance,
+        >::contains_key(6, opening_id));
+    });
+}
+
+#[test]
+fn opening_auto_fill_prefers_earlier_application_on_equal_stake() {
+    build_test_externalities().execute_with(|| {
+        run_to_block(1);
+
+        let opening_id = setup_auto_fill_opening(None);
+
+        let first_application_id = ApplyOnWorkerOpeningFixture::default_for_opening_id(opening_id)
+            .with_origin(RawOrigin::Signed(2), 2)
+            .call_and_assert(Ok(()));
+        ApplyOnWorkerOpeningFixture::default_for_opening_id(opening_id)
+            .with_origin(RawOrigin::Signed(3), 3)
+            .call_and_assert(Ok(()));
+
+        BeginReviewWorkerApplicationsFixture::default_for_opening_id(opening_id)
+            .call_and_assert(Ok(()));
+
+        let worker_id = TestWorkingGroup::next_worker_id();
+
+        run_to_block(6);
+
+        let mut application_id_to_worker_id = BTreeMap::new();
+        application_id_to_worker_id.insert(first_application_id, worker_id);
+        EventFixture::assert_last_crate_event(RawEvent::OpeningFilled(
+            opening_id,
+            application_id_to_worker_id,
+        ));
+    });
+}
+
+#[test]
+fn opening_filled_in_time_is_not_auto_filled() {
+    build_test_externalities().execute_with(|| {
+        let opening_id = setup_auto_fill_opening(None);
+
+        let application_id =
+            ApplyOnWorkerOpeningFixture::default_for_opening_id(opening_id).call_and_assert(Ok(()));
+
+        BeginReviewWorkerApplicationsFixture::default_for_opening_id(opening_id)
+            .call_and_assert(Ok(()));
+
+        FillWorkerOpeningFixture::default_for_ids(opening_id, vec![application_id])
+            .call_and_assert(Ok(()));
+
+        let active_worker_count = TestWorkingGroup::active_worker_count();
+
+        run_to_block(6);
+
+        assert!(!crate::OpeningIdsToAutoFillByBlock::<
+            Test,
+            TestWorkingGroupInstance,
+        >::contains_key(5, opening_id));
+        assert_eq!(TestWorkingGroup::active_worker_count(), active_worker_count);
+    });
+}
+
+#[test]
+fn opening_auto_fill_creates_committed_reward_relationship() {
+    build_test_externalities().execute_with(|| {
+        run_to_block(1);
+
+        let opening_id = setup_auto_fill_opening_with_reward(
+            None,
+            Some(RewardPolicy {
+                amount_per_payout: 10,
+                next_payment_at_block: 3,
+                payout_interval: Some(10),
+            }),
+        );
+
+        ApplyOnWorkerOpeningFixture::default_for_opening_id(opening_id)
+            .with_origin(RawOrigin::Signed(2), 2)
+            .call_and_assert(Ok(()));
+
+        BeginReviewWorkerApplicationsFixture::default_for_opening_id(opening_id)
+            .call_and_assert(Ok(()));
+
+        let worker_id = TestWorkingGroup::next_worker_id();
+
+        run_to_block(6);
+
+        let relationship_id = TestWorkingGroup::worker_by_id(worker_id)
+            .reward_relationship
+            .unwrap();
+        let relationship = recurringrewards::RewardRelationships::<Test>::get(relationship_id);
+        assert_eq!(relationship.amount_per_payout, 10);
+    });
+}
+
+#[test]
+fn set_opening_review_fails_with_auto_fill_and_multiple_rounds() {
+    build_test_externalities().execute_with(|| {
+        let opening_id = setup_auto_fill_opening(None);
+
+        assert_eq!(
+            TestWorkingGroup::set_opening_review(
+                RawOrigin::Signed(1).into(),
+                opening_id,
+                2,
+                BTreeSet::new()
+            ),
+            Err(
+                Error::<Test, TestWorkingGroupInstance>::SetOpeningReviewMultipleRoundsWithAutoFill
+                    .into()
+            )
+        );
+    });
+}
+
+#[test]
+fn add_opening_fails_with_zero_max_hires() {
+    build_test_externalities().execute_with(|| {
+        HireLeadFixture::default().hire_lead();
+
+        let add_opening_fixture =
+            AddWorkerOpeningFixture::default().with_policy_commitment(OpeningPolicyCommitment {
+                max_hires: Some(0),
+                ..OpeningPolicyCommitment::default()
+            });
+        add_opening_fixture.call_and_assert(Err(
+            Error::<Test, TestWorkingGroupInstance>::AddWorkerOpeningZeroMaxHires.into(),
+        ));
+    });
+}
+
+#[test]
+fn begin_review_fails_before_application_deadline() {
+    build_test_externalities().execute_with(|| {
+        HireLeadFixture::default().hire_lead();
+
+        let opening_id = AddWorkerOpeningFixture::default()
+            .with_policy_commitment(OpeningPolicyCommitment {
+                application_deadline: Some(10),
+                ..OpeningPolicyCommitment::default()
+            })
+            .call_and_assert(Ok(()));
+
+        let begin_review_fixture =
+            BeginReviewWorkerApplicationsFixture::default_for_opening_id(opening_id);
+        begin_review_fixture.call_and_assert(Err(
+            Error::<Test, TestWorkingGroupInstance>::BeginWorkerApplicantReviewApplicationDeadlineNotReached.into(),
+        ));
+    });
+}
+
+#[test]
+fn opening_with_application_deadline_is_scheduled_for_auto_fill() {
+    build_test_externalities().execute_with(|| {
+        HireLeadFixture::default().hire_lead();
+
+        let opening_id = AddWorkerOpeningFixture::default()
+            .with_policy_commitment(OpeningPolicyCommitment {
+                max_review_period_length: 5,
+                auto_fill_on_review_period_expiry: Some(1),
+                application_deadline: Some(10),
+                ..OpeningPolicyCommitment::default()
+            })
+            .call_and_assert(Ok(()));
+
+        assert!(crate::OpeningIdsToAutoFillByBlock::<
+            Test,
+            TestWorkingGroupInstance,
+        >::contains_key(15, opening_id));
+    });
+}
+
+#[test]
+fn fill_opening_fails_with_max_hires_exceeded() {
+    build_test_externalities().execute_with(|| {
+        HireLeadFixture::default().hire_lead();
+
+        let opening_id = AddWorkerOpeningFixture::default()
+            .with_policy_commitment(OpeningPolicyCommitment {
+                max_hires: Some(1),
+                ..OpeningPolicyCommitment::default()
+            })
+            .call_and_assert(Ok(()));
+
+        let first_application_id = ApplyOnWorkerOpeningFixture::default_for_opening_id(opening_id)
+            .with_origin(RawOrigin::Signed(2), 2)
+            .call_and_assert(Ok(()));
+        let second_application_id = ApplyOnWorkerOpeningFixture::default_for_opening_id(opening_id)
+            .with_origin(RawOrigin::Signed(3), 3)
+            .call_and_assert(Ok(()));
+
+        BeginReviewWorkerApplicationsFixture::default_for_opening_id(opening_id)
+            .call_and_assert(Ok(()));
+
+        FillWorkerOpeningFixture::default_for_ids(
+            opening_id,
+            vec![first_application_id, second_application_id],
+        )
+        .call_and_assert(Err(
+            Error::<Test, TestWorkingGroupInstance>::FullWorkerOpeningMaxHiresExceeded.into(),
+        ));
+    });
+}
+
+#[test]
+fn migrate_opening_policy_commitments_leaves_new_policy_variables_unset() {
+    build_test_externalities().execute_with(|| {
+        crate::OpeningPolicyCommitmentMigrated::<TestWorkingGroupInstance>::put(false);
+
+        let legacy_opening = crate::types::LegacyOpening::<u64, u64, u64, u64> {
+            hiring_opening_id: 1,
+            applications: BTreeSet::new(),
+            policy_commitment: crate::LegacyOpeningPolicyCommitment {
+                max_review_period_length: 5,
+                ..Default::default()
+            },
+            opening_type: OpeningType::Worker,
+        };
+        frame_support::storage::unhashed::put(
+            &crate::OpeningById::<Test, TestWorkingGroupInstance>::hashed_key_for(0),
+            &legacy_opening,
+        );
+
+        TestWorkingGroup::migrate_opening_policy_commitments();
+
+        assert_eq!(
+            TestWorkingGroup::opening_by_id(0).policy_commitment,
+            OpeningPolicyCommitment {
+                max_review_period_length: 5,
+                ..OpeningPolicyCommitment::default()
+            }
+        );
+        assert!(TestWorkingGroup::opening_policy_commitment_migrated());
+    });
+}
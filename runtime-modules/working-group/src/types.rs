@@ -77,6 +77,22 @@ pub struct OpeningPolicyCommitment<BlockNumber, Balance> {
 
     /// When a worker/lead exists: unstaking period for role stake.
     pub exit_role_stake_unstaking_period: Option<BlockNumber>,
+
+    /// When the review period expires without the opening being filled: number of the active
+    /// applicants with the largest combined application and role stake to hire. The opening is
+    /// not filled automatically when not set.
+    pub auto_fill_on_review_period_expiry: Option<u32>,
+
+    /// Block after which no applications are accepted, the review period begins automatically
+    /// at this block. The review can be started at any time when not set.
+    pub application_deadline: Option<BlockNumber>,
+
+    /// Maximum number of the applicants hired when filling the opening.
+    pub max_hires: Option<u32>,
+
+    /// Reward policy of the applicants hired by the automatic fill. The first payment is made in
+    /// the next block when the committed payment block has passed by the fill.
+    pub auto_fill_reward_policy: Option<RewardPolicy<Balance, BlockNumber>>,
 }
 
 /// An opening for a worker or lead role.
@@ -96,6 +112,69 @@ pub struct Opening<OpeningId, BlockNumber, Balance, WorkerApplicationId: core::c
     pub opening_type: OpeningType,
 }
 
+/// 'OpeningPolicyCommitment' of the previous runtime versions without the automatic fill and the
+/// opening limits. Used by the storage migrations.
+#[derive(Encode, Decode, Default)]
+pub struct LegacyOpeningPolicyCommitment<BlockNumber, Balance> {
+    pub(crate) application_rationing_policy: Option<hiring::ApplicationRationingPolicy>,
+    pub(crate) max_review_period_length: BlockNumber,
+    pub(crate) application_staking_policy: Option<hiring::StakingPolicy<Balance, BlockNumber>>,
+    pub(crate) role_staking_policy: Option<hiring::StakingPolicy<Balance, BlockNumber>>,
+    pub(crate) role_slashing_terms: SlashingTerms,
+    pub(crate) fill_opening_successful_applicant_application_stake_unstaking_period:
+        Option<BlockNumber>,
+    pub(crate) fill_opening_failed_applicant_application_stake_unstaking_period:
+        Option<BlockNumber>,
+    pub(crate) fill_opening_failed_applicant_role_stake_unstaking_period: Option<BlockNumber>,
+    pub(crate) terminate_application_stake_unstaking_period: Option<BlockNumber>,
+    pub(crate) terminate_role_stake_unstaking_period: Option<BlockNumber>,
+    pub(crate) exit_role_application_stake_unstaking_period: Option<BlockNumber>,
+    pub(crate) exit_role_stake_unstaking_period: Option<BlockNumber>,
+}
+
+impl<BlockNumber, Balance> LegacyOpeningPolicyCommitment<BlockNumber, Balance> {
+    /// Converts the legacy commitment, leaving the new policy variables unset.
+    pub fn migrate(self) -> OpeningPolicyCommitment<BlockNumber, Balance> {
+        OpeningPolicyCommitment {
+            application_rationing_policy: self.application_rationing_policy,
+            max_review_period_length: self.max_review_period_length,
+            application_staking_policy: self.application_staking_policy,
+            role_staking_policy: self.role_staking_policy,
+            role_slashing_terms: self.role_slashing_terms,
+            fill_opening_successful_applicant_application_stake_unstaking_period: self
+                .fill_opening_successful_applicant_application_stake_unstaking_period,
+            fill_opening_failed_applicant_application_stake_unstaking_period: self
+                .fill_opening_failed_applicant_application_stake_unstaking_period,
+            fill_opening_failed_applicant_role_stake_unstaking_period: self
+                .fill_opening_failed_applicant_role_stake_unstaking_period,
+            terminate_application_stake_unstaking_period: self
+                .terminate_application_stake_unstaking_period,
+            terminate_role_stake_unstaking_period: self.terminate_role_stake_unstaking_period,
+            exit_role_application_stake_unstaking_period: self
+                .exit_role_application_stake_unstaking_period,
+            exit_role_stake_unstaking_period: self.exit_role_stake_unstaking_period,
+            auto_fill_on_review_period_expiry: None,
+            application_deadline: None,
+            max_hires: None,
+            auto_fill_reward_policy: None,
+        }
+    }
+}
+
+/// 'Opening' of the previous runtime versions. Used by the storage migration.
+#[derive(Encode, Decode)]
+pub(crate) struct LegacyOpening<
+    OpeningId,
+    BlockNumber,
+    Balance,
+    WorkerApplicationId: core::cmp::Ord,
+> {
+    pub(crate) hiring_opening_id: OpeningId,
+    pub(crate) applications: BTreeSet<WorkerApplicationId>,
+    pub(crate) policy_commitment: LegacyOpeningPolicyCommitment<BlockNumber, Balance>,
+    pub(crate) opening_type: OpeningType,
+}
+
 /// Defines type of the opening: regular working group fellow or group leader.
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Encode, Decode, Debug, Clone, PartialEq, Eq, Copy)]
@@ -238,7 +317,7 @@ pub enum ExitInitiationOrigin {
 
 /// The recurring reward if any to be assigned to an actor when filling in the position.
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
-#[derive(Encode, Decode, Clone, PartialEq, Debug)]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug)]
 pub struct RewardPolicy<Balance, BlockNumber> {
     /// Balance per payout.
     pub amount_per_payout: Balance,
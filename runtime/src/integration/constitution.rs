@@ -0,0 +1,11 @@
+use crate::{ContentId, Runtime};
+
+/// Keeps the uploaded constitution documents in the data directory.
+pub struct ConstitutionContentDeletionValidator {}
+impl storage::data_directory::ContentDeletionValidator<Runtime>
+    for ConstitutionContentDeletionValidator
+{
+    fn can_delete_content(content_id: &ContentId) -> bool {
+        !crate::Constitution::is_constitution_content(content_id)
+    }
+}
@@ -1,4 +1,7 @@
-use crate::{AccountId, Credential, Runtime};
+use crate::{
+    AccountId, ContentDirectoryWorkingGroup, ContentDirectoryWorkingGroupInstance,
+    ContentWorkingGroup, Credential, Runtime,
+};
 
 use frame_support::traits::{Currency, Imbalance};
 use frame_support::{parameter_types, IterableStorageMap, StorageMap};
@@ -20,6 +23,10 @@ impl versioned_store_permissions::CredentialChecker<Runtime> for ContentWorkingG
             // Credentials from 0..999 represents groups or more complex requirements
             // Current Lead if set
             credential if credential == CurrentLeadCredential::get() => {
+                if ContentWorkingGroup::curators_migrated() {
+                    return is_content_directory_lead(account);
+                }
+
                 match <content_working_group::Module<Runtime>>::ensure_lead_is_set() {
                     Ok((_, lead)) => lead.role_account == *account,
                     _ => false,
@@ -27,6 +34,15 @@ impl versioned_store_permissions::CredentialChecker<Runtime> for ContentWorkingG
             }
             // Any Active Curator
             credential if credential == AnyActiveCuratorCredential::get() => {
+                // Curators are the content directory workers once migrated
+                if ContentWorkingGroup::curators_migrated() {
+                    return <working_group::WorkerById<
+                        Runtime,
+                        ContentDirectoryWorkingGroupInstance,
+                    >>::iter()
+                    .any(|(_, worker)| worker.role_account_id == *account);
+                }
+
                 // Look for a Curator with a matching role account
                 for (_principal_id, principal) in
                     <content_working_group::PrincipalById<Runtime>>::iter()
@@ -63,9 +79,33 @@ impl versioned_store_permissions::CredentialChecker<Runtime> for ContentWorkingG
             }
             // mapping to working group principal id
             n if n >= PrincipalIdMappingStartsAtCredential::get() => {
+                let principal_id = n - PrincipalIdMappingStartsAtCredential::get();
+
+                if ContentWorkingGroup::curators_migrated()
+                    && <content_working_group::PrincipalById<Runtime>>::contains_key(principal_id)
+                {
+                    // The lead and the curators are the content directory workers once migrated,
+                    // the curator ids were kept as the worker ids.
+                    match <content_working_group::PrincipalById<Runtime>>::get(principal_id) {
+                        content_working_group::Principal::Lead => {
+                            return is_content_directory_lead(account);
+                        }
+                        content_working_group::Principal::Curator(curator_id) => {
+                            return <working_group::WorkerById<
+                                Runtime,
+                                ContentDirectoryWorkingGroupInstance,
+                            >>::contains_key(curator_id)
+                                && ContentDirectoryWorkingGroup::worker_by_id(curator_id)
+                                    .role_account_id
+                                    == *account;
+                        }
+                        content_working_group::Principal::ChannelOwner(_) => {}
+                    }
+                }
+
                 <content_working_group::Module<Runtime>>::account_has_credential(
                     account,
-                    n - PrincipalIdMappingStartsAtCredential::get(),
+                    principal_id,
                 )
             }
             _ => false,
@@ -73,6 +113,15 @@ impl versioned_store_permissions::CredentialChecker<Runtime> for ContentWorkingG
     }
 }
 
+/// Whether the account is the role account of the content directory working group lead.
+pub fn is_content_directory_lead(account: &AccountId) -> bool {
+    ContentDirectoryWorkingGroup::current_lead()
+        .map(|lead_id| {
+            ContentDirectoryWorkingGroup::worker_by_id(lead_id).role_account_id == *account
+        })
+        .unwrap_or(false)
+}
+
 pub struct ContentWorkingGroupStakingEventHandler {}
 impl stake::StakingEventsHandler<Runtime> for ContentWorkingGroupStakingEventHandler {
     fn unstaked(
@@ -87,11 +136,23 @@ impl stake::StakingEventsHandler<Runtime> for ContentWorkingGroupStakingEventHan
 
         let application_id = hiring::ApplicationIdByStakingId::<Runtime>::get(stake_id);
 
-        if !content_working_group::CuratorApplicationById::<Runtime>::contains_key(application_id) {
+        if !content_working_group::CuratorApplicationIdByHiringApplicationId::<Runtime>::contains_key(
+            application_id,
+        ) {
             // Stake not for a Curator
             return remaining_imbalance;
         }
 
+        // Determine member id of the curator, before the hiring module notifies on the application
+        // deactivation, which removes the curator application.
+        let curator_application_id =
+            content_working_group::CuratorApplicationIdByHiringApplicationId::<Runtime>::get(
+                application_id,
+            );
+        let member_id =
+            content_working_group::CuratorApplicationById::<Runtime>::get(curator_application_id)
+                .member_id;
+
         // Notify the Hiring module - is there a potential re-entrancy bug if
         // instant unstaking is occuring?
         hiring::Module::<Runtime>::unstaked(*stake_id);
@@ -101,11 +162,6 @@ impl stake::StakingEventsHandler<Runtime> for ContentWorkingGroupStakingEventHan
             content_working_group::Module::<Runtime>::unstaked(*stake_id);
         }
 
-        // Determine member id of the curator
-        let curator_application =
-            content_working_group::CuratorApplicationById::<Runtime>::get(application_id);
-        let member_id = curator_application.member_id;
-
         // get member's profile
         let member_profile = membership::MembershipById::<Runtime>::get(member_id);
 
@@ -139,3 +195,16 @@ impl stake::StakingEventsHandler<Runtime> for ContentWorkingGroupStakingEventHan
         remaining_imbalance
     }
 }
+
+pub struct ContentWorkingGroupChannelOwnershipValidator {}
+impl storage::data_directory::ChannelOwnershipValidator<Runtime>
+    for ContentWorkingGroupChannelOwnershipValidator
+{
+    fn is_channel_owner(
+        member_id: &<Runtime as membership::Trait>::MemberId,
+        channel_id: &content_working_group::ChannelId<Runtime>,
+    ) -> bool {
+        <content_working_group::ChannelById<Runtime>>::contains_key(channel_id)
+            && <content_working_group::ChannelById<Runtime>>::get(channel_id).owner == *member_id
+    }
+}
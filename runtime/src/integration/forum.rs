@@ -9,7 +9,9 @@
  * run convention should be.
  */
 
-use crate::{AccountId, Runtime};
+use frame_support::StorageMap;
+
+use crate::{AccountId, ActorId, ForumWorkingGroup, ForumWorkingGroupInstance, Runtime};
 
 /// Shim registry which will proxy ForumUserRegistry behaviour to the members module
 pub struct ShimMembershipRegistry {}
@@ -28,4 +30,66 @@ impl forum::ForumUserRegistry<AccountId> for ShimMembershipRegistry {
             None
         }
     }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn add_forum_user(id: &AccountId) {
+        use codec::Encode;
+
+        if membership::Module::<Runtime>::is_member_account(id) {
+            return;
+        }
+
+        membership::Module::<Runtime>::set_screening_authority(
+            system::RawOrigin::Root.into(),
+            id.clone(),
+        )
+        .expect("Root can set the screening authority");
+
+        membership::Module::<Runtime>::add_screened_member(
+            system::RawOrigin::Signed(id.clone()).into(),
+            id.clone(),
+            Some(id.encode()),
+            None,
+            None,
+        )
+        .expect("Forum user membership should be created");
+    }
+}
+
+/// Proxies ForumModeratorRegistry behaviour to the forum working group: its active workers
+/// can be assigned as category moderators by the group lead.
+pub struct ForumWorkingGroupModeratorRegistry;
+
+impl forum::ForumModeratorRegistry<AccountId, ActorId> for ForumWorkingGroupModeratorRegistry {
+    fn forum_worker_account(id: &ActorId) -> Option<AccountId> {
+        if <working_group::WorkerById<Runtime, ForumWorkingGroupInstance>>::contains_key(id) {
+            Some(ForumWorkingGroup::worker_by_id(id).role_account_id)
+        } else {
+            None
+        }
+    }
+
+    fn is_forum_lead(id: &AccountId) -> bool {
+        ForumWorkingGroup::current_lead()
+            .map(|lead_id| ForumWorkingGroup::worker_by_id(lead_id).role_account_id == *id)
+            .unwrap_or(false)
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn add_forum_worker(id: &AccountId, lead: bool) -> ActorId {
+        use frame_support::StorageValue;
+
+        let worker_id = ForumWorkingGroup::next_worker_id();
+        <working_group::WorkerById<Runtime, ForumWorkingGroupInstance>>::insert(
+            worker_id,
+            working_group::Worker::new(&0, id, &None, &None),
+        );
+        <working_group::NextWorkerId<Runtime, ForumWorkingGroupInstance>>::put(worker_id + 1);
+
+        if lead {
+            <working_group::CurrentLead<Runtime, ForumWorkingGroupInstance>>::put(worker_id);
+        }
+
+        worker_id
+    }
 }
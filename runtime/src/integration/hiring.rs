@@ -0,0 +1,18 @@
+use crate::{
+    ContentDirectoryWorkingGroup, ContentWorkingGroup, ForumWorkingGroup, MembershipWorkingGroup,
+    Runtime, StorageWorkingGroup,
+};
+
+/// Notifies the working group owning the deactivated hiring application.
+pub struct ApplicationDeactivatedHandler;
+
+impl hiring::ApplicationDeactivatedHandler<Runtime> for ApplicationDeactivatedHandler {
+    fn deactivated(application_id: &u64, cause: hiring::ApplicationDeactivationCause) {
+        // The hiring application belongs to a single working group.
+        let _ = ForumWorkingGroup::application_deactivated(application_id, cause)
+            || StorageWorkingGroup::application_deactivated(application_id, cause)
+            || ContentDirectoryWorkingGroup::application_deactivated(application_id, cause)
+            || MembershipWorkingGroup::application_deactivated(application_id, cause)
+            || ContentWorkingGroup::application_deactivated(application_id, cause);
+    }
+}
@@ -1,6 +1,9 @@
+pub mod constitution;
 pub mod content_working_group;
 pub mod forum;
+pub mod hiring;
 pub mod proposals;
+pub mod recurring_rewards;
 pub mod storage;
 pub mod transactions;
 pub mod versioned_store_permissions;
@@ -1,6 +1,7 @@
 #![warn(missing_docs)]
 
 use crate::Runtime;
+use governance::council::CouncilSeatVacated;
 use governance::election::CouncilElected;
 
 /// 'Council elected' event handler. Should be applied to the 'election' substrate module.
@@ -12,3 +13,13 @@ impl<Elected, Term> CouncilElected<Elected, Term> for CouncilElectedHandler {
         <proposals_engine::Module<Runtime>>::reset_active_proposals();
     }
 }
+
+/// 'Council seat vacated' event handler. Should be applied to the 'council' substrate module.
+/// The votes of the recalled councilor are discarded by resetting active proposals.
+pub struct CouncilSeatVacatedHandler;
+
+impl<AccountId> CouncilSeatVacated<AccountId> for CouncilSeatVacatedHandler {
+    fn council_seat_vacated(_councilor: &AccountId) {
+        <proposals_engine::Module<Runtime>>::reset_active_proposals();
+    }
+}
@@ -31,6 +31,23 @@ impl<T: governance::council::Trait + membership::Trait>
 
         Err("Council validation failed: account id doesn't belong to a council member")
     }
+
+    /// Adds the account to the active council.
+    #[cfg(feature = "runtime-benchmarks")]
+    fn prepare_actor_origin(_actor_id: MemberId<T>, account_id: <T as system::Trait>::AccountId) {
+        if <governance::council::Module<T>>::is_councilor(&account_id) {
+            return;
+        }
+
+        let mut council: sp_std::vec::Vec<_> = <governance::council::Module<T>>::active_council()
+            .into_iter()
+            .map(|seat| seat.member)
+            .collect();
+        council.push(account_id);
+
+        <governance::council::Module<T>>::set_council(system::RawOrigin::Root.into(), council)
+            .expect("Root can always set the council.");
+    }
 }
 
 impl<T: governance::council::Trait> VotersParameters for CouncilManager<T> {
@@ -6,7 +6,7 @@ mod membership_origin_validator;
 mod proposal_encoder;
 mod staking_events_handler;
 
-pub use council_elected_handler::CouncilElectedHandler;
+pub use council_elected_handler::{CouncilElectedHandler, CouncilSeatVacatedHandler};
 pub use council_origin_validator::CouncilManager;
 pub use membership_origin_validator::{MemberId, MembershipOriginValidator};
 pub use proposal_encoder::ExtrinsicProposalEncoder;
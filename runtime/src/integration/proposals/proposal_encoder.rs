@@ -1,6 +1,9 @@
-use crate::{Call, Runtime};
+use crate::{
+    Call, ContentDirectoryWorkingGroup, Council, ForumWorkingGroup, MembershipWorkingGroup,
+    Runtime, StorageWorkingGroup,
+};
 use common::working_group::WorkingGroup;
-use proposals_codex::{ProposalDetails, ProposalDetailsOf, ProposalEncoder};
+use proposals_codex::{GovernedMint, ProposalDetails, ProposalDetailsOf, ProposalEncoder};
 use working_group::OpeningType;
 
 use codec::Encode;
@@ -19,6 +22,11 @@ macro_rules! wrap_working_group_call {
     ($working_group:expr, $working_group_instance_call:expr) => {{
         match $working_group {
             WorkingGroup::Storage => Call::StorageWorkingGroup($working_group_instance_call),
+            WorkingGroup::Forum => Call::ForumWorkingGroup($working_group_instance_call),
+            WorkingGroup::Content => {
+                Call::ContentDirectoryWorkingGroup($working_group_instance_call)
+            }
+            WorkingGroup::Membership => Call::MembershipWorkingGroup($working_group_instance_call),
         }
     }};
 }
@@ -35,16 +43,21 @@ impl ProposalEncoder<Runtime> for ExtrinsicProposalEncoder {
             ProposalDetails::SetElectionParameters(election_parameters) => Call::CouncilElection(
                 governance::election::Call::set_election_parameters(election_parameters),
             ),
+            // The content working group curators moved to the content directory working group.
             ProposalDetails::SetContentWorkingGroupMintCapacity(mint_balance) => {
-                Call::ContentWorkingGroup(content_working_group::Call::set_mint_capacity(
-                    mint_balance,
-                ))
+                wrap_working_group_call!(
+                    WorkingGroup::Content,
+                    Wg::create_set_mint_capacity_call(mint_balance)
+                )
             }
             ProposalDetails::Spending(balance, destination) => Call::Council(
                 governance::council::Call::spend_from_council_mint(balance, destination),
             ),
-            ProposalDetails::SetLead(new_lead) => {
-                Call::ContentWorkingGroup(content_working_group::Call::replace_lead(new_lead))
+            // ********** Deprecated with the content directory working group introduction.
+            // It is kept only for backward compatibility in the Pioneer. **********
+            ProposalDetails::SetLead(_) => {
+                print("Error: Calling deprecated SetLead encoding option.");
+                return Vec::new();
             }
             ProposalDetails::SetValidatorCount(new_validator_count) => Call::Staking(
                 pallet_staking::Call::set_validator_count(new_validator_count),
@@ -119,6 +132,38 @@ impl ProposalEncoder<Runtime> for ExtrinsicProposalEncoder {
                     Wg::terminate_role_call(terminate_role_params)
                 )
             }
+            ProposalDetails::SetMintCapacityAdjustment(mint, adjustment) => {
+                let mint_id = match mint {
+                    GovernedMint::Council => Council::council_mint(),
+                    GovernedMint::ContentWorkingGroup => ContentDirectoryWorkingGroup::mint(),
+                    GovernedMint::WorkingGroup(WorkingGroup::Storage) => {
+                        StorageWorkingGroup::mint()
+                    }
+                    GovernedMint::WorkingGroup(WorkingGroup::Forum) => ForumWorkingGroup::mint(),
+                    GovernedMint::WorkingGroup(WorkingGroup::Content) => {
+                        ContentDirectoryWorkingGroup::mint()
+                    }
+                    GovernedMint::WorkingGroup(WorkingGroup::Membership) => {
+                        MembershipWorkingGroup::mint()
+                    }
+                };
+
+                Call::Minting(minting::Call::set_mint_adjustment(mint_id, adjustment))
+            }
+            ProposalDetails::SetProposalParameters(proposal_kind, proposal_parameters) => {
+                Call::ProposalsCodex(proposals_codex::Call::set_proposal_parameters(
+                    proposal_kind,
+                    proposal_parameters,
+                ))
+            }
+            ProposalDetails::AmendConstitution(amendment) => {
+                Call::Constitution(constitution::Call::amend_constitution(amendment))
+            }
+            ProposalDetails::Batch(proposals) => {
+                Call::ProposalsCodex(proposals_codex::Call::execute_batch_proposal(
+                    proposals.into_iter().map(Self::encode_proposal).collect(),
+                ))
+            }
         };
 
         call.encode()
@@ -0,0 +1,57 @@
+use frame_support::StorageMap;
+
+use crate::{
+    AccountId, ActorId, Balance, ContentDirectoryWorkingGroup, ContentWorkingGroup, Council,
+    ForumWorkingGroup, MembershipWorkingGroup, RecurringRewards, Runtime, StorageWorkingGroup,
+};
+use common::working_group::WorkingGroup;
+
+/// Records the failed payouts as arrears, paid once the mint is topped up.
+pub struct RewardArrearsRecorder;
+
+impl recurring_rewards::PayoutStatusHandler<Runtime> for RewardArrearsRecorder {
+    fn payout_succeeded(_id: u64, _destination_account: &AccountId, _amount: Balance) {}
+
+    fn payout_failed(id: u64, _destination_account: &AccountId, amount: Balance) {
+        RecurringRewards::record_arrears(id, amount);
+    }
+}
+
+/// Returns the reward owed to the worker of the working group.
+pub fn worker_owed_reward(working_group: WorkingGroup, worker_id: ActorId) -> Balance {
+    let reward_relationship = match working_group {
+        WorkingGroup::Storage => StorageWorkingGroup::worker_by_id(worker_id).reward_relationship,
+        WorkingGroup::Forum => ForumWorkingGroup::worker_by_id(worker_id).reward_relationship,
+        WorkingGroup::Content => {
+            ContentDirectoryWorkingGroup::worker_by_id(worker_id).reward_relationship
+        }
+        WorkingGroup::Membership => {
+            MembershipWorkingGroup::worker_by_id(worker_id).reward_relationship
+        }
+    };
+
+    reward_relationship
+        .map(RecurringRewards::owed_reward)
+        .unwrap_or_default()
+}
+
+/// Returns the reward owed to the curator of the content working group.
+pub fn curator_owed_reward(curator_id: ActorId) -> Balance {
+    if <content_working_group::CuratorById<Runtime>>::contains_key(curator_id) {
+        ContentWorkingGroup::curator_by_id(curator_id)
+            .reward_relationship
+            .map(RecurringRewards::owed_reward)
+            .unwrap_or_default()
+    } else {
+        0
+    }
+}
+
+/// Returns the reward owed to the council member.
+pub fn councilor_owed_reward(account_id: AccountId) -> Balance {
+    if <governance::council::RewardRelationships<Runtime>>::contains_key(&account_id) {
+        RecurringRewards::owed_reward(Council::reward_relationships(&account_id))
+    } else {
+        0
+    }
+}
@@ -1,19 +1,14 @@
 use frame_support::traits::Randomness;
 use sp_std::vec::Vec;
 
-use crate::{ActorId, Runtime};
+use crate::{ActorId, ContentId, Runtime};
 
 /// Provides random storage provider id. We use it when assign the content to the storage provider.
 pub struct StorageProviderHelper;
 
 impl storage::data_directory::StorageProviderHelper<Runtime> for StorageProviderHelper {
     fn get_random_storage_provider() -> Result<ActorId, &'static str> {
-        let ids = crate::StorageWorkingGroup::get_all_worker_ids();
-
-        let live_ids: Vec<ActorId> = ids
-            .into_iter()
-            .filter(|id| !<service_discovery::Module<Runtime>>::is_account_info_expired(id))
-            .collect();
+        let live_ids = Self::get_live_storage_providers();
 
         if live_ids.is_empty() {
             Err("No valid storage provider found.")
@@ -22,6 +17,30 @@ impl storage::data_directory::StorageProviderHelper<Runtime> for StorageProvider
             Ok(live_ids[index])
         }
     }
+
+    fn get_live_storage_providers() -> Vec<ActorId> {
+        let ids = crate::StorageWorkingGroup::get_all_worker_ids();
+
+        ids.into_iter()
+            .filter(|id| !<service_discovery::Module<Runtime>>::is_account_info_expired(id))
+            .collect()
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn prepare_storage_provider(storage_provider_id: ActorId) {
+        use frame_support::StorageMap;
+
+        let expires_at = <system::Module<Runtime>>::block_number()
+            + <service_discovery::Module<Runtime>>::default_lifetime();
+
+        <service_discovery::AccountInfoByStorageProviderId<Runtime>>::insert(
+            storage_provider_id,
+            service_discovery::ServiceProviderRecord {
+                identity: b"benchmark".to_vec(),
+                expires_at,
+            },
+        );
+    }
 }
 
 impl StorageProviderHelper {
@@ -34,3 +53,13 @@ impl StorageProviderHelper {
         (rand as usize) % upper_bound
     }
 }
+
+/// Verifies that the uploaded constitution document exists in the data directory.
+pub struct DataDirectoryContentExistenceValidator {}
+impl constitution::ContentExistenceValidator<ContentId> for DataDirectoryContentExistenceValidator {
+    fn content_exists(content_id: &ContentId) -> bool {
+        <crate::DataDirectory as storage::data_directory::ContentIdExists<Runtime>>::has_content(
+            content_id,
+        )
+    }
+}
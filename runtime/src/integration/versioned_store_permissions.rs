@@ -1,3 +1,4 @@
+use crate::integration::content_working_group::is_content_directory_lead;
 use crate::{AccountId, Runtime};
 use frame_support::{StorageMap, StorageValue};
 
@@ -38,6 +39,11 @@ impl versioned_store_permissions::CreateClassPermissionsChecker<Runtime>
     for ContentLeadCanCreateClasses
 {
     fn account_can_create_class_permissions(account: &AccountId) -> bool {
+        // the lead is the content directory working group lead once the curators are migrated
+        if content_working_group::CuratorsMigrated::get() {
+            return is_content_directory_lead(account);
+        }
+
         // get current lead id
         let maybe_current_lead_id = content_working_group::CurrentLeadId::<Runtime>::get();
         if let Some(lead_id) = maybe_current_lead_id {
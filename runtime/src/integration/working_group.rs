@@ -1,15 +1,23 @@
 use frame_support::StorageMap;
 use sp_std::marker::PhantomData;
 
-use crate::StorageWorkingGroupInstance;
+use crate::{
+    ContentDirectoryWorkingGroupInstance, ForumWorkingGroupInstance,
+    MembershipWorkingGroupInstance, StorageWorkingGroupInstance,
+};
 use stake::{BalanceOf, NegativeImbalance};
 
 pub struct StakingEventsHandler<T> {
     pub marker: PhantomData<T>,
 }
 
-impl<T: stake::Trait + working_group::Trait<StorageWorkingGroupInstance>>
-    stake::StakingEventsHandler<T> for StakingEventsHandler<T>
+impl<
+        T: stake::Trait
+            + working_group::Trait<ForumWorkingGroupInstance>
+            + working_group::Trait<StorageWorkingGroupInstance>
+            + working_group::Trait<ContentDirectoryWorkingGroupInstance>
+            + working_group::Trait<MembershipWorkingGroupInstance>,
+    > stake::StakingEventsHandler<T> for StakingEventsHandler<T>
 {
     /// Unstake remaining sum back to the source_account_id
     fn unstaked(
@@ -24,6 +32,15 @@ impl<T: stake::Trait + working_group::Trait<StorageWorkingGroupInstance>>
 
         let hiring_application_id = hiring::ApplicationIdByStakingId::<T>::get(*stake_id);
 
+        if working_group::MemberIdByHiringApplicationId::<T, ForumWorkingGroupInstance>::contains_key(
+            hiring_application_id,
+        ) {
+            return <working_group::Module<T, ForumWorkingGroupInstance>>::refund_working_group_stake(
+				*stake_id,
+				remaining_imbalance,
+			);
+        }
+
         if working_group::MemberIdByHiringApplicationId::<T, StorageWorkingGroupInstance>::contains_key(
             hiring_application_id,
         ) {
@@ -33,6 +50,24 @@ impl<T: stake::Trait + working_group::Trait<StorageWorkingGroupInstance>>
 			);
         }
 
+        if working_group::MemberIdByHiringApplicationId::<T, ContentDirectoryWorkingGroupInstance>::contains_key(
+            hiring_application_id,
+        ) {
+            return <working_group::Module<T, ContentDirectoryWorkingGroupInstance>>::refund_working_group_stake(
+				*stake_id,
+				remaining_imbalance,
+			);
+        }
+
+        if working_group::MemberIdByHiringApplicationId::<T, MembershipWorkingGroupInstance>::contains_key(
+            hiring_application_id,
+        ) {
+            return <working_group::Module<T, MembershipWorkingGroupInstance>>::refund_working_group_stake(
+				*stake_id,
+				remaining_imbalance,
+			);
+        }
+
         remaining_imbalance
     }
 
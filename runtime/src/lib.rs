@@ -15,6 +15,7 @@ include!(concat!(env!("OUT_DIR"), "/wasm_binary.rs"));
 
 mod constants;
 mod integration;
+mod migration;
 pub mod primitives;
 mod runtime_api;
 #[cfg(test)]
@@ -33,7 +34,7 @@ use sp_authority_discovery::AuthorityId as AuthorityDiscoveryId;
 use sp_core::crypto::KeyTypeId;
 use sp_runtime::curve::PiecewiseLinear;
 use sp_runtime::traits::{BlakeTwo256, Block as BlockT, IdentityLookup, OpaqueKeys, Saturating};
-use sp_runtime::{create_runtime_str, generic, impl_opaque_keys, Perbill};
+use sp_runtime::{create_runtime_str, generic, impl_opaque_keys, Perbill, Percent};
 use sp_std::boxed::Box;
 use sp_std::vec::Vec;
 #[cfg(feature = "std")]
@@ -54,7 +55,7 @@ use storage::data_object_storage_registry;
 pub use common;
 pub use content_working_group as content_wg;
 pub use forum;
-pub use governance::election_params::ElectionParameters;
+pub use governance::election_params::{ElectionParameters, VotingMode};
 pub use membership;
 #[cfg(any(feature = "std", test))]
 pub use pallet_balances::Call as BalancesCall;
@@ -70,10 +71,10 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
     spec_name: create_runtime_str!("joystream-node"),
     impl_name: create_runtime_str!("joystream-node"),
     authoring_version: 7,
-    spec_version: 5,
+    spec_version: 6,
     impl_version: 1,
     apis: crate::runtime_api::EXPORTED_RUNTIME_API_VERSIONS,
-    transaction_version: 1,
+    transaction_version: 2,
 };
 
 /// The version information used to identify this runtime when compiled natively.
@@ -378,24 +379,33 @@ impl versioned_store_permissions::Trait for Runtime {
     );
     type CreateClassPermissionsChecker =
         integration::versioned_store_permissions::ContentLeadOrSudoKeyCanCreateClasses;
+    type WeightInfo = ();
 }
 
 impl hiring::Trait for Runtime {
     type OpeningId = u64;
     type ApplicationId = u64;
-    type ApplicationDeactivatedHandler = (); // TODO - what needs to happen?
+    type ApplicationDeactivatedHandler = integration::hiring::ApplicationDeactivatedHandler;
     type StakeHandlerProvider = hiring::Module<Self>;
 }
 
+parameter_types! {
+    pub const MintSpendingEpochLength: BlockNumber = 7 * DAYS;
+}
+
 impl minting::Trait for Runtime {
     type Currency = <Self as common::currency::GovernanceCurrency>::Currency;
     type MintId = u64;
+    type SpendingEpochLength = MintSpendingEpochLength;
+    type MintCapacityIncreased = RecurringRewards;
+    type WeightInfo = ();
 }
 
 impl recurring_rewards::Trait for Runtime {
-    type PayoutStatusHandler = (); // TODO - deal with successful and failed payouts
+    type Event = Event;
+    type PayoutStatusHandler = integration::recurring_rewards::RewardArrearsRecorder;
     type RecipientId = u64;
-    type RewardRelationshipId = u64;
+    type RewardRelationshipId = RewardRelationshipId;
 }
 
 parameter_types! {
@@ -418,6 +428,7 @@ impl stake::Trait for Runtime {
 
 impl content_wg::Trait for Runtime {
     type Event = Event;
+    type WeightInfo = ();
 }
 
 impl common::currency::GovernanceCurrency for Runtime {
@@ -429,9 +440,24 @@ impl governance::election::Trait for Runtime {
     type CouncilElected = (Council, integration::proposals::CouncilElectedHandler);
 }
 
+parameter_types! {
+    pub const CouncilRecallPeriod: BlockNumber = 14400;
+    pub const CouncilRecallThreshold: Percent = Percent::from_percent(50);
+    pub const MinCouncilRecallStake: Balance = 1000;
+    pub const MaxCouncilRecallBackers: u32 = 100;
+}
+
 impl governance::council::Trait for Runtime {
     type Event = Event;
     type CouncilTermEnded = (CouncilElection,);
+    type CouncilSeatVacated = (
+        CouncilElection,
+        integration::proposals::CouncilSeatVacatedHandler,
+    );
+    type RecallPeriod = CouncilRecallPeriod;
+    type RecallThreshold = CouncilRecallThreshold;
+    type MinRecallStake = MinCouncilRecallStake;
+    type MaxRecallBackers = MaxCouncilRecallBackers;
 }
 
 impl memo::Trait for Runtime {
@@ -440,26 +466,47 @@ impl memo::Trait for Runtime {
 
 parameter_types! {
     pub const MaxObjectsPerInjection: u32 = 100;
+    pub const MaxReplicationChecksPerBlock: u32 = 20;
+    pub const StorageChallengePeriod: BlockNumber = HOURS;
+    pub const StorageChallengesPerPeriod: u32 = 10;
+    pub const StorageChallengeResponsePeriod: BlockNumber = 10 * MINUTES;
+    pub const StorageProofChunkSize: u32 = 1024;
+    pub const FailedStorageChallengeSlash: Balance = 100;
 }
 
 impl storage::data_object_type_registry::Trait for Runtime {
     type Event = Event;
     type DataObjectTypeId = u64;
+    type WeightInfo = ();
 }
 
 impl storage::data_directory::Trait for Runtime {
     type Event = Event;
     type ContentId = ContentId;
+    type ChannelId = content_wg::ChannelId<Runtime>;
+    type ChannelOwnershipValidator =
+        integration::content_working_group::ContentWorkingGroupChannelOwnershipValidator;
+    type ContentDeletionHandler = DataObjectStorageRegistry;
+    type ContentDeletionValidator = integration::constitution::ConstitutionContentDeletionValidator;
     type StorageProviderHelper = integration::storage::StorageProviderHelper;
     type IsActiveDataObjectType = DataObjectTypeRegistry;
     type MemberOriginValidator = MembershipOriginValidator<Self>;
     type MaxObjectsPerInjection = MaxObjectsPerInjection;
+    type WeightInfo = ();
 }
 
 impl storage::data_object_storage_registry::Trait for Runtime {
     type Event = Event;
     type DataObjectStorageRelationshipId = u64;
     type ContentIdExists = DataDirectory;
+    type MaxReplicationChecksPerBlock = MaxReplicationChecksPerBlock;
+    type Randomness = RandomnessCollectiveFlip;
+    type ChallengePeriod = StorageChallengePeriod;
+    type ChallengesPerPeriod = StorageChallengesPerPeriod;
+    type ChallengeResponsePeriod = StorageChallengeResponsePeriod;
+    type StorageProofChunkSize = StorageProofChunkSize;
+    type FailedChallengeSlash = FailedStorageChallengeSlash;
+    type WeightInfo = ();
 }
 
 impl membership::Trait for Runtime {
@@ -468,25 +515,57 @@ impl membership::Trait for Runtime {
     type PaidTermId = u64;
     type SubscriptionId = u64;
     type ActorId = ActorId;
+    type WeightInfo = ();
 }
 
 impl forum::Trait for Runtime {
     type Event = Event;
     type MembershipRegistry = integration::forum::ShimMembershipRegistry;
+    type ModeratorRegistry = integration::forum::ForumWorkingGroupModeratorRegistry;
+    type ModeratorId = ActorId;
     type ThreadId = ThreadId;
     type PostId = PostId;
+    type WeightInfo = ();
 }
 
+// The forum working group instance alias.
+pub type ForumWorkingGroupInstance = working_group::Instance1;
+
 // The storage working group instance alias.
 pub type StorageWorkingGroupInstance = working_group::Instance2;
 
+// The content directory working group instance alias.
+pub type ContentDirectoryWorkingGroupInstance = working_group::Instance3;
+
+// The membership working group instance alias.
+pub type MembershipWorkingGroupInstance = working_group::Instance4;
+
 parameter_types! {
     pub const MaxWorkerNumberLimit: u32 = 100;
 }
 
+impl working_group::Trait<ForumWorkingGroupInstance> for Runtime {
+    type Event = Event;
+    type MaxWorkerNumberLimit = MaxWorkerNumberLimit;
+    type WeightInfo = ();
+}
+
 impl working_group::Trait<StorageWorkingGroupInstance> for Runtime {
     type Event = Event;
     type MaxWorkerNumberLimit = MaxWorkerNumberLimit;
+    type WeightInfo = ();
+}
+
+impl working_group::Trait<ContentDirectoryWorkingGroupInstance> for Runtime {
+    type Event = Event;
+    type MaxWorkerNumberLimit = MaxWorkerNumberLimit;
+    type WeightInfo = ();
+}
+
+impl working_group::Trait<MembershipWorkingGroupInstance> for Runtime {
+    type Event = Event;
+    type MaxWorkerNumberLimit = MaxWorkerNumberLimit;
+    type WeightInfo = ();
 }
 
 impl service_discovery::Trait for Runtime {
@@ -499,6 +578,7 @@ parameter_types! {
     pub const ProposalTitleMaxLength: u32 = 40;
     pub const ProposalDescriptionMaxLength: u32 = 3000;
     pub const ProposalMaxActiveProposalLimit: u32 = 5;
+    pub const ProposalMaxExactExecutionDelay: BlockNumber = 30 * DAYS;
 }
 
 impl proposals_engine::Trait for Runtime {
@@ -513,7 +593,9 @@ impl proposals_engine::Trait for Runtime {
     type TitleMaxLength = ProposalTitleMaxLength;
     type DescriptionMaxLength = ProposalDescriptionMaxLength;
     type MaxActiveProposalLimit = ProposalMaxActiveProposalLimit;
+    type MaxExactExecutionDelay = ProposalMaxExactExecutionDelay;
     type DispatchableCallCode = Call;
+    type WeightInfo = ();
 }
 impl Default for Call {
     fn default() -> Self {
@@ -537,6 +619,7 @@ impl proposals_discussion::Trait for Runtime {
     type ThreadTitleLengthLimit = ProposalThreadTitleLengthLimit;
     type PostLengthLimit = ProposalPostLengthLimit;
     type MaxThreadInARowNumber = ProposalMaxThreadInARowNumber;
+    type WeightInfo = ();
 }
 
 parameter_types! {
@@ -549,8 +632,23 @@ impl proposals_codex::Trait for Runtime {
     type TextProposalMaxLength = TextProposalMaxLength;
     type RuntimeUpgradeWasmProposalMaxLength = RuntimeUpgradeWasmProposalMaxLength;
     type ProposalEncoder = ExtrinsicProposalEncoder;
+    type WeightInfo = ();
 }
 
+parameter_types! {
+    pub const ConstitutionTextMaxLength: u32 = 100_000;
+}
+
+impl constitution::Trait for Runtime {
+    type Event = Event;
+    type ContentId = ContentId;
+    type ContentExistenceValidator = integration::storage::DataDirectoryContentExistenceValidator;
+    type ConstitutionTextMaxLength = ConstitutionTextMaxLength;
+    type WeightInfo = ();
+}
+
+impl migration::Trait for Runtime {}
+
 parameter_types! {
     pub const TombstoneDeposit: Balance = 1; // TODO: adjust fee
     pub const RentByteFee: Balance = 1; // TODO: adjust fee
@@ -609,7 +707,7 @@ construct_runtime!(
         VersionedStorePermissions: versioned_store_permissions::{Module, Call, Storage, Config<T>},
         Stake: stake::{Module, Call, Storage},
         Minting: minting::{Module, Call, Storage},
-        RecurringRewards: recurring_rewards::{Module, Call, Storage},
+        RecurringRewards: recurring_rewards::{Module, Call, Storage, Event<T>},
         Hiring: hiring::{Module, Call, Storage},
         ContentWorkingGroup: content_wg::{Module, Call, Storage, Event<T>, Config<T>},
         // --- Storage
@@ -618,11 +716,20 @@ construct_runtime!(
         DataObjectStorageRegistry: data_object_storage_registry::{Module, Call, Storage, Event<T>, Config<T>},
         Discovery: service_discovery::{Module, Call, Storage, Event<T>},
         // --- Proposals
-        ProposalsEngine: proposals_engine::{Module, Call, Storage, Event<T>},
+        ProposalsEngine: proposals_engine::{Module, Call, Storage, Event<T>, Config},
         ProposalsDiscussion: proposals_discussion::{Module, Call, Storage, Event<T>},
         ProposalsCodex: proposals_codex::{Module, Call, Storage, Config<T>},
         // --- Working groups
-        // reserved for the future use: ForumWorkingGroup: working_group::<Instance1>::{Module, Call, Storage, Event<T>},
+        // The forum working group shifts the module index of the storage working group and the
+        // modules below, changing their call and event encoding (covered by `transaction_version`).
+        ForumWorkingGroup: working_group::<Instance1>::{Module, Call, Storage, Config<T>, Event<T>},
         StorageWorkingGroup: working_group::<Instance2>::{Module, Call, Storage, Config<T>, Event<T>},
+        // --- Migrations
+        Migration: migration::{Module},
+        // --- Constitution
+        Constitution: constitution::{Module, Call, Storage, Event<T>},
+        // --- Working groups added after the constitution to keep the module indices
+        ContentDirectoryWorkingGroup: working_group::<Instance3>::{Module, Call, Storage, Config<T>, Event<T>},
+        MembershipWorkingGroup: working_group::<Instance4>::{Module, Call, Storage, Config<T>, Event<T>},
     }
 );
@@ -0,0 +1,224 @@
+//! Runtime storage migrations performed on the runtime upgrade.
+
+use frame_support::traits::Get;
+use frame_support::weights::Weight;
+use frame_support::{decl_module, print, IterableStorageMap, StorageMap, StorageValue};
+use sp_std::vec::Vec;
+
+use crate::{
+    ContentDirectoryWorkingGroup, ContentDirectoryWorkingGroupInstance, ContentWorkingGroup,
+    Council, ForumWorkingGroup, MembershipWorkingGroup, Minting, ProposalsCodex, ProposalsEngine,
+    Runtime, StorageWorkingGroup,
+};
+
+pub trait Trait: system::Trait + storage::data_directory::Trait + recurring_rewards::Trait {}
+
+decl_module! {
+    pub struct Module<T: Trait> for enum Call where origin: T::Origin {
+        fn on_runtime_upgrade() -> Weight {
+            // Moves the known content ids vector to the index.
+            <storage::data_directory::Module<T>>::migrate_known_content_ids()
+                // Adds the replication factor and the object size limit to the data object types.
+                .saturating_add(
+                    <storage::data_object_type_registry::Module<T>>::migrate_data_object_types(),
+                )
+                // Counts the storage usage of the existing content against the member quotas.
+                .saturating_add(<storage::data_directory::Module<T>>::migrate_member_storage_usage())
+                // Schedules the mint adjustments and the reward payouts by block.
+                .saturating_add(<minting::Module<T>>::migrate_adjustment_schedule())
+                .saturating_add(<recurring_rewards::Module<T>>::migrate_payment_schedule())
+                // Creates the forum, the content directory and the membership working groups.
+                .saturating_add(initialize_new_working_groups())
+                // Moves the curators to the content directory working group.
+                .saturating_add(migrate_curators_to_content_directory_working_group())
+                // Funds the working group budgets from the council mint.
+                .saturating_add(set_council_mint_as_working_group_mints_parent())
+                // Moves the proposal periods to the proposal parameters.
+                .saturating_add(ProposalsCodex::migrate_proposal_parameters())
+                // Sets no exact execution block for the existing proposals.
+                .saturating_add(ProposalsEngine::migrate_exact_execution_block())
+                // Converts the stored proposal details and encodes the pending proposals again.
+                .saturating_add(ProposalsCodex::migrate_proposal_details())
+                // Adds the automatic fill policy and the opening limits to the working group openings.
+                .saturating_add(StorageWorkingGroup::migrate_opening_policy_commitments())
+                .saturating_add(ForumWorkingGroup::migrate_opening_policy_commitments())
+                // Adds the opening limits to the curator openings.
+                .saturating_add(ContentWorkingGroup::migrate_opening_policy_commitments())
+                // Indexes the applications by the hiring application ids to handle their deactivation.
+                .saturating_add(StorageWorkingGroup::migrate_application_ids_by_hiring_application_id())
+                .saturating_add(ForumWorkingGroup::migrate_application_ids_by_hiring_application_id())
+                .saturating_add(
+                    ContentWorkingGroup::migrate_curator_application_ids_by_hiring_application_id(),
+                )
+        }
+    }
+}
+
+// Makes the council mint the parent of the working group mints, so the council sets a single
+// budget. Mints already having a parent are kept.
+fn set_council_mint_as_working_group_mints_parent() -> Weight {
+    let council_mint_id = Council::council_mint();
+    let working_group_mint_ids = [
+        StorageWorkingGroup::mint(),
+        ForumWorkingGroup::mint(),
+        ContentWorkingGroup::mint(),
+        ContentDirectoryWorkingGroup::mint(),
+        MembershipWorkingGroup::mint(),
+    ];
+
+    let mut writes: Weight = 0;
+
+    for mint_id in working_group_mint_ids.iter() {
+        if *mint_id != council_mint_id
+            && Minting::parent_mint_id(mint_id).is_none()
+            // Fails for the mints not created yet.
+            && Minting::set_parent_mint(*mint_id, Some(council_mint_id)).is_ok()
+        {
+            writes += 2;
+        }
+    }
+
+    <Runtime as system::Trait>::DbWeight::get().reads_writes(26, writes)
+}
+
+// Creates the mints and the text constraints of the working groups added by the runtime upgrade.
+// The content directory working group takes the curator constraints, the forum and the
+// membership working groups take the default constraints (as in the genesis config).
+pub(crate) fn initialize_new_working_groups() -> Weight {
+    ForumWorkingGroup::initialize_on_runtime_upgrade(
+        working_group::default_text_constraint(),
+        working_group::default_text_constraint(),
+        working_group::default_text_constraint(),
+        0,
+    )
+    .saturating_add(ContentDirectoryWorkingGroup::initialize_on_runtime_upgrade(
+        ContentWorkingGroup::opening_human_readable_text(),
+        ContentWorkingGroup::curator_application_human_readable_text(),
+        ContentWorkingGroup::curator_exit_rationale_text(),
+        0,
+    ))
+    .saturating_add(MembershipWorkingGroup::initialize_on_runtime_upgrade(
+        working_group::default_text_constraint(),
+        working_group::default_text_constraint(),
+        working_group::default_text_constraint(),
+        0,
+    ))
+}
+
+// Moves the active curators and the lead of the content working group to the content directory
+// working group. The curator ids are kept as the worker ids, so the curator principals keep their
+// permissions. The lead gets the next id after the curators. The moved curators and the lead are
+// exited in the content working group, and the content working group mint becomes the content
+// directory working group mint, so the reward relationships keep being funded by the council.
+pub(crate) fn migrate_curators_to_content_directory_working_group() -> Weight {
+    type WorkerById = working_group::WorkerById<Runtime, ContentDirectoryWorkingGroupInstance>;
+
+    let db_weight = <Runtime as system::Trait>::DbWeight::get();
+
+    if ContentWorkingGroup::curators_migrated() {
+        return db_weight.reads(1);
+    }
+
+    // The curator ids cannot be kept once the working group has hired.
+    if ContentDirectoryWorkingGroup::next_worker_id() != 0 {
+        print("Cannot migrate the curators: the content directory working group has workers.");
+        return db_weight.reads(2);
+    }
+
+    let current_block = <system::Module<Runtime>>::block_number();
+    let exit_summary = content_working_group::CuratorExitSummary::new(
+        &content_working_group::CuratorExitInitiationOrigin::Lead,
+        &current_block,
+        b"Moved to the content directory working group",
+    );
+
+    let active_curators = <content_working_group::CuratorById<Runtime>>::iter()
+        .filter(|(_, curator)| curator.stage == content_working_group::CuratorRoleStage::Active)
+        .collect::<Vec<_>>();
+
+    for (curator_id, curator) in active_curators.iter() {
+        let member_id = ContentWorkingGroup::curator_application_by_id(
+            curator.induction.curator_application_id,
+        )
+        .member_id;
+        let role_stake_profile = curator.role_stake_profile.as_ref().map(|profile| {
+            working_group::RoleStakeProfile::new(
+                &profile.stake_id,
+                &profile.termination_unstaking_period,
+                &profile.exit_unstaking_period,
+            )
+        });
+
+        WorkerById::insert(
+            curator_id,
+            working_group::Worker::new(
+                &member_id,
+                &curator.role_account,
+                &curator.reward_relationship,
+                &role_stake_profile,
+            ),
+        );
+
+        <content_working_group::CuratorById<Runtime>>::mutate(curator_id, |curator| {
+            curator.stage = content_working_group::CuratorRoleStage::Exited(exit_summary.clone())
+        });
+    }
+
+    let mut active_worker_count = active_curators.len() as u32;
+    let mut next_worker_id = ContentWorkingGroup::next_curator_id();
+
+    if let Some(lead_id) = ContentWorkingGroup::current_lead_id() {
+        let lead = ContentWorkingGroup::lead_by_id(lead_id);
+
+        if lead.stage == content_working_group::LeadRoleState::Active {
+            WorkerById::insert(
+                next_worker_id,
+                working_group::Worker::new(
+                    &lead.member_id,
+                    &lead.role_account,
+                    &lead.reward_relationship,
+                    &None,
+                ),
+            );
+            <working_group::CurrentLead<Runtime, ContentDirectoryWorkingGroupInstance>>::put(
+                next_worker_id,
+            );
+
+            next_worker_id += 1;
+            active_worker_count += 1;
+        }
+
+        <content_working_group::LeadById<Runtime>>::insert(
+            lead_id,
+            content_working_group::Lead {
+                stage: content_working_group::LeadRoleState::Exited(
+                    content_working_group::ExitedLeadRole {
+                        initiated_at_block_number: current_block,
+                    },
+                ),
+                ..lead
+            },
+        );
+        <content_working_group::CurrentLeadId<Runtime>>::kill();
+    }
+
+    // The reward relationships of the moved curators and the lead are paid from the content
+    // working group mint. The mint created for the content directory working group on the
+    // initialization is left unused.
+    <working_group::Mint<Runtime, ContentDirectoryWorkingGroupInstance>>::put(
+        ContentWorkingGroup::mint(),
+    );
+
+    <working_group::NextWorkerId<Runtime, ContentDirectoryWorkingGroupInstance>>::put(
+        next_worker_id,
+    );
+    <working_group::ActiveWorkerCount<ContentDirectoryWorkingGroupInstance>>::put(
+        active_worker_count,
+    );
+    content_working_group::CuratorsMigrated::put(true);
+
+    // Curators are never removed.
+    let curators_count: Weight = ContentWorkingGroup::next_curator_id();
+
+    db_weight.reads_writes(7 + 2 * curators_count, 8 + 2 * curators_count)
+}
@@ -10,14 +10,19 @@ use sp_runtime::traits::{BlakeTwo256, Block as BlockT, NumberFor};
 use sp_runtime::{generic, ApplyExtrinsicResult};
 use sp_std::vec::Vec;
 
+use common::working_group::WorkingGroup;
+
 use crate::constants::PRIMARY_PROBABILITY;
+use crate::integration;
 use crate::{
-    AccountId, AuthorityDiscoveryId, Balance, BlockNumber, EpochDuration, GrandpaAuthorityList,
-    GrandpaId, Hash, Index, RuntimeVersion, Signature, VERSION,
+    AccountId, ActorId, AuthorityDiscoveryId, Balance, BlockNumber, ContentId, EpochDuration,
+    GrandpaAuthorityList, GrandpaId, Hash, Index, RewardRelationshipId, RuntimeVersion, Signature,
+    VERSION,
 };
 use crate::{
-    AllModules, AuthorityDiscovery, Babe, Call, Grandpa, Historical, InherentDataExt,
-    RandomnessCollectiveFlip, Runtime, SessionKeys, System, TransactionPayment,
+    AllModules, AuthorityDiscovery, Babe, Call, CouncilElection, DataDirectory, Grandpa,
+    Historical, InherentDataExt, Minting, ProposalsCodex, RandomnessCollectiveFlip,
+    RecurringRewards, Runtime, SessionKeys, System, TransactionPayment,
 };
 
 /// The SignedExtension to the basic transaction logic.
@@ -57,6 +62,89 @@ pub type Executive =
 /// Export of the private const generated within the macro.
 pub const EXPORTED_RUNTIME_API_VERSIONS: sp_version::ApisVec = RUNTIME_API_VERSIONS;
 
+/// Proposal with its details and voting state returned by the `ProposalsApi`.
+pub type ProposalInfo = proposals_codex::ProposalInfoOf<Runtime>;
+
+/// Proposals filter for the `ProposalsApi`.
+pub type ProposalsFilter =
+    proposals_codex::ProposalsFilter<<Runtime as membership::Trait>::MemberId>;
+
+/// Tokens minted by a mint within a spending epoch returned by the `MintsApi`.
+pub type MintSpending = minting::MintSpending<Balance>;
+
+/// Archived results of an election round returned by the `ElectionsApi`.
+pub type ElectionResult = governance::election::ElectionResultOf<Runtime>;
+
+/// Participation of an account in an election round returned by the `ElectionsApi`.
+pub type ElectionParticipation = governance::election::ElectionParticipation<Balance>;
+
+sp_api::decl_runtime_apis! {
+    /// Provides proposals with their decoded details and voting state.
+    pub trait ProposalsApi {
+        /// Returns up to `limit` proposals matching the filter. Proposals are ordered from newer
+        /// to older ones starting from the `start_id` (inclusive) or from the latest proposal.
+        fn proposals(
+            filter: ProposalsFilter,
+            start_id: Option<u32>,
+            limit: u32,
+        ) -> Vec<ProposalInfo>;
+
+        /// Returns the proposal with its details and voting state.
+        fn proposal(proposal_id: u32) -> Option<ProposalInfo>;
+    }
+
+    /// Provides the content ids known to the data directory.
+    pub trait DataDirectoryApi {
+        /// Returns up to `limit` known content ids starting from the `start` index.
+        fn known_content_ids(start: u64, limit: u64) -> Vec<ContentId>;
+
+        /// Returns the number of the known content ids.
+        fn known_content_ids_count() -> u64;
+    }
+
+    /// Provides the mint hierarchy and the spending of the mints.
+    pub trait MintsApi {
+        /// Returns the current spending epoch of the mints.
+        fn current_spending_epoch() -> BlockNumber;
+
+        /// Returns the tokens minted by the mint within the spending epoch, by the recipient
+        /// category.
+        fn mint_spending(mint_id: u64, epoch: BlockNumber) -> MintSpending;
+
+        /// Returns the child mints of the mint, drawing their capacity top-ups from it.
+        fn child_mint_ids(mint_id: u64) -> Vec<u64>;
+    }
+
+    /// Provides the rewards owed for the payouts failed because of an insufficient mint capacity.
+    pub trait RewardArrearsApi {
+        /// Returns the reward owed to the worker of the working group.
+        fn worker_owed_reward(working_group: WorkingGroup, worker_id: ActorId) -> Balance;
+
+        /// Returns the reward owed to the curator of the content working group.
+        fn curator_owed_reward(curator_id: ActorId) -> Balance;
+
+        /// Returns the reward owed to the council member.
+        fn councilor_owed_reward(account_id: AccountId) -> Balance;
+
+        /// Returns the reward owed for the reward relationship. The arrears outlive the
+        /// relationship, so the reward owed to the departed workers can be queried.
+        fn owed_reward(reward_relationship_id: RewardRelationshipId) -> Balance;
+    }
+
+    /// Provides the archived results of the council elections.
+    pub trait ElectionsApi {
+        /// Returns up to `limit` election results ordered from newer to older rounds starting
+        /// from the `start_round` (inclusive) or from the latest round.
+        fn election_results(start_round: Option<u32>, limit: u32) -> Vec<ElectionResult>;
+
+        /// Returns the results of the election round.
+        fn election_result(round: u32) -> Option<ElectionResult>;
+
+        /// Returns the election rounds the account participated in as an applicant or a voter.
+        fn participation_history(account_id: AccountId) -> Vec<ElectionParticipation>;
+    }
+}
+
 impl_runtime_apis! {
     impl sp_api::Core<Block> for Runtime {
         fn version() -> RuntimeVersion {
@@ -191,6 +279,76 @@ impl_runtime_apis! {
         }
     }
 
+    impl ProposalsApi<Block> for Runtime {
+        fn proposals(
+            filter: ProposalsFilter,
+            start_id: Option<u32>,
+            limit: u32,
+        ) -> Vec<ProposalInfo> {
+            ProposalsCodex::proposals(filter, start_id, limit)
+        }
+
+        fn proposal(proposal_id: u32) -> Option<ProposalInfo> {
+            ProposalsCodex::proposal_info(proposal_id)
+        }
+    }
+
+    impl DataDirectoryApi<Block> for Runtime {
+        fn known_content_ids(start: u64, limit: u64) -> Vec<ContentId> {
+            DataDirectory::known_content_ids_page(start, limit)
+        }
+
+        fn known_content_ids_count() -> u64 {
+            DataDirectory::known_content_ids_count()
+        }
+    }
+
+    impl MintsApi<Block> for Runtime {
+        fn current_spending_epoch() -> BlockNumber {
+            Minting::current_spending_epoch()
+        }
+
+        fn mint_spending(mint_id: u64, epoch: BlockNumber) -> MintSpending {
+            Minting::mint_spending(mint_id, epoch)
+        }
+
+        fn child_mint_ids(mint_id: u64) -> Vec<u64> {
+            Minting::child_mint_ids(mint_id)
+        }
+    }
+
+    impl RewardArrearsApi<Block> for Runtime {
+        fn worker_owed_reward(working_group: WorkingGroup, worker_id: ActorId) -> Balance {
+            integration::recurring_rewards::worker_owed_reward(working_group, worker_id)
+        }
+
+        fn curator_owed_reward(curator_id: ActorId) -> Balance {
+            integration::recurring_rewards::curator_owed_reward(curator_id)
+        }
+
+        fn councilor_owed_reward(account_id: AccountId) -> Balance {
+            integration::recurring_rewards::councilor_owed_reward(account_id)
+        }
+
+        fn owed_reward(reward_relationship_id: RewardRelationshipId) -> Balance {
+            RecurringRewards::owed_reward(reward_relationship_id)
+        }
+    }
+
+    impl ElectionsApi<Block> for Runtime {
+        fn election_results(start_round: Option<u32>, limit: u32) -> Vec<ElectionResult> {
+            CouncilElection::election_results_page(start_round, limit)
+        }
+
+        fn election_result(round: u32) -> Option<ElectionResult> {
+            CouncilElection::election_results(round)
+        }
+
+        fn participation_history(account_id: AccountId) -> Vec<ElectionParticipation> {
+            CouncilElection::participation_history(&account_id)
+        }
+    }
+
     impl sp_session::SessionKeys<Block> for Runtime {
         fn generate_session_keys(seed: Option<Vec<u8>>) -> Vec<u8> {
             SessionKeys::generate(seed)
@@ -201,4 +359,46 @@ impl_runtime_apis! {
             SessionKeys::decode_into_raw_public_keys(&encoded)
         }
     }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    impl frame_benchmarking::Benchmark<Block> for Runtime {
+        fn dispatch_benchmark(
+            pallet: Vec<u8>,
+            benchmark: Vec<u8>,
+            lowest_range_values: Vec<u32>,
+            highest_range_values: Vec<u32>,
+            steps: Vec<u32>,
+            repeat: u32,
+        ) -> Result<Vec<frame_benchmarking::BenchmarkBatch>, sp_runtime::RuntimeString> {
+            use frame_benchmarking::{add_benchmark, BenchmarkBatch, Benchmarking};
+            use crate::{
+                Constitution, ContentWorkingGroup, DataDirectory, DataObjectStorageRegistry,
+                DataObjectTypeRegistry, Forum, Members, ProposalsDiscussion, ProposalsEngine,
+                StorageWorkingGroup, VersionedStorePermissions,
+            };
+
+            let mut batches = Vec::<BenchmarkBatch>::new();
+            let params = (&pallet, &benchmark, &lowest_range_values, &highest_range_values, &steps, repeat);
+
+            add_benchmark!(params, batches, b"membership", Members);
+            add_benchmark!(params, batches, b"forum", Forum);
+            add_benchmark!(params, batches, b"working_group", StorageWorkingGroup);
+            add_benchmark!(params, batches, b"content_working_group", ContentWorkingGroup);
+            add_benchmark!(params, batches, b"versioned_store_permissions", VersionedStorePermissions);
+            add_benchmark!(params, batches, b"data_directory", DataDirectory);
+            add_benchmark!(params, batches, b"data_object_type_registry", DataObjectTypeRegistry);
+            add_benchmark!(params, batches, b"data_object_storage_registry", DataObjectStorageRegistry);
+            add_benchmark!(params, batches, b"proposals_engine", ProposalsEngine);
+            add_benchmark!(params, batches, b"proposals_discussion", ProposalsDiscussion);
+            add_benchmark!(params, batches, b"proposals_codex", ProposalsCodex);
+            add_benchmark!(params, batches, b"constitution", Constitution);
+            add_benchmark!(params, batches, b"minting", Minting);
+
+            if batches.is_empty() {
+                return Err("Benchmark not found for this pallet.".into());
+            }
+
+            Ok(batches)
+        }
+    }
 }
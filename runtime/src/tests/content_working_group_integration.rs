@@ -0,0 +1,208 @@
+use super::initial_test_ext;
+use crate::integration::content_working_group::{
+    AnyActiveCuratorCredential, ContentWorkingGroupCredentials, CurrentLeadCredential,
+    PrincipalIdMappingStartsAtCredential,
+};
+use crate::migration::{
+    initialize_new_working_groups, migrate_curators_to_content_directory_working_group,
+};
+use crate::{
+    AccountId, ContentDirectoryWorkingGroup, ContentDirectoryWorkingGroupInstance,
+    ContentWorkingGroup, Minting, Runtime,
+};
+
+use content_working_group::{
+    Curator, CuratorApplication, CuratorInduction, CuratorRoleStage, Lead, LeadRoleState, Principal,
+};
+use frame_support::{StorageMap, StorageValue};
+use versioned_store_permissions::CredentialChecker;
+use working_group::Worker;
+
+#[test]
+fn migrated_curator_credentials_succeed() {
+    initial_test_ext().execute_with(|| {
+        let lead_account: AccountId = [1; 32].into();
+        let curator_account: AccountId = [2; 32].into();
+        let other_account: AccountId = [3; 32].into();
+
+        let curator_id = 4;
+        let lead_worker_id = 5;
+        let curator_principal_id = 0;
+        let lead_principal_id = 1;
+
+        <content_working_group::PrincipalById<Runtime>>::insert(
+            curator_principal_id,
+            Principal::Curator(curator_id),
+        );
+        <content_working_group::PrincipalById<Runtime>>::insert(lead_principal_id, Principal::Lead);
+
+        <working_group::WorkerById<Runtime, ContentDirectoryWorkingGroupInstance>>::insert(
+            curator_id,
+            Worker::new(&0, &curator_account, &None, &None),
+        );
+        <working_group::WorkerById<Runtime, ContentDirectoryWorkingGroupInstance>>::insert(
+            lead_worker_id,
+            Worker::new(&1, &lead_account, &None, &None),
+        );
+        <working_group::CurrentLead<Runtime, ContentDirectoryWorkingGroupInstance>>::put(
+            lead_worker_id,
+        );
+
+        let has_credential = |account: &AccountId, credential| {
+            ContentWorkingGroupCredentials::account_has_credential(account, credential)
+        };
+        let curator_credential = PrincipalIdMappingStartsAtCredential::get() + curator_principal_id;
+        let lead_credential = PrincipalIdMappingStartsAtCredential::get() + lead_principal_id;
+
+        // The content working group curators are checked until migrated.
+        assert!(!has_credential(
+            &curator_account,
+            AnyActiveCuratorCredential::get()
+        ));
+        assert!(!has_credential(&lead_account, CurrentLeadCredential::get()));
+
+        content_working_group::CuratorsMigrated::put(true);
+
+        assert!(has_credential(
+            &curator_account,
+            AnyActiveCuratorCredential::get()
+        ));
+        assert!(has_credential(&curator_account, curator_credential));
+        assert!(!has_credential(&other_account, curator_credential));
+
+        assert!(has_credential(&lead_account, CurrentLeadCredential::get()));
+        assert!(has_credential(&lead_account, lead_credential));
+        assert!(!has_credential(&curator_account, lead_credential));
+    });
+}
+
+#[test]
+fn migrate_curators_to_content_directory_working_group_succeeds() {
+    initial_test_ext().execute_with(|| {
+        let curator_account: AccountId = [1; 32].into();
+        let exited_curator_account: AccountId = [2; 32].into();
+        let lead_account: AccountId = [3; 32].into();
+
+        let curator_member_id = 4;
+        let lead_member_id = 5;
+        let curator_id = 0;
+        let exited_curator_id = 1;
+        let lead_id = 0;
+
+        let mint_id = Minting::add_mint(1000, None).unwrap();
+        <content_working_group::Mint<Runtime>>::put(mint_id);
+
+        let add_reward_relationship = |account: &AccountId| {
+            let recipient = <recurring_rewards::Module<Runtime>>::add_recipient();
+
+            <recurring_rewards::Module<Runtime>>::add_reward_relationship(
+                mint_id,
+                recipient,
+                account.clone(),
+                10,
+                100,
+                Some(10),
+            )
+            .unwrap()
+        };
+        let curator_reward_relationship = add_reward_relationship(&curator_account);
+        let lead_reward_relationship = add_reward_relationship(&lead_account);
+
+        <content_working_group::CuratorApplicationById<Runtime>>::insert(
+            0,
+            CuratorApplication::new(&curator_account, &0, &curator_member_id, &0),
+        );
+        <content_working_group::CuratorById<Runtime>>::insert(
+            curator_id,
+            Curator::new(
+                &curator_account,
+                &Some(curator_reward_relationship),
+                &None,
+                &CuratorRoleStage::Active,
+                &CuratorInduction::new(&lead_id, &0, &1),
+                &0,
+            ),
+        );
+        <content_working_group::CuratorById<Runtime>>::insert(
+            exited_curator_id,
+            Curator::new(
+                &exited_curator_account,
+                &None,
+                &None,
+                &CuratorRoleStage::Exited(content_working_group::CuratorExitSummary::new(
+                    &content_working_group::CuratorExitInitiationOrigin::Curator,
+                    &1,
+                    &[],
+                )),
+                &CuratorInduction::new(&lead_id, &0, &1),
+                &1,
+            ),
+        );
+        <content_working_group::NextCuratorId<Runtime>>::put(2);
+
+        <content_working_group::LeadById<Runtime>>::insert(
+            lead_id,
+            Lead {
+                member_id: lead_member_id,
+                role_account: lead_account.clone(),
+                reward_relationship: Some(lead_reward_relationship),
+                inducted: 1,
+                stage: LeadRoleState::Active,
+            },
+        );
+        <content_working_group::CurrentLeadId<Runtime>>::put(lead_id);
+
+        initialize_new_working_groups();
+        migrate_curators_to_content_directory_working_group();
+
+        // The curator ids are kept as the worker ids, the lead takes the next id.
+        let lead_worker_id = 2;
+
+        assert_eq!(
+            ContentDirectoryWorkingGroup::worker_by_id(curator_id),
+            Worker::new(
+                &curator_member_id,
+                &curator_account,
+                &Some(curator_reward_relationship),
+                &None
+            )
+        );
+        assert!(!<working_group::WorkerById<
+            Runtime,
+            ContentDirectoryWorkingGroupInstance,
+        >>::contains_key(exited_curator_id));
+        assert_eq!(
+            ContentDirectoryWorkingGroup::worker_by_id(lead_worker_id),
+            Worker::new(
+                &lead_member_id,
+                &lead_account,
+                &Some(lead_reward_relationship),
+                &None
+            )
+        );
+        assert_eq!(
+            ContentDirectoryWorkingGroup::current_lead(),
+            Some(lead_worker_id)
+        );
+        assert_eq!(ContentDirectoryWorkingGroup::active_worker_count(), 2);
+        assert_eq!(
+            ContentDirectoryWorkingGroup::next_worker_id(),
+            lead_worker_id + 1
+        );
+
+        // The reward relationships keep being paid from the working group mint.
+        assert_eq!(ContentDirectoryWorkingGroup::mint(), mint_id);
+
+        // The curators and the lead cannot act in the content working group anymore.
+        assert!(matches!(
+            ContentWorkingGroup::curator_by_id(curator_id).stage,
+            CuratorRoleStage::Exited(_)
+        ));
+        assert!(matches!(
+            ContentWorkingGroup::lead_by_id(lead_id).stage,
+            LeadRoleState::Exited(_)
+        ));
+        assert_eq!(ContentWorkingGroup::current_lead_id(), None);
+        assert!(ContentWorkingGroup::curators_migrated());
+    });
+}
@@ -0,0 +1,53 @@
+use super::initial_test_ext;
+use crate::integration::forum::ForumWorkingGroupModeratorRegistry;
+use crate::{AccountId, Runtime};
+
+use forum::ForumModeratorRegistry;
+use frame_support::{StorageMap, StorageValue};
+use working_group::{Instance1, Worker};
+
+#[test]
+fn forum_moderator_registry_succeeds() {
+    initial_test_ext().execute_with(|| {
+        let lead_account: AccountId = [1; 32].into();
+        let worker_account: AccountId = [2; 32].into();
+        let other_account: AccountId = [3; 32].into();
+
+        assert!(!ForumWorkingGroupModeratorRegistry::is_forum_worker(
+            &worker_account
+        ));
+        assert!(!ForumWorkingGroupModeratorRegistry::is_forum_lead(
+            &lead_account
+        ));
+
+        let lead_worker_id = 1;
+        let worker_id = 7;
+
+        <working_group::WorkerById<Runtime, Instance1>>::insert(
+            lead_worker_id,
+            Worker::new(&0, &lead_account, &None, &None),
+        );
+        <working_group::WorkerById<Runtime, Instance1>>::insert(
+            worker_id,
+            Worker::new(&1, &worker_account, &None, &None),
+        );
+        <working_group::CurrentLead<Runtime, Instance1>>::put(lead_worker_id);
+
+        assert!(ForumWorkingGroupModeratorRegistry::is_forum_worker(
+            &worker_account
+        ));
+        assert!(ForumWorkingGroupModeratorRegistry::is_forum_worker(
+            &lead_account
+        ));
+        assert!(!ForumWorkingGroupModeratorRegistry::is_forum_worker(
+            &other_account
+        ));
+
+        assert!(ForumWorkingGroupModeratorRegistry::is_forum_lead(
+            &lead_account
+        ));
+        assert!(!ForumWorkingGroupModeratorRegistry::is_forum_lead(
+            &worker_account
+        ));
+    });
+}
@@ -2,7 +2,10 @@
 
 #![cfg(test)]
 
+mod content_working_group_integration;
+mod forum_integration;
 mod proposals_integration;
+mod reward_arrears_integration;
 mod storage_integration;
 use sp_runtime::BuildStorage;
 
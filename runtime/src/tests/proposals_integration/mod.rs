@@ -4,10 +4,13 @@
 
 mod working_group_proposals;
 
-use crate::{BlockNumber, ProposalCancellationFee, Runtime};
+use crate::integration::proposals::ExtrinsicProposalEncoder;
+use crate::{BlockNumber, ContentDirectoryWorkingGroupInstance, ProposalCancellationFee, Runtime};
 use codec::Encode;
-use governance::election_params::ElectionParameters;
+use governance::council::CouncilSeatVacated;
+use governance::election_params::{ElectionParameters, VotingMode};
 use membership;
+use proposals_codex::{ProposalDetails, ProposalEncoder, ProposalKind};
 use proposals_engine::{
     ActiveStake, ApprovedProposalStatus, BalanceOf, FinalizationData, Proposal,
     ProposalDecisionStatus, ProposalParameters, ProposalStatus, VoteKind, VotersParameters,
@@ -17,6 +20,7 @@ use proposals_engine::{
 use frame_support::dispatch::{DispatchError, DispatchResult};
 use frame_support::traits::{Currency, OnFinalize, OnInitialize};
 use frame_support::{StorageMap, StorageValue};
+use sp_runtime::traits::Hash;
 use sp_runtime::AccountId32;
 use system::RawOrigin;
 
@@ -32,6 +36,7 @@ pub type Council = governance::council::Module<Runtime>;
 pub type Election = governance::election::Module<Runtime>;
 pub type ProposalCodex = proposals_codex::Module<Runtime>;
 pub type Mint = minting::Module<Runtime>;
+pub type Constitution = constitution::Module<Runtime>;
 
 fn setup_members(count: u8) {
     let authority_account_id = <Runtime as system::Trait>::AccountId::default();
@@ -220,6 +225,7 @@ impl DummyProposalFixture {
             self.title,
             self.description,
             self.stake_balance,
+            None,
             self.proposal_code,
         );
         assert_eq!(proposal_id_result, result);
@@ -317,6 +323,7 @@ fn proposal_cancellation_with_slashes_with_balance_checks_succeeds() {
             title: b"title".to_vec(),
             description: b"description".to_vec(),
             voting_results: VotingResults::default(),
+            exactly_execute_at: None,
         };
 
         assert_eq!(proposal, expected_proposal);
@@ -412,6 +419,36 @@ fn proposal_reset_succeeds() {
     });
 }
 
+#[test]
+fn proposal_reset_on_council_seat_vacated_succeeds() {
+    initial_test_ext().execute_with(|| {
+        setup_members(4);
+        setup_council();
+
+        let dummy_proposal = DummyProposalFixture::default().with_voting_period(100);
+        let proposal_id = dummy_proposal.create_proposal_and_assert(Ok(1)).unwrap();
+
+        let mut vote_generator = VoteGenerator::new(proposal_id);
+        vote_generator.vote_and_assert_ok(VoteKind::Reject);
+        vote_generator.vote_and_assert_ok(VoteKind::Abstain);
+
+        let recalled_councilor: [u8; 32] = [1; 32];
+        <Runtime as governance::council::Trait>::CouncilSeatVacated::council_seat_vacated(
+            &recalled_councilor.into(),
+        );
+
+        // The votes of the recalled councilor are discarded along with the others.
+        assert_eq!(
+            ProposalsEngine::proposals(proposal_id).voting_results,
+            VotingResults::default()
+        );
+        assert_eq!(
+            ProposalsEngine::vote_by_proposal_by_voter(proposal_id, 1),
+            VoteKind::default()
+        );
+    });
+}
+
 fn elect_single_councilor() {
     let res = Election::set_election_parameters(
         RawOrigin::Root.into(),
@@ -424,6 +461,7 @@ fn elect_single_councilor() {
             new_term_duration: 2000000,
             min_council_stake: 0,
             min_voting_stake: 0,
+            voting_mode: VotingMode::SingleChoice,
         },
     );
     assert_eq!(res, Ok(()));
@@ -558,6 +596,7 @@ fn text_proposal_execution_succeeds() {
                 b"title".to_vec(),
                 b"body".to_vec(),
                 Some(<BalanceOf<Runtime>>::from(25000u32)),
+                None,
                 b"text".to_vec(),
             )
         })
@@ -568,28 +607,27 @@ fn text_proposal_execution_succeeds() {
 }
 
 #[test]
-fn set_lead_proposal_execution_succeeds() {
+fn set_lead_proposal_is_deprecated() {
     initial_test_ext().execute_with(|| {
         let member_id = 10;
         let account_id: [u8; 32] = [member_id; 32];
 
-        let codex_extrinsic_test_fixture = CodexProposalTestFixture::default_for_call(|| {
+        assert_eq!(
             ProposalCodex::create_set_lead_proposal(
                 RawOrigin::Signed(account_id.clone().into()).into(),
                 member_id as u64,
                 b"title".to_vec(),
                 b"body".to_vec(),
                 Some(<BalanceOf<Runtime>>::from(50000u32)),
+                None,
                 Some((member_id as u64, account_id.into())),
-            )
-        })
-        .with_member_id(member_id as u64);
-
-        assert!(content_working_group::Module::<Runtime>::ensure_lead_is_set().is_err());
-
-        codex_extrinsic_test_fixture.call_extrinsic_and_assert();
+            ),
+            Err(proposals_codex::Error::<Runtime>::SetLeadProposalIsDeprecated.into())
+        );
 
-        assert!(content_working_group::Module::<Runtime>::ensure_lead_is_set().is_ok());
+        assert!(
+            ExtrinsicProposalEncoder::encode_proposal(ProposalDetails::SetLead(None)).is_empty()
+        );
     });
 }
 
@@ -611,6 +649,7 @@ fn spending_proposal_execution_succeeds() {
                 b"title".to_vec(),
                 b"body".to_vec(),
                 Some(<BalanceOf<Runtime>>::from(25_000_u32)),
+                None,
                 new_balance,
                 target_account_id.clone().into(),
             )
@@ -634,8 +673,8 @@ fn set_content_working_group_mint_capacity_execution_succeeds() {
         let new_balance = <BalanceOf<Runtime>>::from(55u32);
 
         let mint_id =
-            Mint::add_mint(0, None).expect("Failed to create a mint for the content working group");
-        <content_working_group::Mint<Runtime>>::put(mint_id);
+            Mint::add_mint(0, None).expect("Failed to create a mint for the working group");
+        <working_group::Mint<Runtime, ContentDirectoryWorkingGroupInstance>>::put(mint_id);
 
         assert_eq!(Mint::get_mint_capacity(mint_id), Ok(0));
 
@@ -646,6 +685,7 @@ fn set_content_working_group_mint_capacity_execution_succeeds() {
                 b"title".to_vec(),
                 b"body".to_vec(),
                 Some(<BalanceOf<Runtime>>::from(50000u32)),
+                None,
                 new_balance,
             )
         });
@@ -656,6 +696,42 @@ fn set_content_working_group_mint_capacity_execution_succeeds() {
     });
 }
 
+#[test]
+fn set_mint_capacity_adjustment_proposal_execution_succeeds() {
+    initial_test_ext().execute_with(|| {
+        let member_id = 1;
+        let account_id: [u8; 32] = [member_id; 32];
+        let adjust_on_interval = minting::AdjustOnInterval {
+            block_interval: 100,
+            adjustment_type: minting::AdjustCapacityBy::Adding(500),
+        };
+
+        let mint_id =
+            Mint::add_mint(0, None).expect("Failed to create a mint for the working group");
+        <working_group::Mint<Runtime, ContentDirectoryWorkingGroupInstance>>::put(mint_id);
+
+        let codex_extrinsic_test_fixture = CodexProposalTestFixture::default_for_call(|| {
+            ProposalCodex::create_set_mint_capacity_adjustment_proposal(
+                RawOrigin::Signed(account_id.clone().into()).into(),
+                member_id as u64,
+                b"title".to_vec(),
+                b"body".to_vec(),
+                Some(<BalanceOf<Runtime>>::from(50000u32)),
+                None,
+                proposals_codex::GovernedMint::ContentWorkingGroup,
+                Some(minting::Adjustment::Interval(adjust_on_interval)),
+            )
+        });
+
+        codex_extrinsic_test_fixture.call_extrinsic_and_assert();
+
+        let next_adjustment = Mint::get_mint_next_adjustment(mint_id)
+            .unwrap()
+            .expect("Mint adjustment is set");
+        assert_eq!(next_adjustment.adjustment, adjust_on_interval);
+    });
+}
+
 #[test]
 fn set_election_parameters_proposal_execution_succeeds() {
     initial_test_ext().execute_with(|| {
@@ -671,6 +747,7 @@ fn set_election_parameters_proposal_execution_succeeds() {
             new_term_duration: 14400,
             min_council_stake: 1,
             min_voting_stake: 1,
+            voting_mode: VotingMode::SingleChoice,
         };
         assert_eq!(Election::announcing_period(), 0);
 
@@ -681,6 +758,7 @@ fn set_election_parameters_proposal_execution_succeeds() {
                 b"title".to_vec(),
                 b"body".to_vec(),
                 Some(<BalanceOf<Runtime>>::from(200_000_u32)),
+                None,
                 election_parameters,
             )
         });
@@ -706,6 +784,7 @@ fn set_validator_count_proposal_execution_succeeds() {
                 b"title".to_vec(),
                 b"body".to_vec(),
                 Some(<BalanceOf<Runtime>>::from(100_000_u32)),
+                None,
                 new_validator_count,
             )
         });
@@ -714,3 +793,139 @@ fn set_validator_count_proposal_execution_succeeds() {
         assert_eq!(<pallet_staking::ValidatorCount>::get(), new_validator_count);
     });
 }
+
+#[test]
+fn set_proposal_parameters_proposal_execution_succeeds() {
+    initial_test_ext().execute_with(|| {
+        let member_id = 1;
+        let account_id: [u8; 32] = [member_id; 32];
+
+        let proposal_parameters = ProposalParameters {
+            voting_period: 14400,
+            grace_period: 100,
+            approval_quorum_percentage: 70,
+            approval_threshold_percentage: 70,
+            slashing_quorum_percentage: 50,
+            slashing_threshold_percentage: 50,
+            required_stake: Some(30000),
+        };
+
+        let codex_extrinsic_test_fixture = CodexProposalTestFixture::default_for_call(|| {
+            ProposalCodex::create_set_proposal_parameters_proposal(
+                RawOrigin::Signed(account_id.clone().into()).into(),
+                member_id as u64,
+                b"title".to_vec(),
+                b"body".to_vec(),
+                Some(<BalanceOf<Runtime>>::from(200_000_u32)),
+                None,
+                ProposalKind::Text,
+                proposal_parameters,
+            )
+        });
+        codex_extrinsic_test_fixture.call_extrinsic_and_assert();
+
+        assert_eq!(
+            ProposalCodex::proposal_parameters(ProposalKind::Text),
+            proposal_parameters
+        );
+    });
+}
+
+#[test]
+fn amend_constitution_proposal_execution_succeeds() {
+    initial_test_ext().execute_with(|| {
+        let member_id = 1;
+        let account_id: [u8; 32] = [member_id; 32];
+
+        let constitution_text = b"constitution".to_vec();
+        let amendment = constitution::ConstitutionAmendment {
+            content_hash: <Runtime as system::Trait>::Hashing::hash(&constitution_text),
+            content: constitution::ConstitutionContent::Text(constitution_text),
+        };
+
+        let codex_extrinsic_test_fixture = CodexProposalTestFixture::default_for_call(|| {
+            ProposalCodex::create_amend_constitution_proposal(
+                RawOrigin::Signed(account_id.clone().into()).into(),
+                member_id as u64,
+                b"title".to_vec(),
+                b"body".to_vec(),
+                Some(<BalanceOf<Runtime>>::from(200_000_u32)),
+                None,
+                amendment.clone(),
+            )
+        });
+        codex_extrinsic_test_fixture.call_extrinsic_and_assert();
+
+        let constitution = Constitution::constitution().unwrap();
+        assert_eq!(Constitution::current_version(), 1);
+        assert_eq!(constitution.content_hash, amendment.content_hash);
+        assert_eq!(constitution.content, amendment.content);
+    });
+}
+
+#[test]
+fn batch_proposal_execution_succeeds() {
+    initial_test_ext().execute_with(|| {
+        let member_id = 1;
+        let account_id: [u8; 32] = [member_id; 32];
+        let new_balance = <BalanceOf<Runtime>>::from(55u32);
+        let new_validator_count = 8;
+
+        let mint_id =
+            Mint::add_mint(0, None).expect("Failed to create a mint for the working group");
+        <working_group::Mint<Runtime, ContentDirectoryWorkingGroupInstance>>::put(mint_id);
+
+        let codex_extrinsic_test_fixture = CodexProposalTestFixture::default_for_call(|| {
+            ProposalCodex::create_batch_proposal(
+                RawOrigin::Signed(account_id.clone().into()).into(),
+                member_id as u64,
+                b"title".to_vec(),
+                b"body".to_vec(),
+                Some(<BalanceOf<Runtime>>::from(100_000_u32)),
+                None,
+                vec![
+                    ProposalDetails::SetContentWorkingGroupMintCapacity(new_balance),
+                    ProposalDetails::SetValidatorCount(new_validator_count),
+                ],
+            )
+        });
+        codex_extrinsic_test_fixture.call_extrinsic_and_assert();
+
+        assert_eq!(Mint::get_mint_capacity(mint_id), Ok(new_balance));
+        assert_eq!(<pallet_staking::ValidatorCount>::get(), new_validator_count);
+    });
+}
+
+#[test]
+fn batch_proposal_execution_reverts_all_changes_on_failure() {
+    initial_test_ext().execute_with(|| {
+        let member_id = 1;
+        let account_id: [u8; 32] = [member_id; 32];
+        let new_balance = <BalanceOf<Runtime>>::from(55u32);
+        let target_account_id: AccountId32 = [12; 32].into();
+
+        let mint_id =
+            Mint::add_mint(0, None).expect("Failed to create a mint for the working group");
+        <working_group::Mint<Runtime, ContentDirectoryWorkingGroupInstance>>::put(mint_id);
+
+        // The council mint has no capacity: the spending proposal execution fails.
+        let codex_extrinsic_test_fixture = CodexProposalTestFixture::default_for_call(|| {
+            ProposalCodex::create_batch_proposal(
+                RawOrigin::Signed(account_id.clone().into()).into(),
+                member_id as u64,
+                b"title".to_vec(),
+                b"body".to_vec(),
+                Some(<BalanceOf<Runtime>>::from(50000u32)),
+                None,
+                vec![
+                    ProposalDetails::SetContentWorkingGroupMintCapacity(new_balance),
+                    ProposalDetails::Spending(100, target_account_id.clone()),
+                ],
+            )
+        });
+        codex_extrinsic_test_fixture.call_extrinsic_and_assert();
+
+        assert_eq!(Mint::get_mint_capacity(mint_id), Ok(0));
+        assert_eq!(Balances::free_balance(target_account_id), 0);
+    });
+}
@@ -0,0 +1,101 @@
+use super::initial_test_ext;
+use crate::integration::recurring_rewards::{
+    councilor_owed_reward, curator_owed_reward, worker_owed_reward,
+};
+use crate::{AccountId, Balances, Council, RecurringRewards, Runtime};
+
+use common::working_group::WorkingGroup;
+use frame_support::traits::OnFinalize;
+use frame_support::StorageMap;
+use working_group::{Instance2, Worker};
+
+// Adds the reward relationship funded by the council mint, paying once at the block.
+fn add_council_mint_reward_relationship(account: &AccountId, amount: u128, at: u32) -> u64 {
+    let recipient_id = RecurringRewards::add_recipient();
+
+    RecurringRewards::add_reward_relationship(
+        Council::council_mint(),
+        recipient_id,
+        account.clone(),
+        amount,
+        at,
+        None,
+    )
+    .unwrap()
+}
+
+#[test]
+fn failed_councilor_payouts_are_owed_until_mint_is_topped_up() {
+    initial_test_ext().execute_with(|| {
+        let councilor_account: AccountId = [1; 32].into();
+        let amount = 1000;
+        let payout_at = 10;
+
+        let relationship_id =
+            add_council_mint_reward_relationship(&councilor_account, amount, payout_at);
+        <governance::council::RewardRelationships<Runtime>>::insert(
+            &councilor_account,
+            relationship_id,
+        );
+
+        // The council mint is empty.
+        <RecurringRewards as OnFinalize<u32>>::on_finalize(payout_at);
+        assert_eq!(councilor_owed_reward(councilor_account.clone()), amount);
+        assert_eq!(Balances::free_balance(&councilor_account), 0);
+
+        assert!(Council::set_council_mint_capacity(system::RawOrigin::Root.into(), amount).is_ok());
+        assert_eq!(councilor_owed_reward(councilor_account.clone()), 0);
+        assert_eq!(Balances::free_balance(&councilor_account), amount);
+    });
+}
+
+#[test]
+fn failed_worker_payouts_are_owed() {
+    initial_test_ext().execute_with(|| {
+        let worker_account: AccountId = [2; 32].into();
+        let worker_id = 3;
+        let amount = 500;
+        let payout_at = 10;
+
+        let relationship_id =
+            add_council_mint_reward_relationship(&worker_account, amount, payout_at);
+        <working_group::WorkerById<Runtime, Instance2>>::insert(
+            worker_id,
+            Worker::new(&0, &worker_account, &Some(relationship_id), &None),
+        );
+
+        <RecurringRewards as OnFinalize<u32>>::on_finalize(payout_at);
+        assert_eq!(worker_owed_reward(WorkingGroup::Storage, worker_id), amount);
+        assert_eq!(worker_owed_reward(WorkingGroup::Forum, worker_id), 0);
+    });
+}
+
+#[test]
+fn failed_curator_payouts_are_owed_after_departure() {
+    initial_test_ext().execute_with(|| {
+        let curator_account: AccountId = [3; 32].into();
+        let curator_id = 4;
+        let amount = 300;
+        let payout_at = 10;
+
+        let relationship_id =
+            add_council_mint_reward_relationship(&curator_account, amount, payout_at);
+        <content_working_group::CuratorById<Runtime>>::insert(
+            curator_id,
+            content_working_group::Curator {
+                role_account: curator_account,
+                reward_relationship: Some(relationship_id),
+                ..Default::default()
+            },
+        );
+
+        <RecurringRewards as OnFinalize<u32>>::on_finalize(payout_at);
+        assert_eq!(curator_owed_reward(curator_id), amount);
+
+        // The arrears of the departed curator are queried by the reward relationship.
+        <content_working_group::CuratorById<Runtime>>::remove(curator_id);
+        RecurringRewards::remove_reward_relationship(relationship_id);
+        assert_eq!(curator_owed_reward(curator_id), 0);
+        assert_eq!(RecurringRewards::owed_reward(relationship_id), amount);
+    });
+}
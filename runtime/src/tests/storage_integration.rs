@@ -41,5 +41,9 @@ fn storage_provider_helper_succeeds() {
 		let worker_ids = vec![worker_id1, worker_id2, worker_id3];
 		let random_provider_id = <StorageProviderHelper as storage::data_directory::StorageProviderHelper<Runtime>>::get_random_storage_provider().unwrap();
 		assert!(worker_ids.contains(&random_provider_id));
+
+		let mut live_provider_ids = <StorageProviderHelper as storage::data_directory::StorageProviderHelper<Runtime>>::get_live_storage_providers();
+		live_provider_ids.sort();
+		assert_eq!(live_provider_ids, worker_ids);
 	});
 }
@@ -0,0 +1,48 @@
+#!/usr/bin/env bash
+
+# The script runs the benchmarks of the Joystream pallets and saves the results per pallet.
+# The runtime uses the flat module weights until the WeightInfo implementations are generated
+# from the saved results.
+
+set -e
+
+SCRIPT_PATH="$(dirname "${BASH_SOURCE[0]}")"
+cd $SCRIPT_PATH/..
+
+export WASM_BUILD_TOOLCHAIN=nightly-2020-05-23
+
+OUTPUT_DIR=${OUTPUT_DIR:=benchmark-results}
+STEPS=${STEPS:=50}
+REPEAT=${REPEAT:=20}
+
+PALLETS=(
+    membership
+    forum
+    working_group
+    content_working_group
+    versioned_store_permissions
+    data_directory
+    data_object_type_registry
+    data_object_storage_registry
+    proposals_engine
+    proposals_discussion
+    proposals_codex
+    constitution
+    minting
+)
+
+cargo build --release --features runtime-benchmarks
+
+mkdir -p $OUTPUT_DIR
+
+for PALLET in "${PALLETS[@]}"
+do
+    echo "Benchmarking ${PALLET}"
+    ./target/release/joystream-node benchmark \
+        --chain=dev \
+        --execution=wasm \
+        --pallet=${PALLET} \
+        --extrinsic='*' \
+        --steps=${STEPS} \
+        --repeat=${REPEAT} > ${OUTPUT_DIR}/${PALLET}.txt
+done
@@ -31,7 +31,7 @@ class AssetsApi {
    */
   async createDataObject(accountId, memberId, contentId, doTypeId, size, ipfsCid) {
     contentId = parseContentId(contentId)
-    const tx = this.base.api.tx.dataDirectory.addContent(memberId, contentId, doTypeId, size, ipfsCid)
+    const tx = this.base.api.tx.dataDirectory.addContent(memberId, null, contentId, doTypeId, size, ipfsCid, null)
     await this.base.signAndSend(accountId, tx)
 
     // If the data object constructed properly, we should now be able to return
@@ -78,11 +78,12 @@ class AssetsApi {
   }
 
   /*
-   * Sets the data object liaison judgement to Accepted
+   * Sets the data object liaison judgement to Accepted, confirming or committing the Merkle root
+   * of the content chunks
    */
-  async acceptContent(providerAccoundId, storageProviderId, contentId) {
+  async acceptContent(providerAccoundId, storageProviderId, contentId, merkleRoot = null) {
     contentId = parseContentId(contentId)
-    const tx = this.base.api.tx.dataDirectory.acceptContent(storageProviderId, contentId)
+    const tx = this.base.api.tx.dataDirectory.acceptContent(storageProviderId, contentId, merkleRoot)
     return this.base.signAndSend(providerAccoundId, tx)
   }
 
@@ -144,7 +145,8 @@ class AssetsApi {
    * Returns array of know content ids
    */
   async getKnownContentIds() {
-    return this.base.api.query.dataDirectory.knownContentIds()
+    const entries = await this.base.api.query.dataDirectory.knownContentIdIndex.entries()
+    return entries.map(([storageKey]) => storageKey.args[0])
   }
 }
 
@@ -75,12 +75,30 @@ export interface AdjustCapacityBy extends Enum {
   readonly asReducing: u128;
 }
 
+/** @name Adjustment */
+export interface Adjustment extends Enum {
+  readonly isInterval: boolean;
+  readonly asInterval: AdjustOnInterval;
+  readonly isIntervalAfterFirstAdjustmentAbsolute: boolean;
+  readonly asIntervalAfterFirstAdjustmentAbsolute: ITuple<[AdjustOnInterval, u32]>;
+  readonly isIntervalAfterFirstAdjustmentRelative: boolean;
+  readonly asIntervalAfterFirstAdjustmentRelative: ITuple<[AdjustOnInterval, u32]>;
+}
+
 /** @name AdjustOnInterval */
 export interface AdjustOnInterval extends Struct {
   readonly block_interval: u32;
   readonly adjustment_type: AdjustCapacityBy;
 }
 
+/** @name ApplicantResult */
+export interface ApplicantResult extends Struct {
+  readonly applicant: GenericAccountId;
+  readonly stake: u128;
+  readonly revealed_votes: u32;
+  readonly revealed_votes_stake: u128;
+}
+
 /** @name Application */
 export interface Application extends Struct {
   readonly opening_id: u64;
@@ -101,6 +119,7 @@ export interface ApplicationDeactivationCause extends Enum {
   readonly isOpeningCancelled: boolean;
   readonly isReviewPeriodExpired: boolean;
   readonly isOpeningFilled: boolean;
+  readonly isNotShortlisted: boolean;
 }
 
 /** @name ApplicationId */
@@ -125,6 +144,14 @@ export interface ApplicationRationingPolicy extends Struct {
   readonly max_active_applicants: u32;
 }
 
+/** @name ApplicationScore */
+export interface ApplicationScore extends Struct {
+  readonly round: u32;
+  readonly reviewer: AccountId;
+  readonly score: u32;
+  readonly note: Text;
+}
+
 /** @name ApplicationStage */
 export interface ApplicationStage extends Enum {
   readonly isActive: boolean;
@@ -257,6 +284,30 @@ export interface ClassSchema extends Struct {
   readonly properties: Vec<u16>;
 }
 
+/** @name Constitution */
+export interface Constitution extends Struct {
+  readonly content_hash: U8aFixed;
+  readonly content: ConstitutionContent;
+  readonly amended_at: u32;
+}
+
+/** @name ConstitutionAmendment */
+export interface ConstitutionAmendment extends Struct {
+  readonly content_hash: U8aFixed;
+  readonly content: ConstitutionContent;
+}
+
+/** @name ConstitutionContent */
+export interface ConstitutionContent extends Enum {
+  readonly isText: boolean;
+  readonly asText: Bytes;
+  readonly isDataObject: boolean;
+  readonly asDataObject: ContentId;
+}
+
+/** @name ConstitutionVersion */
+export interface ConstitutionVersion extends u32 {}
+
 /** @name ContentId */
 export interface ContentId extends U8aFixed {}
 
@@ -381,6 +432,8 @@ export interface DataObjectStorageRelationshipId extends u64 {}
 export interface DataObjectType extends Struct {
   readonly description: Text;
   readonly active: bool;
+  readonly replication_factor: u32;
+  readonly max_object_size: Option<u64>;
 }
 
 /** @name DataObjectTypeId */
@@ -421,6 +474,27 @@ export interface ElectionParameters extends Struct {
   readonly new_term_duration: u32;
   readonly min_council_stake: u128;
   readonly min_voting_stake: u128;
+  readonly voting_mode: VotingMode;
+}
+
+/** @name ElectionParticipation */
+export interface ElectionParticipation extends Struct {
+  readonly round: u32;
+  readonly applicant_stake: Option<u128>;
+  readonly elected: bool;
+  readonly voting_stake: u128;
+  readonly revealed_voting_stake: u128;
+}
+
+/** @name ElectionResult */
+export interface ElectionResult extends Struct {
+  readonly round: u32;
+  readonly ended_at: u32;
+  readonly voting_mode: VotingMode;
+  readonly force_stopped: bool;
+  readonly applicants: Vec<ApplicantResult>;
+  readonly unrevealed_commitments: u32;
+  readonly elected: Seats;
 }
 
 /** @name ElectionStage */
@@ -490,6 +564,14 @@ export interface Finalized extends Struct {
   readonly stakeDataAfterUnstakingError: Option<ActiveStake>;
 }
 
+/** @name GovernedMint */
+export interface GovernedMint extends Enum {
+  readonly isCouncil: boolean;
+  readonly isContentWorkingGroup: boolean;
+  readonly isWorkingGroup: boolean;
+  readonly asWorkingGroup: WorkingGroup;
+}
+
 /** @name HiringApplicationId */
 export interface HiringApplicationId extends u64 {}
 
@@ -572,6 +654,12 @@ export interface MintBalanceOf extends Balance {}
 /** @name MintId */
 export interface MintId extends u64 {}
 
+/** @name MintSpending */
+export interface MintSpending extends Struct {
+  readonly rewards: u128;
+  readonly spending_proposals: u128;
+}
+
 /** @name ModerationAction */
 export interface ModerationAction extends Struct {
   readonly moderated_at: BlockAndTime;
@@ -608,6 +696,12 @@ export interface OpeningDeactivationCause extends Enum {
 /** @name OpeningId */
 export interface OpeningId extends u64 {}
 
+/** @name OpeningLimits */
+export interface OpeningLimits extends Struct {
+  readonly application_deadline: Option<u32>;
+  readonly max_hires: Option<u32>;
+}
+
 /** @name OpeningOf */
 export interface OpeningOf extends Struct {
   readonly hiring_opening_id: u64;
@@ -630,6 +724,15 @@ export interface OpeningPolicyCommitment extends Struct {
   readonly terminate_curator_role_stake_unstaking_period: Option<u32>;
   readonly exit_curator_role_application_stake_unstaking_period: Option<u32>;
   readonly exit_curator_role_stake_unstaking_period: Option<u32>;
+  readonly application_deadline: Option<u32>;
+  readonly max_hires: Option<u32>;
+}
+
+/** @name OpeningReview */
+export interface OpeningReview extends Struct {
+  readonly review_rounds: u32;
+  readonly current_round: u32;
+  readonly reviewers: BTreeSet<AccountId>;
 }
 
 /** @name OpeningStage */
@@ -714,6 +817,14 @@ export interface Post extends Struct {
 /** @name PostId */
 export interface PostId extends u64 {}
 
+/** @name PostReaction */
+export interface PostReaction extends Enum {
+  readonly isLike: boolean;
+  readonly isDislike: boolean;
+  readonly isLaugh: boolean;
+  readonly isInsightful: boolean;
+}
+
 /** @name PostTextChange */
 export interface PostTextChange extends Struct {
   readonly expired_at: BlockAndTime;
@@ -868,6 +979,14 @@ export interface ProposalDetails extends Enum {
   readonly asSetWorkingGroupLeaderReward: ITuple<[WorkerId, Balance, WorkingGroup]>;
   readonly isTerminateWorkingGroupLeaderRole: boolean;
   readonly asTerminateWorkingGroupLeaderRole: TerminateRoleParameters;
+  readonly isSetMintCapacityAdjustment: boolean;
+  readonly asSetMintCapacityAdjustment: ITuple<[GovernedMint, Option<Adjustment>]>;
+  readonly isBatch: boolean;
+  readonly asBatch: Vec<ProposalDetails>;
+  readonly isSetProposalParameters: boolean;
+  readonly asSetProposalParameters: ITuple<[ProposalKind, ProposalParameters]>;
+  readonly isAmendConstitution: boolean;
+  readonly asAmendConstitution: ConstitutionAmendment;
 }
 
 /** @name ProposalDetailsOf */
@@ -906,11 +1025,41 @@ export interface ProposalDetailsOf extends Enum {
   readonly asSetWorkingGroupLeaderReward: ITuple<[WorkerId, Balance, WorkingGroup]>;
   readonly isTerminateWorkingGroupLeaderRole: boolean;
   readonly asTerminateWorkingGroupLeaderRole: TerminateRoleParameters;
+  readonly isSetMintCapacityAdjustment: boolean;
+  readonly asSetMintCapacityAdjustment: ITuple<[GovernedMint, Option<Adjustment>]>;
+  readonly isBatch: boolean;
+  readonly asBatch: Vec<ProposalDetails>;
+  readonly isSetProposalParameters: boolean;
+  readonly asSetProposalParameters: ITuple<[ProposalKind, ProposalParameters]>;
+  readonly isAmendConstitution: boolean;
+  readonly asAmendConstitution: ConstitutionAmendment;
 }
 
 /** @name ProposalId */
 export interface ProposalId extends u32 {}
 
+/** @name ProposalKind */
+export interface ProposalKind extends Enum {
+  readonly isText: boolean;
+  readonly isRuntimeUpgrade: boolean;
+  readonly isSetElectionParameters: boolean;
+  readonly isSpending: boolean;
+  readonly isSetLead: boolean;
+  readonly isSetContentWorkingGroupMintCapacity: boolean;
+  readonly isSetValidatorCount: boolean;
+  readonly isAddWorkingGroupLeaderOpening: boolean;
+  readonly isBeginReviewWorkingGroupLeaderApplications: boolean;
+  readonly isFillWorkingGroupLeaderOpening: boolean;
+  readonly isSetWorkingGroupMintCapacity: boolean;
+  readonly isDecreaseWorkingGroupLeaderStake: boolean;
+  readonly isSlashWorkingGroupLeaderStake: boolean;
+  readonly isSetWorkingGroupLeaderReward: boolean;
+  readonly isTerminateWorkingGroupLeaderRole: boolean;
+  readonly isSetMintCapacityAdjustment: boolean;
+  readonly isSetProposalParameters: boolean;
+  readonly isAmendConstitution: boolean;
+}
+
 /** @name ProposalOf */
 export interface ProposalOf extends Struct {
   readonly parameters: ProposalParameters;
@@ -920,6 +1069,7 @@ export interface ProposalOf extends Struct {
   readonly createdAt: u32;
   readonly status: ProposalStatus;
   readonly votingResults: VotingResults;
+  readonly exactlyExecuteAt: Option<u32>;
 }
 
 /** @name ProposalParameters */
@@ -944,6 +1094,13 @@ export interface ProposalStatus extends Enum {
 /** @name RationaleText */
 export interface RationaleText extends Bytes {}
 
+/** @name Recall */
+export interface Recall extends Struct {
+  readonly initiator: GenericAccountId;
+  readonly backers: Backers;
+  readonly ends_at: u32;
+}
+
 /** @name Recipient */
 export interface Recipient extends Struct {
   readonly total_reward_received: u128;
@@ -978,6 +1135,13 @@ export interface ReviewPeriod extends Struct {
   readonly started_review_period_at_block: u32;
 }
 
+/** @name RewardArrears */
+export interface RewardArrears extends Struct {
+  readonly mint_id: u64;
+  readonly account: GenericAccountId;
+  readonly amount: u128;
+}
+
 /** @name RewardPolicy */
 export interface RewardPolicy extends Struct {
   readonly amount_per_payout: u128;
@@ -1069,6 +1233,12 @@ export interface SlashingTerms extends Enum {
   readonly asSlashable: SlashableTerms;
 }
 
+/** @name SpendingCategory */
+export interface SpendingCategory extends Enum {
+  readonly isRewards: boolean;
+  readonly isSpendingProposals: boolean;
+}
+
 /** @name Stake */
 export interface Stake extends Struct {
   readonly created: u32;
@@ -1114,9 +1284,32 @@ export interface StakingStatus extends Enum {
   readonly asStaked: Staked;
 }
 
+/** @name StorageChallenge */
+export interface StorageChallenge extends Struct {
+  readonly content_id: ContentId;
+  readonly storage_provider_id: StorageProviderId;
+  readonly chunk_index: u64;
+  readonly deadline: u32;
+}
+
+/** @name StorageChallengeId */
+export interface StorageChallengeId extends u64 {}
+
 /** @name StorageProviderId */
 export interface StorageProviderId extends u64 {}
 
+/** @name StorageQuota */
+export interface StorageQuota extends Struct {
+  readonly size_limit: u64;
+  readonly objects_limit: u64;
+}
+
+/** @name StorageUsage */
+export interface StorageUsage extends Struct {
+  readonly size: u64;
+  readonly objects: u64;
+}
+
 /** @name SubscriptionId */
 export interface SubscriptionId extends u64 {}
 
@@ -1186,6 +1379,12 @@ export interface VoteKind extends Enum {
   readonly isAbstain: boolean;
 }
 
+/** @name VotingMode */
+export interface VotingMode extends Enum {
+  readonly isSingleChoice: boolean;
+  readonly isRankedChoice: boolean;
+}
+
 /** @name VotingResults */
 export interface VotingResults extends Struct {
   readonly abstensions: u32;
@@ -1213,6 +1412,9 @@ export interface WorkerOf extends Struct {
 /** @name WorkingGroup */
 export interface WorkingGroup extends Enum {
   readonly isStorage: boolean;
+  readonly isForum: boolean;
+  readonly isContent: boolean;
+  readonly isMembership: boolean;
 }
 
 /** @name WorkingGroupOpeningPolicyCommitment */
@@ -1229,6 +1431,10 @@ export interface WorkingGroupOpeningPolicyCommitment extends Struct {
   readonly terminate_role_stake_unstaking_period: Option<u32>;
   readonly exit_role_application_stake_unstaking_period: Option<u32>;
   readonly exit_role_stake_unstaking_period: Option<u32>;
+  readonly auto_fill_on_review_period_expiry: Option<u32>;
+  readonly application_deadline: Option<u32>;
+  readonly max_hires: Option<u32>;
+  readonly auto_fill_reward_policy: Option<RewardPolicy>;
 }
 
 /** @name WorkingGroupUnstaker */
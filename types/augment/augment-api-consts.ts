@@ -88,6 +88,11 @@ declare module '@polkadot/metadata/Decorated/consts/types' {
        * Exports const -  max simultaneous active proposals number.
        **/
       maxActiveProposalLimit: AugmentedConst<u32>;
+      /**
+       * Exports const -  max allowed delay of the exact execution block after the end of the
+       * voting period.
+       **/
+      maxExactExecutionDelay: AugmentedConst<BlockNumber>;
       /**
        * Exports const -  the fee is applied when the proposal gets rejected. A fee would be slashed (burned).
        **/
@@ -347,13 +347,15 @@ declare module '@polkadot/api/types/submittable' {
        **/
       editPostText: AugmentedSubmittable<(postId: PostId | AnyNumber | Uint8Array, newText: Bytes | string | Uint8Array) => SubmittableExtrinsic<ApiType>>;
       /**
-       * Moderate post
+       * Moderate post. The `moderator_id` is the forum working group worker id of the
+       * category moderator, `None` for the forum sudo.
        **/
-      moderatePost: AugmentedSubmittable<(postId: PostId | AnyNumber | Uint8Array, rationale: Bytes | string | Uint8Array) => SubmittableExtrinsic<ApiType>>;
+      moderatePost: AugmentedSubmittable<(moderatorId: Option<WorkerId> | null | object | string | Uint8Array, postId: PostId | AnyNumber | Uint8Array, rationale: Bytes | string | Uint8Array) => SubmittableExtrinsic<ApiType>>;
       /**
-       * Moderate thread
+       * Moderate thread. The `moderator_id` is the forum working group worker id of the
+       * category moderator, `None` for the forum sudo.
        **/
-      moderateThread: AugmentedSubmittable<(threadId: ThreadId | AnyNumber | Uint8Array, rationale: Bytes | string | Uint8Array) => SubmittableExtrinsic<ApiType>>;
+      moderateThread: AugmentedSubmittable<(moderatorId: Option<WorkerId> | null | object | string | Uint8Array, threadId: ThreadId | AnyNumber | Uint8Array, rationale: Bytes | string | Uint8Array) => SubmittableExtrinsic<ApiType>>;
       /**
        * Set forum sudo.
        **/
@@ -0,0 +1,46 @@
+import { u32, Bytes } from '@polkadot/types'
+import { U8aFixed } from '@polkadot/types/codec'
+import { BlockNumber, Hash } from '@polkadot/types/interfaces'
+import { JoyEnum, JoyStructDecorated } from './common'
+import { ContentId } from './media'
+import { RegistryTypes } from '@polkadot/types/types'
+
+export class ConstitutionVersion extends u32 {}
+
+export class ConstitutionContent extends JoyEnum({
+  Text: Bytes,
+  DataObject: ContentId,
+} as const) {}
+
+export type IConstitutionAmendment = {
+  content_hash: Hash
+  content: ConstitutionContent
+}
+export class ConstitutionAmendment
+  extends JoyStructDecorated({
+    content_hash: U8aFixed, // Hash
+    content: ConstitutionContent,
+  })
+  implements IConstitutionAmendment {}
+
+export type IConstitution = {
+  content_hash: Hash
+  content: ConstitutionContent
+  amended_at: BlockNumber
+}
+export class Constitution
+  extends JoyStructDecorated({
+    content_hash: U8aFixed, // Hash
+    content: ConstitutionContent,
+    amended_at: u32,
+  })
+  implements IConstitution {}
+
+export const constitutionTypes: RegistryTypes = {
+  ConstitutionVersion,
+  ConstitutionContent,
+  ConstitutionAmendment,
+  Constitution,
+}
+
+export default constitutionTypes
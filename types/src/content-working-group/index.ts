@@ -217,6 +217,8 @@ export type IOpeningPolicyCommitment = {
   terminate_curator_role_stake_unstaking_period: Option<BlockNumber>
   exit_curator_role_application_stake_unstaking_period: Option<BlockNumber>
   exit_curator_role_stake_unstaking_period: Option<BlockNumber>
+  application_deadline: Option<BlockNumber>
+  max_hires: Option<u32>
 }
 export class OpeningPolicyCommitment
   extends JoyStructDecorated({
@@ -232,6 +234,8 @@ export class OpeningPolicyCommitment
     terminate_curator_role_stake_unstaking_period: Option.with(u32),
     exit_curator_role_application_stake_unstaking_period: Option.with(u32),
     exit_curator_role_stake_unstaking_period: Option.with(u32),
+    application_deadline: Option.with(u32), // BlockNumber
+    max_hires: Option.with(u32),
   })
   implements IOpeningPolicyCommitment {}
 
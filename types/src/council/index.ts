@@ -1,7 +1,7 @@
 import { Option, U8aFixed } from '@polkadot/types/codec'
 import { Vec } from '@polkadot/types'
 import { BlockNumber, Balance, Hash } from '@polkadot/types/interfaces'
-import { u32, u128 } from '@polkadot/types/primitive'
+import { Null, bool, u32, u128 } from '@polkadot/types/primitive'
 import { RegistryTypes } from '@polkadot/types/types'
 import { JoyStructDecorated } from '../JoyStruct'
 import { JoyEnum } from '../JoyEnum'
@@ -70,6 +70,11 @@ export class ElectionStage extends JoyEnum({
 
 export type AnyElectionStage = Announcing | Voting | Revealing
 
+export class VotingMode extends JoyEnum({
+  SingleChoice: Null,
+  RankedChoice: Null,
+} as const) {}
+
 export type IElectionParameters = {
   announcing_period: BlockNumber
   voting_period: BlockNumber
@@ -79,6 +84,7 @@ export type IElectionParameters = {
   new_term_duration: BlockNumber
   min_council_stake: Balance
   min_voting_stake: Balance
+  voting_mode: VotingMode
 }
 
 export class ElectionParameters
@@ -91,19 +97,95 @@ export class ElectionParameters
     new_term_duration: u32, // BlockNumber
     min_council_stake: u128, // Balance
     min_voting_stake: u128, // Balance
+    voting_mode: VotingMode,
   })
   implements IElectionParameters {}
 
+export type IRecall = {
+  initiator: AccountId
+  backers: Backers
+  ends_at: BlockNumber
+}
+
+export class Recall
+  extends JoyStructDecorated({
+    initiator: AccountId,
+    backers: Backers,
+    ends_at: u32, // BlockNumber
+  })
+  implements IRecall {}
+
+export type IApplicantResult = {
+  applicant: AccountId
+  stake: Balance
+  revealed_votes: u32
+  revealed_votes_stake: Balance
+}
+
+export class ApplicantResult
+  extends JoyStructDecorated({
+    applicant: AccountId,
+    stake: u128, // Balance
+    revealed_votes: u32,
+    revealed_votes_stake: u128, // Balance
+  })
+  implements IApplicantResult {}
+
+export type IElectionResult = {
+  round: u32
+  ended_at: BlockNumber
+  voting_mode: VotingMode
+  force_stopped: bool
+  applicants: Vec<ApplicantResult>
+  unrevealed_commitments: u32
+  elected: Seats
+}
+
+export class ElectionResult
+  extends JoyStructDecorated({
+    round: u32,
+    ended_at: u32, // BlockNumber
+    voting_mode: VotingMode,
+    force_stopped: bool,
+    applicants: Vec.with(ApplicantResult),
+    unrevealed_commitments: u32,
+    elected: Seats,
+  })
+  implements IElectionResult {}
+
+export type IElectionParticipation = {
+  round: u32
+  applicant_stake: Option<Balance>
+  elected: bool
+  voting_stake: Balance
+  revealed_voting_stake: Balance
+}
+
+export class ElectionParticipation
+  extends JoyStructDecorated({
+    round: u32,
+    applicant_stake: Option.with(u128), // Option<Balance>
+    elected: bool,
+    voting_stake: u128, // Balance
+    revealed_voting_stake: u128, // Balance
+  })
+  implements IElectionParticipation {}
+
 export const councilTypes: RegistryTypes = {
   ElectionStage,
   ElectionStake,
   SealedVote,
   TransferableStake,
+  VotingMode,
   ElectionParameters,
   Seat,
   Seats,
   Backer,
   Backers,
+  Recall,
+  ApplicantResult,
+  ElectionResult,
+  ElectionParticipation,
 }
 
 export default councilTypes
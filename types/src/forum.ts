@@ -1,5 +1,5 @@
-import { bool, u32, u64, Text, Option, Vec as Vector } from '@polkadot/types'
-import { BlockAndTime, ThreadId, PostId, JoyStructCustom, JoyStructDecorated } from './common'
+import { bool, u32, u64, Null, Text, Option, Vec as Vector } from '@polkadot/types'
+import { BlockAndTime, ThreadId, PostId, JoyEnum, JoyStructCustom, JoyStructDecorated } from './common'
 import { RegistryTypes } from '@polkadot/types/types'
 import AccountId from '@polkadot/types/generic/AccountId'
 
@@ -51,6 +51,15 @@ export class PostTextChange extends JoyStructCustom({
 
 export class VecPostTextChange extends Vector.with(PostTextChange) {}
 
+export const PostReactionDef = {
+  Like: Null,
+  Dislike: Null,
+  Laugh: Null,
+  Insightful: Null,
+} as const
+export type PostReactionKey = keyof typeof PostReactionDef
+export class PostReaction extends JoyEnum(PostReactionDef) {}
+
 export class OptionModerationAction extends Option.with(ModerationAction) {}
 
 export class CategoryId extends u64 {}
@@ -348,6 +357,7 @@ export class Reply extends JoyStructCustom({
 
 export const forumTypes: RegistryTypes = {
   PostTextChange,
+  PostReaction,
   ModerationAction,
   ChildPositionInParentCategory,
   CategoryId,
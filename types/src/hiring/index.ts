@@ -1,6 +1,7 @@
 import { Null, u128, u64, u32, Option, Text } from '@polkadot/types'
 import { BTreeSet } from '@polkadot/types/codec'
 import { BlockNumber, Balance } from '@polkadot/types/interfaces'
+import AccountId from '@polkadot/types/generic/AccountId'
 import { JoyEnum, JoyStructDecorated } from '../common'
 import { StakeId } from '../stake'
 
@@ -37,6 +38,7 @@ export enum ApplicationDeactivationCauseKeys {
   OpeningCancelled = 'OpeningCancelled',
   ReviewPeriodExpired = 'ReviewPeriodExpired',
   OpeningFilled = 'OpeningFilled',
+  NotShortlisted = 'NotShortlisted',
 }
 const ApplicationDeactivationCauseDef = {
   External: Null,
@@ -46,6 +48,7 @@ const ApplicationDeactivationCauseDef = {
   OpeningCancelled: Null,
   ReviewPeriodExpired: Null,
   OpeningFilled: Null,
+  NotShortlisted: Null,
 } as const
 export class ApplicationDeactivationCause extends JoyEnum(ApplicationDeactivationCauseDef) {}
 
@@ -334,6 +337,48 @@ export class Application
   })
   implements IApplication {}
 
+export type IOpeningReview = {
+  review_rounds: u32
+  current_round: u32
+  reviewers: BTreeSet<AccountId>
+}
+
+export class OpeningReview
+  extends JoyStructDecorated({
+    review_rounds: u32,
+    current_round: u32,
+    reviewers: BTreeSet.with(AccountId),
+  })
+  implements IOpeningReview {}
+
+export type IOpeningLimits = {
+  application_deadline: Option<BlockNumber>
+  max_hires: Option<u32>
+}
+
+export class OpeningLimits
+  extends JoyStructDecorated({
+    application_deadline: Option.with(u32), // BlockNumber
+    max_hires: Option.with(u32),
+  })
+  implements IOpeningLimits {}
+
+export type IApplicationScore = {
+  round: u32
+  reviewer: AccountId
+  score: u32
+  note: Text
+}
+
+export class ApplicationScore
+  extends JoyStructDecorated({
+    round: u32,
+    reviewer: AccountId,
+    score: u32,
+    note: Text,
+  })
+  implements IApplicationScore {}
+
 export const hiringTypes: RegistryTypes = {
   ApplicationId: 'u64',
   OpeningId: 'u64',
@@ -356,6 +401,9 @@ export const hiringTypes: RegistryTypes = {
   UnstakingApplicationStage,
   ApplicationDeactivationCause,
   StakingAmountLimitMode,
+  OpeningReview,
+  ApplicationScore,
+  OpeningLimits,
 }
 
 export default hiringTypes
@@ -15,6 +15,7 @@ import workingGroup from './working-group'
 import discovery from './discovery'
 import media from './media'
 import proposals from './proposals'
+import constitution from './constitution'
 import { InterfaceTypes } from '@polkadot/types/types/registry'
 import { TypeRegistry } from '@polkadot/types'
 
@@ -35,6 +36,7 @@ export {
   discovery,
   media,
   proposals,
+  constitution,
 }
 
 export const types: RegistryTypes = {
@@ -55,6 +57,7 @@ export const types: RegistryTypes = {
   ...discovery,
   ...media,
   ...proposals,
+  ...constitution,
   // Required since migration to Substrate 2.0,
   // see: https://polkadot.js.org/api/start/FAQ.html#the-node-returns-a-could-not-convert-error-on-send
   Address: 'AccountId',
@@ -1,6 +1,6 @@
-import { Option, Vec as Vector, BTreeMap, u64, bool, Text, Null } from '@polkadot/types'
+import { Option, Vec as Vector, BTreeMap, u32, u64, bool, Text, Null } from '@polkadot/types'
 import { U8aFixed } from '@polkadot/types/codec'
-import { H256 } from '@polkadot/types/interfaces'
+import { BlockNumber, H256 } from '@polkadot/types/interfaces'
 import { BlockAndTime, JoyEnum, JoyStructDecorated } from './common'
 import { MemberId } from './members'
 import { StorageProviderId } from './working-group' // this should be in discovery really
@@ -63,22 +63,47 @@ export class DataObjectStorageRelationship extends JoyStructDecorated({
   ready: bool,
 }) {}
 
+export class StorageChallengeId extends u64 {}
+
+export class StorageChallenge extends JoyStructDecorated({
+  content_id: ContentId,
+  storage_provider_id: StorageProviderId,
+  chunk_index: u64,
+  deadline: BlockNumber,
+}) {}
+
 export class DataObjectType extends JoyStructDecorated({
   description: Text,
   active: bool,
+  replication_factor: u32,
+  max_object_size: Option.with(u64),
 }) {}
 
 export class DataObjectsMap extends BTreeMap.with(ContentId, DataObject) {}
 
+export class StorageQuota extends JoyStructDecorated({
+  size_limit: u64,
+  objects_limit: u64,
+}) {}
+
+export class StorageUsage extends JoyStructDecorated({
+  size: u64,
+  objects: u64,
+}) {}
+
 export const mediaTypes: RegistryTypes = {
   ContentId,
   LiaisonJudgement,
   DataObject,
   DataObjectStorageRelationshipId,
   DataObjectStorageRelationship,
+  StorageChallengeId,
+  StorageChallenge,
   DataObjectTypeId,
   DataObjectType,
   DataObjectsMap,
+  StorageQuota,
+  StorageUsage,
 }
 
 export default mediaTypes
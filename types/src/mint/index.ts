@@ -1,4 +1,4 @@
-import { u32, u64, u128, Option } from '@polkadot/types'
+import { u32, u64, u128, Option, Null, Tuple } from '@polkadot/types'
 import { Balance, BlockNumber } from '@polkadot/types/interfaces'
 import { JoyEnum, JoyStructDecorated } from '../common'
 import { RegistryTypes } from '@polkadot/types/types'
@@ -33,6 +33,12 @@ export class NextAdjustment
   })
   implements INextAdjustment {}
 
+export class Adjustment extends JoyEnum({
+  Interval: AdjustOnInterval,
+  IntervalAfterFirstAdjustmentAbsolute: Tuple.with([AdjustOnInterval, u32]),
+  IntervalAfterFirstAdjustmentRelative: Tuple.with([AdjustOnInterval, u32]),
+} as const) {}
+
 export type IMint = {
   capacity: Balance
   next_adjustment: Option<NextAdjustment>
@@ -48,6 +54,24 @@ export class Mint
   })
   implements IMint {}
 
+export const SpendingCategoryDef = {
+  Rewards: Null,
+  SpendingProposals: Null,
+} as const
+export type SpendingCategoryKey = keyof typeof SpendingCategoryDef
+export class SpendingCategory extends JoyEnum(SpendingCategoryDef) {}
+
+export type IMintSpending = {
+  rewards: Balance
+  spending_proposals: Balance
+}
+export class MintSpending
+  extends JoyStructDecorated({
+    rewards: u128,
+    spending_proposals: u128,
+  })
+  implements IMintSpending {}
+
 export const mintTypes: RegistryTypes = {
   MintId: 'u64',
   Mint,
@@ -57,5 +81,8 @@ export const mintTypes: RegistryTypes = {
   NextAdjustment,
   AdjustOnInterval,
   AdjustCapacityBy,
+  Adjustment,
+  SpendingCategory,
+  MintSpending,
 }
 export default mintTypes
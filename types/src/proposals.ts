@@ -9,6 +9,8 @@ import { StakeId } from './stake'
 import { ElectionParameters } from './council'
 import { ActivateOpeningAt, OpeningId, ApplicationId } from './hiring'
 import { WorkingGroupOpeningPolicyCommitment, WorkerId, RewardPolicy } from './working-group'
+import { Adjustment } from './mint'
+import { ConstitutionAmendment } from './constitution'
 
 export type IVotingResults = {
   abstensions: u32
@@ -62,6 +64,7 @@ export type IProposal = {
   createdAt: BlockNumber
   status: ProposalStatus
   votingResults: VotingResults
+  exactlyExecuteAt: Option<BlockNumber>
 }
 
 export const IProposalStatus: { [key: string]: string } = {
@@ -174,6 +177,8 @@ export class Proposal
     status: ProposalStatus,
     /// Curring voting result for the proposal
     votingResults: VotingResults,
+    /// Block to execute the approved proposal at instead of waiting for the grace period
+    exactlyExecuteAt: Option.with(u32), // BlockNumber
   }) {}
 
 export class ThreadCounter extends JoyStructDecorated({
@@ -249,6 +254,37 @@ export class TerminateRoleParameters
   })
   implements ITerminateRoleParameters {}
 
+export const GovernedMintDef = {
+  Council: Null,
+  ContentWorkingGroup: Null,
+  WorkingGroup,
+} as const
+export type GovernedMintKey = keyof typeof GovernedMintDef
+export class GovernedMint extends JoyEnum(GovernedMintDef) {}
+
+export const ProposalKindDef = {
+  Text: Null,
+  RuntimeUpgrade: Null,
+  SetElectionParameters: Null,
+  Spending: Null,
+  SetLead: Null,
+  SetContentWorkingGroupMintCapacity: Null,
+  SetValidatorCount: Null,
+  AddWorkingGroupLeaderOpening: Null,
+  BeginReviewWorkingGroupLeaderApplications: Null,
+  FillWorkingGroupLeaderOpening: Null,
+  SetWorkingGroupMintCapacity: Null,
+  DecreaseWorkingGroupLeaderStake: Null,
+  SlashWorkingGroupLeaderStake: Null,
+  SetWorkingGroupLeaderReward: Null,
+  TerminateWorkingGroupLeaderRole: Null,
+  SetMintCapacityAdjustment: Null,
+  SetProposalParameters: Null,
+  AmendConstitution: Null,
+} as const
+export type ProposalKindKey = keyof typeof ProposalKindDef
+export class ProposalKind extends JoyEnum(ProposalKindDef) {}
+
 export class ProposalDetails extends JoyEnum({
   Text: Text,
   RuntimeUpgrade: Bytes,
@@ -267,6 +303,11 @@ export class ProposalDetails extends JoyEnum({
   SlashWorkingGroupLeaderStake: Tuple.with([WorkerId, 'Balance', WorkingGroup]),
   SetWorkingGroupLeaderReward: Tuple.with([WorkerId, 'Balance', WorkingGroup]),
   TerminateWorkingGroupLeaderRole: TerminateRoleParameters,
+  SetMintCapacityAdjustment: Tuple.with([GovernedMint, Option.with(Adjustment)]),
+  // Recursive type: referenced by name
+  Batch: Vec.with('ProposalDetails'),
+  SetProposalParameters: Tuple.with([ProposalKind, ProposalParameters]),
+  AmendConstitution: ConstitutionAmendment,
 } as const) {}
 
 // export default proposalTypes;
@@ -285,6 +326,8 @@ export const proposalsTypes = {
   AddOpeningParameters,
   FillOpeningParameters,
   TerminateRoleParameters,
+  GovernedMint,
+  ProposalKind,
   // Expose in registry for api.createType purposes:
   ActiveStake,
   Finalized,
@@ -42,10 +42,24 @@ export class RewardRelationship
   })
   implements IRewardRelationship {}
 
+export type IRewardArrears = {
+  mint_id: MintId
+  account: AccountId
+  amount: Balance
+}
+export class RewardArrears
+  extends JoyStructDecorated({
+    mint_id: MintId,
+    account: AccountId,
+    amount: u128, // Balance
+  })
+  implements IRewardArrears {}
+
 export const recurringRewardsTypes: RegistryTypes = {
   RecipientId: 'u64',
   RewardRelationshipId: 'u64',
   Recipient,
   RewardRelationship,
+  RewardArrears,
 }
 export default recurringRewardsTypes
@@ -73,6 +73,21 @@ export class Worker
   }
 }
 
+// Also defined in "content-working-group" runtime module, but those definitions are the consistent
+export type IRewardPolicy = {
+  amount_per_payout: Balance
+  next_payment_at_block: BlockNumber
+  payout_interval: Option<BlockNumber>
+}
+
+export class RewardPolicy
+  extends JoyStructDecorated({
+    amount_per_payout: u128, // Balance
+    next_payment_at_block: u32, // BlockNumber
+    payout_interval: Option.with(u32), // Option<BlockNumber>
+  })
+  implements IRewardPolicy {}
+
 export type IWorkingGroupOpeningPolicyCommitment = {
   application_rationing_policy: Option<ApplicationRationingPolicy>
   max_review_period_length: BlockNumber
@@ -86,19 +101,15 @@ export type IWorkingGroupOpeningPolicyCommitment = {
   terminate_role_stake_unstaking_period: Option<BlockNumber>
   exit_role_application_stake_unstaking_period: Option<BlockNumber>
   exit_role_stake_unstaking_period: Option<BlockNumber>
+  auto_fill_on_review_period_expiry: Option<u32>
+  application_deadline: Option<BlockNumber>
+  max_hires: Option<u32>
+  auto_fill_reward_policy: Option<RewardPolicy>
 }
 
-// This type represents OpeningPolicyCommitment defined inside the runtime's working-grpup module.
-// The only difference between this and the one defined in /content-working-group is in the names of some fields.
-//
-// There is also a minor issue here:
-// Because api metadata still says that ie. the "commitment" argument of "storageWorkingGroup.addOpening" extrinsic
-// is of type "OpeningPolicyCommitment" (not the "WorkingGroupOpeningPolicyCommitment" defined here), the CWG's OpeningPolicyCommitment
-// type is used when sending this extrinsic (it has "terminate_curator_role_stake_unstaking_period" field insted
-// of "terminate_role_stake_unstaking_period" etc.).
-// Since both those types are basically the same structs (only filed names are different) nothing seems to break, but it's
-// very fragile atm and any change to this type in working-group module could result in "unsolvable" inconsistencies
-// (this won't be an issue after CWG gets refactored to use the working-grpup module too)
+// This type represents OpeningPolicyCommitment defined inside the runtime's working-group module.
+// The runtime names the "commitment" argument of the "addOpening" extrinsic "WorkingGroupOpeningPolicyCommitment"
+// in the metadata, so it doesn't get mixed up with the CWG's OpeningPolicyCommitment, which has a different layout.
 export class WorkingGroupOpeningPolicyCommitment
   extends JoyStructDecorated({
     application_rationing_policy: Option.with(ApplicationRationingPolicy),
@@ -113,6 +124,10 @@ export class WorkingGroupOpeningPolicyCommitment
     terminate_role_stake_unstaking_period: Option.with(u32),
     exit_role_application_stake_unstaking_period: Option.with(u32),
     exit_role_stake_unstaking_period: Option.with(u32),
+    auto_fill_on_review_period_expiry: Option.with(u32),
+    application_deadline: Option.with(u32), // BlockNumber
+    max_hires: Option.with(u32),
+    auto_fill_reward_policy: Option.with(RewardPolicy),
   })
   implements IWorkingGroupOpeningPolicyCommitment {}
 
@@ -144,21 +159,6 @@ export class Opening
   })
   implements IOpening {}
 
-// Also defined in "content-working-group" runtime module, but those definitions are the consistent
-export type IRewardPolicy = {
-  amount_per_payout: Balance
-  next_payment_at_block: BlockNumber
-  payout_interval: Option<BlockNumber>
-}
-
-export class RewardPolicy
-  extends JoyStructDecorated({
-    amount_per_payout: u128, // Balance
-    next_payment_at_block: u32, // BlockNumber
-    payout_interval: Option.with(u32), // Option<BlockNumber>
-  })
-  implements IRewardPolicy {}
-
 // Needed for types augment tool
 export { OpeningId, ApplicationId }
 
@@ -7,7 +7,8 @@
 // https://testnet.joystream.org/#/js
 
 const script = async ({ api }) => {
-  const ids = await api.query.dataDirectory.knownContentIds()
+  const entries = await api.query.dataDirectory.knownContentIdIndex.entries()
+  const ids = entries.map(([storageKey]) => storageKey.args[0])
 
   // When a BTreeMap is constructed for injection the node will fail to decode
   // it if its not sorted.
@@ -8,7 +8,8 @@
 // requires nicaea release+
 
 const script = async ({ api }) => {
-  const ids = await api.query.dataDirectory.knownContentIds()
+  const entries = await api.query.dataDirectory.knownContentIdIndex.entries()
+  const ids = entries.map(([storageKey]) => storageKey.args[0])
 
   await Promise.all(
     ids.map(async (id) => {